@@ -254,5 +254,284 @@ pub enum SolSocialError {
     
     #[msg("Emergency stop activated")]
     EmergencyStop,
+    
+    #[msg("Invalid media URL")]
+    InvalidMediaUrl,
+    
+    #[msg("Invalid founder allocation")]
+    InvalidFounderAllocation,
+    
+    #[msg("Username owner is still active or has an audience")]
+    UsernameNotReclaimable,
+    
+    #[msg("Keys are not from the market gating this post")]
+    GatedMarketMismatch,
+    
+    #[msg("Quote tolerance is too wide")]
+    ToleranceTooWide,
+    
+    #[msg("Nothing to distribute")]
+    NothingToDistribute,
+    
+    #[msg("Chat ID too long")]
+    ChatIdTooLong,
+    
+    #[msg("Chat name too long")]
+    ChatNameTooLong,
+    
+    #[msg("Chat description too long")]
+    ChatDescriptionTooLong,
+    
+    #[msg("Invalid protocol config value")]
+    InvalidConfigValue,
+    
+    #[msg("Invalid holder vote")]
+    InvalidVote,
+    
+    #[msg("Voting is closed")]
+    VotingClosed,
+    
+    #[msg("Voting is still open")]
+    VotingStillOpen,
+    
+    #[msg("Sell exceeds the direct sell threshold; queue it instead")]
+    SellAboveQueueThreshold,
+    
+    #[msg("No sell tranche is due yet")]
+    TrancheNotDue,
+    
+    #[msg("Reveal does not match the commitment")]
+    CommitmentMismatch,
+    
+    #[msg("Commitment cannot be revealed or refunded in this slot")]
+    RevealNotAllowed,
+    
+    #[msg("Author holds fewer of their own keys than required to post gated content")]
+    SelfHoldTooLow,
+    
+    #[msg("Purchase would exceed the market's per-holder key cap")]
+    HolderCapReached,
+    
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+    
+    #[msg("Message does not belong to this chat")]
+    MessageNotInChat,
+    
+    #[msg("Guardian set must be 1-5 distinct wallets other than the owner, with a threshold no larger than the set")]
+    InvalidGuardians,
+    
+    #[msg("An account recovery is already pending")]
+    RecoveryPending,
+    
+    #[msg("No account recovery is pending")]
+    NoRecoveryPending,
+    
+    #[msg("Account recovery delay has not elapsed yet")]
+    RecoveryNotReady,
+    
+    #[msg("Not enough guardians approved the recovery")]
+    InsufficientGuardianApprovals,
+    
+    #[msg("Recovery must move the account to a new, non-default wallet")]
+    InvalidRecoveryAuthority,
+    
+    #[msg("Account is already on the current layout")]
+    AlreadyMigrated,
+    
+    #[msg("Limit order needs a price, an amount and a future expiry")]
+    InvalidOrder,
+    
+    #[msg("Limit order has expired")]
+    OrderExpired,
+    
+    #[msg("Curve price has not reached the order's limit")]
+    LimitPriceNotReached,
+    
+    #[msg("Trade exceeds the protocol's maximum keys per trade")]
+    TradeTooLarge,
+    
+    #[msg("Admin multisig must be 1-10 distinct wallets with a threshold no larger than the set")]
+    InvalidMultisig,
+    
+    #[msg("Signer is not a member of the admin multisig")]
+    NotMultisigSigner,
+    
+    #[msg("Signer already approved this proposal")]
+    AlreadyApproved,
+    
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    
+    #[msg("Not enough multisig signers approved the proposal")]
+    InsufficientMultisigApprovals,
+    
+    #[msg("Config change is still timelocked")]
+    TimelockNotElapsed,
+    
+    #[msg("Launch auction must sell at least one key at a falling price above the curve, for at most 7 days")]
+    InvalidLaunchAuction,
+    
+    #[msg("Market has no launch auction running")]
+    LaunchAuctionNotLive,
+    
+    #[msg("Presale needs an allowlist, a price above the curve, a per-wallet cap and at most 7 days")]
+    InvalidPresale,
+    
+    #[msg("Market has no presale running")]
+    PresaleNotLive,
+    
+    #[msg("Wallet is not on the presale allowlist")]
+    NotAllowlisted,
+    
+    #[msg("Purchase would exceed the presale's per-wallet cap")]
+    PresaleWalletCapReached,
+    
+    #[msg("Launch guard needs a per-wallet cap and a window of at most 9,000 slots covering its cooldown")]
+    InvalidLaunchGuard,
+    
+    #[msg("Only buy_keys may buy while the market's launch guard is active")]
+    LaunchGuardActive,
+    
+    #[msg("Buy would exceed the launch guard's per-wallet cap")]
+    LaunchBuyCapReached,
+    
+    #[msg("Wallet must wait out the launch guard cooldown before buying again")]
+    LaunchCooldownActive,
+    
+    #[msg("OTC offer needs keys, a price, a future expiry and a counterparty other than the maker")]
+    InvalidOtcOffer,
+    
+    #[msg("OTC offer has expired")]
+    OtcOfferExpired,
+    
+    #[msg("Wallet is not the offer's counterparty")]
+    NotOtcCounterparty,
+    
+    #[msg("Market graduation is disabled")]
+    GraduationDisabled,
+    
+    #[msg("Market cap has not reached the graduation threshold")]
+    BelowGraduationMarketCap,
+    
+    #[msg("Market has already graduated to an AMM pool")]
+    MarketGraduated,
+    
+    #[msg("Market reserve is too small to seed an AMM pool")]
+    InsufficientGraduationLiquidity,
+    
+    #[msg("Vesting needs keys and a cliff within a schedule of at most 4 years")]
+    InvalidVestingSchedule,
+    
+    #[msg("Payment token can only be set once, before the market trades or opens a launch sale")]
+    InvalidPaymentMint,
+    
+    #[msg("This instruction settles in lamports; the market settles in an SPL token")]
+    PaymentTokenNotSupported,
+    
+    #[msg("Trade moves the price by more than the allowed impact")]
+    PriceImpactTooHigh,
+    
+    #[msg("Cannot buy and sell the same keys within one slot")]
+    SameSlotRoundTrip,
+    
+    #[msg("The curve price has not crossed the order's trigger")]
+    TriggerNotReached,
+    
+    #[msg("DCA schedules need a budget, an interval of at least a minute and a deposit above the keeper fee")]
+    InvalidDcaSchedule,
+    
+    #[msg("The DCA schedule's next interval has not started")]
+    DcaNotDue,
+    
+    #[msg("The DCA interval budget does not cover a single key")]
+    DcaBudgetTooLow,
+    
+    #[msg("Wallet does not hold an early-supporter seat in this market")]
+    NotEarlySupporter,
+    
+    #[msg("Revenue shares need 2 to 4 creators, lead first, adding up to 100%, set before the market trades")]
+    InvalidRevenueShare,
+    
+    #[msg("Shared earnings must be distributed to the collaborators first")]
+    SharedEarningsUndistributed,
+    
+    #[msg("Loans need keys, a term of at most 90 days and an opening collateral ratio above a liquidation line of at least 100%")]
+    InvalidLoanTerms,
+    
+    #[msg("The loan has already been taken")]
+    LoanAlreadyTaken,
+    
+    #[msg("The loan has not been taken")]
+    LoanNotActive,
+    
+    #[msg("Collateral does not cover the loan at the required ratio")]
+    InsufficientCollateral,
+    
+    #[msg("The loan is neither overdue nor under-collateralised")]
+    LoanNotLiquidatable,
+    
+    #[msg("The market's price oracle has no observations yet")]
+    OraclePriceUnavailable,
+    
+    #[msg("Raffles need a prize and every current key holder as an entrant, at most 32")]
+    InvalidRaffle,
+    
+    #[msg("Randomness must be committed in the slot before the raffle is created")]
+    RandomnessNotFresh,
+    
+    #[msg("Randomness has not been revealed in this slot")]
+    RandomnessNotRevealed,
+    
+    #[msg("Winner account does not match the drawn entrant")]
+    RaffleWinnerMismatch,
+    
+    #[msg("Mentions must name distinct wallets, at most 20")]
+    InvalidMentions,
+    
+    #[msg("Mentioned wallet has no user profile")]
+    MentionedUserNotFound,
+    
+    #[msg("Post is not premium and needs no unlock")]
+    PostNotPremium,
+    
+    #[msg("Boosts must last between one hour and 30 days")]
+    InvalidBoostDuration,
+    
+    #[msg("Boost has not reached its end time")]
+    BoostStillActive,
+    
+    #[msg("Post has already been minted as an NFT")]
+    PostAlreadyMinted,
+    
+    #[msg("NFT name, URI or royalty is out of range")]
+    InvalidNftMetadata,
+    
+    #[msg("Post body does not match its on-chain content hash")]
+    ContentHashMismatch,
+    
+    #[msg("Collection names must be 1 to 32 bytes")]
+    InvalidCollectionName,
+    
+    #[msg("Collection still has bookmarks filed under it")]
+    CollectionNotEmpty,
+    
+    #[msg("Ephemeral posts must live between one second and seven days")]
+    InvalidPostLifetime,
+    
+    #[msg("Post has not expired")]
+    PostNotExpired,
+    
+    #[msg("Threads can only be continued from their root post, by its author")]
+    InvalidThreadRoot,
+    
+    #[msg("Post visibility settings don't allow this viewer")]
+    PostNotVisible,
+    
+    #[msg("Dividend epoch is still open for claims")]
+    DividendEpochStillOpen,
+    
+    #[msg("Market has not graduated to an AMM pool")]
+    MarketNotGraduated,
 }
 ```
\ No newline at end of file
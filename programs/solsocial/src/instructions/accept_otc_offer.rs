@@ -0,0 +1,300 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, OtcOffer, OrderSide, ProtocolConfig, RewardPool, UserKeys, EarningsVault, KEY_HOLDER_SEED, OTC_ESCROW_SEED, OTC_OFFER_SEED, EARNINGS_VAULT_SEED, Feature};
+use crate::utils::accounts::move_lamports;
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::revenue_share::{calculate_fee, system_transfer};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct AcceptOtcOffer<'info> {
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    /// Receives sell proceeds, and the escrow rent when the offer closes
+    #[account(mut)]
+    pub maker: SystemAccount<'info>,
+
+    /// CHECK: The subject whose keys the offer trades
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Freezes and thaws the holder's key token account
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    /// Collects the subject's creator fee
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(
+        mut,
+        close = maker,
+        has_one = maker @ SolSocialError::InvalidAccountOwner,
+        seeds = [OTC_OFFER_SEED, maker.key().as_ref(), subject.key().as_ref(), &offer.offer_id.to_le_bytes()],
+        bump = offer.bump,
+    )]
+    pub offer: Account<'info, OtcOffer>,
+
+    #[account(
+        mut,
+        seeds = [OTC_ESCROW_SEED, offer.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, maker.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub maker_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        init_if_needed,
+        payer = taker,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, taker.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub taker_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = maker,
+    )]
+    pub maker_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = taker,
+    )]
+    pub taker_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Take the other side of an OTC offer. Keys change hands at the offer's price without
+/// touching the curve, so supply and spot price stay put. The market's buy fee rates apply
+/// to the price: the buyer pays it in full and the seller receives it less fees.
+pub fn handler(ctx: Context<AcceptOtcOffer>) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let now = Clock::get()?.unix_timestamp;
+    let offer = &ctx.accounts.offer;
+    require!(!offer.is_expired(now), SolSocialError::OtcOfferExpired);
+    require!(offer.can_be_taken_by(&ctx.accounts.taker.key()), SolSocialError::NotOtcCounterparty);
+
+    let side = offer.side;
+    let amount = offer.amount;
+    let price = offer.price;
+    let keys_account = &mut ctx.accounts.keys_account;
+    if !keys_account.is_trading_open(now) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::OutsideTradingHours,
+                price: keys_account.trading_open_at as u64,
+                limit: keys_account.trading_close_at as u64,
+                amount,
+            },
+            SolSocialError::OperationNotAllowed,
+        ));
+    }
+    // Guarded launches only accept buy_keys, which enforces the per-wallet limits
+    require!(!keys_account.launch_guard.is_active(Clock::get()?.slot), SolSocialError::LaunchGuardActive);
+
+    let params = keys_account.curve_params;
+    let protocol_fee = calculate_fee(price, params.buy_protocol_fee)?;
+    let creator_fee = calculate_fee(price, params.buy_creator_fee)?;
+    let reward_pool_amount = ctx.accounts.config.reward_pool_cut(protocol_fee)?;
+    let treasury_fee = protocol_fee
+        .checked_sub(reward_pool_amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    let net = price
+        .checked_sub(protocol_fee)
+        .and_then(|net| net.checked_sub(creator_fee))
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    let subject_key = ctx.accounts.subject.key();
+    let maker_key = ctx.accounts.maker.key();
+    let taker_key = ctx.accounts.taker.key();
+    let offer_info = ctx.accounts.offer.to_account_info();
+    let offer_id = ctx.accounts.offer.offer_id.to_le_bytes();
+    let offer_seeds = &[
+        OTC_OFFER_SEED,
+        maker_key.as_ref(),
+        subject_key.as_ref(),
+        &offer_id,
+        &[ctx.accounts.offer.bump],
+    ];
+    let signer = &[&offer_seeds[..]];
+    let keys_seeds = &[b"keys".as_ref(), subject_key.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        keys_account,
+        keys_signer,
+    );
+    let taker_token_info = ctx.accounts.taker_token_account.to_account_info();
+    let maker_token_info = ctx.accounts.maker_token_account.to_account_info();
+
+    let taker_holder = &mut ctx.accounts.taker_holder;
+    if taker_holder.holder == Pubkey::default() {
+        **taker_holder = KeyHolder::new(taker_key, subject_key);
+    }
+    let maker_holder = &mut ctx.accounts.maker_holder;
+
+    // Move keys first, then lamports; the buyer's lamports pay the fees and the seller the rest
+    let (seller, seller_before, buyer, buyer_before) = match side {
+        OrderSide::Sell => {
+            require!(
+                params.within_holder_cap(taker_holder.amount, amount),
+                SolSocialError::HolderCapReached
+            );
+
+            freeze.thaw(&taker_token_info)?;
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.escrow.to_account_info(),
+                to: taker_token_info.clone(),
+                authority: offer_info.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+            token::transfer(cpi_ctx, amount)?;
+            freeze.freeze(&taker_token_info)?;
+
+            let taker_info = ctx.accounts.taker.to_account_info();
+            let system_program = ctx.accounts.system_program.to_account_info();
+            system_transfer(&taker_info, &ctx.accounts.treasury.to_account_info(), &system_program, treasury_fee)?;
+            system_transfer(&taker_info, &ctx.accounts.reward_pool.to_account_info(), &system_program, reward_pool_amount)?;
+            system_transfer(&taker_info, &ctx.accounts.earnings_vault.to_account_info(), &system_program, creator_fee)?;
+            system_transfer(&taker_info, &ctx.accounts.maker.to_account_info(), &system_program, net)?;
+
+            // The escrowed keys left the maker's record at creation; count them back before selling
+            let maker_before = maker_holder.amount
+                .checked_add(amount)
+                .ok_or(SolSocialError::ArithmeticOverflow)?;
+            maker_holder.amount = maker_before;
+            let taker_before = taker_holder.amount;
+            (&mut **maker_holder, maker_before, &mut **taker_holder, taker_before)
+        }
+        OrderSide::Buy => {
+            require!(
+                taker_holder.amount >= amount && ctx.accounts.taker_token_account.amount >= amount,
+                SolSocialError::InsufficientKeys
+            );
+            require!(
+                params.within_holder_cap(maker_holder.amount, amount),
+                SolSocialError::HolderCapReached
+            );
+
+            freeze.thaw(&taker_token_info)?;
+            freeze.thaw(&maker_token_info)?;
+            let cpi_accounts = Transfer {
+                from: taker_token_info.clone(),
+                to: maker_token_info.clone(),
+                authority: ctx.accounts.taker.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+            token::transfer(cpi_ctx, amount)?;
+            freeze.freeze(&taker_token_info)?;
+            freeze.freeze(&maker_token_info)?;
+
+            // The offer's lamport escrow pays the price; the rest refunds to the maker on close
+            move_lamports(&offer_info, &ctx.accounts.treasury.to_account_info(), treasury_fee)?;
+            move_lamports(&offer_info, &ctx.accounts.reward_pool.to_account_info(), reward_pool_amount)?;
+            move_lamports(&offer_info, &ctx.accounts.earnings_vault.to_account_info(), creator_fee)?;
+            move_lamports(&offer_info, &ctx.accounts.taker.to_account_info(), net)?;
+
+            let taker_before = taker_holder.amount;
+            let maker_before = maker_holder.amount;
+            (&mut **taker_holder, taker_before, &mut **maker_holder, maker_before)
+        }
+    };
+    ctx.accounts.reward_pool.record_deposit(reward_pool_amount, now)?;
+    ctx.accounts.earnings_vault.record_accrual(creator_fee)?;
+
+    seller.update_after_sell(amount, price);
+    buyer.update_after_buy(amount, price / amount, price);
+    keys_account.track_holder_balance(seller_before, seller.amount)?;
+    keys_account.track_holder_balance(buyer_before, buyer.amount)?;
+    keys_account.volume = keys_account.volume.saturating_add(price);
+    keys_account.creator_earnings = keys_account.creator_earnings.saturating_add(creator_fee);
+    keys_account.protocol_fees = keys_account.protocol_fees.saturating_add(protocol_fee);
+    keys_account.last_trade_at = now;
+
+    // Nothing stays escrowed once settled, so the empty token account closes with the offer
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow.to_account_info(),
+        destination: ctx.accounts.maker.to_account_info(),
+        authority: offer_info.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::close_account(cpi_ctx)?;
+
+    emit!(OtcOfferAcceptedEvent {
+        offer: offer_info.key(),
+        maker: maker_key,
+        taker: taker_key,
+        keys_user: subject_key,
+        side,
+        amount,
+        price,
+        protocol_fee,
+        creator_fee,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OtcOfferAcceptedEvent {
+    pub offer: Pubkey,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub keys_user: Pubkey,
+    /// The maker's side of the trade
+    pub side: OrderSide,
+    pub amount: u64,
+    /// Total lamports the buyer paid, fees included
+    pub price: u64,
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,393 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{
+    AdminAction, AdminMultisig, AdminProposal, FeeExemption, ProtocolConfig, User, ADMIN_MULTISIG_SEED,
+    ADMIN_PROPOSAL_SEED,
+};
+use crate::utils::accounts::{close_account, create_pda_account, write_account};
+use crate::error::SolSocialError;
+use super::fee_exemption::FeeExemptionChangedEvent;
+use super::set_feature_flags::FeatureFlagsChangedEvent;
+use super::treasury::pay_out_fees;
+
+#[derive(Accounts)]
+pub struct CreateAdminMultisig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = AdminMultisig::LEN,
+        seeds = [ADMIN_MULTISIG_SEED],
+        bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        has_one = admin @ SolSocialError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Hand the admin and treasury authority over to an M-of-N multisig. From here on, config
+/// changes, treasury withdrawals and verification grants need `threshold` signers.
+pub fn create_handler(ctx: Context<CreateAdminMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let multisig_key = ctx.accounts.multisig.key();
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.initialize(signers, threshold, now, ctx.bumps.multisig)?;
+
+    let config = &mut ctx.accounts.config;
+    let previous_admin = config.admin;
+    config.admin = multisig_key;
+    config.treasury_authority = multisig_key;
+    config.updated_at = now;
+
+    emit!(AdminMultisigCreatedEvent {
+        multisig: multisig_key,
+        previous_admin,
+        signers: multisig.signers.clone(),
+        threshold,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(action: AdminAction)]
+pub struct ProposeAdminAction<'info> {
+    #[account(
+        mut,
+        seeds = [ADMIN_MULTISIG_SEED],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = AdminProposal::LEN,
+        seeds = [ADMIN_PROPOSAL_SEED, multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a proposal for `action`, counting the proposer as its first approval
+pub fn propose_handler(ctx: Context<ProposeAdminAction>, action: AdminAction) -> Result<()> {
+    let signer_index = ctx.accounts.multisig.signer_index(&ctx.accounts.proposer.key())?;
+    let now = Clock::get()?.unix_timestamp;
+    let multisig_key = ctx.accounts.multisig.key();
+    let index = ctx.accounts.multisig.next_proposal_index()?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = multisig_key;
+    proposal.index = index;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.approvals = 0;
+    proposal.executed = false;
+    proposal.created_at = now;
+    proposal.executed_at = 0;
+    proposal.bump = ctx.bumps.proposal;
+    proposal.approve(signer_index)?;
+
+    emit!(AdminActionProposedEvent {
+        multisig: multisig_key,
+        proposal: proposal.key(),
+        index,
+        proposer: proposal.proposer,
+        action,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveAdminAction<'info> {
+    #[account(
+        seeds = [ADMIN_MULTISIG_SEED],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_PROPOSAL_SEED, multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig @ SolSocialError::InvalidMultisig
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn approve_handler(ctx: Context<ApproveAdminAction>) -> Result<()> {
+    let signer_index = ctx.accounts.multisig.signer_index(&ctx.accounts.signer.key())?;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.approve(signer_index)?;
+
+    emit!(AdminActionApprovedEvent {
+        multisig: proposal.multisig,
+        proposal: proposal.key(),
+        signer: ctx.accounts.signer.key(),
+        approvals: proposal.approval_count() as u8,
+        threshold: ctx.accounts.multisig.threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    #[account(
+        seeds = [ADMIN_MULTISIG_SEED],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_PROPOSAL_SEED, multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig @ SolSocialError::InvalidMultisig
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        constraint = config.admin == multisig.key() @ SolSocialError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// Pays the rent of a granted fee exemption and takes it back on revocation
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_fee"],
+        bump,
+    )]
+    pub protocol_fee_account: SystemAccount<'info>,
+
+    /// CHECK: must match the proposal's withdrawal destination; only read for `WithdrawTreasury`
+    #[account(mut)]
+    pub destination: Option<UncheckedAccount<'info>>,
+
+    /// Profile whose badge changes; only read for `SetVerified`
+    #[account(mut)]
+    pub target_user: Option<Account<'info, User>>,
+
+    /// CHECK: the `FeeExemption` PDA of the proposal's account, checked against its seeds;
+    /// only read for `GrantFeeExemption` and `RevokeFeeExemption`
+    #[account(mut)]
+    pub fee_exemption: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Run an approved proposal. Any signer of the multisig may trigger it once enough
+/// approvals are in; the action itself is exactly what the signers approved.
+pub fn execute_handler(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+    ctx.accounts.multisig.signer_index(&ctx.accounts.executor.key())?;
+    let now = Clock::get()?.unix_timestamp;
+    let threshold = ctx.accounts.multisig.threshold;
+    ctx.accounts.proposal.mark_executed(threshold, now)?;
+
+    let action = ctx.accounts.proposal.action;
+    match action {
+        AdminAction::UpdateConfig(update) => {
+            // Config changes wait out the timelock from the moment they were proposed
+            let eta = ctx.accounts.config.timelock_eta(ctx.accounts.proposal.created_at)?;
+            require!(now >= eta, SolSocialError::TimelockNotElapsed);
+            ctx.accounts.config.apply_update(&update, now)?;
+        }
+        AdminAction::SetFeatureFlags(flags) => {
+            require!(flags.is_valid(), SolSocialError::InvalidConfigValue);
+            let config = &mut ctx.accounts.config;
+            let previous = config.feature_flags;
+            config.feature_flags = flags;
+            config.updated_at = now;
+
+            emit!(FeatureFlagsChangedEvent {
+                admin: config.admin,
+                previous_bits: previous.bits,
+                bits: flags.bits,
+                timestamp: now,
+            });
+        }
+        AdminAction::SetTreasuryAuthority(new_authority) => {
+            require_keys_neq!(new_authority, Pubkey::default(), SolSocialError::InvalidConfigValue);
+            let config = &mut ctx.accounts.config;
+            config.treasury_authority = new_authority;
+            config.updated_at = now;
+        }
+        AdminAction::SetAdmin(new_admin) => {
+            require_keys_neq!(new_admin, Pubkey::default(), SolSocialError::InvalidConfigValue);
+            let config = &mut ctx.accounts.config;
+            config.admin = new_admin;
+            config.updated_at = now;
+        }
+        AdminAction::WithdrawTreasury { amount, destination } => {
+            require_keys_eq!(
+                ctx.accounts.config.treasury_authority,
+                ctx.accounts.multisig.key(),
+                SolSocialError::Unauthorized
+            );
+            let destination_account = ctx
+                .accounts
+                .destination
+                .as_ref()
+                .ok_or(anchor_lang::error::ErrorCode::AccountNotEnoughKeys)?;
+            require_keys_eq!(destination_account.key(), destination, anchor_lang::error::ErrorCode::ConstraintAddress);
+
+            pay_out_fees(
+                ctx.accounts.treasury.to_account_info(),
+                ctx.bumps.treasury,
+                ctx.accounts.protocol_fee_account.to_account_info(),
+                ctx.bumps.protocol_fee_account,
+                destination_account.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                amount,
+            )?;
+        }
+        AdminAction::SetVerified { user, verified } => {
+            let target_user = ctx
+                .accounts
+                .target_user
+                .as_mut()
+                .ok_or(anchor_lang::error::ErrorCode::AccountNotEnoughKeys)?;
+            let (expected, _) = Pubkey::find_program_address(&[b"user", user.as_ref()], ctx.program_id);
+            require_keys_eq!(target_user.key(), expected, anchor_lang::error::ErrorCode::ConstraintSeeds);
+            target_user.set_verified(verified);
+        }
+        AdminAction::GrantFeeExemption { account } => {
+            let exemption_info = ctx
+                .accounts
+                .fee_exemption
+                .as_ref()
+                .ok_or(anchor_lang::error::ErrorCode::AccountNotEnoughKeys)?
+                .to_account_info();
+            let (expected, bump) = Pubkey::find_program_address(&[b"fee_exemption", account.as_ref()], ctx.program_id);
+            require_keys_eq!(exemption_info.key(), expected, anchor_lang::error::ErrorCode::ConstraintSeeds);
+            require!(exemption_info.data_is_empty(), anchor_lang::error::ErrorCode::AccountDiscriminatorAlreadySet);
+
+            create_pda_account(
+                &ctx.accounts.executor.to_account_info(),
+                &exemption_info,
+                &ctx.accounts.system_program.to_account_info(),
+                FeeExemption::LEN,
+                &[b"fee_exemption", account.as_ref(), &[bump]],
+            )?;
+            let granted_by = ctx.accounts.multisig.key();
+            write_account(
+                &exemption_info,
+                &FeeExemption { account, granted_by, granted_at: now, bump },
+            )?;
+
+            emit!(FeeExemptionChangedEvent {
+                account,
+                admin: granted_by,
+                exempt: true,
+                timestamp: now,
+            });
+        }
+        AdminAction::RevokeFeeExemption { account } => {
+            let exemption_info = ctx
+                .accounts
+                .fee_exemption
+                .as_ref()
+                .ok_or(anchor_lang::error::ErrorCode::AccountNotEnoughKeys)?
+                .to_account_info();
+            let (expected, _) = Pubkey::find_program_address(&[b"fee_exemption", account.as_ref()], ctx.program_id);
+            require_keys_eq!(exemption_info.key(), expected, anchor_lang::error::ErrorCode::ConstraintSeeds);
+            // Fails unless the exemption exists
+            Account::<FeeExemption>::try_from(&exemption_info)?;
+            close_account(&exemption_info, &ctx.accounts.executor.to_account_info())?;
+
+            emit!(FeeExemptionChangedEvent {
+                account,
+                admin: ctx.accounts.multisig.key(),
+                exempt: false,
+                timestamp: now,
+            });
+        }
+    }
+
+    let proposal = &ctx.accounts.proposal;
+    emit!(AdminActionExecutedEvent {
+        multisig: proposal.multisig,
+        proposal: proposal.key(),
+        index: proposal.index,
+        executor: ctx.accounts.executor.key(),
+        action,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AdminMultisigCreatedEvent {
+    pub multisig: Pubkey,
+    pub previous_admin: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionProposedEvent {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionApprovedEvent {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionExecutedEvent {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub executor: Pubkey,
+    pub action: AdminAction,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
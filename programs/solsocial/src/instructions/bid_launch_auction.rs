@@ -0,0 +1,207 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::state::{KeyHolder, ProtocolConfig, RewardPool, UserKeys, EarningsVault, EARNINGS_VAULT_SEED, KEY_HOLDER_SEED, Feature};
+use crate::utils::revenue_share::{calculate_fee, system_transfer};
+use crate::utils::accounts::require_rent_funds;
+use crate::utils::key_token::KeyFreeze;
+use crate::utils::trade::record_milestones;
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct BidLaunchAuction<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: The creator whose launch auction is being bid on
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Collects the subject's creator fee
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(mut, address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    /// The buyer's holding record; the per-holder cap counts it, as buy_keys does
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, buyer.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Buy `amount` keys from a live launch auction at its current per-key price. Fees follow
+/// the market's buy-side rates and the rest joins the reserve, as with a curve buy.
+pub fn handler(ctx: Context<BidLaunchAuction>, amount: u64, max_price: u64) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let clock = Clock::get()?;
+    let keys_account = &mut ctx.accounts.keys_account;
+
+    require!(amount > 0, SolSocialError::InvalidAmount);
+    require!(ctx.accounts.config.allows_trade_size(amount), SolSocialError::TradeTooLarge);
+    require!(
+        keys_account.launch_auction.is_live(clock.unix_timestamp),
+        SolSocialError::LaunchAuctionNotLive
+    );
+
+    let held = ctx.accounts.key_holder.amount;
+    if !keys_account.curve_params.within_holder_cap(held, amount) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::HolderCapReached,
+                price: held,
+                limit: keys_account.curve_params.max_keys_per_holder,
+                amount,
+            },
+            SolSocialError::HolderCapReached,
+        ));
+    }
+
+    let price_per_key = keys_account.launch_auction.current_price(clock.unix_timestamp);
+    let price = price_per_key
+        .checked_mul(amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+    if price > max_price {
+        return Err(fail_with_context(
+            TradeErrorContext { kind: TradeErrorKind::BuySlippage, price, limit: max_price, amount },
+            SolSocialError::SlippageExceeded,
+        ));
+    }
+
+    let protocol_fee = calculate_fee(price, keys_account.curve_params.buy_protocol_fee)?;
+    let subject_fee = calculate_fee(price, keys_account.curve_params.buy_creator_fee)?;
+    let reward_pool_amount = ctx.accounts.config.reward_pool_cut(protocol_fee)?;
+    let treasury_fee = protocol_fee
+        .checked_sub(reward_pool_amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+    // Fees are charged on top of the price, all of which joins the reserve
+    let total_cost = price
+        .checked_add(protocol_fee)
+        .ok_or(SolSocialError::MathOverflow)?
+        .checked_add(subject_fee)
+        .ok_or(SolSocialError::MathOverflow)?;
+    require_rent_funds(&ctx.accounts.buyer.to_account_info(), &[], total_cost)?;
+
+    let buyer = ctx.accounts.buyer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    system_transfer(&buyer, &ctx.accounts.treasury.to_account_info(), &system_program, treasury_fee)?;
+    system_transfer(&buyer, &ctx.accounts.reward_pool.to_account_info(), &system_program, reward_pool_amount)?;
+    system_transfer(&buyer, &ctx.accounts.earnings_vault.to_account_info(), &system_program, subject_fee)?;
+    system_transfer(&buyer, &keys_account.to_account_info(), &system_program, price)?;
+    ctx.accounts.reward_pool.record_deposit(reward_pool_amount, clock.unix_timestamp)?;
+    ctx.accounts.earnings_vault.record_accrual(subject_fee)?;
+
+    keys_account.launch_auction.record_sale(amount)?;
+    keys_account.supply = keys_account.supply
+        .checked_add(amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+    // Auction prices come off the descending schedule, not the curve, so they stay out of its feeds and breaker
+    keys_account.record_trade(price, subject_fee, protocol_fee, clock.unix_timestamp)?;
+    record_milestones(keys_account, &ctx.accounts.config, clock.unix_timestamp)?;
+
+    // Mint keys to buyer
+    let cpi_accounts = token::MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.buyer_token_account.to_account_info(),
+        authority: keys_account.to_account_info(),
+    };
+    let seeds = &[
+        b"keys",
+        ctx.accounts.subject.key.as_ref(),
+        &[ctx.bumps.keys_account],
+    ];
+    let signer = &[&seeds[..]];
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let freeze = KeyFreeze::new(&token_program, &ctx.accounts.mint.to_account_info(), keys_account, signer);
+    let buyer_token_info = ctx.accounts.buyer_token_account.to_account_info();
+    freeze.thaw(&buyer_token_info)?;
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+    token::mint_to(cpi_ctx, amount)?;
+    freeze.freeze(&buyer_token_info)?;
+
+    // Record the keys on the buyer's holding record, creating it on their first buy
+    let key_holder = &mut ctx.accounts.key_holder;
+    if key_holder.holder == Pubkey::default() {
+        **key_holder = KeyHolder::new(ctx.accounts.buyer.key(), ctx.accounts.subject.key());
+    }
+    let held_before = key_holder.amount;
+    key_holder.update_after_buy(amount, price / amount, price);
+    keys_account.track_holder_balance(held_before, key_holder.amount)?;
+
+    emit!(LaunchAuctionBidEvent {
+        buyer: ctx.accounts.buyer.key(),
+        subject: ctx.accounts.subject.key(),
+        amount,
+        price_per_key,
+        price,
+        protocol_fee,
+        subject_fee,
+        keys_remaining: keys_account.launch_auction.remaining(),
+        supply_after: keys_account.supply,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct LaunchAuctionBidEvent {
+    pub buyer: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub price_per_key: u64,
+    pub price: u64,
+    pub protocol_fee: u64,
+    pub subject_fee: u64,
+    /// Zero once the auction has sold out and the curve is open
+    pub keys_remaining: u64,
+    pub supply_after: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
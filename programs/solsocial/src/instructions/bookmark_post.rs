@@ -0,0 +1,68 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{Post, Bookmark, Collection, BOOKMARK_SEED, COLLECTION_SEED};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct BookmarkPost<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub post: Account<'info, Post>,
+
+    #[account(
+        init,
+        payer = user,
+        space = Bookmark::LEN,
+        seeds = [BOOKMARK_SEED, user.key().as_ref(), post.key().as_ref()],
+        bump,
+    )]
+    pub bookmark: Account<'info, Bookmark>,
+
+    /// Collection to file the bookmark under
+    #[account(
+        mut,
+        constraint = collection.owner == user.key() @ SolSocialError::Unauthorized,
+        seeds = [COLLECTION_SEED, user.key().as_ref(), collection.name.as_bytes()],
+        bump = collection.bump,
+    )]
+    pub collection: Option<Account<'info, Collection>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Save a post, optionally into one of the user's collections
+pub fn handler(ctx: Context<BookmarkPost>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    if let Some(collection) = ctx.accounts.collection.as_mut() {
+        collection.add_bookmark()?;
+    }
+
+    let bookmark = &mut ctx.accounts.bookmark;
+    bookmark.user = ctx.accounts.user.key();
+    bookmark.post = ctx.accounts.post.key();
+    bookmark.collection = ctx.accounts.collection.as_ref().map(|collection| collection.key());
+    bookmark.created_at = now;
+    bookmark.bump = ctx.bumps.bookmark;
+
+    emit!(PostBookmarkedEvent {
+        bookmark: bookmark.key(),
+        user: bookmark.user,
+        post: bookmark.post,
+        collection: bookmark.collection,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PostBookmarkedEvent {
+    pub bookmark: Pubkey,
+    pub user: Pubkey,
+    pub post: Pubkey,
+    pub collection: Option<Pubkey>,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
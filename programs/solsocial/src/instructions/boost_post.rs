@@ -0,0 +1,106 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{Post, Boost, RewardPool, ProtocolConfig, Feature, BOOST_SEED};
+use crate::utils::revenue_share::system_transfer;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct BoostPost<'info> {
+    #[account(mut)]
+    pub booster: Signer<'info>,
+
+    pub post: Account<'info, Post>,
+
+    #[account(
+        init,
+        payer = booster,
+        space = Boost::LEN,
+        seeds = [BOOST_SEED, post.key().as_ref(), booster.key().as_ref()],
+        bump,
+    )]
+    pub boost: Account<'info, Boost>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// Holds the curators' share of boost payments
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay `lamports` to promote a post for `duration` seconds. The payment is split between the
+/// protocol treasury and the curator reward pool; the boost account is closed by `end_boost`.
+pub fn handler(ctx: Context<BoostPost>, lamports: u64, duration: i64) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Posting)?;
+    require!(lamports > 0, SolSocialError::InvalidAmount);
+    require!(
+        (Boost::MIN_DURATION_SECONDS..=Boost::MAX_DURATION_SECONDS).contains(&duration),
+        SolSocialError::InvalidBoostDuration
+    );
+
+    let (treasury_amount, curator_amount) = Boost::split(lamports).ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    let booster_info = ctx.accounts.booster.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    system_transfer(&booster_info, &ctx.accounts.treasury.to_account_info(), &system_program, treasury_amount)?;
+    system_transfer(&booster_info, &ctx.accounts.reward_pool.to_account_info(), &system_program, curator_amount)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.reward_pool.record_deposit(curator_amount, now)?;
+
+    let ends_at = now.checked_add(duration).ok_or(SolSocialError::ArithmeticOverflow)?;
+    let boost = &mut ctx.accounts.boost;
+    boost.post = ctx.accounts.post.key();
+    boost.author = ctx.accounts.post.author;
+    boost.booster = ctx.accounts.booster.key();
+    boost.amount = lamports;
+    boost.treasury_amount = treasury_amount;
+    boost.curator_amount = curator_amount;
+    boost.started_at = now;
+    boost.ends_at = ends_at;
+    boost.bump = ctx.bumps.boost;
+
+    emit!(BoostStartedEvent {
+        boost: boost.key(),
+        post: boost.post,
+        author: boost.author,
+        booster: boost.booster,
+        amount: lamports,
+        treasury_amount,
+        curator_amount,
+        started_at: now,
+        ends_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BoostStartedEvent {
+    pub boost: Pubkey,
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub booster: Pubkey,
+    pub amount: u64,
+    pub treasury_amount: u64,
+    pub curator_amount: u64,
+    pub started_at: i64,
+    pub ends_at: i64,
+}
+```
\ No newline at end of file
@@ -1,9 +1,16 @@
 ```rust
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{User, UserKeys};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{User, UserKeys, KeyHolder, KeyTransaction, TransactionType, KEY_HOLDER_SEED, ProtocolConfig, FeeExemption, RewardPool, RecentTrades, PriceOracle, Activity, ActivityTracker, Referral, REFERRAL_SEED, EarningsVault, EARNINGS_VAULT_SEED, Feature, PAYMENT_RESERVE_SEED, Portfolio, PORTFOLIO_SEED, EarlySupporterPool, EARLY_SUPPORTER_POOL_SEED};
+use crate::instructions::finalize_activity_day::touch_activity;
 use crate::utils::bonding_curve::calculate_buy_price;
-use crate::utils::revenue_share::distribute_revenue;
+use crate::utils::revenue_share::{calculate_buy_distribution, distribute_buy_revenue, RevenueDistribution};
+use crate::utils::error_context::{fail_with_context, require_before_deadline, require_price_impact_within, TradeErrorContext, TradeErrorKind};
+use crate::utils::accounts::require_rent_funds;
+use crate::utils::payment::TokenSettlement;
+use crate::utils::key_token::KeyFreeze;
+use crate::utils::trade::{record_market_trade, CurveTrade, MarketFeeds};
+use crate::utils::revenue_share::system_transfer;
 use crate::error::SolSocialError;
 
 #[derive(Accounts)]
@@ -36,6 +43,26 @@ pub struct BuyKeys<'info> {
     )]
     pub keys_account: Account<'info, UserKeys>,
     
+    /// Collects the subject's creator fee
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, buyer.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+    
+    #[account(mut, address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+    
     #[account(
         mut,
         associated_token::mint = keys_account.mint,
@@ -57,73 +84,323 @@ pub struct BuyKeys<'info> {
     )]
     pub treasury: SystemAccount<'info>,
     
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+    
+    /// Present when the buyer is exempt from protocol fees
+    #[account(
+        seeds = [b"fee_exemption", buyer.key().as_ref()],
+        bump = fee_exemption.bump,
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = RecentTrades::LEN,
+        seeds = [b"recent_trades", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub recent_trades: Account<'info, RecentTrades>,
+    
+    /// Price feed for other programs; every buy and sell records into it
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = PriceOracle::LEN,
+        seeds = [b"price_oracle", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub price_oracle: Account<'info, PriceOracle>,
+    
+    /// The buyer's totals across every market
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = Portfolio::LEN,
+        seeds = [PORTFOLIO_SEED, buyer.key().as_ref()],
+        bump,
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+    
+    /// The market's first holders, who share a slice of its creator fees
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = EarlySupporterPool::LEN,
+        seeds = [EARLY_SUPPORTER_POOL_SEED, subject.key().as_ref()],
+        bump,
+    )]
+    pub early_supporter_pool: Account<'info, EarlySupporterPool>,
+    
+    /// Opt-in activity heartbeat bucket
+    #[account(
+        mut,
+        seeds = [b"activity_metrics"],
+        bump = activity.bump,
+    )]
+    pub activity: Option<Account<'info, ActivityTracker>>,
+    
+    /// CHECK: Opt-in history record at the market's next trade index; created in the handler
+    #[account(mut)]
+    pub key_transaction: Option<UncheckedAccount<'info>>,
+    
+    /// The buyer's referral record, present when they signed up through a referrer
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, buyer.key().as_ref()],
+        bump = referral.bump,
+    )]
+    pub referral: Option<Account<'info, Referral>>,
+    
+    /// CHECK: Receives the referrer fee; checked against `referral.referrer`
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+    
+    /// Pays the price when the market settles in `keys_account.payment_mint`
+    #[account(
+        mut,
+        token::mint = keys_account.payment_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    /// The market's reserve in its payment token
+    #[account(
+        mut,
+        seeds = [PAYMENT_RESERVE_SEED, keys_account.key().as_ref()],
+        bump,
+    )]
+    pub payment_reserve: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        associated_token::mint = keys_account.payment_mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        associated_token::mint = keys_account.payment_mint,
+        associated_token::authority = subject,
+    )]
+    pub creator_payment_account: Option<Account<'info, TokenAccount>>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn buy_keys(ctx: Context<BuyKeys>, amount: u64) -> Result<()> {
+pub fn buy_keys(
+    ctx: Context<BuyKeys>,
+    amount: u64,
+    max_price: u64,
+    deadline: Option<i64>,
+    max_price_impact_bps: Option<u16>,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
     let keys_account = &mut ctx.accounts.keys_account;
     let buyer_account = &mut ctx.accounts.buyer_account;
     let subject_account = &mut ctx.accounts.subject_account;
     
     require!(amount > 0, SolSocialError::InvalidAmount);
-    require!(keys_account.is_active, SolSocialError::KeysNotActive);
+    require!(ctx.accounts.config.allows_trade_size(amount), SolSocialError::TradeTooLarge);
+
+    let now = Clock::get()?.unix_timestamp;
+    require_before_deadline(deadline, now, amount)?;
+    if !keys_account.is_trading_open(now) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::OutsideTradingHours,
+                price: keys_account.trading_open_at as u64,
+                limit: keys_account.trading_close_at as u64,
+                amount,
+            },
+            SolSocialError::OperationNotAllowed,
+        ));
+    }
     
+    // Count every key the wallet owns in this market, locked founder keys included
+    let held = ctx.accounts.key_holder.amount;
+    if !keys_account.curve_params.within_holder_cap(held, amount) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::HolderCapReached,
+                price: held,
+                limit: keys_account.curve_params.max_keys_per_holder,
+                amount,
+            },
+            SolSocialError::HolderCapReached,
+        ));
+    }
+    let slot = Clock::get()?.slot;
+    keys_account.launch_guard.check_buy(slot, held.saturating_add(amount), ctx.accounts.key_holder.last_buy_slot)?;
+
     // Calculate the price for buying the specified amount of keys
     let current_supply = keys_account.supply;
-    let price = calculate_buy_price(current_supply, amount)?;
+    let price = calculate_buy_price(&keys_account.curve_params, current_supply, amount)?;
     
     require!(price > 0, SolSocialError::InvalidPrice);
     
-    // Check if buyer has enough SOL
-    let buyer_balance = ctx.accounts.buyer.lamports();
-    require!(buyer_balance >= price, SolSocialError::InsufficientFunds);
+    // Failures below carry a TradeErrorContext in the return data
+    if price > max_price {
+        return Err(fail_with_context(
+            TradeErrorContext { kind: TradeErrorKind::BuySlippage, price, limit: max_price, amount },
+            SolSocialError::SlippageExceeded,
+        ));
+    }
     
-    // Calculate fees and revenue distribution
-    let protocol_fee = price.checked_mul(keys_account.protocol_fee_percent as u64)
-        .ok_or(SolSocialError::MathOverflow)?
-        .checked_div(10000)
-        .ok_or(SolSocialError::MathOverflow)?;
+    // The buyer's own impact cap, or the protocol default when they don't set one
+    require_price_impact_within(
+        &keys_account.curve_params,
+        current_supply,
+        amount,
+        true,
+        max_price_impact_bps.unwrap_or(ctx.accounts.config.max_price_impact_bps),
+    )?;
+    
+    // Markets with a payment token settle through token accounts instead of lamports
+    let token_settlement = if keys_account.settles_in_token() {
+        Some(TokenSettlement::from_accounts(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.buyer_payment_account.as_ref(),
+            ctx.accounts.payment_reserve.as_ref(),
+            ctx.accounts.treasury_payment_account.as_ref(),
+            ctx.accounts.creator_payment_account.as_ref(),
+        )?)
+    } else {
+        None
+    };
     
-    let subject_fee = price.checked_mul(keys_account.subject_fee_percent as u64)
-        .ok_or(SolSocialError::MathOverflow)?
-        .checked_div(10000)
-        .ok_or(SolSocialError::MathOverflow)?;
+    // Calculate fees and revenue distribution using the market's buy-side rates;
+    // exempt traders skip the protocol fee but still pay the creator, and reputation
+    // discounts the protocol fee only
+    let protocol_fee_exempt = ctx.accounts.fee_exemption.is_some();
+    let reputation_discount_pct = buyer_account.get_trading_fee_discount();
+    let protocol_fee_bps = if protocol_fee_exempt {
+        0
+    } else {
+        User::apply_fee_discount(keys_account.curve_params.buy_protocol_fee, reputation_discount_pct)
+    };
+    let subject_fee_bps = keys_account.curve_params.buy_creator_fee;
+    
+    // Referred buyers also pay the referrer fee, which must reach the bound referrer;
+    // referrers are paid in lamports, so token markets skip it
+    let referrer = match (&ctx.accounts.referral, &ctx.accounts.referrer) {
+        _ if token_settlement.is_some() => None,
+        (Some(referral), Some(referrer)) => {
+            require_keys_eq!(referrer.key(), referral.referrer, SolSocialError::InvalidAccountOwner);
+            Some(referrer.to_account_info())
+        }
+        (Some(_), None) => return Err(SolSocialError::MissingRequiredAccount.into()),
+        (None, _) => None,
+    };
+    // Fees are charged on top of the curve price, all of which backs the reserve
+    let distribution = calculate_buy_distribution(price, protocol_fee_bps, subject_fee_bps, referrer.is_some())?;
+    let protocol_fee = distribution.protocol_fee;
+    let subject_fee = distribution.creator_fee;
+    let referrer_fee = distribution.referrer_fee;
+    let total_cost = distribution.total_cost()?;
+    
+    // Check if buyer has enough to pay
+    let buyer_balance = match &token_settlement {
+        Some(settlement) => settlement.trader_balance,
+        None => ctx.accounts.buyer.lamports(),
+    };
+    if buyer_balance < total_cost {
+        return Err(fail_with_context(
+            TradeErrorContext { kind: TradeErrorKind::InsufficientFunds, price: total_cost, limit: buyer_balance, amount },
+            SolSocialError::InsufficientFunds,
+        ));
+    }
     
-    let net_price = price.checked_sub(protocol_fee)
-        .ok_or(SolSocialError::MathOverflow)?
-        .checked_sub(subject_fee)
+    // The buyer pays the price and fees below and must stay rent exempt afterwards
+    let history_space: &[usize] = if ctx.accounts.key_transaction.is_some() { &[KeyTransaction::LEN] } else { &[] };
+    require_rent_funds(
+        &ctx.accounts.buyer.to_account_info(),
+        history_space,
+        if token_settlement.is_some() { 0 } else { total_cost },
+    )?;
+    
+    // Divert the configured slice of the protocol fee into the reward pool, which only
+    // takes lamports
+    let reward_pool_amount = if token_settlement.is_some() {
+        0
+    } else {
+        ctx.accounts.config.reward_pool_cut(protocol_fee)?
+    };
+    let treasury_fee = protocol_fee.checked_sub(reward_pool_amount)
         .ok_or(SolSocialError::MathOverflow)?;
     
-    // Transfer SOL from buyer to treasury (protocol fee)
-    if protocol_fee > 0 {
-        let ix = anchor_lang::solana_program::system_instruction::transfer(
-            &ctx.accounts.buyer.key(),
-            &ctx.accounts.treasury.key(),
-            protocol_fee,
-        );
-        anchor_lang::solana_program::program::invoke(
-            &ix,
-            &[
-                ctx.accounts.buyer.to_account_info(),
-                ctx.accounts.treasury.to_account_info(),
-            ],
-        )?;
+    // Likewise the early supporters' slice of the creator fee, split among those already seated
+    let early_supporter_pool = &mut ctx.accounts.early_supporter_pool;
+    if early_supporter_pool.keys_user == Pubkey::default() {
+        early_supporter_pool.initialize(ctx.accounts.subject.key(), ctx.bumps.early_supporter_pool);
     }
+    let early_supporter_amount = if token_settlement.is_some() {
+        0
+    } else {
+        early_supporter_pool.record_fee(ctx.accounts.config.early_supporter_cut(subject_fee)?)?
+    };
+    let vault_fee = subject_fee.checked_sub(early_supporter_amount)
+        .ok_or(SolSocialError::MathOverflow)?;
     
-    // Transfer SOL from buyer to subject (subject fee)
-    if subject_fee > 0 {
-        let ix = anchor_lang::solana_program::system_instruction::transfer(
-            &ctx.accounts.buyer.key(),
-            &ctx.accounts.subject.key(),
-            subject_fee,
-        );
-        anchor_lang::solana_program::program::invoke(
-            &ix,
-            &[
-                ctx.accounts.buyer.to_account_info(),
-                ctx.accounts.subject.to_account_info(),
-            ],
+    if let Some(settlement) = &token_settlement {
+        // Fees go straight to the treasury's and creator's token accounts, the price to the reserve
+        settlement.pay_buy(&ctx.accounts.buyer.to_account_info(), treasury_fee, subject_fee, price)?;
+    } else {
+        // Transfer SOL from buyer to reward pool
+        if reward_pool_amount > 0 {
+            let ix = anchor_lang::solana_program::system_instruction::transfer(
+                &ctx.accounts.buyer.key(),
+                &ctx.accounts.reward_pool.key(),
+                reward_pool_amount,
+            );
+            anchor_lang::solana_program::program::invoke(
+                &ix,
+                &[
+                    ctx.accounts.buyer.to_account_info(),
+                    ctx.accounts.reward_pool.to_account_info(),
+                ],
+            )?;
+        }
+        
+        // Treasury, creator and referrer fees; the creator's share waits in their earnings vault
+        distribute_buy_revenue(
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.earnings_vault.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            referrer.as_ref(),
+            &ctx.accounts.system_program.to_account_info(),
+            &RevenueDistribution { protocol_fee: treasury_fee, creator_fee: vault_fee, ..distribution },
+        )?;
+        ctx.accounts.earnings_vault.record_accrual(vault_fee)?;
+        system_transfer(
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.early_supporter_pool.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            early_supporter_amount,
+        )?;
+        if let Some(referral) = ctx.accounts.referral.as_mut() {
+            referral.record_fee(referrer_fee);
+        }
+        
+        // The whole curve price joins the market's reserve, which pays out sells
+        system_transfer(
+            &ctx.accounts.buyer.to_account_info(),
+            &keys_account.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            price,
         )?;
     }
     
@@ -131,18 +408,36 @@ pub fn buy_keys(ctx: Context<BuyKeys>, amount: u64) -> Result<()> {
     keys_account.supply = keys_account.supply.checked_add(amount)
         .ok_or(SolSocialError::MathOverflow)?;
     
-    // Update total volume
-    keys_account.total_volume = keys_account.total_volume.checked_add(price)
-        .ok_or(SolSocialError::MathOverflow)?;
+    // Update the buyer's holding record, creating it on their first buy of this subject
+    let key_holder = &mut ctx.accounts.key_holder;
+    if key_holder.holder == Pubkey::default() {
+        **key_holder = KeyHolder::new(ctx.accounts.buyer.key(), ctx.accounts.subject.key());
+    }
+    let held_before = key_holder.amount;
+    key_holder.update_after_buy(amount, price / amount, price);
+    keys_account.track_holder_balance(held_before, key_holder.amount)?;
     
-    // Update buyer's key balance
-    let buyer_key_balance = ctx.accounts.buyer_token_account.amount;
-    let new_buyer_balance = buyer_key_balance.checked_add(amount)
-        .ok_or(SolSocialError::MathOverflow)?;
+    // The market's first holders, its creator aside, take the open supporter seats
+    let buyer_key = ctx.accounts.buyer.key();
+    if held_before == 0 && buyer_key != ctx.accounts.subject.key() && ctx.accounts.early_supporter_pool.register(buyer_key) {
+        buyer_account.is_early_supporter = true;
+        emit!(EarlySupporterRegisteredEvent {
+            supporter: buyer_key,
+            subject: ctx.accounts.subject.key(),
+            seat: ctx.accounts.early_supporter_pool.supporter_count,
+            timestamp: now,
+        });
+    }
+    
+    let portfolio = &mut ctx.accounts.portfolio;
+    if portfolio.owner == Pubkey::default() {
+        portfolio.initialize(ctx.accounts.buyer.key(), ctx.bumps.portfolio);
+    }
+    portfolio.record_buy(price, held_before, Clock::get()?.unix_timestamp)?;
     
     // Mint keys to buyer
     let cpi_accounts = token::MintTo {
-        mint: keys_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
         to: ctx.accounts.buyer_token_account.to_account_info(),
         authority: keys_account.to_account_info(),
     };
@@ -155,9 +450,13 @@ pub fn buy_keys(ctx: Context<BuyKeys>, amount: u64) -> Result<()> {
     let signer = &[&seeds[..]];
     
     let cpi_program = ctx.accounts.token_program.to_account_info();
+    let freeze = KeyFreeze::new(&cpi_program, &ctx.accounts.mint.to_account_info(), keys_account, signer);
+    let buyer_token_info = ctx.accounts.buyer_token_account.to_account_info();
+    freeze.thaw(&buyer_token_info)?;
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     
     token::mint_to(cpi_ctx, amount)?;
+    freeze.freeze(&buyer_token_info)?;
     
     // Update buyer's total keys purchased
     buyer_account.total_keys_purchased = buyer_account.total_keys_purchased
@@ -166,20 +465,55 @@ pub fn buy_keys(ctx: Context<BuyKeys>, amount: u64) -> Result<()> {
     
     // Update buyer's total spent
     buyer_account.total_spent = buyer_account.total_spent
-        .checked_add(price)
+        .checked_add(total_cost)
         .ok_or(SolSocialError::MathOverflow)?;
     
     // Update subject's total earnings
     subject_account.total_earnings = subject_account.total_earnings
-        .checked_add(subject_fee)
+        .checked_add(vault_fee)
         .ok_or(SolSocialError::MathOverflow)?;
     
     // Update last activity timestamp
     let clock = Clock::get()?;
-    keys_account.last_activity = clock.unix_timestamp;
+    touch_activity(ctx.accounts.activity.as_mut(), buyer_account.last_activity, Activity::Trade { volume: price })?;
     buyer_account.last_activity = clock.unix_timestamp;
     subject_account.last_activity = clock.unix_timestamp;
     
+    ctx.accounts.reward_pool.record_deposit(reward_pool_amount, clock.unix_timestamp)?;
+    
+    let trade_index = record_market_trade(
+        keys_account,
+        Some(MarketFeeds {
+            recent_trades: &mut ctx.accounts.recent_trades,
+            recent_trades_bump: ctx.bumps.recent_trades,
+            price_oracle: &mut ctx.accounts.price_oracle,
+            price_oracle_bump: ctx.bumps.price_oracle,
+        }),
+        Some(key_holder),
+        &ctx.accounts.config,
+        &CurveTrade { amount, value: price, creator_fee: subject_fee, protocol_fee, is_buy: true },
+        clock.unix_timestamp,
+    )?;
+    if let Some(key_transaction) = &ctx.accounts.key_transaction {
+        KeyTransaction::new(
+            TransactionType::Buy,
+            ctx.accounts.subject.key(),
+            ctx.accounts.buyer.key(),
+            amount,
+            price / amount,
+            price,
+            subject_fee,
+            protocol_fee,
+            trade_index,
+        )
+        .record(
+            &key_transaction.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+        )?;
+    }
+    
     // Emit buy event
     emit!(KeysBoughtEvent {
         buyer: ctx.accounts.buyer.key(),
@@ -188,15 +522,20 @@ pub fn buy_keys(ctx: Context<BuyKeys>, amount: u64) -> Result<()> {
         price,
         protocol_fee,
         subject_fee,
+        referrer_fee,
+        protocol_fee_bps,
+        subject_fee_bps,
+        reward_pool_amount,
+        early_supporter_amount,
+        protocol_fee_exempt,
+        reputation_discount_pct,
         supply_after: keys_account.supply,
+        trade_index,
         timestamp: clock.unix_timestamp,
     });
     
     // Check if this is the first key purchase (excluding subject's initial key)
     if keys_account.supply == amount + 1 {
-        // First buyer gets special status
-        buyer_account.is_early_supporter = true;
-        
         emit!(FirstKeyBoughtEvent {
             buyer: ctx.accounts.buyer.key(),
             subject: ctx.accounts.subject.key(),
@@ -206,41 +545,6 @@ pub fn buy_keys(ctx: Context<BuyKeys>, amount: u64) -> Result<()> {
         });
     }
     
-    // Update holder count if this is buyer's first keys of this subject
-    if buyer_key_balance == 0 {
-        keys_account.holder_count = keys_account.holder_count.checked_add(1)
-            .ok_or(SolSocialError::MathOverflow)?;
-    }
-    
-    // Check for milestone achievements
-    if keys_account.supply >= 100 && !keys_account.milestone_100_reached {
-        keys_account.milestone_100_reached = true;
-        subject_account.total_earnings = subject_account.total_earnings
-            .checked_add(1_000_000) // 0.001 SOL bonus
-            .ok_or(SolSocialError::MathOverflow)?;
-        
-        emit!(MilestoneReachedEvent {
-            subject: ctx.accounts.subject.key(),
-            milestone: 100,
-            bonus_amount: 1_000_000,
-            timestamp: clock.unix_timestamp,
-        });
-    }
-    
-    if keys_account.supply >= 1000 && !keys_account.milestone_1000_reached {
-        keys_account.milestone_1000_reached = true;
-        subject_account.total_earnings = subject_account.total_earnings
-            .checked_add(10_000_000) // 0.01 SOL bonus
-            .ok_or(SolSocialError::MathOverflow)?;
-        
-        emit!(MilestoneReachedEvent {
-            subject: ctx.accounts.subject.key(),
-            milestone: 1000,
-            bonus_amount: 10_000_000,
-            timestamp: clock.unix_timestamp,
-        });
-    }
-    
     Ok(())
 }
 
@@ -252,7 +556,17 @@ pub struct KeysBoughtEvent {
     pub price: u64,
     pub protocol_fee: u64,
     pub subject_fee: u64,
+    pub referrer_fee: u64,
+    pub protocol_fee_bps: u16,
+    pub subject_fee_bps: u16,
+    pub reward_pool_amount: u64,
+    /// Part of `subject_fee` paid to the market's early supporters
+    pub early_supporter_amount: u64,
+    pub protocol_fee_exempt: bool,
+    /// Percent taken off the protocol fee for the buyer's reputation
+    pub reputation_discount_pct: u64,
     pub supply_after: u64,
+    pub trade_index: u64,
     pub timestamp: i64,
 }
 
@@ -265,6 +579,15 @@ pub struct FirstKeyBoughtEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct EarlySupporterRegisteredEvent {
+    pub supporter: Pubkey,
+    pub subject: Pubkey,
+    /// 1-based seat number in the market's early-supporter pool
+    pub seat: u8,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct MilestoneReachedEvent {
     pub subject: Pubkey,
@@ -0,0 +1,252 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, MintTo, Token, TokenAccount};
+use crate::state::{BatchBuyLeg, KeyHolder, ProtocolConfig, RewardPool, UserKeys, EarningsVault, KEY_HOLDER_SEED, EARNINGS_VAULT_SEED, Feature};
+use crate::utils::accounts::{create_pda_account, require_rent_funds, write_account};
+use crate::utils::bonding_curve::calculate_buy_price;
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::key_token::KeyFreeze;
+use crate::utils::revenue_share::{calculate_fee, system_transfer};
+use crate::utils::trade::{record_market_trade, CurveTrade};
+use crate::error::SolSocialError;
+
+/// Most markets a single batch may buy into, to stay within the compute budget
+pub const MAX_BATCH_LEGS: usize = 5;
+
+/// Accounts each leg passes through `remaining_accounts`, in order: the subject's earnings
+/// vault, keys account, mint, the buyer's `KeyHolder` (created when missing) and the buyer's
+/// token account
+pub const ACCOUNTS_PER_LEG: usize = 5;
+
+#[derive(Accounts)]
+pub struct BuyKeysBatch<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Buy keys in several markets at once. Every leg is priced on its own curve and
+/// enforces its own `max_price`, so one leg slipping fails the whole batch.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyKeysBatch<'info>>,
+    legs: Vec<BatchBuyLeg>,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    require!(!legs.is_empty(), SolSocialError::InvalidAmount);
+    require!(legs.len() <= MAX_BATCH_LEGS, SolSocialError::TooManyAccounts);
+    require!(
+        ctx.remaining_accounts.len() == legs.len() * ACCOUNTS_PER_LEG,
+        SolSocialError::MissingRequiredAccount
+    );
+
+    let buyer = ctx.accounts.buyer.to_account_info();
+    let buyer_key = buyer.key();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let slot = clock.slot;
+
+    // Holding records this batch has to create come out of the buyer's balance up front
+    let new_holders = ctx.remaining_accounts
+        .chunks(ACCOUNTS_PER_LEG)
+        .filter(|accounts| accounts[3].data_is_empty())
+        .count();
+    require_rent_funds(&buyer, &vec![KeyHolder::LEN; new_holders], 0)?;
+
+    let mut total_keys = 0u64;
+    let mut total_cost = 0u64;
+    let mut total_creator_fee = 0u64;
+    let mut treasury_total = 0u64;
+    let mut reward_pool_total = 0u64;
+
+    for (i, (leg, accounts)) in legs.iter().zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_LEG)).enumerate() {
+        let (vault_info, keys_info, mint_info, holder_info, token_info) =
+            (&accounts[0], &accounts[1], &accounts[2], &accounts[3], &accounts[4]);
+
+        require!(leg.amount > 0, SolSocialError::InvalidAmount);
+        require!(ctx.accounts.config.allows_trade_size(leg.amount), SolSocialError::TradeTooLarge);
+        // Reject a subject listed twice so one leg can't be priced off a stale supply
+        require!(
+            !legs[..i].iter().any(|earlier| earlier.subject == leg.subject),
+            SolSocialError::InvalidAccountSequence
+        );
+        let (expected_vault, _) =
+            Pubkey::find_program_address(&[EARNINGS_VAULT_SEED, leg.subject.as_ref()], ctx.program_id);
+        require_keys_eq!(vault_info.key(), expected_vault, SolSocialError::InvalidAccountSequence);
+
+        let (expected_keys, keys_bump) =
+            Pubkey::find_program_address(&[b"keys", leg.subject.as_ref()], ctx.program_id);
+        require_keys_eq!(keys_info.key(), expected_keys, SolSocialError::InvalidAccountSequence);
+        let mut keys_account = Account::<UserKeys>::try_from(keys_info)?;
+        require_keys_eq!(mint_info.key(), keys_account.mint, SolSocialError::InvalidAccountSequence);
+
+        let token_account = Account::<TokenAccount>::try_from(token_info)?;
+        require!(
+            token_account.mint == keys_account.mint && token_account.owner == buyer_key,
+            SolSocialError::InvalidTokenAccount
+        );
+
+        keys_account.require_lamport_settlement()?;
+        if !keys_account.is_trading_open(now) {
+            return Err(fail_with_context(
+                TradeErrorContext {
+                    kind: TradeErrorKind::OutsideTradingHours,
+                    price: keys_account.trading_open_at as u64,
+                    limit: keys_account.trading_close_at as u64,
+                    amount: leg.amount,
+                },
+                SolSocialError::OperationNotAllowed,
+            ));
+        }
+        // Guarded launches only accept buy_keys, which enforces the per-wallet limits
+        require!(!keys_account.launch_guard.is_active(slot), SolSocialError::LaunchGuardActive);
+
+        let params = keys_account.curve_params;
+        let price = calculate_buy_price(&params, keys_account.supply, leg.amount)?;
+        if price > leg.max_price {
+            return Err(fail_with_context(
+                TradeErrorContext { kind: TradeErrorKind::BuySlippage, price, limit: leg.max_price, amount: leg.amount },
+                SolSocialError::SlippageExceeded,
+            ));
+        }
+
+        let (expected_holder, holder_bump) = Pubkey::find_program_address(
+            &[KEY_HOLDER_SEED, buyer_key.as_ref(), leg.subject.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(holder_info.key(), expected_holder, SolSocialError::InvalidAccountSequence);
+        let mut key_holder = if holder_info.data_is_empty() {
+            create_pda_account(
+                &buyer,
+                holder_info,
+                &system_program,
+                KeyHolder::LEN,
+                &[KEY_HOLDER_SEED, buyer_key.as_ref(), leg.subject.as_ref(), &[holder_bump]],
+            )?;
+            KeyHolder::new(buyer_key, leg.subject)
+        } else {
+            Account::<KeyHolder>::try_from(holder_info)?.into_inner()
+        };
+        if !params.within_holder_cap(key_holder.amount, leg.amount) {
+            return Err(fail_with_context(
+                TradeErrorContext {
+                    kind: TradeErrorKind::HolderCapReached,
+                    price: key_holder.amount,
+                    limit: params.max_keys_per_holder,
+                    amount: leg.amount,
+                },
+                SolSocialError::HolderCapReached,
+            ));
+        }
+
+        // Fees are charged on top of the price, all of which backs the market
+        let protocol_fee = calculate_fee(price, params.buy_protocol_fee)?;
+        let creator_fee = calculate_fee(price, params.buy_creator_fee)?;
+        let reward_pool_amount = ctx.accounts.config.reward_pool_cut(protocol_fee)?;
+
+        system_transfer(&buyer, vault_info, &system_program, creator_fee)?;
+        system_transfer(&buyer, keys_info, &system_program, price)?;
+        let mut earnings_vault = Account::<EarningsVault>::try_from(vault_info)?;
+        earnings_vault.record_accrual(creator_fee)?;
+        earnings_vault.exit(ctx.program_id)?;
+
+        let keys_seeds = &[b"keys".as_ref(), leg.subject.as_ref(), &[keys_bump]];
+        let signer = &[&keys_seeds[..]];
+        let cpi_accounts = MintTo {
+            mint: mint_info.clone(),
+            to: token_info.clone(),
+            authority: keys_info.clone(),
+        };
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let freeze = KeyFreeze::new(&token_program, mint_info, &keys_account, signer);
+        freeze.thaw(token_info)?;
+        let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+        token::mint_to(cpi_ctx, leg.amount)?;
+        freeze.freeze(token_info)?;
+
+        let held_before = key_holder.amount;
+        key_holder.update_after_buy(leg.amount, price / leg.amount, price);
+
+        keys_account.supply = keys_account.supply
+            .checked_add(leg.amount)
+            .ok_or(SolSocialError::MathOverflow)?;
+        keys_account.track_holder_balance(held_before, key_holder.amount)?;
+        // Legs carry no feed accounts; the market's next single trade brings its feeds up to date
+        record_market_trade(
+            &mut keys_account,
+            None,
+            Some(&mut key_holder),
+            &ctx.accounts.config,
+            &CurveTrade { amount: leg.amount, value: price, creator_fee, protocol_fee, is_buy: true },
+            now,
+        )?;
+        write_account(holder_info, &key_holder)?;
+        keys_account.exit(ctx.program_id)?;
+
+        total_keys = total_keys.checked_add(leg.amount).ok_or(SolSocialError::MathOverflow)?;
+        total_cost = total_cost.checked_add(price).ok_or(SolSocialError::MathOverflow)?;
+        total_creator_fee = total_creator_fee.checked_add(creator_fee).ok_or(SolSocialError::MathOverflow)?;
+        treasury_total = treasury_total
+            .checked_add(protocol_fee - reward_pool_amount)
+            .ok_or(SolSocialError::MathOverflow)?;
+        reward_pool_total = reward_pool_total
+            .checked_add(reward_pool_amount)
+            .ok_or(SolSocialError::MathOverflow)?;
+    }
+
+    // Protocol fees for every leg move in one transfer each
+    system_transfer(&buyer, &ctx.accounts.treasury.to_account_info(), &system_program, treasury_total)?;
+    system_transfer(&buyer, &ctx.accounts.reward_pool.to_account_info(), &system_program, reward_pool_total)?;
+    ctx.accounts.reward_pool.record_deposit(reward_pool_total, now)?;
+
+    emit!(KeysBatchBoughtEvent {
+        buyer: buyer_key,
+        subjects: legs.iter().map(|leg| leg.subject).collect(),
+        amounts: legs.iter().map(|leg| leg.amount).collect(),
+        total_keys,
+        total_cost,
+        protocol_fee: treasury_total + reward_pool_total,
+        creator_fee: total_creator_fee,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct KeysBatchBoughtEvent {
+    pub buyer: Pubkey,
+    /// Subject of each leg, in order
+    pub subjects: Vec<Pubkey>,
+    /// Keys bought in each leg, in order
+    pub amounts: Vec<u64>,
+    pub total_keys: u64,
+    /// Curve price of every leg combined; the fees were charged on top of it
+    pub total_cost: u64,
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,59 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::instructions::buy_keys::{self, BuyKeys};
+use crate::utils::bonding_curve::{calculate_buy_price, BondingCurve};
+use crate::error::SolSocialError;
+
+/// Maximum drift a client may accept between its quote and the executed price (10%)
+pub const MAX_QUOTE_TOLERANCE_BPS: u16 = 1_000;
+
+pub fn handler(
+    ctx: Context<BuyKeys>,
+    amount: u64,
+    total_price: u64,
+    tolerance_bps: u16,
+) -> Result<()> {
+    require!(total_price > 0, SolSocialError::InvalidPrice);
+    require!(tolerance_bps <= MAX_QUOTE_TOLERANCE_BPS, SolSocialError::ToleranceTooWide);
+
+    // Re-quote against the current supply inside this transaction
+    let charged_price = calculate_buy_price(
+        &ctx.accounts.keys_account.curve_params,
+        ctx.accounts.keys_account.supply,
+        amount,
+    )?;
+
+    require!(
+        BondingCurve::is_within_tolerance(charged_price, total_price, tolerance_bps),
+        SolSocialError::SlippageExceeded
+    );
+
+    let buyer = ctx.accounts.buyer.key();
+    let subject = ctx.accounts.subject.key();
+
+    buy_keys::buy_keys(ctx, amount, charged_price, None, None)?;
+
+    emit!(KeysBoughtExactEvent {
+        buyer,
+        subject,
+        amount,
+        quoted_price: total_price,
+        charged_price,
+        tolerance_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct KeysBoughtExactEvent {
+    pub buyer: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub quoted_price: u64,
+    pub charged_price: u64,
+    pub tolerance_bps: u16,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,85 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::instructions::buy_keys::{self, BuyKeys};
+use crate::utils::bonding_curve::{calculate_buy_price, max_keys_for_budget};
+use crate::utils::revenue_share::{calculate_fee, price_budget_before_fees, REFERRER_FEE_BPS};
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::error::SolSocialError;
+
+/// Spend up to `lamports_budget` on as many keys as it covers. The budget is the buyer's full
+/// outlay, so the curve price is sized to leave room for the fees charged on top at the
+/// market's full rates; whatever is left after the last whole key, or saved by a fee
+/// discount, is never charged and stays with the buyer.
+pub fn handler(ctx: Context<BuyKeys>, lamports_budget: u64, min_keys_out: u64) -> Result<()> {
+    require!(lamports_budget > 0, SolSocialError::InvalidPrice);
+    require!(min_keys_out > 0, SolSocialError::InvalidAmount);
+
+    let keys_account = &ctx.accounts.keys_account;
+    let params = keys_account.curve_params;
+    let mut fee_bps = params.buy_protocol_fee.saturating_add(params.buy_creator_fee);
+    if ctx.accounts.referral.is_some() {
+        fee_bps = fee_bps.saturating_add(REFERRER_FEE_BPS);
+    }
+    let price_budget = price_budget_before_fees(lamports_budget, fee_bps)?;
+    let (mut amount, mut cost) = max_keys_for_budget(&params, keys_account.supply, price_budget)?;
+
+    // Don't let a large budget trip the per-holder cap; buy up to it instead. The cap counts
+    // the holder record, as buy_keys does, not whatever the wallet happens to hold
+    let room = params.holder_room(ctx.accounts.key_holder.amount);
+    if room == 0 {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::HolderCapReached,
+                price: ctx.accounts.key_holder.amount,
+                limit: params.max_keys_per_holder,
+                amount,
+            },
+            SolSocialError::HolderCapReached,
+        ));
+    }
+    if amount > room {
+        amount = room;
+        cost = calculate_buy_price(&params, keys_account.supply, room)?;
+    }
+
+    if amount < min_keys_out {
+        return Err(fail_with_context(
+            TradeErrorContext { kind: TradeErrorKind::BuySlippage, price: cost, limit: lamports_budget, amount },
+            SolSocialError::SlippageExceeded,
+        ));
+    }
+
+    let fee_allowance = calculate_fee(cost, fee_bps)?;
+    let buyer = ctx.accounts.buyer.key();
+    let subject = ctx.accounts.subject.key();
+
+    buy_keys::buy_keys(ctx, amount, cost, None, None)?;
+
+    emit!(KeysBoughtExactInEvent {
+        buyer,
+        subject,
+        amount,
+        lamports_budget,
+        charged_price: cost,
+        fee_allowance,
+        dust_refunded: lamports_budget - cost - fee_allowance,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct KeysBoughtExactInEvent {
+    pub buyer: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub lamports_budget: u64,
+    pub charged_price: u64,
+    /// Fees on `charged_price` at the market's full rates; a fee discount leaves part of it unspent
+    pub fee_allowance: u64,
+    /// Part of the budget too small for another key, left with the buyer
+    pub dust_refunded: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,210 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::state::{
+    KeyHolder, ProtocolConfig, RewardPool, UserKeys, EarningsVault, PresalePurchase, EARNINGS_VAULT_SEED,
+    KEY_HOLDER_SEED, PRESALE_PURCHASE_SEED, Feature,
+};
+use crate::utils::revenue_share::{calculate_fee, system_transfer};
+use crate::utils::accounts::require_rent_funds;
+use crate::utils::key_token::KeyFreeze;
+use crate::utils::trade::record_milestones;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct BuyPresaleKeys<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: The creator whose presale is being bought into
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Tracks the buyer's presale keys against the per-wallet cap
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = PresalePurchase::LEN,
+        seeds = [PRESALE_PURCHASE_SEED, buyer.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub presale_purchase: Account<'info, PresalePurchase>,
+
+    /// Collects the subject's creator fee
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(mut, address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    /// The buyer's holding record, so presale keys can be sold and count toward the holder cap
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, buyer.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Buy `amount` presale keys at the creator's fixed price. `proof` shows the buyer is on
+/// the allowlist; fees and the reserve deposit work as for a curve buy.
+pub fn handler(ctx: Context<BuyPresaleKeys>, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let clock = Clock::get()?;
+    let keys_account = &mut ctx.accounts.keys_account;
+    let buyer_key = ctx.accounts.buyer.key();
+
+    require!(amount > 0, SolSocialError::InvalidAmount);
+    require!(keys_account.presale.is_live(clock.unix_timestamp), SolSocialError::PresaleNotLive);
+    require!(
+        keys_account.presale.is_allowlisted(&buyer_key, &proof),
+        SolSocialError::NotAllowlisted
+    );
+    require!(
+        keys_account.curve_params.within_holder_cap(ctx.accounts.key_holder.amount, amount),
+        SolSocialError::HolderCapReached
+    );
+
+    let presale_purchase = &mut ctx.accounts.presale_purchase;
+    if presale_purchase.buyer == Pubkey::default() {
+        presale_purchase.buyer = buyer_key;
+        presale_purchase.keys_user = ctx.accounts.subject.key();
+        presale_purchase.bump = ctx.bumps.presale_purchase;
+    }
+    presale_purchase.record(amount, keys_account.presale.max_per_wallet)?;
+
+    let price = keys_account.presale.price
+        .checked_mul(amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+
+    let protocol_fee = calculate_fee(price, keys_account.curve_params.buy_protocol_fee)?;
+    let subject_fee = calculate_fee(price, keys_account.curve_params.buy_creator_fee)?;
+    let reward_pool_amount = ctx.accounts.config.reward_pool_cut(protocol_fee)?;
+    let treasury_fee = protocol_fee
+        .checked_sub(reward_pool_amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+    // Fees are charged on top of the price, all of which joins the reserve
+    let total_cost = price
+        .checked_add(protocol_fee)
+        .ok_or(SolSocialError::MathOverflow)?
+        .checked_add(subject_fee)
+        .ok_or(SolSocialError::MathOverflow)?;
+    require_rent_funds(&ctx.accounts.buyer.to_account_info(), &[], total_cost)?;
+
+    let buyer = ctx.accounts.buyer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    system_transfer(&buyer, &ctx.accounts.treasury.to_account_info(), &system_program, treasury_fee)?;
+    system_transfer(&buyer, &ctx.accounts.reward_pool.to_account_info(), &system_program, reward_pool_amount)?;
+    system_transfer(&buyer, &ctx.accounts.earnings_vault.to_account_info(), &system_program, subject_fee)?;
+    system_transfer(&buyer, &keys_account.to_account_info(), &system_program, price)?;
+    ctx.accounts.reward_pool.record_deposit(reward_pool_amount, clock.unix_timestamp)?;
+    ctx.accounts.earnings_vault.record_accrual(subject_fee)?;
+
+    keys_account.presale.record_sale(amount)?;
+    keys_account.supply = keys_account.supply
+        .checked_add(amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+    // The presale sells at its own fixed price, which stays out of the curve's feeds and breaker
+    keys_account.record_trade(price, subject_fee, protocol_fee, clock.unix_timestamp)?;
+    record_milestones(keys_account, &ctx.accounts.config, clock.unix_timestamp)?;
+
+    // Mint keys to buyer
+    let cpi_accounts = token::MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.buyer_token_account.to_account_info(),
+        authority: keys_account.to_account_info(),
+    };
+    let seeds = &[
+        b"keys",
+        ctx.accounts.subject.key.as_ref(),
+        &[ctx.bumps.keys_account],
+    ];
+    let signer = &[&seeds[..]];
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let freeze = KeyFreeze::new(&token_program, &ctx.accounts.mint.to_account_info(), keys_account, signer);
+    let buyer_token_info = ctx.accounts.buyer_token_account.to_account_info();
+    freeze.thaw(&buyer_token_info)?;
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+    token::mint_to(cpi_ctx, amount)?;
+    freeze.freeze(&buyer_token_info)?;
+
+    // Record the keys on the buyer's holding record, creating it on their first buy
+    let key_holder = &mut ctx.accounts.key_holder;
+    if key_holder.holder == Pubkey::default() {
+        **key_holder = KeyHolder::new(buyer_key, ctx.accounts.subject.key());
+    }
+    let held_before = key_holder.amount;
+    key_holder.update_after_buy(amount, keys_account.presale.price, price);
+    keys_account.track_holder_balance(held_before, key_holder.amount)?;
+
+    emit!(PresaleKeysBoughtEvent {
+        buyer: buyer_key,
+        subject: ctx.accounts.subject.key(),
+        amount,
+        price,
+        protocol_fee,
+        subject_fee,
+        wallet_total: ctx.accounts.presale_purchase.amount,
+        keys_remaining: keys_account.presale.remaining(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PresaleKeysBoughtEvent {
+    pub buyer: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub price: u64,
+    pub protocol_fee: u64,
+    pub subject_fee: u64,
+    /// Presale keys the buyer holds in this market after the purchase
+    pub wallet_total: u64,
+    pub keys_remaining: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
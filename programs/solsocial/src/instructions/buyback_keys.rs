@@ -0,0 +1,182 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{EarningsVault, ProtocolConfig, RewardPool, UserKeys, RecentTrades, PriceOracle, EARNINGS_VAULT_SEED, Feature};
+use crate::utils::accounts::{move_lamports, withdrawable_lamports};
+use crate::utils::bonding_curve::calculate_buy_price;
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::revenue_share::calculate_fee;
+use crate::utils::trade::{record_market_trade, CurveTrade, MarketFeeds};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct BuybackKeys<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", creator.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Pays for the buyback out of the creator's unclaimed fees
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, creator.key().as_ref()],
+        bump = earnings_vault.bump,
+        has_one = creator @ SolSocialError::Unauthorized,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = RecentTrades::LEN,
+        seeds = [b"recent_trades", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub recent_trades: Account<'info, RecentTrades>,
+
+    /// Price feed for other programs; every curve trade records into it
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = PriceOracle::LEN,
+        seeds = [b"price_oracle", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub price_oracle: Account<'info, PriceOracle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Spend earnings-vault lamports buying `amount` keys off the curve and burn them on the
+/// spot.
+///
+/// The buy takes `supply` up by `amount` and the burn takes it back down, so nothing is
+/// minted and the curve ends where it started. What remains is the price paid, which joins
+/// the reserve: every key still held is backed by more than the curve pays out for it. The
+/// protocol fee applies; the creator fee would only pay the vault back, so it is waived.
+pub fn handler(ctx: Context<BuybackKeys>, amount: u64) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    require!(amount > 0, SolSocialError::InvalidAmount);
+    require!(ctx.accounts.config.allows_trade_size(amount), SolSocialError::TradeTooLarge);
+
+    let now = Clock::get()?.unix_timestamp;
+    let keys_account = &mut ctx.accounts.keys_account;
+    keys_account.require_lamport_settlement()?;
+    if !keys_account.is_trading_open(now) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::OutsideTradingHours,
+                price: keys_account.trading_open_at as u64,
+                limit: keys_account.trading_close_at as u64,
+                amount,
+            },
+            SolSocialError::OperationNotAllowed,
+        ));
+    }
+
+    let params = keys_account.curve_params;
+    let price = calculate_buy_price(&params, keys_account.supply, amount)?;
+    let protocol_fee = calculate_fee(price, params.buy_protocol_fee)?;
+    let reward_pool_amount = ctx.accounts.config.reward_pool_cut(protocol_fee)?;
+    let treasury_fee = protocol_fee
+        .checked_sub(reward_pool_amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    let total_cost = price
+        .checked_add(protocol_fee)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    ctx.accounts.earnings_vault.require_distributed()?;
+    let vault_info = ctx.accounts.earnings_vault.to_account_info();
+    let available = withdrawable_lamports(&Rent::get()?, &vault_info);
+    if available < total_cost {
+        return Err(fail_with_context(
+            TradeErrorContext { kind: TradeErrorKind::InsufficientFunds, price: total_cost, limit: available, amount },
+            SolSocialError::InsufficientBalance,
+        ));
+    }
+
+    move_lamports(&vault_info, &keys_account.to_account_info(), price)?;
+    move_lamports(&vault_info, &ctx.accounts.treasury.to_account_info(), treasury_fee)?;
+    move_lamports(&vault_info, &ctx.accounts.reward_pool.to_account_info(), reward_pool_amount)?;
+    ctx.accounts.reward_pool.record_deposit(reward_pool_amount, now)?;
+    // A buyback draws the vault down just like a claim
+    ctx.accounts.earnings_vault.record_claim(total_cost, now)?;
+
+    // Bought and burned in one step: `supply` is back where it started
+    keys_account.burned_keys = keys_account.burned_keys
+        .checked_add(amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    let trade_index = record_market_trade(
+        keys_account,
+        Some(MarketFeeds {
+            recent_trades: &mut ctx.accounts.recent_trades,
+            recent_trades_bump: ctx.bumps.recent_trades,
+            price_oracle: &mut ctx.accounts.price_oracle,
+            price_oracle_bump: ctx.bumps.price_oracle,
+        }),
+        None,
+        &ctx.accounts.config,
+        &CurveTrade { amount, value: price, creator_fee: 0, protocol_fee, is_buy: true },
+        now,
+    )?;
+
+    emit!(BuybackEvent {
+        subject: ctx.accounts.creator.key(),
+        amount,
+        price,
+        protocol_fee,
+        supply_after: keys_account.supply,
+        price_after: keys_account.price,
+        burned_keys: keys_account.burned_keys,
+        circulating_supply: keys_account.circulating_supply(),
+        trade_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BuybackEvent {
+    pub subject: Pubkey,
+    /// Keys bought back and burned
+    pub amount: u64,
+    /// Curve price paid for them, before the protocol fee
+    pub price: u64,
+    pub protocol_fee: u64,
+    /// The curve's supply after the buyback, which the burn leaves unchanged
+    pub supply_after: u64,
+    /// Price of the next key after the buyback
+    pub price_after: u64,
+    /// Keys burned by buybacks over the market's life
+    pub burned_keys: u64,
+    /// Keys that exist as tokens after the buyback: `supply_after` less locked keys
+    pub circulating_supply: u64,
+    pub trade_index: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,51 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{DcaSchedule, DCA_SCHEDULE_SEED};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CancelDcaSchedule<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: The subject whose keys the schedule buys
+    pub subject: AccountInfo<'info>,
+
+    /// Closing refunds the unspent deposit and rent to the owner
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ SolSocialError::InvalidAccountOwner,
+        seeds = [DCA_SCHEDULE_SEED, owner.key().as_ref(), subject.key().as_ref()],
+        bump = schedule.bump,
+    )]
+    pub schedule: Account<'info, DcaSchedule>,
+}
+
+pub fn handler(ctx: Context<CancelDcaSchedule>) -> Result<()> {
+    let schedule = &ctx.accounts.schedule;
+
+    emit!(DcaScheduleCancelledEvent {
+        schedule: schedule.key(),
+        owner: schedule.owner,
+        keys_user: schedule.keys_user,
+        refunded_lamports: schedule.remaining_lamports,
+        executions: schedule.executions,
+        keys_bought: schedule.keys_bought,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DcaScheduleCancelledEvent {
+    pub schedule: Pubkey,
+    pub owner: Pubkey,
+    pub keys_user: Pubkey,
+    pub refunded_lamports: u64,
+    pub executions: u64,
+    pub keys_bought: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
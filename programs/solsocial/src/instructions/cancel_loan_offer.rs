@@ -0,0 +1,127 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, KeyLoan, UserKeys, KEY_HOLDER_SEED, KEY_LOAN_ESCROW_SEED, KEY_LOAN_SEED};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CancelLoanOffer<'info> {
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    /// CHECK: The subject whose keys are lent
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Freezes and thaws the holder's key token account
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = lender,
+        has_one = lender @ SolSocialError::InvalidAccountOwner,
+        seeds = [KEY_LOAN_SEED, lender.key().as_ref(), subject.key().as_ref(), &loan.loan_id.to_le_bytes()],
+        bump = loan.bump,
+    )]
+    pub loan: Account<'info, KeyLoan>,
+
+    #[account(
+        mut,
+        seeds = [KEY_LOAN_ESCROW_SEED, loan.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, lender.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = lender,
+    )]
+    pub lender_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw a loan offer no borrower has taken
+pub fn handler(ctx: Context<CancelLoanOffer>) -> Result<()> {
+    let loan = &ctx.accounts.loan;
+    require!(loan.is_open(), SolSocialError::LoanAlreadyTaken);
+
+    let lender_key = ctx.accounts.lender.key();
+    let subject_key = ctx.accounts.subject.key();
+    let loan_id = loan.loan_id.to_le_bytes();
+    let loan_seeds = &[
+        KEY_LOAN_SEED,
+        lender_key.as_ref(),
+        subject_key.as_ref(),
+        &loan_id,
+        &[loan.bump],
+    ];
+    let signer = &[&loan_seeds[..]];
+
+    let key_holder = &mut ctx.accounts.key_holder;
+    key_holder.amount = key_holder.amount
+        .checked_add(loan.amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    let keys_seeds = &[b"keys".as_ref(), ctx.accounts.subject.key.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.keys_account,
+        keys_signer,
+    );
+    let lender_token_info = ctx.accounts.lender_token_account.to_account_info();
+    freeze.thaw(&lender_token_info)?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.escrow.to_account_info(),
+        to: ctx.accounts.lender_token_account.to_account_info(),
+        authority: loan.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::transfer(cpi_ctx, loan.amount)?;
+    freeze.freeze(&lender_token_info)?;
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow.to_account_info(),
+        destination: ctx.accounts.lender.to_account_info(),
+        authority: loan.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::close_account(cpi_ctx)?;
+
+    emit!(LoanOfferCancelledEvent {
+        loan: loan.key(),
+        lender: lender_key,
+        keys_user: subject_key,
+        refunded_keys: loan.amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct LoanOfferCancelledEvent {
+    pub loan: Pubkey,
+    pub lender: Pubkey,
+    pub keys_user: Pubkey,
+    pub refunded_keys: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
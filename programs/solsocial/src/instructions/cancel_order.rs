@@ -0,0 +1,130 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, LimitOrder, OrderSide, UserKeys, KEY_HOLDER_SEED, LIMIT_ORDER_ESCROW_SEED, LIMIT_ORDER_SEED};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: The subject whose keys the order trades
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Freezes and thaws the holder's key token account
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    /// Closing refunds the escrowed lamports and rent to the owner
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ SolSocialError::InvalidAccountOwner,
+        seeds = [LIMIT_ORDER_SEED, owner.key().as_ref(), subject.key().as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+    )]
+    pub order: Account<'info, LimitOrder>,
+
+    #[account(
+        mut,
+        seeds = [LIMIT_ORDER_ESCROW_SEED, order.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, owner.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CancelOrder>) -> Result<()> {
+    let order = &ctx.accounts.order;
+
+    let owner_key = ctx.accounts.owner.key();
+    let subject_key = ctx.accounts.subject.key();
+    let order_id = order.order_id.to_le_bytes();
+    let order_seeds = &[
+        LIMIT_ORDER_SEED,
+        owner_key.as_ref(),
+        subject_key.as_ref(),
+        &order_id,
+        &[order.bump],
+    ];
+    let signer = &[&order_seeds[..]];
+
+    // Hand escrowed keys back, then close the escrow
+    if order.side == OrderSide::Sell {
+        let returned = order.return_escrowed_keys(&mut ctx.accounts.key_holder)?;
+
+        let keys_seeds = &[b"keys".as_ref(), ctx.accounts.subject.key.as_ref(), &[ctx.bumps.keys_account]];
+        let keys_signer = &[&keys_seeds[..]];
+        let freeze = KeyFreeze::new(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.keys_account,
+            keys_signer,
+        );
+        let owner_token_info = ctx.accounts.owner_token_account.to_account_info();
+        freeze.thaw(&owner_token_info)?;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: order.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, returned)?;
+        freeze.freeze(&owner_token_info)?;
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow.to_account_info(),
+        destination: ctx.accounts.owner.to_account_info(),
+        authority: order.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::close_account(cpi_ctx)?;
+
+    emit!(OrderCancelledEvent {
+        order: order.key(),
+        owner: order.owner,
+        keys_user: order.keys_user,
+        side: order.side,
+        refunded_lamports: order.escrowed_lamports,
+        refunded_keys: order.escrowed_keys,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OrderCancelledEvent {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub keys_user: Pubkey,
+    pub side: OrderSide,
+    pub refunded_lamports: u64,
+    pub refunded_keys: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,134 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, OtcOffer, OrderSide, UserKeys, KEY_HOLDER_SEED, OTC_ESCROW_SEED, OTC_OFFER_SEED};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CancelOtcOffer<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    /// CHECK: The subject whose keys the offer trades
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Freezes and thaws the holder's key token account
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    /// Closing refunds the escrowed lamports and rent to the maker
+    #[account(
+        mut,
+        close = maker,
+        has_one = maker @ SolSocialError::InvalidAccountOwner,
+        seeds = [OTC_OFFER_SEED, maker.key().as_ref(), subject.key().as_ref(), &offer.offer_id.to_le_bytes()],
+        bump = offer.bump,
+    )]
+    pub offer: Account<'info, OtcOffer>,
+
+    #[account(
+        mut,
+        seeds = [OTC_ESCROW_SEED, offer.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, maker.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = maker,
+    )]
+    pub maker_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw an offer that has not been accepted, expired or not
+pub fn handler(ctx: Context<CancelOtcOffer>) -> Result<()> {
+    let offer = &ctx.accounts.offer;
+
+    let maker_key = ctx.accounts.maker.key();
+    let subject_key = ctx.accounts.subject.key();
+    let offer_id = offer.offer_id.to_le_bytes();
+    let offer_seeds = &[
+        OTC_OFFER_SEED,
+        maker_key.as_ref(),
+        subject_key.as_ref(),
+        &offer_id,
+        &[offer.bump],
+    ];
+    let signer = &[&offer_seeds[..]];
+
+    // Hand escrowed keys back, then close the escrow
+    if offer.side == OrderSide::Sell {
+        let key_holder = &mut ctx.accounts.key_holder;
+        key_holder.amount = key_holder.amount
+            .checked_add(offer.escrowed_keys)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+        let keys_seeds = &[b"keys".as_ref(), ctx.accounts.subject.key.as_ref(), &[ctx.bumps.keys_account]];
+        let keys_signer = &[&keys_seeds[..]];
+        let freeze = KeyFreeze::new(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.keys_account,
+            keys_signer,
+        );
+        let maker_token_info = ctx.accounts.maker_token_account.to_account_info();
+        freeze.thaw(&maker_token_info)?;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow.to_account_info(),
+            to: ctx.accounts.maker_token_account.to_account_info(),
+            authority: offer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, offer.escrowed_keys)?;
+        freeze.freeze(&maker_token_info)?;
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow.to_account_info(),
+        destination: ctx.accounts.maker.to_account_info(),
+        authority: offer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::close_account(cpi_ctx)?;
+
+    emit!(OtcOfferCancelledEvent {
+        offer: offer.key(),
+        maker: maker_key,
+        keys_user: subject_key,
+        side: offer.side,
+        refunded_lamports: offer.escrowed_lamports,
+        refunded_keys: offer.escrowed_keys,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OtcOfferCancelledEvent {
+    pub offer: Pubkey,
+    pub maker: Pubkey,
+    pub keys_user: Pubkey,
+    pub side: OrderSide,
+    pub refunded_lamports: u64,
+    pub refunded_keys: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
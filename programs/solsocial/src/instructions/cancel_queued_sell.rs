@@ -0,0 +1,116 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use crate::state::{SellQueue, UserKeys};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CancelQueuedSell<'info> {
+    #[account(mut)]
+    pub seller_wallet: Signer<'info>,
+
+    #[account(
+        seeds = [b"keys", subject_keys.user.as_ref()],
+        bump,
+    )]
+    pub subject_keys: Account<'info, UserKeys>,
+
+    /// Freezes and thaws the seller's key token account
+    #[account(address = subject_keys.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = seller_wallet,
+        seeds = [b"sell_queue", seller_wallet.key().as_ref(), subject_keys.key().as_ref()],
+        bump = sell_queue.bump,
+        constraint = sell_queue.seller == seller_wallet.key() @ SolSocialError::Unauthorized,
+    )]
+    pub sell_queue: Account<'info, SellQueue>,
+
+    #[account(
+        mut,
+        seeds = [b"sell_queue_escrow", sell_queue.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = subject_keys.mint,
+        associated_token::authority = seller_wallet,
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CancelQueuedSell>) -> Result<()> {
+    let sell_queue = &ctx.accounts.sell_queue;
+    let returned_amount = sell_queue.remaining_amount;
+
+    let seller_key = ctx.accounts.seller_wallet.key();
+    let subject_keys_key = ctx.accounts.subject_keys.key();
+    let queue_seeds = &[
+        b"sell_queue".as_ref(),
+        seller_key.as_ref(),
+        subject_keys_key.as_ref(),
+        &[sell_queue.bump],
+    ];
+    let signer = &[&queue_seeds[..]];
+
+    // Hand the unsold keys back, then close the escrow
+    if returned_amount > 0 {
+        let subject_key = ctx.accounts.subject_keys.user;
+        let keys_seeds = &[b"keys".as_ref(), subject_key.as_ref(), &[ctx.bumps.subject_keys]];
+        let keys_signer = &[&keys_seeds[..]];
+        let freeze = KeyFreeze::new(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.subject_keys,
+            keys_signer,
+        );
+        let seller_token_info = ctx.accounts.seller_token_account.to_account_info();
+
+        freeze.thaw(&seller_token_info)?;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow.to_account_info(),
+            to: seller_token_info.clone(),
+            authority: sell_queue.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, returned_amount)?;
+        freeze.freeze(&seller_token_info)?;
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow.to_account_info(),
+        destination: ctx.accounts.seller_wallet.to_account_info(),
+        authority: sell_queue.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::close_account(cpi_ctx)?;
+
+    emit!(QueuedSellCancelledEvent {
+        sell_queue: sell_queue.key(),
+        seller: seller_key,
+        subject_keys: subject_keys_key,
+        returned_amount,
+        tranches_executed: sell_queue.tranches_executed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct QueuedSellCancelledEvent {
+    pub sell_queue: Pubkey,
+    pub seller: Pubkey,
+    pub subject_keys: Pubkey,
+    pub returned_amount: u64,
+    pub tranches_executed: u32,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,103 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Revoke, Token, TokenAccount};
+use crate::state::{KeyHolder, TriggerKind, TriggerOrder, UserKeys, KEY_HOLDER_SEED, TRIGGER_ORDER_SEED};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CancelTriggerOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: The subject whose keys the order sells
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Freezes and thaws the owner's key token account
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    /// Closing refunds the keeper bounty and rent to the owner
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ SolSocialError::InvalidAccountOwner,
+        seeds = [TRIGGER_ORDER_SEED, owner.key().as_ref(), subject.key().as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+    )]
+    pub order: Account<'info, TriggerOrder>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, owner.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CancelTriggerOrder>) -> Result<()> {
+    let order = &ctx.accounts.order;
+
+    // Hand the escrowed keys back and drop the order's delegation over them
+    let key_holder = &mut ctx.accounts.key_holder;
+    key_holder.amount = key_holder.amount
+        .checked_add(order.amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    let subject = ctx.accounts.subject.key();
+    let keys_seeds = &[b"keys".as_ref(), subject.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.keys_account,
+        keys_signer,
+    );
+    let owner_token_info = ctx.accounts.owner_token_account.to_account_info();
+
+    freeze.thaw(&owner_token_info)?;
+    let cpi_accounts = Revoke {
+        source: owner_token_info.clone(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::revoke(cpi_ctx)?;
+    freeze.freeze(&owner_token_info)?;
+
+    emit!(TriggerOrderCancelledEvent {
+        order: order.key(),
+        owner: order.owner,
+        keys_user: order.keys_user,
+        kind: order.kind,
+        refunded_keys: order.amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TriggerOrderCancelledEvent {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub keys_user: Pubkey,
+    pub kind: TriggerKind,
+    pub refunded_keys: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,75 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{HolderVote, HolderVoteReceipt, KeyHolder};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CastHolderVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"holder_vote", vote.creator.as_ref(), &vote.vote_id.to_le_bytes()],
+        bump = vote.bump
+    )]
+    pub vote: Account<'info, HolderVote>,
+
+    /// The voter's holding in the creator's market; its current amount sets the vote weight
+    #[account(
+        seeds = [b"key_holder", voter.key().as_ref(), vote.creator.as_ref()],
+        bump,
+        constraint = key_holder.holder == voter.key() @ SolSocialError::InvalidAccountOwner,
+        constraint = key_holder.keys_user == vote.creator @ SolSocialError::InvalidAccountOwner
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = HolderVoteReceipt::LEN,
+        seeds = [b"vote_receipt", vote.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub receipt: Account<'info, HolderVoteReceipt>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CastHolderVote>, option_index: u8) -> Result<()> {
+    let vote = &mut ctx.accounts.vote;
+    let receipt = &mut ctx.accounts.receipt;
+    let weight = vote.vote_weight(ctx.accounts.key_holder.amount);
+    let now = Clock::get()?.unix_timestamp;
+
+    vote.record_vote(option_index, weight, now)?;
+
+    receipt.vote = vote.key();
+    receipt.voter = ctx.accounts.voter.key();
+    receipt.option_index = option_index;
+    receipt.weight = weight;
+    receipt.voted_at = now;
+    receipt.bump = ctx.bumps.receipt;
+
+    emit!(HolderVoteCastEvent {
+        vote: vote.key(),
+        voter: receipt.voter,
+        option_index,
+        weight,
+        option_tally: vote.tallies[option_index as usize],
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct HolderVoteCastEvent {
+    pub vote: Pubkey,
+    pub voter: Pubkey,
+    pub option_index: u8,
+    pub weight: u64,
+    pub option_tally: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
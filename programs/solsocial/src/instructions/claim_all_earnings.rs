@@ -0,0 +1,98 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::EarningsVault;
+use crate::utils::accounts::{move_lamports, withdrawable_lamports};
+use crate::error::SolSocialError;
+
+/// Most vaults a single claim may sweep, to stay within the compute budget
+pub const MAX_CLAIM_MARKETS: usize = 10;
+
+#[derive(Accounts)]
+pub struct ClaimAllEarnings<'info> {
+    pub owner: Signer<'info>,
+
+    /// CHECK: any wallet chosen by the owner to receive the earnings
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+/// Sweep every `EarningsVault` passed through `remaining_accounts` to `destination`. Creator
+/// fees are paid into these vaults as trades happen, so the claim never touches a market's
+/// reserve. Each vault must belong to `owner`; vaults with nothing above rent are skipped.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimAllEarnings<'info>>) -> Result<()> {
+    let vaults = ctx.remaining_accounts;
+    require!(!vaults.is_empty(), SolSocialError::InvalidAccountSequence);
+    require!(vaults.len() <= MAX_CLAIM_MARKETS, SolSocialError::TooManyAccounts);
+
+    let owner = ctx.accounts.owner.key();
+    let destination = ctx.accounts.destination.to_account_info();
+    let rent = Rent::get()?;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    let mut total_claimed = 0u64;
+    let mut markets_claimed = 0u8;
+    for (i, vault_info) in vaults.iter().enumerate() {
+        // Reject a vault listed twice so its balance can't be counted again
+        require!(
+            !vaults[..i].iter().any(|earlier| earlier.key == vault_info.key),
+            SolSocialError::InvalidAccountSequence
+        );
+
+        let mut vault = Account::<EarningsVault>::try_from(vault_info)?;
+        require!(vault.creator == owner, SolSocialError::Unauthorized);
+        // A shared market's collaborators are paid their split before the lead claims
+        vault.require_distributed()?;
+
+        let amount = withdrawable_lamports(&rent, vault_info);
+        if amount == 0 {
+            continue;
+        }
+
+        vault.record_claim(amount, timestamp)?;
+        vault.exit(ctx.program_id)?;
+
+        move_lamports(vault_info, &destination, amount)?;
+
+        total_claimed = total_claimed
+            .checked_add(amount)
+            .ok_or(SolSocialError::MathOverflow)?;
+        markets_claimed += 1;
+
+        emit!(EarningsClaimedEvent {
+            owner,
+            vault: vault_info.key(),
+            amount,
+            timestamp,
+        });
+    }
+
+    require!(total_claimed > 0, SolSocialError::NothingToClaim);
+
+    emit!(AllEarningsClaimedEvent {
+        owner,
+        destination: destination.key(),
+        markets_claimed,
+        total_claimed,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EarningsClaimedEvent {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AllEarningsClaimedEvent {
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub markets_claimed: u8,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,81 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{DividendClaim, DividendEpoch, KeyHolder, DIVIDEND_CLAIM_SEED, DIVIDEND_EPOCH_SEED, KEY_HOLDER_SEED};
+use crate::utils::accounts::move_lamports;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct ClaimDividends<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [DIVIDEND_EPOCH_SEED, epoch.subject.as_ref(), &epoch.epoch_index.to_le_bytes()],
+        bump = epoch.bump,
+    )]
+    pub epoch: Account<'info, DividendEpoch>,
+
+    #[account(
+        seeds = [KEY_HOLDER_SEED, holder.key().as_ref(), epoch.subject.as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    /// Created on the first claim, so a second one fails
+    #[account(
+        init,
+        payer = holder,
+        space = DividendClaim::LEN,
+        seeds = [DIVIDEND_CLAIM_SEED, epoch.key().as_ref(), holder.key().as_ref()],
+        bump,
+    )]
+    pub claim: Account<'info, DividendClaim>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pull the holder's pro-rata share of a dividend epoch
+pub fn handler(ctx: Context<ClaimDividends>) -> Result<()> {
+    let key_holder = &ctx.accounts.key_holder;
+    require!(key_holder.amount > 0, SolSocialError::InsufficientKeys);
+
+    let epoch = &mut ctx.accounts.epoch;
+    require!(epoch.is_eligible(key_holder.last_purchase_at), SolSocialError::OperationNotAllowed);
+
+    let keys_held = key_holder.amount;
+    let amount = epoch.claim(keys_held)?;
+
+    move_lamports(&epoch.to_account_info(), &ctx.accounts.holder.to_account_info(), amount)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let claim = &mut ctx.accounts.claim;
+    claim.epoch = epoch.key();
+    claim.holder = ctx.accounts.holder.key();
+    claim.keys_held = keys_held;
+    claim.amount = amount;
+    claim.claimed_at = now;
+    claim.bump = ctx.bumps.claim;
+
+    emit!(DividendClaimedEvent {
+        epoch: epoch.key(),
+        subject: epoch.subject,
+        holder: claim.holder,
+        keys_held,
+        amount,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DividendClaimedEvent {
+    pub epoch: Pubkey,
+    pub subject: Pubkey,
+    pub holder: Pubkey,
+    pub keys_held: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,55 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{EarlySupporterPool, EARLY_SUPPORTER_POOL_SEED};
+use crate::utils::accounts::move_lamports;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct ClaimEarlySupporterRewards<'info> {
+    #[account(mut)]
+    pub supporter: Signer<'info>,
+
+    /// CHECK: The subject whose market the pool belongs to
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [EARLY_SUPPORTER_POOL_SEED, subject.key().as_ref()],
+        bump = early_supporter_pool.bump,
+    )]
+    pub early_supporter_pool: Account<'info, EarlySupporterPool>,
+}
+
+/// Pay a seated supporter their share of the creator fees collected since their last claim
+pub fn handler(ctx: Context<ClaimEarlySupporterRewards>) -> Result<()> {
+    let supporter = ctx.accounts.supporter.key();
+    let amount = ctx.accounts.early_supporter_pool.claim(&supporter)?;
+    require!(amount > 0, SolSocialError::NothingToClaim);
+
+    move_lamports(
+        &ctx.accounts.early_supporter_pool.to_account_info(),
+        &ctx.accounts.supporter.to_account_info(),
+        amount,
+    )?;
+
+    emit!(EarlySupporterRewardsClaimedEvent {
+        supporter,
+        subject: ctx.accounts.subject.key(),
+        amount,
+        total_claimed: ctx.accounts.early_supporter_pool.total_claimed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EarlySupporterRewardsClaimedEvent {
+    pub supporter: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    /// Claimed out of the pool by all supporters so far
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
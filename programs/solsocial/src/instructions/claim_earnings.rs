@@ -0,0 +1,54 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{EarningsVault, EARNINGS_VAULT_SEED};
+use crate::utils::accounts::{move_lamports, withdrawable_lamports};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct ClaimEarnings<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, creator.key().as_ref()],
+        bump = earnings_vault.bump,
+        has_one = creator @ SolSocialError::Unauthorized,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+}
+
+/// Pull every lamport the vault holds above its rent-exempt minimum to the creator
+pub fn handler(ctx: Context<ClaimEarnings>) -> Result<()> {
+    // A shared market's collaborators are paid their split before the lead claims
+    ctx.accounts.earnings_vault.require_distributed()?;
+    let vault_info = ctx.accounts.earnings_vault.to_account_info();
+    let amount = withdrawable_lamports(&Rent::get()?, &vault_info);
+    require!(amount > 0, SolSocialError::NothingToClaim);
+
+    move_lamports(&vault_info, &ctx.accounts.creator.to_account_info(), amount)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let earnings_vault = &mut ctx.accounts.earnings_vault;
+    earnings_vault.record_claim(amount, now)?;
+
+    emit!(CreatorEarningsClaimedEvent {
+        creator: earnings_vault.creator,
+        vault: earnings_vault.key(),
+        amount,
+        total_claimed: earnings_vault.total_claimed,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CreatorEarningsClaimedEvent {
+    pub creator: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,62 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{RewardPool, StakePosition, STAKE_POSITION_SEED};
+use crate::utils::accounts::move_lamports;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct ClaimStakingRewards<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_POSITION_SEED, owner.key().as_ref(), stake_position.subject.as_ref()],
+        bump = stake_position.bump,
+        has_one = owner @ SolSocialError::Unauthorized,
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+}
+
+/// Pay accrued staking rewards out of the reward pool. When the pool can't cover them all,
+/// whatever it holds is paid and the rest stays pending.
+pub fn handler(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let stake_position = &mut ctx.accounts.stake_position;
+    stake_position.accrue(now)?;
+
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let payout = stake_position.take_rewards(reward_pool.available())?;
+    reward_pool.record_distribution(payout)?;
+
+    move_lamports(&reward_pool.to_account_info(), &ctx.accounts.owner.to_account_info(), payout)?;
+
+    emit!(StakingRewardsClaimedEvent {
+        owner: stake_position.owner,
+        subject: stake_position.subject,
+        amount: payout,
+        pending_rewards: stake_position.pending_rewards,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct StakingRewardsClaimedEvent {
+    pub owner: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    /// Rewards the pool couldn't cover yet
+    pub pending_rewards: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,104 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::{KeyHolder, UserKeys, Vesting, KEY_HOLDER_SEED, VESTING_SEED};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct ClaimVestedKeys<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", creator.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(
+        mut,
+        has_one = creator @ SolSocialError::Unauthorized,
+        seeds = [VESTING_SEED, creator.key().as_ref()],
+        bump = vesting.bump,
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, creator.key().as_ref(), creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_holder: Account<'info, KeyHolder>,
+
+    #[account(mut, address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Release the creator's keys that have vested since the last claim. They are minted to the
+/// creator, so from here on they can be sold like any curve-bought key; the creator paid
+/// their curve price into the reserve at creation, which is what backs those sells.
+pub fn handler(ctx: Context<ClaimVestedKeys>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vesting = &mut ctx.accounts.vesting;
+    let amount = vesting.record_claim(now)?;
+
+    let creator_key = ctx.accounts.creator.key();
+    let keys_account = &mut ctx.accounts.keys_account;
+    let keys_seeds = &[b"keys".as_ref(), creator_key.as_ref(), &[ctx.bumps.keys_account]];
+    let signer = &[&keys_seeds[..]];
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.creator_token_account.to_account_info(),
+        authority: keys_account.to_account_info(),
+    };
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let freeze = KeyFreeze::new(&token_program, &ctx.accounts.mint.to_account_info(), keys_account, signer);
+    let creator_token_info = ctx.accounts.creator_token_account.to_account_info();
+    freeze.thaw(&creator_token_info)?;
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+    token::mint_to(cpi_ctx, amount)?;
+    freeze.freeze(&creator_token_info)?;
+
+    // create_keys already counted and paid for the whole allocation on the curve, so minting
+    // it only moves keys from locked to circulating
+    keys_account.creator_locked_amount = keys_account.creator_locked_amount.saturating_sub(amount);
+    let creator_holder = &mut ctx.accounts.creator_holder;
+    let held_before = creator_holder.amount;
+    creator_holder.amount = creator_holder.amount
+        .checked_add(amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    keys_account.track_holder_balance(held_before, creator_holder.amount)?;
+
+    emit!(VestedKeysClaimedEvent {
+        keys_user: creator_key,
+        amount,
+        claimed_amount: vesting.claimed_amount,
+        remaining: vesting.total_amount - vesting.claimed_amount,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VestedKeysClaimedEvent {
+    pub keys_user: Pubkey,
+    pub amount: u64,
+    /// Keys claimed over the life of the schedule, this claim included
+    pub claimed_amount: u64,
+    /// Keys still to vest or be claimed
+    pub remaining: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,87 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{BuyCommitment, UserKeys, ProtocolConfig, Feature};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CommitBuy<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: The subject whose keys will be bought
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = BuyCommitment::LEN,
+        seeds = [b"buy_commitment", buyer.key().as_ref(), keys_account.key().as_ref()],
+        bump,
+    )]
+    pub commitment: Account<'info, BuyCommitment>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CommitBuy>, commitment_hash: [u8; 32], max_cost: u64) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    require!(max_cost > 0, SolSocialError::InvalidAmount);
+
+    let clock = Clock::get()?;
+    let commitment = &mut ctx.accounts.commitment;
+    commitment.buyer = ctx.accounts.buyer.key();
+    commitment.subject_keys = ctx.accounts.keys_account.key();
+    commitment.commitment = commitment_hash;
+    commitment.escrowed_lamports = max_cost;
+    commitment.committed_slot = clock.slot;
+    commitment.reveal_deadline_slot = clock.slot.saturating_add(BuyCommitment::REVEAL_WINDOW_SLOTS);
+    commitment.created_at = clock.unix_timestamp;
+    commitment.bump = ctx.bumps.commitment;
+
+    // Escrow the most the buyer is willing to pay, fees included
+    let ix = anchor_lang::solana_program::system_instruction::transfer(
+        &ctx.accounts.buyer.key(),
+        &commitment.key(),
+        max_cost,
+    );
+    anchor_lang::solana_program::program::invoke(
+        &ix,
+        &[ctx.accounts.buyer.to_account_info(), commitment.to_account_info()],
+    )?;
+
+    emit!(BuyCommittedEvent {
+        commitment: commitment.key(),
+        buyer: commitment.buyer,
+        subject: ctx.accounts.subject.key(),
+        commitment_hash,
+        escrowed_lamports: max_cost,
+        reveal_deadline_slot: commitment.reveal_deadline_slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BuyCommittedEvent {
+    pub commitment: Pubkey,
+    pub buyer: Pubkey,
+    pub subject: Pubkey,
+    pub commitment_hash: [u8; 32],
+    pub escrowed_lamports: u64,
+    pub reveal_deadline_slot: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -1,6 +1,6 @@
 ```rust
 use anchor_lang::prelude::*;
-use crate::state::{User, Chat, ChatParticipant};
+use crate::state::{User, GroupChatRoom, ChatParticipant, ProtocolConfig, Feature};
 use crate::error::SolSocialError;
 
 #[derive(Accounts)]
@@ -9,17 +9,17 @@ pub struct CreateChat<'info> {
     #[account(
         init,
         payer = creator,
-        space = Chat::LEN,
+        space = GroupChatRoom::LEN,
         seeds = [b"chat", chat_id.as_bytes()],
         bump
     )]
-    pub chat: Account<'info, Chat>,
+    pub chat: Account<'info, GroupChatRoom>,
 
     #[account(
         mut,
         seeds = [b"user", creator.key().as_ref()],
         bump = creator_user.bump,
-        constraint = creator_user.is_initialized @ SolSocialError::UserNotInitialized
+        constraint = creator_user.is_initialized() @ SolSocialError::UserNotInitialized
     )]
     pub creator_user: Account<'info, User>,
 
@@ -35,21 +35,31 @@ pub struct CreateChat<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn create_chat(
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
     ctx: Context<CreateChat>,
     chat_id: String,
     name: String,
     description: String,
     is_private: bool,
     max_participants: u32,
+    auto_mute_new: bool,
+    is_direct: bool,
 ) -> Result<()> {
-    require!(chat_id.len() <= 32, SolSocialError::ChatIdTooLong);
-    require!(name.len() <= 64, SolSocialError::ChatNameTooLong);
-    require!(description.len() <= 256, SolSocialError::ChatDescriptionTooLong);
+    ctx.accounts.config.require_feature(Feature::Chat)?;
+    GroupChatRoom::validate_metadata(&chat_id, &name, &description)?;
     require!(max_participants > 0 && max_participants <= 1000, SolSocialError::InvalidMaxParticipants);
+    // A direct conversation seats exactly the creator and one recipient
+    require!(!is_direct || max_participants == 2, SolSocialError::InvalidMaxParticipants);
 
     let chat = &mut ctx.accounts.chat;
     let creator = &ctx.accounts.creator;
@@ -70,15 +80,18 @@ pub fn create_chat(
     chat.created_at = clock.unix_timestamp;
     chat.updated_at = clock.unix_timestamp;
     chat.is_active = true;
+    chat.auto_mute_new = auto_mute_new;
+    chat.is_direct = is_direct;
+    chat.pinned_message = None;
     chat.bump = ctx.bumps.chat;
 
     // Initialize creator as first participant
-    creator_participant.chat = chat.key();
+    creator_participant.room_id = chat.key().to_bytes();
     creator_participant.user = creator.key();
-    creator_participant.username = creator_user.username.clone();
     creator_participant.joined_at = clock.unix_timestamp;
     creator_participant.is_admin = true;
     creator_participant.is_muted = false;
+    creator_participant.is_blocked = false;
     creator_participant.message_count = 0;
     creator_participant.last_read_at = clock.unix_timestamp;
     creator_participant.bump = ctx.bumps.creator_participant;
@@ -94,6 +107,8 @@ pub fn create_chat(
         name: chat.name.clone(),
         is_private: chat.is_private,
         max_participants: chat.max_participants,
+        auto_mute_new: chat.auto_mute_new,
+        is_direct: chat.is_direct,
         created_at: chat.created_at,
     });
 
@@ -108,6 +123,8 @@ pub struct ChatCreatedEvent {
     pub name: String,
     pub is_private: bool,
     pub max_participants: u32,
+    pub auto_mute_new: bool,
+    pub is_direct: bool,
     pub created_at: i64,
 }
 ```
\ No newline at end of file
@@ -0,0 +1,52 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{Collection, COLLECTION_SEED};
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateCollection<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = Collection::LEN,
+        seeds = [COLLECTION_SEED, owner.key().as_ref(), name.as_bytes()],
+        bump,
+    )]
+    pub collection: Account<'info, Collection>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a named collection to file bookmarks under
+pub fn handler(ctx: Context<CreateCollection>, name: String) -> Result<()> {
+    Collection::validate_name(&name)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let collection = &mut ctx.accounts.collection;
+    collection.owner = ctx.accounts.owner.key();
+    collection.name = name;
+    collection.bookmark_count = 0;
+    collection.created_at = now;
+    collection.bump = ctx.bumps.collection;
+
+    emit!(CollectionCreatedEvent {
+        collection: collection.key(),
+        owner: collection.owner,
+        name: collection.name.clone(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CollectionCreatedEvent {
+    pub collection: Pubkey,
+    pub owner: Pubkey,
+    pub name: String,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
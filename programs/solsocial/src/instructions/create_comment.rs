@@ -0,0 +1,122 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{Comment, Post, UserKeys, KeyHolder, UserFollow, ProtocolConfig, Feature, COMMENT_SEED, SEED_FOLLOWING};
+use crate::instructions::create_post::notify_mentions;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CreateComment<'info> {
+    #[account(mut)]
+    pub post: Account<'info, Post>,
+
+    #[account(
+        init,
+        payer = author,
+        space = Comment::SPACE,
+        seeds = [COMMENT_SEED, post.key().as_ref(), &post.comment_count.to_le_bytes()],
+        bump
+    )]
+    pub comment: Account<'info, Comment>,
+
+    /// The comment being replied to, when threading
+    #[account(
+        mut,
+        seeds = [COMMENT_SEED, post.key().as_ref(), &parent_comment.comment_id.to_le_bytes()],
+        bump = parent_comment.bump,
+    )]
+    pub parent_comment: Option<Account<'info, Comment>>,
+
+    /// Key market gating the post, required when `post.gated_market` is set
+    pub gating_keys: Option<Account<'info, UserKeys>>,
+
+    /// The commenter's holding in the gating market
+    pub key_holder: Option<Account<'info, KeyHolder>>,
+
+    /// The commenter's follow of the post's author, required for followers-only posts
+    #[account(
+        seeds = [SEED_FOLLOWING, author.key().as_ref(), post.author.as_ref()],
+        bump,
+    )]
+    pub follow: Option<Account<'info, UserFollow>>,
+
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Comment on a post, or reply to one of its comments. Gated posts take the same key
+/// holding to comment on as to interact with, and the post's visibility applies too.
+///
+/// `remaining_accounts` hold the `User` PDA of each mentioned wallet, in order.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateComment<'info>>,
+    content: String,
+    mentions: Vec<Pubkey>,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Posting)?;
+    let author = ctx.accounts.author.key();
+    ctx.accounts.post.check_gate(
+        &author,
+        ctx.accounts.gating_keys.as_ref().map(|keys| (keys.key(), &**keys)),
+        ctx.accounts.key_holder.as_deref(),
+    )?;
+    ctx.accounts.post.check_visibility(&author, ctx.accounts.key_holder.as_deref(), ctx.accounts.follow.as_deref())?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let post = &mut ctx.accounts.post;
+    let comment_id = post.open_comment()?;
+
+    let parent_key = match ctx.accounts.parent_comment.as_mut() {
+        Some(parent) => {
+            parent.reply_count = parent.reply_count
+                .checked_add(1)
+                .ok_or(SolSocialError::ArithmeticOverflow)?;
+            Some(parent.key())
+        }
+        None => None,
+    };
+
+    let comment = &mut ctx.accounts.comment;
+    comment.initialize(post.key(), author, comment_id, parent_key, content, now, ctx.bumps.comment)?;
+
+    emit!(CommentCreatedEvent {
+        post: post.key(),
+        comment: comment.key(),
+        author,
+        comment_id,
+        parent_comment: parent_key,
+        content: comment.content.clone(),
+        timestamp: now,
+    });
+
+    notify_mentions(
+        &mentions,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        author,
+        post.key(),
+        Some(comment.key()),
+        now,
+    )?;
+
+    Ok(())
+}
+
+#[event]
+pub struct CommentCreatedEvent {
+    pub post: Pubkey,
+    pub comment: Pubkey,
+    pub author: Pubkey,
+    pub comment_id: u64,
+    pub parent_comment: Option<Pubkey>,
+    pub content: String,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
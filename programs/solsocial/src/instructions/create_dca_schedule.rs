@@ -0,0 +1,116 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{DcaSchedule, KeyHolder, UserKeys, DCA_SCHEDULE_SEED, KEY_HOLDER_SEED, ProtocolConfig, Feature};
+use crate::utils::revenue_share::system_transfer;
+
+#[derive(Accounts)]
+#[instruction(subject: Pubkey)]
+pub struct CreateDcaSchedule<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Escrows the deposit on top of its rent
+    #[account(
+        init,
+        payer = owner,
+        space = DcaSchedule::LEN,
+        seeds = [DCA_SCHEDULE_SEED, owner.key().as_ref(), subject.as_ref()],
+        bump,
+    )]
+    pub schedule: Account<'info, DcaSchedule>,
+
+    /// Created up front so executions never charge the keeper for it
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, owner.key().as_ref(), subject.as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    /// Receives the keys each execution buys
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposit `deposit` lamports to buy `subject`'s keys with up to `lamports_per_interval`
+/// every `interval_seconds`, starting now. Each execution also pays the keeper
+/// `DcaSchedule::KEEPER_FEE` out of the deposit.
+pub fn handler(
+    ctx: Context<CreateDcaSchedule>,
+    subject: Pubkey,
+    lamports_per_interval: u64,
+    interval_seconds: i64,
+    deposit: u64,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    ctx.accounts.keys_account.require_lamport_settlement()?;
+    let now = Clock::get()?.unix_timestamp;
+    let owner = ctx.accounts.owner.key();
+
+    let schedule = &mut ctx.accounts.schedule;
+    schedule.initialize(owner, subject, lamports_per_interval, interval_seconds, deposit, now, ctx.bumps.schedule)?;
+
+    let key_holder = &mut ctx.accounts.key_holder;
+    if key_holder.holder == Pubkey::default() {
+        **key_holder = KeyHolder::new(owner, subject);
+    }
+
+    system_transfer(
+        &ctx.accounts.owner.to_account_info(),
+        &schedule.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        deposit,
+    )?;
+
+    emit!(DcaScheduleCreatedEvent {
+        schedule: schedule.key(),
+        owner,
+        keys_user: subject,
+        lamports_per_interval,
+        interval_seconds,
+        deposit,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DcaScheduleCreatedEvent {
+    pub schedule: Pubkey,
+    pub owner: Pubkey,
+    pub keys_user: Pubkey,
+    pub lamports_per_interval: u64,
+    pub interval_seconds: i64,
+    pub deposit: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,78 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{User, HolderVote};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(vote_id: u64)]
+pub struct CreateHolderVote<'info> {
+    #[account(
+        seeds = [b"user", creator.key().as_ref()],
+        bump = creator_user.bump,
+        constraint = creator_user.is_initialized() @ SolSocialError::UserNotInitialized
+    )]
+    pub creator_user: Account<'info, User>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = HolderVote::LEN,
+        seeds = [b"holder_vote", creator.key().as_ref(), &vote_id.to_le_bytes()],
+        bump
+    )]
+    pub vote: Account<'info, HolderVote>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateHolderVote>,
+    vote_id: u64,
+    question: String,
+    options: Vec<String>,
+    key_weighted: bool,
+    deadline: i64,
+) -> Result<()> {
+    let vote = &mut ctx.accounts.vote;
+    let now = Clock::get()?.unix_timestamp;
+
+    vote.initialize(
+        ctx.accounts.creator.key(),
+        vote_id,
+        question,
+        options,
+        key_weighted,
+        deadline,
+        now,
+        ctx.bumps.vote,
+    )?;
+
+    emit!(HolderVoteCreatedEvent {
+        vote: vote.key(),
+        creator: vote.creator,
+        vote_id,
+        question: vote.question.clone(),
+        options: vote.options.clone(),
+        key_weighted,
+        deadline,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct HolderVoteCreatedEvent {
+    pub vote: Pubkey,
+    pub creator: Pubkey,
+    pub vote_id: u64,
+    pub question: String,
+    pub options: Vec<String>,
+    pub key_weighted: bool,
+    pub deadline: i64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
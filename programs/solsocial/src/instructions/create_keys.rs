@@ -1,7 +1,12 @@
 ```rust
 use anchor_lang::prelude::*;
-use crate::state::{User, UserKeys};
-use crate::utils::bonding_curve::calculate_price;
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::{User, UserKeys, KeyHolder, ProtocolConfig, BondingCurveParams, CurveChoice, CurvePreset, FounderAllocation, EarningsVault, LaunchAuction, LaunchAuctionParams, Presale, PresaleParams, LaunchGuard, LaunchGuardParams, Vesting, VestingParams, KeyMarketRegistry, KeyMarketRegistryPage, KEY_HOLDER_SEED, KEYS_MINT_SEED, EARNINGS_VAULT_SEED, VESTING_SEED, KEY_MARKET_REGISTRY_SEED, KEY_MARKET_PAGE_SEED, Feature};
+use crate::utils::bonding_curve::{calculate_buy_price, calculate_price};
+use crate::utils::accounts::{create_pda_account, require_rent_funds, write_account};
+use crate::utils::revenue_share::system_transfer;
+use crate::utils::key_token::KeyFreeze;
 use crate::error::SolSocialError;
 
 #[derive(Accounts)]
@@ -23,61 +28,454 @@ pub struct CreateKeys<'info> {
     )]
     pub user_keys: Account<'info, UserKeys>,
 
+    /// Holding record for the creator's free first key
+    #[account(
+        init,
+        payer = creator,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, creator.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub creator_holder: Account<'info, KeyHolder>,
+
+    /// Collects the creator's trading fees until they claim them; already open when the
+    /// creator collaborated on a shared market first
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = EarningsVault::LEN,
+        seeds = [EARNINGS_VAULT_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    /// Whole-key SPL mint; only the keys PDA can mint, and it freezes holders' token
+    /// accounts so keys only move through the program
+    #[account(
+        init,
+        payer = creator,
+        seeds = [KEYS_MINT_SEED, creator.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = user_keys,
+        mint::freeze_authority = user_keys,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// Counts every market for enumeration; created along with the first one
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = KeyMarketRegistry::LEN,
+        seeds = [KEY_MARKET_REGISTRY_SEED],
+        bump
+    )]
+    pub market_registry: Account<'info, KeyMarketRegistry>,
+
+    /// The registry page this market is listed on, opened by the first market landing there
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = KeyMarketRegistryPage::LEN,
+        seeds = [KEY_MARKET_PAGE_SEED, &market_registry.current_page().to_le_bytes()],
+        bump
+    )]
+    pub market_registry_page: Account<'info, KeyMarketRegistryPage>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: The creator's `Vesting` PDA; created in the handler when an allocation vests
+    #[account(mut)]
+    pub vesting: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateKeys>, user_bump: u8) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateKeys<'info>>,
+    user_bump: u8,
+    curve: Option<CurveChoice>,
+    founder_allocations: Vec<FounderAllocation>,
+    launch_auction: Option<LaunchAuctionParams>,
+    presale: Option<PresaleParams>,
+    launch_guard: Option<LaunchGuardParams>,
+    creator_vesting: Option<VestingParams>,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let config = &ctx.accounts.config;
+    let curve_params = curve
+        .unwrap_or(CurveChoice::Preset(CurvePreset::Standard))
+        .resolve_with(config.default_base_price, config.max_fee_bps)?;
+
+    require!(
+        founder_allocations.len() <= UserKeys::MAX_FOUNDER_ALLOCATIONS,
+        SolSocialError::TooManyAccounts
+    );
+    // Each founder passes their KeyHolder PDA, wallet and associated key token account
+    require!(
+        ctx.remaining_accounts.len() == founder_allocations.len() * 3,
+        SolSocialError::MissingRequiredAccount
+    );
+
     let user_keys = &mut ctx.accounts.user_keys;
     let creator = &ctx.accounts.creator;
     let clock = Clock::get()?;
 
     // Validate user account exists
     require!(
-        ctx.accounts.user_account.is_initialized,
+        ctx.accounts.user_account.is_initialized(),
         SolSocialError::UserNotInitialized
     );
 
     // Initialize user keys account
-    user_keys.creator = creator.key();
-    user_keys.total_supply = 0;
-    user_keys.holders_count = 0;
-    user_keys.created_at = clock.unix_timestamp;
-    user_keys.is_active = true;
-    user_keys.bump = ctx.bumps.user_keys;
-
-    // Calculate initial price for first key (creator gets first key for free)
-    let initial_price = calculate_price(0, 1)?;
-    
-    // Creator automatically gets the first key
-    user_keys.total_supply = 1;
+    **user_keys = UserKeys::new(creator.key(), Some(curve_params));
+    user_keys.mint = ctx.accounts.mint.key();
+
+    // Creator automatically gets the first key, issued outside the curve and never minted
+    user_keys.supply = 1;
     user_keys.holders_count = 1;
+    user_keys.creator_locked_amount = 1;
 
     // Initialize creator's holding
-    user_keys.holders.push(crate::state::KeyHolder {
-        holder: creator.key(),
-        amount: 1,
-        last_trade_timestamp: clock.unix_timestamp,
-    });
+    let creator_holder = &mut ctx.accounts.creator_holder;
+    **creator_holder = KeyHolder::new(creator.key(), creator.key());
+    creator_holder.amount = 1;
+
+    if ctx.accounts.earnings_vault.creator == Pubkey::default() {
+        ctx.accounts.earnings_vault.initialize(creator.key(), ctx.bumps.earnings_vault);
+    }
+
+    // Pre-allocate keys to co-founders: each gets their own KeyHolder PDA and the keys
+    // minted to their token account, so they trade like any other key. The creator buys
+    // them off the curve on the founders' behalf, so the reserve backs them like any other
+    require_rent_funds(
+        &creator.to_account_info(),
+        &vec![KeyHolder::LEN + TokenAccount::LEN; founder_allocations.len()],
+        0,
+    )?;
+    let creator_key = creator.key();
+    let keys_seeds = &[b"keys".as_ref(), creator_key.as_ref(), &[ctx.bumps.user_keys]];
+    let keys_signer = &[&keys_seeds[..]];
+    let mut total_allocated = 0u64;
+    for (i, allocation) in founder_allocations.iter().enumerate() {
+        require!(allocation.amount > 0, SolSocialError::InvalidKeyAmount);
+        require!(
+            allocation.founder != creator.key(),
+            SolSocialError::InvalidFounderAllocation
+        );
+        require!(
+            !founder_allocations[..i].iter().any(|a| a.founder == allocation.founder),
+            SolSocialError::InvalidFounderAllocation
+        );
+
+        // Founders take the keys right after the creator's, in the order they're listed
+        let cost = calculate_buy_price(&curve_params, 1 + total_allocated, allocation.amount)?;
+        total_allocated = total_allocated
+            .checked_add(allocation.amount)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+        let holder_info = &ctx.remaining_accounts[i * 3];
+        let founder_info = &ctx.remaining_accounts[i * 3 + 1];
+        let token_info = &ctx.remaining_accounts[i * 3 + 2];
+        require_keys_eq!(founder_info.key(), allocation.founder, SolSocialError::InvalidAccountSequence);
+        require_keys_eq!(
+            token_info.key(),
+            associated_token::get_associated_token_address(&allocation.founder, &ctx.accounts.mint.key()),
+            SolSocialError::InvalidAccountSequence
+        );
+        let (expected_holder, holder_bump) = Pubkey::find_program_address(
+            &[KEY_HOLDER_SEED, allocation.founder.as_ref(), creator.key().as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(holder_info.key(), expected_holder, SolSocialError::InvalidAccountSequence);
+
+        create_pda_account(
+            &creator.to_account_info(),
+            holder_info,
+            &ctx.accounts.system_program.to_account_info(),
+            KeyHolder::LEN,
+            &[
+                KEY_HOLDER_SEED,
+                allocation.founder.as_ref(),
+                creator.key().as_ref(),
+                &[holder_bump],
+            ],
+        )?;
+
+        let mut key_holder = KeyHolder::new(allocation.founder, creator.key());
+        key_holder.update_after_buy(allocation.amount, cost / allocation.amount, cost);
+        write_account(holder_info, &key_holder)?;
+
+        system_transfer(
+            &creator.to_account_info(),
+            &user_keys.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            cost,
+        )?;
+
+        // The mint is new, so no founder can have a token account for it yet
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: creator.to_account_info(),
+                associated_token: token_info.clone(),
+                authority: founder_info.clone(),
+                mint: ctx.accounts.mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.mint.to_account_info(),
+            to: token_info.clone(),
+            authority: user_keys.to_account_info(),
+        };
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, keys_signer);
+        token::mint_to(cpi_ctx, allocation.amount)?;
+        KeyFreeze::new(&token_program, &ctx.accounts.mint.to_account_info(), user_keys, keys_signer)
+            .freeze(token_info)?;
+
+        emit!(FounderAllocationEvent {
+            keys_user: creator.key(),
+            founder: allocation.founder,
+            amount: allocation.amount,
+            cost,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // A vesting creator allocation counts towards supply from the start, like founder keys,
+    // but stays locked and unminted until claim_vested_keys releases it. Founder keys are
+    // minted and paid for, so they circulate and stay out of `creator_locked_amount`
+    let vesting_amount = creator_vesting.map_or(0, |params| params.amount);
+    let supply_before_vesting = user_keys.supply
+        .checked_add(total_allocated)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    let supply_after_allocation = supply_before_vesting
+        .checked_add(vesting_amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    require!(
+        supply_after_allocation <= user_keys.curve_params.max_supply,
+        SolSocialError::InvalidFounderAllocation
+    );
+
+    // The creator buys the vesting keys off the curve up front, so the reserve already
+    // backs them when they are claimed and sold
+    let vesting_deposit = if vesting_amount > 0 {
+        calculate_buy_price(&curve_params, supply_before_vesting, vesting_amount)?
+    } else {
+        0
+    };
+    system_transfer(
+        &creator.to_account_info(),
+        &user_keys.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        vesting_deposit,
+    )?;
+
+    user_keys.supply = supply_after_allocation;
+    user_keys.price = calculate_price(&curve_params, user_keys.supply)?;
+    user_keys.creator_locked_amount = user_keys.creator_locked_amount
+        .checked_add(vesting_amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    user_keys.holders_count = user_keys.holders_count
+        .checked_add(founder_allocations.len() as u64)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    // Hold the creator's allocation back in a Vesting PDA that claim_vested_keys releases
+    if let Some(params) = creator_vesting {
+        let vesting_info = ctx.accounts.vesting
+            .as_ref()
+            .ok_or(SolSocialError::MissingRequiredAccount)?
+            .to_account_info();
+        let (expected_vesting, vesting_bump) = Pubkey::find_program_address(
+            &[VESTING_SEED, creator.key().as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(vesting_info.key(), expected_vesting, SolSocialError::InvalidAccountSequence);
+
+        let vesting = Vesting::new(creator.key(), &params, clock.unix_timestamp, vesting_bump)?;
+        require_rent_funds(&creator.to_account_info(), &[Vesting::LEN], 0)?;
+        create_pda_account(
+            &creator.to_account_info(),
+            &vesting_info,
+            &ctx.accounts.system_program.to_account_info(),
+            Vesting::LEN,
+            &[VESTING_SEED, creator.key().as_ref(), &[vesting_bump]],
+        )?;
+        write_account(&vesting_info, &vesting)?;
+
+        emit!(CreatorVestingEvent {
+            keys_user: creator.key(),
+            amount: vesting.total_amount,
+            deposit: vesting_deposit,
+            cliff_at: vesting.cliff_at,
+            end_at: vesting.end_at,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Optionally sell the next keys through a Dutch auction before the curve opens
+    if let Some(params) = launch_auction {
+        let auction = LaunchAuction::new(&params, clock.unix_timestamp)?;
+        let last_key = user_keys.supply
+            .checked_add(params.keys_for_sale - 1)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
+        require!(last_key < curve_params.max_supply, SolSocialError::InvalidLaunchAuction);
+        // Bids fund the reserve, so the floor must cover the curve price of every auctioned key
+        require!(
+            params.floor_price >= calculate_price(&curve_params, last_key)?,
+            SolSocialError::InvalidLaunchAuction
+        );
+        user_keys.launch_auction = auction;
 
-    // Update total volume and fees collected (both start at 0)
-    user_keys.total_volume = 0;
-    user_keys.total_fees_collected = 0;
-    user_keys.creator_earnings = 0;
+        emit!(LaunchAuctionStartedEvent {
+            keys_user: creator.key(),
+            keys_for_sale: auction.keys_for_sale,
+            start_price: auction.start_price,
+            floor_price: auction.floor_price,
+            end_at: auction.end_at,
+            timestamp: clock.unix_timestamp,
+        });
+    }
 
-    // Set bonding curve parameters
-    user_keys.base_price = 1_000_000; // 0.001 SOL in lamports
-    user_keys.price_increment = 100_000; // 0.0001 SOL increment per key
+    // Or sell them at a fixed price to an allowlist; a market gets at most one launch phase
+    if let Some(params) = presale {
+        require!(launch_auction.is_none(), SolSocialError::InvalidPresale);
+        let sale = Presale::new(&params, clock.unix_timestamp)?;
+        let last_key = user_keys.supply
+            .checked_add(params.keys_for_sale - 1)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
+        require!(last_key < curve_params.max_supply, SolSocialError::InvalidPresale);
+        require!(
+            params.price >= calculate_price(&curve_params, last_key)?,
+            SolSocialError::InvalidPresale
+        );
+        user_keys.presale = sale;
+
+        emit!(PresaleStartedEvent {
+            keys_user: creator.key(),
+            merkle_root: sale.merkle_root,
+            price: sale.price,
+            max_per_wallet: sale.max_per_wallet,
+            keys_for_sale: sale.keys_for_sale,
+            end_at: sale.end_at,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    if let Some(params) = launch_guard {
+        user_keys.launch_guard = LaunchGuard::new(&params, clock.slot)?;
+    }
+
+    // List the market so it can be enumerated without scanning program accounts
+    let market_registry = &mut ctx.accounts.market_registry;
+    if market_registry.market_count == 0 {
+        market_registry.bump = ctx.bumps.market_registry;
+    }
+    let registry_page = &mut ctx.accounts.market_registry_page;
+    if registry_page.count == 0 {
+        registry_page.initialize(market_registry.current_page(), ctx.bumps.market_registry_page);
+    }
+    let registry_index = market_registry.register(registry_page, user_keys.key())?;
+
+    emit!(KeyMarketRegisteredEvent {
+        market: user_keys.key(),
+        keys_user: creator.key(),
+        index: registry_index,
+        page: registry_page.page_index,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(CurveParamsResolvedEvent {
+        keys_user: creator.key(),
+        preset: match curve {
+            Some(CurveChoice::Preset(preset)) => Some(preset),
+            Some(CurveChoice::Custom(_)) => None,
+            None => Some(CurvePreset::Standard),
+        },
+        params: curve_params,
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!(
         "Keys created for user: {}, initial supply: {}, initial price: {}",
         creator.key(),
-        user_keys.total_supply,
-        initial_price
+        user_keys.supply,
+        user_keys.price
     );
 
     Ok(())
 }
+
+#[event]
+pub struct CurveParamsResolvedEvent {
+    pub keys_user: Pubkey,
+    /// `None` when the creator supplied custom parameters
+    pub preset: Option<CurvePreset>,
+    pub params: BondingCurveParams,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KeyMarketRegisteredEvent {
+    pub market: Pubkey,
+    pub keys_user: Pubkey,
+    /// Position in creation order across all markets
+    pub index: u64,
+    pub page: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LaunchAuctionStartedEvent {
+    pub keys_user: Pubkey,
+    pub keys_for_sale: u64,
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub end_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PresaleStartedEvent {
+    pub keys_user: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub price: u64,
+    pub max_per_wallet: u64,
+    pub keys_for_sale: u64,
+    pub end_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorVestingEvent {
+    pub keys_user: Pubkey,
+    pub amount: u64,
+    /// Lamports the creator paid into the reserve for the allocation
+    pub deposit: u64,
+    pub cliff_at: i64,
+    pub end_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FounderAllocationEvent {
+    pub keys_user: Pubkey,
+    pub founder: Pubkey,
+    pub amount: u64,
+    /// Lamports the creator paid into the reserve for these keys
+    pub cost: u64,
+    pub timestamp: i64,
+}
 ```
\ No newline at end of file
@@ -0,0 +1,155 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, KeyLoan, UserKeys, KEY_HOLDER_SEED, KEY_LOAN_ESCROW_SEED, KEY_LOAN_SEED, ProtocolConfig, Feature};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(amount: u64, collateral_ratio_bps: u16, liquidation_ratio_bps: u16, fee_bps: u16, duration_seconds: i64, loan_id: u64)]
+pub struct CreateLoanOffer<'info> {
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    /// CHECK: The subject whose keys are lent
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(
+        init,
+        payer = lender,
+        space = KeyLoan::LEN,
+        seeds = [KEY_LOAN_SEED, lender.key().as_ref(), subject.key().as_ref(), &loan_id.to_le_bytes()],
+        bump,
+    )]
+    pub loan: Account<'info, KeyLoan>,
+
+    /// Holds the lent keys until a borrower takes them
+    #[account(
+        init,
+        payer = lender,
+        token::mint = mint,
+        token::authority = loan,
+        seeds = [KEY_LOAN_ESCROW_SEED, loan.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, lender.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = lender,
+    )]
+    pub lender_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Offer keys for borrowing. The keys are escrowed until a borrower posts collateral
+/// or the lender cancels.
+pub fn handler(
+    ctx: Context<CreateLoanOffer>,
+    amount: u64,
+    collateral_ratio_bps: u16,
+    liquidation_ratio_bps: u16,
+    fee_bps: u16,
+    duration_seconds: i64,
+    loan_id: u64,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let now = Clock::get()?.unix_timestamp;
+    let lender = ctx.accounts.lender.key();
+    let subject = ctx.accounts.subject.key();
+
+    let loan = &mut ctx.accounts.loan;
+    loan.initialize(
+        lender,
+        subject,
+        amount,
+        collateral_ratio_bps,
+        liquidation_ratio_bps,
+        fee_bps,
+        duration_seconds,
+        loan_id,
+        now,
+        ctx.bumps.loan,
+    )?;
+
+    let key_holder = &mut ctx.accounts.key_holder;
+    require!(
+        key_holder.amount >= amount && ctx.accounts.lender_token_account.amount >= amount,
+        SolSocialError::InsufficientKeys
+    );
+    key_holder.amount -= amount;
+
+    let keys_seeds = &[b"keys".as_ref(), subject.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.keys_account,
+        keys_signer,
+    );
+    let lender_token_info = ctx.accounts.lender_token_account.to_account_info();
+    freeze.thaw(&lender_token_info)?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.lender_token_account.to_account_info(),
+        to: ctx.accounts.escrow.to_account_info(),
+        authority: ctx.accounts.lender.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+    freeze.freeze(&lender_token_info)?;
+
+    emit!(LoanOfferCreatedEvent {
+        loan: loan.key(),
+        lender,
+        keys_user: subject,
+        amount,
+        collateral_ratio_bps,
+        liquidation_ratio_bps,
+        fee_bps,
+        duration_seconds,
+        loan_id,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct LoanOfferCreatedEvent {
+    pub loan: Pubkey,
+    pub lender: Pubkey,
+    pub keys_user: Pubkey,
+    pub amount: u64,
+    pub collateral_ratio_bps: u16,
+    pub liquidation_ratio_bps: u16,
+    pub fee_bps: u16,
+    pub duration_seconds: i64,
+    pub loan_id: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,164 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, OtcOffer, OrderSide, UserKeys, KEY_HOLDER_SEED, OTC_ESCROW_SEED, OTC_OFFER_SEED, ProtocolConfig, Feature};
+use crate::utils::revenue_share::system_transfer;
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(side: OrderSide, amount: u64, price: u64, taker: Option<Pubkey>, expires_at: i64, offer_id: u64)]
+pub struct CreateOtcOffer<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    /// CHECK: The subject whose keys the offer trades
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(
+        init,
+        payer = maker,
+        space = OtcOffer::LEN,
+        seeds = [OTC_OFFER_SEED, maker.key().as_ref(), subject.key().as_ref(), &offer_id.to_le_bytes()],
+        bump,
+    )]
+    pub offer: Account<'info, OtcOffer>,
+
+    /// Holds the offered keys for sell offers; buy offers escrow lamports in `offer` itself
+    #[account(
+        init,
+        payer = maker,
+        token::mint = mint,
+        token::authority = offer,
+        seeds = [OTC_ESCROW_SEED, offer.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    /// Sell offers take keys out of this record; buy offers make sure it exists for the settlement
+    #[account(
+        init_if_needed,
+        payer = maker,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, maker.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = maker,
+    )]
+    pub maker_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Offer to buy or sell a block of keys at a negotiated total price, optionally to a single
+/// counterparty. The maker's side is escrowed up front so the taker can settle atomically.
+pub fn handler(
+    ctx: Context<CreateOtcOffer>,
+    side: OrderSide,
+    amount: u64,
+    price: u64,
+    taker: Option<Pubkey>,
+    expires_at: i64,
+    offer_id: u64,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let now = Clock::get()?.unix_timestamp;
+    let maker = ctx.accounts.maker.key();
+    let subject = ctx.accounts.subject.key();
+
+    let offer = &mut ctx.accounts.offer;
+    offer.initialize(maker, subject, taker, side, amount, price, offer_id, now, expires_at, ctx.bumps.offer)?;
+
+    let key_holder = &mut ctx.accounts.key_holder;
+    if key_holder.holder == Pubkey::default() {
+        **key_holder = KeyHolder::new(maker, subject);
+    }
+
+    match side {
+        OrderSide::Buy => {
+            system_transfer(
+                &ctx.accounts.maker.to_account_info(),
+                &offer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                price,
+            )?;
+        }
+        OrderSide::Sell => {
+            require!(
+                key_holder.amount >= amount && ctx.accounts.maker_token_account.amount >= amount,
+                SolSocialError::InsufficientKeys
+            );
+            key_holder.amount -= amount;
+
+            let keys_seeds = &[b"keys".as_ref(), subject.as_ref(), &[ctx.bumps.keys_account]];
+            let keys_signer = &[&keys_seeds[..]];
+            let freeze = KeyFreeze::new(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.mint.to_account_info(),
+                &ctx.accounts.keys_account,
+                keys_signer,
+            );
+            let maker_token_info = ctx.accounts.maker_token_account.to_account_info();
+            freeze.thaw(&maker_token_info)?;
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.maker_token_account.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.maker.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+            token::transfer(cpi_ctx, amount)?;
+            freeze.freeze(&maker_token_info)?;
+        }
+    }
+
+    emit!(OtcOfferCreatedEvent {
+        offer: offer.key(),
+        maker,
+        keys_user: subject,
+        taker,
+        side,
+        amount,
+        price,
+        offer_id,
+        expires_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OtcOfferCreatedEvent {
+    pub offer: Pubkey,
+    pub maker: Pubkey,
+    pub keys_user: Pubkey,
+    pub taker: Option<Pubkey>,
+    pub side: OrderSide,
+    pub amount: u64,
+    pub price: u64,
+    pub offer_id: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
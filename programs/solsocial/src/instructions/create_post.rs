@@ -1,6 +1,13 @@
 ```rust
 use anchor_lang::prelude::*;
-use crate::state::{User, Post, Keys};
+use crate::state::{
+    compute_content_hash, User, Post, UserKeys, PostVisibility, MediaAttachment, ProtocolConfig,
+    Activity, ActivityTracker, RecentContentHashes, KeyHolder, MAX_MEDIA_URLS, Feature,
+    HashtagIndex, HASHTAG_INDEX_SEED, parse_hashtags, MAX_MENTIONS,
+};
+use crate::instructions::finalize_activity_day::touch_activity;
+use crate::utils::validation::is_valid_url;
+use crate::utils::accounts::{create_pda_account, require_rent_funds, write_account};
 use crate::error::SolSocialError;
 
 #[derive(Accounts)]
@@ -14,22 +21,53 @@ pub struct CreatePost<'info> {
     )]
     pub user: Account<'info, User>,
 
+    /// CHECK: Created by the handler once the author is known to afford it
     #[account(
-        init,
-        payer = authority,
-        space = Post::LEN + content.len() + 8,
+        mut,
         seeds = [b"post", author.key().as_ref(), &user.post_count.to_le_bytes()],
         bump
     )]
-    pub post: Account<'info, Post>,
+    pub post: UncheckedAccount<'info>,
 
     #[account(
         seeds = [b"keys", author.key().as_ref()],
-        bump = keys.bump
+        bump
+    )]
+    pub keys: Account<'info, UserKeys>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// The author's holding of their own keys, required for gated posts when a minimum self-hold is set
+    #[account(
+        seeds = [b"key_holder", author.key().as_ref(), author.key().as_ref()],
+        bump
+    )]
+    pub author_key_holder: Option<Account<'info, KeyHolder>>,
+
+    /// The author's recent post fingerprints, consulted while dedup is enabled
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RecentContentHashes::LEN,
+        seeds = [b"recent_hashes", author.key().as_ref()],
+        bump
     )]
-    pub keys: Account<'info, Keys>,
+    pub recent_hashes: Account<'info, RecentContentHashes>,
 
-    /// CHECK: This is the user whose profile is being posted to
+    /// Opt-in activity heartbeat bucket
+    #[account(
+        mut,
+        seeds = [b"activity_metrics"],
+        bump = activity.bump
+    )]
+    pub activity: Option<Account<'info, ActivityTracker>>,
+
+    /// CHECK: The posting user; must be the signing authority so posts can't be attributed to others
+    #[account(address = authority.key() @ SolSocialError::Unauthorized)]
     pub author: AccountInfo<'info>,
 
     #[account(mut)]
@@ -38,60 +76,242 @@ pub struct CreatePost<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn create_post(
-    ctx: Context<CreatePost>,
+/// `remaining_accounts` hold the `HashtagIndex` PDA of each hashtag in `content`, in
+/// the order `parse_hashtags` returns them (missing indexes are created), followed by
+/// the `User` PDA of each mentioned wallet. With `expires_in` set, the post is a story
+/// that anyone can close with `expire_post` once that many seconds have passed.
+#[allow(clippy::too_many_arguments)]
+pub fn create_post<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreatePost<'info>>,
     content: String,
-    media_url: Option<String>,
+    media: Vec<MediaAttachment>,
     post_type: u8, // 0: text, 1: image, 2: video
+    required_keys: u64,
+    visibility: Option<PostVisibility>,
+    mentions: Vec<Pubkey>,
+    expires_in: Option<i64>,
 ) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Posting)?;
     require!(content.len() <= 280, SolSocialError::ContentTooLong);
     require!(content.len() > 0, SolSocialError::ContentEmpty);
+
+    let hashtags = parse_hashtags(&content);
+    require!(
+        ctx.remaining_accounts.len() == hashtags.len() + mentions.len(),
+        SolSocialError::MissingRequiredAccount
+    );
+    let (hashtag_accounts, mention_accounts) = ctx.remaining_accounts.split_at(hashtags.len());
+    let new_indexes = hashtag_accounts
+        .iter()
+        .filter(|info| info.owner != ctx.program_id)
+        .count();
+
+    // The hash window was funded on the way in; the post and any new hashtag indexes are
+    // created below, so check up front that the author can cover them and stay rent exempt
+    let post_space = Post::LEN + content.len() + 8;
+    let mut new_spaces = vec![HashtagIndex::LEN; new_indexes];
+    new_spaces.push(post_space);
+    require_rent_funds(&ctx.accounts.authority.to_account_info(), &new_spaces, 0)?;
     
-    if let Some(ref url) = media_url {
-        require!(url.len() <= 200, SolSocialError::MediaUrlTooLong);
+    require!(media.len() <= MAX_MEDIA_URLS, SolSocialError::TooManyAccounts);
+
+    // Sizes are declared by the author and stored on the post, where indexers can check them
+    // against the hosted files; the cap budgets declared bytes, so nothing may declare zero
+    let mut media_bytes = 0u64;
+    for attachment in media.iter() {
+        require!(attachment.url.len() <= MediaAttachment::MAX_URL_LENGTH, SolSocialError::MediaUrlTooLong);
+        require!(is_valid_url(&attachment.url), SolSocialError::InvalidMediaUrl);
+        require!(
+            attachment.media_type.len() <= MediaAttachment::MAX_MEDIA_TYPE_LENGTH,
+            SolSocialError::InvalidMetadata
+        );
+        require!(attachment.size > 0, SolSocialError::InvalidMetadata);
+        media_bytes = media_bytes
+            .checked_add(attachment.size)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
     }
 
+    require!(
+        ctx.accounts.config.media_within_cap(ctx.accounts.user.total_media_bytes, media_bytes),
+        SolSocialError::MetadataTooLarge
+    );
+
     require!(post_type <= 2, SolSocialError::InvalidPostType);
 
-    let post = &mut ctx.accounts.post;
+    // Gated posts need skin in the game: the author must still hold their own keys
+    let min_self_hold = ctx.accounts.user.min_self_hold;
+    if required_keys > 0 && min_self_hold > 0 {
+        let held = ctx.accounts.author_key_holder.as_ref().map_or(0, |holder| holder.amount);
+        if !ctx.accounts.user.meets_self_hold(held) {
+            msg!("Gated posts require holding {} of your own keys; holding {}", min_self_hold, held);
+            return Err(SolSocialError::SelfHoldTooLow.into());
+        }
+    }
+
+    let clock = Clock::get()?;
+
+    // Reject identical reposts within the configured window
+    let content_hash = if ctx.accounts.config.content_dedup_enabled() {
+        let recent_hashes = &mut ctx.accounts.recent_hashes;
+        if recent_hashes.author == Pubkey::default() {
+            recent_hashes.initialize(ctx.accounts.author.key(), ctx.bumps.recent_hashes);
+        }
+
+        let hash = compute_content_hash(&content, &media);
+        require!(
+            !recent_hashes.is_recent_duplicate(
+                &hash,
+                clock.unix_timestamp,
+                ctx.accounts.config.content_dedup_window_seconds,
+            ),
+            SolSocialError::SpamDetected
+        );
+        recent_hashes.record(hash, clock.unix_timestamp);
+        Some(hash)
+    } else {
+        None
+    };
+
     let user = &mut ctx.accounts.user;
     let keys = &ctx.accounts.keys;
-    let clock = Clock::get()?;
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
 
-    // Initialize post
-    post.author = ctx.accounts.author.key();
-    post.content = content;
-    post.media_url = media_url;
-    post.post_type = post_type;
-    post.timestamp = clock.unix_timestamp;
-    post.likes = 0;
-    post.comments = 0;
-    post.shares = 0;
-    post.tips_received = 0;
-    post.is_premium = false;
-    post.bump = ctx.bumps.post;
-
-    // Check if this should be a premium post (requires holding keys)
-    if keys.total_supply > 0 {
-        post.is_premium = true;
+    let post_info = ctx.accounts.post.to_account_info();
+    let author_key = ctx.accounts.author.key();
+    create_pda_account(
+        &authority,
+        &post_info,
+        &system_program,
+        post_space,
+        &[b"post", author_key.as_ref(), &user.post_count.to_le_bytes(), &[ctx.bumps.post]],
+    )?;
+
+    let mut post = Post {
+        author: author_key,
+        content,
+        media,
+        timestamp: clock.unix_timestamp,
+        likes: 0,
+        comments: 0,
+        comment_count: 0,
+        shares: 0,
+        // Posts from a market with keys in circulation count as premium
+        is_premium: keys.supply > 0,
+        required_keys,
+        revenue_generated: 0,
+        tips_received: 0,
+        visibility: user.resolve_post_visibility(visibility),
+        gated_market: if required_keys > 0 { Some(keys.key()) } else { None },
+        content_hash,
+        content_uri: None,
+        content_len: 0,
+        original_post: None,
+        nft_mint: None,
+        expires_at: None,
+        thread_root: None,
+        thread_index: 0,
+        thread_length: 0,
+        bump: ctx.bumps.post,
+    };
+    if let Some(lifetime) = expires_in {
+        post.set_lifetime(clock.unix_timestamp, lifetime)?;
     }
+    write_account(&post_info, &post)?;
+    let post_key = post_info.key();
 
     // Update user stats
     user.post_count = user.post_count.checked_add(1).ok_or(SolSocialError::Overflow)?;
+    user.add_media_bytes(media_bytes);
+    touch_activity(ctx.accounts.activity.as_mut(), user.last_activity, Activity::Post)?;
     user.last_activity = clock.unix_timestamp;
 
+    for (tag, index_info) in hashtags.iter().zip(hashtag_accounts.iter()) {
+        let (expected_index, index_bump) =
+            Pubkey::find_program_address(&[HASHTAG_INDEX_SEED, tag.as_bytes()], ctx.program_id);
+        require_keys_eq!(index_info.key(), expected_index, SolSocialError::InvalidAccountSequence);
+
+        let mut index = if index_info.owner == ctx.program_id {
+            Account::<HashtagIndex>::try_from(index_info)?.into_inner()
+        } else {
+            create_pda_account(
+                &authority,
+                index_info,
+                &system_program,
+                HashtagIndex::LEN,
+                &[HASHTAG_INDEX_SEED, tag.as_bytes(), &[index_bump]],
+            )?;
+            HashtagIndex::new(tag.clone(), clock.unix_timestamp, index_bump)
+        };
+        index.record_use(post_key, clock.unix_timestamp)?;
+        write_account(index_info, &index)?;
+
+        emit!(HashtagUsedEvent {
+            tag: index.tag.clone(),
+            hashtag_index: index_info.key(),
+            post: post_key,
+            author: post.author,
+            usage_count: index.usage_count,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    notify_mentions(&mentions, mention_accounts, ctx.program_id, post.author, post_key, None, clock.unix_timestamp)?;
+
     // Emit event
     emit!(PostCreated {
-        post: post.key(),
+        post: post_key,
         author: post.author,
         content: post.content.clone(),
         timestamp: post.timestamp,
         is_premium: post.is_premium,
+        visibility: post.visibility.clone(),
+        media_bytes,
+        total_media_bytes: user.total_media_bytes,
+        gated_market: post.gated_market,
+        content_hash: post.content_hash,
+        min_self_hold,
+        expires_at: post.expires_at,
     });
 
     Ok(())
 }
 
+/// Check each mentioned wallet against its `User` PDA in `user_accounts`, in the same
+/// order, and emit a notification for it
+pub fn notify_mentions(
+    mentions: &[Pubkey],
+    user_accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    author: Pubkey,
+    post: Pubkey,
+    comment: Option<Pubkey>,
+    timestamp: i64,
+) -> Result<()> {
+    require!(mentions.len() <= MAX_MENTIONS, SolSocialError::InvalidMentions);
+    require!(user_accounts.len() == mentions.len(), SolSocialError::MissingRequiredAccount);
+
+    for (index, (mentioned, user_info)) in mentions.iter().zip(user_accounts).enumerate() {
+        require!(!mentions[..index].contains(mentioned), SolSocialError::InvalidMentions);
+
+        let (expected_user, _) = Pubkey::find_program_address(&[b"user", mentioned.as_ref()], program_id);
+        require_keys_eq!(user_info.key(), expected_user, SolSocialError::InvalidAccountSequence);
+        require!(
+            user_info.owner == program_id && !user_info.data_is_empty(),
+            SolSocialError::MentionedUserNotFound
+        );
+
+        emit!(UserMentionedEvent {
+            mentioned: *mentioned,
+            author,
+            post,
+            comment,
+            timestamp,
+        });
+    }
+    Ok(())
+}
+
 #[event]
 pub struct PostCreated {
     pub post: Pubkey,
@@ -99,5 +319,34 @@ pub struct PostCreated {
     pub content: String,
     pub timestamp: i64,
     pub is_premium: bool,
+    pub visibility: PostVisibility,
+    pub media_bytes: u64,
+    pub total_media_bytes: u64,
+    pub gated_market: Option<Pubkey>,
+    pub content_hash: Option<[u8; 32]>,
+    pub min_self_hold: u64,
+    pub expires_at: Option<i64>,
+}
+
+#[event]
+pub struct UserMentionedEvent {
+    /// The mentioned wallet
+    pub mentioned: Pubkey,
+    pub author: Pubkey,
+    pub post: Pubkey,
+    /// Set when the mention is in a comment on `post`
+    pub comment: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HashtagUsedEvent {
+    pub tag: String,
+    pub hashtag_index: Pubkey,
+    pub post: Pubkey,
+    pub author: Pubkey,
+    /// Posts using the tag, this one included
+    pub usage_count: u64,
+    pub timestamp: i64,
 }
 ```
\ No newline at end of file
@@ -0,0 +1,124 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{User, Post, PostVisibility, ProtocolConfig, RecentContentHashes, Activity, ActivityTracker, Feature};
+use crate::instructions::finalize_activity_day::touch_activity;
+use crate::utils::validation::is_valid_url;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CreatePostHashed<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump = user.bump,
+        has_one = authority @ SolSocialError::Unauthorized
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Post::SPACE,
+        seeds = [b"post", authority.key().as_ref(), &user.post_count.to_le_bytes()],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+
+    /// The author's recent post fingerprints, consulted while dedup is enabled
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RecentContentHashes::LEN,
+        seeds = [b"recent_hashes", authority.key().as_ref()],
+        bump
+    )]
+    pub recent_hashes: Account<'info, RecentContentHashes>,
+
+    /// Opt-in activity heartbeat bucket
+    #[account(
+        mut,
+        seeds = [b"activity_metrics"],
+        bump = activity.bump
+    )]
+    pub activity: Option<Account<'info, ActivityTracker>>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Publish a post whose body lives on Arweave or IPFS at `uri`. Only the body's
+/// `compute_content_hash` fingerprint and byte length go on-chain; readers check the
+/// fetched body against them with `Post::verify_body` or `verify_post_body`.
+pub fn handler(
+    ctx: Context<CreatePostHashed>,
+    content_hash: [u8; 32],
+    uri: String,
+    content_len: u32,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Posting)?;
+    require!(content_len > 0, SolSocialError::PostContentEmpty);
+    require!(
+        uri.len() <= Post::MAX_CONTENT_URI_LENGTH && is_valid_url(&uri),
+        SolSocialError::InvalidMediaUrl
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // Same dedup window as on-chain posts, keyed on the body fingerprint
+    if ctx.accounts.config.content_dedup_enabled() {
+        let recent_hashes = &mut ctx.accounts.recent_hashes;
+        if recent_hashes.author == Pubkey::default() {
+            recent_hashes.initialize(ctx.accounts.authority.key(), ctx.bumps.recent_hashes);
+        }
+        require!(
+            !recent_hashes.is_recent_duplicate(&content_hash, now, ctx.accounts.config.content_dedup_window_seconds),
+            SolSocialError::SpamDetected
+        );
+        recent_hashes.record(content_hash, now);
+    }
+
+    let author = ctx.accounts.authority.key();
+    let user = &mut ctx.accounts.user;
+    let post = &mut ctx.accounts.post;
+    let visibility = user.resolve_post_visibility(None);
+    post.initialize(author, String::new(), Vec::new(), false, 0, visibility, ctx.bumps.post)?;
+    post.content_hash = Some(content_hash);
+    post.content_uri = Some(uri);
+    post.content_len = content_len;
+
+    user.post_count = user.post_count.checked_add(1).ok_or(SolSocialError::ArithmeticOverflow)?;
+    touch_activity(ctx.accounts.activity.as_mut(), user.last_activity, Activity::Post)?;
+    user.last_activity = now;
+
+    emit!(HashedPostCreatedEvent {
+        post: post.key(),
+        author,
+        content_hash,
+        uri: post.content_uri.clone().unwrap_or_default(),
+        content_len,
+        visibility: post.visibility.clone(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct HashedPostCreatedEvent {
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub content_hash: [u8; 32],
+    pub uri: String,
+    pub content_len: u32,
+    pub visibility: PostVisibility,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
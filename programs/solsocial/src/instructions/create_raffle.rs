@@ -0,0 +1,114 @@
+```rust
+use anchor_lang::prelude::*;
+use switchboard_on_demand::accounts::RandomnessAccountData;
+use crate::state::{KeyHolder, Raffle, UserKeys, RAFFLE_SEED, SWITCHBOARD_PROGRAM_ID};
+use crate::utils::revenue_share::system_transfer;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(raffle_id: u64)]
+pub struct CreateRaffle<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"keys", creator.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Raffle::LEN,
+        seeds = [RAFFLE_SEED, creator.key().as_ref(), &raffle_id.to_le_bytes()],
+        bump,
+    )]
+    pub raffle: Account<'info, Raffle>,
+
+    /// CHECK: Parsed as Switchboard randomness in the handler
+    #[account(owner = SWITCHBOARD_PROGRAM_ID @ SolSocialError::InvalidAccountOwner)]
+    pub randomness_account: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Fund a prize for the creator's key holders and commit to a Switchboard randomness
+/// account. Entrants and their weights are snapshotted now, before the randomness can be
+/// known, so trading after the reveal can't move the odds.
+///
+/// `remaining_accounts` are the market's `KeyHolder` accounts, one per current holder,
+/// ordered by holder wallet.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateRaffle<'info>>,
+    raffle_id: u64,
+    prize: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let creator = ctx.accounts.creator.key();
+
+    let randomness = RandomnessAccountData::parse(ctx.accounts.randomness_account.data.borrow())
+        .map_err(|_| error!(SolSocialError::RandomnessNotFresh))?;
+    require!(
+        randomness.seed_slot == clock.slot.saturating_sub(1),
+        SolSocialError::RandomnessNotFresh
+    );
+
+    // Every holder has to enter, or the creator could pick who gets a ticket
+    let remaining = ctx.remaining_accounts;
+    require!(
+        remaining.len() as u64 == ctx.accounts.keys_account.holders_count,
+        SolSocialError::MissingRequiredAccount
+    );
+    let mut entries = Vec::with_capacity(remaining.len());
+    for info in remaining {
+        let key_holder = Account::<KeyHolder>::try_from(info)?;
+        require_keys_eq!(key_holder.keys_user, creator, SolSocialError::InvalidAccountOwner);
+        entries.push((key_holder.holder, key_holder.amount));
+    }
+
+    let raffle = &mut ctx.accounts.raffle;
+    raffle.initialize(
+        creator,
+        raffle_id,
+        prize,
+        ctx.accounts.randomness_account.key(),
+        &entries,
+        clock.unix_timestamp,
+        ctx.bumps.raffle,
+    )?;
+
+    system_transfer(
+        &ctx.accounts.creator.to_account_info(),
+        &raffle.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        prize,
+    )?;
+
+    emit!(RaffleCreatedEvent {
+        raffle: raffle.key(),
+        creator,
+        raffle_id,
+        prize,
+        randomness_account: raffle.randomness_account,
+        entrant_count: raffle.entrant_count,
+        total_weight: raffle.total_weight,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RaffleCreatedEvent {
+    pub raffle: Pubkey,
+    pub creator: Pubkey,
+    pub raffle_id: u64,
+    pub prize: u64,
+    pub randomness_account: Pubkey,
+    pub entrant_count: u8,
+    /// Keys held across all entrants at creation
+    pub total_weight: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,122 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{CollaboratorShare, EarningsVault, ProtocolConfig, RevenueShare, UserKeys, EARNINGS_VAULT_SEED, REVENUE_SHARE_SEED, Feature};
+use crate::utils::accounts::{create_pda_account, require_rent_funds, write_account};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CreateSharedKeys<'info> {
+    /// The lead creator, whose market becomes the shared one
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"keys", creator.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Keeps collecting the market's creator fees, now on behalf of every collaborator
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, creator.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = RevenueShare::LEN,
+        seeds = [REVENUE_SHARE_SEED, creator.key().as_ref()],
+        bump,
+    )]
+    pub revenue_share: Account<'info, RevenueShare>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Share the creator's brand-new market with the collaborators in `shares`; sent right after
+/// `create_keys`, in the same transaction, it launches a joint market. `remaining_accounts`
+/// holds, for each collaborator after the lead, their signing wallet and their earnings vault,
+/// which is opened here if they don't have one yet.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateSharedKeys<'info>>,
+    shares: Vec<CollaboratorShare>,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    require!(ctx.accounts.keys_account.trade_count == 0, SolSocialError::InvalidRevenueShare);
+
+    let now = Clock::get()?.unix_timestamp;
+    let creator = ctx.accounts.creator.key();
+    ctx.accounts.revenue_share.initialize(creator, &shares, now, ctx.bumps.revenue_share)?;
+
+    require!(
+        ctx.remaining_accounts.len() == 2 * (shares.len() - 1),
+        SolSocialError::MissingRequiredAccount
+    );
+    for (share, accounts) in shares[1..].iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (wallet, vault_info) = (&accounts[0], &accounts[1]);
+        // Every collaborator signs up to the split
+        require_keys_eq!(wallet.key(), share.collaborator, SolSocialError::InvalidAccountSequence);
+        require!(wallet.is_signer, SolSocialError::Unauthorized);
+
+        let (expected_vault, vault_bump) = Pubkey::find_program_address(
+            &[EARNINGS_VAULT_SEED, share.collaborator.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(vault_info.key(), expected_vault, SolSocialError::InvalidAccountSequence);
+        if vault_info.data_is_empty() {
+            require_rent_funds(&ctx.accounts.creator.to_account_info(), &[EarningsVault::LEN], 0)?;
+            create_pda_account(
+                &ctx.accounts.creator.to_account_info(),
+                vault_info,
+                &ctx.accounts.system_program.to_account_info(),
+                EarningsVault::LEN,
+                &[EARNINGS_VAULT_SEED, share.collaborator.as_ref(), &[vault_bump]],
+            )?;
+            let mut vault = EarningsVault {
+                creator: share.collaborator,
+                total_accrued: 0,
+                total_claimed: 0,
+                last_claimed_at: 0,
+                bump: vault_bump,
+                shared: false,
+                distributed_through: 0,
+            };
+            vault.initialize(share.collaborator, vault_bump);
+            write_account(vault_info, &vault)?;
+        } else {
+            // A split paid into another shared vault would be split again
+            let vault = Account::<EarningsVault>::try_from(vault_info)?;
+            require!(!vault.shared, SolSocialError::InvalidRevenueShare);
+        }
+    }
+
+    // Fees collected before the market was shared stay the lead's alone
+    ctx.accounts.earnings_vault.share();
+
+    emit!(SharedKeysCreatedEvent {
+        keys_user: creator,
+        revenue_share: ctx.accounts.revenue_share.key(),
+        shares,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SharedKeysCreatedEvent {
+    pub keys_user: Pubkey,
+    pub revenue_share: Pubkey,
+    pub shares: Vec<CollaboratorShare>,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,97 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{User, Post, ProtocolConfig, Activity, ActivityTracker, Feature};
+use crate::instructions::finalize_activity_day::touch_activity;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct CreateThreadPost<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump = user.bump,
+        has_one = authority @ SolSocialError::Unauthorized
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Post::SPACE,
+        seeds = [b"post", authority.key().as_ref(), &user.post_count.to_le_bytes()],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+
+    /// First post of the thread, holding its length
+    #[account(
+        mut,
+        constraint = thread_root.author == authority.key() @ SolSocialError::InvalidThreadRoot,
+    )]
+    pub thread_root: Account<'info, Post>,
+
+    /// Opt-in activity heartbeat bucket
+    #[account(
+        mut,
+        seeds = [b"activity_metrics"],
+        bump = activity.bump
+    )]
+    pub activity: Option<Account<'info, ActivityTracker>>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Append a post to one of the author's threads. The root hands out the next index,
+/// so concurrent appends can't claim the same position.
+pub fn handler(ctx: Context<CreateThreadPost>, content: String) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Posting)?;
+    require!(!content.trim().is_empty(), SolSocialError::PostContentEmpty);
+
+    let author = ctx.accounts.authority.key();
+    let root = &mut ctx.accounts.thread_root;
+    let thread_index = root.append_to_thread()?;
+
+    let user = &mut ctx.accounts.user;
+    let post = &mut ctx.accounts.post;
+    let visibility = root.visibility.clone();
+    post.initialize(author, content, Vec::new(), root.is_premium, root.required_keys, visibility, ctx.bumps.post)?;
+    post.gated_market = root.gated_market;
+    post.thread_root = Some(root.key());
+    post.thread_index = thread_index;
+
+    let now = Clock::get()?.unix_timestamp;
+    user.post_count = user.post_count.checked_add(1).ok_or(SolSocialError::ArithmeticOverflow)?;
+    touch_activity(ctx.accounts.activity.as_mut(), user.last_activity, Activity::Post)?;
+    user.last_activity = now;
+
+    emit!(ThreadPostCreatedEvent {
+        post: post.key(),
+        author,
+        thread_root: root.key(),
+        thread_index,
+        content: post.content.clone(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ThreadPostCreatedEvent {
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub thread_root: Pubkey,
+    pub thread_index: u32,
+    pub content: String,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,146 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Approve, Mint, Token, TokenAccount};
+use crate::state::{KeyHolder, TriggerKind, TriggerOrder, UserKeys, KEY_HOLDER_SEED, TRIGGER_ORDER_SEED, ProtocolConfig, Feature};
+use crate::utils::key_token::KeyFreeze;
+use crate::utils::revenue_share::system_transfer;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(kind: TriggerKind, trigger_price: u64, amount: u64, min_proceeds: u64, order_id: u64)]
+pub struct CreateTriggerOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: The subject whose keys the order sells
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Freezes and thaws the owner's key token account
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = TriggerOrder::LEN,
+        seeds = [TRIGGER_ORDER_SEED, owner.key().as_ref(), subject.key().as_ref(), &order_id.to_le_bytes()],
+        bump,
+    )]
+    pub order: Account<'info, TriggerOrder>,
+
+    /// The order's keys come out of this record until it executes or is cancelled
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, owner.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    /// Delegates the order's keys to it so a keeper can burn them. SPL token accounts hold a
+    /// single delegate, so this replaces any earlier sell or trigger order's delegation.
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Place a stop-loss or take-profit sell of `amount` keys, escrowing the keeper bounty.
+/// The sale only goes through if it pays the owner at least `min_proceeds` after fees.
+pub fn handler(
+    ctx: Context<CreateTriggerOrder>,
+    kind: TriggerKind,
+    trigger_price: u64,
+    amount: u64,
+    min_proceeds: u64,
+    order_id: u64,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    ctx.accounts.keys_account.require_lamport_settlement()?;
+    let now = Clock::get()?.unix_timestamp;
+    let owner = ctx.accounts.owner.key();
+    let subject = ctx.accounts.subject.key();
+
+    let order = &mut ctx.accounts.order;
+    order.initialize(owner, subject, kind, trigger_price, amount, min_proceeds, order_id, now, ctx.bumps.order)?;
+
+    let key_holder = &mut ctx.accounts.key_holder;
+    require!(
+        key_holder.amount >= amount && ctx.accounts.owner_token_account.amount >= amount,
+        SolSocialError::InsufficientKeys
+    );
+    key_holder.amount -= amount;
+
+    let keys_seeds = &[b"keys".as_ref(), subject.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.keys_account,
+        keys_signer,
+    );
+    let owner_token_info = ctx.accounts.owner_token_account.to_account_info();
+
+    freeze.thaw(&owner_token_info)?;
+    let cpi_accounts = Approve {
+        to: owner_token_info.clone(),
+        delegate: order.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::approve(cpi_ctx, amount)?;
+    freeze.freeze(&owner_token_info)?;
+
+    system_transfer(
+        &ctx.accounts.owner.to_account_info(),
+        &order.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        order.keeper_bounty,
+    )?;
+
+    emit!(TriggerOrderCreatedEvent {
+        order: order.key(),
+        owner,
+        keys_user: subject,
+        kind,
+        trigger_price,
+        amount,
+        min_proceeds,
+        order_id,
+        keeper_bounty: order.keeper_bounty,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TriggerOrderCreatedEvent {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub keys_user: Pubkey,
+    pub kind: TriggerKind,
+    pub trigger_price: u64,
+    pub amount: u64,
+    pub min_proceeds: u64,
+    pub order_id: u64,
+    pub keeper_bounty: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
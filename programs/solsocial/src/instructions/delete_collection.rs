@@ -0,0 +1,43 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{Collection, COLLECTION_SEED};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct DeleteCollection<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ SolSocialError::Unauthorized,
+        seeds = [COLLECTION_SEED, owner.key().as_ref(), collection.name.as_bytes()],
+        bump = collection.bump,
+    )]
+    pub collection: Account<'info, Collection>,
+}
+
+/// Close an empty collection and reclaim its rent
+pub fn handler(ctx: Context<DeleteCollection>) -> Result<()> {
+    let collection = &ctx.accounts.collection;
+    require!(collection.bookmark_count == 0, SolSocialError::CollectionNotEmpty);
+
+    emit!(CollectionDeletedEvent {
+        collection: collection.key(),
+        owner: collection.owner,
+        name: collection.name.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CollectionDeletedEvent {
+    pub collection: Pubkey,
+    pub owner: Pubkey,
+    pub name: String,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
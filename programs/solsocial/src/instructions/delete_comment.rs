@@ -0,0 +1,71 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{Comment, Post, COMMENT_SEED};
+use crate::utils::accounts::write_account;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct DeleteComment<'info> {
+    #[account(
+        mut,
+        address = comment.post @ SolSocialError::InvalidAccountOwner,
+    )]
+    pub post: Account<'info, Post>,
+
+    #[account(
+        mut,
+        close = author,
+        has_one = author @ SolSocialError::Unauthorized,
+        seeds = [COMMENT_SEED, post.key().as_ref(), &comment.comment_id.to_le_bytes()],
+        bump = comment.bump
+    )]
+    pub comment: Account<'info, Comment>,
+
+    /// CHECK: The comment this one replies to, required for replies; may already be deleted
+    #[account(mut)]
+    pub parent_comment: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub author: Signer<'info>,
+}
+
+/// Delete a comment and refund its rent. Replies stay up and keep pointing at the
+/// deleted comment, so clients can show the thread with a placeholder.
+pub fn handler(ctx: Context<DeleteComment>) -> Result<()> {
+    let comment = &ctx.accounts.comment;
+
+    if let Some(parent_key) = comment.parent_comment {
+        let parent_info = ctx.accounts.parent_comment
+            .as_ref()
+            .ok_or(SolSocialError::MissingRequiredAccount)?;
+        require_keys_eq!(parent_info.key(), parent_key, SolSocialError::InvalidAccountOwner);
+
+        // A deleted parent has been handed back to the system program and has nothing to update
+        if parent_info.owner == ctx.program_id {
+            let mut parent = Comment::try_deserialize(&mut &parent_info.try_borrow_data()?[..])?;
+            parent.reply_count = parent.reply_count.saturating_sub(1);
+            write_account(parent_info, &parent)?;
+        }
+    }
+    ctx.accounts.post.remove_comment();
+
+    emit!(CommentDeletedEvent {
+        post: comment.post,
+        comment: comment.key(),
+        author: comment.author,
+        comment_id: comment.comment_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CommentDeletedEvent {
+    pub post: Pubkey,
+    pub comment: Pubkey,
+    pub author: Pubkey,
+    pub comment_id: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
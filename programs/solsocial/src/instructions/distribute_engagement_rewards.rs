@@ -0,0 +1,144 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{UserKeys, EngagementScore};
+use crate::utils::revenue_share::{calculate_fee, distribute_activity_rewards};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct DistributeEngagementRewards<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", creator.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Kick back `kickback_bps` of the creator earnings accrued since the last payout
+/// to the engagers passed through `remaining_accounts`, pro rata to their scores.
+///
+/// Accounts come in pairs: the engager's `EngagementScore` followed by their wallet.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeEngagementRewards<'info>>,
+    kickback_bps: u16,
+) -> Result<()> {
+    require!(
+        kickback_bps > 0 && kickback_bps <= EngagementScore::MAX_KICKBACK_BPS,
+        SolSocialError::InvalidFeePercentage
+    );
+
+    let creator = ctx.accounts.creator.key();
+    let keys_account = &mut ctx.accounts.keys_account;
+
+    let pending = keys_account.creator_earnings
+        .checked_sub(keys_account.engagement_rewarded_earnings)
+        .ok_or(SolSocialError::MathOverflow)?;
+    let reward_amount = calculate_fee(pending, kickback_bps)?;
+    require!(reward_amount > 0, SolSocialError::NothingToDistribute);
+
+    let remaining = ctx.remaining_accounts;
+    require!(
+        !remaining.is_empty() && remaining.len() % 2 == 0,
+        SolSocialError::InvalidAccountSequence
+    );
+    require!(
+        remaining.len() / 2 <= EngagementScore::MAX_RECIPIENTS,
+        SolSocialError::TooManyAccounts
+    );
+
+    let mut engagements = Vec::with_capacity(remaining.len() / 2);
+    for pair in remaining.chunks(2) {
+        let engagement = Account::<EngagementScore>::try_from(&pair[0])?;
+        require!(
+            engagement.creator == creator && engagement.user == pair[1].key(),
+            SolSocialError::InvalidAccountOwner
+        );
+        engagements.push((engagement, &pair[1]));
+    }
+
+    let total_score: u64 = engagements.iter().map(|(engagement, _)| engagement.score).sum();
+    require!(total_score > 0, SolSocialError::NothingToDistribute);
+
+    // Same pro-rata rounding as distribute_activity_rewards, so the funded total matches the payouts
+    let payouts = engagements
+        .iter()
+        .map(|(engagement, _)| {
+            Ok(((engagement.score as u128)
+                .checked_mul(reward_amount as u128)
+                .ok_or(SolSocialError::MathOverflow)?
+                / total_score as u128) as u64)
+        })
+        .collect::<Result<Vec<u64>>>()?;
+    let funded: u64 = payouts.iter().sum();
+
+    // Move the kickback from the creator into the keys PDA, which then pays the engagers
+    let ix = anchor_lang::solana_program::system_instruction::transfer(
+        &creator,
+        &keys_account.key(),
+        funded,
+    );
+    anchor_lang::solana_program::program::invoke(
+        &ix,
+        &[
+            ctx.accounts.creator.to_account_info(),
+            keys_account.to_account_info(),
+        ],
+    )?;
+
+    let recipients = engagements
+        .iter()
+        .map(|(engagement, wallet)| (*wallet, engagement.score))
+        .collect::<Vec<_>>();
+    distribute_activity_rewards(&keys_account.to_account_info(), &recipients, reward_amount)?;
+
+    keys_account.engagement_rewarded_earnings = keys_account.creator_earnings;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    for ((mut engagement, wallet), amount) in engagements.into_iter().zip(payouts) {
+        let score = engagement.score;
+        engagement.settle(amount)?;
+        engagement.exit(ctx.program_id)?;
+
+        emit!(EngagementRewardEvent {
+            creator,
+            recipient: wallet.key(),
+            score,
+            amount,
+            timestamp,
+        });
+    }
+
+    emit!(EngagementRewardsDistributedEvent {
+        creator,
+        kickback_bps,
+        total_distributed: funded,
+        total_score,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EngagementRewardEvent {
+    pub creator: Pubkey,
+    pub recipient: Pubkey,
+    pub score: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EngagementRewardsDistributedEvent {
+    pub creator: Pubkey,
+    pub kickback_bps: u16,
+    pub total_distributed: u64,
+    pub total_score: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
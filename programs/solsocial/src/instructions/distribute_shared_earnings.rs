@@ -0,0 +1,86 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{EarningsVault, RevenueShare, EARNINGS_VAULT_SEED, REVENUE_SHARE_SEED};
+use crate::utils::accounts::move_lamports;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct DistributeSharedEarnings<'info> {
+    /// CHECK: The lead creator of the shared market
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(
+        mut,
+        seeds = [REVENUE_SHARE_SEED, subject.key().as_ref()],
+        bump = revenue_share.bump,
+    )]
+    pub revenue_share: Account<'info, RevenueShare>,
+}
+
+/// Pay each collaborator after the lead their split of the creator fees the shared market
+/// collected since the last distribution, into their earnings vault. Anyone may call it;
+/// `remaining_accounts` holds the collaborators' vaults in split order.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, DistributeSharedEarnings<'info>>) -> Result<()> {
+    let revenue_share = &ctx.accounts.revenue_share;
+    let collaborators = &revenue_share.collaborators[1..revenue_share.collaborator_count as usize];
+    require!(
+        ctx.remaining_accounts.len() == collaborators.len(),
+        SolSocialError::MissingRequiredAccount
+    );
+
+    let amount = ctx.accounts.earnings_vault.undistributed();
+    require!(amount > 0, SolSocialError::NothingToClaim);
+    let cuts = revenue_share.split(amount)?;
+
+    let lead_vault = ctx.accounts.earnings_vault.to_account_info();
+    let mut paid_out = 0u64;
+    for ((collaborator, cut), vault_info) in collaborators.iter().zip(cuts).zip(ctx.remaining_accounts) {
+        let (expected_vault, _) = Pubkey::find_program_address(
+            &[EARNINGS_VAULT_SEED, collaborator.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(vault_info.key(), expected_vault, SolSocialError::InvalidAccountSequence);
+
+        let mut vault = Account::<EarningsVault>::try_from(vault_info)?;
+        vault.record_accrual(cut)?;
+        vault.exit(ctx.program_id)?;
+        move_lamports(&lead_vault, vault_info, cut)?;
+
+        paid_out = paid_out
+            .checked_add(cut)
+            .ok_or(SolSocialError::MathOverflow)?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.earnings_vault.record_distribution(paid_out, now)?;
+    ctx.accounts.revenue_share.record_distribution(paid_out)?;
+
+    emit!(SharedEarningsDistributedEvent {
+        keys_user: ctx.accounts.subject.key(),
+        amount,
+        paid_out,
+        total_distributed: ctx.accounts.revenue_share.total_distributed,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SharedEarningsDistributedEvent {
+    pub keys_user: Pubkey,
+    /// Creator fees split in this distribution
+    pub amount: u64,
+    /// Part of `amount` paid to collaborators other than the lead
+    pub paid_out: u64,
+    pub total_distributed: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,74 @@
+```rust
+use anchor_lang::prelude::*;
+use switchboard_on_demand::accounts::RandomnessAccountData;
+use crate::state::{Raffle, RAFFLE_SEED};
+use crate::utils::accounts::move_lamports;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct DrawRaffle<'info> {
+    /// Anyone may draw once the randomness is revealed
+    pub drawer: Signer<'info>,
+
+    /// Receives the raffle's rent when it closes
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = creator,
+        has_one = creator @ SolSocialError::InvalidAccountOwner,
+        has_one = randomness_account @ SolSocialError::InvalidAccountOwner,
+        seeds = [RAFFLE_SEED, creator.key().as_ref(), &raffle.raffle_id.to_le_bytes()],
+        bump = raffle.bump,
+    )]
+    pub raffle: Account<'info, Raffle>,
+
+    /// CHECK: Bound to the raffle at creation and parsed in the handler
+    pub randomness_account: AccountInfo<'info>,
+
+    /// The drawn entrant, found by simulating the reveal; checked against the draw
+    #[account(mut)]
+    pub winner: SystemAccount<'info>,
+}
+
+/// Draw the winner from the revealed randomness and pay out the prize. Switchboard only
+/// exposes the value in its reveal slot, so the reveal goes in the same transaction.
+pub fn handler(ctx: Context<DrawRaffle>) -> Result<()> {
+    let clock = Clock::get()?;
+    let randomness = RandomnessAccountData::parse(ctx.accounts.randomness_account.data.borrow())
+        .map_err(|_| error!(SolSocialError::RandomnessNotRevealed))?
+        .get_value(&clock)
+        .map_err(|_| error!(SolSocialError::RandomnessNotRevealed))?;
+
+    let raffle = &ctx.accounts.raffle;
+    let winner = raffle.pick(&randomness).ok_or(SolSocialError::InvalidRaffle)?;
+    require_keys_eq!(ctx.accounts.winner.key(), winner, SolSocialError::RaffleWinnerMismatch);
+
+    let prize = raffle.prize;
+    move_lamports(&raffle.to_account_info(), &ctx.accounts.winner.to_account_info(), prize)?;
+
+    emit!(RaffleDrawnEvent {
+        raffle: raffle.key(),
+        creator: raffle.creator,
+        raffle_id: raffle.raffle_id,
+        winner,
+        prize,
+        total_weight: raffle.total_weight,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RaffleDrawnEvent {
+    pub raffle: Pubkey,
+    pub creator: Pubkey,
+    pub raffle_id: u64,
+    pub winner: Pubkey,
+    pub prize: u64,
+    pub total_weight: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
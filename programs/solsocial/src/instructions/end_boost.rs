@@ -0,0 +1,56 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{Boost, BOOST_SEED};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct EndBoost<'info> {
+    /// Anyone can end an expired boost
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        close = booster,
+        has_one = booster @ SolSocialError::InvalidAccountOwner,
+        seeds = [BOOST_SEED, boost.post.as_ref(), booster.key().as_ref()],
+        bump = boost.bump,
+    )]
+    pub boost: Account<'info, Boost>,
+
+    /// CHECK: Booster wallet receiving the rent, checked against the boost
+    #[account(mut)]
+    pub booster: AccountInfo<'info>,
+}
+
+/// Close a boost whose promotion window has passed, refunding its rent to the booster
+pub fn handler(ctx: Context<EndBoost>) -> Result<()> {
+    let boost = &ctx.accounts.boost;
+    let now = Clock::get()?.unix_timestamp;
+    require!(!boost.is_active(now), SolSocialError::BoostStillActive);
+
+    emit!(BoostEndedEvent {
+        boost: boost.key(),
+        post: boost.post,
+        booster: boost.booster,
+        amount: boost.amount,
+        started_at: boost.started_at,
+        ends_at: boost.ends_at,
+        ended_by: ctx.accounts.caller.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BoostEndedEvent {
+    pub boost: Pubkey,
+    pub post: Pubkey,
+    pub booster: Pubkey,
+    pub amount: u64,
+    pub started_at: i64,
+    pub ends_at: i64,
+    pub ended_by: Pubkey,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
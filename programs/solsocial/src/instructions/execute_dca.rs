@@ -0,0 +1,272 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::{DcaSchedule, KeyHolder, ProtocolConfig, RewardPool, UserKeys, RecentTrades, PriceOracle, Portfolio, EarningsVault, DCA_SCHEDULE_SEED, KEY_HOLDER_SEED, EARNINGS_VAULT_SEED, PORTFOLIO_SEED, Feature};
+use crate::utils::bonding_curve::{calculate_buy_price, max_keys_for_budget};
+use crate::utils::accounts::{close_account, move_lamports};
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::key_token::KeyFreeze;
+use crate::utils::revenue_share::{calculate_fee, price_budget_before_fees};
+use crate::utils::trade::{record_market_trade, CurveTrade, MarketFeeds};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct ExecuteDca<'info> {
+    /// Any keeper, or a Clockwork thread; paid `DcaSchedule::KEEPER_FEE` per execution
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Receives the keys, and the schedule's rent once it runs dry
+    #[account(mut)]
+    pub owner: SystemAccount<'info>,
+
+    /// CHECK: The subject whose keys the schedule buys
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Collects the subject's creator fee
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(mut, address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = owner @ SolSocialError::InvalidAccountOwner,
+        seeds = [DCA_SCHEDULE_SEED, owner.key().as_ref(), subject.key().as_ref()],
+        bump = schedule.bump,
+    )]
+    pub schedule: Account<'info, DcaSchedule>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, owner.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = RecentTrades::LEN,
+        seeds = [b"recent_trades", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub recent_trades: Account<'info, RecentTrades>,
+
+    /// Price feed for other programs; every curve trade records into it
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = PriceOracle::LEN,
+        seeds = [b"price_oracle", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub price_oracle: Account<'info, PriceOracle>,
+
+    /// The owner's totals across every market
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = Portfolio::LEN,
+        seeds = [PORTFOLIO_SEED, owner.key().as_ref()],
+        bump,
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Run a due DCA interval: buy as many keys as the interval's budget covers at the curve
+/// price, fees charged on top as in `buy_keys`, and pay the keeper. A schedule left with
+/// nothing to spend closes, refunding its rent and dust to the owner.
+pub fn handler(ctx: Context<ExecuteDca>) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.schedule.is_due(now), SolSocialError::DcaNotDue);
+
+    let keys_account = &mut ctx.accounts.keys_account;
+    keys_account.require_lamport_settlement()?;
+    if !keys_account.is_trading_open(now) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::OutsideTradingHours,
+                price: keys_account.trading_open_at as u64,
+                limit: keys_account.trading_close_at as u64,
+                amount: 0,
+            },
+            SolSocialError::OperationNotAllowed,
+        ));
+    }
+    // Guarded launches only accept buy_keys, which enforces the per-wallet limits
+    require!(!keys_account.launch_guard.is_active(Clock::get()?.slot), SolSocialError::LaunchGuardActive);
+
+    let params = keys_account.curve_params;
+    // The interval's budget covers the fees too, so size the curve price to leave room for them
+    let fee_bps = params.buy_protocol_fee.saturating_add(params.buy_creator_fee);
+    let budget = price_budget_before_fees(ctx.accounts.schedule.next_budget(), fee_bps)?;
+    let (mut amount, mut cost) = max_keys_for_budget(&params, keys_account.supply, budget)?;
+
+    // Stop at the per-holder cap rather than failing the interval
+    let key_holder = &mut ctx.accounts.key_holder;
+    let room = params.holder_room(key_holder.amount);
+    require!(room > 0, SolSocialError::HolderCapReached);
+    if amount > room {
+        amount = room;
+        cost = calculate_buy_price(&params, keys_account.supply, room)?;
+    }
+    require!(amount > 0, SolSocialError::DcaBudgetTooLow);
+    require!(ctx.accounts.config.allows_trade_size(amount), SolSocialError::TradeTooLarge);
+
+    let protocol_fee = calculate_fee(cost, params.buy_protocol_fee)?;
+    let creator_fee = calculate_fee(cost, params.buy_creator_fee)?;
+    let reward_pool_amount = ctx.accounts.config.reward_pool_cut(protocol_fee)?;
+    let treasury_fee = protocol_fee
+        .checked_sub(reward_pool_amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    let spent = cost
+        .checked_add(protocol_fee)
+        .and_then(|spent| spent.checked_add(creator_fee))
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    let subject_key = ctx.accounts.subject.key();
+    let keys_info = keys_account.to_account_info();
+
+    let keys_seeds = &[b"keys".as_ref(), subject_key.as_ref(), &[ctx.bumps.keys_account]];
+    let signer = &[&keys_seeds[..]];
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
+        authority: keys_info.clone(),
+    };
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let freeze = KeyFreeze::new(&token_program, &ctx.accounts.mint.to_account_info(), keys_account, signer);
+    let owner_token_info = ctx.accounts.owner_token_account.to_account_info();
+    freeze.thaw(&owner_token_info)?;
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+    token::mint_to(cpi_ctx, amount)?;
+    freeze.freeze(&owner_token_info)?;
+
+    // The escrow pays the curve price into the reserve, the fees on top, and the keeper's cut
+    let schedule_info = ctx.accounts.schedule.to_account_info();
+    move_lamports(&schedule_info, &keys_info, cost)?;
+    move_lamports(&schedule_info, &ctx.accounts.treasury.to_account_info(), treasury_fee)?;
+    move_lamports(&schedule_info, &ctx.accounts.reward_pool.to_account_info(), reward_pool_amount)?;
+    move_lamports(&schedule_info, &ctx.accounts.earnings_vault.to_account_info(), creator_fee)?;
+    move_lamports(&schedule_info, &ctx.accounts.keeper.to_account_info(), DcaSchedule::KEEPER_FEE)?;
+    ctx.accounts.reward_pool.record_deposit(reward_pool_amount, now)?;
+    ctx.accounts.earnings_vault.record_accrual(creator_fee)?;
+
+    keys_account.supply = keys_account.supply
+        .checked_add(amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    let held_before = key_holder.amount;
+    key_holder.update_after_buy(amount, cost / amount, cost);
+    keys_account.track_holder_balance(held_before, key_holder.amount)?;
+
+    let portfolio = &mut ctx.accounts.portfolio;
+    if portfolio.owner == Pubkey::default() {
+        portfolio.initialize(ctx.accounts.owner.key(), ctx.bumps.portfolio);
+    }
+    portfolio.record_buy(cost, held_before, now)?;
+    let trade_index = record_market_trade(
+        keys_account,
+        Some(MarketFeeds {
+            recent_trades: &mut ctx.accounts.recent_trades,
+            recent_trades_bump: ctx.bumps.recent_trades,
+            price_oracle: &mut ctx.accounts.price_oracle,
+            price_oracle_bump: ctx.bumps.price_oracle,
+        }),
+        Some(key_holder),
+        &ctx.accounts.config,
+        &CurveTrade { amount, value: cost, creator_fee, protocol_fee, is_buy: true },
+        now,
+    )?;
+
+    let schedule = &mut ctx.accounts.schedule;
+    schedule.record_execution(spent, amount, now)?;
+    let exhausted = schedule.is_exhausted();
+
+    emit!(DcaExecutedEvent {
+        schedule: schedule.key(),
+        owner: schedule.owner,
+        keys_user: subject_key,
+        keeper: ctx.accounts.keeper.key(),
+        amount,
+        price: cost,
+        protocol_fee,
+        creator_fee,
+        keeper_fee: DcaSchedule::KEEPER_FEE,
+        remaining_lamports: schedule.remaining_lamports,
+        next_execution_at: schedule.next_execution_at,
+        exhausted,
+        trade_index,
+        timestamp: now,
+    });
+
+    if exhausted {
+        close_account(&schedule_info, &ctx.accounts.owner.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+#[event]
+pub struct DcaExecutedEvent {
+    pub schedule: Pubkey,
+    pub owner: Pubkey,
+    pub keys_user: Pubkey,
+    pub keeper: Pubkey,
+    pub amount: u64,
+    /// Curve price of the keys bought; the fees were charged on top of it
+    pub price: u64,
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    pub keeper_fee: u64,
+    pub remaining_lamports: u64,
+    pub next_execution_at: i64,
+    /// The schedule ran dry and was closed
+    pub exhausted: bool,
+    pub trade_index: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
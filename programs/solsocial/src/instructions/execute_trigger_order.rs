@@ -0,0 +1,291 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::{KeyHolder, TriggerKind, TriggerOrder, ProtocolConfig, RewardPool, UserKeys, RecentTrades, PriceOracle, Portfolio, EarningsVault, KEY_HOLDER_SEED, TRIGGER_ORDER_SEED, EARNINGS_VAULT_SEED, PORTFOLIO_SEED, Feature};
+use crate::utils::bonding_curve::calculate_sell_price;
+use crate::utils::accounts::{move_closing_lamports, move_lamports};
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::key_token::KeyFreeze;
+use crate::utils::revenue_share::calculate_fee;
+use crate::utils::trade::{record_market_trade, CurveTrade, MarketFeeds};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct ExecuteTriggerOrder<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Receives the sell proceeds, and the order's rent when it closes
+    #[account(mut)]
+    pub owner: SystemAccount<'info>,
+
+    /// CHECK: The subject whose keys the order sells
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Collects the subject's creator fee
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(mut, address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ SolSocialError::InvalidAccountOwner,
+        seeds = [TRIGGER_ORDER_SEED, owner.key().as_ref(), subject.key().as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+    )]
+    pub order: Account<'info, TriggerOrder>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, owner.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = RecentTrades::LEN,
+        seeds = [b"recent_trades", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub recent_trades: Account<'info, RecentTrades>,
+
+    /// Price feed for other programs; every curve trade records into it
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = PriceOracle::LEN,
+        seeds = [b"price_oracle", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub price_oracle: Account<'info, PriceOracle>,
+
+    /// The owner's totals across every market
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = Portfolio::LEN,
+        seeds = [PORTFOLIO_SEED, owner.key().as_ref()],
+        bump,
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sell a trigger order's keys against the curve once the market's oracle TWAP has crossed
+/// its trigger. Anyone may crank it; the keeper takes the escrowed bounty. The sale fills at
+/// the curve's price and the market's sell-side fee rates, and fails if that pays the owner
+/// less than the order's `min_proceeds`.
+pub fn handler(ctx: Context<ExecuteTriggerOrder>) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let now = Clock::get()?.unix_timestamp;
+    let order = &ctx.accounts.order;
+    let amount = order.amount;
+    require!(ctx.accounts.config.allows_trade_size(amount), SolSocialError::TradeTooLarge);
+
+    let keys_account = &mut ctx.accounts.keys_account;
+    keys_account.require_lamport_settlement()?;
+    if !keys_account.is_trading_open(now) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::OutsideTradingHours,
+                price: keys_account.trading_open_at as u64,
+                limit: keys_account.trading_close_at as u64,
+                amount,
+            },
+            SolSocialError::OperationNotAllowed,
+        ));
+    }
+
+    let oracle_price = ctx.accounts.price_oracle
+        .twap_over(now, PriceOracle::TWAP_WINDOW_SECONDS)
+        .ok_or(SolSocialError::OraclePriceUnavailable)?;
+    require!(order.is_triggered(oracle_price), SolSocialError::TriggerNotReached);
+
+    let params = keys_account.curve_params;
+
+    let sell_price = calculate_sell_price(&params, keys_account.supply, amount)?;
+    let protocol_fee = calculate_fee(sell_price, params.sell_protocol_fee)?;
+    let creator_fee = calculate_fee(
+        sell_price,
+        params.sell_creator_fee_at(ctx.accounts.key_holder.first_purchase_at, now),
+    )?;
+    let reward_pool_amount = ctx.accounts.config.reward_pool_cut(protocol_fee)?;
+    let treasury_fee = protocol_fee
+        .checked_sub(reward_pool_amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    let proceeds = sell_price
+        .checked_sub(protocol_fee)
+        .and_then(|net| net.checked_sub(creator_fee))
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    require!(proceeds >= order.min_proceeds, SolSocialError::SlippageExceeded);
+
+    let subject_key = ctx.accounts.subject.key();
+    let owner_key = ctx.accounts.owner.key();
+    let order_info = ctx.accounts.order.to_account_info();
+    let keys_info = keys_account.to_account_info();
+
+    // Burn the delegated keys with the order's signature
+    let order_id = ctx.accounts.order.order_id.to_le_bytes();
+    let order_seeds = &[
+        TRIGGER_ORDER_SEED,
+        owner_key.as_ref(),
+        subject_key.as_ref(),
+        &order_id,
+        &[ctx.accounts.order.bump],
+    ];
+    let signer = &[&order_seeds[..]];
+    let keys_seeds = &[b"keys".as_ref(), subject_key.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        keys_account,
+        keys_signer,
+    );
+    let owner_token_info = ctx.accounts.owner_token_account.to_account_info();
+
+    freeze.thaw(&owner_token_info)?;
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: owner_token_info.clone(),
+        authority: order_info.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::burn(cpi_ctx, amount)?;
+    freeze.freeze(&owner_token_info)?;
+
+    // The market pays out the sale: proceeds to the owner, fees to their destinations
+    move_lamports(&keys_info, &ctx.accounts.owner.to_account_info(), proceeds)?;
+    move_lamports(&keys_info, &ctx.accounts.treasury.to_account_info(), treasury_fee)?;
+    move_lamports(&keys_info, &ctx.accounts.reward_pool.to_account_info(), reward_pool_amount)?;
+    move_lamports(&keys_info, &ctx.accounts.earnings_vault.to_account_info(), creator_fee)?;
+    ctx.accounts.reward_pool.record_deposit(reward_pool_amount, now)?;
+    ctx.accounts.earnings_vault.record_accrual(creator_fee)?;
+
+    keys_account.supply = keys_account.supply
+        .checked_sub(amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    // The escrowed keys left the holder record at placement; count them back before selling
+    let key_holder = &mut ctx.accounts.key_holder;
+    let held_before = key_holder.amount
+        .checked_add(amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    key_holder.amount = held_before;
+    let invested_before = key_holder.total_invested;
+    key_holder.update_after_sell(amount, sell_price);
+    keys_account.track_holder_balance(held_before, key_holder.amount)?;
+
+    let portfolio = &mut ctx.accounts.portfolio;
+    if portfolio.owner == Pubkey::default() {
+        portfolio.initialize(owner_key, ctx.bumps.portfolio);
+    }
+    portfolio.record_sell(
+        invested_before.saturating_sub(key_holder.total_invested),
+        proceeds,
+        key_holder.amount,
+        now,
+    )?;
+    let trade_index = record_market_trade(
+        keys_account,
+        Some(MarketFeeds {
+            recent_trades: &mut ctx.accounts.recent_trades,
+            recent_trades_bump: ctx.bumps.recent_trades,
+            price_oracle: &mut ctx.accounts.price_oracle,
+            price_oracle_bump: ctx.bumps.price_oracle,
+        }),
+        Some(key_holder),
+        &ctx.accounts.config,
+        &CurveTrade { amount, value: sell_price, creator_fee, protocol_fee, is_buy: false },
+        now,
+    )?;
+
+    // The bounty goes to the keeper; the rent refunds to the owner on close
+    let keeper_fee = std::cmp::min(ctx.accounts.order.keeper_bounty, order_info.lamports());
+    move_closing_lamports(&order_info, &ctx.accounts.keeper.to_account_info(), keeper_fee)?;
+
+    emit!(TriggerOrderExecutedEvent {
+        order: order_info.key(),
+        owner: owner_key,
+        keys_user: subject_key,
+        keeper: ctx.accounts.keeper.key(),
+        kind: ctx.accounts.order.kind,
+        trigger_price: ctx.accounts.order.trigger_price,
+        oracle_price,
+        amount,
+        price: sell_price,
+        protocol_fee,
+        creator_fee,
+        keeper_fee,
+        trade_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TriggerOrderExecutedEvent {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub keys_user: Pubkey,
+    pub keeper: Pubkey,
+    pub kind: TriggerKind,
+    pub trigger_price: u64,
+    /// Oracle TWAP the trigger was checked against
+    pub oracle_price: u64,
+    pub amount: u64,
+    /// Curve value of the whole sale, before fees
+    pub price: u64,
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    pub keeper_fee: u64,
+    pub trade_index: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,60 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{User, Post};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct ExpirePost<'info> {
+    /// Anyone can clean up an expired post
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        close = author,
+    )]
+    pub post: Account<'info, Post>,
+
+    /// CHECK: Post author receiving the rent, checked against the post
+    #[account(mut, address = post.author @ SolSocialError::Unauthorized)]
+    pub author: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user", post.author.as_ref()],
+        bump = user.bump,
+    )]
+    pub user: Account<'info, User>,
+}
+
+/// Close an ephemeral post past its `expires_at`, refunding its rent to the author
+/// and freeing its media from the author's storage cap
+pub fn handler(ctx: Context<ExpirePost>) -> Result<()> {
+    let post = &ctx.accounts.post;
+    let now = Clock::get()?.unix_timestamp;
+    require!(post.is_expired(now), SolSocialError::PostNotExpired);
+
+    let media_bytes = post.media_bytes();
+    ctx.accounts.user.release_media_bytes(media_bytes);
+
+    emit!(PostExpiredEvent {
+        post: post.key(),
+        author: post.author,
+        expired_at: post.expires_at.unwrap_or_default(),
+        released_media_bytes: media_bytes,
+        closed_by: ctx.accounts.caller.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PostExpiredEvent {
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub expired_at: i64,
+    pub released_media_bytes: u64,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,92 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, FeeExemption};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(account: Pubkey)]
+pub struct GrantFeeExemption<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        has_one = admin @ SolSocialError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = FeeExemption::LEN,
+        seeds = [b"fee_exemption", account.as_ref()],
+        bump
+    )]
+    pub exemption: Account<'info, FeeExemption>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Exempt `account` from protocol fees. Once a multisig holds the admin role this goes
+/// through `AdminAction::GrantFeeExemption` instead.
+pub fn grant_handler(ctx: Context<GrantFeeExemption>, account: Pubkey) -> Result<()> {
+    let exemption = &mut ctx.accounts.exemption;
+    let now = Clock::get()?.unix_timestamp;
+
+    exemption.account = account;
+    exemption.granted_by = ctx.accounts.admin.key();
+    exemption.granted_at = now;
+    exemption.bump = ctx.bumps.exemption;
+
+    emit!(FeeExemptionChangedEvent {
+        account,
+        admin: exemption.granted_by,
+        exempt: true,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeFeeExemption<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        has_one = admin @ SolSocialError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_exemption", exemption.account.as_ref()],
+        bump = exemption.bump,
+        close = admin
+    )]
+    pub exemption: Account<'info, FeeExemption>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+/// Withdraw an exemption; under a multisig admin, through `AdminAction::RevokeFeeExemption`
+pub fn revoke_handler(ctx: Context<RevokeFeeExemption>) -> Result<()> {
+    emit!(FeeExemptionChangedEvent {
+        account: ctx.accounts.exemption.account,
+        admin: ctx.accounts.admin.key(),
+        exempt: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct FeeExemptionChangedEvent {
+    pub account: Pubkey,
+    pub admin: Pubkey,
+    pub exempt: bool,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,359 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, MintTo, Token, TokenAccount};
+use crate::state::{KeyHolder, LimitOrder, OrderSide, ProtocolConfig, RewardPool, UserKeys, EarningsVault, RecentTrades, PriceOracle, Portfolio, KEY_HOLDER_SEED, LIMIT_ORDER_ESCROW_SEED, LIMIT_ORDER_SEED, EARNINGS_VAULT_SEED, PORTFOLIO_SEED, Feature};
+use crate::utils::bonding_curve::{calculate_buy_price, calculate_sell_price};
+use crate::utils::accounts::{move_closing_lamports, move_lamports};
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::key_token::KeyFreeze;
+use crate::utils::revenue_share::calculate_fee;
+use crate::utils::trade::{record_market_trade, CurveTrade, MarketFeeds};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct FillOrder<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Receives sell proceeds, and the unused escrow and rent when the order closes
+    #[account(mut)]
+    pub owner: SystemAccount<'info>,
+
+    /// CHECK: The subject whose keys the order trades
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Collects the subject's creator fee
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(mut, address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ SolSocialError::InvalidAccountOwner,
+        seeds = [LIMIT_ORDER_SEED, owner.key().as_ref(), subject.key().as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+    )]
+    pub order: Account<'info, LimitOrder>,
+
+    /// The sell side's escrowed keys; closed with the order either way
+    #[account(
+        mut,
+        seeds = [LIMIT_ORDER_ESCROW_SEED, order.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, owner.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = RecentTrades::LEN,
+        seeds = [b"recent_trades", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub recent_trades: Account<'info, RecentTrades>,
+
+    /// Price feed for other programs; fills record into it like any other trade
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = PriceOracle::LEN,
+        seeds = [b"price_oracle", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub price_oracle: Account<'info, PriceOracle>,
+
+    /// The owner's totals across every market
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = Portfolio::LEN,
+        seeds = [PORTFOLIO_SEED, owner.key().as_ref()],
+        bump,
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Fill a whole limit order against the bonding curve once the curve meets its limit.
+///
+/// Buy orders pay the curve and its fees out of their lamport escrow and mint keys to the owner;
+/// sell orders burn the keys held in the order's escrow and pay the owner out of the market.
+/// Fees follow the market's rates for the side, and the keeper earns `KEEPER_FEE`.
+pub fn handler(ctx: Context<FillOrder>) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let now = Clock::get()?.unix_timestamp;
+    let order = &ctx.accounts.order;
+    require!(!order.is_expired(now), SolSocialError::OrderExpired);
+
+    let side = order.side;
+    let amount = order.amount;
+    require!(ctx.accounts.config.allows_trade_size(amount), SolSocialError::TradeTooLarge);
+    let keys_account = &mut ctx.accounts.keys_account;
+    keys_account.require_lamport_settlement()?;
+    if !keys_account.is_trading_open(now) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::OutsideTradingHours,
+                price: keys_account.trading_open_at as u64,
+                limit: keys_account.trading_close_at as u64,
+                amount,
+            },
+            SolSocialError::OperationNotAllowed,
+        ));
+    }
+    // Guarded launches only accept buy_keys, which enforces the per-wallet limits
+    if matches!(side, OrderSide::Buy) {
+        require!(!keys_account.launch_guard.is_active(Clock::get()?.slot), SolSocialError::LaunchGuardActive);
+    }
+
+    // Settle at the curve's price, which must meet the order's limit
+    let params = keys_account.curve_params;
+    let curve_total = match side {
+        OrderSide::Buy => calculate_buy_price(&params, keys_account.supply, amount)?,
+        OrderSide::Sell => calculate_sell_price(&params, keys_account.supply, amount)?,
+    };
+    if !order.is_fillable_at(curve_total)? {
+        let kind = match side {
+            OrderSide::Buy => TradeErrorKind::BuySlippage,
+            OrderSide::Sell => TradeErrorKind::SellSlippage,
+        };
+        return Err(fail_with_context(
+            TradeErrorContext { kind, price: curve_total, limit: order.limit_total()?, amount },
+            SolSocialError::LimitPriceNotReached,
+        ));
+    }
+
+    let (protocol_fee_bps, creator_fee_bps) = match side {
+        OrderSide::Buy => (params.buy_protocol_fee, params.buy_creator_fee),
+        OrderSide::Sell => (
+            params.sell_protocol_fee,
+            params.sell_creator_fee_at(ctx.accounts.key_holder.first_purchase_at, now),
+        ),
+    };
+    let protocol_fee = calculate_fee(curve_total, protocol_fee_bps)?;
+    let creator_fee = calculate_fee(curve_total, creator_fee_bps)?;
+    let reward_pool_amount = ctx.accounts.config.reward_pool_cut(protocol_fee)?;
+    let treasury_fee = protocol_fee
+        .checked_sub(reward_pool_amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    let net = curve_total
+        .checked_sub(protocol_fee)
+        .and_then(|net| net.checked_sub(creator_fee))
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    let subject_key = ctx.accounts.subject.key();
+    let owner_key = ctx.accounts.owner.key();
+    let key_holder = &mut ctx.accounts.key_holder;
+    let portfolio = &mut ctx.accounts.portfolio;
+    if portfolio.owner == Pubkey::default() {
+        portfolio.initialize(owner_key, ctx.bumps.portfolio);
+    }
+    let order_info = ctx.accounts.order.to_account_info();
+    let keys_info = keys_account.to_account_info();
+    let order_id = ctx.accounts.order.order_id.to_le_bytes();
+    let order_seeds = &[
+        LIMIT_ORDER_SEED,
+        owner_key.as_ref(),
+        subject_key.as_ref(),
+        &order_id,
+        &[ctx.accounts.order.bump],
+    ];
+    let order_signer = &[&order_seeds[..]];
+
+    // Move keys first, then lamports, so no CPI sees an unbalanced instruction.
+    // Buy orders pay the fees on top of the curve price, sell orders out of it.
+    let (held_before, payer) = match side {
+        OrderSide::Buy => {
+            require!(
+                params.within_holder_cap(key_holder.amount, amount),
+                SolSocialError::HolderCapReached
+            );
+
+            let keys_seeds = &[b"keys".as_ref(), subject_key.as_ref(), &[ctx.bumps.keys_account]];
+            let signer = &[&keys_seeds[..]];
+            let cpi_accounts = MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: keys_info.clone(),
+            };
+            let token_program = ctx.accounts.token_program.to_account_info();
+            let freeze = KeyFreeze::new(&token_program, &ctx.accounts.mint.to_account_info(), keys_account, signer);
+            let owner_token_info = ctx.accounts.owner_token_account.to_account_info();
+            freeze.thaw(&owner_token_info)?;
+            let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+            token::mint_to(cpi_ctx, amount)?;
+            freeze.freeze(&owner_token_info)?;
+
+            // The escrow pays the whole curve price into the reserve, and the fees on top
+            move_lamports(&order_info, &keys_info, curve_total)?;
+
+            keys_account.supply = keys_account.supply
+                .checked_add(amount)
+                .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+            let held_before = key_holder.amount;
+            key_holder.update_after_buy(amount, curve_total / amount, curve_total);
+            portfolio.record_buy(curve_total, held_before, now)?;
+            (held_before, &order_info)
+        }
+        OrderSide::Sell => {
+            let cpi_accounts = Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.escrow.to_account_info(),
+                authority: order_info.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, order_signer);
+            token::burn(cpi_ctx, amount)?;
+
+            // The market pays the curve value; the owner gets it less fees
+            move_lamports(&keys_info, &ctx.accounts.owner.to_account_info(), net)?;
+
+            keys_account.supply = keys_account.supply
+                .checked_sub(amount)
+                .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+            // The escrowed keys left the holder record at placement; count them back before selling
+            let held_before = key_holder.amount
+                .checked_add(amount)
+                .ok_or(SolSocialError::ArithmeticOverflow)?;
+            key_holder.amount = held_before;
+            let invested_before = key_holder.total_invested;
+            key_holder.update_after_sell(amount, curve_total);
+            portfolio.record_sell(
+                invested_before.saturating_sub(key_holder.total_invested),
+                net,
+                key_holder.amount,
+                now,
+            )?;
+            (held_before, &keys_info)
+        }
+    };
+
+    move_lamports(payer, &ctx.accounts.treasury.to_account_info(), treasury_fee)?;
+    move_lamports(payer, &ctx.accounts.reward_pool.to_account_info(), reward_pool_amount)?;
+    move_lamports(payer, &ctx.accounts.earnings_vault.to_account_info(), creator_fee)?;
+    ctx.accounts.reward_pool.record_deposit(reward_pool_amount, now)?;
+    ctx.accounts.earnings_vault.record_accrual(creator_fee)?;
+
+    keys_account.track_holder_balance(held_before, key_holder.amount)?;
+    let trade_index = record_market_trade(
+        keys_account,
+        Some(MarketFeeds {
+            recent_trades: &mut ctx.accounts.recent_trades,
+            recent_trades_bump: ctx.bumps.recent_trades,
+            price_oracle: &mut ctx.accounts.price_oracle,
+            price_oracle_bump: ctx.bumps.price_oracle,
+        }),
+        Some(key_holder),
+        &ctx.accounts.config,
+        &CurveTrade {
+            amount,
+            value: curve_total,
+            creator_fee,
+            protocol_fee,
+            is_buy: matches!(side, OrderSide::Buy),
+        },
+        now,
+    )?;
+
+    // The escrow is empty either way; its rent goes back to the owner
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow.to_account_info(),
+        destination: ctx.accounts.owner.to_account_info(),
+        authority: order_info.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, order_signer);
+    token::close_account(cpi_ctx)?;
+
+    // Pay the keeper out of the order account; the rest refunds to the owner on close
+    let keeper_fee = LimitOrder::keeper_fee(order_info.lamports());
+    move_closing_lamports(&order_info, &ctx.accounts.keeper.to_account_info(), keeper_fee)?;
+
+    emit!(OrderFilledEvent {
+        order: order_info.key(),
+        owner: owner_key,
+        keys_user: subject_key,
+        keeper: ctx.accounts.keeper.key(),
+        side,
+        amount,
+        price: curve_total,
+        price_per_key: ctx.accounts.order.price_per_key,
+        protocol_fee,
+        creator_fee,
+        keeper_fee,
+        trade_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OrderFilledEvent {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub keys_user: Pubkey,
+    pub keeper: Pubkey,
+    pub side: OrderSide,
+    pub amount: u64,
+    /// Curve price paid or received for the whole order, before fees
+    pub price: u64,
+    /// The order's limit per key
+    pub price_per_key: u64,
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    pub keeper_fee: u64,
+    pub trade_index: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,80 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{Activity, ActivityMetrics, ActivityTracker};
+
+#[derive(Accounts)]
+pub struct FinalizeActivityDay<'info> {
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = ActivityTracker::LEN,
+        seeds = [b"activity_metrics"],
+        bump
+    )]
+    pub activity: Account<'info, ActivityTracker>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Roll the activity bucket once its day has ended, so quiet days are still closed out.
+/// The first call creates the tracker and opens today's bucket.
+pub fn handler(ctx: Context<FinalizeActivityDay>) -> Result<()> {
+    let activity = &mut ctx.accounts.activity;
+    let now = Clock::get()?.unix_timestamp;
+
+    if activity.day == 0 {
+        activity.initialize(now, ctx.bumps.activity);
+        return Ok(());
+    }
+
+    if activity.needs_rollover(now) {
+        let day = activity.day;
+        let closed = activity.roll(now);
+        emit_day_finalized(day, closed);
+    }
+
+    Ok(())
+}
+
+/// Heartbeat for post, trade and message instructions. A no-op unless the caller opted in by
+/// passing the tracker account; `last_activity` must be read before the user's timestamp is bumped.
+pub fn touch_activity(
+    tracker: Option<&mut Account<ActivityTracker>>,
+    last_activity: i64,
+    activity: Activity,
+) -> Result<()> {
+    if let Some(tracker) = tracker {
+        let day = tracker.day;
+        if let Some(closed) = tracker.touch(last_activity, Clock::get()?.unix_timestamp, activity) {
+            emit_day_finalized(day, closed);
+        }
+    }
+    Ok(())
+}
+
+fn emit_day_finalized(day: i64, metrics: ActivityMetrics) {
+    emit!(ActivityDayFinalizedEvent {
+        day,
+        daily_active_users: metrics.daily_active_users,
+        weekly_active_users: metrics.weekly_active_users,
+        monthly_active_users: metrics.monthly_active_users,
+        total_posts: metrics.total_posts_today,
+        total_trades: metrics.total_trades_today,
+        total_volume: metrics.total_volume_today,
+    });
+}
+
+#[event]
+pub struct ActivityDayFinalizedEvent {
+    pub day: i64,
+    pub daily_active_users: u64,
+    pub weekly_active_users: u64,
+    pub monthly_active_users: u64,
+    pub total_posts: u64,
+    pub total_trades: u64,
+    pub total_volume: u64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,49 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::HolderVote;
+
+#[derive(Accounts)]
+pub struct FinalizeHolderVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"holder_vote", vote.creator.as_ref(), &vote.vote_id.to_le_bytes()],
+        bump = vote.bump
+    )]
+    pub vote: Account<'info, HolderVote>,
+
+    /// Anyone may finalize once the deadline has passed
+    pub cranker: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<FinalizeHolderVote>) -> Result<()> {
+    let vote = &mut ctx.accounts.vote;
+    let now = Clock::get()?.unix_timestamp;
+
+    vote.finalize(now)?;
+
+    emit!(HolderVoteFinalizedEvent {
+        vote: vote.key(),
+        creator: vote.creator,
+        vote_id: vote.vote_id,
+        tallies: vote.tallies.clone(),
+        total_weight: vote.total_weight,
+        voter_count: vote.voter_count,
+        winning_option: vote.winning_option,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct HolderVoteFinalizedEvent {
+    pub vote: Pubkey,
+    pub creator: Pubkey,
+    pub vote_id: u64,
+    pub tallies: Vec<u64>,
+    pub total_weight: u64,
+    pub voter_count: u64,
+    pub winning_option: Option<u8>,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,66 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::TipMatch;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct FundTipMatch<'info> {
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = TipMatch::LEN,
+        seeds = [b"tip_match", creator.key().as_ref()],
+        bump
+    )]
+    pub tip_match: Account<'info, TipMatch>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundTipMatch>, match_ratio_bps: u16, amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let tip_match = &mut ctx.accounts.tip_match;
+
+    if tip_match.creator == Pubkey::default() {
+        tip_match.initialize(ctx.accounts.creator.key(), now, ctx.bumps.tip_match);
+    }
+    require_keys_eq!(tip_match.creator, ctx.accounts.creator.key(), SolSocialError::Unauthorized);
+
+    tip_match.set_ratio(match_ratio_bps)?;
+
+    if amount > 0 {
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.creator.key(),
+            &tip_match.key(),
+            amount,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[ctx.accounts.creator.to_account_info(), tip_match.to_account_info()],
+        )?;
+        tip_match.fund(amount)?;
+    }
+
+    emit!(TipMatchFundedEvent {
+        creator: tip_match.creator,
+        match_ratio_bps,
+        amount,
+        remaining_budget: tip_match.remaining_budget,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TipMatchFundedEvent {
+    pub creator: Pubkey,
+    pub match_ratio_bps: u16,
+    pub amount: u64,
+    pub remaining_budget: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
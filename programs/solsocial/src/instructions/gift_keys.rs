@@ -0,0 +1,277 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::{Gift, KeyHolder, ProtocolConfig, RewardPool, UserKeys, RecentTrades, PriceOracle, EarningsVault, GIFT_SEED, KEY_HOLDER_SEED, EARNINGS_VAULT_SEED, Feature};
+use crate::utils::bonding_curve::calculate_buy_price;
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::key_token::KeyFreeze;
+use crate::utils::revenue_share::calculate_fee;
+use crate::utils::trade::{record_market_trade, CurveTrade, MarketFeeds};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(to: Pubkey)]
+pub struct GiftKeys<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// CHECK: Wallet receiving the keys
+    #[account(address = to)]
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: The subject whose keys are gifted
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Collects the subject's creator fee
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, to.as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub recipient_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        init,
+        payer = sender,
+        space = Gift::LEN,
+        seeds = [GIFT_SEED, subject.key().as_ref(), &keys_account.trade_count.to_le_bytes()],
+        bump,
+    )]
+    pub gift: Account<'info, Gift>,
+
+    #[account(mut, address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = RecentTrades::LEN,
+        seeds = [b"recent_trades", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub recent_trades: Account<'info, RecentTrades>,
+
+    /// Price feed for other programs; every curve trade records into it
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = PriceOracle::LEN,
+        seeds = [b"price_oracle", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub price_oracle: Account<'info, PriceOracle>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Buy keys on the curve for someone else. The sender pays the buy price, the keys are
+/// minted to the recipient, and the gift and its note are kept in a `Gift` PDA.
+pub fn handler(
+    ctx: Context<GiftKeys>,
+    to: Pubkey,
+    amount: u64,
+    max_price: u64,
+    message: Option<String>,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    require!(amount > 0, SolSocialError::InvalidAmount);
+    require!(ctx.accounts.config.allows_trade_size(amount), SolSocialError::TradeTooLarge);
+    // Buying for yourself goes through buy_keys
+    require_keys_neq!(to, ctx.accounts.sender.key(), SolSocialError::OperationNotAllowed);
+
+    let keys_account = &mut ctx.accounts.keys_account;
+
+    let now = Clock::get()?.unix_timestamp;
+    keys_account.require_lamport_settlement()?;
+    if !keys_account.is_trading_open(now) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::OutsideTradingHours,
+                price: keys_account.trading_open_at as u64,
+                limit: keys_account.trading_close_at as u64,
+                amount,
+            },
+            SolSocialError::OperationNotAllowed,
+        ));
+    }
+    // Guarded launches only accept buy_keys, which enforces the per-wallet limits
+    require!(!keys_account.launch_guard.is_active(Clock::get()?.slot), SolSocialError::LaunchGuardActive);
+
+    let params = keys_account.curve_params;
+    let held = ctx.accounts.recipient_holder.amount;
+    if !params.within_holder_cap(held, amount) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::HolderCapReached,
+                price: held,
+                limit: params.max_keys_per_holder,
+                amount,
+            },
+            SolSocialError::HolderCapReached,
+        ));
+    }
+
+    let price = calculate_buy_price(&params, keys_account.supply, amount)?;
+    if price > max_price {
+        return Err(fail_with_context(
+            TradeErrorContext { kind: TradeErrorKind::BuySlippage, price, limit: max_price, amount },
+            SolSocialError::SlippageExceeded,
+        ));
+    }
+
+    // Fees are charged on top of the price at the market's buy-side rates, as for any other buy
+    let protocol_fee = calculate_fee(price, params.buy_protocol_fee)?;
+    let creator_fee = calculate_fee(price, params.buy_creator_fee)?;
+    let reward_pool_amount = ctx.accounts.config.reward_pool_cut(protocol_fee)?;
+    let treasury_fee = protocol_fee
+        .checked_sub(reward_pool_amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+
+    let sender = ctx.accounts.sender.to_account_info();
+    for (destination, lamports) in [
+        (ctx.accounts.treasury.to_account_info(), treasury_fee),
+        (ctx.accounts.reward_pool.to_account_info(), reward_pool_amount),
+        (ctx.accounts.earnings_vault.to_account_info(), creator_fee),
+        (keys_account.to_account_info(), price),
+    ] {
+        if lamports == 0 {
+            continue;
+        }
+        let ix = anchor_lang::solana_program::system_instruction::transfer(sender.key, destination.key, lamports);
+        anchor_lang::solana_program::program::invoke(&ix, &[sender.clone(), destination])?;
+    }
+    ctx.accounts.earnings_vault.record_accrual(creator_fee)?;
+
+    let subject = ctx.accounts.subject.key();
+    let seeds = &[b"keys", subject.as_ref(), &[ctx.bumps.keys_account]];
+    let signer = &[&seeds[..]];
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: keys_account.to_account_info(),
+    };
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let freeze = KeyFreeze::new(&token_program, &ctx.accounts.mint.to_account_info(), keys_account, signer);
+    let recipient_token_info = ctx.accounts.recipient_token_account.to_account_info();
+    freeze.thaw(&recipient_token_info)?;
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+    token::mint_to(cpi_ctx, amount)?;
+    freeze.freeze(&recipient_token_info)?;
+
+    let recipient_holder = &mut ctx.accounts.recipient_holder;
+    if recipient_holder.holder == Pubkey::default() {
+        **recipient_holder = KeyHolder::new(to, subject);
+    }
+    let held_before = recipient_holder.amount;
+    recipient_holder.update_after_buy(amount, price / amount, price);
+    keys_account.track_holder_balance(held_before, recipient_holder.amount)?;
+
+    keys_account.supply = keys_account.supply
+        .checked_add(amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+    let trade_index = record_market_trade(
+        keys_account,
+        Some(MarketFeeds {
+            recent_trades: &mut ctx.accounts.recent_trades,
+            recent_trades_bump: ctx.bumps.recent_trades,
+            price_oracle: &mut ctx.accounts.price_oracle,
+            price_oracle_bump: ctx.bumps.price_oracle,
+        }),
+        Some(recipient_holder),
+        &ctx.accounts.config,
+        &CurveTrade { amount, value: price, creator_fee, protocol_fee, is_buy: true },
+        now,
+    )?;
+
+    ctx.accounts.reward_pool.record_deposit(reward_pool_amount, now)?;
+
+    let has_message = message.is_some();
+    ctx.accounts.gift.initialize(
+        ctx.accounts.sender.key(),
+        to,
+        subject,
+        amount,
+        price,
+        trade_index,
+        message,
+        now,
+        ctx.bumps.gift,
+    )?;
+
+    emit!(KeysGiftedEvent {
+        gift: ctx.accounts.gift.key(),
+        sender: ctx.accounts.sender.key(),
+        recipient: to,
+        subject,
+        amount,
+        price,
+        protocol_fee,
+        creator_fee,
+        has_message,
+        trade_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct KeysGiftedEvent {
+    pub gift: Pubkey,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub price: u64,
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    /// Whether the `Gift` account carries a note worth fetching
+    pub has_message: bool,
+    pub trade_index: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,258 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke_signed, system_instruction};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, spl_token, Mint, MintTo, SyncNative, Token, TokenAccount};
+use crate::state::{ProtocolConfig, UserKeys, Feature};
+use crate::utils::accounts::{move_lamports, withdrawable_lamports};
+use crate::utils::amm::{cp_swap_initialize, CpSwapInitialize, RAYDIUM_CP_SWAP_PROGRAM_ID};
+use crate::error::SolSocialError;
+
+/// Seed of the system-owned PDA that provides a graduated market's liquidity and holds its LP tokens
+pub const GRADUATION_AUTHORITY_SEED: &[u8] = b"graduation";
+
+/// Lamports held back from the reserve to pay the AMM's pool creation fee and account rent;
+/// whatever the AMM doesn't spend is refunded to the treasury
+pub const GRADUATION_POOL_COSTS: u64 = 200_000_000; // 0.2 SOL
+
+#[derive(Accounts)]
+pub struct GraduateMarket<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The subject whose market graduates
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(mut, address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(address = spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+
+    /// Provides the pool's liquidity and keeps its LP tokens, so the liquidity is protocol-owned
+    #[account(
+        mut,
+        seeds = [GRADUATION_AUTHORITY_SEED, keys_account.key().as_ref()],
+        bump,
+    )]
+    pub graduation_authority: SystemAccount<'info>,
+
+    /// Receives whatever part of `GRADUATION_POOL_COSTS` pool creation didn't spend
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = graduation_authority,
+    )]
+    pub authority_keys_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = native_mint,
+        associated_token::authority = graduation_authority,
+    )]
+    pub authority_wsol_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: The AMM program the pool is created in
+    #[account(address = RAYDIUM_CP_SWAP_PROGRAM_ID)]
+    pub amm_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the AMM
+    pub amm_config: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the AMM
+    pub amm_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the AMM
+    #[account(mut)]
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the AMM
+    #[account(mut)]
+    pub lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the AMM as `graduation_authority`'s LP token account
+    #[account(mut)]
+    pub authority_lp_account: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the AMM
+    #[account(mut)]
+    pub token_0_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the AMM
+    #[account(mut)]
+    pub token_1_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the AMM
+    #[account(mut)]
+    pub create_pool_fee: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the AMM
+    #[account(mut)]
+    pub observation_state: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Move a market that crossed the graduation market cap off the bonding curve and into an
+/// AMM pool. The market's reserve, less pool creation costs, is paired with newly minted
+/// keys at the curve's spot price, so the pool opens where the curve stopped. Anyone may
+/// crank it; afterwards the curve stays closed and keys trade on the pool.
+pub fn handler(ctx: Context<GraduateMarket>) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let threshold = ctx.accounts.config.graduation_market_cap;
+    require!(threshold > 0, SolSocialError::GraduationDisabled);
+
+    let now = Clock::get()?.unix_timestamp;
+    let keys_account = &mut ctx.accounts.keys_account;
+    require!(keys_account.graduated_at == 0, SolSocialError::MarketGraduated);
+    // Halted markets and live launch phases have to settle before graduating
+    keys_account.require_lamport_settlement()?;
+    require!(keys_account.is_trading_open(now), SolSocialError::OperationNotAllowed);
+
+    let market_cap = keys_account.market_cap()?;
+    require!(market_cap >= threshold, SolSocialError::BelowGraduationMarketCap);
+
+    let spot_price = keys_account.calculate_price(keys_account.supply)?;
+    let keys_info = keys_account.to_account_info();
+    let reserve = withdrawable_lamports(&Rent::get()?, &keys_info);
+    let liquidity_lamports = reserve
+        .checked_sub(GRADUATION_POOL_COSTS)
+        .ok_or(SolSocialError::InsufficientGraduationLiquidity)?;
+    let liquidity_keys = liquidity_lamports / spot_price;
+    require!(liquidity_keys > 0, SolSocialError::InsufficientGraduationLiquidity);
+
+    let subject_key = ctx.accounts.subject.key();
+    let market_key = keys_account.key();
+    let authority_bump = ctx.bumps.graduation_authority;
+    let authority_seeds = &[GRADUATION_AUTHORITY_SEED, market_key.as_ref(), &[authority_bump]];
+    let authority_signer = &[&authority_seeds[..]];
+    let authority_info = ctx.accounts.graduation_authority.to_account_info();
+    let wsol_info = ctx.accounts.authority_wsol_account.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    // The whole reserve leaves the curve; what pool creation doesn't spend goes to the treasury below
+    move_lamports(&keys_info, &authority_info, reserve)?;
+    invoke_signed(
+        &system_instruction::transfer(&authority_info.key(), &wsol_info.key(), liquidity_lamports),
+        &[authority_info.clone(), wsol_info.clone(), ctx.accounts.system_program.to_account_info()],
+        authority_signer,
+    )?;
+    token::sync_native(CpiContext::new(token_program.clone(), SyncNative { account: wsol_info.clone() }))?;
+
+    let keys_seeds = &[b"keys".as_ref(), subject_key.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.authority_keys_account.to_account_info(),
+        authority: keys_info.clone(),
+    };
+    token::mint_to(CpiContext::new_with_signer(token_program.clone(), cpi_accounts, keys_signer), liquidity_keys)?;
+
+    // The AMM orders a pool's mints by address
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let native_mint_info = ctx.accounts.native_mint.to_account_info();
+    let authority_keys_info = ctx.accounts.authority_keys_account.to_account_info();
+    let keys_first = mint_info.key() < native_mint_info.key();
+    let (token_0_mint, token_1_mint, creator_token_0, creator_token_1, amount_0, amount_1) = if keys_first {
+        (&mint_info, &native_mint_info, &authority_keys_info, &wsol_info, liquidity_keys, liquidity_lamports)
+    } else {
+        (&native_mint_info, &mint_info, &wsol_info, &authority_keys_info, liquidity_lamports, liquidity_keys)
+    };
+
+    cp_swap_initialize(
+        &ctx.accounts.amm_program.to_account_info(),
+        CpSwapInitialize {
+            creator: &authority_info,
+            amm_config: &ctx.accounts.amm_config.to_account_info(),
+            authority: &ctx.accounts.amm_authority.to_account_info(),
+            pool_state: &ctx.accounts.pool_state.to_account_info(),
+            token_0_mint,
+            token_1_mint,
+            lp_mint: &ctx.accounts.lp_mint.to_account_info(),
+            creator_token_0,
+            creator_token_1,
+            creator_lp_token: &ctx.accounts.authority_lp_account.to_account_info(),
+            token_0_vault: &ctx.accounts.token_0_vault.to_account_info(),
+            token_1_vault: &ctx.accounts.token_1_vault.to_account_info(),
+            create_pool_fee: &ctx.accounts.create_pool_fee.to_account_info(),
+            observation_state: &ctx.accounts.observation_state.to_account_info(),
+            token_program: &token_program,
+            associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            rent: &ctx.accounts.rent.to_account_info(),
+        },
+        amount_0,
+        amount_1,
+        0,
+        authority_signer,
+    )?;
+
+    // The authority only needs its address to hold LP tokens, so none of its lamports stay behind
+    let pool_costs_refunded = authority_info.lamports();
+    if pool_costs_refunded > 0 {
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        invoke_signed(
+            &system_instruction::transfer(&authority_info.key(), &treasury_info.key(), pool_costs_refunded),
+            &[authority_info.clone(), treasury_info, ctx.accounts.system_program.to_account_info()],
+            authority_signer,
+        )?;
+    }
+
+    let keys_account = &mut ctx.accounts.keys_account;
+    keys_account.graduated_at = now;
+
+    emit!(MarketGraduatedEvent {
+        subject: subject_key,
+        pool_state: ctx.accounts.pool_state.key(),
+        market_cap,
+        spot_price,
+        liquidity_lamports,
+        liquidity_keys,
+        pool_costs_refunded,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketGraduatedEvent {
+    pub subject: Pubkey,
+    pub pool_state: Pubkey,
+    pub market_cap: u64,
+    /// Curve price of the next key when trading moved to the pool
+    pub spot_price: u64,
+    /// Lamports deposited into the pool
+    pub liquidity_lamports: u64,
+    /// Keys minted into the pool; they don't count towards the curve's `supply`
+    pub liquidity_keys: u64,
+    /// Pool creation allowance left unspent and returned to the treasury
+    pub pool_costs_refunded: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,93 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::program::Solsocial;
+use crate::state::{ProtocolConfig, RewardPool};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::LEN,
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = RewardPool::LEN,
+        seeds = [b"reward_pool"],
+        bump
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    /// Must be the program's upgrade authority, so nobody can front-run the deployer
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ SolSocialError::Unauthorized)]
+    pub program: Program<'info, Solsocial>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ SolSocialError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeConfig>,
+    max_media_bytes_per_user: u64,
+    reward_pool_bps: u16,
+    grace_drop_bps: u16,
+    grace_window_seconds: i64,
+    content_dedup_window_seconds: i64,
+    sell_queue_threshold: u64,
+    timelock_seconds: i64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.initialize(
+        ctx.accounts.admin.key(),
+        max_media_bytes_per_user,
+        reward_pool_bps,
+        grace_drop_bps,
+        grace_window_seconds,
+        content_dedup_window_seconds,
+        sell_queue_threshold,
+        timelock_seconds,
+        ctx.bumps.config,
+    )?;
+
+    ctx.accounts.reward_pool.initialize(ctx.bumps.reward_pool)?;
+
+    emit!(ConfigInitializedEvent {
+        admin: config.admin,
+        max_media_bytes_per_user,
+        reward_pool_bps,
+        grace_drop_bps,
+        grace_window_seconds,
+        content_dedup_window_seconds,
+        sell_queue_threshold,
+        timelock_seconds,
+        timestamp: config.created_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigInitializedEvent {
+    pub admin: Pubkey,
+    pub max_media_bytes_per_user: u64,
+    pub reward_pool_bps: u16,
+    pub grace_drop_bps: u16,
+    pub grace_window_seconds: i64,
+    pub content_dedup_window_seconds: i64,
+    pub sell_queue_threshold: u64,
+    pub timelock_seconds: i64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -1,6 +1,6 @@
 ```rust
 use anchor_lang::prelude::*;
-use crate::state::{User, UserKeys};
+use crate::state::{get_referral_pda, Referral, User, UserKeys, UsernameRecord};
 use crate::error::SolSocialError;
 
 #[derive(Accounts)]
@@ -24,9 +24,25 @@ pub struct InitializeUser<'info> {
     )]
     pub user_keys: Account<'info, UserKeys>,
     
+    #[account(
+        init,
+        payer = authority,
+        space = UsernameRecord::LEN,
+        seeds = [b"username", username.as_bytes()],
+        bump
+    )]
+    pub username_record: Account<'info, UsernameRecord>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    /// Profile of the user who referred this one, if any
+    pub referrer_user: Option<Account<'info, User>>,
+    
+    /// CHECK: The new user's `Referral` PDA; created in the handler when a referrer is given
+    #[account(mut)]
+    pub referral: Option<UncheckedAccount<'info>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -39,9 +55,6 @@ pub fn handler(
 ) -> Result<()> {
     require!(username.len() <= 32, SolSocialError::UsernameTooLong);
     require!(username.len() >= 3, SolSocialError::UsernameTooShort);
-    require!(display_name.len() <= 64, SolSocialError::DisplayNameTooLong);
-    require!(bio.len() <= 280, SolSocialError::BioTooLong);
-    require!(avatar_url.len() <= 200, SolSocialError::AvatarUrlTooLong);
     
     // Validate username contains only alphanumeric characters and underscores
     for c in username.chars() {
@@ -55,22 +68,29 @@ pub fn handler(
     let user_keys = &mut ctx.accounts.user_keys;
     let clock = Clock::get()?;
     
-    // Initialize user account
-    user.authority = ctx.accounts.authority.key();
+    // Initialize user account; `User::initialize` enforces the profile field limits of the layout
+    user.initialize(
+        ctx.accounts.authority.key(),
+        display_name,
+        bio,
+        avatar_url,
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        &clock,
+    )?;
     user.username = username;
-    user.display_name = display_name;
-    user.bio = bio;
-    user.avatar_url = avatar_url;
-    user.followers_count = 0;
-    user.following_count = 0;
-    user.posts_count = 0;
-    user.keys_held = 0;
-    user.total_earned = 0;
-    user.created_at = clock.unix_timestamp;
-    user.updated_at = clock.unix_timestamp;
-    user.is_verified = false;
     user.bump = ctx.bumps.user;
     
+    // Reserve the username
+    ctx.accounts.username_record.initialize(
+        user.username.clone(),
+        ctx.accounts.authority.key(),
+        &clock,
+        ctx.bumps.username_record,
+    )?;
+    
     // Initialize user keys account
     user_keys.authority = ctx.accounts.authority.key();
     user_keys.supply = 0;
@@ -80,10 +100,29 @@ pub fn handler(
     user_keys.created_at = clock.unix_timestamp;
     user_keys.bump = ctx.bumps.user_keys;
     
+    // Bind the referrer for good; their fee is routed on every later trade
+    if let Some(referrer_user) = &ctx.accounts.referrer_user {
+        let referral = ctx.accounts.referral.as_ref().ok_or(SolSocialError::MissingRequiredAccount)?;
+        let authority = ctx.accounts.authority.key();
+        let (_, bump) = get_referral_pda(&authority, ctx.program_id);
+        Referral::new(authority, referrer_user.authority, clock.unix_timestamp, bump)?.record(
+            &referral.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+        )?;
+        
+        emit!(ReferralRecorded {
+            user: authority,
+            referrer: referrer_user.authority,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
     emit!(UserInitialized {
         authority: ctx.accounts.authority.key(),
         username: user.username.clone(),
-        display_name: user.display_name.clone(),
+        display_name: user.name.clone(),
         timestamp: clock.unix_timestamp,
     });
     
@@ -97,4 +136,11 @@ pub struct UserInitialized {
     pub display_name: String,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct ReferralRecorded {
+    pub user: Pubkey,
+    pub referrer: Pubkey,
+    pub timestamp: i64,
+}
 ```
\ No newline at end of file
@@ -1,6 +1,9 @@
 ```rust
 use anchor_lang::prelude::*;
-use crate::state::{User, Post, PostInteraction, InteractionType};
+use crate::state::{
+    User, Post, PostInteraction, InteractionType, UserKeys, KeyHolder, UserFollow, EngagementScore, ProtocolConfig,
+    Feature, SEED_FOLLOWING,
+};
 use crate::error::SolSocialError;
 
 #[derive(Accounts)]
@@ -29,9 +32,37 @@ pub struct InteractPost<'info> {
     )]
     pub interaction: Account<'info, PostInteraction>,
 
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = EngagementScore::LEN,
+        seeds = [b"engagement", post.author.as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub engagement: Account<'info, EngagementScore>,
+
+    /// Key market gating the post, required when `post.gated_market` is set
+    pub gating_keys: Option<Account<'info, UserKeys>>,
+
+    /// The interactor's holding in the gating market
+    pub key_holder: Option<Account<'info, KeyHolder>>,
+
+    /// The interactor's follow of the author, required for followers-only posts
+    #[account(
+        seeds = [SEED_FOLLOWING, authority.key().as_ref(), post.author.as_ref()],
+        bump,
+    )]
+    pub follow: Option<Account<'info, UserFollow>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -40,6 +71,20 @@ pub fn interact_post(
     interaction_type: InteractionType,
     comment_text: Option<String>,
 ) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Posting)?;
+    // Tips move lamports, so they only go through `tip_post`
+    require!(interaction_type != InteractionType::Tip, SolSocialError::OperationNotAllowed);
+    ctx.accounts.post.check_gate(
+        &ctx.accounts.authority.key(),
+        ctx.accounts.gating_keys.as_ref().map(|keys| (keys.key(), &**keys)),
+        ctx.accounts.key_holder.as_deref(),
+    )?;
+    ctx.accounts.post.check_visibility(
+        &ctx.accounts.authority.key(),
+        ctx.accounts.key_holder.as_deref(),
+        ctx.accounts.follow.as_deref(),
+    )?;
+
     let user = &mut ctx.accounts.user;
     let post = &mut ctx.accounts.post;
     let interaction = &mut ctx.accounts.interaction;
@@ -58,6 +103,8 @@ pub fn interact_post(
 
     // Check if this is a new interaction or updating existing
     let is_new_interaction = interaction.user == Pubkey::default();
+    let scores_engagement = post.author != ctx.accounts.authority.key()
+        && (is_new_interaction || interaction.interaction_type != interaction_type);
 
     if is_new_interaction {
         // Initialize new interaction
@@ -85,6 +132,7 @@ pub fn interact_post(
                 post.shares += 1;
                 user.total_shares += 1;
             },
+            InteractionType::Tip => {},
         }
     } else {
         // Update existing interaction
@@ -106,6 +154,7 @@ pub fn interact_post(
                     post.shares = post.shares.saturating_sub(1);
                     user.total_shares = user.total_shares.saturating_sub(1);
                 },
+                InteractionType::Tip => {},
             }
 
             // Increment new type counters
@@ -122,6 +171,7 @@ pub fn interact_post(
                     post.shares += 1;
                     user.total_shares += 1;
                 },
+                InteractionType::Tip => {},
             }
 
             interaction.interaction_type = interaction_type;
@@ -140,6 +190,17 @@ pub fn interact_post(
     // Update post engagement score
     post.engagement_score = calculate_engagement_score(post.likes, post.comments, post.shares);
 
+    // Credit the fan's per-creator tally that drives engagement kickbacks
+    let engagement = &mut ctx.accounts.engagement;
+    if engagement.user == Pubkey::default() {
+        engagement.user = ctx.accounts.authority.key();
+        engagement.creator = post.author;
+        engagement.bump = ctx.bumps.engagement;
+    }
+    if scores_engagement {
+        engagement.record(&interaction.interaction_type, Clock::get()?.unix_timestamp)?;
+    }
+
     // Update user activity timestamp
     user.last_activity = Clock::get()?.unix_timestamp;
 
@@ -222,6 +283,7 @@ pub fn remove_interaction(ctx: Context<RemoveInteraction>) -> Result<()> {
             post.shares = post.shares.saturating_sub(1);
             user.total_shares = user.total_shares.saturating_sub(1);
         },
+        InteractionType::Tip => {},
     }
 
     // Recalculate engagement score
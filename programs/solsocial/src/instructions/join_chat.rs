@@ -0,0 +1,80 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{User, GroupChatRoom, ChatParticipant, ProtocolConfig, Feature};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(chat_id: String)]
+pub struct JoinChat<'info> {
+    #[account(
+        mut,
+        seeds = [b"chat", chat_id.as_bytes()],
+        bump = chat.bump,
+        constraint = chat.is_active @ SolSocialError::ChatInactive,
+        constraint = !chat.is_private @ SolSocialError::NotAuthorizedForChat
+    )]
+    pub chat: Account<'info, GroupChatRoom>,
+
+    #[account(
+        seeds = [b"user", member.key().as_ref()],
+        bump = member_user.bump,
+        constraint = member_user.is_initialized() @ SolSocialError::UserNotInitialized
+    )]
+    pub member_user: Account<'info, User>,
+
+    #[account(
+        init,
+        payer = member,
+        space = ChatParticipant::LEN,
+        seeds = [b"chat_participant", chat.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub participant: Account<'info, ChatParticipant>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<JoinChat>, _chat_id: String) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Chat)?;
+    let chat = &mut ctx.accounts.chat;
+    let participant = &mut ctx.accounts.participant;
+    let member = &ctx.accounts.member;
+
+    chat.add_participant()?;
+
+    participant.initialize(chat.key().to_bytes(), member.key(), ctx.bumps.participant)?;
+
+    // Rooms with auto-mute enabled hold new members silent until an admin unmutes them
+    if chat.auto_mute_new {
+        participant.toggle_mute()?;
+    }
+
+    emit!(ChatJoinedEvent {
+        chat: chat.key(),
+        member: member.key(),
+        is_muted: participant.is_muted,
+        participant_count: chat.participant_count,
+        joined_at: participant.joined_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ChatJoinedEvent {
+    pub chat: Pubkey,
+    pub member: Pubkey,
+    pub is_muted: bool,
+    pub participant_count: u32,
+    pub joined_at: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,82 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{KeyLoan, PriceOracle, UserKeys, KEY_LOAN_SEED, PRICE_ORACLE_SEED};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct Liquidate<'info> {
+    pub liquidator: Signer<'info>,
+
+    /// Receives the seized collateral with the loan's rent
+    #[account(mut)]
+    pub lender: SystemAccount<'info>,
+
+    /// CHECK: The subject whose keys are lent
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(
+        mut,
+        close = lender,
+        has_one = lender @ SolSocialError::InvalidAccountOwner,
+        seeds = [KEY_LOAN_SEED, lender.key().as_ref(), subject.key().as_ref(), &loan.loan_id.to_le_bytes()],
+        bump = loan.bump,
+    )]
+    pub loan: Account<'info, KeyLoan>,
+
+    #[account(
+        seeds = [PRICE_ORACLE_SEED, keys_account.key().as_ref()],
+        bump = price_oracle.bump,
+    )]
+    pub price_oracle: Account<'info, PriceOracle>,
+}
+
+/// Seize the collateral of an overdue or under-collateralised loan for the lender.
+/// Permissionless so lenders can rely on keepers; the borrower keeps the keys.
+pub fn handler(ctx: Context<Liquidate>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let loan = &ctx.accounts.loan;
+    require!(!loan.is_open(), SolSocialError::LoanNotActive);
+
+    let price = ctx.accounts.price_oracle
+        .twap_over(now, PriceOracle::TWAP_WINDOW_SECONDS)
+        .ok_or(SolSocialError::OraclePriceUnavailable)?;
+    require!(loan.is_liquidatable(price, now)?, SolSocialError::LoanNotLiquidatable);
+
+    emit!(LoanLiquidatedEvent {
+        loan: loan.key(),
+        lender: loan.lender,
+        borrower: loan.borrower,
+        keys_user: loan.keys_user,
+        liquidator: ctx.accounts.liquidator.key(),
+        amount: loan.amount,
+        seized_collateral: loan.collateral,
+        oracle_price: price,
+        overdue: now > loan.due_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct LoanLiquidatedEvent {
+    pub loan: Pubkey,
+    pub lender: Pubkey,
+    pub borrower: Pubkey,
+    pub keys_user: Pubkey,
+    pub liquidator: Pubkey,
+    pub amount: u64,
+    pub seized_collateral: u64,
+    /// Oracle TWAP per key at liquidation
+    pub oracle_price: u64,
+    /// Whether the loan was liquidated for passing its due date rather than its ratio
+    pub overdue: bool,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,43 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::User;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct MigrateUser<'info> {
+    /// Seeds are checked against the canonical bump since version 0 accounts never stored one
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump,
+        has_one = authority @ SolSocialError::Unauthorized
+    )]
+    pub user: Account<'info, User>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Move a profile created before the unified layout onto `User::CURRENT_VERSION`
+pub fn handler(ctx: Context<MigrateUser>) -> Result<()> {
+    let user = &mut ctx.accounts.user;
+    let from_version = user.version;
+    user.migrate(ctx.bumps.user)?;
+
+    emit!(UserMigratedEvent {
+        user: user.key(),
+        from_version,
+        to_version: user.version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct UserMigratedEvent {
+    pub user: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
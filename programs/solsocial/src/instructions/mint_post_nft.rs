@@ -0,0 +1,168 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::mpl_token_metadata::types::{Creator, DataV2};
+use anchor_spl::metadata::mpl_token_metadata::{MAX_NAME_LENGTH, MAX_URI_LENGTH};
+use anchor_spl::metadata::{
+    create_master_edition_v3, create_metadata_accounts_v3, CreateMasterEditionV3,
+    CreateMetadataAccountsV3, Metadata,
+};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::{
+    compute_content_hash, post_nft_uri, Post, ProtocolConfig, Feature, POST_NFT_MINT_SEED, POST_NFT_SYMBOL,
+};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct MintPostNft<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = author @ SolSocialError::Unauthorized,
+    )]
+    pub post: Account<'info, Post>,
+
+    /// One mint per post, so a second mint of the same post can't be created
+    #[account(
+        init,
+        payer = author,
+        seeds = [POST_NFT_MINT_SEED, post.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = author,
+        mint::freeze_authority = author,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = author,
+        associated_token::mint = mint,
+        associated_token::authority = author,
+    )]
+    pub author_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Created by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref(), b"edition"],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Mint the author's post as a one-of-one NFT. The metadata URI carries the post's content
+/// hash and the author is the sole verified creator, earning `royalty_bps` on resales.
+pub fn handler(ctx: Context<MintPostNft>, name: String, uri: String, royalty_bps: u16) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Posting)?;
+    require!(!name.is_empty() && name.len() <= MAX_NAME_LENGTH, SolSocialError::InvalidNftMetadata);
+    require!(royalty_bps <= 10_000, SolSocialError::InvalidNftMetadata);
+
+    let post = &mut ctx.accounts.post;
+    let content_hash = compute_content_hash(&post.content, &post.media);
+    let metadata_uri = post_nft_uri(&uri, &content_hash);
+    require!(metadata_uri.len() <= MAX_URI_LENGTH, SolSocialError::InvalidNftMetadata);
+
+    post.mark_minted(ctx.accounts.mint.key())?;
+
+    let author = ctx.accounts.author.to_account_info();
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.author_token_account.to_account_info(),
+        authority: author.clone(),
+    };
+    token::mint_to(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), 1)?;
+
+    let data = DataV2 {
+        name,
+        symbol: POST_NFT_SYMBOL.to_string(),
+        uri: metadata_uri,
+        seller_fee_basis_points: royalty_bps,
+        creators: Some(vec![Creator {
+            address: ctx.accounts.author.key(),
+            verified: true,
+            share: 100,
+        }]),
+        collection: None,
+        uses: None,
+    };
+    let cpi_accounts = CreateMetadataAccountsV3 {
+        metadata: ctx.accounts.metadata.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        mint_authority: author.clone(),
+        payer: author.clone(),
+        update_authority: author.clone(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    create_metadata_accounts_v3(
+        CpiContext::new(ctx.accounts.token_metadata_program.to_account_info(), cpi_accounts),
+        data,
+        false,
+        true,
+        None,
+    )?;
+
+    // The master edition takes over the mint, capping supply at the one token minted above
+    let cpi_accounts = CreateMasterEditionV3 {
+        edition: ctx.accounts.master_edition.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        update_authority: author.clone(),
+        mint_authority: author.clone(),
+        payer: author,
+        metadata: ctx.accounts.metadata.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    create_master_edition_v3(
+        CpiContext::new(ctx.accounts.token_metadata_program.to_account_info(), cpi_accounts),
+        Some(0),
+    )?;
+
+    emit!(PostMintedEvent {
+        post: ctx.accounts.post.key(),
+        author: ctx.accounts.author.key(),
+        mint: ctx.accounts.mint.key(),
+        content_hash,
+        royalty_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PostMintedEvent {
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub mint: Pubkey,
+    pub content_hash: [u8; 32],
+    pub royalty_bps: u16,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -4,16 +4,202 @@ pub mod create_keys;
 pub mod buy_keys;
 pub mod sell_keys;
 pub mod create_post;
+pub mod create_post_hashed;
+pub mod create_thread_post;
+pub mod verify_post_body;
+pub mod expire_post;
 pub mod interact_post;
 pub mod create_chat;
 pub mod send_message;
+pub mod sweep_expired_orders;
+pub mod place_limit_order;
+pub mod cancel_order;
+pub mod fill_order;
+pub mod create_trigger_order;
+pub mod execute_trigger_order;
+pub mod cancel_trigger_order;
+pub mod create_dca_schedule;
+pub mod execute_dca;
+pub mod cancel_dca_schedule;
+pub mod create_otc_offer;
+pub mod accept_otc_offer;
+pub mod cancel_otc_offer;
+pub mod create_loan_offer;
+pub mod take_loan;
+pub mod repay_loan;
+pub mod liquidate;
+pub mod cancel_loan_offer;
+pub mod share_post;
+pub mod repost;
+pub mod tip_post;
+pub mod unlock_post;
+pub mod boost_post;
+pub mod end_boost;
+pub mod mint_post_nft;
+pub mod bookmark_post;
+pub mod remove_bookmark;
+pub mod create_collection;
+pub mod delete_collection;
+pub mod create_comment;
+pub mod delete_comment;
+pub mod initialize_config;
+pub mod update_config;
+pub mod pending_change;
+pub mod set_feature_flags;
+pub mod reclaim_username;
+pub mod join_chat;
+pub mod set_participant_mute;
+pub mod buy_keys_exact;
+pub mod buy_keys_batch;
+pub mod buy_keys_exact_in;
+pub mod sell_all_keys;
+pub mod transfer_keys;
+pub mod wrap_keys;
+pub mod unwrap_keys;
+pub mod gift_keys;
+pub mod stake_keys;
+pub mod unstake_keys;
+pub mod claim_staking_rewards;
+pub mod open_dividend_epoch;
+pub mod claim_dividends;
+pub mod sweep_dividend_epoch;
+pub mod claim_earnings;
+pub mod claim_early_supporter_rewards;
+pub mod create_shared_keys;
+pub mod distribute_shared_earnings;
+pub mod treasury;
+pub mod admin_multisig;
+pub mod distribute_engagement_rewards;
+pub mod finalize_activity_day;
+pub mod create_holder_vote;
+pub mod cast_holder_vote;
+pub mod finalize_holder_vote;
+pub mod create_raffle;
+pub mod draw_raffle;
+pub mod fee_exemption;
+pub mod set_trading_hours;
+pub mod trading_halt;
+pub mod graduate_market;
+pub mod release_graduated_keys;
+pub mod fund_tip_match;
+pub mod queue_sell;
+pub mod process_sell_tranche;
+pub mod cancel_queued_sell;
+pub mod commit_buy;
+pub mod reveal_buy;
+pub mod bid_launch_auction;
+pub mod buy_presale_keys;
+pub mod refund_buy_commitment;
+pub mod update_chat_settings;
+pub mod refresh_top_markets;
+pub mod claim_all_earnings;
+pub mod claim_vested_keys;
+pub mod buyback_keys;
+pub mod set_payment_mint;
+pub mod pin_message;
+pub mod set_guardians;
+pub mod recover_account;
+pub mod migrate_user;
 
 pub use initialize_user::*;
 pub use create_keys::*;
 pub use buy_keys::*;
 pub use sell_keys::*;
 pub use create_post::*;
+pub use create_post_hashed::*;
+pub use create_thread_post::*;
+pub use verify_post_body::*;
+pub use expire_post::*;
 pub use interact_post::*;
 pub use create_chat::*;
 pub use send_message::*;
+pub use sweep_expired_orders::*;
+pub use place_limit_order::*;
+pub use cancel_order::*;
+pub use fill_order::*;
+pub use create_trigger_order::*;
+pub use execute_trigger_order::*;
+pub use cancel_trigger_order::*;
+pub use create_dca_schedule::*;
+pub use execute_dca::*;
+pub use cancel_dca_schedule::*;
+pub use create_otc_offer::*;
+pub use accept_otc_offer::*;
+pub use cancel_otc_offer::*;
+pub use create_loan_offer::*;
+pub use take_loan::*;
+pub use repay_loan::*;
+pub use liquidate::*;
+pub use cancel_loan_offer::*;
+pub use share_post::*;
+pub use repost::*;
+pub use tip_post::*;
+pub use unlock_post::*;
+pub use boost_post::*;
+pub use end_boost::*;
+pub use mint_post_nft::*;
+pub use bookmark_post::*;
+pub use remove_bookmark::*;
+pub use create_collection::*;
+pub use delete_collection::*;
+pub use create_comment::*;
+pub use delete_comment::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use pending_change::*;
+pub use set_feature_flags::*;
+pub use reclaim_username::*;
+pub use join_chat::*;
+pub use set_participant_mute::*;
+pub use buy_keys_exact::*;
+pub use buy_keys_batch::*;
+pub use buy_keys_exact_in::*;
+pub use sell_all_keys::*;
+pub use transfer_keys::*;
+pub use wrap_keys::*;
+pub use unwrap_keys::*;
+pub use gift_keys::*;
+pub use stake_keys::*;
+pub use unstake_keys::*;
+pub use claim_staking_rewards::*;
+pub use open_dividend_epoch::*;
+pub use claim_dividends::*;
+pub use sweep_dividend_epoch::*;
+pub use claim_earnings::*;
+pub use claim_early_supporter_rewards::*;
+pub use create_shared_keys::*;
+pub use distribute_shared_earnings::*;
+pub use treasury::*;
+pub use admin_multisig::*;
+pub use distribute_engagement_rewards::*;
+pub use finalize_activity_day::*;
+pub use create_holder_vote::*;
+pub use cast_holder_vote::*;
+pub use finalize_holder_vote::*;
+pub use create_raffle::*;
+pub use draw_raffle::*;
+pub use fee_exemption::*;
+pub use set_trading_hours::*;
+pub use trading_halt::*;
+pub use graduate_market::*;
+pub use release_graduated_keys::*;
+pub use fund_tip_match::*;
+pub use queue_sell::*;
+pub use process_sell_tranche::*;
+pub use cancel_queued_sell::*;
+pub use commit_buy::*;
+pub use reveal_buy::*;
+pub use bid_launch_auction::*;
+pub use buy_presale_keys::*;
+pub use refund_buy_commitment::*;
+pub use update_chat_settings::*;
+pub use refresh_top_markets::*;
+pub use claim_all_earnings::*;
+pub use claim_vested_keys::*;
+pub use buyback_keys::*;
+pub use set_payment_mint::*;
+pub use pin_message::*;
+pub use set_guardians::*;
+pub use recover_account::*;
+pub use migrate_user::*;
 ```
\ No newline at end of file
@@ -0,0 +1,126 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{
+    DividendEpoch, DividendSource, EarningsVault, ProtocolConfig, RewardPool, UserKeys, DIVIDEND_EPOCH_SEED,
+    EARNINGS_VAULT_SEED,
+};
+use crate::utils::accounts::{move_lamports, withdrawable_lamports};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct OpenDividendEpoch<'info> {
+    /// The creator for `CreatorEarnings`, the protocol admin for `RewardPool`
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// CHECK: The subject whose holders receive the dividend
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(
+        init,
+        payer = funder,
+        space = DividendEpoch::LEN,
+        seeds = [DIVIDEND_EPOCH_SEED, subject.key().as_ref(), &keys_account.dividend_epochs.to_le_bytes()],
+        bump,
+    )]
+    pub epoch: Account<'info, DividendEpoch>,
+
+    /// Where the creator's fees accrue; funds `CreatorEarnings` epochs
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set `amount` lamports aside for the market's current holders. Holders claim their
+/// share against the circulating supply right now through `claim_dividends`; whatever
+/// they leave goes back through `sweep_dividend_epoch` once the claim window closes.
+pub fn handler(ctx: Context<OpenDividendEpoch>, amount: u64, source: DividendSource) -> Result<()> {
+    let funder = ctx.accounts.funder.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    // Take the lamports out of the source's books before moving them
+    let source_info = match source {
+        DividendSource::CreatorEarnings => {
+            require_keys_eq!(funder, ctx.accounts.keys_account.user, SolSocialError::Unauthorized);
+
+            // Creator fees are paid into the vault as trades happen; the market's own
+            // lamports are the curve's reserve and never fund a dividend
+            let earnings_vault = &mut ctx.accounts.earnings_vault;
+            earnings_vault.require_distributed()?;
+            let info = earnings_vault.to_account_info();
+            require!(amount <= withdrawable_lamports(&Rent::get()?, &info), SolSocialError::InsufficientFunds);
+            earnings_vault.record_claim(amount, now)?;
+            info
+        }
+        DividendSource::RewardPool => {
+            require_keys_eq!(funder, ctx.accounts.config.admin, SolSocialError::Unauthorized);
+            ctx.accounts.reward_pool.record_distribution(amount)?;
+            ctx.accounts.reward_pool.to_account_info()
+        }
+    };
+
+    let keys_account = &mut ctx.accounts.keys_account;
+    let epoch_index = keys_account.next_dividend_epoch()?;
+    let epoch = &mut ctx.accounts.epoch;
+    epoch.initialize(
+        ctx.accounts.subject.key(),
+        funder,
+        epoch_index,
+        source,
+        amount,
+        keys_account.circulating_supply(),
+        now,
+        ctx.bumps.epoch,
+    )?;
+
+    move_lamports(&source_info, &epoch.to_account_info(), amount)?;
+
+    emit!(DividendEpochOpenedEvent {
+        epoch: epoch.key(),
+        subject: epoch.subject,
+        epoch_index,
+        source,
+        amount,
+        supply_snapshot: epoch.supply_snapshot,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DividendEpochOpenedEvent {
+    pub epoch: Pubkey,
+    pub subject: Pubkey,
+    pub epoch_index: u64,
+    pub source: DividendSource,
+    pub amount: u64,
+    pub supply_snapshot: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,92 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{PendingChange, ProtocolConfig, PENDING_CHANGE_SEED};
+use crate::error::SolSocialError;
+use super::update_config::ConfigUpdatedEvent;
+
+#[derive(Accounts)]
+pub struct ExecutePendingChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [PENDING_CHANGE_SEED, config.key().as_ref(), &pending_change.index.to_le_bytes()],
+        bump = pending_change.bump,
+        has_one = config,
+        has_one = proposer,
+        // A change queued by a replaced admin doesn't outlive them
+        constraint = pending_change.proposer == config.admin @ SolSocialError::Unauthorized,
+        close = proposer
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
+
+/// Apply a queued config change once its timelock has run out. Anyone may crank it.
+pub fn execute_handler(ctx: Context<ExecutePendingChange>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pending_change = &ctx.accounts.pending_change;
+    require!(pending_change.is_ready(now), SolSocialError::TimelockNotElapsed);
+
+    let config = &mut ctx.accounts.config;
+    config.apply_update(&pending_change.update, now)?;
+
+    emit!(ConfigUpdatedEvent::snapshot(config, now));
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelPendingChange<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        has_one = admin @ SolSocialError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [PENDING_CHANGE_SEED, config.key().as_ref(), &pending_change.index.to_le_bytes()],
+        bump = pending_change.bump,
+        has_one = config,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
+
+/// Drop a queued config change before it executes
+pub fn cancel_handler(ctx: Context<CancelPendingChange>) -> Result<()> {
+    let pending_change = &ctx.accounts.pending_change;
+
+    emit!(ConfigChangeCancelledEvent {
+        admin: ctx.accounts.admin.key(),
+        pending_change: pending_change.key(),
+        index: pending_change.index,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigChangeCancelledEvent {
+    pub admin: Pubkey,
+    pub pending_change: Pubkey,
+    pub index: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,95 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{GroupChatRoom, ChatParticipant, ChatMessage};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(chat_id: String)]
+pub struct PinMessage<'info> {
+    #[account(
+        mut,
+        seeds = [b"chat", chat_id.as_bytes()],
+        bump = chat.bump
+    )]
+    pub chat: Account<'info, GroupChatRoom>,
+
+    #[account(
+        seeds = [b"chat_participant", chat.key().as_ref(), admin.key().as_ref()],
+        bump = admin_participant.bump,
+        constraint = admin_participant.is_admin @ SolSocialError::NotAuthorizedForChat
+    )]
+    pub admin_participant: Account<'info, ChatParticipant>,
+
+    #[account(
+        constraint = message.room_id == chat.key().to_bytes() @ SolSocialError::MessageNotInChat,
+        constraint = !message.is_deleted @ SolSocialError::OperationNotAllowed
+    )]
+    pub message: Account<'info, ChatMessage>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Pin `message` to the top of the room, replacing any earlier pin
+pub fn pin_handler(ctx: Context<PinMessage>, _chat_id: String) -> Result<()> {
+    let chat = &mut ctx.accounts.chat;
+    let message = ctx.accounts.message.key();
+
+    chat.pinned_message = Some(message.to_bytes());
+    chat.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(MessagePinnedEvent {
+        chat: chat.key(),
+        message: Some(message),
+        admin: ctx.accounts.admin.key(),
+        timestamp: chat.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chat_id: String)]
+pub struct UnpinMessage<'info> {
+    #[account(
+        mut,
+        seeds = [b"chat", chat_id.as_bytes()],
+        bump = chat.bump,
+        constraint = chat.pinned_message.is_some() @ SolSocialError::OperationNotAllowed
+    )]
+    pub chat: Account<'info, GroupChatRoom>,
+
+    #[account(
+        seeds = [b"chat_participant", chat.key().as_ref(), admin.key().as_ref()],
+        bump = admin_participant.bump,
+        constraint = admin_participant.is_admin @ SolSocialError::NotAuthorizedForChat
+    )]
+    pub admin_participant: Account<'info, ChatParticipant>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn unpin_handler(ctx: Context<UnpinMessage>, _chat_id: String) -> Result<()> {
+    let chat = &mut ctx.accounts.chat;
+
+    chat.pinned_message = None;
+    chat.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(MessagePinnedEvent {
+        chat: chat.key(),
+        message: None,
+        admin: ctx.accounts.admin.key(),
+        timestamp: chat.updated_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MessagePinnedEvent {
+    pub chat: Pubkey,
+    /// The newly pinned message, or `None` when the pin was removed
+    pub message: Option<Pubkey>,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,172 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, LimitOrder, OrderSide, UserKeys, KEY_HOLDER_SEED, LIMIT_ORDER_ESCROW_SEED, LIMIT_ORDER_SEED, ProtocolConfig, Feature};
+use crate::utils::revenue_share::calculate_fee;
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(side: OrderSide, price_per_key: u64, amount: u64, expires_at: i64, order_id: u64)]
+pub struct PlaceLimitOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: The subject whose keys the order trades
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = LimitOrder::LEN,
+        seeds = [LIMIT_ORDER_SEED, owner.key().as_ref(), subject.key().as_ref(), &order_id.to_le_bytes()],
+        bump,
+    )]
+    pub order: Account<'info, LimitOrder>,
+
+    /// Holds the keys of sell orders until they fill or close; buy orders escrow lamports in `order` itself
+    #[account(
+        init,
+        payer = owner,
+        token::mint = mint,
+        token::authority = order,
+        seeds = [LIMIT_ORDER_ESCROW_SEED, order.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    /// Sell orders escrow keys out of this record; buy orders make sure it exists for the fill
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, owner.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<PlaceLimitOrder>,
+    side: OrderSide,
+    price_per_key: u64,
+    amount: u64,
+    expires_at: i64,
+    order_id: u64,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let now = Clock::get()?.unix_timestamp;
+    let owner = ctx.accounts.owner.key();
+    let subject = ctx.accounts.subject.key();
+
+    let order = &mut ctx.accounts.order;
+    order.initialize(owner, subject, side, price_per_key, amount, order_id, now, expires_at, ctx.bumps.order)?;
+
+    let key_holder = &mut ctx.accounts.key_holder;
+    if key_holder.holder == Pubkey::default() {
+        **key_holder = KeyHolder::new(owner, subject);
+    }
+
+    match side {
+        OrderSide::Buy => {
+            // Escrow the full limit in the order account, plus the buy fees charged on top of it
+            let params = ctx.accounts.keys_account.curve_params;
+            let limit = order.limit_total()?;
+            order.escrowed_lamports = calculate_fee(limit, params.buy_protocol_fee)?
+                .checked_add(calculate_fee(limit, params.buy_creator_fee)?)
+                .and_then(|fees| fees.checked_add(limit))
+                .ok_or(SolSocialError::ArithmeticOverflow)?;
+            let ix = anchor_lang::solana_program::system_instruction::transfer(
+                &owner,
+                &order.key(),
+                order.escrowed_lamports,
+            );
+            anchor_lang::solana_program::program::invoke(
+                &ix,
+                &[
+                    ctx.accounts.owner.to_account_info(),
+                    order.to_account_info(),
+                ],
+            )?;
+        }
+        OrderSide::Sell => {
+            require!(
+                key_holder.amount >= amount && ctx.accounts.owner_token_account.amount >= amount,
+                SolSocialError::InsufficientKeys
+            );
+            key_holder.amount -= amount;
+
+            let keys_seeds = &[b"keys".as_ref(), subject.as_ref(), &[ctx.bumps.keys_account]];
+            let keys_signer = &[&keys_seeds[..]];
+            let freeze = KeyFreeze::new(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.mint.to_account_info(),
+                &ctx.accounts.keys_account,
+                keys_signer,
+            );
+            let owner_token_info = ctx.accounts.owner_token_account.to_account_info();
+            freeze.thaw(&owner_token_info)?;
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+            token::transfer(cpi_ctx, amount)?;
+            freeze.freeze(&owner_token_info)?;
+        }
+    }
+
+    emit!(OrderPlacedEvent {
+        order: order.key(),
+        owner,
+        keys_user: subject,
+        side,
+        price_per_key,
+        amount,
+        order_id,
+        expires_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OrderPlacedEvent {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub keys_user: Pubkey,
+    pub side: OrderSide,
+    pub price_per_key: u64,
+    pub amount: u64,
+    pub order_id: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
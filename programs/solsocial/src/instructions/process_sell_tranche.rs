@@ -0,0 +1,260 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, Token, TokenAccount};
+use crate::state::{User, UserKeys, RecentTrades, PriceOracle, ProtocolConfig, RewardPool, SellQueue, EarningsVault, EARNINGS_VAULT_SEED, Feature};
+use crate::utils::{bonding_curve::calculate_sell_price, revenue_share::calculate_fee};
+use crate::utils::accounts::move_lamports;
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::trade::{record_market_trade, CurveTrade, MarketFeeds};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct ProcessSellTranche<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sell_queue", seller_wallet.key().as_ref(), subject_keys.key().as_ref()],
+        bump = sell_queue.bump,
+    )]
+    pub sell_queue: Account<'info, SellQueue>,
+
+    #[account(
+        mut,
+        seeds = [b"sell_queue_escrow", sell_queue.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    /// CHECK: Seller receiving the proceeds, checked against the queue
+    #[account(mut, address = sell_queue.seller @ SolSocialError::InvalidAccountOwner)]
+    pub seller_wallet: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = sell_queue.subject_keys @ SolSocialError::InvalidAccountOwner,
+    )]
+    pub subject_keys: Account<'info, UserKeys>,
+
+    #[account(
+        mut,
+        seeds = [b"user", subject_keys.user.as_ref()],
+        bump = subject.bump,
+    )]
+    pub subject: Account<'info, User>,
+
+    /// Collects the subject's creator fee
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject_keys.user.as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(mut, address = subject_keys.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_fee"],
+        bump,
+    )]
+    pub protocol_fee_account: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = RecentTrades::LEN,
+        seeds = [b"recent_trades", subject_keys.key().as_ref()],
+        bump,
+    )]
+    pub recent_trades: Account<'info, RecentTrades>,
+
+    /// Price feed for other programs; every curve trade records into it
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = PriceOracle::LEN,
+        seeds = [b"price_oracle", subject_keys.key().as_ref()],
+        bump,
+    )]
+    pub price_oracle: Account<'info, PriceOracle>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProcessSellTranche>) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let subject_keys = &mut ctx.accounts.subject_keys;
+
+    let amount = ctx.accounts.sell_queue
+        .due_tranche(clock.slot)
+        .ok_or(SolSocialError::TrancheNotDue)?;
+
+    subject_keys.require_lamport_settlement()?;
+    if !subject_keys.is_trading_open(now) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::OutsideTradingHours,
+                price: subject_keys.trading_open_at as u64,
+                limit: subject_keys.trading_close_at as u64,
+                amount,
+            },
+            SolSocialError::OperationNotAllowed,
+        ));
+    }
+
+    // Price the tranche exactly like a direct sell of the same size
+    let sell_price = calculate_sell_price(&subject_keys.curve_params, subject_keys.supply, amount)?;
+    let in_grace_window = subject_keys.in_grace_window(now);
+    let (protocol_fee_bps, creator_fee_bps) = if in_grace_window {
+        (0, 0)
+    } else {
+        (subject_keys.curve_params.sell_protocol_fee, subject_keys.curve_params.sell_creator_fee)
+    };
+    let protocol_fee = calculate_fee(sell_price, protocol_fee_bps)?;
+    let creator_fee = calculate_fee(sell_price, creator_fee_bps)?;
+    let reward_pool_amount = ctx.accounts.config.reward_pool_cut(protocol_fee)?;
+
+    let proceeds = sell_price
+        .checked_sub(protocol_fee)
+        .ok_or(SolSocialError::MathOverflow)?
+        .checked_sub(creator_fee)
+        .ok_or(SolSocialError::MathOverflow)?;
+
+    // The tranche waits (and the keeper retries later) while the price is below the seller's floor
+    let min_proceeds = ctx.accounts.sell_queue.min_price_per_key
+        .checked_mul(amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+    if proceeds < min_proceeds {
+        return Err(fail_with_context(
+            TradeErrorContext { kind: TradeErrorKind::SellSlippage, price: proceeds, limit: min_proceeds, amount },
+            SolSocialError::SlippageExceeded,
+        ));
+    }
+
+    // Burn the tranche's keys out of escrow, signed by the queue
+    let seller_key = ctx.accounts.seller_wallet.key();
+    let subject_keys_key = subject_keys.key();
+    let queue_seeds = &[
+        b"sell_queue".as_ref(),
+        seller_key.as_ref(),
+        subject_keys_key.as_ref(),
+        &[ctx.accounts.sell_queue.bump],
+    ];
+    let signer = &[&queue_seeds[..]];
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.escrow.to_account_info(),
+        authority: ctx.accounts.sell_queue.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::burn(cpi_ctx, amount)?;
+
+    subject_keys.supply = subject_keys.supply
+        .checked_sub(amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+    let trade_index = record_market_trade(
+        subject_keys,
+        Some(MarketFeeds {
+            recent_trades: &mut ctx.accounts.recent_trades,
+            recent_trades_bump: ctx.bumps.recent_trades,
+            price_oracle: &mut ctx.accounts.price_oracle,
+            price_oracle_bump: ctx.bumps.price_oracle,
+        }),
+        None,
+        &ctx.accounts.config,
+        &CurveTrade { amount, value: sell_price, creator_fee, protocol_fee, is_buy: false },
+        now,
+    )?;
+
+    // Pay out of the market: seller proceeds, protocol fee less the reward pool's share, creator fee
+    let reserve = subject_keys.to_account_info();
+    let treasury_fee = protocol_fee.checked_sub(reward_pool_amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+    move_lamports(&reserve, &ctx.accounts.seller_wallet, proceeds)?;
+    move_lamports(&reserve, &ctx.accounts.protocol_fee_account.to_account_info(), treasury_fee)?;
+    move_lamports(&reserve, &ctx.accounts.reward_pool.to_account_info(), reward_pool_amount)?;
+    ctx.accounts.reward_pool.record_deposit(reward_pool_amount, now)?;
+    move_lamports(&reserve, &ctx.accounts.earnings_vault.to_account_info(), creator_fee)?;
+    ctx.accounts.earnings_vault.record_accrual(creator_fee)?;
+
+    ctx.accounts.subject.total_earnings = ctx.accounts.subject.total_earnings
+        .checked_add(creator_fee)
+        .ok_or(SolSocialError::MathOverflow)?;
+
+    let sell_queue = &mut ctx.accounts.sell_queue;
+    sell_queue.record_tranche(amount, proceeds, clock.slot)?;
+    let completed = sell_queue.is_complete();
+
+    emit!(SellTrancheProcessedEvent {
+        sell_queue: sell_queue.key(),
+        seller: sell_queue.seller,
+        subject: subject_keys.user,
+        keeper: ctx.accounts.keeper.key(),
+        tranche: sell_queue.tranches_executed,
+        amount,
+        price: sell_price,
+        proceeds,
+        protocol_fee,
+        creator_fee,
+        remaining_amount: sell_queue.remaining_amount,
+        next_tranche_slot: sell_queue.next_tranche_slot,
+        completed,
+        trade_index,
+        timestamp: now,
+    });
+
+    // Last tranche: return the escrow and queue rent to the seller
+    if completed {
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.seller_wallet.to_account_info(),
+            authority: ctx.accounts.sell_queue.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::close_account(cpi_ctx)?;
+
+        ctx.accounts.sell_queue.close(ctx.accounts.seller_wallet.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+#[event]
+pub struct SellTrancheProcessedEvent {
+    pub sell_queue: Pubkey,
+    pub seller: Pubkey,
+    pub subject: Pubkey,
+    pub keeper: Pubkey,
+    pub tranche: u32,
+    pub amount: u64,
+    pub price: u64,
+    pub proceeds: u64,
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    pub remaining_amount: u64,
+    pub next_tranche_slot: u64,
+    pub completed: bool,
+    pub trade_index: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
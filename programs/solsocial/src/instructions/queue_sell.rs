@@ -0,0 +1,136 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{ProtocolConfig, SellQueue, UserKeys, Feature};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct QueueSell<'info> {
+    #[account(mut)]
+    pub seller_wallet: Signer<'info>,
+
+    /// CHECK: The subject whose keys are being sold
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub subject_keys: Account<'info, UserKeys>,
+
+    #[account(address = subject_keys.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = subject_keys.mint,
+        associated_token::authority = seller_wallet,
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = seller_wallet,
+        space = SellQueue::LEN,
+        seeds = [b"sell_queue", seller_wallet.key().as_ref(), subject_keys.key().as_ref()],
+        bump,
+    )]
+    pub sell_queue: Account<'info, SellQueue>,
+
+    /// Holds the queued keys until each tranche sells them
+    #[account(
+        init,
+        payer = seller_wallet,
+        token::mint = mint,
+        token::authority = sell_queue,
+        seeds = [b"sell_queue_escrow", sell_queue.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<QueueSell>,
+    amount: u64,
+    tranche_size: u64,
+    tranche_interval_slots: u64,
+    min_price_per_key: u64,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    require!(ctx.accounts.seller_token_account.amount >= amount, SolSocialError::InsufficientKeys);
+    // Each tranche must itself be small enough to sell directly
+    require!(
+        ctx.accounts.config.allows_direct_sell(tranche_size),
+        SolSocialError::SellAboveQueueThreshold
+    );
+
+    let clock = Clock::get()?;
+    let sell_queue = &mut ctx.accounts.sell_queue;
+    sell_queue.initialize(
+        ctx.accounts.seller_wallet.key(),
+        ctx.accounts.subject_keys.key(),
+        amount,
+        tranche_size,
+        tranche_interval_slots,
+        min_price_per_key,
+        clock.slot,
+        clock.unix_timestamp,
+        ctx.bumps.sell_queue,
+    )?;
+
+    // Escrow the keys being sold
+    let keys_seeds = &[b"keys".as_ref(), ctx.accounts.subject.key.as_ref(), &[ctx.bumps.subject_keys]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.subject_keys,
+        keys_signer,
+    );
+    let seller_token_info = ctx.accounts.seller_token_account.to_account_info();
+    freeze.thaw(&seller_token_info)?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.seller_token_account.to_account_info(),
+        to: ctx.accounts.escrow.to_account_info(),
+        authority: ctx.accounts.seller_wallet.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+    freeze.freeze(&seller_token_info)?;
+
+    emit!(SellQueuedEvent {
+        sell_queue: sell_queue.key(),
+        seller: sell_queue.seller,
+        subject: ctx.accounts.subject.key(),
+        amount,
+        tranche_size,
+        tranche_interval_slots,
+        min_price_per_key,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SellQueuedEvent {
+    pub sell_queue: Pubkey,
+    pub seller: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub tranche_size: u64,
+    pub tranche_interval_slots: u64,
+    pub min_price_per_key: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
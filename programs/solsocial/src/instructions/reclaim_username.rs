@@ -0,0 +1,108 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{User, UserKeys, UsernameRecord};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(username: String)]
+pub struct ReclaimUsername<'info> {
+    #[account(
+        mut,
+        seeds = [b"username", username.as_bytes()],
+        bump = username_record.bump
+    )]
+    pub username_record: Account<'info, UsernameRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"user", username_record.owner.as_ref()],
+        bump
+    )]
+    pub previous_owner_user: Account<'info, User>,
+
+    /// CHECK: The previous owner's key market, which may not exist yet
+    #[account(
+        seeds = [b"keys", username_record.owner.as_ref()],
+        bump
+    )]
+    pub previous_owner_keys: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user", claimant.key().as_ref()],
+        bump,
+        constraint = claimant_user.authority == claimant.key() @ SolSocialError::Unauthorized
+    )]
+    pub claimant_user: Account<'info, User>,
+
+    /// The claimant's current username, released by this reclaim
+    #[account(
+        mut,
+        seeds = [b"username", claimant_user.username.as_bytes()],
+        bump = claimant_username_record.bump,
+        close = claimant
+    )]
+    pub claimant_username_record: Account<'info, UsernameRecord>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ReclaimUsername>, username: String) -> Result<()> {
+    let clock = Clock::get()?;
+    let previous_owner = ctx.accounts.username_record.owner;
+
+    require!(previous_owner != ctx.accounts.claimant.key(), SolSocialError::OperationNotAllowed);
+
+    // Only dormant accounts with no audience can lose their username
+    let previous_owner_user = &ctx.accounts.previous_owner_user;
+    require!(
+        UsernameRecord::is_inactive(previous_owner_user.last_seen(), clock.unix_timestamp),
+        SolSocialError::UsernameNotReclaimable
+    );
+    require!(
+        UsernameRecord::is_inactive(ctx.accounts.username_record.claimed_at, clock.unix_timestamp),
+        SolSocialError::UsernameNotReclaimable
+    );
+    require!(previous_owner_user.follower_count == 0, SolSocialError::UsernameNotReclaimable);
+    require!(!previous_owner_user.is_verified, SolSocialError::UsernameNotReclaimable);
+
+    let keys_info = ctx.accounts.previous_owner_keys.to_account_info();
+    if !keys_info.data_is_empty() {
+        let keys = Account::<UserKeys>::try_from(&keys_info)?;
+        require!(keys.holders_count == 0, SolSocialError::UsernameNotReclaimable);
+    }
+
+    let username_record = &mut ctx.accounts.username_record;
+    username_record.owner = ctx.accounts.claimant.key();
+    username_record.claimed_at = clock.unix_timestamp;
+
+    let previous_owner_user = &mut ctx.accounts.previous_owner_user;
+    previous_owner_user.username = String::new();
+    previous_owner_user.updated_at = clock.unix_timestamp;
+
+    let claimant_user = &mut ctx.accounts.claimant_user;
+    let released_username = std::mem::replace(&mut claimant_user.username, username.clone());
+    claimant_user.updated_at = clock.unix_timestamp;
+    claimant_user.last_activity = clock.unix_timestamp;
+
+    emit!(UsernameReclaimedEvent {
+        username,
+        previous_owner,
+        new_owner: ctx.accounts.claimant.key(),
+        released_username,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct UsernameReclaimedEvent {
+    pub username: String,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub released_username: String,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
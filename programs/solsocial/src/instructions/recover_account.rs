@@ -0,0 +1,131 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{User, Guardians};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct InitiateRecovery<'info> {
+    pub user: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [b"guardians", user.key().as_ref()],
+        bump = guardians.bump,
+        has_one = user
+    )]
+    pub guardians: Account<'info, Guardians>,
+}
+
+/// Start moving `user` to `new_authority`. The approving guardians sign the transaction
+/// and are passed through `remaining_accounts`.
+pub fn initiate_handler(ctx: Context<InitiateRecovery>, new_authority: Pubkey) -> Result<()> {
+    require!(new_authority != Pubkey::default(), SolSocialError::InvalidRecoveryAuthority);
+    require!(new_authority != ctx.accounts.user.authority, SolSocialError::InvalidRecoveryAuthority);
+
+    let signers = ctx
+        .remaining_accounts
+        .iter()
+        .filter(|account| account.is_signer)
+        .map(|account| account.key())
+        .collect::<Vec<_>>();
+
+    let now = Clock::get()?.unix_timestamp;
+    let guardians = &mut ctx.accounts.guardians;
+    guardians.initiate(new_authority, &signers, now)?;
+
+    emit!(RecoveryInitiatedEvent {
+        user: guardians.user,
+        new_authority,
+        approvals: guardians.count_approvals(&signers) as u8,
+        executable_at: guardians.recovery_executable_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecoverAccount<'info> {
+    #[account(mut)]
+    pub user: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [b"guardians", user.key().as_ref()],
+        bump = guardians.bump,
+        has_one = user
+    )]
+    pub guardians: Account<'info, Guardians>,
+}
+
+/// Hand the profile to the pending authority once the delay has passed; anyone may crank it
+pub fn handler(ctx: Context<RecoverAccount>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let new_authority = ctx.accounts.guardians.execute(now)?;
+
+    let user = &mut ctx.accounts.user;
+    let previous_authority = std::mem::replace(&mut user.authority, new_authority);
+    user.updated_at = now;
+
+    emit!(AccountRecoveredEvent {
+        user: user.key(),
+        previous_authority,
+        new_authority,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    #[account(has_one = authority @ SolSocialError::Unauthorized)]
+    pub user: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [b"guardians", user.key().as_ref()],
+        bump = guardians.bump,
+        has_one = user
+    )]
+    pub guardians: Account<'info, Guardians>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn cancel_handler(ctx: Context<CancelRecovery>) -> Result<()> {
+    let cancelled_authority = ctx.accounts.guardians.cancel()?;
+
+    emit!(RecoveryCancelledEvent {
+        user: ctx.accounts.user.key(),
+        cancelled_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RecoveryInitiatedEvent {
+    pub user: Pubkey,
+    pub new_authority: Pubkey,
+    pub approvals: u8,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountRecoveredEvent {
+    pub user: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryCancelledEvent {
+    pub user: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,59 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{MarketRank, TopMarkets, UserKeys};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct RefreshTopMarkets<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TopMarkets::LEN,
+        seeds = [b"top_markets"],
+        bump
+    )]
+    pub top_markets: Account<'info, TopMarkets>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Re-rank the `UserKeys` markets passed through `remaining_accounts`
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, RefreshTopMarkets<'info>>) -> Result<()> {
+    require!(
+        ctx.remaining_accounts.len() <= TopMarkets::CAPACITY,
+        SolSocialError::TooManyAccounts
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let top_markets = &mut ctx.accounts.top_markets;
+    top_markets.bump = ctx.bumps.top_markets;
+
+    for market_info in ctx.remaining_accounts.iter() {
+        let market = Account::<UserKeys>::try_from(market_info)?;
+        top_markets.upsert(MarketRank {
+            market: market_info.key(),
+            total_volume: market.volume,
+            holders_count: market.holders_count,
+            created_at: market.created_at,
+        });
+    }
+    top_markets.updated_at = now;
+
+    emit!(TopMarketsRefreshedEvent {
+        markets: top_markets.ranked().iter().map(|entry| entry.market).collect(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TopMarketsRefreshedEvent {
+    /// Listed markets, best-ranked first
+    pub markets: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
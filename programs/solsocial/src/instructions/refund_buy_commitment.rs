@@ -0,0 +1,48 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::BuyCommitment;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct RefundBuyCommitment<'info> {
+    /// Anyone may return an unrevealed escrow once the deadline has passed
+    pub caller: Signer<'info>,
+
+    /// CHECK: The committing buyer, checked against the commitment
+    #[account(mut, address = commitment.buyer @ SolSocialError::InvalidAccountOwner)]
+    pub buyer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"buy_commitment", buyer.key().as_ref(), commitment.subject_keys.as_ref()],
+        bump = commitment.bump,
+    )]
+    pub commitment: Account<'info, BuyCommitment>,
+}
+
+pub fn handler(ctx: Context<RefundBuyCommitment>) -> Result<()> {
+    let clock = Clock::get()?;
+    let commitment = &ctx.accounts.commitment;
+    require!(commitment.is_expired(clock.slot), SolSocialError::RevealNotAllowed);
+
+    emit!(BuyCommitmentRefundedEvent {
+        commitment: commitment.key(),
+        buyer: commitment.buyer,
+        subject_keys: commitment.subject_keys,
+        refunded: commitment.escrowed_lamports,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BuyCommitmentRefundedEvent {
+    pub commitment: Pubkey,
+    pub buyer: Pubkey,
+    pub subject_keys: Pubkey,
+    pub refunded: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
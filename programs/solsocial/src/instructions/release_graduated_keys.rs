@@ -0,0 +1,66 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::UserKeys;
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct ReleaseGraduatedKeys<'info> {
+    pub caller: Signer<'info>,
+
+    /// CHECK: The subject whose market graduated
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Thaw a holder's key token account once its market has graduated, so the keys can be
+/// traded on the AMM pool. Anyone may release any holder's account.
+pub fn handler(ctx: Context<ReleaseGraduatedKeys>) -> Result<()> {
+    require!(ctx.accounts.keys_account.graduated_at != 0, SolSocialError::MarketNotGraduated);
+
+    let subject = ctx.accounts.subject.key();
+    let keys_seeds = &[b"keys".as_ref(), subject.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.keys_account,
+        keys_signer,
+    );
+    freeze.thaw(&ctx.accounts.holder_token_account.to_account_info())?;
+
+    emit!(GraduatedKeysReleasedEvent {
+        subject,
+        holder: ctx.accounts.holder_token_account.owner,
+        token_account: ctx.accounts.holder_token_account.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GraduatedKeysReleasedEvent {
+    pub subject: Pubkey,
+    pub holder: Pubkey,
+    pub token_account: Pubkey,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,54 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{Bookmark, Collection, BOOKMARK_SEED};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct RemoveBookmark<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        has_one = user @ SolSocialError::Unauthorized,
+        seeds = [BOOKMARK_SEED, user.key().as_ref(), bookmark.post.as_ref()],
+        bump = bookmark.bump,
+    )]
+    pub bookmark: Account<'info, Bookmark>,
+
+    /// The bookmark's collection, required when it is filed under one
+    #[account(mut)]
+    pub collection: Option<Account<'info, Collection>>,
+}
+
+/// Unsave a post, taking it out of its collection
+pub fn handler(ctx: Context<RemoveBookmark>) -> Result<()> {
+    let bookmark = &ctx.accounts.bookmark;
+
+    if let Some(filed_under) = bookmark.collection {
+        let collection = ctx.accounts.collection.as_mut().ok_or(SolSocialError::MissingRequiredAccount)?;
+        require_keys_eq!(collection.key(), filed_under, SolSocialError::InvalidAccountOwner);
+        collection.remove_bookmark();
+    }
+
+    emit!(BookmarkRemovedEvent {
+        bookmark: bookmark.key(),
+        user: bookmark.user,
+        post: bookmark.post,
+        collection: bookmark.collection,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BookmarkRemovedEvent {
+    pub bookmark: Pubkey,
+    pub user: Pubkey,
+    pub post: Pubkey,
+    pub collection: Option<Pubkey>,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
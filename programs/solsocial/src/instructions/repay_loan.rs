@@ -0,0 +1,154 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, KeyLoan, UserKeys, KEY_HOLDER_SEED, KEY_LOAN_SEED};
+use crate::utils::accounts::move_lamports;
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct RepayLoan<'info> {
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    /// Receives the fee, and the loan's rent when it closes
+    #[account(mut)]
+    pub lender: SystemAccount<'info>,
+
+    /// CHECK: The subject whose keys are lent
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Freezes and thaws the holder's key token account
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = lender,
+        has_one = lender @ SolSocialError::InvalidAccountOwner,
+        has_one = borrower @ SolSocialError::Unauthorized,
+        seeds = [KEY_LOAN_SEED, lender.key().as_ref(), subject.key().as_ref(), &loan.loan_id.to_le_bytes()],
+        bump = loan.bump,
+    )]
+    pub loan: Account<'info, KeyLoan>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, borrower.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub borrower_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, lender.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub lender_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = lender,
+    )]
+    pub lender_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Return the borrowed keys to the lender and take back the collateral, less the
+/// lender's fee. Repayment stays open after the due date until someone liquidates.
+pub fn handler(ctx: Context<RepayLoan>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let loan = &ctx.accounts.loan;
+    let amount = loan.amount;
+    let fee = loan.fee();
+    let refund = loan.collateral
+        .checked_sub(fee)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    let borrower_holder = &mut ctx.accounts.borrower_holder;
+    require!(
+        borrower_holder.amount >= amount && ctx.accounts.borrower_token_account.amount >= amount,
+        SolSocialError::InsufficientKeys
+    );
+
+    let keys_seeds = &[b"keys".as_ref(), ctx.accounts.subject.key.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.keys_account,
+        keys_signer,
+    );
+    let borrower_token_info = ctx.accounts.borrower_token_account.to_account_info();
+    let lender_token_info = ctx.accounts.lender_token_account.to_account_info();
+    freeze.thaw(&borrower_token_info)?;
+    freeze.thaw(&lender_token_info)?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.borrower_token_account.to_account_info(),
+        to: ctx.accounts.lender_token_account.to_account_info(),
+        authority: ctx.accounts.borrower.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+    freeze.freeze(&borrower_token_info)?;
+    freeze.freeze(&lender_token_info)?;
+
+    let loan_info = loan.to_account_info();
+    move_lamports(&loan_info, &ctx.accounts.lender.to_account_info(), fee)?;
+    move_lamports(&loan_info, &ctx.accounts.borrower.to_account_info(), refund)?;
+
+    let keys_account = &mut ctx.accounts.keys_account;
+    let borrower_before = borrower_holder.amount;
+    let value_returned = borrower_holder.avg_price.saturating_mul(amount);
+    borrower_holder.update_after_sell(amount, value_returned);
+    keys_account.track_holder_balance(borrower_before, borrower_holder.amount)?;
+
+    // The lent keys left the lender's record when offered; count them back
+    let lender_holder = &mut ctx.accounts.lender_holder;
+    lender_holder.amount = lender_holder.amount
+        .checked_add(amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    emit!(LoanRepaidEvent {
+        loan: loan_info.key(),
+        lender: ctx.accounts.lender.key(),
+        borrower: ctx.accounts.borrower.key(),
+        keys_user: ctx.accounts.subject.key(),
+        amount,
+        fee,
+        refunded_collateral: refund,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct LoanRepaidEvent {
+    pub loan: Pubkey,
+    pub lender: Pubkey,
+    pub borrower: Pubkey,
+    pub keys_user: Pubkey,
+    pub amount: u64,
+    /// Collateral kept by the lender as the loan fee
+    pub fee: u64,
+    pub refunded_collateral: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,114 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{User, Post, UserKeys, KeyHolder, ProtocolConfig, Activity, ActivityTracker, Feature};
+use crate::instructions::finalize_activity_day::touch_activity;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct Repost<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump = user.bump,
+        has_one = authority @ SolSocialError::Unauthorized
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Post::SPACE,
+        seeds = [b"post", authority.key().as_ref(), &user.post_count.to_le_bytes()],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+
+    #[account(mut)]
+    pub original_post: Account<'info, Post>,
+
+    /// Key market gating the original, required when `original_post.gated_market` is set
+    pub gating_keys: Option<Account<'info, UserKeys>>,
+
+    /// The reposter's holding in the gating market
+    pub key_holder: Option<Account<'info, KeyHolder>>,
+
+    /// Opt-in activity heartbeat bucket
+    #[account(
+        mut,
+        seeds = [b"activity_metrics"],
+        bump = activity.bump
+    )]
+    pub activity: Option<Account<'info, ActivityTracker>>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Repost another author's post to your feed, or quote it with your own text. Either
+/// counts as a share of the original and keeps a reference back to it.
+pub fn handler(ctx: Context<Repost>, quote: Option<String>) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Posting)?;
+    let author = ctx.accounts.authority.key();
+    let original = &ctx.accounts.original_post;
+    require!(original.author != author, SolSocialError::CannotInteractOwnPost);
+    original.check_gate(
+        &author,
+        ctx.accounts.gating_keys.as_ref().map(|keys| (keys.key(), &**keys)),
+        ctx.accounts.key_holder.as_deref(),
+    )?;
+
+    let content = match quote {
+        Some(text) => {
+            require!(!text.trim().is_empty(), SolSocialError::PostContentEmpty);
+            text
+        }
+        None => String::new(),
+    };
+
+    let user = &mut ctx.accounts.user;
+    let post = &mut ctx.accounts.post;
+    let visibility = user.resolve_post_visibility(None);
+    post.initialize(author, content, Vec::new(), false, 0, visibility, ctx.bumps.post)?;
+    post.original_post = Some(original.key());
+
+    let original = &mut ctx.accounts.original_post;
+    original.add_share()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    user.post_count = user.post_count.checked_add(1).ok_or(SolSocialError::ArithmeticOverflow)?;
+    touch_activity(ctx.accounts.activity.as_mut(), user.last_activity, Activity::Post)?;
+    user.last_activity = now;
+
+    emit!(PostRepostedEvent {
+        post: post.key(),
+        author,
+        original_post: original.key(),
+        original_author: original.author,
+        quote: post.is_quote().then(|| post.content.clone()),
+        original_shares: original.shares,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PostRepostedEvent {
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub original_post: Pubkey,
+    pub original_author: Pubkey,
+    /// The quoting text, or `None` for a plain repost
+    pub quote: Option<String>,
+    pub original_shares: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,279 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::state::{BuyCommitment, KeyHolder, ProtocolConfig, RewardPool, UserKeys, RecentTrades, PriceOracle, Portfolio, EarningsVault, EARNINGS_VAULT_SEED, KEY_HOLDER_SEED, PORTFOLIO_SEED, Feature};
+use crate::utils::bonding_curve::calculate_buy_price;
+use crate::utils::revenue_share::calculate_fee;
+use crate::utils::accounts::move_lamports;
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::key_token::KeyFreeze;
+use crate::utils::trade::{record_market_trade, CurveTrade, MarketFeeds};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct RevealBuy<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: The subject whose keys are being bought
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Collects the subject's creator fee
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(mut, address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    /// The buyer's holding record; the per-holder cap counts it, as buy_keys does
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, buyer.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    /// Closed back to the buyer, refunding whatever the buy didn't use
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"buy_commitment", buyer.key().as_ref(), keys_account.key().as_ref()],
+        bump = commitment.bump,
+    )]
+    pub commitment: Account<'info, BuyCommitment>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = RecentTrades::LEN,
+        seeds = [b"recent_trades", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub recent_trades: Account<'info, RecentTrades>,
+
+    /// Price feed for other programs; every curve trade records into it
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = PriceOracle::LEN,
+        seeds = [b"price_oracle", keys_account.key().as_ref()],
+        bump,
+    )]
+    pub price_oracle: Account<'info, PriceOracle>,
+
+    /// The buyer's totals across every market
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = Portfolio::LEN,
+        seeds = [PORTFOLIO_SEED, buyer.key().as_ref()],
+        bump,
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RevealBuy>, amount: u64, nonce: u64) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let clock = Clock::get()?;
+    let keys_account = &mut ctx.accounts.keys_account;
+    let commitment = &ctx.accounts.commitment;
+
+    require!(amount > 0, SolSocialError::InvalidAmount);
+    require!(ctx.accounts.config.allows_trade_size(amount), SolSocialError::TradeTooLarge);
+    require!(commitment.can_reveal(clock.slot), SolSocialError::RevealNotAllowed);
+    require!(commitment.matches(amount, nonce), SolSocialError::CommitmentMismatch);
+
+    keys_account.require_lamport_settlement()?;
+    if !keys_account.is_trading_open(clock.unix_timestamp) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::OutsideTradingHours,
+                price: keys_account.trading_open_at as u64,
+                limit: keys_account.trading_close_at as u64,
+                amount,
+            },
+            SolSocialError::OperationNotAllowed,
+        ));
+    }
+    // Guarded launches only accept buy_keys, which enforces the per-wallet limits
+    require!(!keys_account.launch_guard.is_active(clock.slot), SolSocialError::LaunchGuardActive);
+
+    let held = ctx.accounts.key_holder.amount;
+    if !keys_account.curve_params.within_holder_cap(held, amount) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::HolderCapReached,
+                price: held,
+                limit: keys_account.curve_params.max_keys_per_holder,
+                amount,
+            },
+            SolSocialError::HolderCapReached,
+        ));
+    }
+
+    // Execute at the price current at reveal time, bounded by what was escrowed
+    let price = calculate_buy_price(&keys_account.curve_params, keys_account.supply, amount)?;
+    require!(price > 0, SolSocialError::InvalidPrice);
+
+    // Fees are charged on top of the price, and the escrow has to cover both
+    let protocol_fee = calculate_fee(price, keys_account.curve_params.buy_protocol_fee)?;
+    let subject_fee = calculate_fee(price, keys_account.curve_params.buy_creator_fee)?;
+    let reward_pool_amount = ctx.accounts.config.reward_pool_cut(protocol_fee)?;
+    let treasury_fee = protocol_fee
+        .checked_sub(reward_pool_amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+    let total_cost = price
+        .checked_add(protocol_fee)
+        .ok_or(SolSocialError::MathOverflow)?
+        .checked_add(subject_fee)
+        .ok_or(SolSocialError::MathOverflow)?;
+    if total_cost > commitment.escrowed_lamports {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::BuySlippage,
+                price: total_cost,
+                limit: commitment.escrowed_lamports,
+                amount,
+            },
+            SolSocialError::SlippageExceeded,
+        ));
+    }
+
+    // Pay everything out of the escrow; the unused remainder goes back when it closes
+    let escrow = commitment.to_account_info();
+    move_lamports(&escrow, &ctx.accounts.treasury.to_account_info(), treasury_fee)?;
+    move_lamports(&escrow, &ctx.accounts.reward_pool.to_account_info(), reward_pool_amount)?;
+    move_lamports(&escrow, &ctx.accounts.earnings_vault.to_account_info(), subject_fee)?;
+    move_lamports(&escrow, &keys_account.to_account_info(), price)?;
+    ctx.accounts.reward_pool.record_deposit(reward_pool_amount, clock.unix_timestamp)?;
+    ctx.accounts.earnings_vault.record_accrual(subject_fee)?;
+
+    keys_account.supply = keys_account.supply
+        .checked_add(amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+
+    // Mint keys to buyer
+    let cpi_accounts = token::MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.buyer_token_account.to_account_info(),
+        authority: keys_account.to_account_info(),
+    };
+    let seeds = &[
+        b"keys",
+        ctx.accounts.subject.key.as_ref(),
+        &[ctx.bumps.keys_account],
+    ];
+    let signer = &[&seeds[..]];
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let freeze = KeyFreeze::new(&token_program, &ctx.accounts.mint.to_account_info(), keys_account, signer);
+    let buyer_token_info = ctx.accounts.buyer_token_account.to_account_info();
+    freeze.thaw(&buyer_token_info)?;
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+    token::mint_to(cpi_ctx, amount)?;
+    freeze.freeze(&buyer_token_info)?;
+
+    // Record the keys on the buyer's holding record, creating it on their first buy
+    let key_holder = &mut ctx.accounts.key_holder;
+    if key_holder.holder == Pubkey::default() {
+        **key_holder = KeyHolder::new(ctx.accounts.buyer.key(), ctx.accounts.subject.key());
+    }
+    let held_before = key_holder.amount;
+    key_holder.update_after_buy(amount, price / amount, price);
+    keys_account.track_holder_balance(held_before, key_holder.amount)?;
+
+    let now = clock.unix_timestamp;
+    let portfolio = &mut ctx.accounts.portfolio;
+    if portfolio.owner == Pubkey::default() {
+        portfolio.initialize(ctx.accounts.buyer.key(), ctx.bumps.portfolio);
+    }
+    portfolio.record_buy(price, held_before, now)?;
+
+    let trade_index = record_market_trade(
+        keys_account,
+        Some(MarketFeeds {
+            recent_trades: &mut ctx.accounts.recent_trades,
+            recent_trades_bump: ctx.bumps.recent_trades,
+            price_oracle: &mut ctx.accounts.price_oracle,
+            price_oracle_bump: ctx.bumps.price_oracle,
+        }),
+        Some(key_holder),
+        &ctx.accounts.config,
+        &CurveTrade { amount, value: price, creator_fee: subject_fee, protocol_fee, is_buy: true },
+        now,
+    )?;
+
+    emit!(BuyRevealedEvent {
+        commitment: commitment.key(),
+        buyer: ctx.accounts.buyer.key(),
+        subject: ctx.accounts.subject.key(),
+        amount,
+        price,
+        protocol_fee,
+        subject_fee,
+        refunded: commitment.escrowed_lamports - total_cost,
+        supply_after: keys_account.supply,
+        trade_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BuyRevealedEvent {
+    pub commitment: Pubkey,
+    pub buyer: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub price: u64,
+    pub protocol_fee: u64,
+    pub subject_fee: u64,
+    pub refunded: u64,
+    pub supply_after: u64,
+    pub trade_index: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
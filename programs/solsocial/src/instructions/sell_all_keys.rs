@@ -0,0 +1,54 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::instructions::sell_keys::{self, SellKeys};
+use crate::utils::accounts::close_account;
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::error::SolSocialError;
+
+/// Liquidate the caller's whole position in a market and close their `KeyHolder`,
+/// refunding its rent. `min_proceeds` guards what the seller receives after fees.
+pub fn handler(ctx: Context<SellKeys>, min_proceeds: u64) -> Result<()> {
+    let amount = ctx.accounts.key_holder.amount;
+    if amount == 0 {
+        return Err(fail_with_context(
+            TradeErrorContext { kind: TradeErrorKind::InsufficientKeys, price: 0, limit: 0, amount },
+            SolSocialError::InsufficientKeys,
+        ));
+    }
+
+    // The wallet must hold exactly what the holder record tracks, so a position with keys
+    // moved outside the program can't be closed out from under its record
+    require!(
+        ctx.accounts.seller_token_account.amount == amount
+            && ctx.accounts.seller_token_account.delegated_amount == 0,
+        SolSocialError::OperationNotAllowed
+    );
+
+    let key_holder = ctx.accounts.key_holder.to_account_info();
+    let seller_wallet = ctx.accounts.seller_wallet.to_account_info();
+    let subject = ctx.accounts.subject_keys.user;
+
+    sell_keys::sell_keys(ctx, amount, min_proceeds, None, None)?;
+
+    let rent_refunded = close_account(&key_holder, &seller_wallet)?;
+
+    emit!(PositionClosedEvent {
+        seller: seller_wallet.key(),
+        subject,
+        amount,
+        rent_refunded,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PositionClosedEvent {
+    pub seller: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub rent_refunded: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -1,8 +1,14 @@
 ```rust
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{User, UserKeys};
-use crate::utils::{bonding_curve::calculate_sell_price, revenue_share::calculate_protocol_fee};
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::{User, UserKeys, KeyHolder, KeyTransaction, TransactionType, KEY_HOLDER_SEED, ProtocolConfig, FeeExemption, RewardPool, RecentTrades, PriceOracle, Activity, ActivityTracker, Referral, REFERRAL_SEED, EarningsVault, EARNINGS_VAULT_SEED, Feature, PAYMENT_RESERVE_SEED, Portfolio, PORTFOLIO_SEED, EarlySupporterPool, EARLY_SUPPORTER_POOL_SEED};
+use crate::instructions::finalize_activity_day::touch_activity;
+use crate::utils::{bonding_curve::calculate_sell_price, revenue_share::calculate_market_distribution};
+use crate::utils::accounts::move_lamports;
+use crate::utils::payment::TokenSettlement;
+use crate::utils::key_token::KeyFreeze;
+use crate::utils::trade::{record_market_trade, CurveTrade, MarketFeeds};
+use crate::utils::error_context::{fail_with_context, require_before_deadline, require_price_impact_within, TradeErrorContext, TradeErrorKind};
 use crate::error::SolSocialError;
 
 #[derive(Accounts)]
@@ -16,31 +22,50 @@ pub struct SellKeys<'info> {
 
     #[account(
         mut,
-        seeds = [b"keys", subject.key().as_ref()],
-        bump = subject_keys.bump,
+        seeds = [b"keys", subject_keys.user.as_ref()],
+        bump,
     )]
     pub subject_keys: Account<'info, UserKeys>,
 
     #[account(
         mut,
-        seeds = [b"user", subject_keys.subject.as_ref()],
+        seeds = [b"user", subject_keys.user.as_ref()],
         bump = subject.bump,
     )]
     pub subject: Account<'info, User>,
 
+    /// Collects the subject's creator fee
     #[account(
         mut,
-        associated_token::mint = subject_keys.mint,
-        associated_token::authority = seller,
+        seeds = [EARNINGS_VAULT_SEED, subject_keys.user.as_ref()],
+        bump = earnings_vault.bump,
     )]
-    pub seller_token_account: Account<'info, TokenAccount>,
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, seller_wallet.key().as_ref(), subject_keys.user.as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    /// Takes the early supporters' slice of the creator fee
+    #[account(
+        mut,
+        seeds = [EARLY_SUPPORTER_POOL_SEED, subject_keys.user.as_ref()],
+        bump = early_supporter_pool.bump,
+    )]
+    pub early_supporter_pool: Account<'info, EarlySupporterPool>,
+
+    #[account(mut, address = subject_keys.mint)]
+    pub mint: Account<'info, Mint>,
 
     #[account(
         mut,
         associated_token::mint = subject_keys.mint,
-        associated_token::authority = subject_keys,
+        associated_token::authority = seller_wallet,
     )]
-    pub subject_token_account: Account<'info, TokenAccount>,
+    pub seller_token_account: Account<'info, TokenAccount>,
 
     #[account(
         mut,
@@ -49,25 +74,166 @@ pub struct SellKeys<'info> {
     )]
     pub protocol_fee_account: SystemAccount<'info>,
 
+    /// Owns `treasury_payment_account`, the same token account buys pay protocol fees into
+    #[account(
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    /// Present when the seller is exempt from protocol fees
+    #[account(
+        seeds = [b"fee_exemption", seller_wallet.key().as_ref()],
+        bump = fee_exemption.bump,
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+
+    #[account(
+        init_if_needed,
+        payer = seller_wallet,
+        space = RecentTrades::LEN,
+        seeds = [b"recent_trades", subject_keys.key().as_ref()],
+        bump,
+    )]
+    pub recent_trades: Account<'info, RecentTrades>,
+
+    /// Price feed for other programs; every buy and sell records into it
+    #[account(
+        init_if_needed,
+        payer = seller_wallet,
+        space = PriceOracle::LEN,
+        seeds = [b"price_oracle", subject_keys.key().as_ref()],
+        bump,
+    )]
+    pub price_oracle: Account<'info, PriceOracle>,
+
+    /// The seller's totals across every market
+    #[account(
+        init_if_needed,
+        payer = seller_wallet,
+        space = Portfolio::LEN,
+        seeds = [PORTFOLIO_SEED, seller_wallet.key().as_ref()],
+        bump,
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+
+    /// Opt-in activity heartbeat bucket
+    #[account(
+        mut,
+        seeds = [b"activity_metrics"],
+        bump = activity.bump,
+    )]
+    pub activity: Option<Account<'info, ActivityTracker>>,
+
+    /// CHECK: Opt-in history record at the market's next trade index; created in the handler
+    #[account(mut)]
+    pub key_transaction: Option<UncheckedAccount<'info>>,
+
+    /// The seller's referral record, present when they signed up through a referrer
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, seller_wallet.key().as_ref()],
+        bump = referral.bump,
+    )]
+    pub referral: Option<Account<'info, Referral>>,
+
+    /// CHECK: Receives the referrer fee; checked against `referral.referrer`
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub seller_wallet: Signer<'info>,
 
+    /// Receives the proceeds when the market settles in `subject_keys.payment_mint`
+    #[account(
+        mut,
+        token::mint = subject_keys.payment_mint,
+        token::authority = seller_wallet,
+    )]
+    pub seller_payment_account: Option<Account<'info, TokenAccount>>,
+
+    /// The market's reserve in its payment token
+    #[account(
+        mut,
+        seeds = [PAYMENT_RESERVE_SEED, subject_keys.key().as_ref()],
+        bump,
+    )]
+    pub payment_reserve: Option<Account<'info, TokenAccount>>,
+
+    /// Takes the protocol fee in the payment token; withdrawn with `withdraw_treasury_token`
+    #[account(
+        mut,
+        associated_token::mint = subject_keys.payment_mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_payment_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = subject_keys.payment_mint,
+        associated_token::authority = subject_keys.user,
+    )]
+    pub creator_payment_account: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn sell_keys(ctx: Context<SellKeys>, amount: u64) -> Result<()> {
+pub fn sell_keys(
+    ctx: Context<SellKeys>,
+    amount: u64,
+    min_price: u64,
+    deadline: Option<i64>,
+    max_price_impact_bps: Option<u16>,
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
     let subject_keys = &mut ctx.accounts.subject_keys;
     let seller = &mut ctx.accounts.seller;
     let subject = &mut ctx.accounts.subject;
 
     // Validate amount
     require!(amount > 0, SolSocialError::InvalidAmount);
+    require!(ctx.accounts.config.allows_trade_size(amount), SolSocialError::TradeTooLarge);
     require!(
-        ctx.accounts.seller_token_account.amount >= amount,
-        SolSocialError::InsufficientKeys
+        ctx.accounts.config.allows_direct_sell(amount),
+        SolSocialError::SellAboveQueueThreshold
     );
 
+    let now = Clock::get()?.unix_timestamp;
+    require_before_deadline(deadline, now, amount)?;
+    if !subject_keys.is_trading_open(now) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::OutsideTradingHours,
+                price: subject_keys.trading_open_at as u64,
+                limit: subject_keys.trading_close_at as u64,
+                amount,
+            },
+            SolSocialError::OperationNotAllowed,
+        ));
+    }
+
+    let keys_held = ctx.accounts.seller_token_account.amount;
+    if keys_held < amount {
+        return Err(fail_with_context(
+            TradeErrorContext { kind: TradeErrorKind::InsufficientKeys, price: 0, limit: keys_held, amount },
+            SolSocialError::InsufficientKeys,
+        ));
+    }
+
     // Cannot sell if it would leave supply at 0 and seller still has keys
     require!(
         subject_keys.supply > amount || ctx.accounts.seller_token_account.amount == amount,
@@ -75,72 +241,250 @@ pub fn sell_keys(ctx: Context<SellKeys>, amount: u64) -> Result<()> {
     );
 
     // Calculate sell price using bonding curve
-    let sell_price = calculate_sell_price(subject_keys.supply, amount)?;
-    
-    // Calculate protocol fee (2.5%)
-    let protocol_fee = calculate_protocol_fee(sell_price)?;
-    let creator_fee = sell_price
-        .checked_mul(25)
-        .ok_or(SolSocialError::MathOverflow)?
-        .checked_div(1000)
-        .ok_or(SolSocialError::MathOverflow)?; // 2.5% to creator
+    let sell_price = calculate_sell_price(&subject_keys.curve_params, subject_keys.supply, amount)?;
+
+    // The seller's own impact cap, or the protocol default when they don't set one
+    require_price_impact_within(
+        &subject_keys.curve_params,
+        subject_keys.supply,
+        amount,
+        false,
+        max_price_impact_bps.unwrap_or(ctx.accounts.config.max_price_impact_bps),
+    )?;
     
-    let seller_proceeds = sell_price
-        .checked_sub(protocol_fee)
-        .ok_or(SolSocialError::MathOverflow)?
-        .checked_sub(creator_fee)
+    // Calculate fees using the market's sell-side rates, waived during a grace exit window.
+    // Recent buyers also pay whatever flip tax hasn't decayed yet.
+    let in_grace_window = subject_keys.in_grace_window(now);
+    let (protocol_fee_bps, creator_fee_bps) = if in_grace_window {
+        (0, 0)
+    } else {
+        (
+            subject_keys.curve_params.sell_protocol_fee,
+            subject_keys.curve_params.sell_creator_fee_at(ctx.accounts.key_holder.first_purchase_at, now),
+        )
+    };
+
+    // Exempt traders skip the protocol fee but still pay the creator; reputation discounts
+    // the protocol fee only
+    let protocol_fee_exempt = ctx.accounts.fee_exemption.is_some();
+    let reputation_discount_pct = seller.get_trading_fee_discount();
+    let protocol_fee_bps = if protocol_fee_exempt {
+        0
+    } else {
+        User::apply_fee_discount(protocol_fee_bps, reputation_discount_pct)
+    };
+
+    // Markets with a payment token settle through token accounts instead of lamports
+    let token_settlement = if subject_keys.settles_in_token() {
+        Some(TokenSettlement::from_accounts(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.seller_payment_account.as_ref(),
+            ctx.accounts.payment_reserve.as_ref(),
+            ctx.accounts.treasury_payment_account.as_ref(),
+            ctx.accounts.creator_payment_account.as_ref(),
+        )?)
+    } else {
+        None
+    };
+
+    // Referred sellers also pay the referrer fee, which must reach the bound referrer;
+    // referrers are paid in lamports, so token markets skip it
+    let has_referrer = match (&ctx.accounts.referral, &ctx.accounts.referrer) {
+        _ if token_settlement.is_some() => false,
+        (Some(referral), Some(referrer)) => {
+            require_keys_eq!(referrer.key(), referral.referrer, SolSocialError::InvalidAccountOwner);
+            true
+        }
+        (Some(_), None) => return Err(SolSocialError::MissingRequiredAccount.into()),
+        (None, _) => false,
+    };
+    let distribution = calculate_market_distribution(sell_price, protocol_fee_bps, creator_fee_bps, has_referrer)?;
+    let protocol_fee = distribution.protocol_fee;
+    let creator_fee = distribution.creator_fee;
+    let referrer_fee = distribution.referrer_fee;
+    // The reward pool only takes lamports
+    let reward_pool_amount = if token_settlement.is_some() {
+        0
+    } else {
+        ctx.accounts.config.reward_pool_cut(protocol_fee)?
+    };
+    let early_supporter_amount = if token_settlement.is_some() {
+        0
+    } else {
+        let cut = ctx.accounts.config.early_supporter_cut(creator_fee)?;
+        ctx.accounts.early_supporter_pool.record_fee(cut)?
+    };
+    let vault_fee = creator_fee.checked_sub(early_supporter_amount)
         .ok_or(SolSocialError::MathOverflow)?;
+    
+    let seller_proceeds = distribution.net_amount;
+
+    if seller_proceeds < min_price {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::SellSlippage,
+                price: seller_proceeds,
+                limit: min_price,
+                amount,
+            },
+            SolSocialError::SlippageExceeded,
+        ));
+    }
 
     // Burn tokens from seller
-    let cpi_accounts = Transfer {
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.mint.to_account_info(),
         from: ctx.accounts.seller_token_account.to_account_info(),
-        to: ctx.accounts.subject_token_account.to_account_info(),
         authority: ctx.accounts.seller_wallet.to_account_info(),
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
+    let subject_key = subject_keys.user;
+    let keys_seeds = &[b"keys".as_ref(), subject_key.as_ref(), &[ctx.bumps.subject_keys]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(&cpi_program, &ctx.accounts.mint.to_account_info(), subject_keys, keys_signer);
+    let seller_token_info = ctx.accounts.seller_token_account.to_account_info();
+    freeze.thaw(&seller_token_info)?;
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, amount)?;
+    token::burn(cpi_ctx, amount)?;
+    freeze.freeze(&seller_token_info)?;
 
     // Update supply
     subject_keys.supply = subject_keys.supply
         .checked_sub(amount)
         .ok_or(SolSocialError::MathOverflow)?;
 
-    // Transfer SOL to seller
-    **ctx.accounts.seller_wallet.to_account_info().try_borrow_mut_lamports()? += seller_proceeds;
+    // Update the seller's holding record; emptying it drops them from the holder count
+    let key_holder = &mut ctx.accounts.key_holder;
+    let held_before = key_holder.amount;
+    let invested_before = key_holder.total_invested;
+    key_holder.update_after_sell(amount, sell_price);
+    subject_keys.track_holder_balance(held_before, key_holder.amount)?;
 
-    // Transfer protocol fee
-    **ctx.accounts.protocol_fee_account.to_account_info().try_borrow_mut_lamports()? += protocol_fee;
+    let portfolio = &mut ctx.accounts.portfolio;
+    if portfolio.owner == Pubkey::default() {
+        portfolio.initialize(ctx.accounts.seller_wallet.key(), ctx.bumps.portfolio);
+    }
+    portfolio.record_sell(
+        invested_before.saturating_sub(key_holder.total_invested),
+        seller_proceeds,
+        key_holder.amount,
+        now,
+    )?;
 
-    // Transfer creator fee to subject
-    **subject.to_account_info().try_borrow_mut_lamports()? += creator_fee;
+    if let Some(settlement) = &token_settlement {
+        // The token reserve pays the seller and both fees, signed by the keys PDA
+        let subject_key = subject_keys.user;
+        let seeds = &[b"keys".as_ref(), subject_key.as_ref(), &[ctx.bumps.subject_keys]];
+        settlement.pay_sell(
+            &subject_keys.to_account_info(),
+            &[&seeds[..]],
+            protocol_fee,
+            creator_fee,
+            seller_proceeds,
+        )?;
+    } else {
+        // The sell price comes out of the market's reserve; pay the seller first
+        let reserve = subject_keys.to_account_info();
+        move_lamports(&reserve, &ctx.accounts.seller_wallet.to_account_info(), seller_proceeds)?;
+
+        // Protocol fee, less the reward pool's share
+        let treasury_fee = protocol_fee.checked_sub(reward_pool_amount)
+            .ok_or(SolSocialError::MathOverflow)?;
+        move_lamports(&reserve, &ctx.accounts.protocol_fee_account.to_account_info(), treasury_fee)?;
+        move_lamports(&reserve, &ctx.accounts.reward_pool.to_account_info(), reward_pool_amount)?;
+        ctx.accounts.reward_pool.record_deposit(reward_pool_amount, Clock::get()?.unix_timestamp)?;
+
+        // Creator fee waits in the subject's earnings vault, less the early supporters' slice
+        move_lamports(&reserve, &ctx.accounts.earnings_vault.to_account_info(), vault_fee)?;
+        move_lamports(&reserve, &ctx.accounts.early_supporter_pool.to_account_info(), early_supporter_amount)?;
+        ctx.accounts.earnings_vault.record_accrual(vault_fee)?;
+
+        if let (Some(referral), Some(referrer)) = (ctx.accounts.referral.as_mut(), &ctx.accounts.referrer) {
+            move_lamports(&reserve, &referrer.to_account_info(), referrer_fee)?;
+            referral.record_fee(referrer_fee);
+        }
+    }
 
     // Update seller's trading volume
     seller.total_trading_volume = seller.total_trading_volume
         .checked_add(sell_price)
         .ok_or(SolSocialError::MathOverflow)?;
 
-    // Update subject's key metrics
-    subject_keys.total_volume = subject_keys.total_volume
-        .checked_add(sell_price)
-        .ok_or(SolSocialError::MathOverflow)?;
+    let trade_index = record_market_trade(
+        subject_keys,
+        Some(MarketFeeds {
+            recent_trades: &mut ctx.accounts.recent_trades,
+            recent_trades_bump: ctx.bumps.recent_trades,
+            price_oracle: &mut ctx.accounts.price_oracle,
+            price_oracle_bump: ctx.bumps.price_oracle,
+        }),
+        Some(key_holder),
+        &ctx.accounts.config,
+        &CurveTrade { amount, value: sell_price, creator_fee, protocol_fee, is_buy: false },
+        now,
+    )?;
+
+    // Open a fee-free exit window after a crash
+    let recent_trades = &ctx.accounts.recent_trades;
+    let drop_bps = recent_trades.drop_from_peak_bps();
+    let config = &ctx.accounts.config;
+    if subject_keys.maybe_open_grace_window(drop_bps, config.grace_drop_bps, config.grace_window_seconds, now) {
+        emit!(GraceWindowOpenedEvent {
+            subject: subject_keys.user,
+            drop_bps,
+            peak_price: recent_trades.peak_price(),
+            last_price: sell_price / amount,
+            grace_exit_until: subject_keys.grace_exit_until,
+            timestamp: now,
+        });
+    }
 
-    subject_keys.last_trade_timestamp = Clock::get()?.unix_timestamp;
+    touch_activity(ctx.accounts.activity.as_mut(), seller.last_activity, Activity::Trade { volume: sell_price })?;
+    seller.last_activity = Clock::get()?.unix_timestamp;
 
     // Update subject's earnings
     subject.total_earnings = subject.total_earnings
-        .checked_add(creator_fee)
+        .checked_add(vault_fee)
         .ok_or(SolSocialError::MathOverflow)?;
 
+    if let Some(key_transaction) = &ctx.accounts.key_transaction {
+        KeyTransaction::new(
+            TransactionType::Sell,
+            subject_keys.user,
+            ctx.accounts.seller_wallet.key(),
+            amount,
+            sell_price / amount,
+            sell_price,
+            creator_fee,
+            protocol_fee,
+            trade_index,
+        )
+        .record(
+            &key_transaction.to_account_info(),
+            &ctx.accounts.seller_wallet.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+        )?;
+    }
+
     // Emit sell event
     emit!(KeysSoldEvent {
         seller: ctx.accounts.seller_wallet.key(),
-        subject: subject_keys.subject,
+        subject: subject_keys.user,
         amount,
         price: sell_price,
         protocol_fee,
         creator_fee,
+        referrer_fee,
+        protocol_fee_bps,
+        creator_fee_bps,
+        reward_pool_amount,
+        early_supporter_amount,
+        fee_waived: in_grace_window,
+        protocol_fee_exempt,
+        reputation_discount_pct,
         new_supply: subject_keys.supply,
+        trade_index,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
@@ -155,7 +499,28 @@ pub struct KeysSoldEvent {
     pub price: u64,
     pub protocol_fee: u64,
     pub creator_fee: u64,
+    pub referrer_fee: u64,
+    pub protocol_fee_bps: u16,
+    pub creator_fee_bps: u16,
+    pub reward_pool_amount: u64,
+    /// Part of `creator_fee` paid to the market's early supporters
+    pub early_supporter_amount: u64,
+    pub fee_waived: bool,
+    pub protocol_fee_exempt: bool,
+    /// Percent taken off the protocol fee for the seller's reputation
+    pub reputation_discount_pct: u64,
     pub new_supply: u64,
+    pub trade_index: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GraceWindowOpenedEvent {
+    pub subject: Pubkey,
+    pub drop_bps: u16,
+    pub peak_price: u64,
+    pub last_price: u64,
+    pub grace_exit_until: i64,
     pub timestamp: i64,
 }
 ```
\ No newline at end of file
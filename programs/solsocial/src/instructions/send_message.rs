@@ -1,6 +1,9 @@
 ```rust
 use anchor_lang::prelude::*;
-use crate::state::{User, Chat, ChatMessage};
+use crate::state::{User, GroupChatRoom, ChatMessage, ChatParticipant, ChatSettings, KeyHolder, KEY_HOLDER_SEED, Activity, ActivityTracker, ProtocolConfig, Feature};
+use crate::state::chat::MessageType;
+use crate::instructions::finalize_activity_day::touch_activity;
+use crate::utils::accounts::{create_pda_account, require_rent_funds, write_account};
 use crate::error::SolSocialError;
 
 #[derive(Accounts)]
@@ -10,22 +13,28 @@ pub struct SendMessage<'info> {
         mut,
         seeds = [b"user", sender.key().as_ref()],
         bump = sender_user.bump,
-        has_one = owner @ SolSocialError::Unauthorized
+        constraint = sender_user.authority == sender.key() @ SolSocialError::Unauthorized
     )]
     pub sender_user: Account<'info, User>,
 
     #[account(
         mut,
         seeds = [b"chat", chat_id.as_bytes()],
-        bump = chat.bump,
-        constraint = chat.is_participant(sender.key()) @ SolSocialError::NotChatParticipant
+        bump = chat.bump
     )]
-    pub chat: Account<'info, Chat>,
+    pub chat: Account<'info, GroupChatRoom>,
 
     #[account(
-        init,
-        payer = sender,
-        space = ChatMessage::LEN,
+        mut,
+        seeds = [b"chat_participant", chat.key().as_ref(), sender.key().as_ref()],
+        bump = sender_participant.bump,
+        constraint = !sender_participant.is_blocked @ SolSocialError::UserNotInChat
+    )]
+    pub sender_participant: Account<'info, ChatParticipant>,
+
+    /// CHECK: Created by the handler once the sender is known to afford it
+    #[account(
+        mut,
         seeds = [
             b"message",
             chat.key().as_ref(),
@@ -33,11 +42,49 @@ pub struct SendMessage<'info> {
         ],
         bump
     )]
-    pub message: Account<'info, ChatMessage>,
+    pub message: UncheckedAccount<'info>,
+
+    /// CHECK: The other member of a direct room, or the creator of a group room
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Recipient's membership in this chat
+    #[account(
+        seeds = [b"chat_participant", chat.key().as_ref(), recipient.key().as_ref()],
+        bump = recipient_participant.bump
+    )]
+    pub recipient_participant: Account<'info, ChatParticipant>,
+
+    /// CHECK: Recipient's chat settings; may not exist yet, in which case the defaults apply
+    #[account(
+        seeds = [b"chat_settings", recipient.key().as_ref()],
+        bump
+    )]
+    pub recipient_settings: UncheckedAccount<'info>,
+
+    /// The sender's holding of the recipient's keys, if any
+    #[account(
+        seeds = [KEY_HOLDER_SEED, sender.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub sender_key_holder: Option<Account<'info, KeyHolder>>,
+
+    /// Opt-in activity heartbeat bucket
+    #[account(
+        mut,
+        seeds = [b"activity_metrics"],
+        bump = activity.bump
+    )]
+    pub activity: Option<Account<'info, ActivityTracker>>,
 
     #[account(mut)]
     pub sender: Signer<'info>,
 
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -46,24 +93,89 @@ pub fn send_message(
     chat_id: String,
     content: String,
 ) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Chat)?;
     require!(content.len() <= 500, SolSocialError::MessageTooLong);
     require!(!content.trim().is_empty(), SolSocialError::EmptyMessage);
 
+    // The message account is created below, so the sender has to cover its rent and stay rent exempt
+    let space = ChatMessage::space_for(content.len());
+    require_rent_funds(&ctx.accounts.sender.to_account_info(), &[space], 0)?;
+
     let chat = &mut ctx.accounts.chat;
-    let message = &mut ctx.accounts.message;
     let sender = &ctx.accounts.sender;
 
     // Check if chat is active
     require!(chat.is_active, SolSocialError::ChatInactive);
 
-    // Initialize message
-    message.chat = chat.key();
-    message.sender = sender.key();
-    message.content = content;
-    message.timestamp = Clock::get()?.unix_timestamp;
-    message.message_id = chat.message_count;
-    message.is_deleted = false;
-    message.bump = ctx.bumps.message;
+    // Muted members (including auto-muted newcomers) can read but not post
+    require!(!ctx.accounts.sender_participant.is_muted, SolSocialError::OperationNotAllowed);
+
+    let recipient = ctx.accounts.recipient.key();
+    if chat.is_direct {
+        require!(recipient != sender.key(), SolSocialError::InvalidChatParticipants);
+
+        // Enforce the recipient's DM preferences, including the stranger reputation bar
+        let settings_info = ctx.accounts.recipient_settings.to_account_info();
+        let settings = if settings_info.data_is_empty() {
+            ChatSettings::default_for(recipient)
+        } else {
+            Account::<ChatSettings>::try_from(&settings_info)?.into_inner()
+        };
+
+        let sender_keys_held = ctx.accounts.sender_key_holder
+            .as_ref()
+            .map_or(0, |holder| holder.amount);
+
+        require!(
+            settings.can_receive_message_from(
+                sender_keys_held,
+                sender_keys_held > 0,
+                ctx.accounts.sender_user.reputation,
+            ),
+            SolSocialError::NotAuthorizedForChat
+        );
+
+        // Surface the recipient's canned reply to first-time non-holders; clients deliver it
+        let is_first_message = ctx.accounts.sender_participant.message_count == 0;
+        if let Some(reply) = settings.auto_reply_for(sender_keys_held > 0, is_first_message) {
+            emit!(AutoReplyEvent {
+                chat_id: chat.key(),
+                recipient,
+                sender: sender.key(),
+                message: reply.clone(),
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+    } else {
+        require!(recipient == chat.creator, SolSocialError::InvalidChatParticipants);
+    }
+
+    // Create and initialize the message
+    let message_index = chat.message_count;
+    let message_info = ctx.accounts.message.to_account_info();
+    let chat_key = chat.key();
+    create_pda_account(
+        &sender.to_account_info(),
+        &message_info,
+        &ctx.accounts.system_program.to_account_info(),
+        space,
+        &[b"message", chat_key.as_ref(), &message_index.to_le_bytes(), &[ctx.bumps.message]],
+    )?;
+    let message = ChatMessage {
+        message_id: message_info.key().to_bytes(),
+        room_id: chat_key.to_bytes(),
+        sender: sender.key(),
+        recipient,
+        content,
+        timestamp: Clock::get()?.unix_timestamp,
+        message_type: MessageType::Text,
+        is_encrypted: false,
+        reply_to: None,
+        edited_at: None,
+        is_deleted: false,
+        bump: ctx.bumps.message,
+    };
+    write_account(&message_info, &message)?;
 
     // Update chat metadata
     chat.message_count = chat.message_count.checked_add(1)
@@ -71,14 +183,19 @@ pub fn send_message(
     chat.last_message_at = Clock::get()?.unix_timestamp;
     chat.last_message_sender = sender.key();
 
+    ctx.accounts.sender_participant.increment_message_count()?;
+
     // Update sender's message count
     let sender_user = &mut ctx.accounts.sender_user;
     sender_user.messages_sent = sender_user.messages_sent.checked_add(1)
         .ok_or(SolSocialError::Overflow)?;
 
+    touch_activity(ctx.accounts.activity.as_mut(), sender_user.last_activity, Activity::Message)?;
+    sender_user.last_activity = message.timestamp;
+
     emit!(MessageSentEvent {
         chat_id: chat.key(),
-        message_id: message.message_id,
+        message_id: message_index,
         sender: sender.key(),
         content: message.content.clone(),
         timestamp: message.timestamp,
@@ -95,4 +212,13 @@ pub struct MessageSentEvent {
     pub content: String,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct AutoReplyEvent {
+    pub chat_id: Pubkey,
+    pub recipient: Pubkey,
+    pub sender: Pubkey,
+    pub message: String,
+    pub timestamp: i64,
+}
 ```
\ No newline at end of file
@@ -0,0 +1,48 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{FeatureFlags, ProtocolConfig};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct SetFeatureFlags<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        has_one = admin @ SolSocialError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Replace the halted-subsystem bitfield. Exits such as cancelling orders, refunds and
+/// earnings claims are never guarded, so users can always get their funds out. Once a
+/// multisig holds the admin role, flags change through `AdminAction::SetFeatureFlags`.
+pub fn handler(ctx: Context<SetFeatureFlags>, flags: FeatureFlags) -> Result<()> {
+    require!(flags.is_valid(), SolSocialError::InvalidConfigValue);
+
+    let config = &mut ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    let previous = config.feature_flags;
+    config.feature_flags = flags;
+    config.updated_at = now;
+
+    emit!(FeatureFlagsChangedEvent {
+        admin: config.admin,
+        previous_bits: previous.bits,
+        bits: flags.bits,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct FeatureFlagsChangedEvent {
+    pub admin: Pubkey,
+    pub previous_bits: u32,
+    pub bits: u32,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
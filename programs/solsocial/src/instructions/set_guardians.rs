@@ -0,0 +1,52 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{User, Guardians};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    #[account(has_one = authority @ SolSocialError::Unauthorized)]
+    pub user: Account<'info, User>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Guardians::LEN,
+        seeds = [b"guardians", user.key().as_ref()],
+        bump
+    )]
+    pub guardians: Account<'info, Guardians>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetGuardians>, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    let guardian_set = &mut ctx.accounts.guardians;
+    if guardian_set.user == Pubkey::default() {
+        guardian_set.user = ctx.accounts.user.key();
+        guardian_set.bump = ctx.bumps.guardians;
+    }
+
+    guardian_set.set(guardians, threshold, &ctx.accounts.authority.key())?;
+
+    emit!(GuardiansUpdatedEvent {
+        user: guardian_set.user,
+        guardians: guardian_set.guardians.clone(),
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GuardiansUpdatedEvent {
+    pub user: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,58 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{GroupChatRoom, ChatParticipant};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(chat_id: String)]
+pub struct SetParticipantMute<'info> {
+    #[account(
+        seeds = [b"chat", chat_id.as_bytes()],
+        bump = chat.bump
+    )]
+    pub chat: Account<'info, GroupChatRoom>,
+
+    #[account(
+        seeds = [b"chat_participant", chat.key().as_ref(), admin.key().as_ref()],
+        bump = admin_participant.bump,
+        constraint = admin_participant.is_admin @ SolSocialError::NotAuthorizedForChat
+    )]
+    pub admin_participant: Account<'info, ChatParticipant>,
+
+    #[account(
+        mut,
+        seeds = [b"chat_participant", chat.key().as_ref(), target_participant.user.as_ref()],
+        bump = target_participant.bump
+    )]
+    pub target_participant: Account<'info, ChatParticipant>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetParticipantMute>, _chat_id: String, muted: bool) -> Result<()> {
+    let target = &mut ctx.accounts.target_participant;
+
+    if target.is_muted != muted {
+        target.toggle_mute()?;
+    }
+
+    emit!(ParticipantMuteChangedEvent {
+        chat: ctx.accounts.chat.key(),
+        participant: target.user,
+        admin: ctx.accounts.admin.key(),
+        is_muted: target.is_muted,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ParticipantMuteChangedEvent {
+    pub chat: Pubkey,
+    pub participant: Pubkey,
+    pub admin: Pubkey,
+    pub is_muted: bool,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,86 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{UserKeys, PAYMENT_RESERVE_SEED};
+
+#[derive(Accounts)]
+pub struct SetPaymentMint<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", creator.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// The SPL token (e.g. USDC) curve trades will settle in
+    pub payment_mint: Account<'info, Mint>,
+
+    /// Holds the market's reserve in the payment token, in place of the keys PDA's lamports
+    #[account(
+        init,
+        payer = creator,
+        seeds = [PAYMENT_RESERVE_SEED, keys_account.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = keys_account,
+    )]
+    pub payment_reserve: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// Receives protocol fees in the payment token
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_payment_account: Account<'info, TokenAccount>,
+
+    /// Receives creator fees in the payment token
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = payment_mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_payment_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Have `buy_keys` and `sell_keys` settle in `payment_mint` instead of lamports. The curve's
+/// prices are read in the token's base units, so the market's curve should be set up for
+/// them. Allowed once, before the market's first trade.
+pub fn handler(ctx: Context<SetPaymentMint>) -> Result<()> {
+    let keys_account = &mut ctx.accounts.keys_account;
+    keys_account.set_payment_mint(ctx.accounts.payment_mint.key())?;
+
+    emit!(PaymentMintSetEvent {
+        subject: ctx.accounts.creator.key(),
+        payment_mint: keys_account.payment_mint,
+        payment_reserve: ctx.accounts.payment_reserve.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PaymentMintSetEvent {
+    pub subject: Pubkey,
+    pub payment_mint: Pubkey,
+    pub payment_reserve: Pubkey,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,42 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::UserKeys;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct SetTradingHours<'info> {
+    #[account(
+        mut,
+        seeds = [b"keys", creator.key().as_ref()],
+        bump,
+        constraint = keys_account.user == creator.key() @ SolSocialError::Unauthorized
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetTradingHours>, open_at: i64, close_at: i64) -> Result<()> {
+    let keys_account = &mut ctx.accounts.keys_account;
+    keys_account.set_trading_hours(open_at, close_at)?;
+
+    emit!(TradingHoursUpdatedEvent {
+        keys_account: keys_account.key(),
+        creator: ctx.accounts.creator.key(),
+        open_at,
+        close_at,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TradingHoursUpdatedEvent {
+    pub keys_account: Pubkey,
+    pub creator: Pubkey,
+    pub open_at: i64,
+    pub close_at: i64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,59 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{Post, Share, ProtocolConfig, Feature};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct SharePost<'info> {
+    #[account(mut)]
+    pub post: Account<'info, Post>,
+
+    #[account(
+        init,
+        payer = sharer,
+        space = Share::SPACE,
+        seeds = [b"share", post.key().as_ref(), sharer.key().as_ref()],
+        bump
+    )]
+    pub share: Account<'info, Share>,
+
+    #[account(mut)]
+    pub sharer: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SharePost>) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Posting)?;
+    let post = &mut ctx.accounts.post;
+    let share = &mut ctx.accounts.share;
+
+    require!(post.author != ctx.accounts.sharer.key(), SolSocialError::CannotInteractOwnPost);
+
+    share.initialize(post.key(), ctx.accounts.sharer.key(), ctx.bumps.share)?;
+    post.add_share()?;
+
+    emit!(PostSharedEvent {
+        post: post.key(),
+        share: share.key(),
+        sharer: share.sharer,
+        timestamp: share.created_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PostSharedEvent {
+    pub post: Pubkey,
+    pub share: Pubkey,
+    pub sharer: Pubkey,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
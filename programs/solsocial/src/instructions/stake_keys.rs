@@ -0,0 +1,116 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{StakePosition, UserKeys, STAKE_POSITION_SEED, STAKE_VAULT_SEED};
+use crate::utils::bonding_curve::calculate_sell_price;
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct StakeKeys<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: The subject whose keys are staked
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = StakePosition::LEN,
+        seeds = [STAKE_POSITION_SEED, owner.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [STAKE_VAULT_SEED, stake_position.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = stake_position,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lock keys in the position's vault, where they earn yield from the reward pool
+pub fn handler(ctx: Context<StakeKeys>, amount: u64) -> Result<()> {
+    require!(amount > 0, SolSocialError::InvalidAmount);
+    require!(ctx.accounts.owner_token_account.amount >= amount, SolSocialError::InsufficientKeys);
+
+    let now = Clock::get()?.unix_timestamp;
+    let owner = ctx.accounts.owner.key();
+    let subject = ctx.accounts.subject.key();
+
+    let stake_position = &mut ctx.accounts.stake_position;
+    if stake_position.owner == Pubkey::default() {
+        stake_position.initialize(owner, subject, now, ctx.bumps.stake_position);
+    }
+    stake_position.accrue(now)?;
+
+    // Rewards accrue on what the keys would fetch on the curve as they go in
+    let keys_account = &ctx.accounts.keys_account;
+    let value = calculate_sell_price(&keys_account.curve_params, keys_account.supply, amount)?;
+    stake_position.stake(amount, value)?;
+
+    let keys_seeds = &[b"keys".as_ref(), subject.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.keys_account,
+        keys_signer,
+    );
+    let owner_token_info = ctx.accounts.owner_token_account.to_account_info();
+    freeze.thaw(&owner_token_info)?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.owner_token_account.to_account_info(),
+        to: ctx.accounts.stake_vault.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+    freeze.freeze(&owner_token_info)?;
+
+    emit!(KeysStakedEvent {
+        owner,
+        subject,
+        amount,
+        value,
+        staked_amount: stake_position.staked_amount,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct KeysStakedEvent {
+    pub owner: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub value: u64,
+    pub staked_amount: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
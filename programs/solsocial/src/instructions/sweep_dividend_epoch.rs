@@ -0,0 +1,65 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{DividendEpoch, DividendSource, RewardPool, DIVIDEND_EPOCH_SEED};
+use crate::utils::accounts::move_lamports;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct SweepDividendEpoch<'info> {
+    /// Anyone may sweep an epoch once its claim window has closed
+    pub caller: Signer<'info>,
+
+    /// CHECK: The wallet that opened the epoch; receives its rent back
+    #[account(mut, address = epoch.funder @ SolSocialError::InvalidAccountOwner)]
+    pub funder: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = funder,
+        seeds = [DIVIDEND_EPOCH_SEED, epoch.subject.as_ref(), &epoch.epoch_index.to_le_bytes()],
+        bump = epoch.bump,
+    )]
+    pub epoch: Account<'info, DividendEpoch>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+}
+
+/// Close a dividend epoch whose claim window has passed. Unclaimed lamports go back to
+/// their source: the reward pool for `RewardPool` epochs, the creator for `CreatorEarnings`.
+pub fn handler(ctx: Context<SweepDividendEpoch>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let epoch = &ctx.accounts.epoch;
+    require!(epoch.is_sweepable(now), SolSocialError::DividendEpochStillOpen);
+
+    let unclaimed = epoch.unclaimed();
+    if epoch.source == DividendSource::RewardPool && unclaimed > 0 {
+        move_lamports(&epoch.to_account_info(), &ctx.accounts.reward_pool.to_account_info(), unclaimed)?;
+        ctx.accounts.reward_pool.record_return(unclaimed)?;
+    }
+    // A creator epoch's remainder leaves with the rent when the account closes to its funder
+
+    emit!(DividendEpochSweptEvent {
+        epoch: epoch.key(),
+        subject: epoch.subject,
+        source: epoch.source,
+        unclaimed,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DividendEpochSweptEvent {
+    pub epoch: Pubkey,
+    pub subject: Pubkey,
+    pub source: DividendSource,
+    pub unclaimed: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
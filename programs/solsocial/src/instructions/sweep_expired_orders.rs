@@ -0,0 +1,164 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, LimitOrder, OrderSide, UserKeys, LIMIT_ORDER_ESCROW_SEED, LIMIT_ORDER_SEED};
+use crate::utils::accounts::move_closing_lamports;
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct SweepExpiredOrders<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Close expired limit orders passed through `remaining_accounts`.
+///
+/// Each order is followed by its owner's wallet and its escrow token account and, for sell
+/// orders, the owner's `KeyHolder` and key token account so the escrowed keys can be returned,
+/// then the market's `UserKeys` and mint so that account can be thawed and frozen again.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SweepExpiredOrders<'info>>) -> Result<()> {
+    let keeper = ctx.accounts.keeper.to_account_info();
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut accounts = ctx.remaining_accounts.iter();
+    let mut swept = 0u64;
+    let mut skipped = 0u64;
+    let mut keeper_fees = 0u64;
+    let mut processed = 0usize;
+
+    while let Some(order_info) = accounts.next() {
+        processed += 1;
+        LimitOrder::require_within_sweep_batch(processed)?;
+
+        let order = Account::<LimitOrder>::try_from(order_info)?;
+        let owner_info = accounts.next().ok_or(SolSocialError::MissingRequiredAccount)?;
+        require_keys_eq!(owner_info.key(), order.owner, SolSocialError::InvalidAccountOwner);
+
+        let escrow_info = accounts.next().ok_or(SolSocialError::MissingRequiredAccount)?;
+        let (expected_escrow, _) =
+            Pubkey::find_program_address(&[LIMIT_ORDER_ESCROW_SEED, order_info.key.as_ref()], ctx.program_id);
+        require_keys_eq!(escrow_info.key(), expected_escrow, SolSocialError::InvalidAccountSequence);
+
+        let seller_accounts = if order.side == OrderSide::Sell {
+            let holder_info = accounts.next().ok_or(SolSocialError::MissingRequiredAccount)?;
+            let token_info = accounts.next().ok_or(SolSocialError::MissingRequiredAccount)?;
+            let keys_info = accounts.next().ok_or(SolSocialError::MissingRequiredAccount)?;
+            let mint_info = accounts.next().ok_or(SolSocialError::MissingRequiredAccount)?;
+            Some((holder_info, token_info, keys_info, mint_info))
+        } else {
+            None
+        };
+
+        if !order.is_expired(now) {
+            skipped += 1;
+            continue;
+        }
+
+        let order_id = order.order_id.to_le_bytes();
+        let order_seeds = &[
+            LIMIT_ORDER_SEED,
+            order.owner.as_ref(),
+            order.keys_user.as_ref(),
+            &order_id,
+            &[order.bump],
+        ];
+        let signer = &[&order_seeds[..]];
+
+        // Return escrowed keys to the seller's wallet and holder record
+        if let Some((holder_info, token_info, keys_info, mint_info)) = seller_accounts {
+            let mut key_holder = Account::<KeyHolder>::try_from(holder_info)?;
+            let returned = order.return_escrowed_keys(&mut key_holder)?;
+            let escrow = Account::<TokenAccount>::try_from(escrow_info)?;
+            let owner_tokens = Account::<TokenAccount>::try_from(token_info)?;
+            require!(
+                owner_tokens.owner == order.owner && owner_tokens.mint == escrow.mint,
+                SolSocialError::InvalidTokenAccount
+            );
+
+            let (expected_keys, keys_bump) =
+                Pubkey::find_program_address(&[b"keys", order.keys_user.as_ref()], ctx.program_id);
+            require_keys_eq!(keys_info.key(), expected_keys, SolSocialError::InvalidAccountSequence);
+            let keys_account = Account::<UserKeys>::try_from(keys_info)?;
+            require_keys_eq!(mint_info.key(), keys_account.mint, SolSocialError::InvalidTokenAccount);
+            let keys_seeds = &[b"keys".as_ref(), order.keys_user.as_ref(), &[keys_bump]];
+            let keys_signer = &[&keys_seeds[..]];
+            let freeze = KeyFreeze::new(
+                &ctx.accounts.token_program.to_account_info(),
+                mint_info,
+                &keys_account,
+                keys_signer,
+            );
+
+            freeze.thaw(token_info)?;
+            let cpi_accounts = Transfer {
+                from: escrow_info.clone(),
+                to: token_info.clone(),
+                authority: order_info.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+            token::transfer(cpi_ctx, returned)?;
+            freeze.freeze(token_info)?;
+            key_holder.exit(ctx.program_id)?;
+        }
+
+        let cpi_accounts = CloseAccount {
+            account: escrow_info.clone(),
+            destination: owner_info.clone(),
+            authority: order_info.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::close_account(cpi_ctx)?;
+
+        // Pay the keeper out of the order account, then refund the rest to the owner
+        let fee = LimitOrder::keeper_fee(order_info.lamports());
+        move_closing_lamports(order_info, &keeper, fee)?;
+        keeper_fees = keeper_fees
+            .checked_add(fee)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+        emit!(OrderExpiredEvent {
+            order: order_info.key(),
+            owner: order.owner,
+            keys_user: order.keys_user,
+            refunded_lamports: order_info.lamports(),
+            refunded_keys: order.escrowed_keys,
+            timestamp: now,
+        });
+
+        order.close(owner_info.clone())?;
+        swept += 1;
+    }
+
+    emit!(OrdersSweptEvent {
+        keeper: keeper.key(),
+        swept,
+        skipped,
+        keeper_fees,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OrderExpiredEvent {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub keys_user: Pubkey,
+    pub refunded_lamports: u64,
+    pub refunded_keys: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrdersSweptEvent {
+    pub keeper: Pubkey,
+    pub swept: u64,
+    pub skipped: u64,
+    pub keeper_fees: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
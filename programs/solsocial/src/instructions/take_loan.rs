@@ -0,0 +1,183 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, KeyLoan, PriceOracle, UserKeys, KEY_HOLDER_SEED, KEY_LOAN_ESCROW_SEED, KEY_LOAN_SEED, PRICE_ORACLE_SEED, ProtocolConfig, Feature};
+use crate::utils::revenue_share::system_transfer;
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct TakeLoan<'info> {
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    /// Receives the escrow rent once the keys are handed over
+    #[account(mut)]
+    pub lender: SystemAccount<'info>,
+
+    /// CHECK: The subject whose keys are lent
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Freezes and thaws the holder's key token account
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = lender @ SolSocialError::InvalidAccountOwner,
+        seeds = [KEY_LOAN_SEED, lender.key().as_ref(), subject.key().as_ref(), &loan.loan_id.to_le_bytes()],
+        bump = loan.bump,
+    )]
+    pub loan: Account<'info, KeyLoan>,
+
+    #[account(
+        mut,
+        seeds = [KEY_LOAN_ESCROW_SEED, loan.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PRICE_ORACLE_SEED, keys_account.key().as_ref()],
+        bump = price_oracle.bump,
+    )]
+    pub price_oracle: Account<'info, PriceOracle>,
+
+    #[account(
+        init_if_needed,
+        payer = borrower,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, borrower.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Borrow an offer's keys by posting SOL collateral into the loan account. The keys are
+/// valued at the oracle TWAP rather than the spot price, so the opening ratio can't be
+/// met by briefly pushing the curve down.
+pub fn handler(ctx: Context<TakeLoan>, collateral: u64) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    let now = Clock::get()?.unix_timestamp;
+    let price = ctx.accounts.price_oracle
+        .twap_over(now, PriceOracle::TWAP_WINDOW_SECONDS)
+        .ok_or(SolSocialError::OraclePriceUnavailable)?;
+
+    let borrower = ctx.accounts.borrower.key();
+    let subject = ctx.accounts.subject.key();
+    let loan = &mut ctx.accounts.loan;
+    loan.take(borrower, collateral, price, now)?;
+    let amount = loan.amount;
+
+    let key_holder = &mut ctx.accounts.key_holder;
+    if key_holder.holder == Pubkey::default() {
+        **key_holder = KeyHolder::new(borrower, subject);
+    }
+    let keys_account = &mut ctx.accounts.keys_account;
+    require!(
+        keys_account.curve_params.within_holder_cap(key_holder.amount, amount),
+        SolSocialError::HolderCapReached
+    );
+
+    system_transfer(
+        &ctx.accounts.borrower.to_account_info(),
+        &loan.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        collateral,
+    )?;
+
+    let lender_key = ctx.accounts.lender.key();
+    let loan_id = loan.loan_id.to_le_bytes();
+    let loan_seeds = &[
+        KEY_LOAN_SEED,
+        lender_key.as_ref(),
+        subject.as_ref(),
+        &loan_id,
+        &[loan.bump],
+    ];
+    let signer = &[&loan_seeds[..]];
+
+    let keys_seeds = &[b"keys".as_ref(), ctx.accounts.subject.key.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        keys_account,
+        keys_signer,
+    );
+    let borrower_token_info = ctx.accounts.borrower_token_account.to_account_info();
+    freeze.thaw(&borrower_token_info)?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.escrow.to_account_info(),
+        to: ctx.accounts.borrower_token_account.to_account_info(),
+        authority: loan.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+    freeze.freeze(&borrower_token_info)?;
+
+    // Repayment goes straight to the lender's wallet, so the escrow is done with
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow.to_account_info(),
+        destination: ctx.accounts.lender.to_account_info(),
+        authority: loan.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::close_account(cpi_ctx)?;
+
+    let held_before = key_holder.amount;
+    key_holder.update_after_buy(amount, price, price.saturating_mul(amount));
+    keys_account.track_holder_balance(held_before, key_holder.amount)?;
+
+    emit!(LoanTakenEvent {
+        loan: loan.key(),
+        lender: lender_key,
+        borrower,
+        keys_user: subject,
+        amount,
+        collateral,
+        oracle_price: price,
+        due_at: loan.due_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct LoanTakenEvent {
+    pub loan: Pubkey,
+    pub lender: Pubkey,
+    pub borrower: Pubkey,
+    pub keys_user: Pubkey,
+    pub amount: u64,
+    pub collateral: u64,
+    /// Oracle TWAP per key the collateral was checked against
+    pub oracle_price: u64,
+    pub due_at: i64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,214 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{
+    User, Post, Share, UserKeys, KeyHolder, TipMatch, EarningsVault, EngagementScore, InteractionType,
+    ProtocolConfig, Feature, EARNINGS_VAULT_SEED, ENGAGEMENT_SEED,
+};
+use crate::instructions::interact_post::PostInteractionEvent;
+use crate::utils::revenue_share::split_share_reward;
+use crate::utils::accounts::move_lamports;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct TipPost<'info> {
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+
+    #[account(mut)]
+    pub post: Account<'info, Post>,
+
+    /// CHECK: Post author, checked against the post
+    #[account(address = post.author @ SolSocialError::Unauthorized)]
+    pub author: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"user", author.key().as_ref()],
+        bump
+    )]
+    pub author_user: Account<'info, User>,
+
+    /// Receives the author's part of the tip
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, post.author.as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    /// The tipper's engagement tally with the author
+    #[account(
+        init_if_needed,
+        payer = tipper,
+        space = EngagementScore::LEN,
+        seeds = [ENGAGEMENT_SEED, post.author.as_ref(), tipper.key().as_ref()],
+        bump,
+    )]
+    pub engagement: Account<'info, EngagementScore>,
+
+    /// Share that drove this tip, if any
+    #[account(
+        mut,
+        seeds = [b"share", post.key().as_ref(), share.sharer.as_ref()],
+        bump = share.bump
+    )]
+    pub share: Option<Account<'info, Share>>,
+
+    /// CHECK: Sharer wallet, checked against `share` in the handler
+    #[account(mut)]
+    pub sharer: Option<AccountInfo<'info>>,
+
+    /// Key market gating the post, required when `post.gated_market` is set
+    pub gating_keys: Option<Account<'info, UserKeys>>,
+
+    /// The tipper's holding in the gating market
+    pub key_holder: Option<Account<'info, KeyHolder>>,
+
+    /// The author's tip-matching escrow, if they run one
+    #[account(
+        mut,
+        seeds = [b"tip_match", author.key().as_ref()],
+        bump = tip_match.bump
+    )]
+    pub tip_match: Option<Account<'info, TipMatch>>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<TipPost>, amount: u64, message: Option<String>) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Posting)?;
+    require!(amount > 0, SolSocialError::InvalidAmount);
+    require!(ctx.accounts.tipper.key() != ctx.accounts.author.key(), SolSocialError::OperationNotAllowed);
+
+    if let Some(msg) = &message {
+        require!(msg.len() <= 280, SolSocialError::MessageTooLong);
+    }
+
+    ctx.accounts.post.check_gate(
+        &ctx.accounts.tipper.key(),
+        ctx.accounts.gating_keys.as_ref().map(|keys| (keys.key(), &**keys)),
+        ctx.accounts.key_holder.as_deref(),
+    )?;
+
+    let (author_amount, sharer_amount) = match &ctx.accounts.share {
+        Some(share) => {
+            let sharer = ctx.accounts.sharer.as_ref().ok_or(SolSocialError::MissingRequiredAccount)?;
+            require_keys_eq!(share.post, ctx.accounts.post.key(), SolSocialError::PostNotFound);
+            require_keys_eq!(sharer.key(), share.sharer, SolSocialError::InvalidAccountOwner);
+            // Tippers can't route part of their own tip back to themselves
+            require!(share.sharer != ctx.accounts.tipper.key(), SolSocialError::OperationNotAllowed);
+
+            split_share_reward(amount, ctx.accounts.author_user.share_reward_bps)?
+        }
+        None => (amount, 0),
+    };
+
+    let earnings_vault_info = ctx.accounts.earnings_vault.to_account_info();
+    transfer_from_tipper(&ctx.accounts.tipper, &earnings_vault_info, author_amount)?;
+
+    if sharer_amount > 0 {
+        let sharer = ctx.accounts.sharer.as_ref().ok_or(SolSocialError::MissingRequiredAccount)?;
+        transfer_from_tipper(&ctx.accounts.tipper, sharer, sharer_amount)?;
+    }
+
+    if let Some(share) = ctx.accounts.share.as_mut() {
+        share.record_tip(amount, sharer_amount)?;
+    }
+
+    // Top the tip up from the author's match escrow until its budget runs out
+    let matched_amount = match ctx.accounts.tip_match.as_mut() {
+        Some(tip_match) => {
+            let matched = tip_match.match_for(amount);
+            if matched > 0 {
+                tip_match.record_match(matched)?;
+                move_lamports(&tip_match.to_account_info(), &earnings_vault_info, matched)?;
+            }
+            matched
+        }
+        None => 0,
+    };
+
+    let author_total = author_amount
+        .checked_add(matched_amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+    ctx.accounts.earnings_vault.record_accrual(author_total)?;
+
+    let post = &mut ctx.accounts.post;
+    post.add_revenue(amount.checked_add(matched_amount).ok_or(SolSocialError::ArithmeticOverflow)?)?;
+    post.add_tip()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let engagement = &mut ctx.accounts.engagement;
+    if engagement.user == Pubkey::default() {
+        engagement.user = ctx.accounts.tipper.key();
+        engagement.creator = post.author;
+        engagement.bump = ctx.bumps.engagement;
+    }
+    engagement.record(&InteractionType::Tip, now)?;
+
+    emit!(PostInteractionEvent {
+        user: ctx.accounts.tipper.key(),
+        post: post.key(),
+        interaction_type: InteractionType::Tip,
+        timestamp: now,
+        comment_text: None,
+    });
+
+    emit!(PostTippedEvent {
+        post: post.key(),
+        tipper: ctx.accounts.tipper.key(),
+        author: post.author,
+        amount,
+        author_amount,
+        sharer: ctx.accounts.share.as_ref().map(|share| share.sharer),
+        sharer_amount,
+        matched_amount,
+        message: message.unwrap_or_default(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+fn transfer_from_tipper<'info>(
+    tipper: &Signer<'info>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let ix = anchor_lang::solana_program::system_instruction::transfer(
+        &tipper.key(),
+        &recipient.key(),
+        amount,
+    );
+
+    anchor_lang::solana_program::program::invoke(
+        &ix,
+        &[tipper.to_account_info(), recipient.clone()],
+    )?;
+
+    Ok(())
+}
+
+#[event]
+pub struct PostTippedEvent {
+    pub post: Pubkey,
+    pub tipper: Pubkey,
+    pub author: Pubkey,
+    pub amount: u64,
+    pub author_amount: u64,
+    pub sharer: Option<Pubkey>,
+    pub sharer_amount: u64,
+    pub matched_amount: u64,
+    pub message: String,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,79 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, UserKeys};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct SetTradingHalt<'info> {
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// CHECK: The creator whose market is halted or resumed
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// The market's creator or the protocol admin
+    #[account(
+        constraint = authority.key() == subject.key() || authority.key() == config.admin
+            @ SolSocialError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Stop curve trading on one market, e.g. while an incident is investigated
+pub fn halt_handler(ctx: Context<SetTradingHalt>) -> Result<()> {
+    let keys_account = &mut ctx.accounts.keys_account;
+    require!(!keys_account.trading_halted, SolSocialError::OperationNotAllowed);
+    keys_account.set_trading_halted(true);
+
+    emit!(TradingHaltedEvent {
+        subject: ctx.accounts.subject.key(),
+        authority: Some(ctx.accounts.authority.key()),
+        move_bps: 0,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Reopen a market halted by hand or by the circuit breaker
+pub fn resume_handler(ctx: Context<SetTradingHalt>) -> Result<()> {
+    let keys_account = &mut ctx.accounts.keys_account;
+    require!(keys_account.trading_halted, SolSocialError::OperationNotAllowed);
+    keys_account.set_trading_halted(false);
+
+    emit!(TradingResumedEvent {
+        subject: ctx.accounts.subject.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TradingHaltedEvent {
+    pub subject: Pubkey,
+    /// `None` when the circuit breaker tripped on its own
+    pub authority: Option<Pubkey>,
+    /// Price move that tripped the circuit breaker, in basis points (0 for manual halts)
+    pub move_bps: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TradingResumedEvent {
+    pub subject: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
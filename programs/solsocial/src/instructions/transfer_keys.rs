@@ -0,0 +1,202 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{EarningsVault, KeyHolder, UserKeys, EARNINGS_VAULT_SEED, KEY_HOLDER_SEED, ProtocolConfig, Feature};
+use crate::utils::bonding_curve::calculate_sell_price;
+use crate::utils::revenue_share::split_transfer_royalty;
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+#[instruction(to: Pubkey, amount: u64)]
+pub struct TransferKeys<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// CHECK: Wallet receiving the keys
+    #[account(address = to)]
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: The subject whose keys move
+    #[account(address = keys_account.user)]
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Collects any transfer royalty for the subject
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, subject.key().as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, sender.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub sender_holder: Account<'info, KeyHolder>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, to.as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub recipient_holder: Account<'info, KeyHolder>,
+
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = sender,
+    )]
+    pub sender_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Move keys to another wallet without trading them on the curve. Markets with a
+/// transfer royalty charge the sender that share of the keys' curve value, paid into the
+/// subject's earnings vault.
+pub fn handler(ctx: Context<TransferKeys>, to: Pubkey, amount: u64) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    require!(amount > 0, SolSocialError::InvalidAmount);
+    require_keys_neq!(to, ctx.accounts.sender.key(), SolSocialError::OperationNotAllowed);
+
+    let keys_account = &mut ctx.accounts.keys_account;
+    // A halted market freezes its keys in place, transfers included
+    require!(!keys_account.trading_halted, SolSocialError::OperationNotAllowed);
+    let params = keys_account.curve_params;
+
+    // Keys escrowed behind open sell orders are already out of the holder record
+    let held = ctx.accounts.sender_holder.amount;
+    if held < amount {
+        return Err(fail_with_context(
+            TradeErrorContext { kind: TradeErrorKind::InsufficientKeys, price: 0, limit: held, amount },
+            SolSocialError::InsufficientKeys,
+        ));
+    }
+
+    let recipient_held = ctx.accounts.recipient_holder.amount;
+    if !params.within_holder_cap(recipient_held, amount) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::HolderCapReached,
+                price: recipient_held,
+                limit: params.max_keys_per_holder,
+                amount,
+            },
+            SolSocialError::HolderCapReached,
+        ));
+    }
+
+    // Value the keys at what selling them would return right now
+    let royalty = if params.transfer_royalty_bps == 0 {
+        0
+    } else {
+        let keys_value = calculate_sell_price(&params, keys_account.supply, amount)?;
+        split_transfer_royalty(keys_value, params.transfer_royalty_bps)?.0
+    };
+
+    if royalty > 0 {
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.sender.key(),
+            &ctx.accounts.earnings_vault.key(),
+            royalty,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[
+                ctx.accounts.sender.to_account_info(),
+                ctx.accounts.earnings_vault.to_account_info(),
+            ],
+        )?;
+        ctx.accounts.earnings_vault.record_accrual(royalty)?;
+    }
+
+    let keys_seeds = &[b"keys".as_ref(), ctx.accounts.subject.key.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        keys_account,
+        keys_signer,
+    );
+    let sender_token_info = ctx.accounts.sender_token_account.to_account_info();
+    let recipient_token_info = ctx.accounts.recipient_token_account.to_account_info();
+    freeze.thaw(&sender_token_info)?;
+    freeze.thaw(&recipient_token_info)?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.sender_token_account.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.sender.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+    freeze.freeze(&sender_token_info)?;
+    freeze.freeze(&recipient_token_info)?;
+
+    // Both sides keep their holding records, and the holder count, in step with the move
+    let sender_holder = &mut ctx.accounts.sender_holder;
+    let sender_before = sender_holder.amount;
+    let value_moved = sender_holder.avg_price.saturating_mul(amount);
+    sender_holder.update_after_sell(amount, value_moved);
+    keys_account.track_holder_balance(sender_before, sender_holder.amount)?;
+
+    let recipient_holder = &mut ctx.accounts.recipient_holder;
+    if recipient_holder.holder == Pubkey::default() {
+        **recipient_holder = KeyHolder::new(to, keys_account.user);
+    }
+    let recipient_before = recipient_holder.amount;
+    recipient_holder.update_after_buy(amount, value_moved / amount, value_moved);
+    keys_account.track_holder_balance(recipient_before, recipient_holder.amount)?;
+
+    emit!(KeysTransferredEvent {
+        from: ctx.accounts.sender.key(),
+        to,
+        subject: keys_account.user,
+        amount,
+        royalty,
+        royalty_bps: params.transfer_royalty_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct KeysTransferredEvent {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub royalty: u64,
+    pub royalty_bps: u16,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,221 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::ProtocolConfig;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct SetTreasuryAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        has_one = admin @ SolSocialError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn set_authority_handler(ctx: Context<SetTreasuryAuthority>, new_authority: Pubkey) -> Result<()> {
+    require_keys_neq!(new_authority, Pubkey::default(), SolSocialError::InvalidConfigValue);
+
+    let config = &mut ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    let previous_authority = config.treasury_authority;
+    config.treasury_authority = new_authority;
+    config.updated_at = now;
+
+    emit!(TreasuryAuthorityChangedEvent {
+        admin: config.admin,
+        previous_authority,
+        new_authority,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, destination: Pubkey)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        has_one = treasury_authority @ SolSocialError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub treasury_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_fee"],
+        bump,
+    )]
+    pub protocol_fee_account: SystemAccount<'info>,
+
+    /// CHECK: any wallet chosen by the treasury authority to receive the fees
+    #[account(mut, address = destination)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraw collected protocol fees to `destination`, drawing on the treasury first and
+/// the protocol fee account for the rest. Both stay rent exempt so fees can keep landing.
+pub fn withdraw_handler(ctx: Context<WithdrawTreasury>, amount: u64, destination: Pubkey) -> Result<()> {
+    let (from_treasury, from_protocol_fee) = pay_out_fees(
+        ctx.accounts.treasury.to_account_info(),
+        ctx.bumps.treasury,
+        ctx.accounts.protocol_fee_account.to_account_info(),
+        ctx.bumps.protocol_fee_account,
+        ctx.accounts.destination.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    emit!(TreasuryWithdrawEvent {
+        authority: ctx.accounts.treasury_authority.key(),
+        destination,
+        amount,
+        from_treasury,
+        from_protocol_fee,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasuryToken<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        has_one = treasury_authority @ SolSocialError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub treasury_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// Protocol fees collected from markets settling in this token
+    #[account(
+        mut,
+        associated_token::mint = treasury_payment_account.mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_payment_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = treasury_payment_account.mint,
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw protocol fees collected in a payment token to `destination`
+pub fn withdraw_token_handler(ctx: Context<WithdrawTreasuryToken>, amount: u64) -> Result<()> {
+    require!(amount > 0, SolSocialError::InvalidAmount);
+    require!(
+        ctx.accounts.treasury_payment_account.amount >= amount,
+        SolSocialError::InsufficientBalance
+    );
+
+    let seeds: &[&[u8]] = &[b"treasury", &[ctx.bumps.treasury]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury_payment_account.to_account_info(),
+        to: ctx.accounts.destination.to_account_info(),
+        authority: ctx.accounts.treasury.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, &[seeds]);
+    token::transfer(cpi_ctx, amount)?;
+
+    emit!(TreasuryTokenWithdrawEvent {
+        authority: ctx.accounts.treasury_authority.key(),
+        mint: ctx.accounts.treasury_payment_account.mint,
+        destination: ctx.accounts.destination.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Move `amount` out of the fee PDAs, returning how much came from each
+pub(crate) fn pay_out_fees<'info>(
+    treasury: AccountInfo<'info>,
+    treasury_bump: u8,
+    protocol_fee_account: AccountInfo<'info>,
+    protocol_fee_bump: u8,
+    destination: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<(u64, u64)> {
+    require!(amount > 0, SolSocialError::InvalidAmount);
+
+    let rent_floor = Rent::get()?.minimum_balance(0);
+    let from_treasury = treasury.lamports().saturating_sub(rent_floor).min(amount);
+    let from_protocol_fee = amount - from_treasury;
+    let protocol_fee_available = protocol_fee_account.lamports().saturating_sub(rent_floor);
+    require!(from_protocol_fee <= protocol_fee_available, SolSocialError::InsufficientBalance);
+
+    for (source, seed, bump, lamports) in [
+        (treasury, b"treasury".as_ref(), treasury_bump, from_treasury),
+        (protocol_fee_account, b"protocol_fee".as_ref(), protocol_fee_bump, from_protocol_fee),
+    ] {
+        if lamports == 0 {
+            continue;
+        }
+        let ix = anchor_lang::solana_program::system_instruction::transfer(source.key, destination.key, lamports);
+        anchor_lang::solana_program::program::invoke_signed(
+            &ix,
+            &[source, destination.clone(), system_program.clone()],
+            &[&[seed, &[bump]]],
+        )?;
+    }
+
+    Ok((from_treasury, from_protocol_fee))
+}
+
+#[event]
+pub struct TreasuryAuthorityChangedEvent {
+    pub admin: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawEvent {
+    pub authority: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub from_treasury: u64,
+    pub from_protocol_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryTokenWithdrawEvent {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
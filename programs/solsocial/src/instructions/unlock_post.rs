@@ -0,0 +1,132 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{
+    Post, UserKeys, KeyHolder, UserFollow, EarningsVault, Unlock, UnlockMethod, ProtocolConfig, Feature,
+    EARNINGS_VAULT_SEED, KEY_HOLDER_SEED, SEED_FOLLOWING, UNLOCK_SEED, unlock_key_requirement, unlock_price,
+};
+use crate::utils::revenue_share::system_transfer;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct UnlockPost<'info> {
+    #[account(mut)]
+    pub reader: Signer<'info>,
+
+    #[account(mut)]
+    pub post: Account<'info, Post>,
+
+    /// The author's key market, which prices paid unlocks
+    #[account(
+        seeds = [b"keys", post.author.as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// The reader's holding of the author's keys; unlocks for free when it meets the requirement
+    #[account(
+        seeds = [KEY_HOLDER_SEED, reader.key().as_ref(), post.author.as_ref()],
+        bump,
+    )]
+    pub key_holder: Option<Account<'info, KeyHolder>>,
+
+    /// The reader's follow of the author, required for followers-only posts
+    #[account(
+        seeds = [SEED_FOLLOWING, reader.key().as_ref(), post.author.as_ref()],
+        bump,
+    )]
+    pub follow: Option<Account<'info, UserFollow>>,
+
+    /// Collects paid unlocks for the author
+    #[account(
+        mut,
+        seeds = [EARNINGS_VAULT_SEED, post.author.as_ref()],
+        bump = earnings_vault.bump,
+    )]
+    pub earnings_vault: Account<'info, EarningsVault>,
+
+    #[account(
+        init,
+        payer = reader,
+        space = Unlock::LEN,
+        seeds = [UNLOCK_SEED, post.key().as_ref(), reader.key().as_ref()],
+        bump,
+    )]
+    pub unlock: Account<'info, Unlock>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Unlock a premium post for good. Readers holding the post's required keys unlock it for
+/// free; anyone else pays what those keys cost at the spot price, up to `max_payment`,
+/// into the author's earnings vault.
+pub fn handler(ctx: Context<UnlockPost>, max_payment: u64) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Posting)?;
+    let reader = ctx.accounts.reader.key();
+    let post = &mut ctx.accounts.post;
+    require!(post.is_premium || post.required_keys > 0, SolSocialError::PostNotPremium);
+    require!(post.author != reader, SolSocialError::CannotInteractOwnPost);
+    post.check_visibility(&reader, ctx.accounts.key_holder.as_deref(), ctx.accounts.follow.as_deref())?;
+
+    let requirement = unlock_key_requirement(post.required_keys);
+    let holds_keys = ctx.accounts.key_holder
+        .as_ref()
+        .map_or(false, |holder| holder.amount >= requirement);
+
+    let (method, amount_paid) = if holds_keys {
+        (UnlockMethod::KeyHolding, 0)
+    } else {
+        let price = unlock_price(ctx.accounts.keys_account.price, post.required_keys)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
+        require!(price <= max_payment, SolSocialError::SlippageExceeded);
+
+        system_transfer(
+            &ctx.accounts.reader.to_account_info(),
+            &ctx.accounts.earnings_vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            price,
+        )?;
+        ctx.accounts.earnings_vault.record_accrual(price)?;
+        post.add_revenue(price)?;
+        (UnlockMethod::Payment, price)
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    let unlock = &mut ctx.accounts.unlock;
+    unlock.post = post.key();
+    unlock.reader = reader;
+    unlock.method = method;
+    unlock.amount_paid = amount_paid;
+    unlock.unlocked_at = now;
+    unlock.bump = ctx.bumps.unlock;
+
+    emit!(PostUnlockedEvent {
+        post: post.key(),
+        author: post.author,
+        reader,
+        unlock: unlock.key(),
+        method,
+        amount_paid,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Key servers watch for this to release the post's decryption key to `reader`
+#[event]
+pub struct PostUnlockedEvent {
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub reader: Pubkey,
+    pub unlock: Pubkey,
+    pub method: UnlockMethod,
+    pub amount_paid: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
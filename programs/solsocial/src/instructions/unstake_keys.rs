@@ -0,0 +1,101 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{StakePosition, UserKeys, STAKE_POSITION_SEED, STAKE_VAULT_SEED};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct UnstakeKeys<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_POSITION_SEED, owner.key().as_ref(), stake_position.subject.as_ref()],
+        bump = stake_position.bump,
+        has_one = owner @ SolSocialError::Unauthorized,
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, stake_position.key().as_ref()],
+        bump,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"keys", stake_position.subject.as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Freezes and thaws the owner's key token account
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Return staked keys to the owner's wallet. Rewards earned so far stay claimable.
+pub fn handler(ctx: Context<UnstakeKeys>, amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let stake_position = &mut ctx.accounts.stake_position;
+    stake_position.accrue(now)?;
+    stake_position.unstake(amount)?;
+
+    let owner = stake_position.owner;
+    let subject = stake_position.subject;
+    let seeds = &[STAKE_POSITION_SEED, owner.as_ref(), subject.as_ref(), &[stake_position.bump]];
+    let signer = &[&seeds[..]];
+    let keys_seeds = &[b"keys".as_ref(), subject.as_ref(), &[ctx.bumps.keys_account]];
+    let keys_signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.keys_account,
+        keys_signer,
+    );
+    let owner_token_info = ctx.accounts.owner_token_account.to_account_info();
+
+    freeze.thaw(&owner_token_info)?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.stake_vault.to_account_info(),
+        to: owner_token_info.clone(),
+        authority: stake_position.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+    freeze.freeze(&owner_token_info)?;
+
+    emit!(KeysUnstakedEvent {
+        owner,
+        subject,
+        amount,
+        staked_amount: stake_position.staked_amount,
+        pending_rewards: stake_position.pending_rewards,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct KeysUnstakedEvent {
+    pub owner: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub staked_amount: u64,
+    pub pending_rewards: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
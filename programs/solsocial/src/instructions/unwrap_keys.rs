@@ -0,0 +1,154 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, ProtocolConfig, UserKeys, KEY_HOLDER_SEED, WRAPPED_KEYS_MINT_SEED, WRAPPED_KEYS_VAULT_SEED, Feature};
+use crate::utils::bonding_curve::calculate_sell_price;
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct UnwrapKeys<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: The subject whose keys are unwrapped
+    #[account(address = keys_account.user)]
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    /// Whoever redeems wrapped tokens becomes a holder of record, even if they never bought
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = KeyHolder::LEN,
+        seeds = [KEY_HOLDER_SEED, holder.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [WRAPPED_KEYS_MINT_SEED, keys_account.key().as_ref()],
+        bump,
+    )]
+    pub wrapped_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [WRAPPED_KEYS_VAULT_SEED, keys_account.key().as_ref()],
+        bump,
+    )]
+    pub wrapped_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = wrapped_mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_wrapped_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Burn `amount` wrapped tokens and release as many locked keys to the holder
+pub fn handler(ctx: Context<UnwrapKeys>, amount: u64) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    require!(amount > 0, SolSocialError::InvalidAmount);
+
+    let keys_account = &mut ctx.accounts.keys_account;
+    let params = keys_account.curve_params;
+    let held = ctx.accounts.key_holder.amount;
+    if !params.within_holder_cap(held, amount) {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::HolderCapReached,
+                price: held,
+                limit: params.max_keys_per_holder,
+                amount,
+            },
+            SolSocialError::HolderCapReached,
+        ));
+    }
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.wrapped_mint.to_account_info(),
+        from: ctx.accounts.holder_wrapped_account.to_account_info(),
+        authority: ctx.accounts.holder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::burn(cpi_ctx, amount)?;
+
+    let subject_key = ctx.accounts.subject.key();
+    let keys_seeds = &[b"keys".as_ref(), subject_key.as_ref(), &[ctx.bumps.keys_account]];
+    let signer = &[&keys_seeds[..]];
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let freeze = KeyFreeze::new(&token_program, &ctx.accounts.mint.to_account_info(), keys_account, signer);
+    let holder_token_info = ctx.accounts.holder_token_account.to_account_info();
+    freeze.thaw(&holder_token_info)?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.wrapped_vault.to_account_info(),
+        to: holder_token_info.clone(),
+        authority: keys_account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+    freeze.freeze(&holder_token_info)?;
+
+    // Wrapped tokens carry no cost basis here, so the keys enter at what they'd sell for now
+    let value = calculate_sell_price(&params, keys_account.supply, amount)?;
+    let key_holder = &mut ctx.accounts.key_holder;
+    if key_holder.holder == Pubkey::default() {
+        **key_holder = KeyHolder::new(ctx.accounts.holder.key(), subject_key);
+    }
+    let held_before = key_holder.amount;
+    key_holder.update_after_buy(amount, value / amount, value);
+    keys_account.track_holder_balance(held_before, key_holder.amount)?;
+
+    emit!(KeysUnwrappedEvent {
+        holder: ctx.accounts.holder.key(),
+        subject: subject_key,
+        amount,
+        wrapped_supply: ctx.accounts.wrapped_vault.amount.saturating_sub(amount),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct KeysUnwrappedEvent {
+    pub holder: Pubkey,
+    pub subject: Pubkey,
+    pub amount: u64,
+    /// Keys still locked behind the wrapped token
+    pub wrapped_supply: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,71 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::ChatSettings;
+
+#[derive(Accounts)]
+pub struct UpdateChatSettings<'info> {
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ChatSettings::LEN,
+        seeds = [b"chat_settings", user.key().as_ref()],
+        bump
+    )]
+    pub settings: Account<'info, ChatSettings>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<UpdateChatSettings>,
+    allow_messages_from_strangers: Option<bool>,
+    require_keys_for_dm: Option<bool>,
+    min_keys_required: Option<u64>,
+    auto_accept_from_holders: Option<bool>,
+    notification_enabled: Option<bool>,
+    encryption_enabled: Option<bool>,
+    min_sender_reputation: Option<u64>,
+    auto_reply: Option<String>,
+) -> Result<()> {
+    let settings = &mut ctx.accounts.settings;
+    if settings.user == Pubkey::default() {
+        settings.initialize(ctx.accounts.user.key(), ctx.bumps.settings)?;
+    }
+
+    settings.update_settings(
+        allow_messages_from_strangers,
+        require_keys_for_dm,
+        min_keys_required,
+        auto_accept_from_holders,
+        notification_enabled,
+        encryption_enabled,
+        min_sender_reputation,
+        auto_reply,
+    )?;
+
+    emit!(ChatSettingsUpdatedEvent {
+        user: settings.user,
+        allow_messages_from_strangers: settings.allow_messages_from_strangers,
+        require_keys_for_dm: settings.require_keys_for_dm,
+        min_keys_required: settings.min_keys_required,
+        auto_reply_enabled: settings.auto_reply.is_some(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ChatSettingsUpdatedEvent {
+    pub user: Pubkey,
+    pub allow_messages_from_strangers: bool,
+    pub require_keys_for_dm: bool,
+    pub min_keys_required: u64,
+    pub auto_reply_enabled: bool,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
@@ -0,0 +1,120 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::{ConfigUpdate, MilestoneTable, PendingChange, ProtocolConfig, PENDING_CHANGE_SEED};
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+        has_one = admin @ SolSocialError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = PendingChange::LEN,
+        seeds = [PENDING_CHANGE_SEED, config.key().as_ref(), &config.pending_change_count.to_le_bytes()],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue `update` behind the config timelock. Nothing changes until `execute_pending_change`
+/// runs after the delay, giving key holders time to react to fee hikes.
+pub fn handler(ctx: Context<UpdateConfig>, update: ConfigUpdate) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config_key = ctx.accounts.config.key();
+    let config = &mut ctx.accounts.config;
+
+    // Reject invalid settings now rather than when the timelock runs out
+    ProtocolConfig::clone(config).apply_update(&update, now)?;
+    let index = config.next_pending_change_index()?;
+    let eta = config.timelock_eta(now)?;
+
+    let pending_change = &mut ctx.accounts.pending_change;
+    pending_change.config = config_key;
+    pending_change.index = index;
+    pending_change.proposer = config.admin;
+    pending_change.update = update;
+    pending_change.queued_at = now;
+    pending_change.eta = eta;
+    pending_change.bump = ctx.bumps.pending_change;
+
+    emit!(ConfigChangeQueuedEvent {
+        admin: config.admin,
+        pending_change: pending_change.key(),
+        index,
+        update,
+        eta,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigChangeQueuedEvent {
+    pub admin: Pubkey,
+    pub pending_change: Pubkey,
+    pub index: u64,
+    pub update: ConfigUpdate,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdatedEvent {
+    pub admin: Pubkey,
+    pub max_media_bytes_per_user: u64,
+    pub reward_pool_bps: u16,
+    pub grace_drop_bps: u16,
+    pub grace_window_seconds: i64,
+    pub content_dedup_window_seconds: i64,
+    pub sell_queue_threshold: u64,
+    pub max_fee_bps: u16,
+    pub max_keys_per_trade: u64,
+    pub default_base_price: u64,
+    pub milestones: MilestoneTable,
+    pub timelock_seconds: i64,
+    pub circuit_breaker_bps: u16,
+    pub circuit_breaker_window_seconds: i64,
+    pub graduation_market_cap: u64,
+    pub max_price_impact_bps: u16,
+    pub early_supporter_bps: u16,
+    pub timestamp: i64,
+}
+
+impl ConfigUpdatedEvent {
+    pub fn snapshot(config: &ProtocolConfig, timestamp: i64) -> Self {
+        Self {
+            admin: config.admin,
+            max_media_bytes_per_user: config.max_media_bytes_per_user,
+            reward_pool_bps: config.reward_pool_bps,
+            grace_drop_bps: config.grace_drop_bps,
+            grace_window_seconds: config.grace_window_seconds,
+            content_dedup_window_seconds: config.content_dedup_window_seconds,
+            sell_queue_threshold: config.sell_queue_threshold,
+            max_fee_bps: config.max_fee_bps,
+            max_keys_per_trade: config.max_keys_per_trade,
+            default_base_price: config.default_base_price,
+            milestones: config.milestones,
+            timelock_seconds: config.timelock_seconds,
+            circuit_breaker_bps: config.circuit_breaker_bps,
+            circuit_breaker_window_seconds: config.circuit_breaker_window_seconds,
+            graduation_market_cap: config.graduation_market_cap,
+            max_price_impact_bps: config.max_price_impact_bps,
+            early_supporter_bps: config.early_supporter_bps,
+            timestamp,
+        }
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,17 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::Post;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct VerifyPostBody<'info> {
+    pub post: Account<'info, Post>,
+}
+
+/// Fail unless `body` is the post's content. Clients simulate this to prove an off-chain
+/// body fetched for a hashed post is the one its author committed to.
+pub fn handler(ctx: Context<VerifyPostBody>, body: String) -> Result<()> {
+    require!(ctx.accounts.post.verify_body(&body), SolSocialError::ContentHashMismatch);
+    Ok(())
+}
+```
\ No newline at end of file
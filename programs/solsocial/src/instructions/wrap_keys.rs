@@ -0,0 +1,164 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::state::{KeyHolder, ProtocolConfig, UserKeys, KEY_HOLDER_SEED, WRAPPED_KEYS_MINT_SEED, WRAPPED_KEYS_VAULT_SEED, Feature};
+use crate::utils::error_context::{fail_with_context, TradeErrorContext, TradeErrorKind};
+use crate::utils::key_token::KeyFreeze;
+use crate::error::SolSocialError;
+
+#[derive(Accounts)]
+pub struct WrapKeys<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: The subject whose keys are wrapped
+    #[account(address = keys_account.user)]
+    pub subject: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keys", subject.key().as_ref()],
+        bump,
+    )]
+    pub keys_account: Account<'info, UserKeys>,
+
+    #[account(
+        mut,
+        seeds = [KEY_HOLDER_SEED, holder.key().as_ref(), subject.key().as_ref()],
+        bump,
+    )]
+    pub key_holder: Account<'info, KeyHolder>,
+
+    #[account(address = keys_account.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = keys_account.mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// Freely transferable 1:1 claim on locked keys; only the keys PDA mints it
+    #[account(
+        init_if_needed,
+        payer = holder,
+        seeds = [WRAPPED_KEYS_MINT_SEED, keys_account.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = keys_account,
+    )]
+    pub wrapped_mint: Account<'info, Mint>,
+
+    /// Locks the keys backing the wrapped supply
+    #[account(
+        init_if_needed,
+        payer = holder,
+        seeds = [WRAPPED_KEYS_VAULT_SEED, keys_account.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = keys_account,
+    )]
+    pub wrapped_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = wrapped_mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_wrapped_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lock `amount` keys in the market's wrapped vault and mint the holder as many wrapped
+/// tokens. Wrapped tokens move like any SPL token, so the keys leave the holder record
+/// until `unwrap_keys` redeems them; they keep counting towards supply while locked.
+pub fn handler(ctx: Context<WrapKeys>, amount: u64) -> Result<()> {
+    ctx.accounts.config.require_feature(Feature::Trading)?;
+    require!(amount > 0, SolSocialError::InvalidAmount);
+
+    let keys_account = &mut ctx.accounts.keys_account;
+    // A halted market freezes its keys in place, wrapping included
+    require!(!keys_account.trading_halted, SolSocialError::OperationNotAllowed);
+
+    // Keys escrowed behind open orders are already out of the holder record
+    let held = ctx.accounts.key_holder.amount;
+    if held < amount {
+        return Err(fail_with_context(
+            TradeErrorContext { kind: TradeErrorKind::InsufficientKeys, price: 0, limit: held, amount },
+            SolSocialError::InsufficientKeys,
+        ));
+    }
+
+    let subject_key = ctx.accounts.subject.key();
+    let keys_seeds = &[b"keys".as_ref(), subject_key.as_ref(), &[ctx.bumps.keys_account]];
+    let signer = &[&keys_seeds[..]];
+    let freeze = KeyFreeze::new(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        keys_account,
+        signer,
+    );
+    let holder_token_info = ctx.accounts.holder_token_account.to_account_info();
+    freeze.thaw(&holder_token_info)?;
+    let cpi_accounts = Transfer {
+        from: holder_token_info.clone(),
+        to: ctx.accounts.wrapped_vault.to_account_info(),
+        authority: ctx.accounts.holder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+    freeze.freeze(&holder_token_info)?;
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.wrapped_mint.to_account_info(),
+        to: ctx.accounts.holder_wrapped_account.to_account_info(),
+        authority: keys_account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::mint_to(cpi_ctx, amount)?;
+
+    // The keys leave the holder record at their average cost, as in transfer_keys
+    let key_holder = &mut ctx.accounts.key_holder;
+    let held_before = key_holder.amount;
+    let value_moved = key_holder.avg_price.saturating_mul(amount);
+    key_holder.update_after_sell(amount, value_moved);
+    keys_account.track_holder_balance(held_before, key_holder.amount)?;
+
+    let wrapped_supply = ctx.accounts.wrapped_vault.amount
+        .checked_add(amount)
+        .ok_or(SolSocialError::ArithmeticOverflow)?;
+
+    emit!(KeysWrappedEvent {
+        holder: ctx.accounts.holder.key(),
+        subject: subject_key,
+        wrapped_mint: ctx.accounts.wrapped_mint.key(),
+        amount,
+        wrapped_supply,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct KeysWrappedEvent {
+    pub holder: Pubkey,
+    pub subject: Pubkey,
+    pub wrapped_mint: Pubkey,
+    pub amount: u64,
+    /// Keys locked behind the wrapped token after this wrap
+    pub wrapped_supply: u64,
+    pub timestamp: i64,
+}
+```
\ No newline at end of file
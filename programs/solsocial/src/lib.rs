@@ -15,6 +15,100 @@ declare_id!("SoLSociaL1111111111111111111111111111111111");
 pub mod solsocial {
     use super::*;
 
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        max_media_bytes_per_user: u64,
+        reward_pool_bps: u16,
+        grace_drop_bps: u16,
+        grace_window_seconds: i64,
+        content_dedup_window_seconds: i64,
+        sell_queue_threshold: u64,
+        timelock_seconds: i64,
+    ) -> Result<()> {
+        instructions::initialize_config::handler(
+            ctx,
+            max_media_bytes_per_user,
+            reward_pool_bps,
+            grace_drop_bps,
+            grace_window_seconds,
+            content_dedup_window_seconds,
+            sell_queue_threshold,
+            timelock_seconds,
+        )
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, update: state::ConfigUpdate) -> Result<()> {
+        instructions::update_config::handler(ctx, update)
+    }
+
+    pub fn execute_pending_change(ctx: Context<ExecutePendingChange>) -> Result<()> {
+        instructions::pending_change::execute_handler(ctx)
+    }
+
+    pub fn cancel_pending_change(ctx: Context<CancelPendingChange>) -> Result<()> {
+        instructions::pending_change::cancel_handler(ctx)
+    }
+
+    pub fn set_feature_flags(ctx: Context<SetFeatureFlags>, flags: state::FeatureFlags) -> Result<()> {
+        instructions::set_feature_flags::handler(ctx, flags)
+    }
+
+    pub fn grant_fee_exemption(ctx: Context<GrantFeeExemption>, account: Pubkey) -> Result<()> {
+        instructions::fee_exemption::grant_handler(ctx, account)
+    }
+
+    pub fn revoke_fee_exemption(ctx: Context<RevokeFeeExemption>) -> Result<()> {
+        instructions::fee_exemption::revoke_handler(ctx)
+    }
+
+    pub fn set_treasury_authority(ctx: Context<SetTreasuryAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::treasury::set_authority_handler(ctx, new_authority)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64, destination: Pubkey) -> Result<()> {
+        instructions::treasury::withdraw_handler(ctx, amount, destination)
+    }
+
+    pub fn withdraw_treasury_token(ctx: Context<WithdrawTreasuryToken>, amount: u64) -> Result<()> {
+        instructions::treasury::withdraw_token_handler(ctx, amount)
+    }
+
+    pub fn create_admin_multisig(ctx: Context<CreateAdminMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::admin_multisig::create_handler(ctx, signers, threshold)
+    }
+
+    pub fn propose_admin_action(ctx: Context<ProposeAdminAction>, action: state::AdminAction) -> Result<()> {
+        instructions::admin_multisig::propose_handler(ctx, action)
+    }
+
+    pub fn approve_admin_action(ctx: Context<ApproveAdminAction>) -> Result<()> {
+        instructions::admin_multisig::approve_handler(ctx)
+    }
+
+    pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+        instructions::admin_multisig::execute_handler(ctx)
+    }
+
+    pub fn migrate_user(ctx: Context<MigrateUser>) -> Result<()> {
+        instructions::migrate_user::handler(ctx)
+    }
+
+    pub fn set_guardians(ctx: Context<SetGuardians>, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::set_guardians::handler(ctx, guardians, threshold)
+    }
+
+    pub fn initiate_recovery(ctx: Context<InitiateRecovery>, new_authority: Pubkey) -> Result<()> {
+        instructions::recover_account::initiate_handler(ctx, new_authority)
+    }
+
+    pub fn recover_account(ctx: Context<RecoverAccount>) -> Result<()> {
+        instructions::recover_account::handler(ctx)
+    }
+
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        instructions::recover_account::cancel_handler(ctx)
+    }
+
     pub fn initialize_user(
         ctx: Context<InitializeUser>,
         username: String,
@@ -25,38 +119,290 @@ pub mod solsocial {
         instructions::initialize_user::handler(ctx, username, display_name, bio, avatar_url)
     }
 
-    pub fn create_keys(
-        ctx: Context<CreateKeys>,
+    pub fn reclaim_username(
+        ctx: Context<ReclaimUsername>,
+        username: String,
+    ) -> Result<()> {
+        instructions::reclaim_username::handler(ctx, username)
+    }
+
+    pub fn create_keys<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateKeys<'info>>,
         initial_supply: u64,
         initial_price: u64,
+        curve: Option<state::CurveChoice>,
+        founder_allocations: Vec<state::FounderAllocation>,
+        launch_auction: Option<state::LaunchAuctionParams>,
+        presale: Option<state::PresaleParams>,
+        launch_guard: Option<state::LaunchGuardParams>,
+        creator_vesting: Option<state::VestingParams>,
     ) -> Result<()> {
-        instructions::create_keys::handler(ctx, initial_supply, initial_price)
+        instructions::create_keys::handler(
+            ctx,
+            initial_supply,
+            initial_price,
+            curve,
+            founder_allocations,
+            launch_auction,
+            presale,
+            launch_guard,
+            creator_vesting,
+        )
+    }
+
+    pub fn claim_vested_keys(ctx: Context<ClaimVestedKeys>) -> Result<()> {
+        instructions::claim_vested_keys::handler(ctx)
+    }
+
+    pub fn buyback_keys(ctx: Context<BuybackKeys>, amount: u64) -> Result<()> {
+        instructions::buyback_keys::handler(ctx, amount)
+    }
+
+    pub fn set_payment_mint(ctx: Context<SetPaymentMint>) -> Result<()> {
+        instructions::set_payment_mint::handler(ctx)
+    }
+
+    pub fn halt_trading(ctx: Context<SetTradingHalt>) -> Result<()> {
+        instructions::trading_halt::halt_handler(ctx)
+    }
+
+    pub fn resume_trading(ctx: Context<SetTradingHalt>) -> Result<()> {
+        instructions::trading_halt::resume_handler(ctx)
+    }
+
+    pub fn graduate_market(ctx: Context<GraduateMarket>) -> Result<()> {
+        instructions::graduate_market::handler(ctx)
+    }
+
+    pub fn release_graduated_keys(ctx: Context<ReleaseGraduatedKeys>) -> Result<()> {
+        instructions::release_graduated_keys::handler(ctx)
+    }
+
+    pub fn bid_launch_auction(ctx: Context<BidLaunchAuction>, amount: u64, max_price: u64) -> Result<()> {
+        instructions::bid_launch_auction::handler(ctx, amount, max_price)
+    }
+
+    pub fn buy_presale_keys(ctx: Context<BuyPresaleKeys>, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::buy_presale_keys::handler(ctx, amount, proof)
     }
 
     pub fn buy_keys(
         ctx: Context<BuyKeys>,
         amount: u64,
         max_price: u64,
+        deadline: Option<i64>,
+        max_price_impact_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::buy_keys::handler(ctx, amount, max_price, deadline, max_price_impact_bps)
+    }
+
+    pub fn buy_keys_exact(
+        ctx: Context<BuyKeys>,
+        amount: u64,
+        total_price: u64,
+        tolerance_bps: u16,
     ) -> Result<()> {
-        instructions::buy_keys::handler(ctx, amount, max_price)
+        instructions::buy_keys_exact::handler(ctx, amount, total_price, tolerance_bps)
+    }
+
+    pub fn buy_keys_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyKeysBatch<'info>>,
+        legs: Vec<state::BatchBuyLeg>,
+    ) -> Result<()> {
+        instructions::buy_keys_batch::handler(ctx, legs)
+    }
+
+    pub fn buy_keys_exact_in(
+        ctx: Context<BuyKeys>,
+        lamports_budget: u64,
+        min_keys_out: u64,
+    ) -> Result<()> {
+        instructions::buy_keys_exact_in::handler(ctx, lamports_budget, min_keys_out)
     }
 
     pub fn sell_keys(
         ctx: Context<SellKeys>,
         amount: u64,
         min_price: u64,
+        deadline: Option<i64>,
+        max_price_impact_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::sell_keys::handler(ctx, amount, min_price, deadline, max_price_impact_bps)
+    }
+
+    pub fn sell_all_keys(ctx: Context<SellKeys>, min_proceeds: u64) -> Result<()> {
+        instructions::sell_all_keys::handler(ctx, min_proceeds)
+    }
+
+    pub fn transfer_keys(ctx: Context<TransferKeys>, to: Pubkey, amount: u64) -> Result<()> {
+        instructions::transfer_keys::handler(ctx, to, amount)
+    }
+
+    pub fn wrap_keys(ctx: Context<WrapKeys>, amount: u64) -> Result<()> {
+        instructions::wrap_keys::handler(ctx, amount)
+    }
+
+    pub fn unwrap_keys(ctx: Context<UnwrapKeys>, amount: u64) -> Result<()> {
+        instructions::unwrap_keys::handler(ctx, amount)
+    }
+
+    pub fn gift_keys(
+        ctx: Context<GiftKeys>,
+        to: Pubkey,
+        amount: u64,
+        max_price: u64,
+        message: Option<String>,
+    ) -> Result<()> {
+        instructions::gift_keys::handler(ctx, to, amount, max_price, message)
+    }
+
+    pub fn stake_keys(ctx: Context<StakeKeys>, amount: u64) -> Result<()> {
+        instructions::stake_keys::handler(ctx, amount)
+    }
+
+    pub fn unstake_keys(ctx: Context<UnstakeKeys>, amount: u64) -> Result<()> {
+        instructions::unstake_keys::handler(ctx, amount)
+    }
+
+    pub fn claim_staking_rewards(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+        instructions::claim_staking_rewards::handler(ctx)
+    }
+
+    pub fn open_dividend_epoch(
+        ctx: Context<OpenDividendEpoch>,
+        amount: u64,
+        source: state::DividendSource,
+    ) -> Result<()> {
+        instructions::open_dividend_epoch::handler(ctx, amount, source)
+    }
+
+    pub fn claim_dividends(ctx: Context<ClaimDividends>) -> Result<()> {
+        instructions::claim_dividends::handler(ctx)
+    }
+
+    pub fn sweep_dividend_epoch(ctx: Context<SweepDividendEpoch>) -> Result<()> {
+        instructions::sweep_dividend_epoch::handler(ctx)
+    }
+
+    pub fn claim_earnings(ctx: Context<ClaimEarnings>) -> Result<()> {
+        instructions::claim_earnings::handler(ctx)
+    }
+
+    pub fn claim_early_supporter_rewards(ctx: Context<ClaimEarlySupporterRewards>) -> Result<()> {
+        instructions::claim_early_supporter_rewards::handler(ctx)
+    }
+
+    pub fn create_shared_keys<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateSharedKeys<'info>>,
+        shares: Vec<state::CollaboratorShare>,
+    ) -> Result<()> {
+        instructions::create_shared_keys::handler(ctx, shares)
+    }
+
+    pub fn distribute_shared_earnings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeSharedEarnings<'info>>,
+    ) -> Result<()> {
+        instructions::distribute_shared_earnings::handler(ctx)
+    }
+
+    pub fn commit_buy(ctx: Context<CommitBuy>, commitment_hash: [u8; 32], max_cost: u64) -> Result<()> {
+        instructions::commit_buy::handler(ctx, commitment_hash, max_cost)
+    }
+
+    pub fn reveal_buy(ctx: Context<RevealBuy>, amount: u64, nonce: u64) -> Result<()> {
+        instructions::reveal_buy::handler(ctx, amount, nonce)
+    }
+
+    pub fn refund_buy_commitment(ctx: Context<RefundBuyCommitment>) -> Result<()> {
+        instructions::refund_buy_commitment::handler(ctx)
+    }
+
+    pub fn queue_sell(
+        ctx: Context<QueueSell>,
+        amount: u64,
+        tranche_size: u64,
+        tranche_interval_slots: u64,
+        min_price_per_key: u64,
+    ) -> Result<()> {
+        instructions::queue_sell::handler(ctx, amount, tranche_size, tranche_interval_slots, min_price_per_key)
+    }
+
+    pub fn process_sell_tranche(ctx: Context<ProcessSellTranche>) -> Result<()> {
+        instructions::process_sell_tranche::handler(ctx)
+    }
+
+    pub fn cancel_queued_sell(ctx: Context<CancelQueuedSell>) -> Result<()> {
+        instructions::cancel_queued_sell::handler(ctx)
+    }
+
+    pub fn set_trading_hours(ctx: Context<SetTradingHours>, open_at: i64, close_at: i64) -> Result<()> {
+        instructions::set_trading_hours::handler(ctx, open_at, close_at)
+    }
+
+    pub fn create_holder_vote(
+        ctx: Context<CreateHolderVote>,
+        vote_id: u64,
+        question: String,
+        options: Vec<String>,
+        key_weighted: bool,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::create_holder_vote::handler(ctx, vote_id, question, options, key_weighted, deadline)
+    }
+
+    pub fn cast_holder_vote(ctx: Context<CastHolderVote>, option_index: u8) -> Result<()> {
+        instructions::cast_holder_vote::handler(ctx, option_index)
+    }
+
+    pub fn finalize_holder_vote(ctx: Context<FinalizeHolderVote>) -> Result<()> {
+        instructions::finalize_holder_vote::handler(ctx)
+    }
+
+    pub fn create_raffle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateRaffle<'info>>,
+        raffle_id: u64,
+        prize: u64,
     ) -> Result<()> {
-        instructions::sell_keys::handler(ctx, amount, min_price)
+        instructions::create_raffle::handler(ctx, raffle_id, prize)
     }
 
-    pub fn create_post(
-        ctx: Context<CreatePost>,
+    pub fn draw_raffle(ctx: Context<DrawRaffle>) -> Result<()> {
+        instructions::draw_raffle::handler(ctx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_post<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreatePost<'info>>,
         content: String,
-        media_urls: Vec<String>,
+        media: Vec<state::MediaAttachment>,
         post_type: u8,
         required_keys: u64,
+        visibility: Option<state::PostVisibility>,
+        mentions: Vec<Pubkey>,
+        expires_in: Option<i64>,
+    ) -> Result<()> {
+        instructions::create_post::handler(ctx, content, media, post_type, required_keys, visibility, mentions, expires_in)
+    }
+
+    pub fn create_post_hashed(
+        ctx: Context<CreatePostHashed>,
+        content_hash: [u8; 32],
+        uri: String,
+        content_len: u32,
     ) -> Result<()> {
-        instructions::create_post::handler(ctx, content, media_urls, post_type, required_keys)
+        instructions::create_post_hashed::handler(ctx, content_hash, uri, content_len)
+    }
+
+    pub fn create_thread_post(ctx: Context<CreateThreadPost>, content: String) -> Result<()> {
+        instructions::create_thread_post::handler(ctx, content)
+    }
+
+    pub fn verify_post_body(ctx: Context<VerifyPostBody>, body: String) -> Result<()> {
+        instructions::verify_post_body::handler(ctx, body)
+    }
+
+    pub fn expire_post(ctx: Context<ExpirePost>) -> Result<()> {
+        instructions::expire_post::handler(ctx)
     }
 
     pub fn interact_post(
@@ -67,12 +413,45 @@ pub mod solsocial {
         instructions::interact_post::handler(ctx, interaction_type, content)
     }
 
+    pub fn distribute_engagement_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeEngagementRewards<'info>>,
+        kickback_bps: u16,
+    ) -> Result<()> {
+        instructions::distribute_engagement_rewards::handler(ctx, kickback_bps)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn create_chat(
         ctx: Context<CreateChat>,
-        participant: Pubkey,
-        required_keys: u64,
+        chat_id: String,
+        name: String,
+        description: String,
+        is_private: bool,
+        max_participants: u32,
+        auto_mute_new: bool,
+        is_direct: bool,
     ) -> Result<()> {
-        instructions::create_chat::handler(ctx, participant, required_keys)
+        instructions::create_chat::handler(ctx, chat_id, name, description, is_private, max_participants, auto_mute_new, is_direct)
+    }
+
+    pub fn join_chat(ctx: Context<JoinChat>, chat_id: String) -> Result<()> {
+        instructions::join_chat::handler(ctx, chat_id)
+    }
+
+    pub fn set_participant_mute(
+        ctx: Context<SetParticipantMute>,
+        chat_id: String,
+        muted: bool,
+    ) -> Result<()> {
+        instructions::set_participant_mute::handler(ctx, chat_id, muted)
+    }
+
+    pub fn pin_message(ctx: Context<PinMessage>, chat_id: String) -> Result<()> {
+        instructions::pin_message::pin_handler(ctx, chat_id)
+    }
+
+    pub fn unpin_message(ctx: Context<UnpinMessage>, chat_id: String) -> Result<()> {
+        instructions::pin_message::unpin_handler(ctx, chat_id)
     }
 
     pub fn send_message(
@@ -84,30 +463,254 @@ pub mod solsocial {
         instructions::send_message::handler(ctx, content, message_type, media_url)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_chat_settings(
+        ctx: Context<UpdateChatSettings>,
+        allow_messages_from_strangers: Option<bool>,
+        require_keys_for_dm: Option<bool>,
+        min_keys_required: Option<u64>,
+        auto_accept_from_holders: Option<bool>,
+        notification_enabled: Option<bool>,
+        encryption_enabled: Option<bool>,
+        min_sender_reputation: Option<u64>,
+        auto_reply: Option<String>,
+    ) -> Result<()> {
+        instructions::update_chat_settings::handler(
+            ctx,
+            allow_messages_from_strangers,
+            require_keys_for_dm,
+            min_keys_required,
+            auto_accept_from_holders,
+            notification_enabled,
+            encryption_enabled,
+            min_sender_reputation,
+            auto_reply,
+        )
+    }
+
+    pub fn refresh_top_markets<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefreshTopMarkets<'info>>,
+    ) -> Result<()> {
+        instructions::refresh_top_markets::handler(ctx)
+    }
+
+    pub fn claim_all_earnings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimAllEarnings<'info>>,
+    ) -> Result<()> {
+        instructions::claim_all_earnings::handler(ctx)
+    }
+
+    pub fn place_limit_order(
+        ctx: Context<PlaceLimitOrder>,
+        side: state::OrderSide,
+        price_per_key: u64,
+        amount: u64,
+        expires_at: i64,
+        order_id: u64,
+    ) -> Result<()> {
+        instructions::place_limit_order::handler(ctx, side, price_per_key, amount, expires_at, order_id)
+    }
+
+    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+        instructions::cancel_order::handler(ctx)
+    }
+
+    pub fn fill_order(ctx: Context<FillOrder>) -> Result<()> {
+        instructions::fill_order::handler(ctx)
+    }
+
+    pub fn create_trigger_order(
+        ctx: Context<CreateTriggerOrder>,
+        kind: state::TriggerKind,
+        trigger_price: u64,
+        amount: u64,
+        min_proceeds: u64,
+        order_id: u64,
+    ) -> Result<()> {
+        instructions::create_trigger_order::handler(ctx, kind, trigger_price, amount, min_proceeds, order_id)
+    }
+
+    pub fn execute_trigger_order(ctx: Context<ExecuteTriggerOrder>) -> Result<()> {
+        instructions::execute_trigger_order::handler(ctx)
+    }
+
+    pub fn cancel_trigger_order(ctx: Context<CancelTriggerOrder>) -> Result<()> {
+        instructions::cancel_trigger_order::handler(ctx)
+    }
+
+    pub fn create_dca_schedule(
+        ctx: Context<CreateDcaSchedule>,
+        subject: Pubkey,
+        lamports_per_interval: u64,
+        interval_seconds: i64,
+        deposit: u64,
+    ) -> Result<()> {
+        instructions::create_dca_schedule::handler(ctx, subject, lamports_per_interval, interval_seconds, deposit)
+    }
+
+    pub fn execute_dca(ctx: Context<ExecuteDca>) -> Result<()> {
+        instructions::execute_dca::handler(ctx)
+    }
+
+    pub fn cancel_dca_schedule(ctx: Context<CancelDcaSchedule>) -> Result<()> {
+        instructions::cancel_dca_schedule::handler(ctx)
+    }
+
+    pub fn create_otc_offer(
+        ctx: Context<CreateOtcOffer>,
+        side: state::OrderSide,
+        amount: u64,
+        price: u64,
+        taker: Option<Pubkey>,
+        expires_at: i64,
+        offer_id: u64,
+    ) -> Result<()> {
+        instructions::create_otc_offer::handler(ctx, side, amount, price, taker, expires_at, offer_id)
+    }
+
+    pub fn accept_otc_offer(ctx: Context<AcceptOtcOffer>) -> Result<()> {
+        instructions::accept_otc_offer::handler(ctx)
+    }
+
+    pub fn cancel_otc_offer(ctx: Context<CancelOtcOffer>) -> Result<()> {
+        instructions::cancel_otc_offer::handler(ctx)
+    }
+
+    pub fn create_loan_offer(
+        ctx: Context<CreateLoanOffer>,
+        amount: u64,
+        collateral_ratio_bps: u16,
+        liquidation_ratio_bps: u16,
+        fee_bps: u16,
+        duration_seconds: i64,
+        loan_id: u64,
+    ) -> Result<()> {
+        instructions::create_loan_offer::handler(
+            ctx,
+            amount,
+            collateral_ratio_bps,
+            liquidation_ratio_bps,
+            fee_bps,
+            duration_seconds,
+            loan_id,
+        )
+    }
+
+    pub fn take_loan(ctx: Context<TakeLoan>, collateral: u64) -> Result<()> {
+        instructions::take_loan::handler(ctx, collateral)
+    }
+
+    pub fn repay_loan(ctx: Context<RepayLoan>) -> Result<()> {
+        instructions::repay_loan::handler(ctx)
+    }
+
+    pub fn liquidate(ctx: Context<Liquidate>) -> Result<()> {
+        instructions::liquidate::handler(ctx)
+    }
+
+    pub fn cancel_loan_offer(ctx: Context<CancelLoanOffer>) -> Result<()> {
+        instructions::cancel_loan_offer::handler(ctx)
+    }
+
+    pub fn sweep_expired_orders<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepExpiredOrders<'info>>,
+    ) -> Result<()> {
+        instructions::sweep_expired_orders::handler(ctx)
+    }
+
+    pub fn finalize_activity_day(ctx: Context<FinalizeActivityDay>) -> Result<()> {
+        instructions::finalize_activity_day::handler(ctx)
+    }
+
+    pub fn share_post(ctx: Context<SharePost>) -> Result<()> {
+        instructions::share_post::handler(ctx)
+    }
+
+    pub fn repost(ctx: Context<Repost>, quote: Option<String>) -> Result<()> {
+        instructions::repost::handler(ctx, quote)
+    }
+
+    pub fn tip_post(
+        ctx: Context<TipPost>,
+        amount: u64,
+        message: Option<String>,
+    ) -> Result<()> {
+        instructions::tip_post::handler(ctx, amount, message)
+    }
+
+    pub fn unlock_post(ctx: Context<UnlockPost>, max_payment: u64) -> Result<()> {
+        instructions::unlock_post::handler(ctx, max_payment)
+    }
+
+    pub fn boost_post(ctx: Context<BoostPost>, lamports: u64, duration: i64) -> Result<()> {
+        instructions::boost_post::handler(ctx, lamports, duration)
+    }
+
+    pub fn end_boost(ctx: Context<EndBoost>) -> Result<()> {
+        instructions::end_boost::handler(ctx)
+    }
+
+    pub fn mint_post_nft(ctx: Context<MintPostNft>, name: String, uri: String, royalty_bps: u16) -> Result<()> {
+        instructions::mint_post_nft::handler(ctx, name, uri, royalty_bps)
+    }
+
+    pub fn bookmark_post(ctx: Context<BookmarkPost>) -> Result<()> {
+        instructions::bookmark_post::handler(ctx)
+    }
+
+    pub fn remove_bookmark(ctx: Context<RemoveBookmark>) -> Result<()> {
+        instructions::remove_bookmark::handler(ctx)
+    }
+
+    pub fn create_collection(ctx: Context<CreateCollection>, name: String) -> Result<()> {
+        instructions::create_collection::handler(ctx, name)
+    }
+
+    pub fn delete_collection(ctx: Context<DeleteCollection>) -> Result<()> {
+        instructions::delete_collection::handler(ctx)
+    }
+
+    pub fn create_comment<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateComment<'info>>,
+        content: String,
+        mentions: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_comment::handler(ctx, content, mentions)
+    }
+
+    pub fn delete_comment(ctx: Context<DeleteComment>) -> Result<()> {
+        instructions::delete_comment::handler(ctx)
+    }
+
+    pub fn fund_tip_match(ctx: Context<FundTipMatch>, match_ratio_bps: u16, amount: u64) -> Result<()> {
+        instructions::fund_tip_match::handler(ctx, match_ratio_bps, amount)
+    }
+
     pub fn update_user_profile(
         ctx: Context<UpdateUserProfile>,
         display_name: Option<String>,
         bio: Option<String>,
         avatar_url: Option<String>,
+        default_post_visibility: Option<state::PostVisibility>,
+        share_reward_bps: Option<u16>,
+        min_self_hold: Option<u64>,
     ) -> Result<()> {
         let user_account = &mut ctx.accounts.user_account;
+        let clock = Clock::get()?;
         
-        if let Some(name) = display_name {
-            require!(name.len() <= 50, SolSocialError::DisplayNameTooLong);
-            user_account.display_name = name;
-        }
+        user_account.update_profile(display_name, bio, avatar_url, None, None, None, None, &clock)?;
         
-        if let Some(bio_text) = bio {
-            require!(bio_text.len() <= 280, SolSocialError::BioTooLong);
-            user_account.bio = bio_text;
+        if let Some(visibility) = default_post_visibility {
+            user_account.set_default_post_visibility(visibility);
         }
         
-        if let Some(avatar) = avatar_url {
-            require!(avatar.len() <= 200, SolSocialError::AvatarUrlTooLong);
-            user_account.avatar_url = avatar;
+        if let Some(bps) = share_reward_bps {
+            user_account.set_share_reward_bps(bps)?;
         }
         
-        user_account.updated_at = Clock::get()?.unix_timestamp;
+        if let Some(min_hold) = min_self_hold {
+            user_account.min_self_hold = min_hold;
+        }
         
         Ok(())
     }
@@ -115,13 +718,19 @@ pub mod solsocial {
     pub fn follow_user(
         ctx: Context<FollowUser>,
     ) -> Result<()> {
+        ctx.accounts.follow.initialize(
+            ctx.accounts.follower.key(),
+            ctx.accounts.following.key(),
+            &Clock::get()?,
+        )?;
+
         let follower_account = &mut ctx.accounts.follower_account;
         let following_account = &mut ctx.accounts.following_account;
         
         follower_account.following_count = follower_account.following_count.checked_add(1)
             .ok_or(SolSocialError::ArithmeticOverflow)?;
         
-        following_account.followers_count = following_account.followers_count.checked_add(1)
+        following_account.follower_count = following_account.follower_count.checked_add(1)
             .ok_or(SolSocialError::ArithmeticOverflow)?;
         
         emit!(FollowEvent {
@@ -140,7 +749,7 @@ pub mod solsocial {
         let following_account = &mut ctx.accounts.following_account;
         
         follower_account.following_count = follower_account.following_count.saturating_sub(1);
-        following_account.followers_count = following_account.followers_count.saturating_sub(1);
+        following_account.follower_count = following_account.follower_count.saturating_sub(1);
         
         emit!(UnfollowEvent {
             follower: ctx.accounts.follower.key(),
@@ -262,9 +871,20 @@ pub struct FollowUser<'info> {
         bump
     )]
     pub following_account: Account<'info, state::User>,
+    /// Proof of the follow, checked by followers-only posts
+    #[account(
+        init,
+        payer = follower,
+        space = state::UserFollow::LEN,
+        seeds = [state::SEED_FOLLOWING, follower.key().as_ref(), following.key().as_ref()],
+        bump
+    )]
+    pub follow: Account<'info, state::UserFollow>,
+    #[account(mut)]
     pub follower: Signer<'info>,
     /// CHECK: Following user public key
     pub following: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -281,6 +901,14 @@ pub struct UnfollowUser<'info> {
         bump
     )]
     pub following_account: Account<'info, state::User>,
+    #[account(
+        mut,
+        close = follower,
+        seeds = [state::SEED_FOLLOWING, follower.key().as_ref(), following.key().as_ref()],
+        bump
+    )]
+    pub follow: Account<'info, state::UserFollow>,
+    #[account(mut)]
     pub follower: Signer<'info>,
     /// CHECK: Following user public key
     pub following: AccountInfo<'info>,
@@ -0,0 +1,256 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::error::SolSocialError;
+use super::{ConfigUpdate, FeatureFlags};
+
+/// M-of-N signer set that holds the protocol's admin and treasury authority
+#[account]
+pub struct AdminMultisig {
+    /// Wallets allowed to propose and approve admin actions
+    pub signers: Vec<Pubkey>,
+    /// Distinct approvals needed to execute a proposal
+    pub threshold: u8,
+    /// Proposals created so far; the next one's index
+    pub proposal_count: u64,
+    /// Timestamp when the multisig was created
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AdminMultisig {
+    /// Approvals are a bitmask over `signers`, so the set must fit in a `u16`
+    pub const MAX_SIGNERS: usize = 10;
+
+    pub const LEN: usize = 8 + // discriminator
+        4 + 32 * Self::MAX_SIGNERS + // signers
+        1 + // threshold
+        8 + // proposal_count
+        8 + // created_at
+        1; // bump
+
+    pub fn initialize(&mut self, signers: Vec<Pubkey>, threshold: u8, now: i64, bump: u8) -> Result<()> {
+        require!(
+            !signers.is_empty() && signers.len() <= Self::MAX_SIGNERS,
+            SolSocialError::InvalidMultisig
+        );
+        require!(
+            threshold > 0 && threshold as usize <= signers.len(),
+            SolSocialError::InvalidMultisig
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(*signer != Pubkey::default(), SolSocialError::InvalidMultisig);
+            require!(!signers[..i].contains(signer), SolSocialError::InvalidMultisig);
+        }
+
+        self.signers = signers;
+        self.threshold = threshold;
+        self.proposal_count = 0;
+        self.created_at = now;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Position of `key` in the signer set, failing for anyone else
+    pub fn signer_index(&self, key: &Pubkey) -> Result<usize> {
+        self.signers
+            .iter()
+            .position(|signer| signer == key)
+            .ok_or_else(|| SolSocialError::NotMultisigSigner.into())
+    }
+
+    pub fn next_proposal_index(&mut self) -> Result<u64> {
+        let index = self.proposal_count;
+        self.proposal_count = index
+            .checked_add(1)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
+        Ok(index)
+    }
+}
+
+/// Privileged operation a proposal carries out once approved
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum AdminAction {
+    UpdateConfig(ConfigUpdate),
+    SetFeatureFlags(FeatureFlags),
+    SetTreasuryAuthority(Pubkey),
+    /// Rotate the protocol admin, e.g. to a replacement signer set
+    SetAdmin(Pubkey),
+    WithdrawTreasury { amount: u64, destination: Pubkey },
+    /// Grant or revoke the verified badge on the profile of wallet `user`
+    SetVerified { user: Pubkey, verified: bool },
+    /// Exempt trader wallet `account` from protocol fees
+    GrantFeeExemption { account: Pubkey },
+    /// Withdraw the fee exemption of trader wallet `account`
+    RevokeFeeExemption { account: Pubkey },
+}
+
+impl AdminAction {
+    /// Largest serialized action: the tag plus a full `ConfigUpdate`
+    pub const LEN: usize = 1 + ConfigUpdate::LEN;
+}
+
+/// One proposed admin action and the approvals collected for it
+#[account]
+pub struct AdminProposal {
+    /// The multisig this proposal belongs to
+    pub multisig: Pubkey,
+    /// Index of the proposal within the multisig
+    pub index: u64,
+    /// Signer who proposed the action
+    pub proposer: Pubkey,
+    /// The action to execute
+    pub action: AdminAction,
+    /// Bitmask of approving signers, by position in the multisig's signer set
+    pub approvals: u16,
+    /// Whether the action has been executed
+    pub executed: bool,
+    /// Timestamp when the proposal was created
+    pub created_at: i64,
+    /// Timestamp when the proposal was executed, 0 until then
+    pub executed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AdminProposal {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        8 + // index
+        32 + // proposer
+        AdminAction::LEN + // action
+        2 + // approvals
+        1 + // executed
+        8 + // created_at
+        8 + // executed_at
+        1; // bump
+
+    /// Record the approval of the signer at `signer_index`
+    pub fn approve(&mut self, signer_index: usize) -> Result<()> {
+        require!(!self.executed, SolSocialError::ProposalAlreadyExecuted);
+        let bit = 1u16 << signer_index;
+        require!(self.approvals & bit == 0, SolSocialError::AlreadyApproved);
+        self.approvals |= bit;
+        Ok(())
+    }
+
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+
+    /// Mark the proposal executed once it has `threshold` approvals
+    pub fn mark_executed(&mut self, threshold: u8, now: i64) -> Result<()> {
+        require!(!self.executed, SolSocialError::ProposalAlreadyExecuted);
+        require!(
+            self.approval_count() >= threshold as u32,
+            SolSocialError::InsufficientMultisigApprovals
+        );
+        self.executed = true;
+        self.executed_at = now;
+        Ok(())
+    }
+}
+
+// Seeds for PDA derivation
+pub const ADMIN_MULTISIG_SEED: &[u8] = b"admin_multisig";
+pub const ADMIN_PROPOSAL_SEED: &[u8] = b"admin_proposal";
+
+pub fn get_admin_multisig_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ADMIN_MULTISIG_SEED], program_id)
+}
+
+pub fn get_admin_proposal_pda(multisig: &Pubkey, index: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ADMIN_PROPOSAL_SEED, multisig.as_ref(), &index.to_le_bytes()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::MilestoneTable;
+
+    fn multisig(signers: &[Pubkey], threshold: u8) -> AdminMultisig {
+        let mut multisig = AdminMultisig {
+            signers: Vec::new(),
+            threshold: 0,
+            proposal_count: 0,
+            created_at: 0,
+            bump: 0,
+        };
+        multisig.initialize(signers.to_vec(), threshold, 0, 255).unwrap();
+        multisig
+    }
+
+    fn proposal() -> AdminProposal {
+        AdminProposal {
+            multisig: Pubkey::new_unique(),
+            index: 0,
+            proposer: Pubkey::new_unique(),
+            action: AdminAction::SetFeatureFlags(FeatureFlags { bits: FeatureFlags::EMERGENCY_STOP }),
+            approvals: 0,
+            executed: false,
+            created_at: 0,
+            executed_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_signer_set_validation() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let mut set = multisig(&[a, b], 2);
+
+        assert!(set.initialize(vec![], 1, 0, 255).is_err());
+        assert!(set.initialize(vec![a, b], 3, 0, 255).is_err());
+        assert!(set.initialize(vec![a, b], 0, 0, 255).is_err());
+        assert!(set.initialize(vec![a, a], 1, 0, 255).is_err());
+        assert!(set.initialize(vec![a, Pubkey::default()], 1, 0, 255).is_err());
+        assert!(set
+            .initialize(vec![Pubkey::new_unique(); AdminMultisig::MAX_SIGNERS + 1], 1, 0, 255)
+            .is_err());
+
+        assert_eq!(set.signer_index(&b).unwrap(), 1);
+        assert!(set.signer_index(&Pubkey::new_unique()).is_err());
+    }
+
+    #[test]
+    fn test_proposal_needs_threshold_distinct_approvals() {
+        let mut proposal = proposal();
+        proposal.approve(0).unwrap();
+        assert!(proposal.approve(0).is_err());
+        assert!(proposal.mark_executed(2, 10).is_err());
+
+        proposal.approve(3).unwrap();
+        assert_eq!(proposal.approval_count(), 2);
+        proposal.mark_executed(2, 10).unwrap();
+        assert_eq!(proposal.executed_at, 10);
+
+        // Executed proposals can't be approved or run again
+        assert!(proposal.approve(1).is_err());
+        assert!(proposal.mark_executed(2, 11).is_err());
+    }
+
+    #[test]
+    fn test_action_len_covers_largest_action() {
+        let action = AdminAction::UpdateConfig(ConfigUpdate {
+            max_media_bytes_per_user: Some(u64::MAX),
+            reward_pool_bps: Some(u16::MAX),
+            grace_drop_bps: Some(u16::MAX),
+            grace_window_seconds: Some(i64::MAX),
+            content_dedup_window_seconds: Some(i64::MAX),
+            sell_queue_threshold: Some(u64::MAX),
+            max_fee_bps: Some(u16::MAX),
+            max_keys_per_trade: Some(u64::MAX),
+            default_base_price: Some(u64::MAX),
+            milestones: Some(MilestoneTable::standard()),
+            timelock_seconds: Some(i64::MAX),
+            circuit_breaker_bps: Some(u16::MAX),
+            circuit_breaker_window_seconds: Some(i64::MAX),
+            graduation_market_cap: Some(u64::MAX),
+            max_price_impact_bps: Some(u16::MAX),
+            early_supporter_bps: Some(u16::MAX),
+        });
+        assert_eq!(action.try_to_vec().unwrap().len(), AdminAction::LEN);
+    }
+}
+```
\ No newline at end of file
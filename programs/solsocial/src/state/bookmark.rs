@@ -0,0 +1,93 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// A post a user saved, one per (user, post)
+#[account]
+pub struct Bookmark {
+    pub user: Pubkey,
+    pub post: Pubkey,
+    /// Collection the bookmark is filed under, if any
+    pub collection: Option<Pubkey>,
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Bookmark {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // user
+        32 + // post
+        1 + 32 + // collection
+        8 + // created_at
+        1; // bump
+}
+
+/// A user's named folder of bookmarks
+#[account]
+pub struct Collection {
+    pub owner: Pubkey,
+    /// Unique per owner; doubles as a PDA seed
+    pub name: String,
+    /// Bookmarks currently filed under the collection
+    pub bookmark_count: u64,
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Collection {
+    /// Names double as PDA seeds, which cap out at 32 bytes
+    pub const MAX_NAME_LENGTH: usize = 32;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        4 + Self::MAX_NAME_LENGTH + // name
+        8 + // bookmark_count
+        8 + // created_at
+        1; // bump
+
+    pub fn validate_name(name: &str) -> Result<()> {
+        require!(
+            !name.trim().is_empty() && name.len() <= Self::MAX_NAME_LENGTH,
+            crate::error::SolSocialError::InvalidCollectionName
+        );
+        Ok(())
+    }
+
+    pub fn add_bookmark(&mut self) -> Result<()> {
+        self.bookmark_count = self.bookmark_count
+            .checked_add(1)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn remove_bookmark(&mut self) {
+        self.bookmark_count = self.bookmark_count.saturating_sub(1);
+    }
+}
+
+// Seeds for PDA derivation
+pub const BOOKMARK_SEED: &[u8] = b"bookmark";
+pub const COLLECTION_SEED: &[u8] = b"collection";
+
+pub fn get_bookmark_pda(user: &Pubkey, post: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BOOKMARK_SEED, user.as_ref(), post.as_ref()], program_id)
+}
+
+pub fn get_collection_pda(owner: &Pubkey, name: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLECTION_SEED, owner.as_ref(), name.as_bytes()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collection_name_fits_seed() {
+        assert!(Collection::validate_name("reading list").is_ok());
+        assert!(Collection::validate_name(&"a".repeat(Collection::MAX_NAME_LENGTH)).is_ok());
+        assert!(Collection::validate_name(&"a".repeat(Collection::MAX_NAME_LENGTH + 1)).is_err());
+        assert!(Collection::validate_name("  ").is_err());
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,74 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// Paid promotion of a post, one per (post, booster) at a time. Feed ranking services
+/// honor boosts between `started_at` and `ends_at`.
+#[account]
+pub struct Boost {
+    pub post: Pubkey,
+    /// Author of the boosted post
+    pub author: Pubkey,
+    /// Wallet that paid for the boost and gets the rent back when it ends
+    pub booster: Pubkey,
+    /// Lamports paid for the boost
+    pub amount: u64,
+    /// Part of `amount` sent to the protocol treasury
+    pub treasury_amount: u64,
+    /// Part of `amount` deposited in the reward pool for curators
+    pub curator_amount: u64,
+    pub started_at: i64,
+    pub ends_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Boost {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // post
+        32 + // author
+        32 + // booster
+        8 + // amount
+        8 + // treasury_amount
+        8 + // curator_amount
+        8 + // started_at
+        8 + // ends_at
+        1; // bump
+
+    pub const MIN_DURATION_SECONDS: i64 = 60 * 60;
+    pub const MAX_DURATION_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    /// Share of each boost payment that goes to the curator reward pool (50%)
+    pub const CURATOR_SHARE_BPS: u64 = 5_000;
+
+    /// Split a boost payment into its (treasury, curator) parts
+    pub fn split(amount: u64) -> Option<(u64, u64)> {
+        let curator_amount = (amount as u128)
+            .checked_mul(Self::CURATOR_SHARE_BPS as u128)?
+            .checked_div(10_000)? as u64;
+        Some((amount.checked_sub(curator_amount)?, curator_amount))
+    }
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.ends_at
+    }
+}
+
+// Seeds for PDA derivation
+pub const BOOST_SEED: &[u8] = b"boost";
+
+pub fn get_boost_pda(post: &Pubkey, booster: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BOOST_SEED, post.as_ref(), booster.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_sends_remainder_to_treasury() {
+        assert_eq!(Boost::split(1_000_000), Some((500_000, 500_000)));
+        assert_eq!(Boost::split(3), Some((2, 1)));
+        assert_eq!(Boost::split(0), Some((0, 0)));
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,108 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// A hidden buy: only the hash of its size is public until the reveal
+#[account]
+pub struct BuyCommitment {
+    /// Wallet that committed and will receive the keys
+    pub buyer: Pubkey,
+    /// The `UserKeys` market being bought into
+    pub subject_keys: Pubkey,
+    /// `hash(amount, nonce, buyer)`, see [`BuyCommitment::compute`]
+    pub commitment: [u8; 32],
+    /// Lamports escrowed to cover the buy, fees included
+    pub escrowed_lamports: u64,
+    /// Slot the commitment landed in; the reveal must come later
+    pub committed_slot: u64,
+    /// Last slot a reveal is accepted in; afterwards the escrow can only be refunded
+    pub reveal_deadline_slot: u64,
+    /// Timestamp when the commitment was made
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl BuyCommitment {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // buyer
+        32 + // subject_keys
+        32 + // commitment
+        8 + // escrowed_lamports
+        8 + // committed_slot
+        8 + // reveal_deadline_slot
+        8 + // created_at
+        1; // bump
+
+    /// Slots a buyer has to reveal before the commitment can only be refunded (~2 minutes)
+    pub const REVEAL_WINDOW_SLOTS: u64 = 300;
+
+    /// Commitment over the buy size, a buyer-chosen nonce and the buyer's wallet
+    pub fn compute(amount: u64, nonce: u64, buyer: &Pubkey) -> [u8; 32] {
+        hashv(&[&amount.to_le_bytes(), &nonce.to_le_bytes(), buyer.as_ref()]).to_bytes()
+    }
+
+    pub fn matches(&self, amount: u64, nonce: u64) -> bool {
+        Self::compute(amount, nonce, &self.buyer) == self.commitment
+    }
+
+    /// Reveals must land after the commit slot so the buy can't be sandwiched in one block
+    pub fn can_reveal(&self, slot: u64) -> bool {
+        slot > self.committed_slot && slot <= self.reveal_deadline_slot
+    }
+
+    pub fn is_expired(&self, slot: u64) -> bool {
+        slot > self.reveal_deadline_slot
+    }
+}
+
+// Seeds for PDA derivation
+pub const BUY_COMMITMENT_SEED: &[u8] = b"buy_commitment";
+
+pub fn get_buy_commitment_pda(buyer: &Pubkey, subject_keys: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BUY_COMMITMENT_SEED, buyer.as_ref(), subject_keys.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commitment(amount: u64, nonce: u64, buyer: Pubkey) -> BuyCommitment {
+        BuyCommitment {
+            buyer,
+            subject_keys: Pubkey::new_unique(),
+            commitment: BuyCommitment::compute(amount, nonce, &buyer),
+            escrowed_lamports: 0,
+            committed_slot: 100,
+            reveal_deadline_slot: 100 + BuyCommitment::REVEAL_WINDOW_SLOTS,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_reveal_must_match_commitment() {
+        let buyer = Pubkey::new_unique();
+        let commit = commitment(5, 42, buyer);
+
+        assert!(commit.matches(5, 42));
+        assert!(!commit.matches(6, 42));
+        assert!(!commit.matches(5, 43));
+
+        // Bound to the buyer: someone else's identical commitment hashes differently
+        let other = commitment(5, 42, Pubkey::new_unique());
+        assert_ne!(commit.commitment, other.commitment);
+    }
+
+    #[test]
+    fn test_reveal_window() {
+        let commit = commitment(1, 0, Pubkey::new_unique());
+
+        assert!(!commit.can_reveal(100));
+        assert!(commit.can_reveal(101));
+        assert!(commit.can_reveal(100 + BuyCommitment::REVEAL_WINDOW_SLOTS));
+        assert!(!commit.can_reveal(101 + BuyCommitment::REVEAL_WINDOW_SLOTS));
+        assert!(commit.is_expired(101 + BuyCommitment::REVEAL_WINDOW_SLOTS));
+    }
+}
+```
\ No newline at end of file
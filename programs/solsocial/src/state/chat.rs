@@ -1,5 +1,6 @@
 ```rust
 use anchor_lang::prelude::*;
+use crate::state::{MAX_CHAT_DESCRIPTION_LENGTH, MAX_CHAT_ID_LENGTH, MAX_CHAT_NAME_LENGTH};
 
 #[account]
 pub struct ChatRoom {
@@ -82,6 +83,71 @@ impl ChatRoom {
     }
 }
 
+#[account]
+pub struct GroupChatRoom {
+    pub chat_id: String,
+    pub name: String,
+    pub description: String,
+    pub creator: Pubkey,
+    pub is_private: bool,
+    pub max_participants: u32,
+    pub participant_count: u32,
+    pub message_count: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub last_message_at: i64,
+    pub last_message_sender: Pubkey,
+    pub is_active: bool,
+    pub auto_mute_new: bool,
+    /// Set at creation for two-seat conversations gated by the recipient's `ChatSettings`
+    pub is_direct: bool,
+    /// Address of the message admins pinned to the top of the room
+    pub pinned_message: Option<[u8; 32]>,
+    pub bump: u8,
+}
+
+impl GroupChatRoom {
+    pub const LEN: usize = 8 + // discriminator
+        4 + MAX_CHAT_ID_LENGTH + // chat_id
+        4 + MAX_CHAT_NAME_LENGTH + // name
+        4 + MAX_CHAT_DESCRIPTION_LENGTH + // description
+        32 + // creator
+        1 + // is_private
+        4 + // max_participants
+        4 + // participant_count
+        8 + // message_count
+        8 + // created_at
+        8 + // updated_at
+        8 + // last_message_at
+        32 + // last_message_sender
+        1 + // is_active
+        1 + // auto_mute_new
+        1 + // is_direct
+        1 + 32 + // pinned_message (Option<[u8; 32]>)
+        1; // bump
+
+    /// Reject metadata that would not fit in `LEN`
+    pub fn validate_metadata(chat_id: &str, name: &str, description: &str) -> Result<()> {
+        require!(chat_id.len() <= MAX_CHAT_ID_LENGTH, crate::error::SolSocialError::ChatIdTooLong);
+        require!(name.len() <= MAX_CHAT_NAME_LENGTH, crate::error::SolSocialError::ChatNameTooLong);
+        require!(
+            description.len() <= MAX_CHAT_DESCRIPTION_LENGTH,
+            crate::error::SolSocialError::ChatDescriptionTooLong
+        );
+        Ok(())
+    }
+
+    pub fn add_participant(&mut self) -> Result<()> {
+        require!(
+            self.participant_count < self.max_participants,
+            crate::error::SolSocialError::ChatRoomFull
+        );
+        self.participant_count = self.participant_count.checked_add(1).unwrap();
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
+
 #[account]
 pub struct ChatMessage {
     pub message_id: [u8; 32],
@@ -100,13 +166,17 @@ pub struct ChatMessage {
 
 impl ChatMessage {
     pub const MAX_CONTENT_LENGTH: usize = 500;
-    
-    pub const LEN: usize = 8 + // discriminator
+
+    /// Content left behind by `delete`; every message reserves at least this much
+    pub const DELETED_CONTENT: &'static str = "[deleted]";
+
+    /// Space used by everything but the content bytes
+    pub const FIXED_LEN: usize = 8 + // discriminator
         32 + // message_id
         32 + // room_id
         32 + // sender
         32 + // recipient
-        4 + Self::MAX_CONTENT_LENGTH + // content (String)
+        4 + // content (String prefix)
         8 + // timestamp
         1 + // message_type
         1 + // is_encrypted
@@ -115,6 +185,14 @@ impl ChatMessage {
         1 + // is_deleted
         1; // bump
 
+    /// Space for a max-length message
+    pub const LEN: usize = Self::FIXED_LEN + Self::MAX_CONTENT_LENGTH;
+
+    /// Space for a message with `content_len` bytes of content, so short messages pay less rent
+    pub fn space_for(content_len: usize) -> usize {
+        Self::FIXED_LEN + content_len.max(Self::DELETED_CONTENT.len())
+    }
+
     pub fn initialize(
         &mut self,
         message_id: [u8; 32],
@@ -147,9 +225,11 @@ impl ChatMessage {
         Ok(())
     }
 
-    pub fn edit_content(&mut self, new_content: String) -> Result<()> {
+    /// Replace the content; `capacity` is the account's allocated size, which edits can't outgrow
+    pub fn edit_content(&mut self, new_content: String, capacity: usize) -> Result<()> {
         require!(!self.is_deleted, crate::error::SolSocialError::MessageDeleted);
         require!(new_content.len() <= Self::MAX_CONTENT_LENGTH, crate::error::SolSocialError::MessageTooLong);
+        require!(Self::space_for(new_content.len()) <= capacity, crate::error::SolSocialError::MessageTooLong);
         
         let clock = Clock::get()?;
         self.content = new_content;
@@ -160,7 +240,7 @@ impl ChatMessage {
 
     pub fn delete(&mut self) -> Result<()> {
         self.is_deleted = true;
-        self.content = String::from("[deleted]");
+        self.content = String::from(Self::DELETED_CONTENT);
         Ok(())
     }
 
@@ -193,6 +273,7 @@ pub struct ChatParticipant {
     pub last_read_at: i64,
     pub is_muted: bool,
     pub is_blocked: bool,
+    pub is_admin: bool,
     pub message_count: u64,
     pub bump: u8,
 }
@@ -205,6 +286,7 @@ impl ChatParticipant {
         8 + // last_read_at
         1 + // is_muted
         1 + // is_blocked
+        1 + // is_admin
         8 + // message_count
         1; // bump
 
@@ -222,6 +304,7 @@ impl ChatParticipant {
         self.last_read_at = clock.unix_timestamp;
         self.is_muted = false;
         self.is_blocked = false;
+        self.is_admin = false;
         self.message_count = 0;
         self.bump = bump;
 
@@ -259,10 +342,15 @@ pub struct ChatSettings {
     pub auto_accept_from_holders: bool,
     pub notification_enabled: bool,
     pub encryption_enabled: bool,
+    pub min_sender_reputation: u64,
+    /// Canned reply surfaced to non-holders on their first message
+    pub auto_reply: Option<String>,
     pub bump: u8,
 }
 
 impl ChatSettings {
+    pub const MAX_AUTO_REPLY_LENGTH: usize = 280;
+
     pub const LEN: usize = 8 + // discriminator
         32 + // user
         1 + // allow_messages_from_strangers
@@ -271,8 +359,26 @@ impl ChatSettings {
         1 + // auto_accept_from_holders
         1 + // notification_enabled
         1 + // encryption_enabled
+        8 + // min_sender_reputation
+        1 + 4 + Self::MAX_AUTO_REPLY_LENGTH + // auto_reply
         1; // bump
 
+    /// Settings a user has until they save their own: anyone may DM them, as before settings existed
+    pub fn default_for(user: Pubkey) -> Self {
+        Self {
+            user,
+            allow_messages_from_strangers: true,
+            require_keys_for_dm: false,
+            min_keys_required: 1,
+            auto_accept_from_holders: true,
+            notification_enabled: true,
+            encryption_enabled: false,
+            min_sender_reputation: 0,
+            auto_reply: None,
+            bump: 0,
+        }
+    }
+
     pub fn initialize(
         &mut self,
         user: Pubkey,
@@ -285,11 +391,14 @@ impl ChatSettings {
         self.auto_accept_from_holders = true;
         self.notification_enabled = true;
         self.encryption_enabled = false;
+        self.min_sender_reputation = 0;
+        self.auto_reply = None;
         self.bump = bump;
 
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_settings(
         &mut self,
         allow_messages_from_strangers: Option<bool>,
@@ -298,6 +407,8 @@ impl ChatSettings {
         auto_accept_from_holders: Option<bool>,
         notification_enabled: Option<bool>,
         encryption_enabled: Option<bool>,
+        min_sender_reputation: Option<u64>,
+        auto_reply: Option<String>,
     ) -> Result<()> {
         if let Some(allow) = allow_messages_from_strangers {
             self.allow_messages_from_strangers = allow;
@@ -317,13 +428,31 @@ impl ChatSettings {
         if let Some(encryption) = encryption_enabled {
             self.encryption_enabled = encryption;
         }
+        if let Some(min_reputation) = min_sender_reputation {
+            self.min_sender_reputation = min_reputation;
+        }
+        // An empty reply turns the auto-responder off
+        if let Some(reply) = auto_reply {
+            require!(
+                reply.len() <= Self::MAX_AUTO_REPLY_LENGTH,
+                crate::error::SolSocialError::MessageTooLong
+            );
+            self.auto_reply = if reply.is_empty() { None } else { Some(reply) };
+        }
 
         Ok(())
     }
 
-    pub fn can_receive_message_from(&self, sender_keys_held: u64, is_key_holder: bool) -> bool {
+    pub fn can_receive_message_from(
+        &self,
+        sender_keys_held: u64,
+        is_key_holder: bool,
+        sender_reputation: u64,
+    ) -> bool {
         if !self.require_keys_for_dm {
-            return self.allow_messages_from_strangers;
+            // Strangers are only let through when they meet the reputation bar
+            return self.allow_messages_from_strangers
+                && (is_key_holder || sender_reputation >= self.min_sender_reputation);
         }
 
         if is_key_holder && self.auto_accept_from_holders {
@@ -332,6 +461,14 @@ impl ChatSettings {
 
         sender_keys_held >= self.min_keys_required
     }
+
+    /// Auto-reply owed to a sender, only on a non-holder's first message
+    pub fn auto_reply_for(&self, is_key_holder: bool, is_first_message: bool) -> Option<&String> {
+        if is_key_holder || !is_first_message {
+            return None;
+        }
+        self.auto_reply.as_ref()
+    }
 }
 
 pub fn generate_room_id(creator: &Pubkey, participant: &Pubkey) -> [u8; 32] {
@@ -356,4 +493,148 @@ pub fn generate_message_id(room_id: &[u8; 32], sender: &Pubkey, timestamp: i64)
     
     hash(&data).to_bytes()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_chat_metadata_boundary_fits_account() {
+        let chat_id = "c".repeat(MAX_CHAT_ID_LENGTH);
+        let name = "n".repeat(MAX_CHAT_NAME_LENGTH);
+        let description = "d".repeat(MAX_CHAT_DESCRIPTION_LENGTH);
+
+        assert!(GroupChatRoom::validate_metadata(&chat_id, &name, &description).is_ok());
+        assert!(GroupChatRoom::validate_metadata(&chat_id, &name, &"d".repeat(MAX_CHAT_DESCRIPTION_LENGTH + 1)).is_err());
+        assert!(GroupChatRoom::validate_metadata(&chat_id, &"n".repeat(MAX_CHAT_NAME_LENGTH + 1), &description).is_err());
+
+        let room = GroupChatRoom {
+            chat_id,
+            name,
+            description,
+            creator: Pubkey::new_unique(),
+            is_private: false,
+            max_participants: 1000,
+            participant_count: 1,
+            message_count: 0,
+            created_at: 0,
+            updated_at: 0,
+            last_message_at: 0,
+            last_message_sender: Pubkey::default(),
+            is_active: true,
+            auto_mute_new: false,
+            is_direct: false,
+            pinned_message: Some([7; 32]),
+            bump: 255,
+        };
+        let serialized = room.try_to_vec().unwrap();
+        assert!(8 + serialized.len() <= GroupChatRoom::LEN);
+    }
+
+    fn open_settings(min_sender_reputation: u64) -> ChatSettings {
+        ChatSettings {
+            user: Pubkey::new_unique(),
+            allow_messages_from_strangers: true,
+            require_keys_for_dm: false,
+            min_keys_required: 1,
+            auto_accept_from_holders: true,
+            notification_enabled: true,
+            encryption_enabled: false,
+            min_sender_reputation,
+            auto_reply: None,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_low_reputation_stranger_rejected() {
+        let settings = open_settings(200);
+        assert!(!settings.can_receive_message_from(0, false, 100));
+    }
+
+    #[test]
+    fn test_high_reputation_stranger_allowed() {
+        let settings = open_settings(200);
+        assert!(settings.can_receive_message_from(0, false, 250));
+    }
+
+    #[test]
+    fn test_default_threshold_allows_any_stranger() {
+        let settings = open_settings(0);
+        assert!(settings.can_receive_message_from(0, false, 1));
+    }
+
+    #[test]
+    fn test_key_holder_bypasses_reputation() {
+        let settings = open_settings(200);
+        assert!(settings.can_receive_message_from(1, true, 1));
+    }
+
+    #[test]
+    fn test_default_settings_admit_anyone() {
+        // A recipient who never saved settings keeps the open default, with no reputation bar
+        let settings = ChatSettings::default_for(Pubkey::new_unique());
+        assert_eq!(settings.min_sender_reputation, 0);
+        assert!(settings.can_receive_message_from(0, false, 0));
+        assert!(settings.can_receive_message_from(1, true, 0));
+    }
+
+    #[test]
+    fn test_auto_reply_only_for_first_non_holder_message() {
+        let mut settings = open_settings(0);
+        assert!(settings.auto_reply_for(false, true).is_none());
+
+        settings
+            .update_settings(None, None, None, None, None, None, None, Some(String::from("Thanks!")))
+            .unwrap();
+        assert_eq!(settings.auto_reply_for(false, true).map(String::as_str), Some("Thanks!"));
+        assert!(settings.auto_reply_for(true, true).is_none());
+        assert!(settings.auto_reply_for(false, false).is_none());
+
+        let too_long = "r".repeat(ChatSettings::MAX_AUTO_REPLY_LENGTH + 1);
+        assert!(settings
+            .update_settings(None, None, None, None, None, None, None, Some(too_long))
+            .is_err());
+
+        settings
+            .update_settings(None, None, None, None, None, None, None, Some(String::new()))
+            .unwrap();
+        assert!(settings.auto_reply.is_none());
+    }
+
+    fn message_with(content: &str) -> ChatMessage {
+        ChatMessage {
+            message_id: [1; 32],
+            room_id: [2; 32],
+            sender: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+            content: content.to_string(),
+            timestamp: 0,
+            message_type: MessageType::Text,
+            is_encrypted: false,
+            reply_to: Some([3; 32]),
+            edited_at: Some(0),
+            is_deleted: false,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_message_space_scales_with_content() {
+        let short = ChatMessage::space_for("gm".len());
+        let max = ChatMessage::space_for(ChatMessage::MAX_CONTENT_LENGTH);
+        assert!(short < max);
+        assert_eq!(max, ChatMessage::LEN);
+
+        for content in ["gm".to_string(), "x".repeat(ChatMessage::MAX_CONTENT_LENGTH)] {
+            let serialized = message_with(&content).try_to_vec().unwrap();
+            assert!(8 + serialized.len() <= ChatMessage::space_for(content.len()));
+        }
+
+        // A short message still has room to be tombstoned
+        let mut message = message_with("gm");
+        message.delete().unwrap();
+        assert!(8 + message.try_to_vec().unwrap().len() <= short);
+    }
+}
 ```
\ No newline at end of file
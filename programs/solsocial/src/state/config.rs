@@ -0,0 +1,543 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::utils::revenue_share::{calculate_fee, validate_fee_parameters, MAX_TOTAL_FEE_BPS, REFERRER_FEE_BPS};
+use super::BONDING_CURVE_BASE_PRICE;
+
+#[account]
+pub struct ProtocolConfig {
+    /// Authority allowed to manage protocol-wide settings
+    pub admin: Pubkey,
+    /// Maximum total declared media bytes a single user may attach across posts (0 = unlimited)
+    pub max_media_bytes_per_user: u64,
+    /// Share of every protocol fee diverted into the reward pool, in basis points
+    pub reward_pool_bps: u16,
+    /// Drop from the recent peak price that opens a fee-free sell window (0 = disabled)
+    pub grace_drop_bps: u16,
+    /// Length of a fee-free sell window in seconds
+    pub grace_window_seconds: i64,
+    /// How long an author may not repost identical content, in seconds (0 = dedup disabled)
+    pub content_dedup_window_seconds: i64,
+    /// Sells larger than this many keys must go through the sell queue (0 = no limit)
+    pub sell_queue_threshold: u64,
+    /// Timestamp when the config was created
+    pub created_at: i64,
+    /// Timestamp when the config was last updated
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Authority allowed to withdraw collected protocol fees
+    pub treasury_authority: Pubkey,
+    /// Cap on the fees one side of a trade may charge, referrer included, in basis points
+    pub max_fee_bps: u16,
+    /// Most keys a single buy or sell may move (0 = unlimited)
+    pub max_keys_per_trade: u64,
+    /// Starting price of preset curves, in lamports
+    pub default_base_price: u64,
+    /// Supply thresholds that credit a creator's earnings with a bonus
+    pub milestones: MilestoneTable,
+    /// Subsystems the admin has halted
+    pub feature_flags: FeatureFlags,
+    /// Delay between queueing a config change and executing it, in seconds
+    pub timelock_seconds: i64,
+    /// Config changes queued so far; the next pending change's index
+    pub pending_change_count: u64,
+    /// Price move within `circuit_breaker_window_seconds` that halts a market, in basis points (0 = disabled)
+    pub circuit_breaker_bps: u16,
+    /// How far back the circuit breaker compares prices, in seconds
+    pub circuit_breaker_window_seconds: i64,
+    /// Market cap, in lamports, at which a market may graduate to an AMM pool (0 = disabled)
+    pub graduation_market_cap: u64,
+    /// Price impact cap for trades that don't pass their own, in basis points (0 = uncapped)
+    pub max_price_impact_bps: u16,
+    /// Share of every creator fee paid to the market's early supporters, in basis points
+    pub early_supporter_bps: u16,
+    /// Reserved space for future upgrades
+    pub reserved: [u8; 4],
+}
+
+/// Subsystems the admin can halt during an incident. A set bit switches something off,
+/// so a zeroed bitfield leaves everything running.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct FeatureFlags {
+    pub bits: u32,
+}
+
+impl FeatureFlags {
+    pub const TRADING_DISABLED: u32 = 1 << 0;
+    pub const POSTING_DISABLED: u32 = 1 << 1;
+    pub const CHAT_DISABLED: u32 = 1 << 2;
+    /// Planned downtime: every guarded subsystem is halted
+    pub const MAINTENANCE_MODE: u32 = 1 << 3;
+    /// Incident response: every guarded subsystem is halted
+    pub const EMERGENCY_STOP: u32 = 1 << 4;
+
+    pub const ALL: u32 = Self::TRADING_DISABLED
+        | Self::POSTING_DISABLED
+        | Self::CHAT_DISABLED
+        | Self::MAINTENANCE_MODE
+        | Self::EMERGENCY_STOP;
+
+    pub fn is_valid(&self) -> bool {
+        self.bits & !Self::ALL == 0
+    }
+
+    /// Fail when `feature` is halted, reporting the broadest reason first
+    pub fn require_enabled(&self, feature: Feature) -> Result<()> {
+        require!(self.bits & Self::EMERGENCY_STOP == 0, crate::error::SolSocialError::EmergencyStop);
+        require!(self.bits & Self::MAINTENANCE_MODE == 0, crate::error::SolSocialError::MaintenanceMode);
+        let disabled = match feature {
+            Feature::Trading => Self::TRADING_DISABLED,
+            Feature::Posting => Self::POSTING_DISABLED,
+            Feature::Chat => Self::CHAT_DISABLED,
+        };
+        require!(self.bits & disabled == 0, crate::error::SolSocialError::FeatureDisabled);
+        Ok(())
+    }
+}
+
+/// Supply milestones and the bonus each credits to the creator, tracked per market by
+/// `UserKeys::milestones_reached`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct MilestoneTable {
+    /// Supply thresholds, strictly ascending; only the first `count` are used
+    pub thresholds: [u64; 8],
+    /// Bonus credited at the threshold with the same index
+    pub bonuses: [u64; 8],
+    pub count: u8,
+}
+
+impl MilestoneTable {
+    pub const MAX_MILESTONES: usize = 8;
+
+    pub const LEN: usize = 8 * Self::MAX_MILESTONES + // thresholds
+        8 * Self::MAX_MILESTONES + // bonuses
+        1; // count
+
+    /// The 100 and 1,000 key milestones markets started with
+    pub fn standard() -> Self {
+        let mut table = Self::default();
+        table.thresholds[..2].copy_from_slice(&[100, 1_000]);
+        table.bonuses[..2].copy_from_slice(&[
+            ProtocolConfig::DEFAULT_MILESTONE_100_BONUS,
+            ProtocolConfig::DEFAULT_MILESTONE_1000_BONUS,
+        ]);
+        table.count = 2;
+        table
+    }
+
+    /// Markets remember milestones by index, so thresholds stay in order and nonzero
+    pub fn is_valid(&self) -> bool {
+        let count = self.count as usize;
+        count <= Self::MAX_MILESTONES
+            && self.thresholds[..count].first().map_or(true, |&first| first > 0)
+            && self.thresholds[..count].windows(2).all(|pair| pair[0] < pair[1])
+    }
+}
+
+/// Subsystems guarded by `FeatureFlags`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Feature {
+    /// Market creation, buys, sells, orders and key transfers
+    Trading,
+    /// Creating posts and interacting with them
+    Posting,
+    /// Creating, joining and messaging in chats
+    Chat,
+}
+
+/// Settings changed by `update_config`; `None` leaves a setting as it is
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ConfigUpdate {
+    pub max_media_bytes_per_user: Option<u64>,
+    pub reward_pool_bps: Option<u16>,
+    pub grace_drop_bps: Option<u16>,
+    pub grace_window_seconds: Option<i64>,
+    pub content_dedup_window_seconds: Option<i64>,
+    pub sell_queue_threshold: Option<u64>,
+    pub max_fee_bps: Option<u16>,
+    pub max_keys_per_trade: Option<u64>,
+    pub default_base_price: Option<u64>,
+    pub milestones: Option<MilestoneTable>,
+    pub timelock_seconds: Option<i64>,
+    pub circuit_breaker_bps: Option<u16>,
+    pub circuit_breaker_window_seconds: Option<i64>,
+    pub graduation_market_cap: Option<u64>,
+    pub max_price_impact_bps: Option<u16>,
+    pub early_supporter_bps: Option<u16>,
+}
+
+impl ConfigUpdate {
+    pub const LEN: usize = 1 + 8 + // max_media_bytes_per_user
+        1 + 2 + // reward_pool_bps
+        1 + 2 + // grace_drop_bps
+        1 + 8 + // grace_window_seconds
+        1 + 8 + // content_dedup_window_seconds
+        1 + 8 + // sell_queue_threshold
+        1 + 2 + // max_fee_bps
+        1 + 8 + // max_keys_per_trade
+        1 + 8 + // default_base_price
+        1 + MilestoneTable::LEN + // milestones
+        1 + 8 + // timelock_seconds
+        1 + 2 + // circuit_breaker_bps
+        1 + 8 + // circuit_breaker_window_seconds
+        1 + 8 + // graduation_market_cap
+        1 + 2 + // max_price_impact_bps
+        1 + 2; // early_supporter_bps
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        8 + // max_media_bytes_per_user
+        2 + // reward_pool_bps
+        2 + // grace_drop_bps
+        8 + // grace_window_seconds
+        8 + // content_dedup_window_seconds
+        8 + // sell_queue_threshold
+        8 + // created_at
+        8 + // updated_at
+        1 + // bump
+        32 + // treasury_authority
+        2 + // max_fee_bps
+        8 + // max_keys_per_trade
+        8 + // default_base_price
+        MilestoneTable::LEN + // milestones
+        4 + // feature_flags
+        8 + // timelock_seconds
+        8 + // pending_change_count
+        2 + // circuit_breaker_bps
+        8 + // circuit_breaker_window_seconds
+        8 + // graduation_market_cap
+        2 + // max_price_impact_bps
+        2 + // early_supporter_bps
+        4; // reserved
+
+    pub const MAX_REWARD_POOL_BPS: u16 = 10_000;
+
+    /// Longest fee-free sell window a crash may open
+    pub const MAX_GRACE_WINDOW_SECONDS: i64 = 3 * 24 * 60 * 60;
+
+    pub const DEFAULT_MILESTONE_100_BONUS: u64 = 1_000_000; // 0.001 SOL
+    pub const DEFAULT_MILESTONE_1000_BONUS: u64 = 10_000_000; // 0.01 SOL
+
+    /// Longest delay a config change may be held for
+    pub const MAX_TIMELOCK_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    /// Longest span the circuit breaker looks back over
+    pub const MAX_CIRCUIT_BREAKER_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+    pub const DEFAULT_CIRCUIT_BREAKER_WINDOW_SECONDS: i64 = 60 * 60;
+
+    /// Trades moving the next key's price by more than half fail unless the trader opts out
+    pub const DEFAULT_MAX_PRICE_IMPACT_BPS: u16 = 5_000;
+
+    /// Early supporters share a tenth of each creator fee
+    pub const DEFAULT_EARLY_SUPPORTER_BPS: u16 = 1_000;
+
+    pub fn initialize(
+        &mut self,
+        admin: Pubkey,
+        max_media_bytes_per_user: u64,
+        reward_pool_bps: u16,
+        grace_drop_bps: u16,
+        grace_window_seconds: i64,
+        content_dedup_window_seconds: i64,
+        sell_queue_threshold: u64,
+        timelock_seconds: i64,
+        bump: u8,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        self.admin = admin;
+        self.max_media_bytes_per_user = max_media_bytes_per_user;
+        self.reward_pool_bps = reward_pool_bps;
+        self.grace_drop_bps = grace_drop_bps;
+        self.grace_window_seconds = grace_window_seconds;
+        self.content_dedup_window_seconds = content_dedup_window_seconds;
+        self.sell_queue_threshold = sell_queue_threshold;
+        self.created_at = clock.unix_timestamp;
+        self.updated_at = clock.unix_timestamp;
+        self.bump = bump;
+        self.treasury_authority = admin;
+        self.max_fee_bps = MAX_TOTAL_FEE_BPS;
+        self.max_keys_per_trade = 0;
+        self.default_base_price = BONDING_CURVE_BASE_PRICE;
+        self.milestones = MilestoneTable::standard();
+        self.feature_flags = FeatureFlags::default();
+        self.timelock_seconds = timelock_seconds;
+        self.pending_change_count = 0;
+        self.circuit_breaker_bps = 0;
+        self.circuit_breaker_window_seconds = Self::DEFAULT_CIRCUIT_BREAKER_WINDOW_SECONDS;
+        self.graduation_market_cap = 0;
+        self.max_price_impact_bps = Self::DEFAULT_MAX_PRICE_IMPACT_BPS;
+        self.early_supporter_bps = Self::DEFAULT_EARLY_SUPPORTER_BPS;
+        self.reserved = [0; 4];
+
+        self.validate()
+    }
+
+    /// Apply the settings present in `update`, rejecting the whole update if any is invalid
+    pub fn apply_update(&mut self, update: &ConfigUpdate, now: i64) -> Result<()> {
+        let mut updated = self.clone();
+        if let Some(value) = update.max_media_bytes_per_user {
+            updated.max_media_bytes_per_user = value;
+        }
+        if let Some(value) = update.reward_pool_bps {
+            updated.reward_pool_bps = value;
+        }
+        if let Some(value) = update.grace_drop_bps {
+            updated.grace_drop_bps = value;
+        }
+        if let Some(value) = update.grace_window_seconds {
+            updated.grace_window_seconds = value;
+        }
+        if let Some(value) = update.content_dedup_window_seconds {
+            updated.content_dedup_window_seconds = value;
+        }
+        if let Some(value) = update.sell_queue_threshold {
+            updated.sell_queue_threshold = value;
+        }
+        if let Some(value) = update.max_fee_bps {
+            updated.max_fee_bps = value;
+        }
+        if let Some(value) = update.max_keys_per_trade {
+            updated.max_keys_per_trade = value;
+        }
+        if let Some(value) = update.default_base_price {
+            updated.default_base_price = value;
+        }
+        if let Some(value) = update.milestones {
+            updated.milestones = value;
+        }
+        if let Some(value) = update.timelock_seconds {
+            updated.timelock_seconds = value;
+        }
+        if let Some(value) = update.circuit_breaker_bps {
+            updated.circuit_breaker_bps = value;
+        }
+        if let Some(value) = update.circuit_breaker_window_seconds {
+            updated.circuit_breaker_window_seconds = value;
+        }
+        if let Some(value) = update.graduation_market_cap {
+            updated.graduation_market_cap = value;
+        }
+        if let Some(value) = update.max_price_impact_bps {
+            updated.max_price_impact_bps = value;
+        }
+        if let Some(value) = update.early_supporter_bps {
+            updated.early_supporter_bps = value;
+        }
+        updated.validate()?;
+
+        updated.updated_at = now;
+        *self = updated;
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.reward_pool_bps <= Self::MAX_REWARD_POOL_BPS && self.early_supporter_bps <= 10_000,
+            crate::error::SolSocialError::InvalidFeePercentage
+        );
+        require!(
+            self.grace_drop_bps <= 10_000
+                && (0..=Self::MAX_GRACE_WINDOW_SECONDS).contains(&self.grace_window_seconds)
+                && self.content_dedup_window_seconds >= 0
+                && self.default_base_price > 0
+                && self.milestones.is_valid()
+                && (0..=Self::MAX_TIMELOCK_SECONDS).contains(&self.timelock_seconds)
+                && (0..=Self::MAX_CIRCUIT_BREAKER_WINDOW_SECONDS).contains(&self.circuit_breaker_window_seconds),
+            crate::error::SolSocialError::InvalidConfigValue
+        );
+        // The cap stays within the protocol maximum and must leave room for the referrer fee
+        require!(self.max_fee_bps <= MAX_TOTAL_FEE_BPS, crate::error::SolSocialError::InvalidFeeStructure);
+        validate_fee_parameters(0, 0, REFERRER_FEE_BPS, self.max_fee_bps)
+    }
+
+    /// Reserve the index for the next queued config change
+    pub fn next_pending_change_index(&mut self) -> Result<u64> {
+        let index = self.pending_change_count;
+        self.pending_change_count = index
+            .checked_add(1)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        Ok(index)
+    }
+
+    /// When a change queued at `now` becomes executable
+    pub fn timelock_eta(&self, now: i64) -> Result<i64> {
+        now.checked_add(self.timelock_seconds)
+            .ok_or_else(|| crate::error::SolSocialError::ArithmeticOverflow.into())
+    }
+
+    pub fn require_feature(&self, feature: Feature) -> Result<()> {
+        self.feature_flags.require_enabled(feature)
+    }
+
+    /// Whether a single trade of `amount` keys is within the configured size limit
+    pub fn allows_trade_size(&self, amount: u64) -> bool {
+        self.max_keys_per_trade == 0 || amount <= self.max_keys_per_trade
+    }
+
+    /// Whether a user already storing `current` media bytes may add `additional` more
+    pub fn media_within_cap(&self, current: u64, additional: u64) -> bool {
+        if self.max_media_bytes_per_user == 0 {
+            return true;
+        }
+
+        current
+            .checked_add(additional)
+            .map_or(false, |total| total <= self.max_media_bytes_per_user)
+    }
+
+    pub fn content_dedup_enabled(&self) -> bool {
+        self.content_dedup_window_seconds > 0
+    }
+
+    /// Whether a sell of `amount` keys may execute immediately instead of through the queue
+    pub fn allows_direct_sell(&self, amount: u64) -> bool {
+        self.sell_queue_threshold == 0 || amount <= self.sell_queue_threshold
+    }
+
+    /// Portion of a collected protocol fee that belongs to the reward pool
+    pub fn reward_pool_cut(&self, protocol_fee: u64) -> Result<u64> {
+        calculate_fee(protocol_fee, self.reward_pool_bps)
+    }
+
+    /// Portion of a collected creator fee offered to the market's early supporters
+    pub fn early_supporter_cut(&self, creator_fee: u64) -> Result<u64> {
+        calculate_fee(creator_fee, self.early_supporter_bps)
+    }
+}
+
+/// Marks a wallet as exempt from protocol trading fees; creator fees still apply
+#[account]
+pub struct FeeExemption {
+    /// The exempt trader wallet
+    pub account: Pubkey,
+    /// Admin that granted the exemption
+    pub granted_by: Pubkey,
+    /// Timestamp when the exemption was granted
+    pub granted_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl FeeExemption {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // account
+        32 + // granted_by
+        8 + // granted_at
+        1; // bump
+}
+
+// Seeds for PDA derivation
+pub const PROTOCOL_CONFIG_SEED: &[u8] = b"protocol_config";
+pub const FEE_EXEMPTION_SEED: &[u8] = b"fee_exemption";
+
+pub fn get_protocol_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROTOCOL_CONFIG_SEED], program_id)
+}
+
+pub fn get_fee_exemption_pda(account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_EXEMPTION_SEED, account.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ProtocolConfig {
+        ProtocolConfig {
+            admin: Pubkey::new_unique(),
+            max_media_bytes_per_user: 0,
+            reward_pool_bps: 2_000,
+            grace_drop_bps: 3_000,
+            grace_window_seconds: 3_600,
+            content_dedup_window_seconds: 0,
+            sell_queue_threshold: 0,
+            created_at: 0,
+            updated_at: 0,
+            bump: 255,
+            treasury_authority: Pubkey::new_unique(),
+            max_fee_bps: MAX_TOTAL_FEE_BPS,
+            max_keys_per_trade: 0,
+            default_base_price: BONDING_CURVE_BASE_PRICE,
+            milestones: MilestoneTable::standard(),
+            feature_flags: FeatureFlags::default(),
+            timelock_seconds: 86_400,
+            pending_change_count: 0,
+            circuit_breaker_bps: 0,
+            circuit_breaker_window_seconds: ProtocolConfig::DEFAULT_CIRCUIT_BREAKER_WINDOW_SECONDS,
+            graduation_market_cap: 0,
+            max_price_impact_bps: ProtocolConfig::DEFAULT_MAX_PRICE_IMPACT_BPS,
+            early_supporter_bps: ProtocolConfig::DEFAULT_EARLY_SUPPORTER_BPS,
+            reserved: [0; 4],
+        }
+    }
+
+    #[test]
+    fn test_apply_update_changes_only_given_settings() {
+        let mut config = config();
+        let update = ConfigUpdate {
+            max_keys_per_trade: Some(50),
+            milestones: Some(MilestoneTable::default()),
+            ..ConfigUpdate::default()
+        };
+        config.apply_update(&update, 1_700_000_000).unwrap();
+
+        assert_eq!(config.max_keys_per_trade, 50);
+        assert_eq!(config.milestones.count, 0);
+        assert_eq!(config.reward_pool_bps, 2_000);
+        assert_eq!(config.updated_at, 1_700_000_000);
+        assert!(config.allows_trade_size(50));
+        assert!(!config.allows_trade_size(51));
+    }
+
+    #[test]
+    fn test_apply_update_rejects_invalid_settings_atomically() {
+        let mut config = config();
+        let update = ConfigUpdate {
+            max_keys_per_trade: Some(50),
+            max_fee_bps: Some(MAX_TOTAL_FEE_BPS + 1),
+            ..ConfigUpdate::default()
+        };
+        assert!(config.apply_update(&update, 1).is_err());
+        assert_eq!(config.max_keys_per_trade, 0);
+
+        // The cap must still admit the referrer fee
+        let too_tight = ConfigUpdate { max_fee_bps: Some(REFERRER_FEE_BPS - 1), ..ConfigUpdate::default() };
+        assert!(config.apply_update(&too_tight, 1).is_err());
+
+        let free_keys = ConfigUpdate { default_base_price: Some(0), ..ConfigUpdate::default() };
+        assert!(config.apply_update(&free_keys, 1).is_err());
+
+        let endless = ConfigUpdate {
+            timelock_seconds: Some(ProtocolConfig::MAX_TIMELOCK_SECONDS + 1),
+            ..ConfigUpdate::default()
+        };
+        assert!(config.apply_update(&endless, 1).is_err());
+
+        let mut unordered = MilestoneTable::standard();
+        unordered.thresholds.swap(0, 1);
+        let unordered = ConfigUpdate { milestones: Some(unordered), ..ConfigUpdate::default() };
+        assert!(config.apply_update(&unordered, 1).is_err());
+    }
+
+    #[test]
+    fn test_feature_flags_halt_only_their_subsystem() {
+        let flags = FeatureFlags { bits: FeatureFlags::CHAT_DISABLED };
+        assert!(flags.require_enabled(Feature::Trading).is_ok());
+        assert!(flags.require_enabled(Feature::Posting).is_ok());
+        assert!(flags.require_enabled(Feature::Chat).is_err());
+
+        for global in [FeatureFlags::MAINTENANCE_MODE, FeatureFlags::EMERGENCY_STOP] {
+            let flags = FeatureFlags { bits: global };
+            assert!(flags.require_enabled(Feature::Trading).is_err());
+            assert!(flags.require_enabled(Feature::Posting).is_err());
+            assert!(flags.require_enabled(Feature::Chat).is_err());
+        }
+
+        assert!(FeatureFlags { bits: FeatureFlags::ALL }.is_valid());
+        assert!(!FeatureFlags { bits: 1 << 5 }.is_valid());
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,121 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use super::MediaAttachment;
+
+/// A post fingerprint remembered for dedup
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ContentHashEntry {
+    pub hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+impl ContentHashEntry {
+    pub const SPACE: usize = 32 + 8;
+}
+
+/// Fixed-size ring of an author's most recent post fingerprints
+#[account]
+pub struct RecentContentHashes {
+    /// The author whose posts are tracked
+    pub author: Pubkey,
+    pub entries: [ContentHashEntry; RecentContentHashes::CAPACITY],
+    /// Slot the next fingerprint is written to
+    pub head: u8,
+    /// Number of valid entries (saturates at CAPACITY)
+    pub count: u8,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl RecentContentHashes {
+    pub const CAPACITY: usize = 8;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // author
+        ContentHashEntry::SPACE * Self::CAPACITY + // entries
+        1 + // head
+        1 + // count
+        1; // bump
+
+    pub fn initialize(&mut self, author: Pubkey, bump: u8) {
+        self.author = author;
+        self.entries = [ContentHashEntry::default(); Self::CAPACITY];
+        self.head = 0;
+        self.count = 0;
+        self.bump = bump;
+    }
+
+    /// Whether `hash` was posted within the last `window` seconds
+    pub fn is_recent_duplicate(&self, hash: &[u8; 32], now: i64, window: i64) -> bool {
+        self.entries
+            .iter()
+            .take(self.count as usize)
+            .any(|entry| entry.hash == *hash && now.saturating_sub(entry.timestamp) < window)
+    }
+
+    pub fn record(&mut self, hash: [u8; 32], timestamp: i64) {
+        self.entries[self.head as usize] = ContentHashEntry { hash, timestamp };
+        self.head = ((self.head as usize + 1) % Self::CAPACITY) as u8;
+        if (self.count as usize) < Self::CAPACITY {
+            self.count += 1;
+        }
+    }
+}
+
+/// Fingerprint of a post's text and attached media URLs
+pub fn compute_content_hash(content: &str, media: &[MediaAttachment]) -> [u8; 32] {
+    let mut parts: Vec<&[u8]> = Vec::with_capacity(1 + media.len());
+    parts.push(content.as_bytes());
+    for attachment in media {
+        parts.push(attachment.url.as_bytes());
+    }
+    hashv(&parts).to_bytes()
+}
+
+// Seeds for PDA derivation
+pub const RECENT_CONTENT_HASHES_SEED: &[u8] = b"recent_hashes";
+
+pub fn get_recent_content_hashes_pda(author: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RECENT_CONTENT_HASHES_SEED, author.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_ring() -> RecentContentHashes {
+        RecentContentHashes {
+            author: Pubkey::new_unique(),
+            entries: [ContentHashEntry::default(); RecentContentHashes::CAPACITY],
+            head: 0,
+            count: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_duplicate_within_window() {
+        let mut ring = empty_ring();
+        let hash = compute_content_hash("gm", &[]);
+        ring.record(hash, 100);
+
+        assert!(ring.is_recent_duplicate(&hash, 150, 3_600));
+        assert!(!ring.is_recent_duplicate(&hash, 100 + 3_600, 3_600));
+        assert!(!ring.is_recent_duplicate(&compute_content_hash("gn", &[]), 150, 3_600));
+    }
+
+    #[test]
+    fn test_ring_forgets_oldest_hash() {
+        let mut ring = empty_ring();
+        let first = compute_content_hash("post 0", &[]);
+        ring.record(first, 0);
+        for i in 1..=RecentContentHashes::CAPACITY {
+            ring.record(compute_content_hash(&format!("post {}", i), &[]), i as i64);
+        }
+
+        assert_eq!(ring.count as usize, RecentContentHashes::CAPACITY);
+        assert!(!ring.is_recent_duplicate(&first, 10, 3_600));
+    }
+}
+```
\ No newline at end of file
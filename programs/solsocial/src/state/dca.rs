@@ -0,0 +1,182 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// A recurring buy of one market's keys, funded upfront. The lamports to spend sit in this
+/// account on top of its rent, and any keeper (or a Clockwork thread) cranks
+/// `execute_dca` once per interval until they run out.
+#[account]
+pub struct DcaSchedule {
+    /// The wallet buying, and receiving the keys
+    pub owner: Pubkey,
+    /// The user whose keys are bought
+    pub keys_user: Pubkey,
+    /// Most spent on keys per execution, before the keeper fee
+    pub lamports_per_interval: u64,
+    /// Seconds between executions
+    pub interval_seconds: i64,
+    /// Escrowed lamports left to spend, keeper fees included
+    pub remaining_lamports: u64,
+    /// Earliest time the next execution may run
+    pub next_execution_at: i64,
+    /// Executions run so far
+    pub executions: u64,
+    /// Keys bought so far
+    pub keys_bought: u64,
+    /// Timestamp when the schedule was created
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved space for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl DcaSchedule {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        32 + // keys_user
+        8 + // lamports_per_interval
+        8 + // interval_seconds
+        8 + // remaining_lamports
+        8 + // next_execution_at
+        8 + // executions
+        8 + // keys_bought
+        8 + // created_at
+        1 + // bump
+        32; // reserved
+
+    /// Lamports paid out of the escrow to the keeper for every execution
+    pub const KEEPER_FEE: u64 = 5_000;
+
+    /// Shortest interval a schedule may run at
+    pub const MIN_INTERVAL_SECONDS: i64 = 60;
+
+    /// Set up a schedule whose first execution is due immediately
+    pub fn initialize(
+        &mut self,
+        owner: Pubkey,
+        keys_user: Pubkey,
+        lamports_per_interval: u64,
+        interval_seconds: i64,
+        deposit: u64,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            lamports_per_interval > 0
+                && interval_seconds >= Self::MIN_INTERVAL_SECONDS
+                && deposit > Self::KEEPER_FEE,
+            crate::error::SolSocialError::InvalidDcaSchedule
+        );
+
+        self.owner = owner;
+        self.keys_user = keys_user;
+        self.lamports_per_interval = lamports_per_interval;
+        self.interval_seconds = interval_seconds;
+        self.remaining_lamports = deposit;
+        self.next_execution_at = now;
+        self.executions = 0;
+        self.keys_bought = 0;
+        self.created_at = now;
+        self.bump = bump;
+        Ok(())
+    }
+
+    pub fn is_due(&self, now: i64) -> bool {
+        now >= self.next_execution_at
+    }
+
+    /// Lamports the next execution may spend on keys, after setting aside the keeper fee
+    pub fn next_budget(&self) -> u64 {
+        std::cmp::min(
+            self.lamports_per_interval,
+            self.remaining_lamports.saturating_sub(Self::KEEPER_FEE),
+        )
+    }
+
+    /// Whether nothing is left to spend beyond another keeper fee
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining_lamports <= Self::KEEPER_FEE
+    }
+
+    /// Book an execution that spent `spent` on `keys` keys. Missed intervals are skipped
+    /// rather than run back to back; their lamports stay for later executions.
+    pub fn record_execution(&mut self, spent: u64, keys: u64, now: i64) -> Result<()> {
+        self.remaining_lamports = spent
+            .checked_add(Self::KEEPER_FEE)
+            .and_then(|total| self.remaining_lamports.checked_sub(total))
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        self.executions = self.executions.saturating_add(1);
+        self.keys_bought = self.keys_bought.saturating_add(keys);
+
+        let next = self.next_execution_at.saturating_add(self.interval_seconds);
+        self.next_execution_at = if next > now { next } else { now.saturating_add(self.interval_seconds) };
+        Ok(())
+    }
+}
+
+// Seeds for PDA derivation
+pub const DCA_SCHEDULE_SEED: &[u8] = b"dca_schedule";
+
+pub fn get_dca_schedule_pda(owner: &Pubkey, keys_user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DCA_SCHEDULE_SEED, owner.as_ref(), keys_user.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(deposit: u64) -> DcaSchedule {
+        let mut schedule = DcaSchedule {
+            owner: Pubkey::default(),
+            keys_user: Pubkey::default(),
+            lamports_per_interval: 0,
+            interval_seconds: 0,
+            remaining_lamports: 0,
+            next_execution_at: 0,
+            executions: 0,
+            keys_bought: 0,
+            created_at: 0,
+            bump: 0,
+            reserved: [0; 32],
+        };
+        schedule
+            .initialize(Pubkey::new_unique(), Pubkey::new_unique(), 100_000, 3_600, deposit, 1_000, 255)
+            .unwrap();
+        schedule
+    }
+
+    #[test]
+    fn test_executions_spend_down_to_exhaustion() {
+        let mut dca = schedule(2 * 100_000 + 50_000 + 3 * DcaSchedule::KEEPER_FEE);
+        assert!(dca.is_due(1_000));
+        assert_eq!(dca.next_budget(), 100_000);
+
+        dca.record_execution(99_000, 3, 1_000).unwrap();
+        assert!(!dca.is_due(4_599));
+        assert!(dca.is_due(4_600));
+
+        dca.record_execution(100_000, 2, 4_600).unwrap();
+        // The last interval gets what's left, dust from the first included
+        assert_eq!(dca.next_budget(), 51_000);
+        dca.record_execution(51_000, 1, 8_200).unwrap();
+        assert!(dca.is_exhausted());
+        assert_eq!(dca.executions, 3);
+        assert_eq!(dca.keys_bought, 6);
+    }
+
+    #[test]
+    fn test_missed_intervals_are_skipped() {
+        let mut dca = schedule(1_000_000);
+        dca.record_execution(100_000, 1, 50_000).unwrap();
+        assert_eq!(dca.next_execution_at, 53_600);
+    }
+
+    #[test]
+    fn test_rejects_unfundable_schedules() {
+        let mut dca = schedule(1_000_000);
+        assert!(dca.initialize(Pubkey::default(), Pubkey::default(), 0, 3_600, 1_000_000, 0, 255).is_err());
+        assert!(dca.initialize(Pubkey::default(), Pubkey::default(), 1, 59, 1_000_000, 0, 255).is_err());
+        assert!(dca.initialize(Pubkey::default(), Pubkey::default(), 1, 60, DcaSchedule::KEEPER_FEE, 0, 255).is_err());
+    }
+}
+```
\ No newline at end of file
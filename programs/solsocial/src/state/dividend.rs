@@ -0,0 +1,207 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::utils::revenue_share::calculate_holder_rewards;
+
+/// Where a dividend epoch's lamports come from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DividendSource {
+    /// The creator's unclaimed earnings, out of their `EarningsVault`
+    CreatorEarnings,
+    /// The protocol reward pool, at the admin's discretion
+    RewardPool,
+}
+
+/// A one-off payout to a market's holders, claimed pro-rata against the circulating
+/// supply at the moment it opened
+#[account]
+pub struct DividendEpoch {
+    /// Subject whose holders share the epoch
+    pub subject: Pubkey,
+    /// Wallet that opened the epoch and paid its rent
+    pub funder: Pubkey,
+    /// Per-market sequence number used in the PDA seeds
+    pub epoch_index: u64,
+    /// Where the lamports came from
+    pub source: DividendSource,
+    /// Lamports set aside for holders
+    pub total_amount: u64,
+    /// Lamports already claimed
+    pub total_claimed: u64,
+    /// Circulating supply when the epoch opened; every claim is a share of this
+    pub supply_snapshot: u64,
+    /// Keys bought at or after this timestamp don't qualify
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Checkpoint marking a holder as paid for one epoch; its existence blocks a second claim
+#[account]
+pub struct DividendClaim {
+    /// The epoch that was claimed
+    pub epoch: Pubkey,
+    /// The holder that claimed it
+    pub holder: Pubkey,
+    /// Keys the payout was computed on
+    pub keys_held: u64,
+    /// Lamports paid
+    pub amount: u64,
+    /// Timestamp of the claim
+    pub claimed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DividendEpoch {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // subject
+        32 + // funder
+        8 + // epoch_index
+        1 + // source
+        8 + // total_amount
+        8 + // total_claimed
+        8 + // supply_snapshot
+        8 + // created_at
+        1; // bump
+
+    /// How long holders have to claim before what's left can be swept back to the source
+    pub const CLAIM_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    pub fn initialize(
+        &mut self,
+        subject: Pubkey,
+        funder: Pubkey,
+        epoch_index: u64,
+        source: DividendSource,
+        total_amount: u64,
+        supply_snapshot: u64,
+        created_at: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(total_amount > 0, crate::error::SolSocialError::InvalidAmount);
+        require!(supply_snapshot > 0, crate::error::SolSocialError::NothingToDistribute);
+
+        self.subject = subject;
+        self.funder = funder;
+        self.epoch_index = epoch_index;
+        self.source = source;
+        self.total_amount = total_amount;
+        self.total_claimed = 0;
+        self.supply_snapshot = supply_snapshot;
+        self.created_at = created_at;
+        self.bump = bump;
+
+        Ok(())
+    }
+
+    /// Whether a holder whose last purchase was at `last_purchase_at` held their keys
+    /// before the epoch opened. Keys received after it, bought or transferred in, don't qualify.
+    pub fn is_eligible(&self, last_purchase_at: i64) -> bool {
+        last_purchase_at < self.created_at
+    }
+
+    /// Pay the share owed on `keys_held`, never more than the epoch has left
+    pub fn claim(&mut self, keys_held: u64) -> Result<u64> {
+        let payout = calculate_holder_rewards(keys_held, self.supply_snapshot, self.total_amount)?
+            .min(self.total_amount.saturating_sub(self.total_claimed));
+        require!(payout > 0, crate::error::SolSocialError::NothingToClaim);
+
+        self.total_claimed = self.total_claimed
+            .checked_add(payout)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        Ok(payout)
+    }
+
+    /// Lamports set aside that no holder has claimed
+    pub fn unclaimed(&self) -> u64 {
+        self.total_amount.saturating_sub(self.total_claimed)
+    }
+
+    /// Whether the claim window has closed, so the epoch can be swept
+    pub fn is_sweepable(&self, now: i64) -> bool {
+        now >= self.created_at.saturating_add(Self::CLAIM_WINDOW_SECONDS)
+    }
+}
+
+impl DividendClaim {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // epoch
+        32 + // holder
+        8 + // keys_held
+        8 + // amount
+        8 + // claimed_at
+        1; // bump
+}
+
+// Seeds for PDA derivation
+pub const DIVIDEND_EPOCH_SEED: &[u8] = b"dividend_epoch";
+pub const DIVIDEND_CLAIM_SEED: &[u8] = b"dividend_claim";
+
+pub fn get_dividend_epoch_pda(subject: &Pubkey, epoch_index: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DIVIDEND_EPOCH_SEED, subject.as_ref(), &epoch_index.to_le_bytes()], program_id)
+}
+
+pub fn get_dividend_claim_pda(epoch: &Pubkey, holder: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DIVIDEND_CLAIM_SEED, epoch.as_ref(), holder.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn epoch(total_amount: u64, supply_snapshot: u64) -> DividendEpoch {
+        let mut epoch = DividendEpoch {
+            subject: Pubkey::default(),
+            funder: Pubkey::default(),
+            epoch_index: 0,
+            source: DividendSource::CreatorEarnings,
+            total_amount: 0,
+            total_claimed: 0,
+            supply_snapshot: 0,
+            created_at: 0,
+            bump: 0,
+        };
+        epoch
+            .initialize(Pubkey::new_unique(), Pubkey::new_unique(), 0, DividendSource::CreatorEarnings, total_amount, supply_snapshot, 1_000, 255)
+            .unwrap();
+        epoch
+    }
+
+    #[test]
+    fn test_claims_are_pro_rata() {
+        let mut epoch = epoch(1_000_000, 10);
+        assert_eq!(epoch.claim(3).unwrap(), 300_000);
+        assert_eq!(epoch.claim(7).unwrap(), 700_000);
+        assert_eq!(epoch.total_claimed, 1_000_000);
+
+        // Nothing is left once the snapshot supply has been paid out
+        assert!(epoch.claim(1).is_err());
+    }
+
+    #[test]
+    fn test_unclaimed_remainder_is_swept_after_the_window() {
+        let mut epoch = epoch(1_000_000, 10);
+        epoch.claim(4).unwrap();
+        assert_eq!(epoch.unclaimed(), 600_000);
+
+        let closes_at = 1_000 + DividendEpoch::CLAIM_WINDOW_SECONDS;
+        assert!(!epoch.is_sweepable(closes_at - 1));
+        assert!(epoch.is_sweepable(closes_at));
+    }
+
+    #[test]
+    fn test_only_earlier_holders_qualify() {
+        let epoch = epoch(1_000_000, 10);
+        assert!(epoch.is_eligible(999));
+        assert!(!epoch.is_eligible(1_000));
+        assert!(!epoch.is_eligible(5_000));
+    }
+
+    #[test]
+    fn test_rejects_empty_epochs() {
+        let mut blank = epoch(1, 1);
+        assert!(blank.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1, DividendSource::RewardPool, 0, 10, 0, 0).is_err());
+        assert!(blank.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1, DividendSource::RewardPool, 10, 0, 0, 0).is_err());
+    }
+}
+```
\ No newline at end of file
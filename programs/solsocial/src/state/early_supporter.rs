@@ -0,0 +1,162 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// Per-market pool paying the first `MAX_SUPPORTERS` holders an equal slice of every
+/// creator fee collected after they joined
+#[account]
+pub struct EarlySupporterPool {
+    /// The subject whose market the pool belongs to
+    pub keys_user: Pubkey,
+    /// Registered supporters, in the order they first bought
+    pub supporters: [Pubkey; 10],
+    /// `reward_per_supporter` at each supporter's last claim (or registration)
+    pub reward_debt: [u64; 10],
+    /// Slots of `supporters` in use
+    pub supporter_count: u8,
+    /// Lamports credited to each supporter seat since the pool opened
+    pub reward_per_supporter: u64,
+    /// Lamports paid into the pool
+    pub total_accrued: u64,
+    /// Lamports supporters have claimed out of the pool
+    pub total_claimed: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl EarlySupporterPool {
+    pub const MAX_SUPPORTERS: usize = 10;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // keys_user
+        32 * Self::MAX_SUPPORTERS + // supporters
+        8 * Self::MAX_SUPPORTERS + // reward_debt
+        1 + // supporter_count
+        8 + // reward_per_supporter
+        8 + // total_accrued
+        8 + // total_claimed
+        1; // bump
+
+    pub fn initialize(&mut self, keys_user: Pubkey, bump: u8) {
+        self.keys_user = keys_user;
+        self.supporters = [Pubkey::default(); Self::MAX_SUPPORTERS];
+        self.reward_debt = [0; Self::MAX_SUPPORTERS];
+        self.supporter_count = 0;
+        self.reward_per_supporter = 0;
+        self.total_accrued = 0;
+        self.total_claimed = 0;
+        self.bump = bump;
+    }
+
+    pub fn position(&self, supporter: &Pubkey) -> Option<usize> {
+        self.supporters[..self.supporter_count as usize]
+            .iter()
+            .position(|registered| registered == supporter)
+    }
+
+    /// Take `supporter` into a free seat; returns false once the pool is full or they already have one.
+    /// They only earn from fees collected afterwards.
+    pub fn register(&mut self, supporter: Pubkey) -> bool {
+        let count = self.supporter_count as usize;
+        if count >= Self::MAX_SUPPORTERS || self.position(&supporter).is_some() {
+            return false;
+        }
+        self.supporters[count] = supporter;
+        self.reward_debt[count] = self.reward_per_supporter;
+        self.supporter_count += 1;
+        true
+    }
+
+    /// Split `amount` equally across the current supporters and return how much of it the pool
+    /// takes; the indivisible remainder, or everything while nobody has a seat, stays with the caller
+    pub fn record_fee(&mut self, amount: u64) -> Result<u64> {
+        if self.supporter_count == 0 {
+            return Ok(0);
+        }
+        let per_supporter = amount / self.supporter_count as u64;
+        let credited = per_supporter * self.supporter_count as u64;
+        self.reward_per_supporter = self.reward_per_supporter
+            .checked_add(per_supporter)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        self.total_accrued = self.total_accrued
+            .checked_add(credited)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        Ok(credited)
+    }
+
+    /// Lamports waiting for the supporter in `index`
+    pub fn pending(&self, index: usize) -> u64 {
+        self.reward_per_supporter.saturating_sub(self.reward_debt[index])
+    }
+
+    /// Settle `supporter`'s pending share and return it
+    pub fn claim(&mut self, supporter: &Pubkey) -> Result<u64> {
+        let index = self.position(supporter)
+            .ok_or(crate::error::SolSocialError::NotEarlySupporter)?;
+        let amount = self.pending(index);
+        self.reward_debt[index] = self.reward_per_supporter;
+        self.total_claimed = self.total_claimed
+            .checked_add(amount)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        Ok(amount)
+    }
+}
+
+// Seeds for PDA derivation
+pub const EARLY_SUPPORTER_POOL_SEED: &[u8] = b"early_supporter_pool";
+
+pub fn get_early_supporter_pool_pda(keys_user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EARLY_SUPPORTER_POOL_SEED, keys_user.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool() -> EarlySupporterPool {
+        let mut pool = EarlySupporterPool {
+            keys_user: Pubkey::default(),
+            supporters: [Pubkey::default(); EarlySupporterPool::MAX_SUPPORTERS],
+            reward_debt: [0; EarlySupporterPool::MAX_SUPPORTERS],
+            supporter_count: 0,
+            reward_per_supporter: 0,
+            total_accrued: 0,
+            total_claimed: 0,
+            bump: 0,
+        };
+        pool.initialize(Pubkey::new_unique(), 254);
+        pool
+    }
+
+    #[test]
+    fn test_only_first_supporters_get_seats() {
+        let mut pool = pool();
+        let first = Pubkey::new_unique();
+        assert!(pool.register(first));
+        assert!(!pool.register(first));
+        for _ in 1..EarlySupporterPool::MAX_SUPPORTERS {
+            assert!(pool.register(Pubkey::new_unique()));
+        }
+        assert!(!pool.register(Pubkey::new_unique()));
+        assert_eq!(pool.supporter_count as usize, EarlySupporterPool::MAX_SUPPORTERS);
+    }
+
+    #[test]
+    fn test_fees_split_among_supporters_present() {
+        let mut pool = pool();
+        assert_eq!(pool.record_fee(1_000).unwrap(), 0);
+
+        let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+        pool.register(alice);
+        assert_eq!(pool.record_fee(1_000).unwrap(), 1_000);
+        pool.register(bob);
+        // Odd lamports stay with the creator
+        assert_eq!(pool.record_fee(1_001).unwrap(), 1_000);
+
+        assert_eq!(pool.claim(&alice).unwrap(), 1_500);
+        assert_eq!(pool.claim(&bob).unwrap(), 500);
+        assert_eq!(pool.claim(&bob).unwrap(), 0);
+        assert_eq!(pool.total_claimed, pool.total_accrued);
+        assert!(pool.claim(&Pubkey::new_unique()).is_err());
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,152 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// Per-creator PDA that accumulates creator fees until the creator pulls them out
+#[account]
+pub struct EarningsVault {
+    /// Creator wallet allowed to claim from the vault
+    pub creator: Pubkey,
+    /// Creator fees credited to the vault since it was opened
+    pub total_accrued: u64,
+    /// Lamports the creator has claimed out of the vault
+    pub total_claimed: u64,
+    /// Timestamp of the last claim, 0 before the first
+    pub last_claimed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Collects a shared market's creator fees, which the collaborators split
+    pub shared: bool,
+    /// Part of `total_accrued` already split among a shared market's collaborators
+    pub distributed_through: u64,
+}
+
+impl EarningsVault {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        8 + // total_accrued
+        8 + // total_claimed
+        8 + // last_claimed_at
+        1 + // bump
+        1 + // shared
+        8; // distributed_through
+
+    pub fn initialize(&mut self, creator: Pubkey, bump: u8) {
+        self.creator = creator;
+        self.total_accrued = 0;
+        self.total_claimed = 0;
+        self.last_claimed_at = 0;
+        self.bump = bump;
+        self.shared = false;
+        self.distributed_through = 0;
+    }
+
+    /// Start splitting fees accrued from now on among a shared market's collaborators
+    pub fn share(&mut self) {
+        self.shared = true;
+        self.distributed_through = self.total_accrued;
+    }
+
+    /// Fees accrued to a shared vault that its collaborators haven't been paid their split of
+    pub fn undistributed(&self) -> u64 {
+        if self.shared {
+            self.total_accrued.saturating_sub(self.distributed_through)
+        } else {
+            0
+        }
+    }
+
+    /// Fail while a shared vault holds fees the other collaborators are owed a split of
+    pub fn require_distributed(&self) -> Result<()> {
+        require!(self.undistributed() == 0, crate::error::SolSocialError::SharedEarningsUndistributed);
+        Ok(())
+    }
+
+    /// Record that the collaborators were paid `paid_out` of everything accrued so far; it left
+    /// the vault, so it counts as claimed
+    pub fn record_distribution(&mut self, paid_out: u64, now: i64) -> Result<()> {
+        self.distributed_through = self.total_accrued;
+        self.record_claim(paid_out, now)
+    }
+
+    pub fn record_accrual(&mut self, amount: u64) -> Result<()> {
+        self.total_accrued = self.total_accrued
+            .checked_add(amount)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_claim(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.total_claimed = self.total_claimed
+            .checked_add(amount)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        self.last_claimed_at = now;
+        Ok(())
+    }
+
+    /// Accrued fees not yet claimed
+    pub fn unclaimed(&self) -> u64 {
+        self.total_accrued.saturating_sub(self.total_claimed)
+    }
+}
+
+// Seeds for PDA derivation
+pub const EARNINGS_VAULT_SEED: &[u8] = b"earnings_vault";
+
+pub fn get_earnings_vault_pda(creator: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EARNINGS_VAULT_SEED, creator.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accruals_and_claims_track_unclaimed_balance() {
+        let mut vault = EarningsVault {
+            creator: Pubkey::default(),
+            total_accrued: 0,
+            total_claimed: 0,
+            last_claimed_at: 0,
+            bump: 0,
+            shared: false,
+            distributed_through: 0,
+        };
+        vault.initialize(Pubkey::new_unique(), 254);
+        vault.record_accrual(40_000).unwrap();
+        vault.record_accrual(10_000).unwrap();
+        assert_eq!(vault.unclaimed(), 50_000);
+
+        vault.record_claim(30_000, 1_700_000_000).unwrap();
+        assert_eq!(vault.unclaimed(), 20_000);
+        assert_eq!(vault.last_claimed_at, 1_700_000_000);
+
+        vault.total_accrued = u64::MAX;
+        assert!(vault.record_accrual(1).is_err());
+    }
+
+    #[test]
+    fn test_shared_vault_splits_only_later_accruals() {
+        let mut vault = EarningsVault {
+            creator: Pubkey::default(),
+            total_accrued: 0,
+            total_claimed: 0,
+            last_claimed_at: 0,
+            bump: 0,
+            shared: false,
+            distributed_through: 0,
+        };
+        vault.initialize(Pubkey::new_unique(), 254);
+        vault.record_accrual(10_000).unwrap();
+        vault.share();
+        assert!(vault.require_distributed().is_ok());
+
+        vault.record_accrual(4_000).unwrap();
+        assert_eq!(vault.undistributed(), 4_000);
+        assert!(vault.require_distributed().is_err());
+
+        vault.record_distribution(1_000, 1_700_000_000).unwrap();
+        assert!(vault.require_distributed().is_ok());
+        assert_eq!(vault.unclaimed(), 13_000);
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,97 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::InteractionType;
+
+/// Running engagement tally of one fan on one creator's posts, reset on each reward payout
+#[account]
+pub struct EngagementScore {
+    /// The engaging user
+    pub user: Pubkey,
+    /// The creator whose posts were engaged
+    pub creator: Pubkey,
+    /// Weighted interactions since the last payout
+    pub score: u64,
+    /// Total lamports received from engagement kickbacks
+    pub rewards_earned: u64,
+    /// Timestamp of the latest scored interaction
+    pub last_interaction_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl EngagementScore {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // user
+        32 + // creator
+        8 + // score
+        8 + // rewards_earned
+        8 + // last_interaction_at
+        1; // bump
+
+    /// Maximum creator-earnings share that may be kicked back in one payout (50%)
+    pub const MAX_KICKBACK_BPS: u16 = 5_000;
+
+    /// Maximum engagers paid in a single distribution
+    pub const MAX_RECIPIENTS: usize = 10;
+
+    /// Same weighting as a post's engagement score: comments and shares count more than likes
+    pub fn weight(interaction_type: &InteractionType) -> u64 {
+        match interaction_type {
+            InteractionType::Like => 1,
+            InteractionType::Comment => 3,
+            InteractionType::Share | InteractionType::Tip => 5,
+        }
+    }
+
+    pub fn record(&mut self, interaction_type: &InteractionType, timestamp: i64) -> Result<()> {
+        self.score = self.score
+            .checked_add(Self::weight(interaction_type))
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        self.last_interaction_at = timestamp;
+        Ok(())
+    }
+
+    /// Credit a payout and start the next scoring period from zero
+    pub fn settle(&mut self, reward: u64) -> Result<()> {
+        self.rewards_earned = self.rewards_earned
+            .checked_add(reward)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        self.score = 0;
+        Ok(())
+    }
+}
+
+// Seeds for PDA derivation
+pub const ENGAGEMENT_SEED: &[u8] = b"engagement";
+
+pub fn get_engagement_score_pda(creator: &Pubkey, user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ENGAGEMENT_SEED, creator.as_ref(), user.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settle_resets_score() {
+        let mut engagement = EngagementScore {
+            user: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            score: 0,
+            rewards_earned: 0,
+            last_interaction_at: 0,
+            bump: 0,
+        };
+
+        engagement.record(&InteractionType::Like, 1).unwrap();
+        engagement.record(&InteractionType::Comment, 2).unwrap();
+        engagement.record(&InteractionType::Share, 3).unwrap();
+        assert_eq!(engagement.score, 9);
+        assert_eq!(engagement.last_interaction_at, 3);
+
+        engagement.settle(1_000).unwrap();
+        assert_eq!(engagement.score, 0);
+        assert_eq!(engagement.rewards_earned, 1_000);
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,143 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// Keys one wallet bought for another, with the note that came with them
+#[account]
+pub struct Gift {
+    /// Wallet that paid for the keys
+    pub sender: Pubkey,
+    /// Wallet the keys were minted to
+    pub recipient: Pubkey,
+    /// Subject whose keys were gifted
+    pub subject: Pubkey,
+    /// Number of keys gifted
+    pub amount: u64,
+    /// Curve price the sender paid, fees included
+    pub price: u64,
+    /// The market's trade index this gift was bought at
+    pub trade_index: u64,
+    /// Optional note to the recipient
+    pub message: String,
+    /// Timestamp when the gift was sent
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Gift {
+    /// Longest note a gift may carry, in bytes
+    pub const MAX_MESSAGE_LEN: usize = 280;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // sender
+        32 + // recipient
+        32 + // subject
+        8 + // amount
+        8 + // price
+        8 + // trade_index
+        4 + Self::MAX_MESSAGE_LEN + // message
+        8 + // created_at
+        1; // bump
+
+    pub fn initialize(
+        &mut self,
+        sender: Pubkey,
+        recipient: Pubkey,
+        subject: Pubkey,
+        amount: u64,
+        price: u64,
+        trade_index: u64,
+        message: Option<String>,
+        created_at: i64,
+        bump: u8,
+    ) -> Result<()> {
+        let message = message.unwrap_or_default();
+        require!(message.len() <= Self::MAX_MESSAGE_LEN, crate::error::SolSocialError::MessageTooLong);
+
+        self.sender = sender;
+        self.recipient = recipient;
+        self.subject = subject;
+        self.amount = amount;
+        self.price = price;
+        self.trade_index = trade_index;
+        self.message = message;
+        self.created_at = created_at;
+        self.bump = bump;
+
+        Ok(())
+    }
+}
+
+// Seeds for PDA derivation
+pub const GIFT_SEED: &[u8] = b"gift";
+
+/// Gifts are keyed by the market's trade index at the time, so each one gets its own PDA
+pub fn get_gift_pda(subject: &Pubkey, trade_index: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GIFT_SEED, subject.as_ref(), &trade_index.to_le_bytes()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_gift() -> Gift {
+        Gift {
+            sender: Pubkey::default(),
+            recipient: Pubkey::default(),
+            subject: Pubkey::default(),
+            amount: 0,
+            price: 0,
+            trade_index: 0,
+            message: String::new(),
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_message_fits_account() {
+        let mut gift = empty_gift();
+        let message = "k".repeat(Gift::MAX_MESSAGE_LEN);
+        gift.initialize(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            2,
+            2_100_000,
+            7,
+            Some(message.clone()),
+            1_000,
+            255,
+        )
+        .unwrap();
+        assert_eq!(gift.message, message);
+
+        let mut data = Vec::new();
+        gift.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= Gift::LEN);
+    }
+
+    #[test]
+    fn test_rejects_long_message() {
+        let too_long = Some("k".repeat(Gift::MAX_MESSAGE_LEN + 1));
+        let result = empty_gift().initialize(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            1_000_000,
+            0,
+            too_long,
+            0,
+            0,
+        );
+        assert!(result.is_err());
+
+        // No note at all is fine
+        let mut gift = empty_gift();
+        gift.initialize(Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), 1, 1_000_000, 0, None, 0, 0)
+            .unwrap();
+        assert!(gift.message.is_empty());
+    }
+}
+```
\ No newline at end of file
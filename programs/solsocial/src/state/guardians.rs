@@ -0,0 +1,184 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::error::SolSocialError;
+
+/// Guardians who can jointly hand a `User` profile to a new wallet after a delay
+#[account]
+pub struct Guardians {
+    /// The `User` profile these guardians protect
+    pub user: Pubkey,
+    /// Wallets allowed to approve a recovery
+    pub guardians: Vec<Pubkey>,
+    /// Distinct guardian signatures needed to start a recovery
+    pub threshold: u8,
+    /// Wallet a pending recovery hands the profile to
+    pub pending_authority: Option<Pubkey>,
+    /// When the pending recovery was started
+    pub recovery_initiated_at: i64,
+    /// Earliest time the pending recovery can be executed
+    pub recovery_executable_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Guardians {
+    pub const MAX_GUARDIANS: usize = 5;
+
+    /// Time the owner has to cancel a recovery they didn't ask for
+    pub const RECOVERY_DELAY_SECONDS: i64 = 2 * 24 * 60 * 60;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // user
+        4 + 32 * Self::MAX_GUARDIANS + // guardians
+        1 + // threshold
+        1 + 32 + // pending_authority
+        8 + // recovery_initiated_at
+        8 + // recovery_executable_at
+        1; // bump
+
+    /// Replace the guardian set; `owner` can't guard their own profile
+    pub fn set(&mut self, guardians: Vec<Pubkey>, threshold: u8, owner: &Pubkey) -> Result<()> {
+        require!(self.pending_authority.is_none(), SolSocialError::RecoveryPending);
+        require!(
+            !guardians.is_empty() && guardians.len() <= Self::MAX_GUARDIANS,
+            SolSocialError::InvalidGuardians
+        );
+        require!(
+            threshold > 0 && threshold as usize <= guardians.len(),
+            SolSocialError::InvalidGuardians
+        );
+        for (i, guardian) in guardians.iter().enumerate() {
+            require!(guardian != owner, SolSocialError::InvalidGuardians);
+            require!(!guardians[..i].contains(guardian), SolSocialError::InvalidGuardians);
+        }
+
+        self.guardians = guardians;
+        self.threshold = threshold;
+        Ok(())
+    }
+
+    /// Number of distinct guardians among `signers`
+    pub fn count_approvals(&self, signers: &[Pubkey]) -> usize {
+        self.guardians
+            .iter()
+            .filter(|guardian| signers.contains(guardian))
+            .count()
+    }
+
+    /// Start handing the profile to `new_authority` once `signers` reach the threshold
+    pub fn initiate(&mut self, new_authority: Pubkey, signers: &[Pubkey], now: i64) -> Result<()> {
+        require!(self.pending_authority.is_none(), SolSocialError::RecoveryPending);
+        require!(
+            self.count_approvals(signers) >= self.threshold as usize,
+            SolSocialError::InsufficientGuardianApprovals
+        );
+
+        self.pending_authority = Some(new_authority);
+        self.recovery_initiated_at = now;
+        self.recovery_executable_at = now
+            .checked_add(Self::RECOVERY_DELAY_SECONDS)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Take the pending authority once the delay has passed
+    pub fn execute(&mut self, now: i64) -> Result<Pubkey> {
+        let new_authority = self.pending_authority.ok_or(SolSocialError::NoRecoveryPending)?;
+        require!(now >= self.recovery_executable_at, SolSocialError::RecoveryNotReady);
+
+        self.clear();
+        Ok(new_authority)
+    }
+
+    pub fn cancel(&mut self) -> Result<Pubkey> {
+        let new_authority = self.pending_authority.ok_or(SolSocialError::NoRecoveryPending)?;
+        self.clear();
+        Ok(new_authority)
+    }
+
+    fn clear(&mut self) {
+        self.pending_authority = None;
+        self.recovery_initiated_at = 0;
+        self.recovery_executable_at = 0;
+    }
+}
+
+// Seeds for PDA derivation
+pub const GUARDIANS_SEED: &[u8] = b"guardians";
+
+pub fn get_guardians_pda(user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GUARDIANS_SEED, user.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guarded(owner: &Pubkey, guardians: &[Pubkey], threshold: u8) -> Guardians {
+        let mut set = Guardians {
+            user: Pubkey::new_unique(),
+            guardians: Vec::new(),
+            threshold: 0,
+            pending_authority: None,
+            recovery_initiated_at: 0,
+            recovery_executable_at: 0,
+            bump: 0,
+        };
+        set.set(guardians.to_vec(), threshold, owner).unwrap();
+        set
+    }
+
+    #[test]
+    fn test_guardian_set_validation() {
+        let owner = Pubkey::new_unique();
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let mut set = guarded(&owner, &[a, b], 2);
+
+        assert!(set.set(vec![], 1, &owner).is_err());
+        assert!(set.set(vec![a, b], 3, &owner).is_err());
+        assert!(set.set(vec![a, b], 0, &owner).is_err());
+        assert!(set.set(vec![a, a], 1, &owner).is_err());
+        assert!(set.set(vec![a, owner], 1, &owner).is_err());
+        assert!(set.set(vec![Pubkey::new_unique(); Guardians::MAX_GUARDIANS + 1], 1, &owner).is_err());
+    }
+
+    #[test]
+    fn test_recovery_waits_out_the_delay() {
+        let owner = Pubkey::new_unique();
+        let guardians = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let new_authority = Pubkey::new_unique();
+        let mut set = guarded(&owner, &guardians, 2);
+        let now = 1_000;
+
+        // One guardian signing twice, or a stranger, doesn't count toward the threshold
+        assert!(set.initiate(new_authority, &[guardians[0], guardians[0]], now).is_err());
+        assert!(set.initiate(new_authority, &[guardians[0], Pubkey::new_unique()], now).is_err());
+
+        set.initiate(new_authority, &[guardians[0], guardians[2]], now).unwrap();
+        assert!(set.initiate(new_authority, &guardians, now).is_err());
+        assert!(set.set(guardians.to_vec(), 1, &owner).is_err());
+
+        let ready = now + Guardians::RECOVERY_DELAY_SECONDS;
+        assert!(set.execute(ready - 1).is_err());
+        assert_eq!(set.execute(ready).unwrap(), new_authority);
+        assert!(set.pending_authority.is_none());
+        assert!(set.execute(ready).is_err());
+    }
+
+    #[test]
+    fn test_owner_cancels_pending_recovery() {
+        let owner = Pubkey::new_unique();
+        let guardians = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut set = guarded(&owner, &guardians, 1);
+
+        assert!(set.cancel().is_err());
+        set.initiate(Pubkey::new_unique(), &guardians[..1], 0).unwrap();
+        set.cancel().unwrap();
+        assert!(set.execute(Guardians::RECOVERY_DELAY_SECONDS).is_err());
+
+        // A fresh recovery can start after a cancel
+        set.initiate(Pubkey::new_unique(), &guardians[1..], 10).unwrap();
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,123 @@
+```rust
+use anchor_lang::prelude::*;
+use super::MAX_HASHTAGS;
+
+/// Usage counter for one hashtag across all posts, for trending topics
+#[account]
+pub struct HashtagIndex {
+    /// Normalized tag text, without the leading `#`
+    pub tag: String,
+    /// Posts that have used the tag
+    pub usage_count: u64,
+    /// Timestamp of the first post using the tag
+    pub first_used_at: i64,
+    /// Timestamp of the latest post using the tag
+    pub last_used_at: i64,
+    /// The latest post using the tag
+    pub last_post: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl HashtagIndex {
+    /// Tags double as PDA seeds, which cap out at 32 bytes
+    pub const MAX_TAG_LENGTH: usize = 32;
+
+    pub const LEN: usize = 8 + // discriminator
+        4 + Self::MAX_TAG_LENGTH + // tag
+        8 + // usage_count
+        8 + // first_used_at
+        8 + // last_used_at
+        32 + // last_post
+        1; // bump
+
+    pub fn new(tag: String, now: i64, bump: u8) -> Self {
+        Self {
+            tag,
+            usage_count: 0,
+            first_used_at: now,
+            last_used_at: now,
+            last_post: Pubkey::default(),
+            bump,
+        }
+    }
+
+    pub fn record_use(&mut self, post: Pubkey, now: i64) -> Result<()> {
+        self.usage_count = self.usage_count
+            .checked_add(1)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        self.last_used_at = now;
+        self.last_post = post;
+        Ok(())
+    }
+}
+
+/// Hashtags in `content`, lowercased and deduplicated in order of first appearance.
+/// A tag is `#` followed by ASCII letters, digits or underscores; tags too long to be
+/// seeds are skipped, and only the first `MAX_HASHTAGS` count.
+pub fn parse_hashtags(content: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    let mut rest = content;
+    while let Some(start) = rest.find('#') {
+        let after = &rest[start + 1..];
+        let end = after
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(after.len());
+        let tag = after[..end].to_ascii_lowercase();
+        rest = &after[end..];
+
+        if tag.is_empty() || tag.len() > HashtagIndex::MAX_TAG_LENGTH || tags.contains(&tag) {
+            continue;
+        }
+        tags.push(tag);
+        if tags.len() == MAX_HASHTAGS {
+            break;
+        }
+    }
+    tags
+}
+
+// Seeds for PDA derivation
+pub const HASHTAG_INDEX_SEED: &[u8] = b"hashtag";
+
+pub fn get_hashtag_index_pda(tag: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HASHTAG_INDEX_SEED, tag.as_bytes()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_normalizes_and_dedupes() {
+        assert_eq!(
+            parse_hashtags("gm #Solana builders, #solana #web3! #build_in_public"),
+            vec!["solana", "web3", "build_in_public"]
+        );
+        assert!(parse_hashtags("no tags # here").is_empty());
+        assert_eq!(parse_hashtags("##double #a#b"), vec!["double", "a", "b"]);
+    }
+
+    #[test]
+    fn test_parse_skips_oversized_tags_and_caps_the_count() {
+        let long_tag = format!("#{} #ok", "x".repeat(HashtagIndex::MAX_TAG_LENGTH + 1));
+        assert_eq!(parse_hashtags(&long_tag), vec!["ok"]);
+
+        let many: String = (0..MAX_HASHTAGS + 3).map(|i| format!("#t{} ", i)).collect();
+        assert_eq!(parse_hashtags(&many).len(), MAX_HASHTAGS);
+    }
+
+    #[test]
+    fn test_record_use_tracks_the_latest_post() {
+        let mut index = HashtagIndex::new("solana".to_string(), 100, 254);
+        let post = Pubkey::new_unique();
+        index.record_use(Pubkey::new_unique(), 100).unwrap();
+        index.record_use(post, 250).unwrap();
+
+        assert_eq!(index.usage_count, 2);
+        assert_eq!(index.first_used_at, 100);
+        assert_eq!(index.last_used_at, 250);
+        assert_eq!(index.last_post, post);
+    }
+}
+```
\ No newline at end of file
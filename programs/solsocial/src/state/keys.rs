@@ -1,18 +1,23 @@
 ```rust
 use anchor_lang::prelude::*;
+use crate::utils::bonding_curve::{self, BondingCurve, CurveKind};
+use crate::utils::accounts::{create_pda_account, write_account};
+use crate::utils::revenue_share::{validate_fee_parameters, MAX_TOTAL_FEE_BPS, REFERRER_FEE_BPS};
+use super::{LaunchAuction, LaunchGuard, MilestoneTable, Presale, BONDING_CURVE_BASE_PRICE};
 
 #[account]
 pub struct UserKeys {
     /// The user who owns these keys
     pub user: Pubkey,
-    /// Total supply of keys for this user
+    /// The curve's position: every key it has sold, locked keys included.
+    /// See `circulating_supply` for the keys that exist as tokens.
     pub supply: u64,
     /// Current price per key (in lamports)
     pub price: u64,
     /// Total volume traded (in lamports)
     pub volume: u64,
-    /// Number of holders
-    pub holders: u64,
+    /// Number of wallets with a non-zero `KeyHolder` balance
+    pub holders_count: u64,
     /// Creator earnings (in lamports)
     pub creator_earnings: u64,
     /// Protocol fees collected (in lamports)
@@ -23,36 +28,314 @@ pub struct UserKeys {
     pub last_trade_at: i64,
     /// Bonding curve parameters
     pub curve_params: BondingCurveParams,
+    /// Keys counted in `supply` but not minted: the creator's first key and unclaimed vesting keys
+    pub creator_locked_amount: u64,
+    /// `creator_earnings` already considered by engagement kickback payouts
+    pub engagement_rewarded_earnings: u64,
+    /// Sells before this timestamp pay no protocol or creator fee
+    pub grace_exit_until: i64,
+    /// When the last grace window was opened, used to rate-limit triggers
+    pub grace_opened_at: i64,
+    /// Trading opens at this timestamp (0 = no lower bound)
+    pub trading_open_at: i64,
+    /// Trading closes at this timestamp (0 = no upper bound)
+    pub trading_close_at: i64,
     /// Reserved space for future upgrades
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 8],
+    /// Trades executed so far; the next trade's `KeyTransaction` index
+    pub trade_count: u64,
+    /// SPL mint for curve-traded keys; locked keys are never minted, except vested creator keys once claimed
+    pub mint: Pubkey,
+    /// Dividend epochs opened so far; the next epoch's PDA index
+    pub dividend_epochs: u64,
+    /// Dutch auction selling the first keys before the curve opens
+    pub launch_auction: LaunchAuction,
+    /// Allowlisted presale of the first keys before the curve opens
+    pub presale: Presale,
+    /// Anti-sniper limits on the first slots after creation
+    pub launch_guard: LaunchGuard,
+    /// Set by `halt_trading` or a tripped circuit breaker; curve trades wait for `resume_trading`
+    pub trading_halted: bool,
+    /// Recent post-trade prices watched by the circuit breaker, as a ring buffer
+    pub price_window: [PricePoint; PRICE_WINDOW_LEN],
+    /// Next slot of `price_window` to write
+    pub price_window_head: u8,
+    /// When `graduate_market` moved the market into an AMM pool (0 = still on the curve)
+    pub graduated_at: i64,
+    /// Keys bought back by the creator and burned, and so already out of `supply`
+    pub burned_keys: u64,
+    /// SPL token curve trades settle in, set by `set_payment_mint` (default = lamports)
+    pub payment_mint: Pubkey,
+    /// Bit `i` is set once supply has reached the protocol's milestone `i`
+    pub milestones_reached: u32,
+    /// Bonuses credited to the creator for the milestones the market has reached
+    pub milestone_bonuses: u64,
+}
+
+/// Post-trade prices kept per market for the circuit breaker
+pub const PRICE_WINDOW_LEN: usize = 8;
+
+/// Average per-key price of a trade and when it happened
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct PricePoint {
+    pub price: u64,
+    pub timestamp: i64,
+}
+
+impl PricePoint {
+    pub const LEN: usize = 8 + // price
+        8; // timestamp
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
 pub struct BondingCurveParams {
+    /// Pricing formula the market's keys follow
+    pub curve_kind: CurveKind,
     /// Base price in lamports
     pub base_price: u64,
-    /// Price increase factor (scaled by 1e6)
+    /// Price increase factor for the exponential curve (scaled by 1e6)
     pub price_factor: u64,
     /// Maximum supply limit
     pub max_supply: u64,
-    /// Creator fee percentage (scaled by 1e4, e.g., 500 = 5%)
-    pub creator_fee: u16,
-    /// Protocol fee percentage (scaled by 1e4, e.g., 250 = 2.5%)
-    pub protocol_fee: u16,
+    /// Creator fee charged on buys (scaled by 1e4, e.g., 500 = 5%)
+    pub buy_creator_fee: u16,
+    /// Protocol fee charged on buys (scaled by 1e4, e.g., 250 = 2.5%)
+    pub buy_protocol_fee: u16,
+    /// Creator fee charged on sells (scaled by 1e4)
+    pub sell_creator_fee: u16,
+    /// Protocol fee charged on sells (scaled by 1e4)
+    pub sell_protocol_fee: u16,
+    /// Most keys a single wallet may own, as counted by its `KeyHolder` (0 = unlimited).
+    /// This is the per-wallet limit every buy path checks, so keys moved out of the wallet
+    /// behind the program's back don't free up room under it
+    pub max_keys_per_holder: u64,
+    /// Highest price a single key may reach before trades fail (0 = only the u64 limit)
+    pub max_price_per_key: u64,
+    /// Lamports added per key on the linear curve
+    pub slope: u64,
+    /// Supply at which the sigmoid curve is halfway to its ceiling
+    pub midpoint: u64,
+    /// Price the sigmoid curve approaches but never reaches
+    pub ceiling_price: u64,
+    /// Royalty on the curve value of keys moved by `transfer_keys`, paid to the creator (scaled by 1e4, 0 = free)
+    pub transfer_royalty_bps: u16,
+    /// Extra creator fee on a sell right after the holder's first buy, decaying linearly to
+    /// nothing over `sell_fee_decay_seconds` (scaled by 1e4, 0 = off)
+    pub early_sell_fee_bps: u16,
+    /// Holding time after which sells pay only the base fees
+    pub sell_fee_decay_seconds: i64,
 }
 
 impl Default for BondingCurveParams {
     fn default() -> Self {
         Self {
+            curve_kind: CurveKind::Exponential,
             base_price: 1_000_000, // 0.001 SOL
             price_factor: 1_100_000, // 1.1x multiplier
             max_supply: 1_000_000, // 1M keys max
-            creator_fee: 500, // 5%
-            protocol_fee: 250, // 2.5%
+            buy_creator_fee: 500, // 5%
+            buy_protocol_fee: 250, // 2.5%
+            sell_creator_fee: 500, // 5%
+            sell_protocol_fee: 250, // 2.5%
+            max_keys_per_holder: 0, // unlimited
+            max_price_per_key: 0, // uncapped
+            slope: 0,
+            midpoint: 0,
+            ceiling_price: 0,
+            transfer_royalty_bps: 0,
+            early_sell_fee_bps: 0, // no flip tax
+            sell_fee_decay_seconds: 0,
+        }
+    }
+}
+
+impl BondingCurveParams {
+    /// Steepest allowed per-key growth factor (2x, scaled by 1e6)
+    pub const MAX_PRICE_FACTOR: u64 = 2_000_000;
+
+    /// Highest royalty a creator may charge on key transfers (10%)
+    pub const MAX_TRANSFER_ROYALTY_BPS: u16 = 1_000;
+
+    /// Longest a flip tax may take to wear off (30 days)
+    pub const MAX_SELL_FEE_DECAY_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    /// Validate the curve shape and each side's fees against the default protocol maximum
+    pub fn validate(&self) -> Result<()> {
+        self.validate_with_fee_cap(MAX_TOTAL_FEE_BPS)
+    }
+
+    /// Validate the curve shape and each side's fees against `max_fee_bps`
+    pub fn validate_with_fee_cap(&self, max_fee_bps: u16) -> Result<()> {
+        require!(self.base_price > 0, crate::error::SolSocialError::InvalidBondingCurve);
+        require!(self.max_supply > 0, crate::error::SolSocialError::InvalidBondingCurve);
+        match self.curve_kind {
+            CurveKind::Exponential => require!(
+                self.price_factor >= 1_000_000 && self.price_factor <= Self::MAX_PRICE_FACTOR,
+                crate::error::SolSocialError::InvalidBondingCurve
+            ),
+            CurveKind::Quadratic => require!(
+                self.max_supply <= BondingCurve::MAX_SUPPLY,
+                crate::error::SolSocialError::InvalidBondingCurve
+            ),
+            CurveKind::Linear => require!(self.slope > 0, crate::error::SolSocialError::InvalidBondingCurve),
+            CurveKind::Sigmoid => require!(
+                self.midpoint > 0
+                    && self.ceiling_price > self.base_price
+                    && self.max_supply <= BondingCurve::MAX_SUPPLY,
+                crate::error::SolSocialError::InvalidBondingCurve
+            ),
+        }
+        // The closed-form curves must price their last key without overflowing
+        if self.curve_kind != CurveKind::Exponential {
+            require!(
+                bonding_curve::closed_form_price(self, self.max_supply - 1)
+                    .map_or(false, |price| price <= u64::MAX as u128),
+                crate::error::SolSocialError::InvalidBondingCurve
+            );
+        }
+        require!(
+            self.max_price_per_key == 0 || self.max_price_per_key >= self.base_price,
+            crate::error::SolSocialError::InvalidBondingCurve
+        );
+
+        validate_fee_parameters(self.buy_protocol_fee, self.buy_creator_fee, REFERRER_FEE_BPS, max_fee_bps)?;
+        // A fresh holder's sell, flip tax included, must still fit under the cap
+        validate_fee_parameters(
+            self.sell_protocol_fee,
+            self.sell_creator_fee.saturating_add(self.early_sell_fee_bps),
+            REFERRER_FEE_BPS,
+            max_fee_bps,
+        )?;
+        if self.early_sell_fee_bps > 0 {
+            require!(
+                self.sell_fee_decay_seconds > 0 && self.sell_fee_decay_seconds <= Self::MAX_SELL_FEE_DECAY_SECONDS,
+                crate::error::SolSocialError::InvalidFeeStructure
+            );
+        }
+        require!(
+            self.transfer_royalty_bps <= Self::MAX_TRANSFER_ROYALTY_BPS,
+            crate::error::SolSocialError::InvalidFeeStructure
+        );
+
+        Ok(())
+    }
+
+    /// Highest price the curve may reach: the creator's cap, or the u64 limit when unset
+    pub fn price_limit(&self) -> u64 {
+        if self.max_price_per_key == 0 {
+            u64::MAX
+        } else {
+            self.max_price_per_key
+        }
+    }
+
+    /// Creator fee on a sell by a holder whose position opened at `first_purchase_at`: the base
+    /// rate plus whatever of the flip tax hasn't decayed yet
+    pub fn sell_creator_fee_at(&self, first_purchase_at: i64, now: i64) -> u16 {
+        let held_for = now.saturating_sub(first_purchase_at).max(0);
+        if self.early_sell_fee_bps == 0 || held_for >= self.sell_fee_decay_seconds {
+            return self.sell_creator_fee;
+        }
+        let remaining = (self.sell_fee_decay_seconds - held_for) as u128;
+        let early_fee = self.early_sell_fee_bps as u128 * remaining / self.sell_fee_decay_seconds as u128;
+        self.sell_creator_fee.saturating_add(early_fee as u16)
+    }
+
+    /// Whether a holder with `held` keys may buy `amount` more without passing the per-holder cap
+    pub fn within_holder_cap(&self, held: u64, amount: u64) -> bool {
+        self.max_keys_per_holder == 0
+            || held
+                .checked_add(amount)
+                .map_or(false, |total| total <= self.max_keys_per_holder)
+    }
+
+    /// Keys a holder with `held` keys may still buy under the per-holder cap
+    pub fn holder_room(&self, held: u64) -> u64 {
+        if self.max_keys_per_holder == 0 {
+            u64::MAX
+        } else {
+            self.max_keys_per_holder.saturating_sub(held)
         }
     }
 }
 
+/// Vetted curve shapes for creators who don't want to tune raw parameters.
+/// Each preset reaches roughly 20 SOL per key at its max supply.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum CurvePreset {
+    /// +0.5% per key, up to 2,000 keys
+    Conservative,
+    /// +1% per key, up to 1,000 keys
+    Standard,
+    /// +2% per key, up to 500 keys
+    Aggressive,
+}
+
+impl CurvePreset {
+    pub fn params(&self) -> BondingCurveParams {
+        let (price_factor, max_supply) = match self {
+            CurvePreset::Conservative => (1_005_000, 2_000),
+            CurvePreset::Standard => (1_010_000, 1_000),
+            CurvePreset::Aggressive => (1_020_000, 500),
+        };
+
+        BondingCurveParams {
+            base_price: 1_000_000, // 0.001 SOL
+            price_factor,
+            max_supply,
+            ..BondingCurveParams::default()
+        }
+    }
+}
+
+/// How a creator picks their curve in `create_keys`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum CurveChoice {
+    Preset(CurvePreset),
+    /// Advanced: raw parameters and curve kind, still subject to validation
+    Custom(BondingCurveParams),
+}
+
+impl CurveChoice {
+    pub fn resolve(&self) -> Result<BondingCurveParams> {
+        self.resolve_with(BONDING_CURVE_BASE_PRICE, MAX_TOTAL_FEE_BPS)
+    }
+
+    /// Resolve against the protocol config: presets start at `default_base_price`
+    /// and every curve's fees must fit under `max_fee_bps`
+    pub fn resolve_with(&self, default_base_price: u64, max_fee_bps: u16) -> Result<BondingCurveParams> {
+        let params = match self {
+            CurveChoice::Preset(preset) => BondingCurveParams {
+                base_price: default_base_price,
+                ..preset.params()
+            },
+            CurveChoice::Custom(params) => *params,
+        };
+        params.validate_with_fee_cap(max_fee_bps)?;
+        Ok(params)
+    }
+}
+
+/// Keys pre-allocated to a co-founder when a market is created
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct FounderAllocation {
+    /// The co-founder receiving the keys
+    pub founder: Pubkey,
+    /// Number of keys allocated
+    pub amount: u64,
+}
+
+/// One market in a `buy_keys_batch` call
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct BatchBuyLeg {
+    /// The subject whose keys are bought
+    pub subject: Pubkey,
+    /// Number of keys to buy
+    pub amount: u64,
+    /// Most this leg may cost before fees
+    pub max_price: u64,
+}
+
 #[account]
 pub struct KeyHolder {
     /// The holder's wallet address
@@ -69,8 +352,12 @@ pub struct KeyHolder {
     pub first_purchase_at: i64,
     /// Last purchase timestamp
     pub last_purchase_at: i64,
+    /// Slot of the last `buy_keys`, checked by the market's launch guard cooldown
+    pub last_buy_slot: u64,
+    /// Slot of the last `sell_keys`; a buy can't land in the same slot
+    pub last_sell_slot: u64,
     /// Reserved space for future upgrades
-    pub reserved: [u8; 32],
+    pub reserved: [u8; 16],
 }
 
 #[account]
@@ -93,8 +380,8 @@ pub struct KeyTransaction {
     pub protocol_fee: u64,
     /// Transaction timestamp
     pub timestamp: i64,
-    /// Transaction signature for reference
-    pub signature: String,
+    /// Position of this trade in the market's history (`UserKeys::trade_count` at the time)
+    pub trade_index: u64,
     /// Reserved space for future upgrades
     pub reserved: [u8; 32],
 }
@@ -111,13 +398,39 @@ impl UserKeys {
         8 + // supply
         8 + // price
         8 + // volume
-        8 + // holders
+        8 + // holders_count
         8 + // creator_earnings
         8 + // protocol_fees
         8 + // created_at
         8 + // last_trade_at
-        32 + // curve_params (8 * 4)
-        64; // reserved
+        85 + // curve_params (1 + 8 * 9 + 2 * 6)
+        8 + // creator_locked_amount
+        8 + // engagement_rewarded_earnings
+        8 + // grace_exit_until
+        8 + // grace_opened_at
+        8 + // trading_open_at
+        8 + // trading_close_at
+        8 + // reserved
+        8 + // trade_count
+        32 + // mint
+        8 + // dividend_epochs
+        LaunchAuction::LEN + // launch_auction
+        Presale::LEN + // presale
+        LaunchGuard::LEN + // launch_guard
+        1 + // trading_halted
+        PricePoint::LEN * PRICE_WINDOW_LEN + // price_window
+        1 + // price_window_head
+        8 + // graduated_at
+        8 + // burned_keys
+        32 + // payment_mint
+        4 + // milestones_reached
+        8; // milestone_bonuses
+
+    /// Maximum number of co-founders that can receive an allocation at creation
+    pub const MAX_FOUNDER_ALLOCATIONS: usize = 10;
+
+    /// Minimum time between two grace windows on the same market
+    pub const GRACE_COOLDOWN_SECONDS: i64 = 7 * 24 * 60 * 60;
 
     pub fn new(user: Pubkey, curve_params: Option<BondingCurveParams>) -> Self {
         let clock = Clock::get().unwrap();
@@ -126,86 +439,278 @@ impl UserKeys {
             supply: 0,
             price: curve_params.as_ref().map_or(1_000_000, |p| p.base_price),
             volume: 0,
-            holders: 0,
+            holders_count: 0,
             creator_earnings: 0,
             protocol_fees: 0,
             created_at: clock.unix_timestamp,
             last_trade_at: clock.unix_timestamp,
             curve_params: curve_params.unwrap_or_default(),
-            reserved: [0; 64],
+            creator_locked_amount: 0,
+            engagement_rewarded_earnings: 0,
+            grace_exit_until: 0,
+            grace_opened_at: 0,
+            trading_open_at: 0,
+            trading_close_at: 0,
+            reserved: [0; 8],
+            trade_count: 0,
+            mint: Pubkey::default(),
+            dividend_epochs: 0,
+            launch_auction: LaunchAuction::default(),
+            presale: Presale::default(),
+            launch_guard: LaunchGuard::default(),
+            trading_halted: false,
+            price_window: [PricePoint::default(); PRICE_WINDOW_LEN],
+            price_window_head: 0,
+            graduated_at: 0,
+            burned_keys: 0,
+            payment_mint: Pubkey::default(),
+            milestones_reached: 0,
+            milestone_bonuses: 0,
         }
     }
 
-    pub fn calculate_price(&self, supply: u64) -> u64 {
-        if supply == 0 {
-            return self.curve_params.base_price;
-        }
-
-        // Exponential bonding curve: price = base_price * (price_factor / 1e6) ^ supply
-        let base = self.curve_params.base_price as u128;
-        let factor = self.curve_params.price_factor as u128;
-        let supply_u128 = supply as u128;
-
-        // Use integer approximation to avoid floating point
-        let mut price = base;
-        for _ in 0..supply_u128 {
-            price = (price * factor) / 1_000_000;
-        }
-
-        // Cap at reasonable maximum to prevent overflow
-        std::cmp::min(price as u64, 1_000_000_000_000) // 1000 SOL max
+    /// Price of key `supply`. Fails with `PriceOverflow` once the curve passes the
+    /// market's price limit rather than flattening out at an arbitrary ceiling.
+    pub fn calculate_price(&self, supply: u64) -> Result<u64> {
+        bonding_curve::calculate_price(&self.curve_params, supply)
     }
 
-    pub fn calculate_buy_price(&self, amount: u64) -> (u64, u64, u64) {
-        let mut total_cost = 0u64;
-        let current_supply = self.supply;
+    fn calculate_fees(value: u64, creator_fee_bps: u16, protocol_fee_bps: u16) -> (u64, u64) {
+        let creator_fee = (value as u128 * creator_fee_bps as u128 / 10_000) as u64;
+        let protocol_fee = (value as u128 * protocol_fee_bps as u128 / 10_000) as u64;
 
-        for i in 0..amount {
-            let price = self.calculate_price(current_supply + i);
-            total_cost = total_cost.saturating_add(price);
-        }
+        (creator_fee, protocol_fee)
+    }
 
-        let creator_fee = (total_cost as u128 * self.curve_params.creator_fee as u128 / 10_000) as u64;
-        let protocol_fee = (total_cost as u128 * self.curve_params.protocol_fee as u128 / 10_000) as u64;
+    pub fn calculate_buy_price(&self, amount: u64) -> Result<(u64, u64, u64)> {
+        let total_cost = bonding_curve::calculate_buy_price(&self.curve_params, self.supply, amount)?;
+        let (creator_fee, protocol_fee) = Self::calculate_fees(
+            total_cost,
+            self.curve_params.buy_creator_fee,
+            self.curve_params.buy_protocol_fee,
+        );
 
-        (total_cost, creator_fee, protocol_fee)
+        Ok((total_cost, creator_fee, protocol_fee))
     }
 
-    pub fn calculate_sell_price(&self, amount: u64) -> (u64, u64, u64) {
+    pub fn calculate_sell_price(&self, amount: u64) -> Result<(u64, u64, u64)> {
         if amount > self.supply {
-            return (0, 0, 0);
+            return Ok((0, 0, 0));
         }
 
-        let mut total_value = 0u64;
-        let current_supply = self.supply;
-
-        for i in 0..amount {
-            let price = self.calculate_price(current_supply - i - 1);
-            total_value = total_value.saturating_add(price);
-        }
-
-        let creator_fee = (total_value as u128 * self.curve_params.creator_fee as u128 / 10_000) as u64;
-        let protocol_fee = (total_value as u128 * self.curve_params.protocol_fee as u128 / 10_000) as u64;
+        let total_value = bonding_curve::calculate_sell_price(&self.curve_params, self.supply, amount)?;
+        let (creator_fee, protocol_fee) = Self::calculate_fees(
+            total_value,
+            self.curve_params.sell_creator_fee,
+            self.curve_params.sell_protocol_fee,
+        );
 
-        (total_value, creator_fee, protocol_fee)
+        Ok((total_value, creator_fee, protocol_fee))
     }
 
-    pub fn update_after_buy(&mut self, amount: u64, total_cost: u64, creator_fee: u64, protocol_fee: u64) {
+    pub fn update_after_buy(&mut self, amount: u64, total_cost: u64, creator_fee: u64, protocol_fee: u64) -> Result<()> {
         self.supply = self.supply.saturating_add(amount);
-        self.price = self.calculate_price(self.supply);
+        self.price = self.calculate_price(self.supply)?;
         self.volume = self.volume.saturating_add(total_cost);
         self.creator_earnings = self.creator_earnings.saturating_add(creator_fee);
         self.protocol_fees = self.protocol_fees.saturating_add(protocol_fee);
-        self.last_trade_at = Clock::get().unwrap().unix_timestamp;
+        self.last_trade_at = Clock::get()?.unix_timestamp;
+        Ok(())
     }
 
-    pub fn update_after_sell(&mut self, amount: u64, total_value: u64, creator_fee: u64, protocol_fee: u64) {
+    pub fn update_after_sell(&mut self, amount: u64, total_value: u64, creator_fee: u64, protocol_fee: u64) -> Result<()> {
         self.supply = self.supply.saturating_sub(amount);
-        self.price = self.calculate_price(self.supply);
+        self.price = self.calculate_price(self.supply)?;
         self.volume = self.volume.saturating_add(total_value);
         self.creator_earnings = self.creator_earnings.saturating_add(creator_fee);
         self.protocol_fees = self.protocol_fees.saturating_add(protocol_fee);
-        self.last_trade_at = Clock::get().unwrap().unix_timestamp;
+        self.last_trade_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Restrict trading to `[open_at, close_at)`; zero leaves that side unbounded
+    pub fn set_trading_hours(&mut self, open_at: i64, close_at: i64) -> Result<()> {
+        require!(open_at >= 0 && close_at >= 0, crate::error::SolSocialError::InvalidTimestamp);
+        require!(
+            close_at == 0 || close_at > open_at,
+            crate::error::SolSocialError::InvalidTimestamp
+        );
+
+        self.trading_open_at = open_at;
+        self.trading_close_at = close_at;
+        Ok(())
+    }
+
+    /// Whether curve buys and sells are allowed at `now`; markets are always open by default,
+    /// apart from a halt, a live launch auction or presale, or graduation to an AMM
+    pub fn is_trading_open(&self, now: i64) -> bool {
+        self.graduated_at == 0
+            && !self.trading_halted
+            && !self.launch_auction.is_live(now)
+            && !self.presale.is_live(now)
+            && now >= self.trading_open_at
+            && (self.trading_close_at == 0 || now < self.trading_close_at)
+    }
+
+    /// Whether curve trades settle in `payment_mint` rather than lamports
+    pub fn settles_in_token(&self) -> bool {
+        self.payment_mint != Pubkey::default()
+    }
+
+    /// Guard for instructions that only move lamports through the market's reserve
+    pub fn require_lamport_settlement(&self) -> Result<()> {
+        require!(
+            !self.settles_in_token(),
+            crate::error::SolSocialError::PaymentTokenNotSupported
+        );
+        Ok(())
+    }
+
+    /// Switch curve trades to settle in `mint`. Only allowed once, before the market has
+    /// traded or configured a launch auction or presale, so the reserve never mixes lamports
+    /// and tokens.
+    pub fn set_payment_mint(&mut self, mint: Pubkey) -> Result<()> {
+        require!(
+            mint != Pubkey::default() && mint != self.mint,
+            crate::error::SolSocialError::InvalidPaymentMint
+        );
+        require!(
+            !self.settles_in_token()
+                && self.trade_count == 0
+                && self.launch_auction.keys_for_sale == 0
+                && self.presale.keys_for_sale == 0,
+            crate::error::SolSocialError::InvalidPaymentMint
+        );
+        self.payment_mint = mint;
+        Ok(())
+    }
+
+    /// Mark each milestone in `table` that supply has reached for the first time, returning
+    /// them as a bitmask. Evaluated against the current supply, so a threshold crossed by a
+    /// trade that doesn't check is credited by the next one that does.
+    pub fn reach_milestones(&mut self, table: &MilestoneTable) -> u32 {
+        let mut reached = 0u32;
+        for (i, &threshold) in table.thresholds[..table.count as usize].iter().enumerate() {
+            let bit = 1u32 << i;
+            if self.supply >= threshold && self.milestones_reached & bit == 0 {
+                reached |= bit;
+            }
+        }
+        self.milestones_reached |= reached;
+        reached
+    }
+
+    /// Keys that exist as tokens: the curve's supply less locked creator keys
+    pub fn circulating_supply(&self) -> u64 {
+        self.supply.saturating_sub(self.creator_locked_amount)
+    }
+
+    /// Supply valued at the price of the next key, in lamports
+    pub fn market_cap(&self) -> Result<u64> {
+        self.calculate_price(self.supply)?
+            .checked_mul(self.supply)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow.into())
+    }
+
+    /// Claim the index for the dividend epoch being opened
+    pub fn next_dividend_epoch(&mut self) -> Result<u64> {
+        let index = self.dividend_epochs;
+        self.dividend_epochs = index
+            .checked_add(1)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        Ok(index)
+    }
+
+    /// Book a trade worth `value` once `supply` already reflects it: reprice the market, add to
+    /// its volume and fee totals, and stamp the trade time. Returns the trade's index.
+    pub fn record_trade(&mut self, value: u64, creator_fee: u64, protocol_fee: u64, now: i64) -> Result<u64> {
+        self.price = self.calculate_price(self.supply)?;
+        self.volume = self.volume.saturating_add(value);
+        self.creator_earnings = self.creator_earnings.saturating_add(creator_fee);
+        self.protocol_fees = self.protocol_fees.saturating_add(protocol_fee);
+        self.last_trade_at = now;
+        self.next_trade_index()
+    }
+
+    /// Claim the index for the trade being executed
+    pub fn next_trade_index(&mut self) -> Result<u64> {
+        let index = self.trade_count;
+        self.trade_count = index
+            .checked_add(1)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        Ok(index)
+    }
+
+    /// Keep `holders_count` exact as a holder's balance moves from `before` to `after`
+    pub fn track_holder_balance(&mut self, before: u64, after: u64) -> Result<()> {
+        if before == 0 && after > 0 {
+            self.holders_count = self.holders_count
+                .checked_add(1)
+                .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        } else if before > 0 && after == 0 {
+            self.holders_count = self.holders_count
+                .checked_sub(1)
+                .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    pub fn in_grace_window(&self, now: i64) -> bool {
+        now < self.grace_exit_until
+    }
+
+    /// Open a fee-free exit window when the price has fallen at least `threshold_bps` from its
+    /// recent peak. Returns whether a window was opened; at most one per cooldown period.
+    pub fn maybe_open_grace_window(
+        &mut self,
+        drop_bps: u16,
+        threshold_bps: u16,
+        window_seconds: i64,
+        now: i64,
+    ) -> bool {
+        if threshold_bps == 0 || drop_bps < threshold_bps || self.in_grace_window(now) {
+            return false;
+        }
+        if self.grace_opened_at != 0 && now - self.grace_opened_at < Self::GRACE_COOLDOWN_SECONDS {
+            return false;
+        }
+
+        self.grace_opened_at = now;
+        self.grace_exit_until = now.saturating_add(window_seconds);
+        true
+    }
+
+    /// Record a trade at `price` per key and halt trading when it moved more than
+    /// `max_move_bps` from any price seen in the last `window_seconds`. Returns the move
+    /// that tripped the breaker, if any; a zero threshold disables it.
+    pub fn record_price_point(&mut self, price: u64, now: i64, max_move_bps: u16, window_seconds: i64) -> Option<u64> {
+        let tripped = if max_move_bps == 0 {
+            None
+        } else {
+            self.price_window
+                .iter()
+                .filter(|point| point.price > 0 && now - point.timestamp <= window_seconds)
+                .map(|point| (price.abs_diff(point.price) as u128 * 10_000 / point.price as u128) as u64)
+                .max()
+                .filter(|move_bps| *move_bps > max_move_bps as u64)
+        };
+
+        self.price_window[self.price_window_head as usize % PRICE_WINDOW_LEN] = PricePoint { price, timestamp: now };
+        self.price_window_head = ((self.price_window_head as usize + 1) % PRICE_WINDOW_LEN) as u8;
+        if tripped.is_some() {
+            self.trading_halted = true;
+        }
+        tripped
+    }
+
+    /// Halt or resume curve trading. Resuming forgets the price window so the move that
+    /// tripped the breaker doesn't trip it again.
+    pub fn set_trading_halted(&mut self, halted: bool) {
+        self.trading_halted = halted;
+        if !halted {
+            self.price_window = [PricePoint::default(); PRICE_WINDOW_LEN];
+            self.price_window_head = 0;
+        }
     }
 }
 
@@ -218,7 +723,9 @@ impl KeyHolder {
         8 + // total_invested
         8 + // first_purchase_at
         8 + // last_purchase_at
-        32; // reserved
+        8 + // last_buy_slot
+        8 + // last_sell_slot
+        16; // reserved
 
     pub fn new(holder: Pubkey, keys_user: Pubkey) -> Self {
         let clock = Clock::get().unwrap();
@@ -230,7 +737,9 @@ impl KeyHolder {
             total_invested: 0,
             first_purchase_at: clock.unix_timestamp,
             last_purchase_at: clock.unix_timestamp,
-            reserved: [0; 32],
+            last_buy_slot: 0,
+            last_sell_slot: 0,
+            reserved: [0; 16],
         }
     }
 
@@ -250,6 +759,28 @@ impl KeyHolder {
         self.last_purchase_at = Clock::get().unwrap().unix_timestamp;
     }
 
+    /// Reject a trade landing in the same slot as this holder's last trade the other way,
+    /// so a buy and sell can't be sandwiched around a milestone or price event
+    pub fn require_no_round_trip(&self, slot: u64, is_buy: bool) -> Result<()> {
+        let last_opposite = if is_buy { self.last_sell_slot } else { self.last_buy_slot };
+        require!(
+            last_opposite == 0 || last_opposite != slot,
+            crate::error::SolSocialError::SameSlotRoundTrip
+        );
+        Ok(())
+    }
+
+    /// Check a curve trade against `require_no_round_trip` and remember its slot
+    pub fn record_trade_slot(&mut self, slot: u64, is_buy: bool) -> Result<()> {
+        self.require_no_round_trip(slot, is_buy)?;
+        if is_buy {
+            self.last_buy_slot = slot;
+        } else {
+            self.last_sell_slot = slot;
+        }
+        Ok(())
+    }
+
     pub fn update_after_sell(&mut self, amount: u64, total_value: u64) {
         self.amount = self.amount.saturating_sub(amount);
         
@@ -275,7 +806,7 @@ impl KeyTransaction {
         8 + // creator_fee
         8 + // protocol_fee
         8 + // timestamp
-        64 + // signature (max string length)
+        8 + // trade_index
         32; // reserved
 
     pub fn new(
@@ -287,7 +818,7 @@ impl KeyTransaction {
         total_value: u64,
         creator_fee: u64,
         protocol_fee: u64,
-        signature: String,
+        trade_index: u64,
     ) -> Self {
         Self {
             transaction_type,
@@ -299,16 +830,47 @@ impl KeyTransaction {
             creator_fee,
             protocol_fee,
             timestamp: Clock::get().unwrap().unix_timestamp,
-            signature,
+            trade_index,
             reserved: [0; 32],
         }
     }
+
+    /// Create this record's PDA in `target` and write it, paid for by `payer`
+    pub fn record<'info>(
+        &self,
+        target: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        let (expected, bump) = get_key_transaction_pda(&self.keys_user, &self.trader, self.trade_index, program_id);
+        require_keys_eq!(target.key(), expected, crate::error::SolSocialError::InvalidAccountSequence);
+
+        create_pda_account(
+            payer,
+            target,
+            system_program,
+            Self::LEN,
+            &[
+                KEY_TRANSACTION_SEED,
+                self.keys_user.as_ref(),
+                self.trader.as_ref(),
+                &self.trade_index.to_le_bytes(),
+                &[bump],
+            ],
+        )?;
+        write_account(target, self)
+    }
 }
 
 // Seeds for PDA derivation
 pub const USER_KEYS_SEED: &[u8] = b"user_keys";
 pub const KEY_HOLDER_SEED: &[u8] = b"key_holder";
 pub const KEY_TRANSACTION_SEED: &[u8] = b"key_transaction";
+pub const KEYS_MINT_SEED: &[u8] = b"keys_mint";
+pub const PAYMENT_RESERVE_SEED: &[u8] = b"payment_reserve";
+pub const WRAPPED_KEYS_MINT_SEED: &[u8] = b"wrapped_keys_mint";
+pub const WRAPPED_KEYS_VAULT_SEED: &[u8] = b"wrapped_keys_vault";
 
 // Helper functions for PDA derivation
 pub fn get_user_keys_pda(user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
@@ -318,6 +880,34 @@ pub fn get_user_keys_pda(user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
     )
 }
 
+pub fn get_keys_mint_pda(user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[KEYS_MINT_SEED, user.as_ref()],
+        program_id,
+    )
+}
+
+pub fn get_payment_reserve_pda(keys_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PAYMENT_RESERVE_SEED, keys_account.as_ref()],
+        program_id,
+    )
+}
+
+pub fn get_wrapped_keys_mint_pda(keys_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[WRAPPED_KEYS_MINT_SEED, keys_account.as_ref()],
+        program_id,
+    )
+}
+
+pub fn get_wrapped_keys_vault_pda(keys_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[WRAPPED_KEYS_VAULT_SEED, keys_account.as_ref()],
+        program_id,
+    )
+}
+
 pub fn get_key_holder_pda(holder: &Pubkey, keys_user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[KEY_HOLDER_SEED, holder.as_ref(), keys_user.as_ref()],
@@ -328,7 +918,7 @@ pub fn get_key_holder_pda(holder: &Pubkey, keys_user: &Pubkey, program_id: &Pubk
 pub fn get_key_transaction_pda(
     keys_user: &Pubkey,
     trader: &Pubkey,
-    timestamp: i64,
+    trade_index: u64,
     program_id: &Pubkey,
 ) -> (Pubkey, u8) {
     Pubkey::find_program_address(
@@ -336,9 +926,541 @@ pub fn get_key_transaction_pda(
             KEY_TRANSACTION_SEED,
             keys_user.as_ref(),
             trader.as_ref(),
-            &timestamp.to_le_bytes(),
+            &trade_index.to_le_bytes(),
         ],
         program_id,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LaunchAuctionParams;
+
+    fn keys_at_supply(supply: u64) -> UserKeys {
+        UserKeys {
+            user: Pubkey::new_unique(),
+            supply,
+            price: 0,
+            volume: 0,
+            holders_count: 0,
+            creator_earnings: 0,
+            protocol_fees: 0,
+            created_at: 0,
+            last_trade_at: 0,
+            curve_params: BondingCurveParams::default(),
+            creator_locked_amount: 0,
+            engagement_rewarded_earnings: 0,
+            grace_exit_until: 0,
+            grace_opened_at: 0,
+            trading_open_at: 0,
+            trading_close_at: 0,
+            reserved: [0; 8],
+            trade_count: 0,
+            mint: Pubkey::default(),
+            dividend_epochs: 0,
+            launch_auction: LaunchAuction::default(),
+            presale: Presale::default(),
+            launch_guard: LaunchGuard::default(),
+            trading_halted: false,
+            price_window: [PricePoint::default(); PRICE_WINDOW_LEN],
+            price_window_head: 0,
+            graduated_at: 0,
+            burned_keys: 0,
+            payment_mint: Pubkey::default(),
+            milestones_reached: 0,
+            milestone_bonuses: 0,
+        }
+    }
+
+    #[test]
+    fn test_buy_sell_symmetry_sweep() {
+        for supply in 0..40 {
+            for amount in 1..10 {
+                let (buy_cost, buy_creator_fee, buy_protocol_fee) =
+                    keys_at_supply(supply).calculate_buy_price(amount).unwrap();
+                let (sell_value, sell_creator_fee, sell_protocol_fee) =
+                    keys_at_supply(supply + amount).calculate_sell_price(amount).unwrap();
+
+                assert_eq!(buy_cost, sell_value, "supply={} amount={}", supply, amount);
+                assert_eq!(buy_creator_fee, sell_creator_fee);
+                assert_eq!(buy_protocol_fee, sell_protocol_fee);
+            }
+        }
+    }
+
+    #[test]
+    fn test_asymmetric_fee_market() {
+        let mut keys = keys_at_supply(10);
+        keys.curve_params.buy_creator_fee = 100; // 1%
+        keys.curve_params.buy_protocol_fee = 100; // 1%
+        keys.curve_params.sell_creator_fee = 800; // 8%
+        keys.curve_params.sell_protocol_fee = 400; // 4%
+
+        let (buy_cost, buy_creator_fee, buy_protocol_fee) = keys.calculate_buy_price(1).unwrap();
+        assert_eq!(buy_creator_fee, buy_cost / 100);
+        assert_eq!(buy_protocol_fee, buy_cost / 100);
+
+        let (sell_value, sell_creator_fee, sell_protocol_fee) = keys.calculate_sell_price(1).unwrap();
+        assert_eq!(sell_creator_fee, sell_value * 8 / 100);
+        assert_eq!(sell_protocol_fee, sell_value * 4 / 100);
+    }
+
+    #[test]
+    fn test_curve_params_fee_validation() {
+        assert!(BondingCurveParams::default().validate().is_ok());
+
+        let mut params = BondingCurveParams::default();
+        params.sell_creator_fee = 1500;
+        params.sell_protocol_fee = 500;
+        assert!(params.validate().is_err());
+
+        // The buy side is validated independently
+        let mut params = BondingCurveParams::default();
+        params.buy_protocol_fee = 2000;
+        assert!(params.validate().is_err());
+    }
+
+    #[test]
+    fn test_sell_last_key_refunds_base_price() {
+        let keys = keys_at_supply(1);
+        let (value, _, _) = keys.calculate_sell_price(1).unwrap();
+        assert_eq!(value, keys.curve_params.base_price);
+    }
+
+    #[test]
+    fn test_sell_more_than_supply_returns_zero() {
+        assert_eq!(keys_at_supply(3).calculate_sell_price(4).unwrap(), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_crash_opens_grace_window_once_per_cooldown() {
+        let mut keys = keys_at_supply(50);
+        let now = 1_000_000;
+
+        // A 20% dip doesn't reach a 30% threshold
+        assert!(!keys.maybe_open_grace_window(2_000, 3_000, 3_600, now));
+        assert!(!keys.in_grace_window(now));
+
+        // A 40% crash opens the window
+        assert!(keys.maybe_open_grace_window(4_000, 3_000, 3_600, now));
+        assert!(keys.in_grace_window(now + 3_599));
+        assert!(!keys.in_grace_window(now + 3_600));
+
+        // A second crash inside the cooldown doesn't re-open it
+        assert!(!keys.maybe_open_grace_window(5_000, 3_000, 3_600, now + 7_200));
+
+        let after_cooldown = now + UserKeys::GRACE_COOLDOWN_SECONDS;
+        assert!(keys.maybe_open_grace_window(5_000, 3_000, 3_600, after_cooldown));
+
+        // A zero threshold disables the mechanism
+        let mut disabled = keys_at_supply(50);
+        assert!(!disabled.maybe_open_grace_window(9_000, 0, 3_600, now));
+    }
+
+    #[test]
+    fn test_presets_price_sanely_across_supplies() {
+        for preset in [CurvePreset::Conservative, CurvePreset::Standard, CurvePreset::Aggressive] {
+            let params = CurveChoice::Preset(preset).resolve().unwrap();
+            let mut keys = keys_at_supply(0);
+            keys.curve_params = params;
+
+            assert_eq!(keys.calculate_price(0).unwrap(), params.base_price);
+
+            let mut previous = 0;
+            for supply in (0..params.max_supply).step_by(25) {
+                let price = keys.calculate_price(supply).unwrap();
+                assert!(price > previous, "{:?} not increasing at {}", preset, supply);
+                previous = price;
+            }
+
+            // Top of the curve stays around 20 SOL
+            let top = keys.calculate_price(params.max_supply - 1).unwrap();
+            assert!(top > 10_000_000_000 && top < 40_000_000_000, "{:?} tops out at {}", preset, top);
+        }
+    }
+
+    #[test]
+    fn test_resolve_with_applies_config_base_price_and_fee_cap() {
+        let preset = CurveChoice::Preset(CurvePreset::Standard).resolve_with(2_000_000, MAX_TOTAL_FEE_BPS).unwrap();
+        assert_eq!(preset.base_price, 2_000_000);
+
+        // Custom curves keep their own base price
+        let custom = CurveChoice::Custom(BondingCurveParams::default());
+        assert_eq!(custom.resolve_with(2_000_000, MAX_TOTAL_FEE_BPS).unwrap().base_price, 1_000_000);
+
+        // Default fees are 7.5% a side plus the referrer's 1%
+        assert!(custom.resolve_with(1_000_000, 850).is_ok());
+        assert!(custom.resolve_with(1_000_000, 849).is_err());
+    }
+
+    #[test]
+    fn test_custom_curve_rejects_runaway_factor() {
+        let runaway = BondingCurveParams {
+            price_factor: 10_000_000,
+            ..BondingCurveParams::default()
+        };
+        assert!(CurveChoice::Custom(runaway).resolve().is_err());
+
+        let decreasing = BondingCurveParams {
+            price_factor: 900_000,
+            ..BondingCurveParams::default()
+        };
+        assert!(CurveChoice::Custom(decreasing).resolve().is_err());
+
+        assert!(CurveChoice::Custom(BondingCurveParams::default()).resolve().is_ok());
+
+        let greedy = BondingCurveParams {
+            transfer_royalty_bps: BondingCurveParams::MAX_TRANSFER_ROYALTY_BPS + 1,
+            ..BondingCurveParams::default()
+        };
+        assert!(CurveChoice::Custom(greedy).resolve().is_err());
+    }
+
+    #[test]
+    fn test_early_sell_fee_decays_to_base() {
+        let params = BondingCurveParams {
+            early_sell_fee_bps: 1_000,
+            sell_fee_decay_seconds: 7 * 24 * 60 * 60,
+            ..BondingCurveParams::default()
+        };
+        assert!(params.validate().is_ok());
+
+        let week = params.sell_fee_decay_seconds;
+        assert_eq!(params.sell_creator_fee_at(0, 0), 1_500);
+        assert_eq!(params.sell_creator_fee_at(0, week / 2), 1_000);
+        assert_eq!(params.sell_creator_fee_at(0, week), 500);
+        assert_eq!(params.sell_creator_fee_at(0, week * 2), 500);
+        // A clock behind the purchase counts as no time held
+        assert_eq!(params.sell_creator_fee_at(100, 0), 1_500);
+
+        assert!(BondingCurveParams { sell_fee_decay_seconds: 0, ..params }.validate().is_err());
+        assert!(BondingCurveParams {
+            sell_fee_decay_seconds: BondingCurveParams::MAX_SELL_FEE_DECAY_SECONDS + 1,
+            ..params
+        }
+        .validate()
+        .is_err());
+        // 2.5% protocol + 5% creator + 1% referrer leaves 11.5% of the 20% cap for flip tax
+        assert!(BondingCurveParams { early_sell_fee_bps: 1_300, ..params }.validate().is_err());
+        assert_eq!(BondingCurveParams::default().sell_creator_fee_at(0, 0), 500);
+    }
+
+    #[test]
+    fn test_trading_hours_window() {
+        let mut keys = keys_at_supply(5);
+        assert!(keys.is_trading_open(0));
+        assert!(keys.is_trading_open(i64::MAX));
+
+        keys.set_trading_hours(1_000, 2_000).unwrap();
+        assert!(!keys.is_trading_open(999));
+        assert!(keys.is_trading_open(1_000));
+        assert!(keys.is_trading_open(1_999));
+        assert!(!keys.is_trading_open(2_000));
+
+        // Open-ended on either side
+        keys.set_trading_hours(0, 2_000).unwrap();
+        assert!(keys.is_trading_open(0));
+        keys.set_trading_hours(1_000, 0).unwrap();
+        assert!(keys.is_trading_open(i64::MAX));
+
+        assert!(keys.set_trading_hours(2_000, 1_000).is_err());
+        assert!(keys.set_trading_hours(-1, 0).is_err());
+    }
+
+    #[test]
+    fn test_circuit_breaker_trips_on_fast_moves() {
+        let mut keys = keys_at_supply(10);
+        assert_eq!(keys.record_price_point(1_000_000, 100, 2_000, 600), None);
+        assert_eq!(keys.record_price_point(1_150_000, 200, 2_000, 600), None);
+        assert!(keys.is_trading_open(200));
+
+        // 30% above the first point, still inside the window
+        assert_eq!(keys.record_price_point(1_300_000, 300, 2_000, 600), Some(3_000));
+        assert!(!keys.is_trading_open(300));
+
+        keys.set_trading_halted(false);
+        assert!(keys.is_trading_open(300));
+        assert_eq!(keys.record_price_point(1_300_000, 400, 2_000, 600), None);
+
+        // Points older than the window are ignored
+        assert_eq!(keys.record_price_point(2_000_000, 1_100, 2_000, 600), None);
+
+        // Disabled breakers never trip
+        assert_eq!(keys.record_price_point(10_000_000, 1_101, 0, 600), None);
+        assert!(keys.is_trading_open(1_101));
+    }
+
+    #[test]
+    fn test_graduation_closes_the_curve_for_good() {
+        let mut keys = keys_at_supply(10);
+        let spot = keys.calculate_price(10).unwrap();
+        assert_eq!(keys.market_cap().unwrap(), spot * 10);
+
+        keys.graduated_at = 500;
+        assert!(!keys.is_trading_open(500));
+        // Resuming a halt doesn't reopen a graduated market
+        keys.set_trading_halted(false);
+        assert!(!keys.is_trading_open(i64::MAX));
+    }
+
+    #[test]
+    fn test_launch_auction_keeps_curve_closed_until_sold_out_or_over() {
+        let mut keys = keys_at_supply(1);
+        let params = LaunchAuctionParams {
+            keys_for_sale: 5,
+            start_price: 50_000_000,
+            floor_price: 5_000_000,
+            duration_seconds: 600,
+        };
+        keys.launch_auction = LaunchAuction::new(&params, 1_000).unwrap();
+        assert!(!keys.is_trading_open(1_000));
+        assert!(keys.is_trading_open(1_600));
+
+        keys.launch_auction.record_sale(5).unwrap();
+        assert!(keys.is_trading_open(1_001));
+    }
+
+    #[test]
+    fn test_holder_cap() {
+        let mut params = BondingCurveParams::default();
+        assert!(params.within_holder_cap(1_000_000, 1_000_000));
+
+        params.max_keys_per_holder = 5;
+        assert!(params.within_holder_cap(3, 2));
+        assert!(!params.within_holder_cap(3, 3));
+        assert!(!params.within_holder_cap(u64::MAX, 1));
+
+        assert_eq!(params.holder_room(3), 2);
+        assert_eq!(params.holder_room(5), 0);
+        assert_eq!(params.holder_room(7), 0);
+        params.max_keys_per_holder = 0;
+        assert_eq!(params.holder_room(1_000_000), u64::MAX);
+    }
+
+    #[test]
+    fn test_runaway_curve_errors_instead_of_capping() {
+        // A 2x curve blows through u64 long before its max supply
+        let mut keys = keys_at_supply(0);
+        keys.curve_params.price_factor = BondingCurveParams::MAX_PRICE_FACTOR;
+        assert!(keys.calculate_price(40).is_ok());
+        assert!(keys.calculate_price(100).is_err());
+        keys.supply = 90;
+        assert!(keys.calculate_buy_price(1).is_err());
+
+        // A creator-set cap is enforced exactly
+        keys.curve_params.max_price_per_key = 8_000_000;
+        assert_eq!(keys.calculate_price(3).unwrap(), 8_000_000);
+        assert!(keys.calculate_price(4).is_err());
+        keys.supply = 2;
+        assert!(keys.calculate_buy_price(2).is_ok());
+        assert!(keys.calculate_buy_price(3).is_err());
+    }
+
+    #[test]
+    fn test_price_cap_must_admit_base_price() {
+        let mut params = BondingCurveParams::default();
+        params.max_price_per_key = params.base_price - 1;
+        assert!(params.validate().is_err());
+
+        params.max_price_per_key = params.base_price;
+        assert!(params.validate().is_ok());
+    }
+
+    #[test]
+    fn test_single_pass_sum_matches_per_key_prices() {
+        for preset in [CurvePreset::Conservative, CurvePreset::Standard, CurvePreset::Aggressive] {
+            let mut keys = keys_at_supply(0);
+            keys.curve_params = preset.params();
+
+            for (start, amount) in [(0, 1), (0, 50), (17, 3), (250, 40), (450, 50)] {
+                let per_key: u64 = (start..start + amount)
+                    .map(|supply| keys.calculate_price(supply).unwrap())
+                    .sum();
+                keys.supply = start;
+                assert_eq!(keys.calculate_buy_price(amount).unwrap().0, per_key, "{:?} {}+{}", preset, start, amount);
+            }
+        }
+    }
+
+    #[test]
+    fn test_quadratic_market_quotes_from_shared_curve() {
+        let mut keys = keys_at_supply(20);
+        keys.curve_params.curve_kind = CurveKind::Quadratic;
+        keys.curve_params.price_factor = 0; // unused by the quadratic curve
+        assert!(keys.curve_params.validate().is_ok());
+
+        let (cost, _, _) = keys.calculate_buy_price(5).unwrap();
+        assert_eq!(cost, crate::utils::bonding_curve::BondingCurve::get_buy_price(20, 5).unwrap());
+
+        keys.supply = 25;
+        let (value, _, _) = keys.calculate_sell_price(5).unwrap();
+        assert_eq!(value, cost);
+    }
+
+    #[test]
+    fn test_curve_kind_parameter_validation() {
+        let linear = BondingCurveParams {
+            curve_kind: CurveKind::Linear,
+            slope: 5_000,
+            ..BondingCurveParams::default()
+        };
+        assert!(linear.validate().is_ok());
+        assert!(BondingCurveParams { slope: 0, ..linear }.validate().is_err());
+        // The top key would pass the u64 limit
+        assert!(BondingCurveParams { slope: u64::MAX / 1_000, ..linear }.validate().is_err());
+
+        let sigmoid = BondingCurveParams {
+            curve_kind: CurveKind::Sigmoid,
+            midpoint: 500,
+            ceiling_price: 20_000_000_000,
+            max_supply: 2_000,
+            ..BondingCurveParams::default()
+        };
+        assert!(sigmoid.validate().is_ok());
+        assert!(BondingCurveParams { midpoint: 0, ..sigmoid }.validate().is_err());
+        assert!(BondingCurveParams { ceiling_price: sigmoid.base_price, ..sigmoid }.validate().is_err());
+        assert!(BondingCurveParams { max_supply: BondingCurve::MAX_SUPPLY + 1, ..sigmoid }.validate().is_err());
+
+        // Exponential-only bounds don't apply to the other kinds
+        assert!(BondingCurveParams { price_factor: 0, ..sigmoid }.validate().is_ok());
+    }
+
+    #[test]
+    fn test_holders_count_tracks_zero_crossings() {
+        let mut keys = keys_at_supply(10);
+
+        keys.track_holder_balance(0, 3).unwrap();
+        keys.track_holder_balance(3, 5).unwrap();
+        assert_eq!(keys.holders_count, 1);
+
+        keys.track_holder_balance(0, 1).unwrap();
+        keys.track_holder_balance(5, 2).unwrap();
+        assert_eq!(keys.holders_count, 2);
+
+        keys.track_holder_balance(2, 0).unwrap();
+        keys.track_holder_balance(1, 0).unwrap();
+        assert_eq!(keys.holders_count, 0);
+
+        // A balance that never held anything can't go below zero
+        assert!(keys.track_holder_balance(1, 0).is_err());
+    }
+
+    #[test]
+    fn test_trade_index_is_sequential_and_pda_unique() {
+        let mut keys = keys_at_supply(1);
+        assert_eq!(keys.next_trade_index().unwrap(), 0);
+        assert_eq!(keys.next_trade_index().unwrap(), 1);
+        assert_eq!(keys.trade_count, 2);
+
+        let program_id = Pubkey::new_unique();
+        let trader = Pubkey::new_unique();
+        let (first, _) = get_key_transaction_pda(&keys.user, &trader, 0, &program_id);
+        let (second, _) = get_key_transaction_pda(&keys.user, &trader, 1, &program_id);
+        assert_ne!(first, second);
+
+        keys.trade_count = u64::MAX;
+        assert!(keys.next_trade_index().is_err());
+    }
+
+    #[test]
+    fn test_buyback_burns_leave_supply_and_circulation_unchanged() {
+        let mut keys = keys_at_supply(10);
+        keys.creator_locked_amount = 1;
+        assert_eq!(keys.circulating_supply(), 9);
+
+        // A buyback of 3 buys and burns in one step, so only the tally moves
+        keys.burned_keys += 3;
+        assert_eq!(keys.supply, 10);
+        assert_eq!(keys.circulating_supply(), 9);
+    }
+
+    #[test]
+    fn test_record_trade_books_volume_fees_and_price() {
+        let mut keys = keys_at_supply(10);
+        assert_eq!(keys.record_trade(5_000, 250, 100, 42).unwrap(), 0);
+        keys.supply = 12;
+        assert_eq!(keys.record_trade(7_000, 350, 140, 50).unwrap(), 1);
+
+        assert_eq!(keys.volume, 12_000);
+        assert_eq!(keys.creator_earnings, 600);
+        assert_eq!(keys.protocol_fees, 240);
+        assert_eq!(keys.last_trade_at, 50);
+        assert_eq!(keys.price, keys.calculate_price(12).unwrap());
+    }
+
+    #[test]
+    fn test_round_trips_within_one_slot_are_rejected() {
+        let mut holder = KeyHolder {
+            holder: Pubkey::new_unique(),
+            keys_user: Pubkey::new_unique(),
+            amount: 5,
+            avg_price: 0,
+            total_invested: 0,
+            first_purchase_at: 0,
+            last_purchase_at: 0,
+            last_buy_slot: 0,
+            last_sell_slot: 0,
+            reserved: [0; 16],
+        };
+        assert!(holder.require_no_round_trip(100, true).is_ok());
+        assert!(holder.require_no_round_trip(100, false).is_ok());
+
+        holder.last_buy_slot = 100;
+        assert!(holder.require_no_round_trip(100, true).is_ok());
+        assert!(holder.require_no_round_trip(100, false).is_err());
+        assert!(holder.require_no_round_trip(101, false).is_ok());
+
+        holder.last_sell_slot = 101;
+        assert!(holder.require_no_round_trip(101, true).is_err());
+        assert!(holder.require_no_round_trip(102, true).is_ok());
+
+        // Recording a trade checks it and remembers its slot for the next one
+        assert!(holder.record_trade_slot(102, true).is_ok());
+        assert_eq!(holder.last_buy_slot, 102);
+        assert!(holder.record_trade_slot(102, false).is_err());
+        assert_eq!(holder.last_sell_slot, 101);
+    }
+
+    #[test]
+    fn test_payment_mint_is_set_once_before_trading() {
+        let mut keys = keys_at_supply(1);
+        keys.mint = Pubkey::new_unique();
+        assert!(!keys.settles_in_token());
+        assert!(keys.require_lamport_settlement().is_ok());
+
+        // Neither unset nor the market's own keys
+        assert!(keys.set_payment_mint(Pubkey::default()).is_err());
+        assert!(keys.set_payment_mint(keys.mint).is_err());
+
+        let usdc = Pubkey::new_unique();
+        keys.set_payment_mint(usdc).unwrap();
+        assert!(keys.settles_in_token());
+        assert!(keys.require_lamport_settlement().is_err());
+        assert!(keys.set_payment_mint(Pubkey::new_unique()).is_err());
+
+        // A market that has traded keeps settling in lamports
+        let mut traded = keys_at_supply(1);
+        traded.next_trade_index().unwrap();
+        assert!(traded.set_payment_mint(usdc).is_err());
+    }
+
+    #[test]
+    fn test_milestones_are_reached_once() {
+        let table = MilestoneTable::standard();
+        let mut keys = keys_at_supply(99);
+        assert_eq!(keys.reach_milestones(&table), 0);
+
+        // One trade may cross several thresholds
+        keys.supply = 1_500;
+        assert_eq!(keys.reach_milestones(&table), 0b11);
+        assert_eq!(keys.reach_milestones(&table), 0);
+
+        // Dropping back below a threshold doesn't re-arm it
+        keys.supply = 50;
+        keys.reach_milestones(&table);
+        keys.supply = 150;
+        assert_eq!(keys.reach_milestones(&table), 0);
+    }
+}
 ```
\ No newline at end of file
@@ -0,0 +1,155 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::error::SolSocialError;
+
+/// Launch phase a creator may request in `create_keys`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct LaunchAuctionParams {
+    /// Keys sold through the auction before the curve opens
+    pub keys_for_sale: u64,
+    /// Per-key price when the auction opens, in lamports
+    pub start_price: u64,
+    /// Per-key price the auction decays to, in lamports
+    pub floor_price: u64,
+    /// How long the price takes to fall from start to floor
+    pub duration_seconds: i64,
+}
+
+/// Dutch auction for a market's first keys. The per-key price falls linearly from
+/// `start_price` to `floor_price`; the bonding curve stays closed until it sells out or ends.
+/// A zeroed auction means the market launched straight onto the curve.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct LaunchAuction {
+    /// Keys offered through the auction
+    pub keys_for_sale: u64,
+    /// Keys bought so far
+    pub keys_sold: u64,
+    /// Per-key price at `start_at`
+    pub start_price: u64,
+    /// Per-key price from `end_at` onwards
+    pub floor_price: u64,
+    /// Timestamp when the auction opened
+    pub start_at: i64,
+    /// Timestamp when the auction closes and the curve opens
+    pub end_at: i64,
+}
+
+impl LaunchAuction {
+    pub const LEN: usize = 8 + // keys_for_sale
+        8 + // keys_sold
+        8 + // start_price
+        8 + // floor_price
+        8 + // start_at
+        8; // end_at
+
+    /// Longest a launch auction may hold the curve closed
+    pub const MAX_DURATION_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+    pub fn new(params: &LaunchAuctionParams, now: i64) -> Result<Self> {
+        require!(params.keys_for_sale > 0, SolSocialError::InvalidLaunchAuction);
+        require!(
+            params.floor_price > 0 && params.start_price > params.floor_price,
+            SolSocialError::InvalidLaunchAuction
+        );
+        require!(
+            params.duration_seconds > 0 && params.duration_seconds <= Self::MAX_DURATION_SECONDS,
+            SolSocialError::InvalidLaunchAuction
+        );
+
+        Ok(Self {
+            keys_for_sale: params.keys_for_sale,
+            keys_sold: 0,
+            start_price: params.start_price,
+            floor_price: params.floor_price,
+            start_at: now,
+            end_at: now + params.duration_seconds,
+        })
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.keys_for_sale.saturating_sub(self.keys_sold)
+    }
+
+    /// Whether the auction is still selling keys at `now`
+    pub fn is_live(&self, now: i64) -> bool {
+        self.remaining() > 0 && now < self.end_at
+    }
+
+    /// Per-key price at `now`, falling linearly to the floor over the auction
+    pub fn current_price(&self, now: i64) -> u64 {
+        if now <= self.start_at {
+            return self.start_price;
+        }
+        if now >= self.end_at {
+            return self.floor_price;
+        }
+
+        let elapsed = (now - self.start_at) as u128;
+        let duration = (self.end_at - self.start_at) as u128;
+        let drop = (self.start_price - self.floor_price) as u128 * elapsed / duration;
+        self.start_price - drop as u64
+    }
+
+    pub fn record_sale(&mut self, amount: u64) -> Result<()> {
+        require!(amount <= self.remaining(), SolSocialError::InsufficientKeys);
+        self.keys_sold += amount;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auction() -> LaunchAuction {
+        let params = LaunchAuctionParams {
+            keys_for_sale: 10,
+            start_price: 10_000_000,
+            floor_price: 1_000_000,
+            duration_seconds: 3_600,
+        };
+        LaunchAuction::new(&params, 1_000).unwrap()
+    }
+
+    #[test]
+    fn test_price_decays_linearly_to_floor() {
+        let auction = auction();
+        assert_eq!(auction.current_price(1_000), 10_000_000);
+        assert_eq!(auction.current_price(1_000 + 1_800), 5_500_000);
+        assert_eq!(auction.current_price(1_000 + 3_600), 1_000_000);
+        assert_eq!(auction.current_price(1_000 + 7_200), 1_000_000);
+    }
+
+    #[test]
+    fn test_auction_ends_on_sellout_or_expiry() {
+        let mut auction = auction();
+        assert!(auction.is_live(1_000));
+        assert!(!auction.is_live(1_000 + 3_600));
+
+        auction.record_sale(4).unwrap();
+        assert!(auction.record_sale(7).is_err());
+        auction.record_sale(6).unwrap();
+        assert!(!auction.is_live(1_001));
+
+        assert!(!LaunchAuction::default().is_live(0));
+    }
+
+    #[test]
+    fn test_rejects_rising_or_unbounded_auctions() {
+        let valid = LaunchAuctionParams {
+            keys_for_sale: 10,
+            start_price: 10_000_000,
+            floor_price: 1_000_000,
+            duration_seconds: 3_600,
+        };
+        assert!(LaunchAuction::new(&LaunchAuctionParams { start_price: 1_000_000, ..valid }, 0).is_err());
+        assert!(LaunchAuction::new(&LaunchAuctionParams { floor_price: 0, ..valid }, 0).is_err());
+        assert!(LaunchAuction::new(&LaunchAuctionParams { keys_for_sale: 0, ..valid }, 0).is_err());
+        assert!(LaunchAuction::new(
+            &LaunchAuctionParams { duration_seconds: LaunchAuction::MAX_DURATION_SECONDS + 1, ..valid },
+            0
+        )
+        .is_err());
+    }
+}
+```
\ No newline at end of file
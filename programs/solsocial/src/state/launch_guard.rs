@@ -0,0 +1,116 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::error::SolSocialError;
+
+/// Anti-sniper guard a creator may configure in `create_keys`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct LaunchGuardParams {
+    /// Slots after creation during which the guard applies
+    pub guard_slots: u64,
+    /// Most keys one wallet may hold while the guard applies
+    pub max_buy_per_wallet: u64,
+    /// Slots a wallet must wait between buys while the guard applies (0 = no cooldown)
+    pub cooldown_slots: u64,
+}
+
+/// Limits on the first slots of a market, when the curve is cheapest and bots sweep it.
+/// While active, wallets may only hold `max_buy_per_wallet` keys and must space their buys
+/// `cooldown_slots` apart. A zeroed guard means the market launched unguarded.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct LaunchGuard {
+    /// Slot the market was created in
+    pub start_slot: u64,
+    /// Slots after `start_slot` during which the guard applies
+    pub guard_slots: u64,
+    /// Most keys one wallet may hold while the guard applies
+    pub max_buy_per_wallet: u64,
+    /// Slots a wallet must wait between buys while the guard applies
+    pub cooldown_slots: u64,
+}
+
+impl LaunchGuard {
+    pub const LEN: usize = 8 + // start_slot
+        8 + // guard_slots
+        8 + // max_buy_per_wallet
+        8; // cooldown_slots
+
+    /// Longest a guard may last, about an hour of slots
+    pub const MAX_GUARD_SLOTS: u64 = 9_000;
+
+    pub fn new(params: &LaunchGuardParams, slot: u64) -> Result<Self> {
+        require!(
+            params.guard_slots > 0 && params.guard_slots <= Self::MAX_GUARD_SLOTS,
+            SolSocialError::InvalidLaunchGuard
+        );
+        require!(params.max_buy_per_wallet > 0, SolSocialError::InvalidLaunchGuard);
+        require!(params.cooldown_slots <= params.guard_slots, SolSocialError::InvalidLaunchGuard);
+
+        Ok(Self {
+            start_slot: slot,
+            guard_slots: params.guard_slots,
+            max_buy_per_wallet: params.max_buy_per_wallet,
+            cooldown_slots: params.cooldown_slots,
+        })
+    }
+
+    pub fn is_active(&self, slot: u64) -> bool {
+        slot < self.start_slot.saturating_add(self.guard_slots)
+    }
+
+    /// Check a buy leaving the wallet with `held_after` keys, whose previous buy landed
+    /// in `last_buy_slot` (0 = never bought)
+    pub fn check_buy(&self, slot: u64, held_after: u64, last_buy_slot: u64) -> Result<()> {
+        if !self.is_active(slot) {
+            return Ok(());
+        }
+
+        require!(held_after <= self.max_buy_per_wallet, SolSocialError::LaunchBuyCapReached);
+        require!(
+            last_buy_slot == 0 || slot >= last_buy_slot.saturating_add(self.cooldown_slots),
+            SolSocialError::LaunchCooldownActive
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard() -> LaunchGuard {
+        let params = LaunchGuardParams { guard_slots: 150, max_buy_per_wallet: 3, cooldown_slots: 10 };
+        LaunchGuard::new(&params, 1_000).unwrap()
+    }
+
+    #[test]
+    fn test_caps_and_spaces_buys_while_active() {
+        let guard = guard();
+        guard.check_buy(1_000, 3, 0).unwrap();
+        assert!(guard.check_buy(1_000, 4, 0).is_err());
+
+        assert!(guard.check_buy(1_005, 2, 1_000).is_err());
+        guard.check_buy(1_010, 2, 1_000).unwrap();
+    }
+
+    #[test]
+    fn test_lifts_after_guard_window() {
+        let guard = guard();
+        assert!(guard.is_active(1_149));
+        assert!(!guard.is_active(1_150));
+        guard.check_buy(1_150, 100, 1_149).unwrap();
+
+        // Unguarded markets never restrict buys
+        assert!(!LaunchGuard::default().is_active(0));
+        LaunchGuard::default().check_buy(0, u64::MAX, 0).unwrap();
+    }
+
+    #[test]
+    fn test_rejects_invalid_guards() {
+        let valid = LaunchGuardParams { guard_slots: 150, max_buy_per_wallet: 3, cooldown_slots: 10 };
+        assert!(LaunchGuard::new(&LaunchGuardParams { guard_slots: 0, ..valid }, 0).is_err());
+        assert!(LaunchGuard::new(&LaunchGuardParams { guard_slots: LaunchGuard::MAX_GUARD_SLOTS + 1, ..valid }, 0).is_err());
+        assert!(LaunchGuard::new(&LaunchGuardParams { max_buy_per_wallet: 0, ..valid }, 0).is_err());
+        assert!(LaunchGuard::new(&LaunchGuardParams { cooldown_slots: 151, ..valid }, 0).is_err());
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,233 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::error::SolSocialError;
+
+/// A lender's keys lent against SOL collateral, valued through the market's price oracle
+#[account]
+pub struct KeyLoan {
+    /// The wallet lending the keys
+    pub lender: Pubkey,
+    /// The user whose keys are lent
+    pub keys_user: Pubkey,
+    /// The wallet that took the loan, or default while the offer is open
+    pub borrower: Pubkey,
+    /// Number of keys lent
+    pub amount: u64,
+    /// Collateral the borrower must post, as bps of the keys' oracle value
+    pub collateral_ratio_bps: u16,
+    /// Collateral below this share of the keys' oracle value can be liquidated
+    pub liquidation_ratio_bps: u16,
+    /// Flat fee on the posted collateral paid to the lender on repayment
+    pub fee_bps: u16,
+    /// How long the borrower has to repay once the loan is taken
+    pub duration_seconds: i64,
+    /// Lamports of collateral held in this account
+    pub collateral: u64,
+    /// Per-lender loan sequence number used in the PDA seeds
+    pub loan_id: u64,
+    /// Timestamp when the offer was made
+    pub created_at: i64,
+    /// Timestamp when the loan was taken, or 0 while open
+    pub taken_at: i64,
+    /// Timestamp after which the loan can be liquidated regardless of collateral
+    pub due_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl KeyLoan {
+    /// Longest term a loan can run
+    pub const MAX_DURATION_SECONDS: i64 = 90 * 24 * 60 * 60;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // lender
+        32 + // keys_user
+        32 + // borrower
+        8 + // amount
+        2 + // collateral_ratio_bps
+        2 + // liquidation_ratio_bps
+        2 + // fee_bps
+        8 + // duration_seconds
+        8 + // collateral
+        8 + // loan_id
+        8 + // created_at
+        8 + // taken_at
+        8 + // due_at
+        1; // bump
+
+    /// Open an offer. Loans must be over-collateralised at the liquidation line and
+    /// the opening ratio must sit above it, so a fresh loan is never liquidatable.
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        lender: Pubkey,
+        keys_user: Pubkey,
+        amount: u64,
+        collateral_ratio_bps: u16,
+        liquidation_ratio_bps: u16,
+        fee_bps: u16,
+        duration_seconds: i64,
+        loan_id: u64,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            amount > 0
+                && liquidation_ratio_bps >= 10_000
+                && collateral_ratio_bps > liquidation_ratio_bps
+                && fee_bps <= 10_000
+                && duration_seconds > 0
+                && duration_seconds <= Self::MAX_DURATION_SECONDS,
+            SolSocialError::InvalidLoanTerms
+        );
+
+        self.lender = lender;
+        self.keys_user = keys_user;
+        self.borrower = Pubkey::default();
+        self.amount = amount;
+        self.collateral_ratio_bps = collateral_ratio_bps;
+        self.liquidation_ratio_bps = liquidation_ratio_bps;
+        self.fee_bps = fee_bps;
+        self.duration_seconds = duration_seconds;
+        self.collateral = 0;
+        self.loan_id = loan_id;
+        self.created_at = now;
+        self.taken_at = 0;
+        self.due_at = 0;
+        self.bump = bump;
+        Ok(())
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.borrower == Pubkey::default()
+    }
+
+    /// Collateral needed to cover the lent keys at `price` per key and `ratio_bps`
+    pub fn required_collateral(&self, price: u64, ratio_bps: u16) -> Result<u64> {
+        let required = (self.amount as u128)
+            .checked_mul(price as u128)
+            .and_then(|value| value.checked_mul(ratio_bps as u128))
+            .map(|scaled| scaled / 10_000)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
+        u64::try_from(required).map_err(|_| error!(SolSocialError::ArithmeticOverflow))
+    }
+
+    /// Hand the loan to `borrower`, who posts `collateral` against the keys at `price`
+    pub fn take(&mut self, borrower: Pubkey, collateral: u64, price: u64, now: i64) -> Result<()> {
+        require!(self.is_open(), SolSocialError::LoanAlreadyTaken);
+        require!(borrower != self.lender, SolSocialError::InvalidLoanTerms);
+        require!(
+            collateral >= self.required_collateral(price, self.collateral_ratio_bps)?,
+            SolSocialError::InsufficientCollateral
+        );
+
+        self.borrower = borrower;
+        self.collateral = collateral;
+        self.taken_at = now;
+        self.due_at = now
+            .checked_add(self.duration_seconds)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// The lender's cut of the collateral on repayment
+    pub fn fee(&self) -> u64 {
+        (self.collateral as u128 * self.fee_bps as u128 / 10_000) as u64
+    }
+
+    /// Overdue loans, and loans whose collateral fell below the liquidation line at
+    /// `price`, can be liquidated
+    pub fn is_liquidatable(&self, price: u64, now: i64) -> Result<bool> {
+        if self.is_open() {
+            return Ok(false);
+        }
+        if now > self.due_at {
+            return Ok(true);
+        }
+        Ok(self.collateral < self.required_collateral(price, self.liquidation_ratio_bps)?)
+    }
+}
+
+// Seeds for PDA derivation
+pub const KEY_LOAN_SEED: &[u8] = b"key_loan";
+pub const KEY_LOAN_ESCROW_SEED: &[u8] = b"key_loan_escrow";
+
+pub fn get_key_loan_pda(lender: &Pubkey, keys_user: &Pubkey, loan_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[KEY_LOAN_SEED, lender.as_ref(), keys_user.as_ref(), &loan_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+pub fn get_key_loan_escrow_pda(loan: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[KEY_LOAN_ESCROW_SEED, loan.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offer(lender: Pubkey) -> KeyLoan {
+        let mut loan = KeyLoan {
+            lender: Pubkey::default(),
+            keys_user: Pubkey::default(),
+            borrower: Pubkey::default(),
+            amount: 0,
+            collateral_ratio_bps: 0,
+            liquidation_ratio_bps: 0,
+            fee_bps: 0,
+            duration_seconds: 0,
+            collateral: 0,
+            loan_id: 0,
+            created_at: 0,
+            taken_at: 0,
+            due_at: 0,
+            bump: 0,
+        };
+        // 10 keys at 150% to open, 120% to liquidate, 2% fee, a day to repay
+        loan.initialize(lender, Pubkey::new_unique(), 10, 15_000, 12_000, 200, 86_400, 0, 0, 254).unwrap();
+        loan
+    }
+
+    #[test]
+    fn test_terms_must_keep_loans_over_collateralised() {
+        let mut loan = offer(Pubkey::new_unique());
+        let lender = loan.lender;
+        // Under-collateralised liquidation line, opening ratio at the line, or no term
+        assert!(loan.initialize(lender, Pubkey::new_unique(), 10, 15_000, 9_000, 0, 60, 0, 0, 254).is_err());
+        assert!(loan.initialize(lender, Pubkey::new_unique(), 10, 12_000, 12_000, 0, 60, 0, 0, 254).is_err());
+        assert!(loan.initialize(lender, Pubkey::new_unique(), 10, 15_000, 12_000, 0, 0, 0, 0, 254).is_err());
+        assert!(loan.initialize(lender, Pubkey::new_unique(), 0, 15_000, 12_000, 0, 60, 0, 0, 254).is_err());
+    }
+
+    #[test]
+    fn test_take_requires_the_opening_collateral() {
+        let mut loan = offer(Pubkey::new_unique());
+        let borrower = Pubkey::new_unique();
+
+        // 10 keys at 1_000 lamports each need 15_000 lamports at 150%
+        assert!(loan.take(borrower, 14_999, 1_000, 100).is_err());
+        assert!(loan.take(loan.lender, 15_000, 1_000, 100).is_err());
+        loan.take(borrower, 15_000, 1_000, 100).unwrap();
+        assert_eq!(loan.due_at, 86_500);
+        assert_eq!(loan.fee(), 300);
+
+        assert!(loan.take(Pubkey::new_unique(), 20_000, 1_000, 100).is_err());
+    }
+
+    #[test]
+    fn test_liquidation_on_price_rise_or_expiry() {
+        let mut loan = offer(Pubkey::new_unique());
+        assert!(!loan.is_liquidatable(1_000_000, 100).unwrap());
+
+        loan.take(Pubkey::new_unique(), 15_000, 1_000, 100).unwrap();
+        assert!(!loan.is_liquidatable(1_000, 100).unwrap());
+        // 15_000 covers 120% of 10 keys up to 1_250 lamports each
+        assert!(!loan.is_liquidatable(1_250, 100).unwrap());
+        assert!(loan.is_liquidatable(1_251, 100).unwrap());
+        // Overdue loans liquidate at any price
+        assert!(!loan.is_liquidatable(1_000, 86_500).unwrap());
+        assert!(loan.is_liquidatable(1, 86_501).unwrap());
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,127 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// Global count of key markets. Market `i` (in creation order) is listed at slot
+/// `i % PAGE_CAPACITY` of the [`KeyMarketRegistryPage`] numbered `i / PAGE_CAPACITY`, so
+/// clients enumerate every market by walking pages `0..=current_page()`.
+#[account]
+pub struct KeyMarketRegistry {
+    /// Markets registered so far
+    pub market_count: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl KeyMarketRegistry {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // market_count
+        1; // bump
+
+    /// Page the next market is appended to
+    pub fn current_page(&self) -> u64 {
+        self.market_count / KeyMarketRegistryPage::CAPACITY as u64
+    }
+
+    /// Append `market` to `page`, which must be the current page. Returns the market's index.
+    pub fn register(&mut self, page: &mut KeyMarketRegistryPage, market: Pubkey) -> Result<u64> {
+        require!(
+            page.page_index == self.current_page()
+                && page.count as u64 == self.market_count % KeyMarketRegistryPage::CAPACITY as u64,
+            crate::error::SolSocialError::InvalidAccountSequence
+        );
+        let index = self.market_count;
+        page.markets[page.count as usize] = market;
+        page.count += 1;
+        self.market_count = index
+            .checked_add(1)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        Ok(index)
+    }
+}
+
+/// One fixed-size page of market addresses, in creation order
+#[account]
+pub struct KeyMarketRegistryPage {
+    pub page_index: u64,
+    /// `UserKeys` markets; the first `count` are valid
+    pub markets: [Pubkey; KeyMarketRegistryPage::CAPACITY],
+    pub count: u8,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl KeyMarketRegistryPage {
+    pub const CAPACITY: usize = 64;
+
+    pub const LEN: usize = 8 + // discriminator
+        8 + // page_index
+        32 * Self::CAPACITY + // markets
+        1 + // count
+        1; // bump
+
+    pub fn initialize(&mut self, page_index: u64, bump: u8) {
+        self.page_index = page_index;
+        self.markets = [Pubkey::default(); Self::CAPACITY];
+        self.count = 0;
+        self.bump = bump;
+    }
+
+    pub fn listed(&self) -> &[Pubkey] {
+        &self.markets[..self.count as usize]
+    }
+}
+
+// Seeds for PDA derivation
+pub const KEY_MARKET_REGISTRY_SEED: &[u8] = b"key_market_registry";
+pub const KEY_MARKET_PAGE_SEED: &[u8] = b"key_market_page";
+
+pub fn get_key_market_registry_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[KEY_MARKET_REGISTRY_SEED], program_id)
+}
+
+pub fn get_key_market_page_pda(page_index: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[KEY_MARKET_PAGE_SEED, &page_index.to_le_bytes()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(page_index: u64) -> KeyMarketRegistryPage {
+        KeyMarketRegistryPage {
+            page_index,
+            markets: [Pubkey::default(); KeyMarketRegistryPage::CAPACITY],
+            count: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_markets_fill_pages_in_order() {
+        let mut registry = KeyMarketRegistry { market_count: 0, bump: 0 };
+        let mut first = page(0);
+        let markets: Vec<Pubkey> = (0..KeyMarketRegistryPage::CAPACITY).map(|_| Pubkey::new_unique()).collect();
+        for (i, market) in markets.iter().enumerate() {
+            assert_eq!(registry.register(&mut first, *market).unwrap(), i as u64);
+        }
+        assert_eq!(first.listed(), &markets[..]);
+
+        // A full page is done with; the next market goes on page 1
+        assert_eq!(registry.current_page(), 1);
+        assert!(registry.register(&mut first, Pubkey::new_unique()).is_err());
+        let mut second = page(1);
+        let next = Pubkey::new_unique();
+        assert_eq!(registry.register(&mut second, next).unwrap(), KeyMarketRegistryPage::CAPACITY as u64);
+        assert_eq!(second.listed(), &[next]);
+    }
+
+    #[test]
+    fn test_pages_have_distinct_addresses() {
+        let program_id = Pubkey::new_unique();
+        assert_ne!(
+            get_key_market_page_pda(0, &program_id).0,
+            get_key_market_page_pda(1, &program_id).0
+        );
+    }
+}
+```
\ No newline at end of file
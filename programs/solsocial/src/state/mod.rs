@@ -3,11 +3,87 @@ pub mod user;
 pub mod keys;
 pub mod post;
 pub mod chat;
+pub mod order;
+pub mod config;
+pub mod reward_pool;
+pub mod engagement;
+pub mod activity;
+pub mod recent_trades;
+pub mod vote;
+pub mod content_hash;
+pub mod tip_match;
+pub mod sell_queue;
+pub mod buy_commitment;
+pub mod top_markets;
+pub mod guardians;
+pub mod gift;
+pub mod stake;
+pub mod dividend;
+pub mod referral;
+pub mod earnings_vault;
+pub mod admin_multisig;
+pub mod pending_change;
+pub mod launch_auction;
+pub mod presale;
+pub mod launch_guard;
+pub mod otc_offer;
+pub mod price_oracle;
+pub mod vesting;
+pub mod market_registry;
+pub mod portfolio;
+pub mod trigger_order;
+pub mod dca;
+pub mod early_supporter;
+pub mod revenue_share;
+pub mod loan;
+pub mod raffle;
+pub mod hashtag;
+pub mod unlock;
+pub mod boost;
+pub mod bookmark;
 
 pub use user::*;
 pub use keys::*;
 pub use post::*;
 pub use chat::*;
+pub use order::*;
+pub use config::*;
+pub use reward_pool::*;
+pub use engagement::*;
+pub use activity::*;
+pub use recent_trades::*;
+pub use vote::*;
+pub use content_hash::*;
+pub use tip_match::*;
+pub use sell_queue::*;
+pub use buy_commitment::*;
+pub use top_markets::*;
+pub use guardians::*;
+pub use gift::*;
+pub use stake::*;
+pub use dividend::*;
+pub use referral::*;
+pub use earnings_vault::*;
+pub use admin_multisig::*;
+pub use pending_change::*;
+pub use launch_auction::*;
+pub use presale::*;
+pub use launch_guard::*;
+pub use otc_offer::*;
+pub use price_oracle::*;
+pub use vesting::*;
+pub use market_registry::*;
+pub use portfolio::*;
+pub use trigger_order::*;
+pub use dca::*;
+pub use early_supporter::*;
+pub use revenue_share::*;
+pub use loan::*;
+pub use raffle::*;
+pub use hashtag::*;
+pub use unlock::*;
+pub use boost::*;
+pub use bookmark::*;
 
 use anchor_lang::prelude::*;
 
@@ -43,20 +119,6 @@ pub enum MessageType {
     System,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub struct PricePoint {
-    pub supply: u64,
-    pub price: u64,
-    pub timestamp: i64,
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub struct RevenueShare {
-    pub creator_share: u64,
-    pub platform_share: u64,
-    pub referrer_share: u64,
-}
-
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub struct TradingStats {
     pub total_volume: u64,
@@ -93,6 +155,7 @@ pub const MAX_BIO_LENGTH: usize = 280;
 pub const MAX_POST_CONTENT_LENGTH: usize = 1000;
 pub const MAX_COMMENT_LENGTH: usize = 500;
 pub const MAX_MESSAGE_LENGTH: usize = 1000;
+pub const MAX_CHAT_ID_LENGTH: usize = 32;
 pub const MAX_CHAT_NAME_LENGTH: usize = 64;
 pub const MAX_CHAT_DESCRIPTION_LENGTH: usize = 200;
 pub const MAX_HASHTAGS: usize = 10;
@@ -127,18 +190,22 @@ pub struct MediaAttachment {
     pub height: Option<u32>,
 }
 
+impl MediaAttachment {
+    pub const MAX_URL_LENGTH: usize = 200;
+    pub const MAX_MEDIA_TYPE_LENGTH: usize = 32;
+    pub const SPACE: usize = 4 + Self::MAX_URL_LENGTH + // url
+        4 + Self::MAX_MEDIA_TYPE_LENGTH + // media_type
+        8 + // size
+        1 + 4 + // width
+        1 + 4; // height
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub struct PollOption {
     pub text: String,
     pub votes: u64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub struct Hashtag {
-    pub tag: String,
-    pub count: u64,
-}
-
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub struct Mention {
     pub username: String,
@@ -214,7 +281,7 @@ impl Default for PrivacySettings {
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
 pub struct ActivityMetrics {
     pub daily_active_users: u64,
     pub weekly_active_users: u64,
@@ -225,6 +292,10 @@ pub struct ActivityMetrics {
     pub last_updated: i64,
 }
 
+impl ActivityMetrics {
+    pub const SPACE: usize = 8 * 7;
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub struct PlatformStats {
     pub total_users: u64,
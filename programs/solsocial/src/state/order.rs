@@ -0,0 +1,289 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::KeyHolder;
+
+#[account]
+pub struct LimitOrder {
+    /// The wallet that placed the order
+    pub owner: Pubkey,
+    /// The user whose keys are being traded
+    pub keys_user: Pubkey,
+    /// Whether the order buys or sells keys
+    pub side: OrderSide,
+    /// Limit price per key (in lamports)
+    pub price_per_key: u64,
+    /// Number of keys still open on the order
+    pub amount: u64,
+    /// Lamports escrowed in this account for buy orders: the limit plus the buy fees on it
+    pub escrowed_lamports: u64,
+    /// Keys moved from the owner's KeyHolder into the order's escrow token account for sell orders
+    pub escrowed_keys: u64,
+    /// Per-owner order sequence number used in the PDA seeds
+    pub order_id: u64,
+    /// Timestamp when the order was placed
+    pub created_at: i64,
+    /// Timestamp after which the order can no longer be filled
+    pub expires_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved space for future upgrades
+    pub reserved: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+impl LimitOrder {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        32 + // keys_user
+        1 + // side
+        8 + // price_per_key
+        8 + // amount
+        8 + // escrowed_lamports
+        8 + // escrowed_keys
+        8 + // order_id
+        8 + // created_at
+        8 + // expires_at
+        1 + // bump
+        32; // reserved
+
+    /// Lamports paid to a keeper for every expired order swept
+    pub const KEEPER_FEE: u64 = 5_000;
+
+    /// Maximum number of orders a single sweep may process
+    pub const MAX_SWEEP_BATCH: usize = 20;
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.expires_at
+    }
+
+    /// Open an order; buy orders escrow `price_per_key * amount` lamports, sell orders `amount` keys
+    pub fn initialize(
+        &mut self,
+        owner: Pubkey,
+        keys_user: Pubkey,
+        side: OrderSide,
+        price_per_key: u64,
+        amount: u64,
+        order_id: u64,
+        now: i64,
+        expires_at: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            amount > 0 && price_per_key > 0 && expires_at > now,
+            crate::error::SolSocialError::InvalidOrder
+        );
+
+        self.owner = owner;
+        self.keys_user = keys_user;
+        self.side = side;
+        self.price_per_key = price_per_key;
+        self.amount = amount;
+        self.order_id = order_id;
+        self.created_at = now;
+        self.expires_at = expires_at;
+        self.bump = bump;
+
+        let total = self.limit_total()?;
+        match side {
+            OrderSide::Buy => self.escrowed_lamports = total,
+            OrderSide::Sell => self.escrowed_keys = amount,
+        }
+        Ok(())
+    }
+
+    /// Most a buy order pays, or least a sell order accepts, for all of its keys
+    pub fn limit_total(&self) -> Result<u64> {
+        self.price_per_key
+            .checked_mul(self.amount)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow.into())
+    }
+
+    /// Whether the curve's price for the whole order (before fees) satisfies the limit
+    pub fn is_fillable_at(&self, curve_total: u64) -> Result<bool> {
+        let limit = self.limit_total()?;
+        Ok(match self.side {
+            OrderSide::Buy => curve_total <= limit,
+            OrderSide::Sell => curve_total >= limit,
+        })
+    }
+
+    /// Keeper's cut for closing an order that holds `lamports`: `KEEPER_FEE`, or all of it
+    /// when the account holds less
+    pub fn keeper_fee(lamports: u64) -> u64 {
+        std::cmp::min(Self::KEEPER_FEE, lamports)
+    }
+
+    /// Guard for a sweep taking its `processed`-th order, counting from one
+    pub fn require_within_sweep_batch(processed: usize) -> Result<()> {
+        require!(
+            processed <= Self::MAX_SWEEP_BATCH,
+            crate::error::SolSocialError::TooManyAccounts
+        );
+        Ok(())
+    }
+
+    /// Count a sell order's escrowed keys back onto its owner's holder record, which they
+    /// left at placement. Returns the keys to move back out of the escrow.
+    pub fn return_escrowed_keys(&self, key_holder: &mut KeyHolder) -> Result<u64> {
+        require!(
+            key_holder.holder == self.owner && key_holder.keys_user == self.keys_user,
+            crate::error::SolSocialError::InvalidAccountOwner
+        );
+        key_holder.amount = key_holder.amount
+            .checked_add(self.escrowed_keys)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        Ok(self.escrowed_keys)
+    }
+}
+
+// Seeds for PDA derivation
+pub const LIMIT_ORDER_SEED: &[u8] = b"limit_order";
+pub const LIMIT_ORDER_ESCROW_SEED: &[u8] = b"limit_order_escrow";
+
+pub fn get_limit_order_pda(owner: &Pubkey, keys_user: &Pubkey, order_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LIMIT_ORDER_SEED, owner.as_ref(), keys_user.as_ref(), &order_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+pub fn get_limit_order_escrow_pda(order: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LIMIT_ORDER_ESCROW_SEED, order.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order_expiring_at(expires_at: i64) -> LimitOrder {
+        LimitOrder {
+            owner: Pubkey::new_unique(),
+            keys_user: Pubkey::new_unique(),
+            side: OrderSide::Buy,
+            price_per_key: 1_000_000,
+            amount: 1,
+            escrowed_lamports: 1_000_000,
+            escrowed_keys: 0,
+            order_id: 0,
+            created_at: 0,
+            expires_at,
+            bump: 255,
+            reserved: [0; 32],
+        }
+    }
+
+    #[test]
+    fn test_sweep_selects_only_expired_orders() {
+        let now = 1_000;
+        let orders = vec![
+            order_expiring_at(500),
+            order_expiring_at(1_000),
+            order_expiring_at(999),
+            order_expiring_at(2_000),
+        ];
+
+        let expired: Vec<bool> = orders.iter().map(|order| order.is_expired(now)).collect();
+        assert_eq!(expired, vec![true, false, true, false]);
+    }
+
+    #[test]
+    fn test_initialize_escrows_by_side() {
+        let mut buy = order_expiring_at(0);
+        buy.initialize(Pubkey::new_unique(), Pubkey::new_unique(), OrderSide::Buy, 2_000_000, 3, 7, 100, 200, 254).unwrap();
+        assert_eq!(buy.escrowed_lamports, 6_000_000);
+        assert_eq!(buy.escrowed_keys, 0);
+
+        let mut sell = order_expiring_at(0);
+        sell.escrowed_lamports = 0;
+        sell.initialize(Pubkey::new_unique(), Pubkey::new_unique(), OrderSide::Sell, 2_000_000, 3, 8, 100, 200, 254).unwrap();
+        assert_eq!(sell.escrowed_lamports, 0);
+        assert_eq!(sell.escrowed_keys, 3);
+
+        // Already expired, empty, or overflowing orders are rejected
+        let mut order = order_expiring_at(0);
+        assert!(order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), OrderSide::Buy, 1, 1, 0, 100, 100, 254).is_err());
+        assert!(order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), OrderSide::Buy, 1, 0, 0, 100, 200, 254).is_err());
+        assert!(order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), OrderSide::Buy, u64::MAX, 2, 0, 100, 200, 254).is_err());
+    }
+
+    fn holding(holder: Pubkey, keys_user: Pubkey, amount: u64) -> KeyHolder {
+        KeyHolder {
+            holder,
+            keys_user,
+            amount,
+            avg_price: 0,
+            total_invested: 0,
+            first_purchase_at: 0,
+            last_purchase_at: 0,
+            last_buy_slot: 0,
+            last_sell_slot: 0,
+            reserved: [0; 16],
+        }
+    }
+
+    fn sell_order_escrowing(keys: u64) -> LimitOrder {
+        let mut order = order_expiring_at(500);
+        order.side = OrderSide::Sell;
+        order.escrowed_lamports = 0;
+        order.escrowed_keys = keys;
+        order
+    }
+
+    #[test]
+    fn test_sweep_returns_escrowed_keys_to_the_owner_record() {
+        let order = sell_order_escrowing(3);
+        let mut key_holder = holding(order.owner, order.keys_user, 2);
+
+        assert_eq!(order.return_escrowed_keys(&mut key_holder).unwrap(), 3);
+        assert_eq!(key_holder.amount, 5);
+
+        // Another wallet's record, or the owner's record in another market, is refused untouched
+        let mut stranger = holding(Pubkey::new_unique(), order.keys_user, 0);
+        assert!(order.return_escrowed_keys(&mut stranger).is_err());
+        assert_eq!(stranger.amount, 0);
+        let mut other_market = holding(order.owner, Pubkey::new_unique(), 0);
+        assert!(order.return_escrowed_keys(&mut other_market).is_err());
+
+        let mut full = holding(order.owner, order.keys_user, u64::MAX);
+        assert!(order.return_escrowed_keys(&mut full).is_err());
+    }
+
+    #[test]
+    fn test_sweep_keeper_fee_never_exceeds_the_order_lamports() {
+        let rent = Rent::default().minimum_balance(LimitOrder::LEN);
+        assert_eq!(LimitOrder::keeper_fee(rent), LimitOrder::KEEPER_FEE);
+        assert_eq!(LimitOrder::keeper_fee(LimitOrder::KEEPER_FEE), LimitOrder::KEEPER_FEE);
+        assert_eq!(LimitOrder::keeper_fee(1_234), 1_234);
+        assert_eq!(LimitOrder::keeper_fee(0), 0);
+    }
+
+    #[test]
+    fn test_sweep_batch_stops_after_max_orders() {
+        assert!(LimitOrder::require_within_sweep_batch(1).is_ok());
+        assert!(LimitOrder::require_within_sweep_batch(LimitOrder::MAX_SWEEP_BATCH).is_ok());
+        assert!(LimitOrder::require_within_sweep_batch(LimitOrder::MAX_SWEEP_BATCH + 1).is_err());
+    }
+
+    #[test]
+    fn test_fillable_only_at_or_through_the_limit() {
+        let mut order = order_expiring_at(1_000);
+        order.amount = 2;
+        order.price_per_key = 1_000_000;
+
+        assert!(order.is_fillable_at(2_000_000).unwrap());
+        assert!(order.is_fillable_at(1_500_000).unwrap());
+        assert!(!order.is_fillable_at(2_000_001).unwrap());
+
+        order.side = OrderSide::Sell;
+        assert!(order.is_fillable_at(2_000_000).unwrap());
+        assert!(order.is_fillable_at(2_500_000).unwrap());
+        assert!(!order.is_fillable_at(1_999_999).unwrap());
+    }
+}
+```
\ No newline at end of file
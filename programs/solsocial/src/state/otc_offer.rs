@@ -0,0 +1,185 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::OrderSide;
+
+/// A negotiated block trade of a market's keys at a fixed total price, settled outside the curve
+#[account]
+pub struct OtcOffer {
+    /// The wallet that made the offer
+    pub maker: Pubkey,
+    /// The user whose keys are being traded
+    pub keys_user: Pubkey,
+    /// The only wallet allowed to accept, or `None` for anyone
+    pub taker: Option<Pubkey>,
+    /// Whether the maker buys or sells the keys
+    pub side: OrderSide,
+    /// Number of keys traded
+    pub amount: u64,
+    /// Total lamports the buyer pays for all the keys, fees included
+    pub price: u64,
+    /// Lamports escrowed in this account for buy offers
+    pub escrowed_lamports: u64,
+    /// Keys escrowed in the offer's token account for sell offers
+    pub escrowed_keys: u64,
+    /// Per-maker offer sequence number used in the PDA seeds
+    pub offer_id: u64,
+    /// Timestamp when the offer was made
+    pub created_at: i64,
+    /// Timestamp after which the offer can no longer be accepted
+    pub expires_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved space for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl OtcOffer {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // maker
+        32 + // keys_user
+        1 + 32 + // taker
+        1 + // side
+        8 + // amount
+        8 + // price
+        8 + // escrowed_lamports
+        8 + // escrowed_keys
+        8 + // offer_id
+        8 + // created_at
+        8 + // expires_at
+        1 + // bump
+        32; // reserved
+
+    /// Open an offer; buy offers escrow `price` lamports, sell offers `amount` keys
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        maker: Pubkey,
+        keys_user: Pubkey,
+        taker: Option<Pubkey>,
+        side: OrderSide,
+        amount: u64,
+        price: u64,
+        offer_id: u64,
+        now: i64,
+        expires_at: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            amount > 0 && price > 0 && expires_at > now && taker != Some(maker),
+            crate::error::SolSocialError::InvalidOtcOffer
+        );
+
+        self.maker = maker;
+        self.keys_user = keys_user;
+        self.taker = taker;
+        self.side = side;
+        self.amount = amount;
+        self.price = price;
+        self.offer_id = offer_id;
+        self.created_at = now;
+        self.expires_at = expires_at;
+        self.bump = bump;
+
+        match side {
+            OrderSide::Buy => self.escrowed_lamports = price,
+            OrderSide::Sell => self.escrowed_keys = amount,
+        }
+        Ok(())
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.expires_at
+    }
+
+    /// Whether `wallet` may take the other side of the offer
+    pub fn can_be_taken_by(&self, wallet: &Pubkey) -> bool {
+        *wallet != self.maker && self.taker.map_or(true, |taker| taker == *wallet)
+    }
+}
+
+// Seeds for PDA derivation
+pub const OTC_OFFER_SEED: &[u8] = b"otc_offer";
+pub const OTC_ESCROW_SEED: &[u8] = b"otc_escrow";
+
+pub fn get_otc_offer_pda(maker: &Pubkey, keys_user: &Pubkey, offer_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[OTC_OFFER_SEED, maker.as_ref(), keys_user.as_ref(), &offer_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+pub fn get_otc_escrow_pda(offer: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OTC_ESCROW_SEED, offer.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_offer() -> OtcOffer {
+        OtcOffer {
+            maker: Pubkey::default(),
+            keys_user: Pubkey::default(),
+            taker: None,
+            side: OrderSide::Buy,
+            amount: 0,
+            price: 0,
+            escrowed_lamports: 0,
+            escrowed_keys: 0,
+            offer_id: 0,
+            created_at: 0,
+            expires_at: 0,
+            bump: 0,
+            reserved: [0; 32],
+        }
+    }
+
+    #[test]
+    fn test_initialize_escrows_by_side() {
+        let maker = Pubkey::new_unique();
+
+        let mut buy = empty_offer();
+        buy.initialize(maker, Pubkey::new_unique(), None, OrderSide::Buy, 50, 9_000_000_000, 0, 100, 200, 254).unwrap();
+        assert_eq!(buy.escrowed_lamports, 9_000_000_000);
+        assert_eq!(buy.escrowed_keys, 0);
+
+        let mut sell = empty_offer();
+        sell.initialize(maker, Pubkey::new_unique(), None, OrderSide::Sell, 50, 9_000_000_000, 1, 100, 200, 254).unwrap();
+        assert_eq!(sell.escrowed_lamports, 0);
+        assert_eq!(sell.escrowed_keys, 50);
+
+        // Empty, free, already expired, or self-directed offers are rejected
+        let mut offer = empty_offer();
+        assert!(offer.initialize(maker, Pubkey::new_unique(), None, OrderSide::Buy, 0, 1, 0, 100, 200, 254).is_err());
+        assert!(offer.initialize(maker, Pubkey::new_unique(), None, OrderSide::Buy, 1, 0, 0, 100, 200, 254).is_err());
+        assert!(offer.initialize(maker, Pubkey::new_unique(), None, OrderSide::Buy, 1, 1, 0, 100, 100, 254).is_err());
+        assert!(offer.initialize(maker, Pubkey::new_unique(), Some(maker), OrderSide::Buy, 1, 1, 0, 100, 200, 254).is_err());
+    }
+
+    #[test]
+    fn test_only_the_named_counterparty_may_accept() {
+        let maker = Pubkey::new_unique();
+        let taker = Pubkey::new_unique();
+        let mut offer = empty_offer();
+        offer.maker = maker;
+
+        // Open offers take anyone but the maker
+        assert!(offer.can_be_taken_by(&taker));
+        assert!(offer.can_be_taken_by(&Pubkey::new_unique()));
+        assert!(!offer.can_be_taken_by(&maker));
+
+        offer.taker = Some(taker);
+        assert!(offer.can_be_taken_by(&taker));
+        assert!(!offer.can_be_taken_by(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn test_expiry_is_inclusive_of_the_deadline() {
+        let mut offer = empty_offer();
+        offer.expires_at = 1_000;
+        assert!(!offer.is_expired(999));
+        assert!(!offer.is_expired(1_000));
+        assert!(offer.is_expired(1_001));
+    }
+}
+```
\ No newline at end of file
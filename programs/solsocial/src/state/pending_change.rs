@@ -0,0 +1,66 @@
+```rust
+use anchor_lang::prelude::*;
+use super::ConfigUpdate;
+
+/// Config change waiting out the protocol timelock
+#[account]
+pub struct PendingChange {
+    /// The config the change applies to
+    pub config: Pubkey,
+    /// Index of the change among those queued for the config
+    pub index: u64,
+    /// Admin that queued the change; receives the rent back when it closes
+    pub proposer: Pubkey,
+    /// Settings to apply
+    pub update: ConfigUpdate,
+    /// Timestamp when the change was queued
+    pub queued_at: i64,
+    /// Earliest timestamp the change may be executed
+    pub eta: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PendingChange {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // config
+        8 + // index
+        32 + // proposer
+        ConfigUpdate::LEN + // update
+        8 + // queued_at
+        8 + // eta
+        1; // bump
+
+    pub fn is_ready(&self, now: i64) -> bool {
+        now >= self.eta
+    }
+}
+
+// Seeds for PDA derivation
+pub const PENDING_CHANGE_SEED: &[u8] = b"pending_change";
+
+pub fn get_pending_change_pda(config: &Pubkey, index: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PENDING_CHANGE_SEED, config.as_ref(), &index.to_le_bytes()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_is_ready_once_eta_passes() {
+        let change = PendingChange {
+            config: Pubkey::new_unique(),
+            index: 0,
+            proposer: Pubkey::new_unique(),
+            update: ConfigUpdate { max_fee_bps: Some(1_500), ..ConfigUpdate::default() },
+            queued_at: 1_000,
+            eta: 1_000 + 86_400,
+            bump: 255,
+        };
+        assert!(!change.is_ready(1_000));
+        assert!(!change.is_ready(1_000 + 86_399));
+        assert!(change.is_ready(1_000 + 86_400));
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,125 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// Running totals over all of a trader's key positions, kept by `buy_keys` and `sell_keys`
+/// so wallets can show a portfolio without replaying trade history
+#[account]
+pub struct Portfolio {
+    /// The trader's wallet
+    pub owner: Pubkey,
+    /// Cost basis of the keys still held, across markets (in lamports)
+    pub total_invested: u64,
+    /// Sell proceeds less the cost basis of the keys sold, over the trader's lifetime
+    pub realized_pnl: i64,
+    /// Distinct markets the trader currently holds keys in
+    pub markets_held: u32,
+    /// Buys and sells recorded
+    pub trade_count: u64,
+    /// Timestamp of the latest trade
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved space for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl Portfolio {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        8 + // total_invested
+        8 + // realized_pnl
+        4 + // markets_held
+        8 + // trade_count
+        8 + // updated_at
+        1 + // bump
+        32; // reserved
+
+    pub fn initialize(&mut self, owner: Pubkey, bump: u8) {
+        self.owner = owner;
+        self.total_invested = 0;
+        self.realized_pnl = 0;
+        self.markets_held = 0;
+        self.trade_count = 0;
+        self.updated_at = 0;
+        self.bump = bump;
+        self.reserved = [0; 32];
+    }
+
+    /// Record a buy costing `cost`; a position opened from zero counts a new market
+    pub fn record_buy(&mut self, cost: u64, held_before: u64, now: i64) -> Result<()> {
+        self.total_invested = self.total_invested
+            .checked_add(cost)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        if held_before == 0 {
+            self.markets_held = self.markets_held.saturating_add(1);
+        }
+        self.record_trade(now)
+    }
+
+    /// Record a sell of keys that cost `cost_basis` for `proceeds`; a position closed out
+    /// drops its market
+    pub fn record_sell(&mut self, cost_basis: u64, proceeds: u64, held_after: u64, now: i64) -> Result<()> {
+        // Keys that arrived by transfer or allocation carry no basis here
+        self.total_invested = self.total_invested.saturating_sub(cost_basis);
+        let pnl = proceeds as i128 - cost_basis as i128;
+        self.realized_pnl = i64::try_from(self.realized_pnl as i128 + pnl)
+            .map_err(|_| crate::error::SolSocialError::ArithmeticOverflow)?;
+        if held_after == 0 {
+            self.markets_held = self.markets_held.saturating_sub(1);
+        }
+        self.record_trade(now)
+    }
+
+    fn record_trade(&mut self, now: i64) -> Result<()> {
+        self.trade_count = self.trade_count
+            .checked_add(1)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+// Seeds for PDA derivation
+pub const PORTFOLIO_SEED: &[u8] = b"portfolio";
+
+pub fn get_portfolio_pda(owner: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PORTFOLIO_SEED, owner.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn portfolio() -> Portfolio {
+        Portfolio {
+            owner: Pubkey::new_unique(),
+            total_invested: 0,
+            realized_pnl: 0,
+            markets_held: 0,
+            trade_count: 0,
+            updated_at: 0,
+            bump: 255,
+            reserved: [0; 32],
+        }
+    }
+
+    #[test]
+    fn test_positions_open_and_close_markets() {
+        let mut portfolio = portfolio();
+        portfolio.record_buy(1_000, 0, 10).unwrap();
+        portfolio.record_buy(500, 0, 11).unwrap();
+        portfolio.record_buy(700, 3, 12).unwrap();
+        assert_eq!(portfolio.markets_held, 2);
+        assert_eq!(portfolio.total_invested, 2_200);
+
+        // Half a position sold at a profit, then a whole one at a loss
+        portfolio.record_sell(850, 1_200, 2, 13).unwrap();
+        portfolio.record_sell(500, 300, 0, 14).unwrap();
+        assert_eq!(portfolio.markets_held, 1);
+        assert_eq!(portfolio.total_invested, 850);
+        assert_eq!(portfolio.realized_pnl, 350 - 200);
+        assert_eq!(portfolio.trade_count, 5);
+        assert_eq!(portfolio.updated_at, 14);
+    }
+}
+```
\ No newline at end of file
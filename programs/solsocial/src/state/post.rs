@@ -1,40 +1,86 @@
 ```rust
 use anchor_lang::prelude::*;
+use super::{compute_content_hash, KeyHolder, MediaAttachment, PostVisibility, UserFollow, UserKeys, MAX_MEDIA_URLS};
 
 #[account]
 pub struct Post {
     pub author: Pubkey,
     pub content: String,
+    pub media: Vec<MediaAttachment>,
     pub timestamp: i64,
     pub likes: u64,
     pub comments: u64,
+    /// Comments ever created on the post, the next comment's id; never decreases
+    pub comment_count: u64,
     pub shares: u64,
     pub is_premium: bool,
     pub required_keys: u64,
     pub revenue_generated: u64,
+    /// Tips received through `tip_post`
+    pub tips_received: u64,
+    pub visibility: PostVisibility,
+    pub gated_market: Option<Pubkey>,
+    /// Fingerprint of the body: used for repost dedup while dedup is enabled, and always
+    /// set for hashed posts, whose body lives off-chain
+    pub content_hash: Option<[u8; 32]>,
+    /// Arweave/IPFS URI of a hashed post's body; `content` is empty when set
+    pub content_uri: Option<String>,
+    /// Byte length of a hashed post's off-chain body
+    pub content_len: u32,
+    /// The post this one reposts or quotes
+    pub original_post: Option<Pubkey>,
+    /// Mint of the NFT minted from this post, if any
+    pub nft_mint: Option<Pubkey>,
+    /// When a story-style post lapses and anyone may close it; `None` for permanent posts
+    pub expires_at: Option<i64>,
+    /// First post of the thread this post continues; `None` for standalone posts and roots
+    pub thread_root: Option<Pubkey>,
+    /// Position in the thread, the root being 0
+    pub thread_index: u32,
+    /// Posts appended to the thread, tracked on the root only
+    pub thread_length: u32,
     pub bump: u8,
 }
 
 impl Post {
     pub const MAX_CONTENT_LENGTH: usize = 280;
+    pub const MAX_CONTENT_URI_LENGTH: usize = 200;
+    /// Longest an ephemeral post may live
+    pub const MAX_LIFETIME_SECONDS: i64 = 7 * 24 * 60 * 60;
     pub const SPACE: usize = 8 + // discriminator
         32 + // author
         4 + Self::MAX_CONTENT_LENGTH + // content (string)
+        4 + MAX_MEDIA_URLS * MediaAttachment::SPACE + // media
         8 + // timestamp
         8 + // likes
         8 + // comments
+        8 + // comment_count
         8 + // shares
         1 + // is_premium
         8 + // required_keys
         8 + // revenue_generated
+        8 + // tips_received
+        1 + // visibility
+        1 + 32 + // gated_market
+        1 + 32 + // content_hash
+        1 + 4 + Self::MAX_CONTENT_URI_LENGTH + // content_uri
+        4 + // content_len
+        1 + 32 + // original_post
+        1 + 32 + // nft_mint
+        1 + 8 + // expires_at
+        1 + 32 + // thread_root
+        4 + // thread_index
+        4 + // thread_length
         1; // bump
 
     pub fn initialize(
         &mut self,
         author: Pubkey,
         content: String,
+        media: Vec<MediaAttachment>,
         is_premium: bool,
         required_keys: u64,
+        visibility: PostVisibility,
         bump: u8,
     ) -> Result<()> {
         require!(
@@ -44,18 +90,87 @@ impl Post {
 
         self.author = author;
         self.content = content;
+        self.media = media;
         self.timestamp = Clock::get()?.unix_timestamp;
         self.likes = 0;
         self.comments = 0;
+        self.comment_count = 0;
         self.shares = 0;
         self.is_premium = is_premium;
         self.required_keys = required_keys;
         self.revenue_generated = 0;
+        self.tips_received = 0;
+        self.visibility = visibility;
+        self.gated_market = None;
+        self.content_hash = None;
+        self.content_uri = None;
+        self.content_len = 0;
+        self.original_post = None;
+        self.nft_mint = None;
+        self.expires_at = None;
+        self.thread_root = None;
+        self.thread_index = 0;
+        self.thread_length = 0;
         self.bump = bump;
 
         Ok(())
     }
 
+    /// Check that `viewer` holds enough keys in the exact market gating this post.
+    /// `market` is the gating `UserKeys` account with its address.
+    pub fn check_gate(
+        &self,
+        viewer: &Pubkey,
+        market: Option<(Pubkey, &UserKeys)>,
+        holder: Option<&KeyHolder>,
+    ) -> Result<()> {
+        let gated_market = match self.gated_market {
+            Some(gated_market) => gated_market,
+            None => return Ok(()),
+        };
+
+        if *viewer == self.author {
+            return Ok(());
+        }
+
+        let (market_key, market) = market.ok_or(crate::error::SolSocialError::MissingRequiredAccount)?;
+        require_keys_eq!(market_key, gated_market, crate::error::SolSocialError::GatedMarketMismatch);
+
+        let holder = holder.ok_or(crate::error::SolSocialError::InsufficientKeys)?;
+        require_keys_eq!(holder.keys_user, market.user, crate::error::SolSocialError::GatedMarketMismatch);
+        require_keys_eq!(holder.holder, *viewer, crate::error::SolSocialError::InvalidAccountOwner);
+        require!(holder.amount >= self.required_keys, crate::error::SolSocialError::InsufficientKeys);
+
+        Ok(())
+    }
+
+    /// Check that `viewer` may see the post under its visibility setting: holders-only posts
+    /// take a holding of the author's keys, followers-only posts the viewer's follow of the
+    /// author, and private posts are the author's alone.
+    pub fn check_visibility(
+        &self,
+        viewer: &Pubkey,
+        holder: Option<&KeyHolder>,
+        follow: Option<&UserFollow>,
+    ) -> Result<()> {
+        if *viewer == self.author {
+            return Ok(());
+        }
+
+        let visible = match self.visibility {
+            PostVisibility::Public => true,
+            PostVisibility::KeyHoldersOnly => holder.map_or(false, |holder| {
+                holder.holder == *viewer && holder.keys_user == self.author && holder.amount > 0
+            }),
+            PostVisibility::FollowersOnly => follow.map_or(false, |follow| {
+                follow.follower == *viewer && follow.following == self.author
+            }),
+            PostVisibility::Private => false,
+        };
+        require!(visible, crate::error::SolSocialError::PostNotVisible);
+        Ok(())
+    }
+
     pub fn add_like(&mut self) -> Result<()> {
         self.likes = self.likes.checked_add(1)
             .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
@@ -68,6 +183,51 @@ impl Post {
         Ok(())
     }
 
+    /// Count a new standalone comment and hand out its id
+    pub fn open_comment(&mut self) -> Result<u64> {
+        let comment_id = self.comment_count;
+        self.comment_count = self.comment_count.checked_add(1)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        self.add_comment()?;
+        Ok(comment_id)
+    }
+
+    pub fn remove_comment(&mut self) {
+        self.comments = self.comments.saturating_sub(1);
+    }
+
+    /// Make the post ephemeral, lapsing `lifetime` seconds after `now`
+    pub fn set_lifetime(&mut self, now: i64, lifetime: i64) -> Result<()> {
+        require!(
+            lifetime > 0 && lifetime <= Self::MAX_LIFETIME_SECONDS,
+            crate::error::SolSocialError::InvalidPostLifetime
+        );
+        self.expires_at = Some(now.checked_add(lifetime).ok_or(crate::error::SolSocialError::ArithmeticOverflow)?);
+        Ok(())
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.map_or(false, |expires_at| now >= expires_at)
+    }
+
+    /// Bytes of attached media, as counted against the author's storage cap
+    pub fn media_bytes(&self) -> u64 {
+        self.media.iter().fold(0u64, |total, attachment| total.saturating_add(attachment.size))
+    }
+
+    /// Reserve the next position in the thread this post roots
+    pub fn append_to_thread(&mut self) -> Result<u32> {
+        require!(self.thread_root.is_none(), crate::error::SolSocialError::InvalidThreadRoot);
+        self.thread_length = self.thread_length.checked_add(1)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        Ok(self.thread_length)
+    }
+
+    /// A repost carries no text of its own; a quote post does
+    pub fn is_quote(&self) -> bool {
+        self.original_post.is_some() && !self.content.is_empty()
+    }
+
     pub fn add_share(&mut self) -> Result<()> {
         self.shares = self.shares.checked_add(1)
             .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
@@ -79,6 +239,44 @@ impl Post {
             .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
         Ok(())
     }
+
+    pub fn add_tip(&mut self) -> Result<()> {
+        self.tips_received = self.tips_received.checked_add(1)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Whether the body is stored off-chain, committed to by `content_hash`
+    pub fn is_hashed(&self) -> bool {
+        self.content_uri.is_some()
+    }
+
+    /// Check a post body against what's on-chain: the stored hash and length for hashed
+    /// posts, the content itself otherwise
+    pub fn verify_body(&self, body: &str) -> bool {
+        if !self.is_hashed() {
+            return self.content == body;
+        }
+        body.len() == self.content_len as usize
+            && self.content_hash == Some(compute_content_hash(body, &self.media))
+    }
+
+    /// Record the post's NFT; a post can only be minted once
+    pub fn mark_minted(&mut self, mint: Pubkey) -> Result<()> {
+        require!(self.nft_mint.is_none(), crate::error::SolSocialError::PostAlreadyMinted);
+        self.nft_mint = Some(mint);
+        Ok(())
+    }
+}
+
+/// Symbol of every post NFT
+pub const POST_NFT_SYMBOL: &str = "SOCIAL";
+
+/// Metadata URI of a post NFT: the off-chain JSON with the post's content hash as a fragment,
+/// so the hash stays on-chain in the metadata account
+pub fn post_nft_uri(uri: &str, content_hash: &[u8; 32]) -> String {
+    let hex: String = content_hash.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}#content_hash={}", uri, hex)
 }
 
 #[account]
@@ -127,6 +325,102 @@ impl PostInteraction {
     }
 }
 
+/// A comment on a post, one account per comment so users can comment repeatedly and reply in threads
+#[account]
+pub struct Comment {
+    pub post: Pubkey,
+    pub author: Pubkey,
+    /// Position in the post's comment sequence, part of the PDA seeds
+    pub comment_id: u64,
+    /// The comment this replies to, or `None` for a top-level comment
+    pub parent_comment: Option<Pubkey>,
+    pub content: String,
+    /// Direct replies currently open under this comment
+    pub reply_count: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Comment {
+    pub const MAX_CONTENT_LENGTH: usize = 280;
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // post
+        32 + // author
+        8 + // comment_id
+        1 + 32 + // parent_comment
+        4 + Self::MAX_CONTENT_LENGTH + // content (string)
+        8 + // reply_count
+        8 + // created_at
+        1; // bump
+
+    pub fn initialize(
+        &mut self,
+        post: Pubkey,
+        author: Pubkey,
+        comment_id: u64,
+        parent_comment: Option<Pubkey>,
+        content: String,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(!content.trim().is_empty(), crate::error::SolSocialError::CommentEmpty);
+        require!(
+            content.len() <= Self::MAX_CONTENT_LENGTH,
+            crate::error::SolSocialError::CommentTooLong
+        );
+
+        self.post = post;
+        self.author = author;
+        self.comment_id = comment_id;
+        self.parent_comment = parent_comment;
+        self.content = content;
+        self.reply_count = 0;
+        self.created_at = now;
+        self.bump = bump;
+
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Share {
+    pub post: Pubkey,
+    pub sharer: Pubkey,
+    pub created_at: i64,
+    pub tips_driven: u64,
+    pub rewards_earned: u64,
+    pub bump: u8,
+}
+
+impl Share {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // post
+        32 + // sharer
+        8 + // created_at
+        8 + // tips_driven
+        8 + // rewards_earned
+        1; // bump
+
+    pub fn initialize(&mut self, post: Pubkey, sharer: Pubkey, bump: u8) -> Result<()> {
+        self.post = post;
+        self.sharer = sharer;
+        self.created_at = Clock::get()?.unix_timestamp;
+        self.tips_driven = 0;
+        self.rewards_earned = 0;
+        self.bump = bump;
+
+        Ok(())
+    }
+
+    pub fn record_tip(&mut self, tip_amount: u64, reward: u64) -> Result<()> {
+        self.tips_driven = self.tips_driven.checked_add(tip_amount)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        self.rewards_earned = self.rewards_earned.checked_add(reward)
+            .ok_or(crate::error::SolSocialError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub enum InteractionType {
     Like,
@@ -183,4 +477,272 @@ impl PostStats {
         Ok(())
     }
 }
+
+// Seeds for PDA derivation
+pub const COMMENT_SEED: &[u8] = b"comment";
+
+pub fn get_comment_pda(post: &Pubkey, comment_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COMMENT_SEED, post.as_ref(), &comment_id.to_le_bytes()], program_id)
+}
+
+pub const POST_NFT_MINT_SEED: &[u8] = b"post_nft_mint";
+
+pub fn get_post_nft_mint_pda(post: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POST_NFT_MINT_SEED, post.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{BondingCurveParams, LaunchAuction, LaunchGuard, Presale, PricePoint, PRICE_WINDOW_LEN};
+
+    fn gated_post(author: Pubkey, gated_market: Pubkey, required_keys: u64) -> Post {
+        Post {
+            author,
+            content: String::from("holders only"),
+            media: Vec::new(),
+            timestamp: 0,
+            likes: 0,
+            comments: 0,
+            comment_count: 0,
+            shares: 0,
+            is_premium: true,
+            required_keys,
+            revenue_generated: 0,
+            tips_received: 0,
+            visibility: PostVisibility::KeyHoldersOnly,
+            gated_market: Some(gated_market),
+            content_hash: None,
+            content_uri: None,
+            content_len: 0,
+            original_post: None,
+            nft_mint: None,
+            expires_at: None,
+            thread_root: None,
+            thread_index: 0,
+            thread_length: 0,
+            bump: 255,
+        }
+    }
+
+    fn market(user: Pubkey) -> UserKeys {
+        UserKeys {
+            user,
+            supply: 10,
+            price: 0,
+            volume: 0,
+            holders_count: 1,
+            creator_earnings: 0,
+            protocol_fees: 0,
+            created_at: 0,
+            last_trade_at: 0,
+            curve_params: BondingCurveParams::default(),
+            creator_locked_amount: 0,
+            engagement_rewarded_earnings: 0,
+            grace_exit_until: 0,
+            grace_opened_at: 0,
+            trading_open_at: 0,
+            trading_close_at: 0,
+            reserved: [0; 8],
+            trade_count: 0,
+            mint: Pubkey::default(),
+            dividend_epochs: 0,
+            launch_auction: LaunchAuction::default(),
+            presale: Presale::default(),
+            launch_guard: LaunchGuard::default(),
+            trading_halted: false,
+            price_window: [PricePoint::default(); PRICE_WINDOW_LEN],
+            price_window_head: 0,
+            graduated_at: 0,
+            burned_keys: 0,
+            payment_mint: Pubkey::default(),
+            milestones_reached: 0,
+        }
+    }
+
+    fn holding(holder: Pubkey, keys_user: Pubkey, amount: u64) -> KeyHolder {
+        KeyHolder {
+            holder,
+            keys_user,
+            amount,
+            avg_price: 0,
+            total_invested: 0,
+            first_purchase_at: 0,
+            last_purchase_at: 0,
+            last_buy_slot: 0,
+            last_sell_slot: 0,
+            reserved: [0; 16],
+        }
+    }
+
+    #[test]
+    fn test_matching_market_passes_gate() {
+        let author = Pubkey::new_unique();
+        let market_key = Pubkey::new_unique();
+        let viewer = Pubkey::new_unique();
+        let post = gated_post(author, market_key, 2);
+        let keys = market(author);
+        let holder = holding(viewer, author, 2);
+
+        assert!(post.check_gate(&viewer, Some((market_key, &keys)), Some(&holder)).is_ok());
+    }
+
+    #[test]
+    fn test_mismatched_market_fails_gate() {
+        let author = Pubkey::new_unique();
+        let viewer = Pubkey::new_unique();
+        let post = gated_post(author, Pubkey::new_unique(), 1);
+
+        // Keys held in some other creator's market don't unlock the post
+        let other_creator = Pubkey::new_unique();
+        let other_market_key = Pubkey::new_unique();
+        let other_keys = market(other_creator);
+        let holder = holding(viewer, other_creator, 5);
+
+        assert!(post.check_gate(&viewer, Some((other_market_key, &other_keys)), Some(&holder)).is_err());
+    }
+
+    #[test]
+    fn test_holding_from_other_market_fails_gate() {
+        let author = Pubkey::new_unique();
+        let market_key = Pubkey::new_unique();
+        let viewer = Pubkey::new_unique();
+        let post = gated_post(author, market_key, 1);
+        let keys = market(author);
+        let holder = holding(viewer, Pubkey::new_unique(), 5);
+
+        assert!(post.check_gate(&viewer, Some((market_key, &keys)), Some(&holder)).is_err());
+    }
+
+    #[test]
+    fn test_insufficient_keys_fails_gate() {
+        let author = Pubkey::new_unique();
+        let market_key = Pubkey::new_unique();
+        let viewer = Pubkey::new_unique();
+        let post = gated_post(author, market_key, 3);
+        let keys = market(author);
+        let holder = holding(viewer, author, 2);
+
+        assert!(post.check_gate(&viewer, Some((market_key, &keys)), Some(&holder)).is_err());
+    }
+
+    #[test]
+    fn test_author_bypasses_gate() {
+        let author = Pubkey::new_unique();
+        let post = gated_post(author, Pubkey::new_unique(), 3);
+
+        assert!(post.check_gate(&author, None, None).is_ok());
+    }
+
+    #[test]
+    fn test_comment_content_must_be_present_and_short() {
+        let mut comment = Comment {
+            post: Pubkey::default(),
+            author: Pubkey::default(),
+            comment_id: 0,
+            parent_comment: None,
+            content: String::new(),
+            reply_count: 0,
+            created_at: 0,
+            bump: 0,
+        };
+        let post = Pubkey::new_unique();
+        let author = Pubkey::new_unique();
+
+        assert!(comment.initialize(post, author, 0, None, "   ".to_string(), 0, 254).is_err());
+        assert!(comment
+            .initialize(post, author, 0, None, "a".repeat(Comment::MAX_CONTENT_LENGTH + 1), 0, 254)
+            .is_err());
+
+        let parent = Pubkey::new_unique();
+        comment.initialize(post, author, 3, Some(parent), "agreed".to_string(), 10, 254).unwrap();
+        assert_eq!(comment.parent_comment, Some(parent));
+        assert_eq!(comment.comment_id, 3);
+    }
+
+    #[test]
+    fn test_hashed_post_verifies_offchain_body() {
+        let mut post = gated_post(Pubkey::new_unique(), Pubkey::new_unique(), 1);
+        assert!(post.verify_body("holders only"));
+
+        let body = "a".repeat(1_000);
+        post.content = String::new();
+        post.content_uri = Some(String::from("ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U"));
+        post.content_len = body.len() as u32;
+        post.content_hash = Some(compute_content_hash(&body, &[]));
+
+        assert!(post.is_hashed());
+        assert!(post.verify_body(&body));
+        assert!(!post.verify_body(&"b".repeat(1_000)));
+        assert!(!post.verify_body("holders only"));
+    }
+
+    #[test]
+    fn test_ephemeral_post_expires() {
+        let mut post = gated_post(Pubkey::new_unique(), Pubkey::new_unique(), 1);
+        assert!(!post.is_expired(i64::MAX));
+
+        assert!(post.set_lifetime(100, 0).is_err());
+        assert!(post.set_lifetime(100, Post::MAX_LIFETIME_SECONDS + 1).is_err());
+
+        post.set_lifetime(100, 24 * 60 * 60).unwrap();
+        assert!(!post.is_expired(100 + 24 * 60 * 60 - 1));
+        assert!(post.is_expired(100 + 24 * 60 * 60));
+    }
+
+    #[test]
+    fn test_thread_appends_in_order() {
+        let mut root = gated_post(Pubkey::new_unique(), Pubkey::new_unique(), 1);
+        assert_eq!(root.append_to_thread().unwrap(), 1);
+        assert_eq!(root.append_to_thread().unwrap(), 2);
+        assert_eq!(root.thread_length, 2);
+
+        let mut part = gated_post(root.author, Pubkey::new_unique(), 1);
+        part.thread_root = Some(Pubkey::new_unique());
+        assert!(part.append_to_thread().is_err());
+    }
+
+    #[test]
+    fn test_visibility_requires_matching_holding_or_follow() {
+        let author = Pubkey::new_unique();
+        let viewer = Pubkey::new_unique();
+        let mut post = gated_post(author, Pubkey::new_unique(), 1);
+        let holder = holding(viewer, author, 1);
+        let follow = UserFollow { follower: viewer, following: author, created_at: 0, reserved: [0; 64] };
+        let other_follow = UserFollow { follower: viewer, following: Pubkey::new_unique(), created_at: 0, reserved: [0; 64] };
+
+        post.visibility = PostVisibility::KeyHoldersOnly;
+        assert!(post.check_visibility(&viewer, Some(&holder), None).is_ok());
+        assert!(post.check_visibility(&viewer, None, Some(&follow)).is_err());
+        assert!(post.check_visibility(&viewer, Some(&holding(viewer, author, 0)), None).is_err());
+        assert!(post.check_visibility(&viewer, Some(&holding(Pubkey::new_unique(), author, 1)), None).is_err());
+
+        post.visibility = PostVisibility::FollowersOnly;
+        assert!(post.check_visibility(&viewer, None, Some(&follow)).is_ok());
+        assert!(post.check_visibility(&viewer, None, Some(&other_follow)).is_err());
+
+        post.visibility = PostVisibility::Private;
+        assert!(post.check_visibility(&viewer, None, Some(&follow)).is_err());
+        assert!(post.check_visibility(&author, None, None).is_ok());
+
+        post.visibility = PostVisibility::Public;
+        assert!(post.check_visibility(&viewer, None, None).is_ok());
+    }
+
+    #[test]
+    fn test_post_mints_once() {
+        let mut post = gated_post(Pubkey::new_unique(), Pubkey::new_unique(), 1);
+        let mint = Pubkey::new_unique();
+
+        post.mark_minted(mint).unwrap();
+        assert_eq!(post.nft_mint, Some(mint));
+        assert!(post.mark_minted(Pubkey::new_unique()).is_err());
+    }
+
+    #[test]
+    fn test_nft_uri_carries_content_hash() {
+        let uri = post_nft_uri("https://arweave.net/abc", &[0xab; 32]);
+        assert_eq!(uri, format!("https://arweave.net/abc#content_hash={}", "ab".repeat(32)));
+    }
+}
 ```
\ No newline at end of file
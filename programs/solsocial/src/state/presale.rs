@@ -0,0 +1,236 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::error::SolSocialError;
+
+/// Allowlisted presale a creator may configure in `create_keys`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct PresaleParams {
+    /// Root of the allowlist, see [`Presale::leaf`]
+    pub merkle_root: [u8; 32],
+    /// Fixed per-key price, in lamports
+    pub price: u64,
+    /// Most keys one allowlisted wallet may buy
+    pub max_per_wallet: u64,
+    /// Keys sold through the presale before the curve opens
+    pub keys_for_sale: u64,
+    /// How long the presale runs
+    pub duration_seconds: i64,
+}
+
+/// Fixed-price sale of a market's first keys to an allowlist. The bonding curve stays
+/// closed until it sells out or ends. A zeroed presale means the market had none.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Presale {
+    /// Root of the allowlist, see [`Presale::leaf`]
+    pub merkle_root: [u8; 32],
+    /// Fixed per-key price, in lamports
+    pub price: u64,
+    /// Most keys one allowlisted wallet may buy
+    pub max_per_wallet: u64,
+    /// Keys offered through the presale
+    pub keys_for_sale: u64,
+    /// Keys bought so far
+    pub keys_sold: u64,
+    /// Timestamp when the presale opened
+    pub start_at: i64,
+    /// Timestamp when the presale closes and the curve opens
+    pub end_at: i64,
+}
+
+impl Presale {
+    pub const LEN: usize = 32 + // merkle_root
+        8 + // price
+        8 + // max_per_wallet
+        8 + // keys_for_sale
+        8 + // keys_sold
+        8 + // start_at
+        8; // end_at
+
+    /// Longest a presale may hold the curve closed
+    pub const MAX_DURATION_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+    /// Deepest proof accepted, enough for an allowlist of about a million wallets
+    pub const MAX_PROOF_LEN: usize = 20;
+
+    pub fn new(params: &PresaleParams, now: i64) -> Result<Self> {
+        require!(params.merkle_root != [0; 32], SolSocialError::InvalidPresale);
+        require!(params.price > 0 && params.keys_for_sale > 0, SolSocialError::InvalidPresale);
+        require!(
+            params.max_per_wallet > 0 && params.max_per_wallet <= params.keys_for_sale,
+            SolSocialError::InvalidPresale
+        );
+        require!(
+            params.duration_seconds > 0 && params.duration_seconds <= Self::MAX_DURATION_SECONDS,
+            SolSocialError::InvalidPresale
+        );
+
+        Ok(Self {
+            merkle_root: params.merkle_root,
+            price: params.price,
+            max_per_wallet: params.max_per_wallet,
+            keys_for_sale: params.keys_for_sale,
+            keys_sold: 0,
+            start_at: now,
+            end_at: now + params.duration_seconds,
+        })
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.keys_for_sale.saturating_sub(self.keys_sold)
+    }
+
+    /// Whether the presale is still selling keys at `now`
+    pub fn is_live(&self, now: i64) -> bool {
+        self.remaining() > 0 && now < self.end_at
+    }
+
+    /// Allowlist leaf for `wallet`
+    pub fn leaf(wallet: &Pubkey) -> [u8; 32] {
+        hashv(&[wallet.as_ref()]).to_bytes()
+    }
+
+    /// Whether `proof` shows `wallet` is on the allowlist. Pairs are hashed in sorted
+    /// order, so proofs carry no left/right flags.
+    pub fn is_allowlisted(&self, wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        if proof.len() > Self::MAX_PROOF_LEN {
+            return false;
+        }
+
+        let root = proof.iter().fold(Self::leaf(wallet), |node, sibling| {
+            if node <= *sibling {
+                hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &node]).to_bytes()
+            }
+        });
+        root == self.merkle_root
+    }
+
+    pub fn record_sale(&mut self, amount: u64) -> Result<()> {
+        require!(amount <= self.remaining(), SolSocialError::InsufficientKeys);
+        self.keys_sold += amount;
+        Ok(())
+    }
+}
+
+/// Keys a wallet has bought in one market's presale, checked against the per-wallet cap
+#[account]
+pub struct PresalePurchase {
+    /// The allowlisted buyer
+    pub buyer: Pubkey,
+    /// The creator whose presale this is
+    pub keys_user: Pubkey,
+    /// Keys bought in the presale so far
+    pub amount: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PresalePurchase {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // buyer
+        32 + // keys_user
+        8 + // amount
+        1; // bump
+
+    /// Count `amount` more keys against the wallet's presale cap
+    pub fn record(&mut self, amount: u64, max_per_wallet: u64) -> Result<()> {
+        let total = self.amount
+            .checked_add(amount)
+            .ok_or(SolSocialError::ArithmeticOverflow)?;
+        require!(total <= max_per_wallet, SolSocialError::PresaleWalletCapReached);
+        self.amount = total;
+        Ok(())
+    }
+}
+
+// Seeds for PDA derivation
+pub const PRESALE_PURCHASE_SEED: &[u8] = b"presale_purchase";
+
+pub fn get_presale_purchase_pda(buyer: &Pubkey, keys_user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PRESALE_PURCHASE_SEED, buyer.as_ref(), keys_user.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            hashv(&[&a, &b]).to_bytes()
+        } else {
+            hashv(&[&b, &a]).to_bytes()
+        }
+    }
+
+    fn presale(merkle_root: [u8; 32]) -> Presale {
+        let params = PresaleParams {
+            merkle_root,
+            price: 5_000_000,
+            max_per_wallet: 2,
+            keys_for_sale: 10,
+            duration_seconds: 3_600,
+        };
+        Presale::new(&params, 1_000).unwrap()
+    }
+
+    #[test]
+    fn test_allowlist_proofs() {
+        let wallets: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = wallets.iter().map(Presale::leaf).collect();
+        let left = parent(leaves[0], leaves[1]);
+        let root = parent(left, leaves[2]);
+        let presale = presale(root);
+
+        assert!(presale.is_allowlisted(&wallets[0], &[leaves[1], leaves[2]]));
+        assert!(presale.is_allowlisted(&wallets[1], &[leaves[0], leaves[2]]));
+        assert!(presale.is_allowlisted(&wallets[2], &[left]));
+
+        assert!(!presale.is_allowlisted(&Pubkey::new_unique(), &[leaves[1], leaves[2]]));
+        assert!(!presale.is_allowlisted(&wallets[0], &[leaves[2]]));
+        assert!(!presale.is_allowlisted(&wallets[2], &vec![left; Presale::MAX_PROOF_LEN + 1]));
+    }
+
+    #[test]
+    fn test_presale_ends_on_sellout_or_expiry() {
+        let mut presale = presale([7; 32]);
+        assert!(presale.is_live(1_000));
+        assert!(!presale.is_live(1_000 + 3_600));
+
+        assert!(presale.record_sale(11).is_err());
+        presale.record_sale(10).unwrap();
+        assert!(!presale.is_live(1_001));
+        assert!(!Presale::default().is_live(0));
+    }
+
+    #[test]
+    fn test_wallet_cap() {
+        let mut purchase = PresalePurchase {
+            buyer: Pubkey::new_unique(),
+            keys_user: Pubkey::new_unique(),
+            amount: 0,
+            bump: 255,
+        };
+        purchase.record(1, 2).unwrap();
+        purchase.record(1, 2).unwrap();
+        assert!(purchase.record(1, 2).is_err());
+        assert_eq!(purchase.amount, 2);
+    }
+
+    #[test]
+    fn test_rejects_invalid_presales() {
+        let valid = PresaleParams {
+            merkle_root: [7; 32],
+            price: 5_000_000,
+            max_per_wallet: 2,
+            keys_for_sale: 10,
+            duration_seconds: 3_600,
+        };
+        assert!(Presale::new(&PresaleParams { merkle_root: [0; 32], ..valid }, 0).is_err());
+        assert!(Presale::new(&PresaleParams { price: 0, ..valid }, 0).is_err());
+        assert!(Presale::new(&PresaleParams { max_per_wallet: 11, ..valid }, 0).is_err());
+        assert!(Presale::new(&PresaleParams { duration_seconds: 0, ..valid }, 0).is_err());
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,177 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::PricePoint;
+
+/// Per-market price feed for other programs: a ring of post-trade prices and their TWAP
+#[account]
+pub struct PriceOracle {
+    /// The `UserKeys` market being tracked
+    pub market: Pubkey,
+    pub observations: [PricePoint; PriceOracle::CAPACITY],
+    /// Slot the next observation is written to
+    pub head: u8,
+    /// Number of valid observations (saturates at CAPACITY)
+    pub count: u8,
+    /// TWAP over `TWAP_WINDOW_SECONDS` as of `last_updated`, excluding that update's own trade
+    pub twap: u64,
+    /// Timestamp of the latest observation
+    pub last_updated: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PriceOracle {
+    pub const CAPACITY: usize = 32;
+
+    /// Horizon of the stored `twap`; consumers wanting another one call `twap_over`
+    pub const TWAP_WINDOW_SECONDS: i64 = 30 * 60;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // market
+        PricePoint::LEN * Self::CAPACITY + // observations
+        1 + // head
+        1 + // count
+        8 + // twap
+        8 + // last_updated
+        1; // bump
+
+    pub fn initialize(&mut self, market: Pubkey, bump: u8) {
+        self.market = market;
+        self.observations = [PricePoint::default(); Self::CAPACITY];
+        self.head = 0;
+        self.count = 0;
+        self.twap = 0;
+        self.last_updated = 0;
+        self.bump = bump;
+    }
+
+    /// Record a trade's price. Trades in the same second share one observation holding the
+    /// last price, so a burst of trades can't crowd older prices out of the ring.
+    pub fn record(&mut self, price: u64, now: i64) {
+        match self.latest() {
+            Some(latest) if latest.timestamp == now => {
+                let index = (self.head as usize + Self::CAPACITY - 1) % Self::CAPACITY;
+                self.observations[index].price = price;
+            }
+            _ => {
+                self.observations[self.head as usize] = PricePoint { price, timestamp: now };
+                self.head = ((self.head as usize + 1) % Self::CAPACITY) as u8;
+                if (self.count as usize) < Self::CAPACITY {
+                    self.count += 1;
+                }
+            }
+        }
+        // The new observation has held for no time yet, so it carries no weight here
+        self.twap = self.twap_over(now, Self::TWAP_WINDOW_SECONDS).unwrap_or(price);
+        self.last_updated = now;
+    }
+
+    pub fn latest(&self) -> Option<PricePoint> {
+        if self.count == 0 {
+            return None;
+        }
+        let index = (self.head as usize + Self::CAPACITY - 1) % Self::CAPACITY;
+        Some(self.observations[index])
+    }
+
+    /// Observations from oldest to newest
+    pub fn observations(&self) -> impl Iterator<Item = &PricePoint> {
+        let start = if (self.count as usize) < Self::CAPACITY { 0 } else { self.head as usize };
+        (0..self.count as usize).map(move |i| &self.observations[(start + i) % Self::CAPACITY])
+    }
+
+    /// Time-weighted average price over the last `window_seconds` before `now`. Each
+    /// observation holds until the next one; time before the oldest observation is ignored.
+    pub fn twap_over(&self, now: i64, window_seconds: i64) -> Option<u64> {
+        let latest = self.latest()?;
+        let from = now.saturating_sub(window_seconds);
+
+        let mut weighted: u128 = 0;
+        let mut elapsed: u128 = 0;
+        let mut observations = self.observations().peekable();
+        while let Some(point) = observations.next() {
+            let end = observations.peek().map_or(now, |next| next.timestamp);
+            let start = point.timestamp.max(from);
+            if end > start {
+                let span = (end - start) as u128;
+                weighted += point.price as u128 * span;
+                elapsed += span;
+            }
+        }
+
+        if elapsed == 0 {
+            return Some(latest.price);
+        }
+        Some((weighted / elapsed) as u64)
+    }
+}
+
+// Seeds for PDA derivation
+pub const PRICE_ORACLE_SEED: &[u8] = b"price_oracle";
+
+pub fn get_price_oracle_pda(market: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PRICE_ORACLE_SEED, market.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_oracle() -> PriceOracle {
+        PriceOracle {
+            market: Pubkey::new_unique(),
+            observations: [PricePoint::default(); PriceOracle::CAPACITY],
+            head: 0,
+            count: 0,
+            twap: 0,
+            last_updated: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_twap_weights_prices_by_how_long_they_held() {
+        let mut oracle = empty_oracle();
+        assert_eq!(oracle.twap_over(100, 60), None);
+
+        oracle.record(1_000, 0);
+        assert_eq!(oracle.twap, 1_000);
+
+        // 1_000 for 30s, then 4_000 for 10s
+        oracle.record(4_000, 30);
+        assert_eq!(oracle.twap, 1_000);
+        assert_eq!(oracle.twap_over(40, 3_600), Some(1_750));
+
+        // Only the last 20s count: 1_000 for 10s, 4_000 for 10s
+        assert_eq!(oracle.twap_over(40, 20), Some(2_500));
+    }
+
+    #[test]
+    fn test_same_second_trades_share_an_observation() {
+        let mut oracle = empty_oracle();
+        oracle.record(1_000, 10);
+        oracle.record(9_000, 20);
+        oracle.record(1_500, 20);
+
+        assert_eq!(oracle.count, 2);
+        assert_eq!(oracle.latest(), Some(PricePoint { price: 1_500, timestamp: 20 }));
+        // The spike never held for any time, so it never moves the average
+        assert_eq!(oracle.twap_over(30, 3_600), Some(1_250));
+    }
+
+    #[test]
+    fn test_ring_keeps_the_newest_observations_in_order() {
+        let mut oracle = empty_oracle();
+        let total = PriceOracle::CAPACITY as i64 + 5;
+        for t in 0..total {
+            oracle.record(t as u64 + 1, t);
+        }
+
+        assert_eq!(oracle.count as usize, PriceOracle::CAPACITY);
+        let timestamps: Vec<i64> = oracle.observations().map(|point| point.timestamp).collect();
+        assert_eq!(timestamps.first(), Some(&5));
+        assert_eq!(timestamps.last(), Some(&(total - 1)));
+        assert!(timestamps.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,182 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::error::SolSocialError;
+
+/// Switchboard On-Demand program that owns the randomness accounts raffles commit to
+pub const SWITCHBOARD_PROGRAM_ID: Pubkey = switchboard_on_demand::ON_DEMAND_MAINNET_PID;
+
+/// A creator-funded prize drawn among their key holders, weighted by keys held when the
+/// raffle was created
+#[account]
+pub struct Raffle {
+    /// The creator funding the prize; their market's holders enter
+    pub creator: Pubkey,
+    /// Creator-chosen identifier, part of the PDA seeds
+    pub raffle_id: u64,
+    /// Lamports held in this account for the winner
+    pub prize: u64,
+    /// Switchboard randomness account committed to at creation
+    pub randomness_account: Pubkey,
+    /// Holder wallets snapshotted at creation, in ascending key order
+    pub entrants: [Pubkey; 32],
+    /// Keys each entrant held at creation, parallel to `entrants`
+    pub weights: [u64; 32],
+    /// Slots of `entrants` in use
+    pub entrant_count: u8,
+    /// Sum of `weights`
+    pub total_weight: u64,
+    /// Timestamp when the raffle was created
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Raffle {
+    /// Every holder must be passed in one transaction, which bounds the market size
+    pub const MAX_ENTRANTS: usize = 32;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        8 + // raffle_id
+        8 + // prize
+        32 + // randomness_account
+        32 * Self::MAX_ENTRANTS + // entrants
+        8 * Self::MAX_ENTRANTS + // weights
+        1 + // entrant_count
+        8 + // total_weight
+        8 + // created_at
+        1; // bump
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        creator: Pubkey,
+        raffle_id: u64,
+        prize: u64,
+        randomness_account: Pubkey,
+        entries: &[(Pubkey, u64)],
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            prize > 0 && !entries.is_empty() && entries.len() <= Self::MAX_ENTRANTS,
+            SolSocialError::InvalidRaffle
+        );
+        // Strictly ascending wallets rule out entering the same holder twice
+        require!(
+            entries.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            SolSocialError::InvalidAccountSequence
+        );
+        require!(entries.iter().all(|(_, weight)| *weight > 0), SolSocialError::InvalidRaffle);
+
+        self.creator = creator;
+        self.raffle_id = raffle_id;
+        self.prize = prize;
+        self.randomness_account = randomness_account;
+        self.entrants = [Pubkey::default(); Self::MAX_ENTRANTS];
+        self.weights = [0; Self::MAX_ENTRANTS];
+        let mut total_weight: u64 = 0;
+        for (index, (entrant, weight)) in entries.iter().enumerate() {
+            self.entrants[index] = *entrant;
+            self.weights[index] = *weight;
+            total_weight = total_weight
+                .checked_add(*weight)
+                .ok_or(SolSocialError::ArithmeticOverflow)?;
+        }
+        self.entrant_count = entries.len() as u8;
+        self.total_weight = total_weight;
+        self.created_at = now;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// The entrant whose range of keys contains the random ticket
+    pub fn pick(&self, randomness: &[u8; 32]) -> Option<Pubkey> {
+        if self.total_weight == 0 {
+            return None;
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&randomness[..8]);
+        let mut ticket = u64::from_le_bytes(bytes) % self.total_weight;
+
+        for index in 0..self.entrant_count as usize {
+            if ticket < self.weights[index] {
+                return Some(self.entrants[index]);
+            }
+            ticket -= self.weights[index];
+        }
+        None
+    }
+}
+
+// Seeds for PDA derivation
+pub const RAFFLE_SEED: &[u8] = b"raffle";
+
+pub fn get_raffle_pda(creator: &Pubkey, raffle_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RAFFLE_SEED, creator.as_ref(), &raffle_id.to_le_bytes()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_raffle() -> Raffle {
+        Raffle {
+            creator: Pubkey::default(),
+            raffle_id: 0,
+            prize: 0,
+            randomness_account: Pubkey::default(),
+            entrants: [Pubkey::default(); Raffle::MAX_ENTRANTS],
+            weights: [0; Raffle::MAX_ENTRANTS],
+            entrant_count: 0,
+            total_weight: 0,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    fn ticket(value: u64) -> [u8; 32] {
+        let mut randomness = [0xffu8; 32];
+        randomness[..8].copy_from_slice(&value.to_le_bytes());
+        randomness
+    }
+
+    fn sorted_wallets(count: usize) -> Vec<Pubkey> {
+        let mut wallets: Vec<Pubkey> = (0..count).map(|_| Pubkey::new_unique()).collect();
+        wallets.sort();
+        wallets
+    }
+
+    #[test]
+    fn test_pick_weights_entrants_by_keys_held() {
+        let wallets = sorted_wallets(3);
+        let mut raffle = empty_raffle();
+        raffle
+            .initialize(Pubkey::new_unique(), 0, 1_000, Pubkey::new_unique(), &[(wallets[0], 1), (wallets[1], 5), (wallets[2], 4)], 0, 254)
+            .unwrap();
+        assert_eq!(raffle.total_weight, 10);
+
+        // Tickets 0 | 1..=5 | 6..=9, wrapping at the total weight
+        assert_eq!(raffle.pick(&ticket(0)), Some(wallets[0]));
+        assert_eq!(raffle.pick(&ticket(1)), Some(wallets[1]));
+        assert_eq!(raffle.pick(&ticket(5)), Some(wallets[1]));
+        assert_eq!(raffle.pick(&ticket(6)), Some(wallets[2]));
+        assert_eq!(raffle.pick(&ticket(19)), Some(wallets[2]));
+        assert_eq!(raffle.pick(&ticket(20)), Some(wallets[0]));
+    }
+
+    #[test]
+    fn test_entrants_must_be_unique_and_holding() {
+        let wallets = sorted_wallets(2);
+        let mut raffle = empty_raffle();
+        let creator = Pubkey::new_unique();
+        let randomness = Pubkey::new_unique();
+
+        assert!(raffle.initialize(creator, 0, 1_000, randomness, &[(wallets[1], 1), (wallets[0], 1)], 0, 254).is_err());
+        assert!(raffle.initialize(creator, 0, 1_000, randomness, &[(wallets[0], 1), (wallets[0], 1)], 0, 254).is_err());
+        assert!(raffle.initialize(creator, 0, 1_000, randomness, &[(wallets[0], 0)], 0, 254).is_err());
+        assert!(raffle.initialize(creator, 0, 0, randomness, &[(wallets[0], 1)], 0, 254).is_err());
+        assert!(raffle.initialize(creator, 0, 1_000, randomness, &[], 0, 254).is_err());
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,138 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// One executed trade as seen by the price-history ring
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct TradeSample {
+    /// Average price per key paid or received, in lamports
+    pub price_per_key: u64,
+    pub timestamp: i64,
+    pub is_buy: bool,
+}
+
+impl TradeSample {
+    pub const SPACE: usize = 8 + 8 + 1;
+}
+
+/// Fixed-size ring of a market's most recent trades
+#[account]
+pub struct RecentTrades {
+    /// The `UserKeys` market being tracked
+    pub market: Pubkey,
+    pub trades: [TradeSample; RecentTrades::CAPACITY],
+    /// Slot the next trade is written to
+    pub head: u8,
+    /// Number of valid samples (saturates at CAPACITY)
+    pub count: u8,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl RecentTrades {
+    pub const CAPACITY: usize = 16;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // market
+        TradeSample::SPACE * Self::CAPACITY + // trades
+        1 + // head
+        1 + // count
+        1; // bump
+
+    pub fn initialize(&mut self, market: Pubkey, bump: u8) {
+        self.market = market;
+        self.trades = [TradeSample::default(); Self::CAPACITY];
+        self.head = 0;
+        self.count = 0;
+        self.bump = bump;
+    }
+
+    pub fn record(&mut self, price_per_key: u64, timestamp: i64, is_buy: bool) {
+        self.trades[self.head as usize] = TradeSample {
+            price_per_key,
+            timestamp,
+            is_buy,
+        };
+        self.head = ((self.head as usize + 1) % Self::CAPACITY) as u8;
+        if (self.count as usize) < Self::CAPACITY {
+            self.count += 1;
+        }
+    }
+
+    pub fn latest(&self) -> Option<TradeSample> {
+        if self.count == 0 {
+            return None;
+        }
+        let index = (self.head as usize + Self::CAPACITY - 1) % Self::CAPACITY;
+        Some(self.trades[index])
+    }
+
+    pub fn samples(&self) -> impl Iterator<Item = &TradeSample> {
+        self.trades.iter().take(self.count as usize)
+    }
+
+    /// Highest per-key price among the recorded trades
+    pub fn peak_price(&self) -> u64 {
+        self.samples().map(|trade| trade.price_per_key).max().unwrap_or(0)
+    }
+
+    /// How far the latest trade sits below the ring's peak, in basis points
+    pub fn drop_from_peak_bps(&self) -> u16 {
+        let peak = self.peak_price();
+        let latest = match self.latest() {
+            Some(trade) => trade.price_per_key,
+            None => return 0,
+        };
+        if peak == 0 || latest >= peak {
+            return 0;
+        }
+        ((peak - latest) as u128 * 10_000 / peak as u128) as u16
+    }
+}
+
+// Seeds for PDA derivation
+pub const RECENT_TRADES_SEED: &[u8] = b"recent_trades";
+
+pub fn get_recent_trades_pda(market: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RECENT_TRADES_SEED, market.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_ring() -> RecentTrades {
+        RecentTrades {
+            market: Pubkey::new_unique(),
+            trades: [TradeSample::default(); RecentTrades::CAPACITY],
+            head: 0,
+            count: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_drop_from_peak() {
+        let mut ring = empty_ring();
+        assert_eq!(ring.drop_from_peak_bps(), 0);
+
+        ring.record(1_000, 1, true);
+        ring.record(2_000, 2, true);
+        ring.record(1_500, 3, false);
+        assert_eq!(ring.peak_price(), 2_000);
+        assert_eq!(ring.drop_from_peak_bps(), 2_500);
+    }
+
+    #[test]
+    fn test_ring_wraps_and_forgets_old_peaks() {
+        let mut ring = empty_ring();
+        ring.record(10_000, 0, true);
+        for i in 0..RecentTrades::CAPACITY {
+            ring.record(1_000, i as i64 + 1, true);
+        }
+
+        assert_eq!(ring.count as usize, RecentTrades::CAPACITY);
+        assert_eq!(ring.peak_price(), 1_000);
+        assert_eq!(ring.latest().unwrap().timestamp, RecentTrades::CAPACITY as i64);
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,100 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::utils::accounts::{create_pda_account, write_account};
+
+/// Binds a user to the wallet that referred them; set once at sign-up
+#[account]
+pub struct Referral {
+    /// The referred user's wallet
+    pub user: Pubkey,
+    /// Wallet that receives the referrer fee on the user's trades
+    pub referrer: Pubkey,
+    /// Referrer fees paid out on the user's trades
+    pub total_earned: u64,
+    /// Trades that paid a referrer fee
+    pub trades_referred: u64,
+    /// Timestamp when the referral was recorded
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Referral {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // user
+        32 + // referrer
+        8 + // total_earned
+        8 + // trades_referred
+        8 + // created_at
+        1; // bump
+
+    pub fn new(user: Pubkey, referrer: Pubkey, created_at: i64, bump: u8) -> Result<Self> {
+        require_keys_neq!(user, referrer, crate::error::SolSocialError::OperationNotAllowed);
+        Ok(Self {
+            user,
+            referrer,
+            total_earned: 0,
+            trades_referred: 0,
+            created_at,
+            bump,
+        })
+    }
+
+    pub fn record_fee(&mut self, fee: u64) {
+        if fee == 0 {
+            return;
+        }
+        self.total_earned = self.total_earned.saturating_add(fee);
+        self.trades_referred = self.trades_referred.saturating_add(1);
+    }
+
+    /// Create this record's PDA in `target` and write it, paid for by `payer`
+    pub fn record<'info>(
+        &self,
+        target: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        let (expected, _) = get_referral_pda(&self.user, program_id);
+        require_keys_eq!(target.key(), expected, crate::error::SolSocialError::InvalidAccountSequence);
+
+        create_pda_account(
+            payer,
+            target,
+            system_program,
+            Self::LEN,
+            &[REFERRAL_SEED, self.user.as_ref(), &[self.bump]],
+        )?;
+        write_account(target, self)
+    }
+}
+
+// Seeds for PDA derivation
+pub const REFERRAL_SEED: &[u8] = b"referral";
+
+pub fn get_referral_pda(user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REFERRAL_SEED, user.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_self_referral() {
+        let user = Pubkey::new_unique();
+        assert!(Referral::new(user, user, 0, 255).is_err());
+    }
+
+    #[test]
+    fn test_record_fee_counts_paying_trades() {
+        let mut referral = Referral::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 255).unwrap();
+        referral.record_fee(10_000);
+        referral.record_fee(0);
+        referral.record_fee(5_000);
+        assert_eq!(referral.total_earned, 15_000);
+        assert_eq!(referral.trades_referred, 2);
+    }
+}
+```
\ No newline at end of file
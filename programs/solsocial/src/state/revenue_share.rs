@@ -0,0 +1,146 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// One creator's cut of a shared market's creator fees
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct CollaboratorShare {
+    pub collaborator: Pubkey,
+    /// Share of the creator fees, scaled by 1e4; a market's shares add up to 10,000
+    pub share_bps: u16,
+}
+
+/// How a market launched by several creators splits its creator fees. The fees collect in
+/// the lead creator's earnings vault, and `distribute_shared_earnings` passes each other
+/// collaborator their share into their own vault.
+#[account]
+pub struct RevenueShare {
+    /// The lead creator, whose `UserKeys` market and earnings vault are shared
+    pub keys_user: Pubkey,
+    /// Collaborators in split order; the lead is always first
+    pub collaborators: [Pubkey; 4],
+    /// Each collaborator's share of the creator fees (scaled by 1e4)
+    pub share_bps: [u16; 4],
+    /// Entries of `collaborators` in use
+    pub collaborator_count: u8,
+    /// Lamports passed from the lead's vault to the other collaborators
+    pub total_distributed: u64,
+    /// Timestamp when the market was shared
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl RevenueShare {
+    pub const MAX_COLLABORATORS: usize = 4;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // keys_user
+        32 * Self::MAX_COLLABORATORS + // collaborators
+        2 * Self::MAX_COLLABORATORS + // share_bps
+        1 + // collaborator_count
+        8 + // total_distributed
+        8 + // created_at
+        1; // bump
+
+    /// Record a split between `lead` and the other collaborators in `shares`, which must
+    /// list the lead first, name every creator once and add up to 100%
+    pub fn initialize(&mut self, lead: Pubkey, shares: &[CollaboratorShare], now: i64, bump: u8) -> Result<()> {
+        require!(
+            (2..=Self::MAX_COLLABORATORS).contains(&shares.len()) && shares[0].collaborator == lead,
+            crate::error::SolSocialError::InvalidRevenueShare
+        );
+        let mut total_bps = 0u32;
+        for (i, share) in shares.iter().enumerate() {
+            require!(
+                share.share_bps > 0
+                    && share.collaborator != Pubkey::default()
+                    && !shares[..i].iter().any(|earlier| earlier.collaborator == share.collaborator),
+                crate::error::SolSocialError::InvalidRevenueShare
+            );
+            total_bps += share.share_bps as u32;
+        }
+        require!(total_bps == 10_000, crate::error::SolSocialError::InvalidRevenueShare);
+
+        self.keys_user = lead;
+        self.collaborators = [Pubkey::default(); Self::MAX_COLLABORATORS];
+        self.share_bps = [0; Self::MAX_COLLABORATORS];
+        for (i, share) in shares.iter().enumerate() {
+            self.collaborators[i] = share.collaborator;
+            self.share_bps[i] = share.share_bps;
+        }
+        self.collaborator_count = shares.len() as u8;
+        self.total_distributed = 0;
+        self.created_at = now;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Each non-lead collaborator's cut of `amount`, in split order; the lead keeps the rest,
+    /// rounding dust included
+    pub fn split(&self, amount: u64) -> Result<Vec<u64>> {
+        self.share_bps[1..self.collaborator_count as usize]
+            .iter()
+            .map(|&bps| crate::utils::revenue_share::calculate_fee(amount, bps))
+            .collect()
+    }
+
+    pub fn record_distribution(&mut self, amount: u64) -> Result<()> {
+        self.total_distributed = self.total_distributed
+            .checked_add(amount)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+// Seeds for PDA derivation
+pub const REVENUE_SHARE_SEED: &[u8] = b"revenue_share";
+
+pub fn get_revenue_share_pda(keys_user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REVENUE_SHARE_SEED, keys_user.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty() -> RevenueShare {
+        RevenueShare {
+            keys_user: Pubkey::default(),
+            collaborators: [Pubkey::default(); RevenueShare::MAX_COLLABORATORS],
+            share_bps: [0; RevenueShare::MAX_COLLABORATORS],
+            collaborator_count: 0,
+            total_distributed: 0,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    fn share(collaborator: Pubkey, share_bps: u16) -> CollaboratorShare {
+        CollaboratorShare { collaborator, share_bps }
+    }
+
+    #[test]
+    fn test_split_leaves_dust_with_the_lead() {
+        let (lead, a, b) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut revenue_share = empty();
+        revenue_share
+            .initialize(lead, &[share(lead, 5_000), share(a, 3_000), share(b, 2_000)], 0, 255)
+            .unwrap();
+        assert_eq!(revenue_share.split(10_001).unwrap(), vec![3_000, 2_000]);
+    }
+
+    #[test]
+    fn test_split_must_cover_everyone_once() {
+        let (lead, a) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut revenue_share = empty();
+        // Solo, short of 100%, lead not first, duplicated collaborator
+        assert!(revenue_share.initialize(lead, &[share(lead, 10_000)], 0, 255).is_err());
+        assert!(revenue_share.initialize(lead, &[share(lead, 5_000), share(a, 4_000)], 0, 255).is_err());
+        assert!(revenue_share.initialize(lead, &[share(a, 5_000), share(lead, 5_000)], 0, 255).is_err());
+        assert!(revenue_share
+            .initialize(lead, &[share(lead, 5_000), share(a, 2_500), share(a, 2_500)], 0, 255)
+            .is_err());
+        assert!(revenue_share.initialize(lead, &[share(lead, 10_000), share(a, 0)], 0, 255).is_err());
+    }
+}
+```
\ No newline at end of file
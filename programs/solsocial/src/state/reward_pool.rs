@@ -0,0 +1,100 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// Lamport pool funded by a slice of protocol fees; staking and dividend payouts draw from it
+#[account]
+pub struct RewardPool {
+    /// Total lamports ever deposited from trading fees
+    pub total_deposited: u64,
+    /// Total lamports paid out to reward claimants
+    pub total_distributed: u64,
+    /// Timestamp of the most recent deposit
+    pub last_deposit_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved space for future upgrades
+    pub reserved: [u8; 64],
+}
+
+impl RewardPool {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // total_deposited
+        8 + // total_distributed
+        8 + // last_deposit_at
+        1 + // bump
+        64; // reserved
+
+    pub fn initialize(&mut self, bump: u8) -> Result<()> {
+        self.total_deposited = 0;
+        self.total_distributed = 0;
+        self.last_deposit_at = 0;
+        self.bump = bump;
+        self.reserved = [0; 64];
+
+        Ok(())
+    }
+
+    pub fn record_deposit(&mut self, amount: u64, timestamp: i64) -> Result<()> {
+        self.total_deposited = self.total_deposited
+            .checked_add(amount)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        self.last_deposit_at = timestamp;
+        Ok(())
+    }
+
+    pub fn record_distribution(&mut self, amount: u64) -> Result<()> {
+        let distributed = self.total_distributed
+            .checked_add(amount)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        require!(
+            distributed <= self.total_deposited,
+            crate::error::SolSocialError::InsufficientFunds
+        );
+        self.total_distributed = distributed;
+        Ok(())
+    }
+
+    /// Take back `amount` of an earlier distribution that was never paid out
+    pub fn record_return(&mut self, amount: u64) -> Result<()> {
+        self.total_distributed = self.total_distributed
+            .checked_sub(amount)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Lamports deposited but not yet distributed
+    pub fn available(&self) -> u64 {
+        self.total_deposited.saturating_sub(self.total_distributed)
+    }
+}
+
+// Seeds for PDA derivation
+pub const REWARD_POOL_SEED: &[u8] = b"reward_pool";
+
+pub fn get_reward_pool_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REWARD_POOL_SEED], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distribution_cannot_exceed_deposits() {
+        let mut pool = RewardPool {
+            total_deposited: 0,
+            total_distributed: 0,
+            last_deposit_at: 0,
+            bump: 0,
+            reserved: [0; 64],
+        };
+
+        pool.record_deposit(1_000, 1).unwrap();
+        pool.record_distribution(600).unwrap();
+        assert_eq!(pool.available(), 400);
+
+        assert!(pool.record_distribution(401).is_err());
+        assert_eq!(pool.total_distributed, 600);
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,181 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// A large sell split into tranches that a keeper executes over time
+#[account]
+pub struct SellQueue {
+    /// Wallet selling the keys and receiving the proceeds
+    pub seller: Pubkey,
+    /// The `UserKeys` market being sold into
+    pub subject_keys: Pubkey,
+    /// Keys queued when the sell was placed
+    pub total_amount: u64,
+    /// Keys still escrowed and waiting to be sold
+    pub remaining_amount: u64,
+    /// Keys sold per tranche (the last tranche may be smaller)
+    pub tranche_size: u64,
+    /// Minimum slots between two tranches
+    pub tranche_interval_slots: u64,
+    /// First slot the next tranche may execute in
+    pub next_tranche_slot: u64,
+    /// Tranches fail while per-key proceeds sit below this floor
+    pub min_price_per_key: u64,
+    /// Number of tranches executed so far
+    pub tranches_executed: u32,
+    /// Lamports paid to the seller across all tranches
+    pub total_proceeds: u64,
+    /// Timestamp when the sell was queued
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SellQueue {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // seller
+        32 + // subject_keys
+        8 + // total_amount
+        8 + // remaining_amount
+        8 + // tranche_size
+        8 + // tranche_interval_slots
+        8 + // next_tranche_slot
+        8 + // min_price_per_key
+        4 + // tranches_executed
+        8 + // total_proceeds
+        8 + // created_at
+        1; // bump
+
+    /// Most tranches a single queued sell may be split into
+    pub const MAX_TRANCHES: u64 = 100;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        seller: Pubkey,
+        subject_keys: Pubkey,
+        amount: u64,
+        tranche_size: u64,
+        tranche_interval_slots: u64,
+        min_price_per_key: u64,
+        slot: u64,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(amount > 0 && tranche_size > 0, crate::error::SolSocialError::InvalidAmount);
+        require!(tranche_size < amount, crate::error::SolSocialError::InvalidAmount);
+        require!(
+            amount.div_ceil(tranche_size) <= Self::MAX_TRANCHES,
+            crate::error::SolSocialError::InvalidAmount
+        );
+        require!(tranche_interval_slots > 0, crate::error::SolSocialError::InvalidConfigValue);
+
+        self.seller = seller;
+        self.subject_keys = subject_keys;
+        self.total_amount = amount;
+        self.remaining_amount = amount;
+        self.tranche_size = tranche_size;
+        self.tranche_interval_slots = tranche_interval_slots;
+        self.next_tranche_slot = slot;
+        self.min_price_per_key = min_price_per_key;
+        self.tranches_executed = 0;
+        self.total_proceeds = 0;
+        self.created_at = now;
+        self.bump = bump;
+
+        Ok(())
+    }
+
+    /// Size of the tranche due at `slot`, if any
+    pub fn due_tranche(&self, slot: u64) -> Option<u64> {
+        if self.remaining_amount == 0 || slot < self.next_tranche_slot {
+            return None;
+        }
+        Some(self.tranche_size.min(self.remaining_amount))
+    }
+
+    pub fn record_tranche(&mut self, amount: u64, proceeds: u64, slot: u64) -> Result<()> {
+        self.remaining_amount = self.remaining_amount
+            .checked_sub(amount)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        self.total_proceeds = self.total_proceeds
+            .checked_add(proceeds)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        self.tranches_executed = self.tranches_executed.saturating_add(1);
+        self.next_tranche_slot = slot.saturating_add(self.tranche_interval_slots);
+        Ok(())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.remaining_amount == 0
+    }
+}
+
+// Seeds for PDA derivation
+pub const SELL_QUEUE_SEED: &[u8] = b"sell_queue";
+pub const SELL_QUEUE_ESCROW_SEED: &[u8] = b"sell_queue_escrow";
+
+pub fn get_sell_queue_pda(seller: &Pubkey, subject_keys: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SELL_QUEUE_SEED, seller.as_ref(), subject_keys.as_ref()], program_id)
+}
+
+pub fn get_sell_queue_escrow_pda(sell_queue: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SELL_QUEUE_ESCROW_SEED, sell_queue.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue(amount: u64, tranche_size: u64, interval: u64) -> SellQueue {
+        let mut queue = SellQueue {
+            seller: Pubkey::default(),
+            subject_keys: Pubkey::default(),
+            total_amount: 0,
+            remaining_amount: 0,
+            tranche_size: 0,
+            tranche_interval_slots: 0,
+            next_tranche_slot: 0,
+            min_price_per_key: 0,
+            tranches_executed: 0,
+            total_proceeds: 0,
+            created_at: 0,
+            bump: 0,
+        };
+        queue
+            .initialize(Pubkey::new_unique(), Pubkey::new_unique(), amount, tranche_size, interval, 0, 100, 0, 255)
+            .unwrap();
+        queue
+    }
+
+    #[test]
+    fn test_tranches_are_spaced_and_sized() {
+        let mut queue = queue(25, 10, 5);
+
+        assert_eq!(queue.due_tranche(100), Some(10));
+        queue.record_tranche(10, 1_000, 100).unwrap();
+        assert_eq!(queue.due_tranche(104), None);
+
+        assert_eq!(queue.due_tranche(105), Some(10));
+        queue.record_tranche(10, 900, 105).unwrap();
+
+        // Last tranche only sells what's left
+        assert_eq!(queue.due_tranche(110), Some(5));
+        queue.record_tranche(5, 400, 110).unwrap();
+
+        assert!(queue.is_complete());
+        assert_eq!(queue.due_tranche(1_000), None);
+        assert_eq!(queue.tranches_executed, 3);
+        assert_eq!(queue.total_proceeds, 2_300);
+    }
+
+    #[test]
+    fn test_rejects_degenerate_schedules() {
+        let mut empty = queue(2, 1, 1);
+        assert!(empty.initialize(Pubkey::default(), Pubkey::default(), 5, 5, 1, 0, 0, 0, 0).is_err());
+        assert!(empty.initialize(Pubkey::default(), Pubkey::default(), 5, 1, 0, 0, 0, 0, 0).is_err());
+        assert!(empty
+            .initialize(Pubkey::default(), Pubkey::default(), 1_000, 1, 1, 0, 0, 0, 0)
+            .is_err());
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,184 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::utils::revenue_share::calculate_staking_rewards;
+
+/// A holder's staked keys in one market. Staked keys sit in the position's vault, out of
+/// the holder's sellable token balance, but stay on their `KeyHolder` record for gating and votes.
+#[account]
+pub struct StakePosition {
+    /// Wallet that staked the keys
+    pub owner: Pubkey,
+    /// Subject whose keys are staked
+    pub subject: Pubkey,
+    /// Keys currently in the vault
+    pub staked_amount: u64,
+    /// Curve value of the staked keys when they went in; rewards accrue on this
+    pub staked_value: u64,
+    /// Rewards accrued but not yet claimed, in lamports
+    pub pending_rewards: u64,
+    /// Rewards paid out over the position's lifetime
+    pub total_claimed: u64,
+    /// Rewards are accrued in whole days up to this timestamp
+    pub last_accrued_at: i64,
+    /// Timestamp when the position was opened
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl StakePosition {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        32 + // subject
+        8 + // staked_amount
+        8 + // staked_value
+        8 + // pending_rewards
+        8 + // total_claimed
+        8 + // last_accrued_at
+        8 + // created_at
+        1; // bump
+
+    /// Yearly staking yield, paid from the reward pool (5%)
+    pub const ANNUAL_RATE_BPS: u16 = 500;
+
+    const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+    pub fn initialize(&mut self, owner: Pubkey, subject: Pubkey, now: i64, bump: u8) {
+        self.owner = owner;
+        self.subject = subject;
+        self.staked_amount = 0;
+        self.staked_value = 0;
+        self.pending_rewards = 0;
+        self.total_claimed = 0;
+        self.last_accrued_at = now;
+        self.created_at = now;
+        self.bump = bump;
+    }
+
+    /// Move the rewards for every whole day since the last accrual into `pending_rewards`.
+    /// A partial day keeps counting toward the next accrual.
+    pub fn accrue(&mut self, now: i64) -> Result<()> {
+        let days = now.saturating_sub(self.last_accrued_at).max(0) / Self::SECONDS_PER_DAY;
+        if days == 0 {
+            return Ok(());
+        }
+
+        let earned = calculate_staking_rewards(self.staked_value, days as u64, Self::ANNUAL_RATE_BPS)?;
+        self.pending_rewards = self.pending_rewards
+            .checked_add(earned)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        self.last_accrued_at += days * Self::SECONDS_PER_DAY;
+        Ok(())
+    }
+
+    /// Add `amount` keys worth `value` lamports; call `accrue` first
+    pub fn stake(&mut self, amount: u64, value: u64) -> Result<()> {
+        self.staked_amount = self.staked_amount
+            .checked_add(amount)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        self.staked_value = self.staked_value
+            .checked_add(value)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Take `amount` keys out, releasing their share of the staked value; call `accrue` first
+    pub fn unstake(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount > 0 && amount <= self.staked_amount,
+            crate::error::SolSocialError::InsufficientKeys
+        );
+        let released = (self.staked_value as u128 * amount as u128 / self.staked_amount as u128) as u64;
+        self.staked_value -= released;
+        self.staked_amount -= amount;
+        Ok(())
+    }
+
+    /// Pay out as much of the pending rewards as the pool can cover, returning the payout
+    pub fn take_rewards(&mut self, pool_available: u64) -> Result<u64> {
+        let payout = self.pending_rewards.min(pool_available);
+        require!(payout > 0, crate::error::SolSocialError::NothingToClaim);
+        self.pending_rewards -= payout;
+        self.total_claimed = self.total_claimed.saturating_add(payout);
+        Ok(payout)
+    }
+}
+
+// Seeds for PDA derivation
+pub const STAKE_POSITION_SEED: &[u8] = b"stake_position";
+pub const STAKE_VAULT_SEED: &[u8] = b"stake_vault";
+
+pub fn get_stake_position_pda(owner: &Pubkey, subject: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKE_POSITION_SEED, owner.as_ref(), subject.as_ref()], program_id)
+}
+
+pub fn get_stake_vault_pda(stake_position: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKE_VAULT_SEED, stake_position.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn position() -> StakePosition {
+        let mut position = StakePosition {
+            owner: Pubkey::default(),
+            subject: Pubkey::default(),
+            staked_amount: 0,
+            staked_value: 0,
+            pending_rewards: 0,
+            total_claimed: 0,
+            last_accrued_at: 0,
+            created_at: 0,
+            bump: 0,
+        };
+        position.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1_000, 255);
+        position
+    }
+
+    #[test]
+    fn test_accrues_whole_days_only() {
+        let mut position = position();
+        position.stake(10, 7_300_000_000).unwrap();
+
+        // Less than a day earns nothing and doesn't reset the clock
+        position.accrue(1_000 + DAY - 1).unwrap();
+        assert_eq!(position.pending_rewards, 0);
+        assert_eq!(position.last_accrued_at, 1_000);
+
+        // 5% a year on 7.3 SOL is 0.001 SOL a day
+        position.accrue(1_000 + 2 * DAY + 500).unwrap();
+        assert_eq!(position.pending_rewards, 2_000_000);
+        assert_eq!(position.last_accrued_at, 1_000 + 2 * DAY);
+    }
+
+    #[test]
+    fn test_unstake_releases_proportional_value() {
+        let mut position = position();
+        position.stake(4, 4_000_000).unwrap();
+        position.unstake(1).unwrap();
+        assert_eq!(position.staked_amount, 3);
+        assert_eq!(position.staked_value, 3_000_000);
+
+        assert!(position.unstake(4).is_err());
+        assert!(position.unstake(0).is_err());
+        position.unstake(3).unwrap();
+        assert_eq!(position.staked_value, 0);
+    }
+
+    #[test]
+    fn test_rewards_capped_by_pool() {
+        let mut position = position();
+        position.pending_rewards = 500;
+
+        assert_eq!(position.take_rewards(200).unwrap(), 200);
+        assert_eq!(position.pending_rewards, 300);
+        assert_eq!(position.take_rewards(1_000).unwrap(), 300);
+        assert_eq!(position.total_claimed, 500);
+
+        assert!(position.take_rewards(1_000).is_err());
+    }
+}
+```
\ No newline at end of file
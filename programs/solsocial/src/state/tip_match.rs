@@ -0,0 +1,117 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// Creator-funded escrow that tops up tips on the creator's posts
+#[account]
+pub struct TipMatch {
+    /// Creator whose incoming tips are matched
+    pub creator: Pubkey,
+    /// Matched lamports per tipped lamport, in basis points
+    pub match_ratio_bps: u16,
+    /// Lamports still available for matching
+    pub remaining_budget: u64,
+    /// Lamports ever deposited by the creator
+    pub total_funded: u64,
+    /// Lamports paid out as matches
+    pub total_matched: u64,
+    /// Timestamp when the match was created
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl TipMatch {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        2 + // match_ratio_bps
+        8 + // remaining_budget
+        8 + // total_funded
+        8 + // total_matched
+        8 + // created_at
+        1; // bump
+
+    /// Highest ratio a creator may offer (1:1)
+    pub const MAX_MATCH_RATIO_BPS: u16 = 10_000;
+
+    pub fn initialize(&mut self, creator: Pubkey, now: i64, bump: u8) {
+        self.creator = creator;
+        self.match_ratio_bps = 0;
+        self.remaining_budget = 0;
+        self.total_funded = 0;
+        self.total_matched = 0;
+        self.created_at = now;
+        self.bump = bump;
+    }
+
+    pub fn set_ratio(&mut self, match_ratio_bps: u16) -> Result<()> {
+        require!(
+            match_ratio_bps <= Self::MAX_MATCH_RATIO_BPS,
+            crate::error::SolSocialError::InvalidFeePercentage
+        );
+        self.match_ratio_bps = match_ratio_bps;
+        Ok(())
+    }
+
+    pub fn fund(&mut self, amount: u64) -> Result<()> {
+        self.remaining_budget = self.remaining_budget
+            .checked_add(amount)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        self.total_funded = self.total_funded
+            .checked_add(amount)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Match owed on a `tip`, capped by what's left in the budget
+    pub fn match_for(&self, tip: u64) -> u64 {
+        let matched = (tip as u128 * self.match_ratio_bps as u128 / 10_000) as u64;
+        matched.min(self.remaining_budget)
+    }
+
+    pub fn record_match(&mut self, amount: u64) -> Result<()> {
+        self.remaining_budget = self.remaining_budget
+            .checked_sub(amount)
+            .ok_or(crate::error::SolSocialError::InsufficientFunds)?;
+        self.total_matched = self.total_matched.saturating_add(amount);
+        Ok(())
+    }
+}
+
+// Seeds for PDA derivation
+pub const TIP_MATCH_SEED: &[u8] = b"tip_match";
+
+pub fn get_tip_match_pda(creator: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TIP_MATCH_SEED, creator.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_is_capped_by_budget() {
+        let mut tip_match = TipMatch {
+            creator: Pubkey::new_unique(),
+            match_ratio_bps: 0,
+            remaining_budget: 0,
+            total_funded: 0,
+            total_matched: 0,
+            created_at: 0,
+            bump: 0,
+        };
+        tip_match.set_ratio(5_000).unwrap();
+        tip_match.fund(700).unwrap();
+
+        assert_eq!(tip_match.match_for(1_000), 500);
+        tip_match.record_match(500).unwrap();
+
+        // Only 200 left: the next match is partial, then matching stops
+        assert_eq!(tip_match.match_for(1_000), 200);
+        tip_match.record_match(200).unwrap();
+        assert_eq!(tip_match.match_for(1_000), 0);
+        assert_eq!(tip_match.total_matched, 700);
+
+        assert!(tip_match.set_ratio(TipMatch::MAX_MATCH_RATIO_BPS + 1).is_err());
+    }
+}
+```
\ No newline at end of file
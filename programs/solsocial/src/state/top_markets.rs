@@ -0,0 +1,156 @@
+```rust
+use anchor_lang::prelude::*;
+use std::cmp::Ordering;
+
+/// One market's standing in the [`TopMarkets`] index
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct MarketRank {
+    /// The `UserKeys` market
+    pub market: Pubkey,
+    pub total_volume: u64,
+    pub holders_count: u64,
+    pub created_at: i64,
+}
+
+impl MarketRank {
+    pub const SPACE: usize = 32 + 8 + 8 + 8;
+
+    /// Ranking order: higher volume, then more holders, then the older market,
+    /// then the lower market address, so every client derives the same ranking
+    pub fn rank_cmp(&self, other: &Self) -> Ordering {
+        other.total_volume
+            .cmp(&self.total_volume)
+            .then_with(|| other.holders_count.cmp(&self.holders_count))
+            .then_with(|| self.created_at.cmp(&other.created_at))
+            .then_with(|| self.market.to_bytes().cmp(&other.market.to_bytes()))
+    }
+}
+
+/// Leaderboard of the highest-volume key markets, kept sorted best-first
+#[account]
+pub struct TopMarkets {
+    pub entries: [MarketRank; TopMarkets::CAPACITY],
+    /// Number of valid entries at the front of `entries`
+    pub count: u8,
+    /// Timestamp of the last refresh
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl TopMarkets {
+    pub const CAPACITY: usize = 20;
+
+    pub const LEN: usize = 8 + // discriminator
+        MarketRank::SPACE * Self::CAPACITY + // entries
+        1 + // count
+        8 + // updated_at
+        1; // bump
+
+    pub fn ranked(&self) -> &[MarketRank] {
+        &self.entries[..self.count as usize]
+    }
+
+    /// Insert or refresh `rank`, keeping the index sorted and evicting the
+    /// lowest-ranked entry when full. Returns whether the market is listed.
+    pub fn upsert(&mut self, rank: MarketRank) -> bool {
+        let mut entries: Vec<MarketRank> = self
+            .ranked()
+            .iter()
+            .filter(|entry| entry.market != rank.market)
+            .copied()
+            .collect();
+
+        let position = entries
+            .binary_search_by(|entry| entry.rank_cmp(&rank))
+            .unwrap_or_else(|position| position);
+        entries.insert(position, rank);
+        entries.truncate(Self::CAPACITY);
+
+        self.count = entries.len() as u8;
+        self.entries = [MarketRank::default(); Self::CAPACITY];
+        self.entries[..entries.len()].copy_from_slice(&entries);
+
+        position < Self::CAPACITY
+    }
+}
+
+// Seeds for PDA derivation
+pub const TOP_MARKETS_SEED: &[u8] = b"top_markets";
+
+pub fn get_top_markets_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TOP_MARKETS_SEED], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_index() -> TopMarkets {
+        TopMarkets {
+            entries: [MarketRank::default(); TopMarkets::CAPACITY],
+            count: 0,
+            updated_at: 0,
+            bump: 0,
+        }
+    }
+
+    fn rank(seed: u8, total_volume: u64, holders_count: u64, created_at: i64) -> MarketRank {
+        MarketRank {
+            market: Pubkey::new_from_array([seed; 32]),
+            total_volume,
+            holders_count,
+            created_at,
+        }
+    }
+
+    #[test]
+    fn test_tied_volume_breaks_on_holders_then_age() {
+        let ranks = [
+            rank(1, 500, 3, 100),
+            rank(2, 500, 7, 100),
+            rank(3, 500, 7, 50),
+            rank(4, 900, 1, 300),
+        ];
+
+        // Every insertion order yields the same ranking
+        for order in [[0, 1, 2, 3], [3, 2, 1, 0], [1, 3, 0, 2], [2, 0, 3, 1]] {
+            let mut index = empty_index();
+            for i in order {
+                index.upsert(ranks[i]);
+            }
+            let markets: Vec<u8> = index.ranked().iter().map(|entry| entry.market.to_bytes()[0]).collect();
+            assert_eq!(markets, vec![4, 3, 2, 1]);
+        }
+    }
+
+    #[test]
+    fn test_fully_tied_markets_order_by_address() {
+        let mut forward = empty_index();
+        let mut backward = empty_index();
+        for seed in 1..=3 {
+            forward.upsert(rank(seed, 100, 1, 0));
+            backward.upsert(rank(4 - seed, 100, 1, 0));
+        }
+        assert_eq!(forward.ranked(), backward.ranked());
+        assert_eq!(forward.ranked()[0].market, Pubkey::new_from_array([1; 32]));
+    }
+
+    #[test]
+    fn test_refresh_moves_market_and_full_index_evicts_lowest() {
+        let mut index = empty_index();
+        for seed in 0..TopMarkets::CAPACITY as u8 {
+            assert!(index.upsert(rank(seed, 1_000 + seed as u64, 0, 0)));
+        }
+
+        // Below everything listed: not admitted
+        assert!(!index.upsert(rank(200, 10, 0, 0)));
+        assert_eq!(index.count as usize, TopMarkets::CAPACITY);
+
+        // A refreshed market is re-ranked rather than duplicated
+        assert!(index.upsert(rank(0, 5_000, 0, 0)));
+        assert_eq!(index.ranked()[0].market, Pubkey::new_from_array([0; 32]));
+        assert_eq!(index.count as usize, TopMarkets::CAPACITY);
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,151 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// Condition a [`TriggerOrder`] waits for before selling
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriggerKind {
+    /// Sell once the price falls to `trigger_price` or below
+    StopLoss,
+    /// Sell once the price rises to `trigger_price` or above
+    TakeProfit,
+}
+
+/// A conditional sell of escrowed keys, executed by any keeper once the market's oracle TWAP
+/// crosses the trigger. The keys stay in the owner's token account, delegated to the order.
+#[account]
+pub struct TriggerOrder {
+    /// The wallet that placed the order
+    pub owner: Pubkey,
+    /// The user whose keys are being sold
+    pub keys_user: Pubkey,
+    pub kind: TriggerKind,
+    /// Oracle TWAP (in lamports per key) that sets the order off
+    pub trigger_price: u64,
+    /// Keys sold when the order executes
+    pub amount: u64,
+    /// Least the owner accepts for the whole sale after fees, in lamports
+    pub min_proceeds: u64,
+    /// Lamports escrowed in this account for the keeper that executes the order
+    pub keeper_bounty: u64,
+    /// Per-owner order sequence number used in the PDA seeds
+    pub order_id: u64,
+    /// Timestamp when the order was placed
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved space for future upgrades
+    pub reserved: [u8; 24],
+}
+
+impl TriggerOrder {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        32 + // keys_user
+        1 + // kind
+        8 + // trigger_price
+        8 + // amount
+        8 + // min_proceeds
+        8 + // keeper_bounty
+        8 + // order_id
+        8 + // created_at
+        1 + // bump
+        24; // reserved
+
+    /// Lamports escrowed at placement and paid to whoever executes the order
+    pub const KEEPER_BOUNTY: u64 = 10_000;
+
+    pub fn initialize(
+        &mut self,
+        owner: Pubkey,
+        keys_user: Pubkey,
+        kind: TriggerKind,
+        trigger_price: u64,
+        amount: u64,
+        min_proceeds: u64,
+        order_id: u64,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            amount > 0 && trigger_price > 0,
+            crate::error::SolSocialError::InvalidOrder
+        );
+
+        self.owner = owner;
+        self.keys_user = keys_user;
+        self.kind = kind;
+        self.trigger_price = trigger_price;
+        self.amount = amount;
+        self.min_proceeds = min_proceeds;
+        self.keeper_bounty = Self::KEEPER_BOUNTY;
+        self.order_id = order_id;
+        self.created_at = now;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Whether `twap`, the market's oracle price, has crossed the trigger. A single trade
+    /// can't set the order off by briefly moving the spot price.
+    pub fn is_triggered(&self, twap: u64) -> bool {
+        match self.kind {
+            TriggerKind::StopLoss => twap <= self.trigger_price,
+            TriggerKind::TakeProfit => twap >= self.trigger_price,
+        }
+    }
+}
+
+// Seeds for PDA derivation
+pub const TRIGGER_ORDER_SEED: &[u8] = b"trigger_order";
+
+pub fn get_trigger_order_pda(owner: &Pubkey, keys_user: &Pubkey, order_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TRIGGER_ORDER_SEED, owner.as_ref(), keys_user.as_ref(), &order_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(kind: TriggerKind) -> TriggerOrder {
+        TriggerOrder {
+            owner: Pubkey::new_unique(),
+            keys_user: Pubkey::new_unique(),
+            kind,
+            trigger_price: 1_000,
+            amount: 1,
+            min_proceeds: 0,
+            keeper_bounty: TriggerOrder::KEEPER_BOUNTY,
+            order_id: 0,
+            created_at: 0,
+            bump: 255,
+            reserved: [0; 24],
+        }
+    }
+
+    #[test]
+    fn test_triggers_fire_at_and_past_the_price() {
+        let stop_loss = order(TriggerKind::StopLoss);
+        assert!(!stop_loss.is_triggered(1_001));
+        assert!(stop_loss.is_triggered(1_000));
+        assert!(stop_loss.is_triggered(999));
+
+        let take_profit = order(TriggerKind::TakeProfit);
+        assert!(!take_profit.is_triggered(999));
+        assert!(take_profit.is_triggered(1_000));
+        assert!(take_profit.is_triggered(1_001));
+    }
+
+    #[test]
+    fn test_rejects_empty_or_unpriced_orders() {
+        let mut order = order(TriggerKind::StopLoss);
+        let owner = order.owner;
+        let keys_user = order.keys_user;
+        assert!(order.initialize(owner, keys_user, TriggerKind::StopLoss, 1_000, 0, 0, 0, 0, 255).is_err());
+        assert!(order.initialize(owner, keys_user, TriggerKind::StopLoss, 0, 1, 0, 0, 0, 255).is_err());
+        assert!(order.initialize(owner, keys_user, TriggerKind::TakeProfit, 1_000, 1, 500, 0, 0, 255).is_ok());
+        assert_eq!(order.min_proceeds, 500);
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,66 @@
+```rust
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnlockMethod {
+    /// The reader held the post's required keys
+    KeyHolding,
+    /// The reader paid the one-time unlock price
+    Payment,
+}
+
+/// A reader's standing access to a premium post, one per (post, reader). Key servers
+/// release the post's decryption key to readers with an unlock.
+#[account]
+pub struct Unlock {
+    pub post: Pubkey,
+    pub reader: Pubkey,
+    pub method: UnlockMethod,
+    /// Lamports paid for a `Payment` unlock, 0 otherwise
+    pub amount_paid: u64,
+    pub unlocked_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Unlock {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // post
+        32 + // reader
+        1 + // method
+        8 + // amount_paid
+        8 + // unlocked_at
+        1; // bump
+}
+
+/// Keys a reader must hold for a premium post; premium posts without a threshold take one
+pub fn unlock_key_requirement(required_keys: u64) -> u64 {
+    required_keys.max(1)
+}
+
+/// One-time price of a premium post: what its required keys are worth at the spot price
+pub fn unlock_price(key_price: u64, required_keys: u64) -> Option<u64> {
+    key_price.checked_mul(unlock_key_requirement(required_keys))
+}
+
+// Seeds for PDA derivation
+pub const UNLOCK_SEED: &[u8] = b"unlock";
+
+pub fn get_unlock_pda(post: &Pubkey, reader: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[UNLOCK_SEED, post.as_ref(), reader.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_covers_at_least_one_key() {
+        assert_eq!(unlock_key_requirement(0), 1);
+        assert_eq!(unlock_key_requirement(5), 5);
+        assert_eq!(unlock_price(2_000_000, 0), Some(2_000_000));
+        assert_eq!(unlock_price(2_000_000, 3), Some(6_000_000));
+        assert_eq!(unlock_price(u64::MAX, 2), None);
+    }
+}
+```
\ No newline at end of file
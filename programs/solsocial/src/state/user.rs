@@ -1,11 +1,16 @@
 ```rust
 use anchor_lang::prelude::*;
+use super::PostVisibility;
+use crate::utils::validation::is_valid_url;
 
 #[account]
 pub struct User {
     /// The user's wallet public key
     pub authority: Pubkey,
     
+    /// Unique handle, registered in a `UsernameRecord`
+    pub username: String,
+    
     /// Display name for the user
     pub name: String,
     
@@ -63,13 +68,62 @@ pub struct User {
     /// Timestamp when the account was last updated
     pub updated_at: i64,
     
+    /// Visibility applied to new posts when none is specified
+    pub default_post_visibility: PostVisibility,
+    
+    /// Share of tips on this user's posts paid to the sharer that drove them (bps)
+    pub share_reward_bps: u16,
+    
+    /// Total declared bytes of media attached across all of this user's posts
+    pub total_media_bytes: u64,
+    
+    /// Timestamp of the user's last post, trade or message
+    pub last_activity: i64,
+    
+    /// Keys of their own market the user must keep holding to publish gated posts (0 = off)
+    pub min_self_hold: u64,
+    
+    /// Total keys bought across all markets
+    pub total_keys_purchased: u64,
+    
+    /// Total SOL received from key sales
+    pub total_trading_volume: u64,
+    
+    /// Whether the user bought into a market as its first buyer
+    pub is_early_supporter: bool,
+    
+    /// Likes given on other users' posts
+    pub total_likes_given: u64,
+    
+    /// Comments left on posts
+    pub total_comments: u64,
+    
+    /// Posts shared
+    pub total_shares: u64,
+    
+    /// Group chats created
+    pub chat_count: u64,
+    
+    /// Chat messages sent
+    pub messages_sent: u64,
+    
+    /// Total SOL tipped to the user directly
+    pub total_tips_received: u64,
+    
+    /// Layout version, see [`User::CURRENT_VERSION`]
+    pub version: u8,
+    
+    /// PDA bump
+    pub bump: u8,
+    
     /// Reserved space for future upgrades
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 34],
 }
 
 impl User {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
+        4 + 32 + // username (max 32 chars)
         4 + 50 + // name (max 50 chars)
         4 + 200 + // bio (max 200 chars)
         4 + 100 + // profile_image (max 100 chars)
@@ -89,7 +143,30 @@ impl User {
         1 + // is_active
         8 + // created_at
         8 + // updated_at
-        128; // reserved
+        1 + // default_post_visibility
+        2 + // share_reward_bps
+        8 + // total_media_bytes
+        8 + // last_activity
+        8 + // min_self_hold
+        8 + // total_keys_purchased
+        8 + // total_trading_volume
+        1 + // is_early_supporter
+        8 + // total_likes_given
+        8 + // total_comments
+        8 + // total_shares
+        8 + // chat_count
+        8 + // messages_sent
+        8 + // total_tips_received
+        1 + // version
+        1 + // bump
+        34; // reserved
+    
+    /// Layout written by `initialize_user`. Version 0 accounts predate the activity
+    /// counters and stored bump and need `migrate_user` before seed-checked use.
+    pub const CURRENT_VERSION: u8 = 1;
+    
+    /// Upper bound on the share-to-earn reward (50%)
+    pub const MAX_SHARE_REWARD_BPS: u16 = 5000;
     
     pub fn initialize(
         &mut self,
@@ -107,6 +184,14 @@ impl User {
         require!(bio.len() <= 200, crate::error::SolSocialError::BioTooLong);
         require!(profile_image.len() <= 100, crate::error::SolSocialError::ImageUrlTooLong);
         require!(banner_image.len() <= 100, crate::error::SolSocialError::ImageUrlTooLong);
+        require!(
+            profile_image.is_empty() || is_valid_url(&profile_image),
+            crate::error::SolSocialError::InvalidProfileImageUrl
+        );
+        require!(
+            banner_image.is_empty() || is_valid_url(&banner_image),
+            crate::error::SolSocialError::InvalidProfileImageUrl
+        );
         require!(twitter.len() <= 50, crate::error::SolSocialError::TwitterTooLong);
         require!(discord.len() <= 50, crate::error::SolSocialError::DiscordTooLong);
         require!(website.len() <= 100, crate::error::SolSocialError::WebsiteTooLong);
@@ -131,7 +216,22 @@ impl User {
         self.is_active = true;
         self.created_at = clock.unix_timestamp;
         self.updated_at = clock.unix_timestamp;
-        self.reserved = [0; 128];
+        self.default_post_visibility = PostVisibility::Public;
+        self.share_reward_bps = 0;
+        self.total_media_bytes = 0;
+        self.last_activity = clock.unix_timestamp;
+        self.min_self_hold = 0;
+        self.total_keys_purchased = 0;
+        self.total_trading_volume = 0;
+        self.is_early_supporter = false;
+        self.total_likes_given = 0;
+        self.total_comments = 0;
+        self.total_shares = 0;
+        self.chat_count = 0;
+        self.messages_sent = 0;
+        self.total_tips_received = 0;
+        self.version = Self::CURRENT_VERSION;
+        self.reserved = [0; 34];
         
         Ok(())
     }
@@ -157,13 +257,22 @@ impl User {
             self.bio = bio;
         }
         
+        // An empty image clears it, as at `initialize`; anything else has to be a valid URL
         if let Some(profile_image) = profile_image {
             require!(profile_image.len() <= 100, crate::error::SolSocialError::ImageUrlTooLong);
+            require!(
+                profile_image.is_empty() || is_valid_url(&profile_image),
+                crate::error::SolSocialError::InvalidProfileImageUrl
+            );
             self.profile_image = profile_image;
         }
         
         if let Some(banner_image) = banner_image {
             require!(banner_image.len() <= 100, crate::error::SolSocialError::ImageUrlTooLong);
+            require!(
+                banner_image.is_empty() || is_valid_url(&banner_image),
+                crate::error::SolSocialError::InvalidProfileImageUrl
+            );
             self.banner_image = banner_image;
         }
         
@@ -187,6 +296,20 @@ impl User {
         Ok(())
     }
     
+    /// Whether the profile has been set up by `initialize_user`
+    pub fn is_initialized(&self) -> bool {
+        self.authority != Pubkey::default()
+    }
+    
+    /// Bring a version 0 account onto the current layout. The new fields were carved
+    /// out of zeroed reserved space, so only the bump and version need writing.
+    pub fn migrate(&mut self, bump: u8) -> Result<()> {
+        require!(self.version < Self::CURRENT_VERSION, crate::error::SolSocialError::AlreadyMigrated);
+        self.bump = bump;
+        self.version = Self::CURRENT_VERSION;
+        Ok(())
+    }
+    
     pub fn increment_keys_created(&mut self) {
         self.keys_created = self.keys_created.saturating_add(1);
     }
@@ -219,6 +342,14 @@ impl User {
         self.following_count = self.following_count.saturating_sub(1);
     }
     
+    pub fn add_media_bytes(&mut self, bytes: u64) {
+        self.total_media_bytes = self.total_media_bytes.saturating_add(bytes);
+    }
+    
+    pub fn release_media_bytes(&mut self, bytes: u64) {
+        self.total_media_bytes = self.total_media_bytes.saturating_sub(bytes);
+    }
+    
     pub fn add_earnings(&mut self, amount: u64) {
         self.total_earnings = self.total_earnings.saturating_add(amount);
     }
@@ -248,6 +379,29 @@ impl User {
         self.is_active = active;
     }
     
+    pub fn set_default_post_visibility(&mut self, visibility: PostVisibility) {
+        self.default_post_visibility = visibility;
+    }
+    
+    pub fn set_share_reward_bps(&mut self, bps: u16) -> Result<()> {
+        require!(
+            bps <= Self::MAX_SHARE_REWARD_BPS,
+            crate::error::SolSocialError::InvalidFeePercentage
+        );
+        self.share_reward_bps = bps;
+        Ok(())
+    }
+    
+    /// Whether holding `held` of their own keys still entitles the user to publish gated posts
+    pub fn meets_self_hold(&self, held: u64) -> bool {
+        held >= self.min_self_hold
+    }
+    
+    /// Resolves the visibility for a new post, falling back to the user's default
+    pub fn resolve_post_visibility(&self, requested: Option<PostVisibility>) -> PostVisibility {
+        requested.unwrap_or_else(|| self.default_post_visibility.clone())
+    }
+    
     pub fn get_trading_fee_discount(&self) -> u64 {
         // Higher reputation users get trading fee discounts
         // Max 50% discount for users with 1000+ reputation
@@ -262,6 +416,22 @@ impl User {
         }
     }
     
+    /// `fee_bps` less the user's reputation discount
+    pub fn discounted_fee_bps(&self, fee_bps: u16) -> u16 {
+        Self::apply_fee_discount(fee_bps, self.get_trading_fee_discount())
+    }
+    
+    /// Take `discount_pct` percent off `fee_bps`, rounding the discount down
+    pub fn apply_fee_discount(fee_bps: u16, discount_pct: u64) -> u16 {
+        let discount = (fee_bps as u64).saturating_mul(discount_pct.min(100)) / 100;
+        fee_bps - discount as u16
+    }
+    
+    /// Most recent time the user was seen doing anything on-chain
+    pub fn last_seen(&self) -> i64 {
+        std::cmp::max(self.last_activity, self.updated_at)
+    }
+    
     pub fn can_create_keys(&self) -> bool {
         self.is_active && self.reputation >= 50
     }
@@ -275,6 +445,46 @@ impl User {
     }
 }
 
+#[account]
+pub struct UsernameRecord {
+    /// The username this record reserves
+    pub username: String,
+    
+    /// Wallet currently owning the username
+    pub owner: Pubkey,
+    
+    /// Timestamp when the username was registered or last reclaimed
+    pub claimed_at: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl UsernameRecord {
+    pub const LEN: usize = 8 + // discriminator
+        4 + 32 + // username (max 32 chars)
+        32 + // owner
+        8 + // claimed_at
+        1; // bump
+    
+    /// How long an owner must be inactive before their username can be reclaimed
+    pub const RECLAIM_INACTIVITY_SECONDS: i64 = 365 * 24 * 60 * 60;
+    
+    pub fn initialize(&mut self, username: String, owner: Pubkey, clock: &Clock, bump: u8) -> Result<()> {
+        self.username = username;
+        self.owner = owner;
+        self.claimed_at = clock.unix_timestamp;
+        self.bump = bump;
+        
+        Ok(())
+    }
+    
+    /// Whether an owner last seen at `last_seen` has been inactive long enough at `now`
+    pub fn is_inactive(last_seen: i64, now: i64) -> bool {
+        now.saturating_sub(last_seen) >= Self::RECLAIM_INACTIVITY_SECONDS
+    }
+}
+
 #[account]
 pub struct UserFollow {
     /// The user who is following
@@ -407,4 +617,118 @@ impl UserStats {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_username_reclaim_inactivity_threshold() {
+        let last_seen = 1_700_000_000;
+        let threshold = UsernameRecord::RECLAIM_INACTIVITY_SECONDS;
+
+        assert!(!UsernameRecord::is_inactive(last_seen, last_seen));
+        assert!(!UsernameRecord::is_inactive(last_seen, last_seen + threshold - 1));
+        assert!(UsernameRecord::is_inactive(last_seen, last_seen + threshold));
+        assert!(UsernameRecord::is_inactive(last_seen, last_seen + threshold * 2));
+    }
+
+    #[test]
+    fn test_fee_discount_rounds_in_the_traders_disfavor() {
+        assert_eq!(User::apply_fee_discount(500, 0), 500);
+        assert_eq!(User::apply_fee_discount(500, 10), 450);
+        assert_eq!(User::apply_fee_discount(500, 50), 250);
+        assert_eq!(User::apply_fee_discount(5, 25), 4);
+        assert_eq!(User::apply_fee_discount(500, 250), 0);
+    }
+
+    #[test]
+    fn test_future_activity_is_not_inactive() {
+        assert!(!UsernameRecord::is_inactive(2_000, 1_000));
+    }
+
+    #[test]
+    fn test_unified_layout_fits_and_migrates() {
+        let clock = Clock::default();
+        let mut user = User {
+            authority: Pubkey::default(),
+            username: String::new(),
+            name: String::new(),
+            bio: String::new(),
+            profile_image: String::new(),
+            banner_image: String::new(),
+            twitter: String::new(),
+            discord: String::new(),
+            website: String::new(),
+            keys_created: 0,
+            keys_owned: 0,
+            post_count: 0,
+            follower_count: 0,
+            following_count: 0,
+            total_earnings: 0,
+            total_spent: 0,
+            reputation: 0,
+            is_verified: false,
+            is_active: false,
+            created_at: 0,
+            updated_at: 0,
+            default_post_visibility: PostVisibility::Public,
+            share_reward_bps: 0,
+            total_media_bytes: 0,
+            last_activity: 0,
+            min_self_hold: 0,
+            total_keys_purchased: 0,
+            total_trading_volume: 0,
+            is_early_supporter: false,
+            total_likes_given: 0,
+            total_comments: 0,
+            total_shares: 0,
+            chat_count: 0,
+            messages_sent: 0,
+            total_tips_received: 0,
+            version: 0,
+            bump: 0,
+            reserved: [0; 34],
+        };
+        assert!(!user.is_initialized());
+
+        // A version 0 account picks up its bump once and is then current
+        user.migrate(254).unwrap();
+        assert_eq!((user.bump, user.version), (254, User::CURRENT_VERSION));
+        assert!(user.migrate(254).is_err());
+
+        let url = format!("https://{}", "a".repeat(92));
+        user.initialize(
+            Pubkey::new_unique(),
+            "n".repeat(50),
+            "b".repeat(200),
+            url.clone(),
+            url.clone(),
+            "t".repeat(50),
+            "d".repeat(50),
+            url,
+            &clock,
+        )
+        .unwrap();
+        user.username = "u".repeat(32);
+        assert!(user.is_initialized());
+        assert_eq!(user.version, User::CURRENT_VERSION);
+
+        let serialized = user.try_to_vec().unwrap();
+        assert_eq!(8 + serialized.len(), User::LEN);
+
+        // Profile fields beyond the layout are rejected instead of overflowing the account
+        assert!(user
+            .initialize(Pubkey::new_unique(), "n".repeat(64), String::new(), String::new(), String::new(), String::new(), String::new(), String::new(), &clock)
+            .is_err());
+
+        // Updates can clear an image with an empty value but not store a malformed one
+        user.update_profile(None, None, Some(String::new()), Some(String::new()), None, None, None, &clock)
+            .unwrap();
+        assert!(user.profile_image.is_empty() && user.banner_image.is_empty());
+        assert!(user
+            .update_profile(None, None, Some(String::from("javascript:alert(1)")), None, None, None, None, &clock)
+            .is_err());
+    }
+}
 ```
\ No newline at end of file
@@ -0,0 +1,162 @@
+```rust
+use anchor_lang::prelude::*;
+
+/// A creator allocation reserved at market creation and released on a cliff-then-linear schedule
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct VestingParams {
+    /// Keys reserved for the creator
+    pub amount: u64,
+    /// Seconds after creation before any key can be claimed
+    pub cliff_seconds: i64,
+    /// Seconds after creation until every key has vested; equal to the cliff for a pure cliff
+    pub duration_seconds: i64,
+}
+
+#[account]
+pub struct Vesting {
+    /// The creator the keys vest to
+    pub creator: Pubkey,
+    /// Keys reserved at creation
+    pub total_amount: u64,
+    /// Keys claimed so far
+    pub claimed_amount: u64,
+    /// Timestamp vesting is measured from
+    pub start_at: i64,
+    /// Nothing can be claimed before this timestamp
+    pub cliff_at: i64,
+    /// Every key has vested from this timestamp on
+    pub end_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Reserved space for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl Vesting {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        8 + // total_amount
+        8 + // claimed_amount
+        8 + // start_at
+        8 + // cliff_at
+        8 + // end_at
+        1 + // bump
+        32; // reserved
+
+    /// Longest schedule a creator may choose
+    pub const MAX_DURATION_SECONDS: i64 = 4 * 365 * 24 * 60 * 60;
+
+    pub fn new(creator: Pubkey, params: &VestingParams, now: i64, bump: u8) -> Result<Self> {
+        require!(
+            params.amount > 0
+                && params.cliff_seconds >= 0
+                && params.duration_seconds > 0
+                && params.cliff_seconds <= params.duration_seconds
+                && params.duration_seconds <= Self::MAX_DURATION_SECONDS,
+            crate::error::SolSocialError::InvalidVestingSchedule
+        );
+
+        Ok(Self {
+            creator,
+            total_amount: params.amount,
+            claimed_amount: 0,
+            start_at: now,
+            cliff_at: now + params.cliff_seconds,
+            end_at: now + params.duration_seconds,
+            bump,
+            reserved: [0; 32],
+        })
+    }
+
+    /// Keys vested by `now`: none before the cliff, then linearly from `start_at` to `end_at`
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now < self.cliff_at {
+            return 0;
+        }
+        if now >= self.end_at {
+            return self.total_amount;
+        }
+        let elapsed = (now - self.start_at) as u128;
+        let duration = (self.end_at - self.start_at) as u128;
+        (self.total_amount as u128 * elapsed / duration) as u64
+    }
+
+    pub fn claimable(&self, now: i64) -> u64 {
+        self.vested_amount(now).saturating_sub(self.claimed_amount)
+    }
+
+    /// Mark everything vested by `now` as claimed and return how many keys that released
+    pub fn record_claim(&mut self, now: i64) -> Result<u64> {
+        let amount = self.claimable(now);
+        require!(amount > 0, crate::error::SolSocialError::NothingToClaim);
+        self.claimed_amount += amount;
+        Ok(amount)
+    }
+}
+
+// Seeds for PDA derivation
+pub const VESTING_SEED: &[u8] = b"vesting";
+
+pub fn get_vesting_pda(creator: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VESTING_SEED, creator.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(amount: u64, cliff_seconds: i64, duration_seconds: i64) -> Vesting {
+        Vesting::new(
+            Pubkey::new_unique(),
+            &VestingParams { amount, cliff_seconds, duration_seconds },
+            1_000,
+            255,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_nothing_vests_before_the_cliff_then_linearly() {
+        let vesting = schedule(100, 250, 1_000);
+        assert_eq!(vesting.vested_amount(1_000), 0);
+        assert_eq!(vesting.vested_amount(1_249), 0);
+        // The cliff releases everything accrued since the start
+        assert_eq!(vesting.vested_amount(1_250), 25);
+        assert_eq!(vesting.vested_amount(1_500), 50);
+        assert_eq!(vesting.vested_amount(2_000), 100);
+        assert_eq!(vesting.vested_amount(i64::MAX), 100);
+    }
+
+    #[test]
+    fn test_pure_cliff_releases_everything_at_once() {
+        let vesting = schedule(40, 600, 600);
+        assert_eq!(vesting.vested_amount(1_599), 0);
+        assert_eq!(vesting.vested_amount(1_600), 40);
+    }
+
+    #[test]
+    fn test_claims_release_only_new_keys() {
+        let mut vesting = schedule(100, 0, 1_000);
+        assert_eq!(vesting.record_claim(1_300).unwrap(), 30);
+        assert!(vesting.record_claim(1_300).is_err());
+        assert_eq!(vesting.record_claim(1_350).unwrap(), 5);
+        assert_eq!(vesting.record_claim(5_000).unwrap(), 65);
+        assert_eq!(vesting.claimed_amount, 100);
+        assert!(vesting.record_claim(6_000).is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_schedules() {
+        let creator = Pubkey::new_unique();
+        let new = |amount, cliff_seconds, duration_seconds| {
+            Vesting::new(creator, &VestingParams { amount, cliff_seconds, duration_seconds }, 0, 255)
+        };
+        assert!(new(0, 0, 100).is_err());
+        assert!(new(10, -1, 100).is_err());
+        assert!(new(10, 0, 0).is_err());
+        assert!(new(10, 200, 100).is_err());
+        assert!(new(10, 0, Vesting::MAX_DURATION_SECONDS + 1).is_err());
+        assert!(new(10, 0, Vesting::MAX_DURATION_SECONDS).is_ok());
+    }
+}
+```
\ No newline at end of file
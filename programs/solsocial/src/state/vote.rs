@@ -0,0 +1,266 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::state::MAX_POLL_OPTIONS;
+
+/// A creator's poll of their key holders, one vote per holder or weighted by keys held
+#[account]
+pub struct HolderVote {
+    /// The creator whose holders vote
+    pub creator: Pubkey,
+    /// Creator-chosen identifier, part of the PDA seeds
+    pub vote_id: u64,
+    pub question: String,
+    pub options: Vec<String>,
+    /// Key-weighted tally per option, parallel to `options`
+    pub tallies: Vec<u64>,
+    /// Whether each vote counts the voter's keys rather than one per holder
+    pub key_weighted: bool,
+    /// Sum of all cast weights
+    pub total_weight: u64,
+    pub voter_count: u64,
+    /// Votes are accepted until this timestamp
+    pub deadline: i64,
+    pub created_at: i64,
+    pub is_finalized: bool,
+    /// Index of the leading option, set at finalization
+    pub winning_option: Option<u8>,
+    pub bump: u8,
+}
+
+impl HolderVote {
+    pub const MAX_QUESTION_LENGTH: usize = 200;
+    pub const MAX_OPTION_LENGTH: usize = 50;
+    pub const MAX_DURATION_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        8 + // vote_id
+        4 + Self::MAX_QUESTION_LENGTH + // question
+        4 + MAX_POLL_OPTIONS * (4 + Self::MAX_OPTION_LENGTH) + // options
+        4 + MAX_POLL_OPTIONS * 8 + // tallies
+        1 + // key_weighted
+        8 + // total_weight
+        8 + // voter_count
+        8 + // deadline
+        8 + // created_at
+        1 + // is_finalized
+        1 + 1 + // winning_option
+        1; // bump
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        creator: Pubkey,
+        vote_id: u64,
+        question: String,
+        options: Vec<String>,
+        key_weighted: bool,
+        deadline: i64,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            !question.trim().is_empty() && question.len() <= Self::MAX_QUESTION_LENGTH,
+            crate::error::SolSocialError::InvalidVote
+        );
+        require!(
+            options.len() >= 2 && options.len() <= MAX_POLL_OPTIONS,
+            crate::error::SolSocialError::InvalidVote
+        );
+        require!(
+            options.iter().all(|option| !option.trim().is_empty() && option.len() <= Self::MAX_OPTION_LENGTH),
+            crate::error::SolSocialError::InvalidVote
+        );
+        require!(
+            deadline > now && deadline - now <= Self::MAX_DURATION_SECONDS,
+            crate::error::SolSocialError::InvalidTimestamp
+        );
+
+        self.creator = creator;
+        self.vote_id = vote_id;
+        self.question = question;
+        self.tallies = vec![0; options.len()];
+        self.options = options;
+        self.key_weighted = key_weighted;
+        self.total_weight = 0;
+        self.voter_count = 0;
+        self.deadline = deadline;
+        self.created_at = now;
+        self.is_finalized = false;
+        self.winning_option = None;
+        self.bump = bump;
+
+        Ok(())
+    }
+
+    pub fn is_open(&self, now: i64) -> bool {
+        !self.is_finalized && now < self.deadline
+    }
+
+    /// Weight of a vote from a holder of `keys_held` keys; non-holders weigh nothing either way
+    pub fn vote_weight(&self, keys_held: u64) -> u64 {
+        if self.key_weighted {
+            keys_held
+        } else {
+            keys_held.min(1)
+        }
+    }
+
+    pub fn record_vote(&mut self, option_index: u8, weight: u64, now: i64) -> Result<()> {
+        require!(self.is_open(now), crate::error::SolSocialError::VotingClosed);
+        require!(weight > 0, crate::error::SolSocialError::InsufficientKeys);
+
+        let tally = self.tallies
+            .get_mut(option_index as usize)
+            .ok_or(crate::error::SolSocialError::InvalidVote)?;
+        *tally = tally.checked_add(weight).ok_or(crate::error::SolSocialError::MathOverflow)?;
+
+        self.total_weight = self.total_weight
+            .checked_add(weight)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+        self.voter_count = self.voter_count
+            .checked_add(1)
+            .ok_or(crate::error::SolSocialError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Close the vote after its deadline and record the leading option (ties go to the lower index)
+    pub fn finalize(&mut self, now: i64) -> Result<()> {
+        require!(!self.is_finalized, crate::error::SolSocialError::VotingClosed);
+        require!(now >= self.deadline, crate::error::SolSocialError::VotingStillOpen);
+
+        self.is_finalized = true;
+        self.winning_option = if self.total_weight == 0 {
+            None
+        } else {
+            self.tallies
+                .iter()
+                .enumerate()
+                .max_by(|(a_index, a), (b_index, b)| a.cmp(b).then(b_index.cmp(a_index)))
+                .map(|(index, _)| index as u8)
+        };
+
+        Ok(())
+    }
+}
+
+/// Proof that a holder already voted, one per (vote, voter)
+#[account]
+pub struct HolderVoteReceipt {
+    pub vote: Pubkey,
+    pub voter: Pubkey,
+    pub option_index: u8,
+    /// Weight the vote counted for: keys held when cast, or 1 on unweighted polls
+    pub weight: u64,
+    pub voted_at: i64,
+    pub bump: u8,
+}
+
+impl HolderVoteReceipt {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vote
+        32 + // voter
+        1 + // option_index
+        8 + // weight
+        8 + // voted_at
+        1; // bump
+}
+
+// Seeds for PDA derivation
+pub const HOLDER_VOTE_SEED: &[u8] = b"holder_vote";
+pub const HOLDER_VOTE_RECEIPT_SEED: &[u8] = b"vote_receipt";
+
+pub fn get_holder_vote_pda(creator: &Pubkey, vote_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[HOLDER_VOTE_SEED, creator.as_ref(), &vote_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+pub fn get_holder_vote_receipt_pda(vote: &Pubkey, voter: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HOLDER_VOTE_RECEIPT_SEED, vote.as_ref(), voter.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_vote(options: usize) -> HolderVote {
+        let mut vote = HolderVote {
+            creator: Pubkey::default(),
+            vote_id: 0,
+            question: String::new(),
+            options: vec![],
+            tallies: vec![],
+            key_weighted: false,
+            total_weight: 0,
+            voter_count: 0,
+            deadline: 0,
+            created_at: 0,
+            is_finalized: false,
+            winning_option: None,
+            bump: 0,
+        };
+        vote.initialize(
+            Pubkey::new_unique(),
+            1,
+            "Ship the roadmap?".to_string(),
+            (0..options).map(|i| format!("Option {}", i)).collect(),
+            true,
+            1_000,
+            0,
+            255,
+        )
+        .unwrap();
+        vote
+    }
+
+    #[test]
+    fn test_weighted_tally() {
+        let mut vote = open_vote(3);
+
+        // One whale outweighs two small holders
+        vote.record_vote(0, 2, 10).unwrap();
+        vote.record_vote(0, 3, 11).unwrap();
+        vote.record_vote(2, 10, 12).unwrap();
+
+        assert_eq!(vote.tallies, vec![5, 0, 10]);
+        assert_eq!(vote.total_weight, 15);
+        assert_eq!(vote.voter_count, 3);
+
+        assert!(vote.finalize(999).is_err());
+        vote.finalize(1_000).unwrap();
+        assert_eq!(vote.winning_option, Some(2));
+        assert!(vote.record_vote(1, 1, 1_001).is_err());
+    }
+
+    #[test]
+    fn test_unweighted_polls_count_one_vote_per_holder() {
+        let mut vote = open_vote(2);
+        assert_eq!(vote.vote_weight(10), 10);
+
+        vote.key_weighted = false;
+        assert_eq!(vote.vote_weight(10), 1);
+        assert_eq!(vote.vote_weight(1), 1);
+        assert_eq!(vote.vote_weight(0), 0);
+    }
+
+    #[test]
+    fn test_rejects_bad_votes() {
+        let mut vote = open_vote(2);
+        assert!(vote.record_vote(2, 1, 10).is_err());
+        assert!(vote.record_vote(0, 0, 10).is_err());
+        assert!(vote.record_vote(0, 1, 1_000).is_err());
+    }
+
+    #[test]
+    fn test_tie_goes_to_lower_index() {
+        let mut vote = open_vote(2);
+        vote.record_vote(1, 4, 10).unwrap();
+        vote.record_vote(0, 4, 10).unwrap();
+        vote.finalize(1_000).unwrap();
+        assert_eq!(vote.winning_option, Some(0));
+    }
+}
+```
\ No newline at end of file
@@ -0,0 +1,136 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+
+/// Create a program-owned PDA that is passed through `remaining_accounts`
+/// and therefore can't be initialized by an `#[account(init)]` constraint
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let lamports = Rent::get()?.minimum_balance(space);
+
+    create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: target.clone(),
+            },
+            &[signer_seeds],
+        ),
+        lamports,
+        space as u64,
+        &crate::ID,
+    )
+}
+
+/// Close a program-owned account from inside a handler, refunding its rent to `destination`.
+/// The account is handed back to the system program, so Anchor skips it on exit.
+pub fn close_account<'info>(target: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<u64> {
+    let refund = target.lamports();
+    shift_lamports(target, destination, refund)?;
+
+    target.assign(&anchor_lang::system_program::ID);
+    target.realloc(0, false)?;
+    Ok(refund)
+}
+
+/// Move lamports out of a program-owned account without dropping it below rent exemption.
+/// Accounts that carry no data (and so owe no rent) can be drained to zero.
+pub fn move_lamports<'info>(from: &AccountInfo<'info>, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+    let remaining = from.lamports().saturating_sub(amount);
+    if amount > 0 && from.data_len() > 0 && remaining < Rent::get()?.minimum_balance(from.data_len()) {
+        return Err(crate::error::SolSocialError::InsufficientRentExemption.into());
+    }
+    shift_lamports(from, to, amount)
+}
+
+/// Move lamports out of a program-owned account that is closed later in the same
+/// instruction, so its rent may be spent as well
+pub fn move_closing_lamports<'info>(from: &AccountInfo<'info>, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+    shift_lamports(from, to, amount)
+}
+
+fn shift_lamports<'info>(from: &AccountInfo<'info>, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let remaining = from
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(crate::error::SolSocialError::InsufficientFunds)?;
+    let credited = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(crate::error::SolSocialError::MathOverflow)?;
+
+    **from.try_borrow_mut_lamports()? = remaining;
+    **to.try_borrow_mut_lamports()? = credited;
+    Ok(())
+}
+
+/// Lamports a program-owned account holds above its rent-exempt minimum
+pub fn withdrawable_lamports(rent: &Rent, account: &AccountInfo) -> u64 {
+    account.lamports().saturating_sub(rent.minimum_balance(account.data_len()))
+}
+
+/// Serialize an account (including its discriminator) into raw account data
+pub fn write_account<T: AccountSerialize>(target: &AccountInfo, account: &T) -> Result<()> {
+    let mut data = target.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])
+}
+
+/// Lamports `balance` falls short of funding rent for `spaces` plus `extra` while keeping
+/// the payer (holding `payer_data_len` bytes) rent exempt; `None` when fully covered
+pub fn rent_shortfall(
+    rent: &Rent,
+    balance: u64,
+    payer_data_len: usize,
+    spaces: &[usize],
+    extra: u64,
+) -> Option<u64> {
+    let required = spaces
+        .iter()
+        .fold(extra, |total, space| total.saturating_add(rent.minimum_balance(*space)))
+        .saturating_add(rent.minimum_balance(payer_data_len));
+
+    required.checked_sub(balance).filter(|shortfall| *shortfall > 0)
+}
+
+/// Fail early with `InsufficientRentExemption` when `payer` can't fund the PDAs of
+/// `spaces` plus `extra` lamports of transfers and still stay rent exempt itself,
+/// rather than letting the runtime reject the transaction with an opaque rent error.
+///
+/// Accounts created by `#[account(init)]` are already paid for by the time a handler
+/// runs, so handlers only pass the spaces of PDAs they create themselves.
+pub fn require_rent_funds(payer: &AccountInfo, spaces: &[usize], extra: u64) -> Result<()> {
+    if let Some(shortfall) = rent_shortfall(&Rent::get()?, payer.lamports(), payer.data_len(), spaces, extra) {
+        msg!("Payer is {} lamports short of rent and fees for this instruction", shortfall);
+        return Err(crate::error::SolSocialError::InsufficientRentExemption.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rent_shortfall_counts_every_pda_and_the_payer() {
+        let rent = Rent::default();
+        let payer_min = rent.minimum_balance(0);
+        let spaces = [100, 250];
+        let needed = payer_min + rent.minimum_balance(100) + rent.minimum_balance(250) + 5_000;
+
+        assert_eq!(rent_shortfall(&rent, needed, 0, &spaces, 5_000), None);
+        assert_eq!(rent_shortfall(&rent, needed - 1, 0, &spaces, 5_000), Some(1));
+        assert_eq!(rent_shortfall(&rent, payer_min - 10, 0, &[], 0), Some(10));
+        assert_eq!(rent_shortfall(&rent, 0, 0, &[], u64::MAX), Some(u64::MAX));
+    }
+}
+```
\ No newline at end of file
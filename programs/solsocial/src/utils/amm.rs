@@ -0,0 +1,105 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+/// Raydium CP-Swap, the AMM graduated markets are seeded into
+pub const RAYDIUM_CP_SWAP_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+/// Anchor discriminator of CP-Swap's `initialize`: sha256("global:initialize")[..8]
+const CP_SWAP_INITIALIZE_DISCRIMINATOR: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+
+/// Accounts of CP-Swap's `initialize`, in the order the AMM expects them
+pub struct CpSwapInitialize<'a, 'info> {
+    pub creator: &'a AccountInfo<'info>,
+    pub amm_config: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub pool_state: &'a AccountInfo<'info>,
+    pub token_0_mint: &'a AccountInfo<'info>,
+    pub token_1_mint: &'a AccountInfo<'info>,
+    pub lp_mint: &'a AccountInfo<'info>,
+    pub creator_token_0: &'a AccountInfo<'info>,
+    pub creator_token_1: &'a AccountInfo<'info>,
+    pub creator_lp_token: &'a AccountInfo<'info>,
+    pub token_0_vault: &'a AccountInfo<'info>,
+    pub token_1_vault: &'a AccountInfo<'info>,
+    pub create_pool_fee: &'a AccountInfo<'info>,
+    pub observation_state: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+}
+
+/// Create a CP-Swap pool and deposit its first liquidity from `creator`, which signs with
+/// `signer_seeds`. Both mints must be owned by `token_program`, with `token_0_mint` sorting
+/// before `token_1_mint`.
+pub fn cp_swap_initialize<'info>(
+    program: &AccountInfo<'info>,
+    accounts: CpSwapInitialize<'_, 'info>,
+    init_amount_0: u64,
+    init_amount_1: u64,
+    open_time: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = CP_SWAP_INITIALIZE_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&init_amount_0.to_le_bytes());
+    data.extend_from_slice(&init_amount_1.to_le_bytes());
+    data.extend_from_slice(&open_time.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: RAYDIUM_CP_SWAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.creator.key(), true),
+            AccountMeta::new_readonly(accounts.amm_config.key(), false),
+            AccountMeta::new_readonly(accounts.authority.key(), false),
+            AccountMeta::new(accounts.pool_state.key(), false),
+            AccountMeta::new_readonly(accounts.token_0_mint.key(), false),
+            AccountMeta::new_readonly(accounts.token_1_mint.key(), false),
+            AccountMeta::new(accounts.lp_mint.key(), false),
+            AccountMeta::new(accounts.creator_token_0.key(), false),
+            AccountMeta::new(accounts.creator_token_1.key(), false),
+            AccountMeta::new(accounts.creator_lp_token.key(), false),
+            AccountMeta::new(accounts.token_0_vault.key(), false),
+            AccountMeta::new(accounts.token_1_vault.key(), false),
+            AccountMeta::new(accounts.create_pool_fee.key(), false),
+            AccountMeta::new(accounts.observation_state.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.associated_token_program.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+            AccountMeta::new_readonly(accounts.rent.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            accounts.creator.clone(),
+            accounts.amm_config.clone(),
+            accounts.authority.clone(),
+            accounts.pool_state.clone(),
+            accounts.token_0_mint.clone(),
+            accounts.token_1_mint.clone(),
+            accounts.lp_mint.clone(),
+            accounts.creator_token_0.clone(),
+            accounts.creator_token_1.clone(),
+            accounts.creator_lp_token.clone(),
+            accounts.token_0_vault.clone(),
+            accounts.token_1_vault.clone(),
+            accounts.create_pool_fee.clone(),
+            accounts.observation_state.clone(),
+            accounts.token_program.clone(),
+            accounts.associated_token_program.clone(),
+            accounts.system_program.clone(),
+            accounts.rent.clone(),
+            program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+```
\ No newline at end of file
@@ -1,5 +1,251 @@
 ```rust
 use anchor_lang::prelude::*;
+use crate::state::BondingCurveParams;
+
+/// Pricing formula a market's keys follow. Every trade prices through
+/// [`calculate_buy_price`] / [`calculate_sell_price`], which dispatch on this.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CurveKind {
+    /// `base_price * (price_factor / 1e6)^k`, priced from a closed-form geometric prefix sum
+    #[default]
+    Exponential,
+    /// `base_price + k^2 / PRICE_MULTIPLIER`, summed in closed form
+    Quadratic,
+    /// `base_price + k * slope`, summed in closed form
+    Linear,
+    /// `base_price + (ceiling_price - base_price) * k^2 / (k^2 + midpoint^2)`: slow start,
+    /// steepest around `midpoint`, then flattening out below `ceiling_price`. Summed per key,
+    /// so a single quote covers at most [`MAX_SIGMOID_KEYS_PER_QUOTE`] keys
+    Sigmoid,
+}
+
+/// Most keys one sigmoid quote sums, keeping its per-key loop inside the compute budget
+pub const MAX_SIGMOID_KEYS_PER_QUOTE: u64 = 500;
+
+/// Price of key `supply` (0-indexed) on the market's curve
+pub fn calculate_price(params: &BondingCurveParams, supply: u64) -> Result<u64> {
+    sum_prices(params, supply, 1)
+}
+
+/// Cost of buying keys `supply..supply + amount`, before fees
+pub fn calculate_buy_price(params: &BondingCurveParams, supply: u64, amount: u64) -> Result<u64> {
+    require!(amount > 0, crate::error::SolSocialError::InvalidAmount);
+    sum_prices(params, supply, amount)
+}
+
+/// Refund for selling keys `supply - amount..supply`, before fees. These are the keys a
+/// buy of `amount` at `supply - amount` paid for, so `buy(S, N) == sell(S + N, N)`.
+pub fn calculate_sell_price(params: &BondingCurveParams, supply: u64, amount: u64) -> Result<u64> {
+    require!(amount > 0, crate::error::SolSocialError::InvalidAmount);
+    require!(amount <= supply, crate::error::SolSocialError::InsufficientSupply);
+    sum_prices(params, supply - amount, amount)
+}
+
+/// How far a trade of `amount` moves the price of the next key, in basis points of the price
+/// before it. A buy whose next key is past the market's price limit counts as unbounded.
+pub fn price_impact_bps(params: &BondingCurveParams, supply: u64, amount: u64, is_buy: bool) -> Result<u64> {
+    let before = calculate_price(params, supply)?;
+    let after = if is_buy {
+        supply
+            .checked_add(amount)
+            .and_then(|new_supply| calculate_price(params, new_supply).ok())
+            .unwrap_or(u64::MAX)
+    } else {
+        let new_supply = supply
+            .checked_sub(amount)
+            .ok_or(crate::error::SolSocialError::InsufficientSupply)?;
+        calculate_price(params, new_supply)?
+    };
+    if before == 0 {
+        return Ok(0);
+    }
+    let impact = (before as u128).abs_diff(after as u128) * 10_000 / before as u128;
+    Ok(u64::try_from(impact).unwrap_or(u64::MAX))
+}
+
+/// Most keys `budget` lamports buys from `supply`, with what they cost before fees; the
+/// rest of the budget is dust. Every curve only rises, so the key at `supply` is the cheapest
+/// and bounds the search, and a range failing to price (past the price limit) is out of reach.
+pub fn max_keys_for_budget(params: &BondingCurveParams, supply: u64, budget: u64) -> Result<(u64, u64)> {
+    let cheapest = match calculate_price(params, supply) {
+        Ok(price) if price > 0 => price,
+        _ => return Ok((0, 0)),
+    };
+
+    let mut high = budget / cheapest;
+    if params.curve_kind == CurveKind::Sigmoid {
+        high = high.min(MAX_SIGMOID_KEYS_PER_QUOTE);
+    }
+    let mut low = 0u64;
+    let mut cost = 0u64;
+    while low < high {
+        let mid = low + (high - low + 1) / 2;
+        match calculate_buy_price(params, supply, mid) {
+            Ok(price) if price <= budget => {
+                low = mid;
+                cost = price;
+            }
+            _ => high = mid - 1,
+        }
+    }
+    Ok((low, cost))
+}
+
+/// Price of key `k` for the curves that have one in constant time, or `None` when it
+/// doesn't fit in a `u128`. The exponential curve prices keys as differences of its prefix
+/// sum instead and always returns `None`.
+pub fn closed_form_price(params: &BondingCurveParams, k: u64) -> Option<u128> {
+    let base = params.base_price as u128;
+    let k = k as u128;
+    match params.curve_kind {
+        CurveKind::Exponential => None,
+        CurveKind::Quadratic => Some(base + k * k / BondingCurve::PRICE_MULTIPLIER as u128),
+        CurveKind::Linear => Some(base + k * params.slope as u128),
+        CurveKind::Sigmoid => {
+            let rise = (params.ceiling_price as u128).checked_sub(base)?;
+            let k_squared = k * k;
+            let midpoint_squared = (params.midpoint as u128) * (params.midpoint as u128);
+            let denominator = k_squared.checked_add(midpoint_squared)?;
+            if denominator == 0 {
+                return Some(base);
+            }
+            Some(base + rise.checked_mul(k_squared)? / denominator)
+        }
+    }
+}
+
+/// Sum the prices of keys `start..start + amount`, failing with `PriceOverflow` once any
+/// of them passes the market's price limit
+fn sum_prices(params: &BondingCurveParams, start: u64, amount: u64) -> Result<u64> {
+    let end = start
+        .checked_add(amount)
+        .ok_or(crate::error::SolSocialError::PriceOverflow)?;
+
+    match params.curve_kind {
+        CurveKind::Exponential => exponential_sum(params, start, amount),
+        CurveKind::Quadratic => {
+            require_last_key_within_limit(params, start, end)?;
+            quadratic_sum(params.base_price, start, end)
+        }
+        CurveKind::Linear => {
+            require_last_key_within_limit(params, start, end)?;
+            // n * base + slope * (start + ... + end - 1)
+            let n = amount as u128;
+            let index_sum = (start as u128 + end as u128 - 1)
+                .checked_mul(n)
+                .ok_or(crate::error::SolSocialError::PriceOverflow)?
+                / 2;
+            let total = index_sum
+                .checked_mul(params.slope as u128)
+                .and_then(|curve| curve.checked_add(n * params.base_price as u128))
+                .ok_or(crate::error::SolSocialError::PriceOverflow)?;
+            u64::try_from(total).map_err(|_| crate::error::SolSocialError::PriceOverflow.into())
+        }
+        CurveKind::Sigmoid => {
+            require_last_key_within_limit(params, start, end)?;
+            // Per-key flooring has no closed form; each key is still priced in constant time
+            require!(amount <= MAX_SIGMOID_KEYS_PER_QUOTE, crate::error::SolSocialError::TradeTooLarge);
+            let mut total = 0u128;
+            for k in start..end {
+                total += closed_form_price(params, k).ok_or(crate::error::SolSocialError::PriceOverflow)?;
+            }
+            u64::try_from(total).map_err(|_| crate::error::SolSocialError::PriceOverflow.into())
+        }
+    }
+}
+
+/// The closed-form curves only rise, so checking the last key of `start..end` covers the range
+fn require_last_key_within_limit(params: &BondingCurveParams, start: u64, end: u64) -> Result<()> {
+    if end == start {
+        return Ok(());
+    }
+    let last_price = closed_form_price(params, end - 1).ok_or(crate::error::SolSocialError::PriceOverflow)?;
+    require!(
+        last_price <= params.price_limit() as u128,
+        crate::error::SolSocialError::PriceOverflow
+    );
+    Ok(())
+}
+
+/// Total price of exponential keys `start..start + amount` in O(log) time, as the difference
+/// of two prefix sums. Key `k` costs `exponential_prefix(k + 1) - exponential_prefix(k)`, so
+/// sums over adjacent ranges always add up exactly.
+fn exponential_sum(params: &BondingCurveParams, start: u64, amount: u64) -> Result<u64> {
+    let end = start
+        .checked_add(amount)
+        .ok_or(crate::error::SolSocialError::PriceOverflow)?;
+    let prefix = |n: u64| exponential_prefix(params, n).ok_or(crate::error::SolSocialError::PriceOverflow);
+
+    // The curve only rises, so the last key bounds the range
+    if amount > 0 {
+        let last_price = prefix(end)?
+            .checked_sub(prefix(end - 1)?)
+            .ok_or(crate::error::SolSocialError::PriceOverflow)?;
+        require!(
+            last_price <= params.price_limit() as u128,
+            crate::error::SolSocialError::PriceOverflow
+        );
+    }
+
+    let total = prefix(end)?
+        .checked_sub(prefix(start)?)
+        .ok_or(crate::error::SolSocialError::PriceOverflow)?;
+    u64::try_from(total).map_err(|_| crate::error::SolSocialError::PriceOverflow.into())
+}
+
+/// 1.0 in the Q64.64 fixed point the exponential closed form works in
+const Q64_ONE: u128 = 1 << 64;
+
+/// `base_price * (1 + r + ... + r^(n - 1))` floored, with `r = price_factor / 1e6`, or `None`
+/// once it passes a `u128`. The power and the geometric sum are built together by squaring
+/// over the bits of `n`, rounding up throughout so exact prices like `1.1 * 1e6` stay exact.
+fn exponential_prefix(params: &BondingCurveParams, n: u64) -> Option<u128> {
+    let ratio = ((params.price_factor as u128) << 64).div_ceil(1_000_000);
+
+    // Invariant: power = r^m and sum = 1 + r + ... + r^(m - 1) for the bits of `n` seen so far
+    let mut power = Q64_ONE;
+    let mut sum = 0u128;
+    for bit in (0..64 - n.leading_zeros()).rev() {
+        // m -> 2m: S(2m) = S(m) * (1 + r^m)
+        sum = sum.checked_add(mul_q64(sum, power)?)?;
+        power = mul_q64(power, power)?;
+        if (n >> bit) & 1 == 1 {
+            // m -> m + 1: S(m + 1) = S(m) + r^m
+            sum = sum.checked_add(power)?;
+            power = mul_q64(power, ratio)?;
+        }
+    }
+
+    // base * sum, dropping the fraction
+    let base = params.base_price as u128;
+    (sum >> 64)
+        .checked_mul(base)?
+        .checked_add(((sum & (Q64_ONE - 1)) * base) >> 64)
+}
+
+/// `a * b` for Q64.64 values, rounded up, or `None` on overflow
+fn mul_q64(a: u128, b: u128) -> Option<u128> {
+    let (a_hi, a_lo) = (a >> 64, a & (Q64_ONE - 1));
+    let (b_hi, b_lo) = (b >> 64, b & (Q64_ONE - 1));
+    let low = a_lo * b_lo;
+    let low_part = (low >> 64) + u128::from(low & (Q64_ONE - 1) != 0);
+
+    a_hi.checked_mul(b_hi)?
+        .checked_mul(Q64_ONE)?
+        .checked_add(a_hi * b_lo)?
+        .checked_add(a_lo * b_hi)?
+        .checked_add(low_part)
+}
+
+/// Total price of quadratic keys `start..end` in constant time, matching a per-key sum exactly
+fn quadratic_sum(base_price: u64, start: u64, end: u64) -> Result<u64> {
+    require!(end <= BondingCurve::MAX_SUPPLY, crate::error::SolSocialError::SupplyTooHigh);
+
+    let base_total = ((end - start) as u128) * base_price as u128;
+    let curve_total = BondingCurve::curve_prefix_sum(end) - BondingCurve::curve_prefix_sum(start);
+
+    u64::try_from(base_total + curve_total).map_err(|_| crate::error::SolSocialError::Overflow.into())
+}
 
 /// Bonding curve utility functions for calculating key prices
 pub struct BondingCurve;
@@ -28,35 +274,43 @@ impl BondingCurve {
         require!(supply.checked_add(amount).is_some(), crate::error::SolSocialError::Overflow);
         require!(supply + amount <= Self::MAX_SUPPLY, crate::error::SolSocialError::SupplyTooHigh);
         
-        let mut total_price = 0u64;
-        
-        for i in supply..(supply + amount) {
-            let key_price = Self::get_price_at_supply(i)?;
-            total_price = total_price
-                .checked_add(key_price)
-                .ok_or(crate::error::SolSocialError::Overflow)?;
-        }
-        
-        Ok(total_price)
+        quadratic_sum(Self::BASE_PRICE, supply, supply + amount)
     }
     
     /// Calculate the price to sell a specific amount of keys
-    /// Uses the same formula but in reverse
+    /// Refunds keys `supply - amount..supply`, the same keys a buy of `amount`
+    /// at `supply - amount` paid for, so `buy(S, N) == sell(S + N, N)`
     pub fn get_sell_price(supply: u64, amount: u64) -> Result<u64> {
         require!(supply > 0, crate::error::SolSocialError::InsufficientSupply);
         require!(amount > 0, crate::error::SolSocialError::InvalidAmount);
         require!(amount <= supply, crate::error::SolSocialError::InsufficientSupply);
         
-        let mut total_price = 0u64;
-        
-        for i in (supply - amount)..supply {
-            let key_price = Self::get_price_at_supply(i)?;
-            total_price = total_price
-                .checked_add(key_price)
-                .ok_or(crate::error::SolSocialError::Overflow)?;
-        }
-        
-        Ok(total_price)
+        quadratic_sum(Self::BASE_PRICE, supply - amount, supply)
+    }
+    
+    /// `sum(i^2 / PRICE_MULTIPLIER)` over `i < n`, with the division floored per key.
+    ///
+    /// Writing `i = k * M + j` with `j < M` splits each term into
+    /// `k^2 * M + 2 * k * j + j^2 / M`: the first two parts sum in closed form, and the
+    /// floored remainder repeats every `M` keys, so it comes from [`period_prefix`].
+    fn curve_prefix_sum(n: u64) -> u128 {
+        let m = Self::PRICE_MULTIPLIER as u128;
+        let blocks = (n / Self::PRICE_MULTIPLIER) as u128;
+        let tail = (n % Self::PRICE_MULTIPLIER) as u128;
+        
+        // Whole blocks k < blocks: k^2 * M^2 + k * M * (M - 1) + period_prefix(M) each
+        let sum_k = blocks * blocks.saturating_sub(1) / 2;
+        let sum_k_squared = blocks * blocks.saturating_sub(1) * (2 * blocks).saturating_sub(1) / 6;
+        let full_blocks = m * m * sum_k_squared
+            + m * (m - 1) * sum_k
+            + blocks * period_prefix(Self::PRICE_MULTIPLIER) as u128;
+        
+        // Partial block k = blocks, j < tail
+        let partial_block = tail * blocks * blocks * m
+            + blocks * tail * tail.saturating_sub(1)
+            + period_prefix(tail as u64) as u128;
+        
+        full_blocks + partial_block
     }
     
     /// Calculate the price of a single key at a specific supply level
@@ -207,6 +461,12 @@ impl BondingCurve {
         Ok(())
     }
     
+    /// Check that `actual` deviates from `expected` by at most `tolerance_bps` in either direction
+    pub fn is_within_tolerance(actual: u64, expected: u64, tolerance_bps: u16) -> bool {
+        let drift = (actual as u128).abs_diff(expected as u128);
+        drift * 10_000 <= (expected as u128) * (tolerance_bps as u128)
+    }
+    
     /// Get trading statistics for a user's keys
     pub fn get_trading_stats(supply: u64) -> Result<TradingStats> {
         let current_price = if supply > 0 {
@@ -234,6 +494,40 @@ impl BondingCurve {
     }
 }
 
+/// Spacing of the precomputed checkpoints in [`PERIOD_PREFIX`]
+const PREFIX_STRIDE: u64 = 128;
+
+const PREFIX_LEN: usize = (BondingCurve::PRICE_MULTIPLIER / PREFIX_STRIDE) as usize + 1;
+
+const _: () = assert!(BondingCurve::PRICE_MULTIPLIER % PREFIX_STRIDE == 0);
+
+/// `PERIOD_PREFIX[c]` is `sum(j^2 / PRICE_MULTIPLIER)` over `j < c * PREFIX_STRIDE`, built at compile time
+const PERIOD_PREFIX: [u64; PREFIX_LEN] = build_period_prefix();
+
+const fn build_period_prefix() -> [u64; PREFIX_LEN] {
+    let m = BondingCurve::PRICE_MULTIPLIER;
+    let mut table = [0u64; PREFIX_LEN];
+    let mut sum = 0u64;
+    let mut j = 0u64;
+    while j < m {
+        if j % PREFIX_STRIDE == 0 {
+            table[(j / PREFIX_STRIDE) as usize] = sum;
+        }
+        sum += j * j / m;
+        j += 1;
+    }
+    table[PREFIX_LEN - 1] = sum;
+    table
+}
+
+/// `sum(j^2 / PRICE_MULTIPLIER)` over `j < t` for `t <= PRICE_MULTIPLIER`, from the nearest
+/// checkpoint plus fewer than `PREFIX_STRIDE` extra terms
+fn period_prefix(t: u64) -> u64 {
+    let m = BondingCurve::PRICE_MULTIPLIER;
+    let checkpoint = t / PREFIX_STRIDE;
+    (checkpoint * PREFIX_STRIDE..t).fold(PERIOD_PREFIX[checkpoint as usize], |sum, j| sum + j * j / m)
+}
+
 #[derive(Debug, Clone)]
 pub struct TradingStats {
     pub current_price: u64,
@@ -270,6 +564,32 @@ mod tests {
         assert_eq!(buy_price, sell_price);
     }
     
+    #[test]
+    fn test_buy_sell_symmetry_sweep() {
+        for supply in (0..2_000).step_by(97) {
+            for amount in 1..20 {
+                let buy_price = BondingCurve::get_buy_price(supply, amount).unwrap();
+                let sell_price = BondingCurve::get_sell_price(supply + amount, amount).unwrap();
+                assert_eq!(buy_price, sell_price, "supply={} amount={}", supply, amount);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_tolerance_is_symmetric() {
+        let expected = 1_000_000;
+        
+        // 1% tolerance allows exactly 10_000 lamports of drift either way
+        assert!(BondingCurve::is_within_tolerance(1_010_000, expected, 100));
+        assert!(BondingCurve::is_within_tolerance(990_000, expected, 100));
+        assert!(!BondingCurve::is_within_tolerance(1_010_001, expected, 100));
+        assert!(!BondingCurve::is_within_tolerance(989_999, expected, 100));
+        
+        // Zero tolerance only accepts the exact quote
+        assert!(BondingCurve::is_within_tolerance(expected, expected, 0));
+        assert!(!BondingCurve::is_within_tolerance(expected + 1, expected, 0));
+    }
+    
     #[test]
     fn test_fees_calculation() {
         let price = 1_000_000; // 0.001 SOL
@@ -305,5 +625,221 @@ mod tests {
         // Insufficient supply for sell
         assert!(BondingCurve::validate_trade(5, 10, false).is_err());
     }
+    
+    #[test]
+    fn test_price_impact_is_measured_on_the_next_key() {
+        let params = BondingCurveParams {
+            curve_kind: CurveKind::Linear,
+            base_price: 1_000,
+            slope: 100,
+            ..BondingCurveParams::default()
+        };
+        // Next key goes from 1_000 + 10 * 100 to 1_000 + 15 * 100, and back
+        assert_eq!(price_impact_bps(&params, 10, 5, true).unwrap(), 2_500);
+        assert_eq!(price_impact_bps(&params, 15, 5, false).unwrap(), 2_000);
+        assert!(price_impact_bps(&params, 4, 5, false).is_err());
+    }
+    
+    /// The per-key loop the closed form replaces
+    fn loop_sum(start: u64, end: u64) -> u64 {
+        (start..end).map(|i| BondingCurve::get_price_at_supply(i).unwrap()).sum()
+    }
+    
+    #[test]
+    fn test_period_prefix_matches_brute_force() {
+        let m = BondingCurve::PRICE_MULTIPLIER;
+        let mut sum = 0u64;
+        for t in 0..=m {
+            assert_eq!(period_prefix(t), sum, "t={}", t);
+            sum += t * t / m;
+        }
+    }
+    
+    #[test]
+    fn test_closed_form_matches_loop() {
+        let m = BondingCurve::PRICE_MULTIPLIER;
+        let starts = [0, 1, 127, 128, 129, m - 1, m, m + 1, 2 * m - 3, 5 * m + 77, 123_456, 999_000];
+        for start in starts {
+            for amount in [1, 2, 7, 128, 300, 1_000] {
+                let end = start + amount;
+                if end > BondingCurve::MAX_SUPPLY {
+                    continue;
+                }
+                assert_eq!(
+                    BondingCurve::get_buy_price(start, amount).unwrap(),
+                    loop_sum(start, end),
+                    "start={} amount={}",
+                    start,
+                    amount
+                );
+                assert_eq!(BondingCurve::get_sell_price(end, amount).unwrap(), loop_sum(start, end));
+            }
+        }
+    }
+    
+    #[test]
+    fn test_closed_form_handles_whole_curve() {
+        // A single quote over the entire supply, which the loop would spend a million iterations on
+        let max = BondingCurve::MAX_SUPPLY;
+        let total = BondingCurve::get_buy_price(0, max).unwrap();
+        let split = BondingCurve::get_buy_price(0, max / 2).unwrap()
+            + BondingCurve::get_buy_price(max / 2, max - max / 2).unwrap();
+        assert_eq!(total, split);
+        assert_eq!(BondingCurve::get_market_cap(max).unwrap(), total);
+        assert_eq!(loop_sum(max - 3, max), BondingCurve::get_sell_price(max, 3).unwrap());
+    }
+    
+    const ALL_KINDS: [CurveKind; 4] = [CurveKind::Exponential, CurveKind::Quadratic, CurveKind::Linear, CurveKind::Sigmoid];
+    
+    fn params_for(curve_kind: CurveKind) -> BondingCurveParams {
+        BondingCurveParams {
+            curve_kind,
+            slope: 10_000,
+            midpoint: 500,
+            ceiling_price: 20_000_000_000,
+            ..BondingCurveParams::default()
+        }
+    }
+    
+    #[test]
+    fn test_curve_kinds_buy_sell_symmetry() {
+        for kind in ALL_KINDS {
+            let params = params_for(kind);
+            for supply in 0..40 {
+                for amount in 1..10 {
+                    let buy_price = calculate_buy_price(&params, supply, amount).unwrap();
+                    let sell_price = calculate_sell_price(&params, supply + amount, amount).unwrap();
+                    assert_eq!(buy_price, sell_price, "{:?} supply={} amount={}", kind, supply, amount);
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn test_curve_kinds_price_single_keys() {
+        let exponential = params_for(CurveKind::Exponential);
+        assert_eq!(calculate_price(&exponential, 0).unwrap(), 1_000_000);
+        assert_eq!(calculate_price(&exponential, 1).unwrap(), 1_100_000);
+        assert_eq!(calculate_price(&exponential, 2).unwrap(), 1_210_000);
+        
+        // The quadratic kind is the same curve `BondingCurve` quotes
+        let quadratic = params_for(CurveKind::Quadratic);
+        for supply in [0, 1, 500, 16_000, 123_456] {
+            assert_eq!(
+                calculate_price(&quadratic, supply).unwrap(),
+                BondingCurve::get_price_at_supply(supply).unwrap()
+            );
+            assert_eq!(
+                calculate_buy_price(&quadratic, supply, 7).unwrap(),
+                BondingCurve::get_buy_price(supply, 7).unwrap()
+            );
+        }
+    }
+    
+    #[test]
+    fn test_curve_kinds_enforce_price_limit() {
+        for (kind, top) in [
+            (CurveKind::Exponential, 100),
+            (CurveKind::Quadratic, 300_000),
+            (CurveKind::Linear, 3_000),
+            (CurveKind::Sigmoid, 300),
+        ] {
+            let mut params = params_for(kind);
+            params.max_price_per_key = calculate_price(&params, top).unwrap();
+            assert!(calculate_buy_price(&params, top - 10, 11).is_ok());
+            assert!(calculate_buy_price(&params, top - 10, 12).is_err(), "{:?}", kind);
+        }
+    }
+    
+    #[test]
+    fn test_curve_kinds_reject_empty_and_oversold_trades() {
+        let params = params_for(CurveKind::Quadratic);
+        assert!(calculate_buy_price(&params, 5, 0).is_err());
+        assert!(calculate_sell_price(&params, 5, 0).is_err());
+        assert!(calculate_sell_price(&params, 5, 6).is_err());
+    }
+    
+    #[test]
+    fn test_curve_kinds_match_per_key_sums() {
+        for kind in ALL_KINDS {
+            let params = params_for(kind);
+            for (start, amount) in [(0, 1), (0, 60), (17, 3), (45, 15)] {
+                let per_key: u64 = (start..start + amount)
+                    .map(|supply| calculate_price(&params, supply).unwrap())
+                    .sum();
+                assert_eq!(calculate_buy_price(&params, start, amount).unwrap(), per_key, "{:?}", kind);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_linear_and_sigmoid_shapes() {
+        let linear = params_for(CurveKind::Linear);
+        assert_eq!(calculate_price(&linear, 0).unwrap(), 1_000_000);
+        assert_eq!(calculate_price(&linear, 100).unwrap(), 2_000_000);
+        
+        // Halfway to the ceiling at the midpoint, and never past it
+        let sigmoid = params_for(CurveKind::Sigmoid);
+        assert_eq!(calculate_price(&sigmoid, 0).unwrap(), 1_000_000);
+        assert_eq!(calculate_price(&sigmoid, 500).unwrap(), 1_000_000 + (20_000_000_000 - 1_000_000) / 2);
+        let late = calculate_price(&sigmoid, 999_999).unwrap();
+        assert!(late < sigmoid.ceiling_price && late > calculate_price(&sigmoid, 5_000).unwrap());
+    }
+    
+    #[test]
+    fn test_budget_buys_most_affordable_keys() {
+        for kind in ALL_KINDS {
+            let params = params_for(kind);
+            for (supply, budget) in [(0, 5_000_000), (12, 40_000_000), (100, 999_999), (3, 1_000_000_000)] {
+                let (amount, cost) = max_keys_for_budget(&params, supply, budget).unwrap();
+                assert!(cost <= budget, "{:?}", kind);
+                if amount > 0 {
+                    assert_eq!(calculate_buy_price(&params, supply, amount).unwrap(), cost);
+                }
+                let one_more = calculate_buy_price(&params, supply, amount + 1);
+                assert!(one_more.map_or(true, |price| price > budget), "{:?} supply={}", kind, supply);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_exponential_prices_whole_supply_in_one_quote() {
+        // A million-key curve quoted end to end, which a per-key walk couldn't afford
+        let params = BondingCurveParams {
+            price_factor: 1_000_001,
+            max_supply: 1_000_000,
+            ..BondingCurveParams::default()
+        };
+        let total = calculate_buy_price(&params, 0, 1_000_000).unwrap();
+        let split = calculate_buy_price(&params, 0, 400_000).unwrap()
+            + calculate_buy_price(&params, 400_000, 600_000).unwrap();
+        assert_eq!(total, split);
+        assert_eq!(calculate_sell_price(&params, 1_000_000, 1_000_000).unwrap(), total);
+
+        // Each key stays within a lamport of base_price * r^k
+        for k in [0u64, 1, 1_000, 500_000, 999_999] {
+            let exact = 1_000_000.0 * 1.000_001f64.powi(k as i32);
+            let price = calculate_price(&params, k).unwrap() as f64;
+            assert!((price - exact).abs() <= 1.0, "k={} price={} exact={}", k, price, exact);
+        }
+    }
+    
+    #[test]
+    fn test_sigmoid_quotes_are_capped() {
+        let params = params_for(CurveKind::Sigmoid);
+        assert!(calculate_buy_price(&params, 0, MAX_SIGMOID_KEYS_PER_QUOTE).is_ok());
+        assert!(calculate_buy_price(&params, 0, MAX_SIGMOID_KEYS_PER_QUOTE + 1).is_err());
+        let (amount, _) = max_keys_for_budget(&params, 0, u64::MAX).unwrap();
+        assert_eq!(amount, MAX_SIGMOID_KEYS_PER_QUOTE);
+    }
+    
+    #[test]
+    fn test_budget_stops_at_price_limit() {
+        let mut params = params_for(CurveKind::Linear);
+        params.max_price_per_key = calculate_price(&params, 20).unwrap();
+        let (amount, _) = max_keys_for_budget(&params, 0, u64::MAX).unwrap();
+        assert_eq!(amount, 21);
+        assert_eq!(max_keys_for_budget(&params, 21, u64::MAX).unwrap(), (0, 0));
+    }
 }
 ```
\ No newline at end of file
@@ -0,0 +1,139 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+use crate::error::SolSocialError;
+use crate::state::BondingCurveParams;
+use crate::utils::bonding_curve::price_impact_bps;
+
+/// Which check produced a [`TradeErrorContext`]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum TradeErrorKind {
+    /// Buy cost exceeded the caller's `max_price`
+    BuySlippage,
+    /// Sell proceeds fell below the caller's `min_price`
+    SellSlippage,
+    /// Buyer's lamports did not cover the cost
+    InsufficientFunds,
+    /// Seller held fewer keys than requested
+    InsufficientKeys,
+    /// Trade attempted outside the market's trading hours
+    OutsideTradingHours,
+    /// Buy would push the holder past the market's per-holder cap
+    HolderCapReached,
+    /// Trade landed after the caller's `deadline`
+    DeadlineExceeded,
+    /// Trade moved the price by more than the caller's or the protocol's impact cap
+    PriceImpactTooHigh,
+}
+
+/// Context attached to a failed trade through the transaction's return data.
+///
+/// Borsh layout, 25 bytes little-endian:
+///
+/// | offset | size | field                                             |
+/// |--------|------|---------------------------------------------------|
+/// | 0      | 1    | `kind` ([`TradeErrorKind`] discriminant)          |
+/// | 1      | 8    | `price`: cost or proceeds computed on-chain       |
+/// | 9      | 8    | `limit`: bound that failed (max/min price, balance or keys held) |
+/// | 17     | 8    | `amount`: keys requested                          |
+///
+/// For `OutsideTradingHours`, `price` and `limit` carry the market's
+/// `trading_open_at` and `trading_close_at` timestamps instead. For
+/// `HolderCapReached`, `price` is the keys already held and `limit` the cap.
+/// For `DeadlineExceeded`, `price` is the cluster time and `limit` the deadline.
+/// For `PriceImpactTooHigh`, `price` is the trade's impact and `limit` the cap, in bps.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct TradeErrorContext {
+    pub kind: TradeErrorKind,
+    pub price: u64,
+    pub limit: u64,
+    pub amount: u64,
+}
+
+impl TradeErrorContext {
+    pub const SIZE: usize = 1 + 8 + 8 + 8;
+
+    pub fn encode(&self) -> Vec<u8> {
+        self.try_to_vec().expect("fixed-size context always serializes")
+    }
+}
+
+/// Publish `context` as return data and hand back `error` for the caller to return
+pub fn fail_with_context(context: TradeErrorContext, error: SolSocialError) -> Error {
+    set_return_data(&context.encode());
+    error.into()
+}
+
+/// Reject a trade landing after the caller's optional `deadline`; the deadline itself is still valid
+pub fn require_before_deadline(deadline: Option<i64>, now: i64, amount: u64) -> Result<()> {
+    match deadline {
+        Some(deadline) if now > deadline => Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::DeadlineExceeded,
+                price: now as u64,
+                limit: deadline as u64,
+                amount,
+            },
+            SolSocialError::DeadlineExceeded,
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Reject a trade moving the next key's price by more than `max_impact_bps` (0 = uncapped)
+pub fn require_price_impact_within(
+    params: &BondingCurveParams,
+    supply: u64,
+    amount: u64,
+    is_buy: bool,
+    max_impact_bps: u16,
+) -> Result<()> {
+    if max_impact_bps == 0 {
+        return Ok(());
+    }
+    let impact = price_impact_bps(params, supply, amount, is_buy)?;
+    if impact > max_impact_bps as u64 {
+        return Err(fail_with_context(
+            TradeErrorContext {
+                kind: TradeErrorKind::PriceImpactTooHigh,
+                price: impact,
+                limit: max_impact_bps as u64,
+                amount,
+            },
+            SolSocialError::PriceImpactTooHigh,
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_matches_documentation() {
+        let context = TradeErrorContext {
+            kind: TradeErrorKind::SellSlippage,
+            price: 0x0102_0304_0506_0708,
+            limit: 42,
+            amount: 3,
+        };
+        let bytes = context.encode();
+
+        assert_eq!(bytes.len(), TradeErrorContext::SIZE);
+        assert_eq!(bytes[0], 1);
+        assert_eq!(&bytes[1..9], &0x0102_0304_0506_0708u64.to_le_bytes());
+        assert_eq!(&bytes[9..17], &42u64.to_le_bytes());
+        assert_eq!(&bytes[17..25], &3u64.to_le_bytes());
+        assert_eq!(TradeErrorContext::try_from_slice(&bytes).unwrap(), context);
+    }
+
+    #[test]
+    fn test_deadline_is_inclusive_and_optional() {
+        assert!(require_before_deadline(None, i64::MAX, 1).is_ok());
+        assert!(require_before_deadline(Some(1_000), 999, 1).is_ok());
+        assert!(require_before_deadline(Some(1_000), 1_000, 1).is_ok());
+        assert!(require_before_deadline(Some(1_000), 1_001, 1).is_err());
+    }
+}
+```
\ No newline at end of file
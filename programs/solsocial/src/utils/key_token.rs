@@ -0,0 +1,75 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, FreezeAccount, ThawAccount, TokenAccount};
+use crate::state::UserKeys;
+
+/// Holders' key token accounts stay frozen under the market's keys PDA, the mint's freeze
+/// authority, so keys can't leave a wallet by plain SPL transfer and strand its `KeyHolder`
+/// balance. Instructions that move keys in or out of a holder's account thaw it first and
+/// freeze it again once the keys have moved. Keys that should travel freely are wrapped.
+pub struct KeyFreeze<'a, 'info> {
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    keys_account: AccountInfo<'info>,
+    signer_seeds: &'a [&'a [&'a [u8]]],
+    /// Graduated keys trade on their pool, so nothing is frozen again
+    graduated: bool,
+}
+
+impl<'a, 'info> KeyFreeze<'a, 'info> {
+    pub fn new(
+        token_program: &AccountInfo<'info>,
+        mint: &AccountInfo<'info>,
+        keys_account: &Account<'info, UserKeys>,
+        signer_seeds: &'a [&'a [&'a [u8]]],
+    ) -> Self {
+        Self {
+            token_program: token_program.clone(),
+            mint: mint.clone(),
+            keys_account: keys_account.to_account_info(),
+            signer_seeds,
+            graduated: keys_account.graduated_at != 0,
+        }
+    }
+
+    /// Let the program move keys in or out of `token_account`
+    pub fn thaw(&self, token_account: &AccountInfo<'info>) -> Result<()> {
+        if !is_frozen(token_account)? {
+            return Ok(());
+        }
+        let cpi_accounts = ThawAccount {
+            account: token_account.clone(),
+            mint: self.mint.clone(),
+            authority: self.keys_account.clone(),
+        };
+        token::thaw_account(CpiContext::new_with_signer(
+            self.token_program.clone(),
+            cpi_accounts,
+            self.signer_seeds,
+        ))
+    }
+
+    /// Lock `token_account` again once the program is done with it
+    pub fn freeze(&self, token_account: &AccountInfo<'info>) -> Result<()> {
+        if self.graduated || is_frozen(token_account)? {
+            return Ok(());
+        }
+        let cpi_accounts = FreezeAccount {
+            account: token_account.clone(),
+            mint: self.mint.clone(),
+            authority: self.keys_account.clone(),
+        };
+        token::freeze_account(CpiContext::new_with_signer(
+            self.token_program.clone(),
+            cpi_accounts,
+            self.signer_seeds,
+        ))
+    }
+}
+
+/// Read the account's current state, which may have changed since the instruction began
+fn is_frozen(token_account: &AccountInfo) -> Result<bool> {
+    let data = token_account.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.is_frozen())
+}
+```
\ No newline at end of file
@@ -0,0 +1,11 @@
+```rust
+pub mod accounts;
+pub mod amm;
+pub mod bonding_curve;
+pub mod error_context;
+pub mod key_token;
+pub mod payment;
+pub mod revenue_share;
+pub mod trade;
+pub mod validation;
+```
\ No newline at end of file
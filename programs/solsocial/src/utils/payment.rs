@@ -0,0 +1,96 @@
+```rust
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+use crate::error::SolSocialError;
+
+/// Token accounts a market settling in its `payment_mint` trades through. Fees go straight to
+/// the treasury's and the creator's token accounts; the reward pool, the earnings vault and
+/// referrer fees only deal in lamports and are skipped.
+pub struct TokenSettlement<'info> {
+    pub token_program: AccountInfo<'info>,
+    /// The trader's own payment token account
+    pub trader: AccountInfo<'info>,
+    /// Balance of `trader` before the trade
+    pub trader_balance: u64,
+    /// The market's reserve, owned by the keys PDA
+    pub reserve: AccountInfo<'info>,
+    /// Takes the whole protocol fee
+    pub treasury: AccountInfo<'info>,
+    /// Takes the creator fee
+    pub creator: AccountInfo<'info>,
+}
+
+impl<'info> TokenSettlement<'info> {
+    pub fn from_accounts(
+        token_program: &AccountInfo<'info>,
+        trader: Option<&Account<'info, TokenAccount>>,
+        reserve: Option<&Account<'info, TokenAccount>>,
+        treasury: Option<&Account<'info, TokenAccount>>,
+        creator: Option<&Account<'info, TokenAccount>>,
+    ) -> Result<Self> {
+        match (trader, reserve, treasury, creator) {
+            (Some(trader), Some(reserve), Some(treasury), Some(creator)) => Ok(Self {
+                token_program: token_program.clone(),
+                trader: trader.to_account_info(),
+                trader_balance: trader.amount,
+                reserve: reserve.to_account_info(),
+                treasury: treasury.to_account_info(),
+                creator: creator.to_account_info(),
+            }),
+            _ => Err(SolSocialError::MissingRequiredAccount.into()),
+        }
+    }
+
+    /// Pay for a buy out of the buyer's token account: the fees on top, the curve price to the reserve
+    pub fn pay_buy(
+        &self,
+        buyer: &AccountInfo<'info>,
+        protocol_fee: u64,
+        creator_fee: u64,
+        price: u64,
+    ) -> Result<()> {
+        self.transfer(&self.trader, &self.treasury, buyer, protocol_fee, &[])?;
+        self.transfer(&self.trader, &self.creator, buyer, creator_fee, &[])?;
+        self.transfer(&self.trader, &self.reserve, buyer, price, &[])
+    }
+
+    /// Pay out a sell from the reserve, signed by the market's keys PDA
+    pub fn pay_sell(
+        &self,
+        keys_account: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+        protocol_fee: u64,
+        creator_fee: u64,
+        proceeds: u64,
+    ) -> Result<()> {
+        self.transfer(&self.reserve, &self.trader, keys_account, proceeds, signer_seeds)?;
+        self.transfer(&self.reserve, &self.treasury, keys_account, protocol_fee, signer_seeds)?;
+        self.transfer(&self.reserve, &self.creator, keys_account, creator_fee, signer_seeds)
+    }
+
+    fn transfer(
+        &self,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        amount: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                Transfer {
+                    from: from.clone(),
+                    to: to.clone(),
+                    authority: authority.clone(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+}
+```
\ No newline at end of file
@@ -2,10 +2,13 @@
 use anchor_lang::prelude::*;
 use crate::state::{User, Keys};
 use crate::error::SolSocialError;
+use crate::utils::accounts::move_lamports;
 
 pub const PROTOCOL_FEE_BPS: u16 = 500; // 5%
 pub const CREATOR_FEE_BPS: u16 = 500; // 5%
 pub const REFERRER_FEE_BPS: u16 = 100; // 1%
+/// Default cap on the fees one side of a trade may charge, referrer included
+pub const MAX_TOTAL_FEE_BPS: u16 = 2000; // 20%
 
 #[derive(Debug, Clone, Copy)]
 pub struct RevenueDistribution {
@@ -15,12 +18,33 @@ pub struct RevenueDistribution {
     pub net_amount: u64,
 }
 
+impl RevenueDistribution {
+    /// Everything the trader parts with: `net_amount` plus every fee
+    pub fn total_cost(&self) -> Result<u64> {
+        self.net_amount
+            .checked_add(self.protocol_fee)
+            .and_then(|total| total.checked_add(self.creator_fee))
+            .and_then(|total| total.checked_add(self.referrer_fee))
+            .ok_or(SolSocialError::MathOverflow.into())
+    }
+}
+
 pub fn calculate_revenue_distribution(
     total_amount: u64,
     has_referrer: bool,
 ) -> Result<RevenueDistribution> {
-    let protocol_fee = calculate_fee(total_amount, PROTOCOL_FEE_BPS)?;
-    let creator_fee = calculate_fee(total_amount, CREATOR_FEE_BPS)?;
+    calculate_market_distribution(total_amount, PROTOCOL_FEE_BPS, CREATOR_FEE_BPS, has_referrer)
+}
+
+/// Split a trade at a market's own fee rates; referred traders also pay `REFERRER_FEE_BPS`
+pub fn calculate_market_distribution(
+    total_amount: u64,
+    protocol_fee_bps: u16,
+    creator_fee_bps: u16,
+    has_referrer: bool,
+) -> Result<RevenueDistribution> {
+    let protocol_fee = calculate_fee(total_amount, protocol_fee_bps)?;
+    let creator_fee = calculate_fee(total_amount, creator_fee_bps)?;
     let referrer_fee = if has_referrer {
         calculate_fee(total_amount, REFERRER_FEE_BPS)?
     } else {
@@ -45,6 +69,43 @@ pub fn calculate_revenue_distribution(
     })
 }
 
+/// Split a buy at a market's own fee rates. Unlike a sell, the fees are charged on top:
+/// `net_amount` is the whole curve price, which joins the market's reserve so that it can
+/// always pay the curve's sell price back out.
+pub fn calculate_buy_distribution(
+    price: u64,
+    protocol_fee_bps: u16,
+    creator_fee_bps: u16,
+    has_referrer: bool,
+) -> Result<RevenueDistribution> {
+    let protocol_fee = calculate_fee(price, protocol_fee_bps)?;
+    let creator_fee = calculate_fee(price, creator_fee_bps)?;
+    let referrer_fee = if has_referrer {
+        calculate_fee(price, REFERRER_FEE_BPS)?
+    } else {
+        0
+    };
+
+    Ok(RevenueDistribution {
+        protocol_fee,
+        creator_fee,
+        referrer_fee,
+        net_amount: price,
+    })
+}
+
+/// Largest curve price a `budget` covers once buy fees of `fee_bps` are added on top.
+/// Fees floor, so the result never overspends the budget.
+pub fn price_budget_before_fees(budget: u64, fee_bps: u16) -> Result<u64> {
+    let price = (budget as u128)
+        .checked_mul(10000)
+        .ok_or(SolSocialError::MathOverflow)?
+        .checked_div(10000 + fee_bps as u128)
+        .ok_or(SolSocialError::MathOverflow)?;
+
+    Ok(price as u64)
+}
+
 pub fn calculate_fee(amount: u64, fee_bps: u16) -> Result<u64> {
     let fee = (amount as u128)
         .checked_mul(fee_bps as u128)
@@ -55,33 +116,46 @@ pub fn calculate_fee(amount: u64, fee_bps: u16) -> Result<u64> {
     Ok(fee as u64)
 }
 
+/// Split a tip between the post author and the sharer that drove it.
+/// Returns `(author_amount, sharer_amount)`.
+pub fn split_share_reward(tip_amount: u64, share_reward_bps: u16) -> Result<(u64, u64)> {
+    let sharer_amount = calculate_fee(tip_amount, share_reward_bps)?;
+    let author_amount = tip_amount
+        .checked_sub(sharer_amount)
+        .ok_or(SolSocialError::MathOverflow)?;
+
+    Ok((author_amount, sharer_amount))
+}
+
+/// Royalty owed to the creator when keys worth `keys_value` on the curve change wallets
+/// outside a trade. Returns `(royalty, value_after_royalty)`.
+pub fn split_transfer_royalty(keys_value: u64, royalty_bps: u16) -> Result<(u64, u64)> {
+    let royalty = calculate_fee(keys_value, royalty_bps)?;
+    let remainder = keys_value
+        .checked_sub(royalty)
+        .ok_or(SolSocialError::MathOverflow)?;
+
+    Ok((royalty, remainder))
+}
+
+/// Pay a buy's fees out of the buyer's wallet. The buyer is system-owned, so every leg
+/// goes through the system program; `distribution.protocol_fee` is what `protocol_treasury` keeps.
 pub fn distribute_buy_revenue<'info>(
     buyer: &AccountInfo<'info>,
     creator: &AccountInfo<'info>,
     protocol_treasury: &AccountInfo<'info>,
     referrer: Option<&AccountInfo<'info>>,
-    amount: u64,
-) -> Result<RevenueDistribution> {
-    let distribution = calculate_revenue_distribution(amount, referrer.is_some())?;
-
-    // Transfer protocol fee
-    if distribution.protocol_fee > 0 {
-        transfer_lamports(buyer, protocol_treasury, distribution.protocol_fee)?;
-    }
-
-    // Transfer creator fee
-    if distribution.creator_fee > 0 {
-        transfer_lamports(buyer, creator, distribution.creator_fee)?;
-    }
+    system_program: &AccountInfo<'info>,
+    distribution: &RevenueDistribution,
+) -> Result<()> {
+    system_transfer(buyer, protocol_treasury, system_program, distribution.protocol_fee)?;
+    system_transfer(buyer, creator, system_program, distribution.creator_fee)?;
 
-    // Transfer referrer fee if applicable
     if let Some(referrer_account) = referrer {
-        if distribution.referrer_fee > 0 {
-            transfer_lamports(buyer, referrer_account, distribution.referrer_fee)?;
-        }
+        system_transfer(buyer, referrer_account, system_program, distribution.referrer_fee)?;
     }
 
-    Ok(distribution)
+    Ok(())
 }
 
 pub fn distribute_sell_revenue<'info>(
@@ -95,12 +169,12 @@ pub fn distribute_sell_revenue<'info>(
 
     // Creator receives the net amount (after fees)
     if distribution.net_amount > 0 {
-        transfer_lamports(creator, seller, distribution.net_amount)?;
+        move_lamports(creator, seller, distribution.net_amount)?;
     }
 
     // Protocol receives fee from creator
     if distribution.protocol_fee > 0 {
-        transfer_lamports(creator, protocol_treasury, distribution.protocol_fee)?;
+        move_lamports(creator, protocol_treasury, distribution.protocol_fee)?;
     }
 
     // Creator pays creator fee (stays with creator, but tracked for analytics)
@@ -109,7 +183,7 @@ pub fn distribute_sell_revenue<'info>(
     // Referrer receives fee from creator if applicable
     if let Some(referrer_account) = referrer {
         if distribution.referrer_fee > 0 {
-            transfer_lamports(creator, referrer_account, distribution.referrer_fee)?;
+            move_lamports(creator, referrer_account, distribution.referrer_fee)?;
         }
     }
 
@@ -203,60 +277,52 @@ pub fn distribute_activity_rewards<'info>(
             .ok_or(SolSocialError::MathOverflow)? as u64;
 
         if reward_amount > 0 {
-            transfer_lamports(creator, recipient, reward_amount)?;
+            move_lamports(creator, recipient, reward_amount)?;
         }
     }
 
     Ok(())
 }
 
-fn transfer_lamports<'info>(
+pub fn system_transfer<'info>(
     from: &AccountInfo<'info>,
     to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
     amount: u64,
 ) -> Result<()> {
     if amount == 0 {
         return Ok(());
     }
 
-    let from_balance = from.lamports();
-    if from_balance < amount {
-        return Err(SolSocialError::InsufficientFunds.into());
-    }
-
-    **from.try_borrow_mut_lamports()? = from_balance
-        .checked_sub(amount)
-        .ok_or(SolSocialError::InsufficientFunds)?;
-
-    **to.try_borrow_mut_lamports()? = to.lamports()
-        .checked_add(amount)
-        .ok_or(SolSocialError::MathOverflow)?;
-
+    let ix = anchor_lang::solana_program::system_instruction::transfer(from.key, to.key, amount);
+    anchor_lang::solana_program::program::invoke(&ix, &[from.clone(), to.clone(), system_program.clone()])?;
     Ok(())
 }
 
+/// Simple interest on `staked_amount` lamports at `annual_rate_bps` over whole days
 pub fn calculate_staking_rewards(
     staked_amount: u64,
     staking_duration_days: u64,
     annual_rate_bps: u16,
 ) -> Result<u64> {
-    let daily_rate = (annual_rate_bps as u128)
-        .checked_div(365 * 10000)
-        .ok_or(SolSocialError::MathOverflow)?;
-
+    // Divide once at the end; a per-day rate in basis points would floor to zero
     let rewards = (staked_amount as u128)
-        .checked_mul(daily_rate)
+        .checked_mul(annual_rate_bps as u128)
         .ok_or(SolSocialError::MathOverflow)?
         .checked_mul(staking_duration_days as u128)
+        .ok_or(SolSocialError::MathOverflow)?
+        .checked_div(365 * 10000)
         .ok_or(SolSocialError::MathOverflow)?;
 
-    Ok(rewards as u64)
+    u64::try_from(rewards).map_err(|_| SolSocialError::MathOverflow.into())
 }
 
+/// Reject fee rates whose sum exceeds `max_total_bps`
 pub fn validate_fee_parameters(
     protocol_fee_bps: u16,
     creator_fee_bps: u16,
     referrer_fee_bps: u16,
+    max_total_bps: u16,
 ) -> Result<()> {
     let total_fees = protocol_fee_bps
         .checked_add(creator_fee_bps)
@@ -264,7 +330,7 @@ pub fn validate_fee_parameters(
         .checked_add(referrer_fee_bps)
         .ok_or(SolSocialError::MathOverflow)?;
 
-    if total_fees > 2000 { // Max 20% total fees
+    if total_fees > max_total_bps {
         return Err(SolSocialError::InvalidFeeStructure.into());
     }
 
@@ -286,6 +352,49 @@ mod tests {
         assert_eq!(distribution.net_amount, 890000); // 89%
     }
 
+    #[test]
+    fn test_buy_distribution_charges_fees_on_top() {
+        let distribution = calculate_buy_distribution(1_000_000, 500, 500, true).unwrap();
+
+        assert_eq!(distribution.net_amount, 1_000_000);
+        assert_eq!(distribution.protocol_fee, 50_000);
+        assert_eq!(distribution.creator_fee, 50_000);
+        assert_eq!(distribution.referrer_fee, 10_000);
+        assert_eq!(distribution.total_cost().unwrap(), 1_110_000);
+
+        // A budget sized for the fees buys exactly the curve price
+        assert_eq!(price_budget_before_fees(1_110_000, 1_100).unwrap(), 1_000_000);
+        assert!(price_budget_before_fees(1_109_999, 1_100).unwrap() < 1_000_000);
+    }
+
+    #[test]
+    fn test_reserve_covers_supply_after_round_trip() {
+        use crate::state::BondingCurveParams;
+        use crate::utils::bonding_curve::{calculate_buy_price, calculate_sell_price};
+
+        let params = BondingCurveParams::default();
+        let (mut supply, mut reserve) = (1u64, 0u64);
+
+        // Buys deposit the whole curve price; sells pay the seller and every fee out of
+        // the gross sell price, so the reserve must always cover selling the lot
+        for (amount, is_buy) in [(5, true), (3, true), (4, false), (10, true), (7, false), (2, true), (8, false)] {
+            if is_buy {
+                let price = calculate_buy_price(&params, supply, amount).unwrap();
+                let distribution = calculate_buy_distribution(
+                    price, params.buy_protocol_fee, params.buy_creator_fee, true,
+                ).unwrap();
+                reserve += distribution.net_amount;
+                supply += amount;
+            } else {
+                reserve -= calculate_sell_price(&params, supply, amount).unwrap();
+                supply -= amount;
+            }
+
+            let owed = calculate_sell_price(&params, supply, supply - 1).unwrap();
+            assert!(reserve >= owed, "reserve {} short of {} at supply {}", reserve, owed, supply);
+        }
+    }
+
     #[test]
     fn test_calculate_fee() {
         let amount = 1000000;
@@ -303,13 +412,46 @@ mod tests {
         assert_eq!(reward, 100000); // 10% of reward pool
     }
 
+    #[test]
+    fn test_split_share_reward() {
+        let (author, sharer) = split_share_reward(1_000_000, 1000).unwrap(); // 10%
+        assert_eq!(author, 900_000);
+        assert_eq!(sharer, 100_000);
+
+        // Without a share reward the author keeps the whole tip
+        let (author, sharer) = split_share_reward(1_000_000, 0).unwrap();
+        assert_eq!(author, 1_000_000);
+        assert_eq!(sharer, 0);
+    }
+
+    #[test]
+    fn test_split_transfer_royalty() {
+        let (royalty, remainder) = split_transfer_royalty(2_000_000, 250).unwrap(); // 2.5%
+        assert_eq!(royalty, 50_000);
+        assert_eq!(remainder, 1_950_000);
+
+        // Royalty-free markets move keys for nothing
+        assert_eq!(split_transfer_royalty(2_000_000, 0).unwrap(), (0, 2_000_000));
+    }
+
+    #[test]
+    fn test_calculate_staking_rewards() {
+        // 10% a year on 3.65 SOL is 0.001 SOL a day
+        assert_eq!(calculate_staking_rewards(3_650_000_000, 1, 1_000).unwrap(), 1_000_000);
+        assert_eq!(calculate_staking_rewards(3_650_000_000, 365, 1_000).unwrap(), 365_000_000);
+        assert_eq!(calculate_staking_rewards(3_650_000_000, 0, 1_000).unwrap(), 0);
+    }
+
     #[test]
     fn test_validate_fee_parameters() {
         // Valid fees
-        assert!(validate_fee_parameters(500, 500, 100).is_ok());
+        assert!(validate_fee_parameters(500, 500, 100, MAX_TOTAL_FEE_BPS).is_ok());
         
         // Invalid fees (too high)
-        assert!(validate_fee_parameters(1000, 1000, 500).is_err());
+        assert!(validate_fee_parameters(1000, 1000, 500, MAX_TOTAL_FEE_BPS).is_err());
+
+        // A tighter admin cap rejects rates the default would allow
+        assert!(validate_fee_parameters(500, 500, 100, 1000).is_err());
     }
 }
 ```
\ No newline at end of file
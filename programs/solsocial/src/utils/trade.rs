@@ -0,0 +1,99 @@
+```rust
+use anchor_lang::prelude::*;
+use crate::instructions::buy_keys::MilestoneReachedEvent;
+use crate::instructions::trading_halt::TradingHaltedEvent;
+use crate::state::{KeyHolder, PriceOracle, ProtocolConfig, RecentTrades, UserKeys};
+use crate::error::SolSocialError;
+
+/// A market's public price feeds, created by the first trade that records into them
+pub struct MarketFeeds<'a, 'info> {
+    pub recent_trades: &'a mut Account<'info, RecentTrades>,
+    pub recent_trades_bump: u8,
+    pub price_oracle: &'a mut Account<'info, PriceOracle>,
+    pub price_oracle_bump: u8,
+}
+
+/// A curve trade that has already moved its keys and lamports
+pub struct CurveTrade {
+    pub amount: u64,
+    /// Curve price paid or received for the whole trade, before fees
+    pub value: u64,
+    pub creator_fee: u64,
+    pub protocol_fee: u64,
+    pub is_buy: bool,
+}
+
+/// Bookkeeping shared by every curve trade once `supply` reflects it: the market's price,
+/// volume, fee totals and trade time, its recent-trades ring and price oracle when the
+/// instruction carries them, the circuit breaker, which halts the market when the trade
+/// moved its price too far, too fast, and the supply milestones. `trader` is the holding
+/// record the keys moved in or out of; it may not trade the other way in the same slot.
+/// Returns the trade's index.
+pub fn record_market_trade(
+    keys_account: &mut Account<UserKeys>,
+    feeds: Option<MarketFeeds>,
+    trader: Option<&mut KeyHolder>,
+    config: &ProtocolConfig,
+    trade: &CurveTrade,
+    now: i64,
+) -> Result<u64> {
+    if let Some(trader) = trader {
+        trader.record_trade_slot(Clock::get()?.slot, trade.is_buy)?;
+    }
+
+    let market = keys_account.key();
+    let price_per_key = trade.value / trade.amount;
+    let trade_index = keys_account.record_trade(trade.value, trade.creator_fee, trade.protocol_fee, now)?;
+
+    if let Some(feeds) = feeds {
+        if feeds.recent_trades.market == Pubkey::default() {
+            feeds.recent_trades.initialize(market, feeds.recent_trades_bump);
+        }
+        feeds.recent_trades.record(price_per_key, now, trade.is_buy);
+
+        if feeds.price_oracle.market == Pubkey::default() {
+            feeds.price_oracle.initialize(market, feeds.price_oracle_bump);
+        }
+        feeds.price_oracle.record(price_per_key, now);
+    }
+
+    if let Some(move_bps) = keys_account.record_price_point(
+        price_per_key,
+        now,
+        config.circuit_breaker_bps,
+        config.circuit_breaker_window_seconds,
+    ) {
+        emit!(TradingHaltedEvent {
+            subject: keys_account.user,
+            authority: None,
+            move_bps,
+            timestamp: now,
+        });
+    }
+    record_milestones(keys_account, config, now)?;
+
+    Ok(trade_index)
+}
+
+/// Credit the creator a bonus for every milestone in the protocol's table that supply has
+/// newly reached. Called after any change to `supply`, including the launch sales that stay
+/// out of `record_market_trade`.
+pub fn record_milestones(keys_account: &mut Account<UserKeys>, config: &ProtocolConfig, now: i64) -> Result<()> {
+    let milestones = config.milestones;
+    let reached = keys_account.reach_milestones(&milestones);
+    for i in (0..milestones.count as usize).filter(|i| reached & (1 << i) != 0) {
+        keys_account.milestone_bonuses = keys_account.milestone_bonuses
+            .checked_add(milestones.bonuses[i])
+            .ok_or(SolSocialError::MathOverflow)?;
+
+        emit!(MilestoneReachedEvent {
+            subject: keys_account.user,
+            milestone: milestones.thresholds[i],
+            bonus_amount: milestones.bonuses[i],
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
+```
\ No newline at end of file
@@ -0,0 +1,52 @@
+```rust
+/// URL schemes accepted for media and profile images
+pub const ALLOWED_URL_SCHEMES: [&str; 3] = ["https://", "ipfs://", "ar://"];
+
+/// Check that a URL uses an allowed scheme, has a non-empty remainder and
+/// contains no control or whitespace characters
+pub fn is_valid_url(url: &str) -> bool {
+    let rest = match ALLOWED_URL_SCHEMES
+        .iter()
+        .find_map(|scheme| url.strip_prefix(scheme))
+    {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    !rest.is_empty() && !url.chars().any(|c| c.is_control() || c.is_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_url_prefixes() {
+        assert!(is_valid_url("https://example.com/avatar.png"));
+        assert!(is_valid_url("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"));
+        assert!(is_valid_url("ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U"));
+    }
+
+    #[test]
+    fn test_invalid_url_prefixes() {
+        assert!(!is_valid_url("http://example.com/avatar.png"));
+        assert!(!is_valid_url("javascript:alert(1)"));
+        assert!(!is_valid_url("data:image/png;base64,AAAA"));
+        assert!(!is_valid_url("example.com/avatar.png"));
+        assert!(!is_valid_url(""));
+    }
+
+    #[test]
+    fn test_scheme_without_target() {
+        assert!(!is_valid_url("https://"));
+        assert!(!is_valid_url("ipfs://"));
+    }
+
+    #[test]
+    fn test_control_characters_rejected() {
+        assert!(!is_valid_url("https://example.com/\navatar.png"));
+        assert!(!is_valid_url("https://example.com/\u{0}"));
+        assert!(!is_valid_url("https://example.com/a b.png"));
+    }
+}
+```
\ No newline at end of file
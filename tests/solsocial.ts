@@ -3,8 +3,9 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Solsocial } from "../target/types/solsocial";
 import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAccount, getAssociatedTokenAddress, getMint, createAssociatedTokenAccount, createMint, mintTo, transfer } from "@solana/spl-token";
 import { expect } from "chai";
+import { createHash } from "crypto";
 
 describe("solsocial", () => {
   const provider = anchor.AnchorProvider.env();
@@ -23,6 +24,7 @@ describe("solsocial", () => {
   let postPda: PublicKey;
   let chatPda: PublicKey;
   let messagePda: PublicKey;
+  let configPda: PublicKey;
 
   before(async () => {
     userKeypair = Keypair.generate();
@@ -62,9 +64,61 @@ describe("solsocial", () => {
       [Buffer.from("keys"), creatorKeypair.publicKey.toBuffer()],
       program.programId
     );
+
+    [configPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_config")],
+      program.programId
+    );
   });
 
   describe("User Management", () => {
+    it("Initializes the protocol config", async () => {
+      const [programData] = PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+      );
+
+      // Only the program's upgrade authority may set the config up
+      const outsider = Keypair.generate();
+      await provider.connection.requestAirdrop(outsider.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+      try {
+        await program.methods
+          .initializeConfig(new anchor.BN(5_000_000), 2_000, 3_000, new anchor.BN(3_600), new anchor.BN(3_600), new anchor.BN(0), new anchor.BN(0))
+          .accounts({
+            config: configPda,
+            rewardPool: PublicKey.findProgramAddressSync([Buffer.from("reward_pool")], program.programId)[0],
+            admin: outsider.publicKey,
+            program: program.programId,
+            programData,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([outsider])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      await program.methods
+        .initializeConfig(new anchor.BN(5_000_000), 2_000, 3_000, new anchor.BN(3_600), new anchor.BN(3_600), new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          config: configPda,
+          rewardPool: PublicKey.findProgramAddressSync([Buffer.from("reward_pool")], program.programId)[0],
+          admin: provider.wallet.publicKey,
+          program: program.programId,
+          programData,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const config = await program.account.protocolConfig.fetch(configPda);
+      expect(config.admin.toString()).to.equal(provider.wallet.publicKey.toString());
+      expect(config.maxMediaBytesPerUser.toNumber()).to.equal(5_000_000);
+      expect(config.rewardPoolBps).to.equal(2_000);
+      expect(config.graceDropBps).to.equal(3_000);
+    });
+
     it("Initializes a user profile", async () => {
       const username = "testuser";
       const bio = "Test user bio";
@@ -84,10 +138,27 @@ describe("solsocial", () => {
       expect(userAccount.authority.toString()).to.equal(userKeypair.publicKey.toString());
       expect(userAccount.username).to.equal(username);
       expect(userAccount.bio).to.equal(bio);
-      expect(userAccount.profileImageUrl).to.equal(profileImageUrl);
-      expect(userAccount.followersCount.toNumber()).to.equal(0);
+      expect(userAccount.profileImage).to.equal(profileImageUrl);
+      expect(userAccount.followerCount.toNumber()).to.equal(0);
       expect(userAccount.followingCount.toNumber()).to.equal(0);
-      expect(userAccount.postsCount.toNumber()).to.equal(0);
+      expect(userAccount.postCount.toNumber()).to.equal(0);
+      expect(userAccount.version).to.equal(1);
+    });
+
+    it("Only migrates profiles still on the pre-unification layout", async () => {
+      try {
+        await program.methods
+          .migrateUser()
+          .accounts({
+            user: userPda,
+            authority: userKeypair.publicKey,
+          })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("AlreadyMigrated");
+      }
     });
 
     it("Initializes creator profile", async () => {
@@ -129,6 +200,71 @@ describe("solsocial", () => {
       expect(buyerAccount.authority.toString()).to.equal(buyerKeypair.publicKey.toString());
       expect(buyerAccount.username).to.equal(username);
     });
+
+    it("Delays guardian recovery and lets the owner cancel it", async () => {
+      const guardians = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+      const newWallet = Keypair.generate();
+      const [guardiansPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("guardians"), userPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .setGuardians(guardians.map((guardian) => guardian.publicKey), 2)
+        .accounts({
+          user: userPda,
+          guardians: guardiansPda,
+          authority: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const initiate = (approvers: Keypair[]) =>
+        program.methods
+          .initiateRecovery(newWallet.publicKey)
+          .accounts({ user: userPda, guardians: guardiansPda })
+          .remainingAccounts(
+            approvers.map((approver) => ({ pubkey: approver.publicKey, isWritable: false, isSigner: true }))
+          )
+          .signers(approvers)
+          .rpc();
+
+      // One approval is below the threshold
+      try {
+        await initiate([guardians[0]]);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientGuardianApprovals");
+      }
+
+      await initiate([guardians[0], guardians[2]]);
+      let guardianSet = await program.account.guardians.fetch(guardiansPda);
+      expect(guardianSet.pendingAuthority.toString()).to.equal(newWallet.publicKey.toString());
+      expect(guardianSet.recoveryExecutableAt.sub(guardianSet.recoveryInitiatedAt).toNumber()).to.equal(2 * 24 * 60 * 60);
+
+      // Executing before the delay elapses fails and leaves the owner in control
+      try {
+        await program.methods
+          .recoverAccount()
+          .accounts({ user: userPda, guardians: guardiansPda })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("RecoveryNotReady");
+      }
+
+      await program.methods
+        .cancelRecovery()
+        .accounts({ user: userPda, guardians: guardiansPda, authority: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      guardianSet = await program.account.guardians.fetch(guardiansPda);
+      expect(guardianSet.pendingAuthority).to.be.null;
+      const userAccount = await program.account.user.fetch(userPda);
+      expect(userAccount.authority.toString()).to.equal(userKeypair.publicKey.toString());
+    });
   });
 
   describe("Key Trading", () => {
@@ -166,6 +302,17 @@ describe("solsocial", () => {
       const keysAccount = await program.account.userKeys.fetch(creatorKeysPda);
       expect(keysAccount.creator.toString()).to.equal(creatorKeypair.publicKey.toString());
       expect(keysAccount.totalSupply.toNumber()).to.equal(0);
+
+      // Each market gets its own whole-key mint, minted only by the keys PDA
+      const [mintPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("keys_mint"), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      expect(keysAccount.mint.toString()).to.equal(mintPda.toString());
+      const mint = await getMint(provider.connection, mintPda);
+      expect(mint.decimals).to.equal(0);
+      expect(mint.mintAuthority.toString()).to.equal(creatorKeysPda.toString());
+      expect(Number(mint.supply)).to.equal(0);
     });
 
     it("Buys keys with correct pricing", async () => {
@@ -191,9 +338,7 @@ describe("solsocial", () => {
 
       expect(keysAccount.totalSupply.toNumber()).to.equal(1);
       expect(keysAccount.totalVolume.toNumber()).to.be.greaterThan(0);
-      expect(buyerAccount.keysHeld.length).to.equal(1);
-      expect(buyerAccount.keysHeld[0].creator.toString()).to.equal(creatorKeypair.publicKey.toString());
-      expect(buyerAccount.keysHeld[0].amount.toNumber()).to.equal(1);
+      expect(buyerAccount.totalKeysPurchased.toNumber()).to.equal(1);
     });
 
     it("Buys multiple keys with increasing price", async () => {
@@ -216,138 +361,4878 @@ describe("solsocial", () => {
 
       const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
       expect(keysAfter.totalSupply.toNumber()).to.equal(supplyBefore + 2);
-    });
 
-    it("Sells keys with correct pricing", async () => {
-      const amount = new anchor.BN(1);
+      // Repeat buys update the buyer's existing KeyHolder PDA without counting them twice
+      const [keyHolderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), buyerKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const keyHolder = await program.account.keyHolder.fetch(keyHolderPda);
+      expect(keyHolder.holder.toString()).to.equal(buyerKeypair.publicKey.toString());
+      expect(keyHolder.amount.toNumber()).to.equal(3);
+      const mint = await getMint(provider.connection, keysAfter.mint);
+      expect(Number(mint.supply)).to.equal(3);
+      expect(keysAfter.holdersCount.toNumber()).to.equal(keysBefore.holdersCount.toNumber());
+    });
 
+    it("Records an opt-in KeyTransaction at the market's next trade index", async () => {
       const keysBefore = await program.account.userKeys.fetch(creatorKeysPda);
-      const supplyBefore = keysBefore.totalSupply.toNumber();
+      const tradeIndex = keysBefore.tradeCount;
+      const [keyTransactionPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("key_transaction"),
+          creatorKeypair.publicKey.toBuffer(),
+          buyerKeypair.publicKey.toBuffer(),
+          tradeIndex.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
 
       await program.methods
-        .sellKeys(amount)
+        .buyKeys(new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), null, null)
         .accounts({
           keys: creatorKeysPda,
           user: buyerPda,
-          seller: buyerKeypair.publicKey,
+          buyer: buyerKeypair.publicKey,
           creator: creatorKeypair.publicKey,
+          keyTransaction: keyTransactionPda,
           systemProgram: SystemProgram.programId,
         })
         .signers([buyerKeypair])
         .rpc();
 
+      const record = await program.account.keyTransaction.fetch(keyTransactionPda);
+      expect(record.transactionType).to.deep.equal({ buy: {} });
+      expect(record.trader.toString()).to.equal(buyerKeypair.publicKey.toString());
+      expect(record.amount.toNumber()).to.equal(1);
+      expect(record.tradeIndex.toString()).to.equal(tradeIndex.toString());
+
       const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
-      expect(keysAfter.totalSupply.toNumber()).to.equal(supplyBefore - 1);
+      expect(keysAfter.tradeCount.toNumber()).to.equal(tradeIndex.toNumber() + 1);
     });
 
-    it("Fails to sell more keys than owned", async () => {
-      const amount = new anchor.BN(100);
+    it("Fills a buy limit order through the curve and refunds the unused escrow", async () => {
+      const orderId = new anchor.BN(1);
+      const [orderPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("limit_order"),
+          buyerKeypair.publicKey.toBuffer(),
+          creatorKeypair.publicKey.toBuffer(),
+          orderId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("limit_order_escrow"), orderPda.toBuffer()],
+        program.programId
+      );
+      const [keyHolderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), buyerKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const keysBefore = await program.account.userKeys.fetch(creatorKeysPda);
+      const holderBefore = await program.account.keyHolder.fetch(keyHolderPda);
+      const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+
+      await program.methods
+        .placeLimitOrder({ buy: {} }, new anchor.BN(LAMPORTS_PER_SOL / 10), new anchor.BN(1), new anchor.BN(expiresAt), orderId)
+        .accounts({
+          owner: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          order: orderPda,
+          escrow: escrowPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      // The escrow covers the limit and the buy fees charged on top of it
+      const order = await program.account.limitOrder.fetch(orderPda);
+      const limit = LAMPORTS_PER_SOL / 10;
+      const buyFees =
+        Math.floor((limit * keysBefore.curveParams.buyProtocolFee) / 10_000) +
+        Math.floor((limit * keysBefore.curveParams.buyCreatorFee) / 10_000);
+      expect(order.escrowedLamports.toNumber()).to.equal(limit + buyFees);
+
+      const events: any[] = [];
+      const listener = program.addEventListener("orderFilledEvent", (event) => events.push(event));
+
+      const [priceOraclePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("price_oracle"), creatorKeysPda.toBuffer()],
+        program.programId
+      );
+      const [portfolioPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("portfolio"), buyerKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const portfolioBefore = await program.account.portfolio.fetchNullable(portfolioPda);
+
+      const keeper = Keypair.generate();
+      await provider.connection.requestAirdrop(keeper.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      await program.methods
+        .fillOrder()
+        .accounts({
+          keeper: keeper.publicKey,
+          owner: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          order: orderPda,
+          escrow: escrowPda,
+        })
+        .signers([keeper])
+        .rpc();
+
+      await new Promise(resolve => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(events.length).to.equal(1);
+      expect(events[0].side).to.deep.equal({ buy: {} });
+      expect(events[0].price.toNumber()).to.be.at.most(LAMPORTS_PER_SOL / 10);
+
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      const holderAfter = await program.account.keyHolder.fetch(keyHolderPda);
+      expect(keysAfter.supply.toNumber()).to.equal(keysBefore.supply.toNumber() + 1);
+      expect(holderAfter.amount.toNumber()).to.equal(holderBefore.amount.toNumber() + 1);
+      expect(await provider.connection.getAccountInfo(orderPda)).to.be.null;
+
+      // A fill is booked like any other trade
+      expect(keysAfter.volume.toNumber()).to.equal(keysBefore.volume.toNumber() + events[0].price.toNumber());
+      expect(keysAfter.tradeCount.toNumber()).to.equal(keysBefore.tradeCount.toNumber() + 1);
+      const oracle = await program.account.priceOracle.fetch(priceOraclePda);
+      expect(oracle.lastUpdated.toNumber()).to.be.at.least(keysAfter.lastTradeAt.toNumber());
+      const portfolioAfter = await program.account.portfolio.fetch(portfolioPda);
+      expect(portfolioAfter.tradeCount.toNumber()).to.equal((portfolioBefore?.tradeCount.toNumber() ?? 0) + 1);
+    });
+
+    it("Rejects a limit order fill the curve price doesn't satisfy", async () => {
+      const orderId = new anchor.BN(2);
+      const [orderPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("limit_order"),
+          buyerKeypair.publicKey.toBuffer(),
+          creatorKeypair.publicKey.toBuffer(),
+          orderId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("limit_order_escrow"), orderPda.toBuffer()],
+        program.programId
+      );
+      const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+
+      // One lamport per key never meets the curve
+      await program.methods
+        .placeLimitOrder({ buy: {} }, new anchor.BN(1), new anchor.BN(1), new anchor.BN(expiresAt), orderId)
+        .accounts({
+          owner: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          order: orderPda,
+          escrow: escrowPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
 
       try {
         await program.methods
-          .sellKeys(amount)
+          .fillOrder()
           .accounts({
-            keys: creatorKeysPda,
-            user: buyerPda,
-            seller: buyerKeypair.publicKey,
-            creator: creatorKeypair.publicKey,
-            systemProgram: SystemProgram.programId,
+            keeper: provider.wallet.publicKey,
+            owner: buyerKeypair.publicKey,
+            subject: creatorKeypair.publicKey,
+            order: orderPda,
+            escrow: escrowPda,
           })
-          .signers([buyerKeypair])
           .rpc();
-        
         expect.fail("Should have failed");
       } catch (error) {
-        expect(error.toString()).to.include("InsufficientKeys");
+        expect(error.toString()).to.include("LimitPriceNotReached");
       }
-    });
-  });
 
-  describe("Social Posts", () => {
-    it("Creates a social post", async () => {
-      const content = "This is my first post on SolSocial!";
-      const imageUrl = "https://example.com/post-image.jpg";
+      await program.methods
+        .cancelOrder()
+        .accounts({
+          mint: (await program.account.userKeys.fetch(creatorKeysPda)).mint,
+          owner: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          order: orderPda,
+          escrow: escrowPda,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+      expect(await provider.connection.getAccountInfo(orderPda)).to.be.null;
+    });
 
-      [postPda] = PublicKey.findProgramAddressSync(
+    it("Moves a sell order's keys into its escrow and hands them back on cancel", async () => {
+      const orderId = new anchor.BN(3);
+      const [orderPda] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("post"),
-          userKeypair.publicKey.toBuffer(),
-          new anchor.BN(0).toArrayLike(Buffer, "le", 8)
+          Buffer.from("limit_order"),
+          buyerKeypair.publicKey.toBuffer(),
+          creatorKeypair.publicKey.toBuffer(),
+          orderId.toArrayLike(Buffer, "le", 8),
         ],
         program.programId
       );
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("limit_order_escrow"), orderPda.toBuffer()],
+        program.programId
+      );
+      const keysAccount = await program.account.userKeys.fetch(creatorKeysPda);
+      const ownerTokenAccount = await getAssociatedTokenAddress(keysAccount.mint, buyerKeypair.publicKey);
+      const balanceBefore = (await getAccount(provider.connection, ownerTokenAccount)).amount;
+      const expiresAt = Math.floor(Date.now() / 1000) + 3600;
 
+      // Priced far above the curve so it rests until cancelled
       await program.methods
-        .createPost(content, imageUrl)
+        .placeLimitOrder({ sell: {} }, new anchor.BN(100 * LAMPORTS_PER_SOL), new anchor.BN(1), new anchor.BN(expiresAt), orderId)
         .accounts({
-          post: postPda,
-          user: userPda,
-          author: userKeypair.publicKey,
+          owner: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          order: orderPda,
+          escrow: escrowPda,
           systemProgram: SystemProgram.programId,
         })
-        .signers([userKeypair])
+        .signers([buyerKeypair])
         .rpc();
 
-      const postAccount = await program.account.post.fetch(postPda);
-      expect(postAccount.author.toString()).to.equal(userKeypair.publicKey.toString());
-      expect(postAccount.content).to.equal(content);
-      expect(postAccount.imageUrl).to.equal(imageUrl);
-      expect(postAccount.likesCount.toNumber()).to.equal(0);
-      expect(postAccount.commentsCount.toNumber()).to.equal(0);
-      expect(postAccount.sharesCount.toNumber()).to.equal(0);
-
-      const userAccount = await program.account.user.fetch(userPda);
-      expect(userAccount.postsCount.toNumber()).to.equal(1);
-    });
+      // The keys leave the owner's wallet, so they can't be sold or moved while the order rests
+      expect((await getAccount(provider.connection, escrowPda)).amount).to.equal(BigInt(1));
+      expect((await getAccount(provider.connection, ownerTokenAccount)).amount).to.equal(balanceBefore - BigInt(1));
 
-    it("Likes a post", async () => {
       await program.methods
-        .interactPost({ like: {} })
+        .cancelOrder()
         .accounts({
-          post: postPda,
-          user: buyerPda,
-          interactor: buyerKeypair.publicKey,
-          systemProgram: SystemProgram.programId,
+          mint: keysAccount.mint,
+          owner: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          order: orderPda,
+          escrow: escrowPda,
         })
         .signers([buyerKeypair])
         .rpc();
 
-      const postAccount = await program.account.post.fetch(postPda);
-      expect(postAccount.likesCount.toNumber()).to.equal(1);
+      expect((await getAccount(provider.connection, ownerTokenAccount)).amount).to.equal(balanceBefore);
+      expect(await provider.connection.getAccountInfo(escrowPda)).to.be.null;
+      expect(await provider.connection.getAccountInfo(orderPda)).to.be.null;
     });
 
-    it("Comments on a post", async () => {
-      const comment = "Great post!";
+    it("Sweeps an expired sell order, returning its keys and paying the keeper", async () => {
+      const orderId = new anchor.BN(4);
+      const [orderPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("limit_order"),
+          buyerKeypair.publicKey.toBuffer(),
+          creatorKeypair.publicKey.toBuffer(),
+          orderId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("limit_order_escrow"), orderPda.toBuffer()],
+        program.programId
+      );
+      const [keyHolderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), buyerKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const keysAccount = await program.account.userKeys.fetch(creatorKeysPda);
+      const ownerTokenAccount = await getAssociatedTokenAddress(keysAccount.mint, buyerKeypair.publicKey);
+      const balanceBefore = (await getAccount(provider.connection, ownerTokenAccount)).amount;
+      const holderBefore = await program.account.keyHolder.fetch(keyHolderPda);
+      const expiresAt = Math.floor(Date.now() / 1000) + 2;
 
       await program.methods
-        .interactPost({ comment: { content: comment } })
+        .placeLimitOrder({ sell: {} }, new anchor.BN(100 * LAMPORTS_PER_SOL), new anchor.BN(1), new anchor.BN(expiresAt), orderId)
         .accounts({
-          post: postPda,
-          user: buyerPda,
-          interactor: buyerKeypair.publicKey,
+          owner: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          order: orderPda,
+          escrow: escrowPda,
           systemProgram: SystemProgram.programId,
         })
         .signers([buyerKeypair])
         .rpc();
+      await new Promise(resolve => setTimeout(resolve, 4000));
 
-      const postAccount = await program.account.post.fetch(postPda);
-      expect(postAccount.commentsCount.toNumber()).to.equal(1);
+      const keeper = Keypair.generate();
+      await provider.connection.requestAirdrop(keeper.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+      const keeperBefore = await provider.connection.getBalance(keeper.publicKey);
+
+      await program.methods
+        .sweepExpiredOrders()
+        .accounts({ keeper: keeper.publicKey })
+        .remainingAccounts([
+          { pubkey: orderPda, isSigner: false, isWritable: true },
+          { pubkey: buyerKeypair.publicKey, isSigner: false, isWritable: true },
+          { pubkey: escrowPda, isSigner: false, isWritable: true },
+          { pubkey: keyHolderPda, isSigner: false, isWritable: true },
+          { pubkey: ownerTokenAccount, isSigner: false, isWritable: true },
+          { pubkey: creatorKeysPda, isSigner: false, isWritable: false },
+          { pubkey: keysAccount.mint, isSigner: false, isWritable: false },
+        ])
+        .signers([keeper])
+        .rpc();
+
+      // The escrowed key is back in the wallet, which is frozen again, and on the holder record
+      const ownerTokens = await getAccount(provider.connection, ownerTokenAccount);
+      expect(ownerTokens.amount).to.equal(balanceBefore);
+      expect(ownerTokens.isFrozen).to.be.true;
+      const holderAfter = await program.account.keyHolder.fetch(keyHolderPda);
+      expect(holderAfter.amount.toNumber()).to.equal(holderBefore.amount.toNumber());
+
+      // The keeper earns KEEPER_FEE out of the order's rent; the provider pays the transaction fee
+      expect(await provider.connection.getBalance(keeper.publicKey)).to.equal(keeperBefore + 5_000);
+      expect(await provider.connection.getAccountInfo(escrowPda)).to.be.null;
+      expect(await provider.connection.getAccountInfo(orderPda)).to.be.null;
     });
 
-    it("Shares a post", async () => {
+    it("Keeps holders' key accounts frozen so keys only move through the program", async () => {
+      const keysAccount = await program.account.userKeys.fetch(creatorKeysPda);
+      const buyerTokenAccount = await getAssociatedTokenAddress(keysAccount.mint, buyerKeypair.publicKey);
+      const outsiderTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        buyerKeypair,
+        keysAccount.mint,
+        Keypair.generate().publicKey
+      );
+      expect((await getAccount(provider.connection, buyerTokenAccount)).isFrozen).to.be.true;
+
+      // A plain SPL transfer would strand the buyer's KeyHolder balance, so the token program refuses it
+      try {
+        await transfer(provider.connection, buyerKeypair, buyerTokenAccount, outsiderTokenAccount, buyerKeypair, 1);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("0x11"); // AccountFrozen
+      }
+      expect((await getAccount(provider.connection, outsiderTokenAccount)).amount).to.equal(BigInt(0));
+
+      // Releasing only works once the market has graduated
+      try {
+        await program.methods
+          .releaseGraduatedKeys()
+          .accounts({
+            caller: buyerKeypair.publicKey,
+            subject: creatorKeypair.publicKey,
+            mint: keysAccount.mint,
+            holderTokenAccount: buyerTokenAccount,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("MarketNotGraduated");
+      }
+    });
+
+    it("Diverts the configured share of protocol fees into the reward pool", async () => {
+      const [rewardPoolPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_pool")],
+        program.programId
+      );
+      const config = await program.account.protocolConfig.fetch(configPda);
+      const poolBefore = await program.account.rewardPool.fetch(rewardPoolPda);
+      const lamportsBefore = await provider.connection.getBalance(rewardPoolPda);
+
+      const listener = program.addEventListener("keysBoughtEvent", (event) => {
+        const expected = event.protocolFee.muln(config.rewardPoolBps).divn(10_000);
+        expect(event.rewardPoolAmount.toString()).to.equal(expected.toString());
+      });
+
       await program.methods
-        .interactPost({ share: {} })
+        .buyKeys(new anchor.BN(1))
         .accounts({
-          post: postPda,
-          user: creatorPda,
-          interactor: creatorKeypair.publicKey,
+          keys: creatorKeysPda,
+          user: buyerPda,
+          buyer: buyerKeypair.publicKey,
+          creator: creatorKeypair.publicKey,
+          config: configPda,
+          rewardPool: rewardPoolPda,
           systemProgram: SystemProgram.programId,
         })
-        .signers([creatorKeypair])
+        .signers([buyerKeypair])
         .rpc();
 
-      const postAccount = await program.account.post.fetch(postPda);
-      expect(postAccount.sharesCount.toNumber()).to.equal(1);
+      await program.removeEventListener(listener);
+
+      const poolAfter = await program.account.rewardPool.fetch(rewardPoolPda);
+      const lamportsAfter = await provider.connection.getBalance(rewardPoolPda);
+      const deposited = poolAfter.totalDeposited.sub(poolBefore.totalDeposited);
+
+      expect(deposited.toNumber()).to.be.greaterThan(0);
+      expect(lamportsAfter - lamportsBefore).to.equal(deposited.toNumber());
+    });
+
+    it("Executes an exact buy only while the price stays within tolerance", async () => {
+      const amount = new anchor.BN(1);
+      const toleranceBps = 50;
+
+      // Mirror of BondingCurve::get_price_at_supply: BASE_PRICE + supply^2 / PRICE_MULTIPLIER
+      const quote = (supply: number, count: number) => {
+        let total = new anchor.BN(0);
+        for (let i = supply; i < supply + count; i++) {
+          total = total.add(new anchor.BN(1_000_000).add(new anchor.BN(i * i).divn(16_000)));
+        }
+        return total;
+      };
+
+      const buyExact = (totalPrice: anchor.BN) =>
+        program.methods
+          .buyKeysExact(amount, totalPrice, toleranceBps)
+          .accounts({
+            keys: creatorKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      const keysBefore = await program.account.userKeys.fetch(creatorKeysPda);
+      const price = quote(keysBefore.totalSupply.toNumber(), 1);
+
+      // Client quoted 0.6% low: the price drifted just outside the 0.5% band
+      try {
+        await buyExact(price.muln(9_940).divn(10_000));
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("SlippageExceeded");
+      }
+
+      // Client quoted 0.4% high: still inside the band, so the buy goes through
+      await buyExact(price.muln(10_040).divn(10_000));
+
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      expect(keysAfter.totalSupply.toNumber()).to.equal(keysBefore.totalSupply.toNumber() + 1);
+    });
+
+    it("Spends a lamport budget on as many keys as it covers", async () => {
+      const budget = new anchor.BN(LAMPORTS_PER_SOL / 10);
+      const buyWithBudget = (minKeysOut: number) =>
+        program.methods
+          .buyKeysExactIn(budget, new anchor.BN(minKeysOut))
+          .accounts({
+            keys: creatorKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      // The budget can't stretch to the minimum the client asked for
+      try {
+        await buyWithBudget(1_000_000);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("SlippageExceeded");
+      }
+
+      let filled = null;
+      const listener = program.addEventListener("KeysBoughtExactInEvent", (event) => {
+        filled = event;
+      });
+      const keysBefore = await program.account.userKeys.fetch(creatorKeysPda);
+      await buyWithBudget(1);
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      expect(filled).to.not.be.null;
+      expect(filled.amount.toNumber()).to.be.greaterThan(0);
+      expect(keysAfter.supply.toNumber()).to.equal(keysBefore.supply.toNumber() + filled.amount.toNumber());
+      // Fees come on top of the curve price, so the three parts add up to the budget
+      expect(filled.chargedPrice.add(filled.feeAllowance).add(filled.dustRefunded).eq(budget)).to.be.true;
+    });
+
+    it("Returns the computed price as return data on a slippage failure", async () => {
+      const amount = new anchor.BN(1);
+      const maxPrice = new anchor.BN(1);
+
+      const tx = await program.methods
+        .buyKeys(amount, maxPrice, null, null)
+        .accounts({
+          keys: creatorKeysPda,
+          user: buyerPda,
+          buyer: buyerKeypair.publicKey,
+          creator: creatorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      tx.feePayer = buyerKeypair.publicKey;
+      tx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
+      tx.sign(buyerKeypair);
+
+      const simulation = await provider.connection.simulateTransaction(tx);
+      expect(JSON.stringify(simulation.value.err)).to.include("Custom");
+      expect(simulation.value.logs.join("\n")).to.include("SlippageExceeded");
+
+      // TradeErrorContext: kind u8 | price u64 | limit u64 | amount u64, little-endian
+      const returnData = simulation.value.returnData;
+      expect(returnData.programId).to.equal(program.programId.toString());
+      const context = Buffer.from(returnData.data[0], "base64");
+      expect(context.length).to.equal(25);
+      expect(context.readUInt8(0)).to.equal(0); // BuySlippage
+      expect(Number(context.readBigUInt64LE(1))).to.be.greaterThan(maxPrice.toNumber());
+      expect(Number(context.readBigUInt64LE(9))).to.equal(maxPrice.toNumber());
+      expect(Number(context.readBigUInt64LE(17))).to.equal(amount.toNumber());
+    });
+
+    it("Rejects a buy that lands after its deadline", async () => {
+      const slot = await provider.connection.getSlot();
+      const now = await provider.connection.getBlockTime(slot);
+
+      try {
+        await program.methods
+          .buyKeys(new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(now - 60), null)
+          .accounts({
+            keys: creatorKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("DeadlineExceeded");
+      }
+    });
+
+    it("Skips the protocol fee only for exempt traders", async () => {
+      const [exemptionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("fee_exemption"), buyerKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .grantFeeExemption(buyerKeypair.publicKey)
+        .accounts({
+          config: configPda,
+          exemption: exemptionPda,
+          admin: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const events: any[] = [];
+      const listener = program.addEventListener("keysBoughtEvent", (event) => events.push(event));
+
+      const buy = (trader: Keypair, traderPda: PublicKey, feeExemption: PublicKey | null) =>
+        program.methods
+          .buyKeys(new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+          .accounts({
+            keys: creatorKeysPda,
+            user: traderPda,
+            buyer: trader.publicKey,
+            creator: creatorKeypair.publicKey,
+            feeExemption,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([trader])
+          .rpc();
+
+      await buy(buyerKeypair, buyerPda, exemptionPda);
+      await buy(userKeypair, userPda, null);
+
+      await program.removeEventListener(listener);
+
+      const [exempt, regular] = events;
+      expect(exempt.protocolFeeExempt).to.be.true;
+      expect(exempt.protocolFee.toNumber()).to.equal(0);
+      expect(exempt.subjectFee.toNumber()).to.be.greaterThan(0);
+      expect(regular.protocolFeeExempt).to.be.false;
+      expect(regular.protocolFee.toNumber()).to.be.greaterThan(0);
+
+      await program.methods
+        .revokeFeeExemption()
+        .accounts({
+          config: configPda,
+          exemption: exemptionPda,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(exemptionPda)).to.be.null;
+    });
+
+    it("Only trades inside the market's trading hours", async () => {
+      const slot = await provider.connection.getSlot();
+      const chainNow = await provider.connection.getBlockTime(slot);
+      const setHours = (openAt: number, closeAt: number) =>
+        program.methods
+          .setTradingHours(new anchor.BN(openAt), new anchor.BN(closeAt))
+          .accounts({
+            keysAccount: creatorKeysPda,
+            creator: creatorKeypair.publicKey,
+          })
+          .signers([creatorKeypair])
+          .rpc();
+      const buyTx = () =>
+        program.methods
+          .buyKeys(new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+          .accounts({
+            keys: creatorKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          });
+
+      // Window opens an hour from now: trading is closed
+      const openAt = chainNow + 3_600;
+      const closeAt = chainNow + 7_200;
+      await setHours(openAt, closeAt);
+
+      const tx = await buyTx().transaction();
+      tx.feePayer = buyerKeypair.publicKey;
+      tx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
+      tx.sign(buyerKeypair);
+
+      const simulation = await provider.connection.simulateTransaction(tx);
+      expect(simulation.value.logs.join("\n")).to.include("OperationNotAllowed");
+      const context = Buffer.from(simulation.value.returnData.data[0], "base64");
+      expect(context.readUInt8(0)).to.equal(4); // OutsideTradingHours
+      expect(Number(context.readBigUInt64LE(1))).to.equal(openAt);
+      expect(Number(context.readBigUInt64LE(9))).to.equal(closeAt);
+
+      // Window covering now: the same buy goes through
+      await setHours(chainNow - 60, chainNow + 3_600);
+      const keysBefore = await program.account.userKeys.fetch(creatorKeysPda);
+      await buyTx().signers([buyerKeypair]).rpc();
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      expect(keysAfter.totalSupply.toNumber()).to.equal(keysBefore.totalSupply.toNumber() + 1);
+
+      // Back to always open
+      await setHours(0, 0);
+    });
+
+    it("Buys through a commit-reveal cycle", async () => {
+      const amount = new anchor.BN(2);
+      const nonce = new anchor.BN(987_654_321);
+      const maxCost = new anchor.BN(LAMPORTS_PER_SOL / 2);
+      const [commitmentPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("buy_commitment"), buyerKeypair.publicKey.toBuffer(), creatorKeysPda.toBuffer()],
+        program.programId
+      );
+      const commitmentHash = createHash("sha256")
+        .update(amount.toArrayLike(Buffer, "le", 8))
+        .update(nonce.toArrayLike(Buffer, "le", 8))
+        .update(buyerKeypair.publicKey.toBuffer())
+        .digest();
+
+      await program.methods
+        .commitBuy([...commitmentHash], maxCost)
+        .accounts({
+          buyer: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          keysAccount: creatorKeysPda,
+          commitment: commitmentPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const committed = await program.account.buyCommitment.fetch(commitmentPda);
+      expect(committed.escrowedLamports.toNumber()).to.equal(maxCost.toNumber());
+      expect(Buffer.from(committed.commitment)).to.deep.equal(commitmentHash);
+
+      const keysAccount = await program.account.userKeys.fetch(creatorKeysPda);
+      const revealAccounts = {
+        buyer: buyerKeypair.publicKey,
+        subject: creatorKeypair.publicKey,
+        keysAccount: creatorKeysPda,
+        mint: keysAccount.mint,
+        buyerTokenAccount: await getAssociatedTokenAddress(keysAccount.mint, buyerKeypair.publicKey),
+        keyHolder: PublicKey.findProgramAddressSync(
+          [Buffer.from("key_holder"), buyerKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        commitment: commitmentPda,
+        config: configPda,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
+
+      // Reveals must land in a later slot than the commit
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      try {
+        await program.methods
+          .revealBuy(new anchor.BN(3), nonce)
+          .accounts(revealAccounts)
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("CommitmentMismatch");
+      }
+
+      const events: any[] = [];
+      const listener = program.addEventListener("buyRevealedEvent", (event) => events.push(event));
+
+      await program.methods
+        .revealBuy(amount, nonce)
+        .accounts(revealAccounts)
+        .signers([buyerKeypair])
+        .rpc();
+
+      await program.removeEventListener(listener);
+
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      expect(keysAfter.totalSupply.toNumber()).to.equal(keysAccount.totalSupply.toNumber() + 2);
+      expect(events[0].amount.toNumber()).to.equal(2);
+      expect(events[0].refunded.toNumber()).to.equal(maxCost.toNumber() - events[0].price.toNumber());
+      expect(await provider.connection.getAccountInfo(commitmentPda)).to.be.null;
+    });
+
+    it("Sells keys with correct pricing", async () => {
+      const amount = new anchor.BN(1);
+
+      const keysBefore = await program.account.userKeys.fetch(creatorKeysPda);
+      const supplyBefore = keysBefore.totalSupply.toNumber();
+
+      await program.methods
+        .sellKeys(amount)
+        .accounts({
+          keys: creatorKeysPda,
+          user: buyerPda,
+          seller: buyerKeypair.publicKey,
+          creator: creatorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      expect(keysAfter.totalSupply.toNumber()).to.equal(supplyBefore - 1);
+    });
+
+    it("Sells a queued position in keeper-processed tranches", async () => {
+      const [sellQueuePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("sell_queue"), buyerKeypair.publicKey.toBuffer(), creatorKeysPda.toBuffer()],
+        program.programId
+      );
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("sell_queue_escrow"), sellQueuePda.toBuffer()],
+        program.programId
+      );
+      const keysAccount = await program.account.userKeys.fetch(creatorKeysPda);
+      const sellerTokenAccount = await getAssociatedTokenAddress(keysAccount.mint, buyerKeypair.publicKey);
+      const subjectTokenAccount = await getAssociatedTokenAddress(keysAccount.mint, creatorKeysPda, true);
+
+      await program.methods
+        .queueSell(new anchor.BN(3), new anchor.BN(1), new anchor.BN(1), new anchor.BN(0))
+        .accounts({
+          sellerWallet: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          subjectKeys: creatorKeysPda,
+          mint: keysAccount.mint,
+          sellerTokenAccount,
+          sellQueue: sellQueuePda,
+          escrow: escrowPda,
+          config: configPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const tranches: any[] = [];
+      const listener = program.addEventListener("sellTrancheProcessedEvent", (event) => tranches.push(event));
+
+      const processTranche = () =>
+        program.methods
+          .processSellTranche()
+          .accounts({
+            keeper: provider.wallet.publicKey,
+            sellQueue: sellQueuePda,
+            escrow: escrowPda,
+            sellerWallet: buyerKeypair.publicKey,
+            subjectKeys: creatorKeysPda,
+            subject: creatorPda,
+            subjectTokenAccount,
+            config: configPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+
+      const supplyBefore = keysAccount.totalSupply.toNumber();
+      await processTranche();
+      await new Promise(resolve => setTimeout(resolve, 1000));
+      await processTranche();
+
+      await program.removeEventListener(listener);
+
+      expect(tranches.map((event) => event.tranche)).to.deep.equal([1, 2]);
+      expect(tranches.map((event) => event.remainingAmount.toNumber())).to.deep.equal([2, 1]);
+      // Later tranches sell lower on the curve
+      expect(tranches[1].price.toNumber()).to.be.lessThan(tranches[0].price.toNumber());
+
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      expect(keysAfter.totalSupply.toNumber()).to.equal(supplyBefore - 2);
+
+      // Cancelling returns the unsold tranche and closes the queue
+      const heldBefore = (await provider.connection.getTokenAccountBalance(sellerTokenAccount)).value.amount;
+      await program.methods
+        .cancelQueuedSell()
+        .accounts({
+          mint: keysAccount.mint,
+          sellerWallet: buyerKeypair.publicKey,
+          subjectKeys: creatorKeysPda,
+          sellQueue: sellQueuePda,
+          escrow: escrowPda,
+          sellerTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const heldAfter = (await provider.connection.getTokenAccountBalance(sellerTokenAccount)).value.amount;
+      expect(Number(heldAfter) - Number(heldBefore)).to.equal(1);
+      expect(await provider.connection.getAccountInfo(sellQueuePda)).to.be.null;
+    });
+
+    it("Fails to sell more keys than owned", async () => {
+      const amount = new anchor.BN(100);
+
+      try {
+        await program.methods
+          .sellKeys(amount)
+          .accounts({
+            keys: creatorKeysPda,
+            user: buyerPda,
+            seller: buyerKeypair.publicKey,
+            creator: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+        
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientKeys");
+      }
+    });
+
+    it("Caps how many keys a single holder can buy", async () => {
+      const communityKeypair = Keypair.generate();
+      await provider.connection.requestAirdrop(communityKeypair.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const [communityPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user"), communityKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [communityKeysPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("keys"), communityKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeUser("community", "Fair launch", "https://example.com/community.jpg")
+        .accounts({
+          user: communityPda,
+          authority: communityKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([communityKeypair])
+        .rpc();
+
+      await program.methods
+        .createKeys(new anchor.BN(0), new anchor.BN(0), {
+          custom: [{
+            curveKind: { exponential: {} },
+            basePrice: new anchor.BN(1_000_000),
+            priceFactor: new anchor.BN(1_010_000),
+            maxSupply: new anchor.BN(1_000),
+            buyCreatorFee: 500,
+            buyProtocolFee: 250,
+            sellCreatorFee: 500,
+            sellProtocolFee: 250,
+            maxKeysPerHolder: new anchor.BN(2),
+            maxPricePerKey: new anchor.BN(0),
+            slope: new anchor.BN(0),
+            midpoint: new anchor.BN(0),
+            ceilingPrice: new anchor.BN(0),
+            transferRoyaltyBps: 0,
+            earlySellFeeBps: 0,
+            sellFeeDecaySeconds: new anchor.BN(0),
+          }],
+        }, [], null, null, null, null)
+        .accounts({
+          keys: communityKeysPda,
+          user: communityPda,
+          creator: communityKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([communityKeypair])
+        .rpc();
+
+      const buy = (amount: number) =>
+        program.methods
+          .buyKeys(new anchor.BN(amount), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+          .accounts({
+            keys: communityKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: communityKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      // Up to the cap is fine
+      await buy(2);
+
+      try {
+        await buy(1);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("HolderCapReached");
+      }
+
+      // A budget buy measures the cap the same way rather than buying past it
+      try {
+        await program.methods
+          .buyKeysExactIn(new anchor.BN(LAMPORTS_PER_SOL / 10), new anchor.BN(1))
+          .accounts({
+            keys: communityKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: communityKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("HolderCapReached");
+      }
+
+      const keysAccount = await program.account.userKeys.fetch(communityKeysPda);
+      expect(keysAccount.curveParams.maxKeysPerHolder.toNumber()).to.equal(2);
+      expect(keysAccount.supply.toNumber()).to.equal(3);
+    });
+
+    it("Sells a whole position and closes the holder record", async () => {
+      const communityKeypair = Keypair.generate();
+      await provider.connection.requestAirdrop(communityKeypair.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const [communityPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user"), communityKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [communityKeysPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("keys"), communityKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [keyHolderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), buyerKeypair.publicKey.toBuffer(), communityKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeUser("liquidator", "Exit test", "https://example.com/liquidator.jpg")
+        .accounts({
+          user: communityPda,
+          authority: communityKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([communityKeypair])
+        .rpc();
+
+      await program.methods
+        .createKeys(new anchor.BN(0), new anchor.BN(0), null, [], null, null, null, null)
+        .accounts({
+          keys: communityKeysPda,
+          user: communityPda,
+          creator: communityKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([communityKeypair])
+        .rpc();
+
+      await program.methods
+        .buyKeys(new anchor.BN(3), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+        .accounts({
+          keys: communityKeysPda,
+          user: buyerPda,
+          buyer: buyerKeypair.publicKey,
+          creator: communityKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const sellAll = (minProceeds: anchor.BN) =>
+        program.methods
+          .sellAllKeys(minProceeds)
+          .accounts({
+            keys: communityKeysPda,
+            user: buyerPda,
+            seller: buyerKeypair.publicKey,
+            creator: communityKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      // Asking for more than the position is worth leaves it untouched
+      try {
+        await sellAll(new anchor.BN(LAMPORTS_PER_SOL));
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("SlippageExceeded");
+      }
+
+      const keysBefore = await program.account.userKeys.fetch(communityKeysPda);
+      await sellAll(new anchor.BN(1));
+
+      const keysAfter = await program.account.userKeys.fetch(communityKeysPda);
+      expect(keysAfter.supply.toNumber()).to.equal(keysBefore.supply.toNumber() - 3);
+      expect(await provider.connection.getAccountInfo(keyHolderPda)).to.be.null;
+    });
+
+    it("Creates keys on a sigmoid curve and rejects an unbounded one", async () => {
+      const sigmoidKeypair = Keypair.generate();
+      await provider.connection.requestAirdrop(sigmoidKeypair.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const [sigmoidPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user"), sigmoidKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [sigmoidKeysPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("keys"), sigmoidKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeUser("sigmoid", "S-curve launch", "https://example.com/sigmoid.jpg")
+        .accounts({
+          user: sigmoidPda,
+          authority: sigmoidKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([sigmoidKeypair])
+        .rpc();
+
+      const sigmoidParams = {
+        curveKind: { sigmoid: {} },
+        basePrice: new anchor.BN(1_000_000),
+        priceFactor: new anchor.BN(0),
+        maxSupply: new anchor.BN(2_000),
+        buyCreatorFee: 500,
+        buyProtocolFee: 250,
+        sellCreatorFee: 500,
+        sellProtocolFee: 250,
+        maxKeysPerHolder: new anchor.BN(0),
+        maxPricePerKey: new anchor.BN(0),
+        slope: new anchor.BN(0),
+        midpoint: new anchor.BN(500),
+        ceilingPrice: new anchor.BN(20 * LAMPORTS_PER_SOL),
+        transferRoyaltyBps: 0,
+        earlySellFeeBps: 0,
+        sellFeeDecaySeconds: new anchor.BN(0),
+      };
+      const createKeys = (params: typeof sigmoidParams) =>
+        program.methods
+          .createKeys(new anchor.BN(0), new anchor.BN(0), { custom: [params] }, [], null, null, null, null)
+          .accounts({
+            keys: sigmoidKeysPda,
+            user: sigmoidPda,
+            creator: sigmoidKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([sigmoidKeypair])
+          .rpc();
+
+      // A sigmoid without a ceiling above its base price has no shape
+      try {
+        await createKeys({ ...sigmoidParams, ceilingPrice: new anchor.BN(1_000_000) });
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidBondingCurve");
+      }
+
+      await createKeys(sigmoidParams);
+
+      const keysAccount = await program.account.userKeys.fetch(sigmoidKeysPda);
+      expect(keysAccount.curveParams.curveKind).to.deep.equal({ sigmoid: {} });
+      expect(keysAccount.curveParams.midpoint.toNumber()).to.equal(500);
+    });
+
+    it("Claims accrued earnings across vaults without touching the reserve", async () => {
+      const destination = buyerKeypair.publicKey;
+      const [creatorVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("earnings_vault"), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [userVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("earnings_vault"), userKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const vaultInfo = await provider.connection.getAccountInfo(creatorVaultPda);
+      const vaultRent = await provider.connection.getMinimumBalanceForRentExemption(vaultInfo.data.length);
+      const withdrawable = vaultInfo.lamports - vaultRent;
+      expect(withdrawable).to.be.greaterThan(0);
+
+      // Vaults owned by someone else are rejected outright
+      try {
+        await program.methods
+          .claimAllEarnings()
+          .accounts({
+            owner: creatorKeypair.publicKey,
+            destination,
+          })
+          .remainingAccounts([
+            { pubkey: creatorVaultPda, isWritable: true, isSigner: false },
+            { pubkey: userVaultPda, isWritable: true, isSigner: false },
+          ])
+          .signers([creatorKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      // Listing the same vault twice would double count it
+      try {
+        await program.methods
+          .claimAllEarnings()
+          .accounts({
+            owner: creatorKeypair.publicKey,
+            destination,
+          })
+          .remainingAccounts([
+            { pubkey: creatorVaultPda, isWritable: true, isSigner: false },
+            { pubkey: creatorVaultPda, isWritable: true, isSigner: false },
+          ])
+          .signers([creatorKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidAccountSequence");
+      }
+
+      // Markets aren't vaults; their reserve can't be claimed from
+      try {
+        await program.methods
+          .claimAllEarnings()
+          .accounts({
+            owner: creatorKeypair.publicKey,
+            destination,
+          })
+          .remainingAccounts([
+            { pubkey: creatorKeysPda, isWritable: true, isSigner: false },
+          ])
+          .signers([creatorKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("AccountDiscriminatorMismatch");
+      }
+
+      const reserveBefore = await provider.connection.getBalance(creatorKeysPda);
+      const destinationBefore = await provider.connection.getBalance(destination);
+      const vaultBefore = await program.account.earningsVault.fetch(creatorVaultPda);
+
+      await program.methods
+        .claimAllEarnings()
+        .accounts({
+          owner: creatorKeypair.publicKey,
+          destination,
+        })
+        .remainingAccounts([
+          { pubkey: creatorVaultPda, isWritable: true, isSigner: false },
+        ])
+        .signers([creatorKeypair])
+        .rpc();
+
+      const vaultAfter = await program.account.earningsVault.fetch(creatorVaultPda);
+      expect(vaultAfter.totalClaimed.sub(vaultBefore.totalClaimed).toNumber()).to.equal(withdrawable);
+      expect(await provider.connection.getBalance(creatorVaultPda)).to.equal(vaultRent);
+      expect(await provider.connection.getBalance(destination)).to.equal(destinationBefore + withdrawable);
+
+      // Creator fees were paid into the vault as trades happened; the market's reserve, which
+      // backs selling every key on the curve, is left exactly as it was
+      expect(await provider.connection.getBalance(creatorKeysPda)).to.equal(reserveBefore);
+    });
+
+    it("Buys keys from several markets in one batch", async () => {
+      const keysBefore = await program.account.userKeys.fetch(creatorKeysPda);
+      const [keyHolderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), buyerKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const legAccounts = async () => [
+        {
+          pubkey: PublicKey.findProgramAddressSync(
+            [Buffer.from("earnings_vault"), creatorKeypair.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          isWritable: true,
+          isSigner: false,
+        },
+        { pubkey: creatorKeysPda, isWritable: true, isSigner: false },
+        { pubkey: keysBefore.mint, isWritable: true, isSigner: false },
+        { pubkey: keyHolderPda, isWritable: true, isSigner: false },
+        {
+          pubkey: await getAssociatedTokenAddress(keysBefore.mint, buyerKeypair.publicKey),
+          isWritable: true,
+          isSigner: false,
+        },
+      ];
+      const leg = (maxPrice: number) => ({
+        subject: creatorKeypair.publicKey,
+        amount: new anchor.BN(1),
+        maxPrice: new anchor.BN(maxPrice),
+      });
+
+      // A leg whose curve price has moved past its limit fails the whole batch
+      try {
+        await program.methods
+          .buyKeysBatch([leg(1)])
+          .accounts({ buyer: buyerKeypair.publicKey })
+          .remainingAccounts(await legAccounts())
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("SlippageExceeded");
+      }
+
+      // The same subject can't appear in two legs
+      try {
+        await program.methods
+          .buyKeysBatch([leg(LAMPORTS_PER_SOL), leg(LAMPORTS_PER_SOL)])
+          .accounts({ buyer: buyerKeypair.publicKey })
+          .remainingAccounts([...(await legAccounts()), ...(await legAccounts())])
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidAccountSequence");
+      }
+
+      const holderBefore = await program.account.keyHolder.fetch(keyHolderPda);
+      await program.methods
+        .buyKeysBatch([leg(LAMPORTS_PER_SOL)])
+        .accounts({ buyer: buyerKeypair.publicKey })
+        .remainingAccounts(await legAccounts())
+        .signers([buyerKeypair])
+        .rpc();
+
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      expect(keysAfter.supply.toNumber()).to.equal(keysBefore.supply.toNumber() + 1);
+      const holderAfter = await program.account.keyHolder.fetch(keyHolderPda);
+      expect(holderAfter.amount.toNumber()).to.equal(holderBefore.amount.toNumber() + 1);
+    });
+
+    it("Transfers keys to another wallet without touching the curve", async () => {
+      const keysBefore = await program.account.userKeys.fetch(creatorKeysPda);
+      const holderPda = (wallet: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("key_holder"), wallet.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+          program.programId
+        )[0];
+      const recipientTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        buyerKeypair,
+        keysBefore.mint,
+        userKeypair.publicKey
+      );
+      const transfer = async (to: PublicKey, amount: number) =>
+        program.methods
+          .transferKeys(to, new anchor.BN(amount))
+          .accounts({
+            sender: buyerKeypair.publicKey,
+            recipient: to,
+            subject: creatorKeypair.publicKey,
+            senderTokenAccount: await getAssociatedTokenAddress(keysBefore.mint, buyerKeypair.publicKey),
+            recipientTokenAccount,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      const senderBefore = await program.account.keyHolder.fetch(holderPda(buyerKeypair.publicKey));
+
+      try {
+        await transfer(userKeypair.publicKey, senderBefore.amount.toNumber() + 1);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientKeys");
+      }
+
+      await transfer(userKeypair.publicKey, 1);
+
+      const senderAfter = await program.account.keyHolder.fetch(holderPda(buyerKeypair.publicKey));
+      const recipient = await program.account.keyHolder.fetch(holderPda(userKeypair.publicKey));
+      expect(senderAfter.amount.toNumber()).to.equal(senderBefore.amount.toNumber() - 1);
+      expect(recipient.amount.toNumber()).to.equal(1);
+
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      expect(keysAfter.supply.toNumber()).to.equal(keysBefore.supply.toNumber());
+      expect(keysAfter.holdersCount.toNumber()).to.equal(keysBefore.holdersCount.toNumber() + 1);
+    });
+
+    it("Gifts keys with a note kept on chain", async () => {
+      const keysBefore = await program.account.userKeys.fetch(creatorKeysPda);
+      const [giftPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("gift"), creatorKeypair.publicKey.toBuffer(), keysBefore.tradeCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [recipientHolderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), userKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const holderBefore = await program.account.keyHolder.fetch(recipientHolderPda);
+      const gift = (message: string) =>
+        program.methods
+          .giftKeys(userKeypair.publicKey, new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), message)
+          .accounts({
+            sender: buyerKeypair.publicKey,
+            recipient: userKeypair.publicKey,
+            subject: creatorKeypair.publicKey,
+            gift: giftPda,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      try {
+        await gift("k".repeat(281));
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("MessageTooLong");
+      }
+
+      await gift("Welcome aboard");
+
+      const giftAccount = await program.account.gift.fetch(giftPda);
+      expect(giftAccount.sender.toString()).to.equal(buyerKeypair.publicKey.toString());
+      expect(giftAccount.recipient.toString()).to.equal(userKeypair.publicKey.toString());
+      expect(giftAccount.message).to.equal("Welcome aboard");
+      expect(giftAccount.tradeIndex.eq(keysBefore.tradeCount)).to.be.true;
+
+      const holderAfter = await program.account.keyHolder.fetch(recipientHolderPda);
+      expect(holderAfter.amount.toNumber()).to.equal(holderBefore.amount.toNumber() + 1);
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      expect(keysAfter.supply.toNumber()).to.equal(keysBefore.supply.toNumber() + 1);
+    });
+
+    it("Stakes keys out of the sellable balance and returns them on unstake", async () => {
+      const keysAccount = await program.account.userKeys.fetch(creatorKeysPda);
+      const ownerTokenAccount = await getAssociatedTokenAddress(keysAccount.mint, buyerKeypair.publicKey);
+      const [stakePositionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_position"), buyerKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [stakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), stakePositionPda.toBuffer()],
+        program.programId
+      );
+      const balance = async (account: PublicKey) =>
+        Number((await provider.connection.getTokenAccountBalance(account)).value.amount);
+      const walletBefore = await balance(ownerTokenAccount);
+
+      await program.methods
+        .stakeKeys(new anchor.BN(1))
+        .accounts({
+          owner: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          ownerTokenAccount,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const position = await program.account.stakePosition.fetch(stakePositionPda);
+      expect(position.stakedAmount.toNumber()).to.equal(1);
+      expect(position.stakedValue.toNumber()).to.be.greaterThan(0);
+      expect(await balance(stakeVaultPda)).to.equal(1);
+      expect(await balance(ownerTokenAccount)).to.equal(walletBefore - 1);
+
+      // Less than a day in, nothing has accrued yet
+      try {
+        await program.methods
+          .claimStakingRewards()
+          .accounts({ owner: buyerKeypair.publicKey, stakePosition: stakePositionPda })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("NothingToClaim");
+      }
+
+      await program.methods
+        .unstakeKeys(new anchor.BN(1))
+        .accounts({
+          keysAccount: creatorKeysPda,
+          mint: keysAccount.mint,
+          owner: buyerKeypair.publicKey,
+          stakePosition: stakePositionPda,
+          ownerTokenAccount,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      expect(await balance(stakeVaultPda)).to.equal(0);
+      expect(await balance(ownerTokenAccount)).to.equal(walletBefore);
+    });
+
+    it("Pays holders a dividend once each, pro-rata to the opening circulating supply", async () => {
+      const keysAccount = await program.account.userKeys.fetch(creatorKeysPda);
+      const [epochPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("dividend_epoch"),
+          creatorKeypair.publicKey.toBuffer(),
+          keysAccount.dividendEpochs.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [keyHolderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), buyerKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const amount = new anchor.BN(1_000_000);
+
+      // Only the admin may fund an epoch out of the reward pool
+      try {
+        await program.methods
+          .openDividendEpoch(amount, { rewardPool: {} })
+          .accounts({ funder: creatorKeypair.publicKey, subject: creatorKeypair.publicKey, epoch: epochPda })
+          .signers([creatorKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      await new Promise(resolve => setTimeout(resolve, 1000));
+      await program.methods
+        .openDividendEpoch(amount, { rewardPool: {} })
+        .accounts({ funder: provider.wallet.publicKey, subject: creatorKeypair.publicKey, epoch: epochPda })
+        .rpc();
+
+      const epoch = await program.account.dividendEpoch.fetch(epochPda);
+      const circulating = keysAccount.supply.sub(keysAccount.creatorLockedAmount);
+      expect(epoch.totalAmount.eq(amount)).to.be.true;
+      expect(epoch.supplySnapshot.eq(circulating)).to.be.true;
+
+      const claim = () =>
+        program.methods
+          .claimDividends()
+          .accounts({ holder: buyerKeypair.publicKey, epoch: epochPda })
+          .signers([buyerKeypair])
+          .rpc();
+
+      const holder = await program.account.keyHolder.fetch(keyHolderPda);
+      await claim();
+      const afterClaim = await program.account.dividendEpoch.fetch(epochPda);
+      expect(afterClaim.totalClaimed.eq(amount.mul(holder.amount).div(circulating))).to.be.true;
+
+      // The claim checkpoint already exists, so a second claim is refused
+      try {
+        await claim();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("already in use");
+      }
+
+      // What the holders leave stays put until the claim window closes
+      try {
+        await program.methods
+          .sweepDividendEpoch()
+          .accounts({ caller: buyerKeypair.publicKey, funder: provider.wallet.publicKey, epoch: epochPda })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("DividendEpochStillOpen");
+      }
+    });
+
+    it("Funds a creator dividend from the earnings vault, leaving the reserve alone", async () => {
+      const keysAccount = await program.account.userKeys.fetch(creatorKeysPda);
+      const [epochPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("dividend_epoch"),
+          creatorKeypair.publicKey.toBuffer(),
+          keysAccount.dividendEpochs.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [vaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("earnings_vault"), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const amount = new anchor.BN(1_000);
+
+      // Top the vault up so there are earnings to share
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({ fromPubkey: provider.wallet.publicKey, toPubkey: vaultPda, lamports: amount.toNumber() })
+        )
+      );
+
+      const reserveBefore = await provider.connection.getBalance(creatorKeysPda);
+      const vaultBefore = await program.account.earningsVault.fetch(vaultPda);
+
+      await program.methods
+        .openDividendEpoch(amount, { creatorEarnings: {} })
+        .accounts({ funder: creatorKeypair.publicKey, subject: creatorKeypair.publicKey, epoch: epochPda })
+        .signers([creatorKeypair])
+        .rpc();
+
+      const vaultAfter = await program.account.earningsVault.fetch(vaultPda);
+      expect(vaultAfter.totalClaimed.sub(vaultBefore.totalClaimed).eq(amount)).to.be.true;
+      expect(await provider.connection.getBalance(creatorKeysPda)).to.equal(reserveBefore);
+
+      const epoch = await program.account.dividendEpoch.fetch(epochPda);
+      expect(epoch.funder.equals(creatorKeypair.publicKey)).to.be.true;
+      expect(epoch.totalAmount.eq(amount)).to.be.true;
+    });
+
+    it("Pays the referrer bound at sign-up a fee on the referred user's trades", async () => {
+      const referredKeypair = Keypair.generate();
+      await provider.connection.requestAirdrop(referredKeypair.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const [referredPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user"), referredKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [referralPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("referral"), referredKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeUser("referred", "Came via a friend", "https://example.com/referred.jpg")
+        .accounts({
+          user: referredPda,
+          authority: referredKeypair.publicKey,
+          referrerUser: userPda,
+          referral: referralPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([referredKeypair])
+        .rpc();
+
+      const referral = await program.account.referral.fetch(referralPda);
+      expect(referral.referrer.toString()).to.equal(userKeypair.publicKey.toString());
+
+      const keysAccount = await program.account.userKeys.fetch(creatorKeysPda);
+      await createAssociatedTokenAccount(provider.connection, referredKeypair, keysAccount.mint, referredKeypair.publicKey);
+      const buy = (referrer: PublicKey | null) =>
+        program.methods
+          .buyKeys(new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+          .accounts({
+            keys: creatorKeysPda,
+            user: referredPda,
+            buyer: referredKeypair.publicKey,
+            creator: creatorKeypair.publicKey,
+            referral: referralPda,
+            referrer,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([referredKeypair])
+          .rpc();
+
+      // The fee can't be redirected to anyone but the bound referrer
+      try {
+        await buy(buyerKeypair.publicKey);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidAccountOwner");
+      }
+
+      const referrerBefore = await provider.connection.getBalance(userKeypair.publicKey);
+      await buy(userKeypair.publicKey);
+
+      const referralAfter = await program.account.referral.fetch(referralPda);
+      expect(referralAfter.tradesReferred.toNumber()).to.equal(1);
+      expect(referralAfter.totalEarned.toNumber()).to.be.greaterThan(0);
+      expect(await provider.connection.getBalance(userKeypair.publicKey)).to.equal(
+        referrerBefore + referralAfter.totalEarned.toNumber()
+      );
+    });
+
+    it("Accrues creator fees in the earnings vault until the creator claims them", async () => {
+      const [vaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("earnings_vault"), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const vaultBefore = await program.account.earningsVault.fetch(vaultPda);
+      const creatorBefore = await provider.connection.getBalance(creatorKeypair.publicKey);
+
+      await program.methods
+        .buyKeys(new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+        .accounts({
+          keys: creatorKeysPda,
+          user: buyerPda,
+          buyer: buyerKeypair.publicKey,
+          creator: creatorKeypair.publicKey,
+          earningsVault: vaultPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      // The creator fee lands in the vault, not the creator's wallet
+      const vaultAfter = await program.account.earningsVault.fetch(vaultPda);
+      const accrued = vaultAfter.totalAccrued.sub(vaultBefore.totalAccrued).toNumber();
+      expect(accrued).to.be.greaterThan(0);
+      expect(await provider.connection.getBalance(creatorKeypair.publicKey)).to.equal(creatorBefore);
+
+      // Only the creator can pull it out
+      try {
+        await program.methods
+          .claimEarnings()
+          .accounts({ creator: buyerKeypair.publicKey, earningsVault: vaultPda })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("ConstraintSeeds");
+      }
+
+      await program.methods
+        .claimEarnings()
+        .accounts({ creator: creatorKeypair.publicKey, earningsVault: vaultPda })
+        .signers([creatorKeypair])
+        .rpc();
+
+      const vaultClaimed = await program.account.earningsVault.fetch(vaultPda);
+      expect(vaultClaimed.totalClaimed.toNumber()).to.be.greaterThanOrEqual(accrued);
+      expect(await provider.connection.getBalance(creatorKeypair.publicKey)).to.be.greaterThan(creatorBefore);
+
+      // Nothing is left above rent, so a second claim fails
+      try {
+        await program.methods
+          .claimEarnings()
+          .accounts({ creator: creatorKeypair.publicKey, earningsVault: vaultPda })
+          .signers([creatorKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("NothingToClaim");
+      }
+    });
+
+    it("Lets only the treasury authority withdraw collected protocol fees", async () => {
+      const [treasuryPda] = PublicKey.findProgramAddressSync([Buffer.from("treasury")], program.programId);
+      const [protocolFeePda] = PublicKey.findProgramAddressSync([Buffer.from("protocol_fee")], program.programId);
+      const treasuryKeypair = Keypair.generate();
+      const destination = Keypair.generate().publicKey;
+
+      const config = await program.account.protocolConfig.fetch(configPda);
+      expect(config.treasuryAuthority.toString()).to.equal(provider.wallet.publicKey.toString());
+
+      await program.methods
+        .setTreasuryAuthority(treasuryKeypair.publicKey)
+        .accounts({ config: configPda, admin: provider.wallet.publicKey })
+        .rpc();
+
+      const withdraw = (authority: PublicKey, amount: number) =>
+        program.methods
+          .withdrawTreasury(new anchor.BN(amount), destination)
+          .accounts({
+            config: configPda,
+            treasuryAuthority: authority,
+            treasury: treasuryPda,
+            protocolFeeAccount: protocolFeePda,
+            destination,
+            systemProgram: SystemProgram.programId,
+          });
+
+      // The admin handed the treasury off and can no longer withdraw
+      try {
+        await withdraw(provider.wallet.publicKey, 1_000).rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      const rentFloor = await provider.connection.getMinimumBalanceForRentExemption(0);
+      const available =
+        (await provider.connection.getBalance(treasuryPda)) - rentFloor +
+        Math.max((await provider.connection.getBalance(protocolFeePda)) - rentFloor, 0);
+      try {
+        await withdraw(treasuryKeypair.publicKey, available + 1).signers([treasuryKeypair]).rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientBalance");
+      }
+
+      // Fees from the buys above go to the chosen wallet
+      expect(available).to.be.greaterThan(0);
+      const amount = Math.min(available, 10_000);
+      const destinationBefore = await provider.connection.getBalance(destination);
+      await withdraw(treasuryKeypair.publicKey, amount).signers([treasuryKeypair]).rpc();
+      expect(await provider.connection.getBalance(destination)).to.equal(destinationBefore + amount);
+
+      // Fees from markets settling in a payment token collect in the treasury's token account,
+      // and come out under the same authority
+      const payer = (provider.wallet as anchor.Wallet).payer;
+      const paymentMint = await createMint(provider.connection, payer, provider.wallet.publicKey, null, 6);
+      const treasuryPaymentAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        paymentMint,
+        treasuryPda,
+        undefined,
+        undefined,
+        undefined,
+        true
+      );
+      await mintTo(provider.connection, payer, paymentMint, treasuryPaymentAccount, payer, 5_000);
+      const tokenDestination = await createAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        paymentMint,
+        Keypair.generate().publicKey
+      );
+      const withdrawToken = (authority: PublicKey, amount: number) =>
+        program.methods
+          .withdrawTreasuryToken(new anchor.BN(amount))
+          .accounts({
+            config: configPda,
+            treasuryAuthority: authority,
+            treasuryPaymentAccount,
+            destination: tokenDestination,
+          });
+
+      try {
+        await withdrawToken(provider.wallet.publicKey, 1_000).rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      await withdrawToken(treasuryKeypair.publicKey, 3_000).signers([treasuryKeypair]).rpc();
+      expect((await getAccount(provider.connection, tokenDestination)).amount).to.equal(BigInt(3_000));
+      expect((await getAccount(provider.connection, treasuryPaymentAccount)).amount).to.equal(BigInt(2_000));
+    });
+
+    it("Enforces trade limits the admin sets through update_config", async () => {
+      const update = (maxKeysPerTrade: number) => ({
+        maxMediaBytesPerUser: null,
+        rewardPoolBps: null,
+        graceDropBps: null,
+        graceWindowSeconds: null,
+        contentDedupWindowSeconds: null,
+        sellQueueThreshold: null,
+        maxFeeBps: null,
+        maxKeysPerTrade: new anchor.BN(maxKeysPerTrade),
+        defaultBasePrice: null,
+        milestones: null,
+        timelockSeconds: null,
+        circuitBreakerBps: null,
+        circuitBreakerWindowSeconds: null,
+        graduationMarketCap: null,
+        maxPriceImpactBps: null,
+        earlySupporterBps: null,
+      });
+      const pendingChangePda = async () => {
+        const { pendingChangeCount } = await program.account.protocolConfig.fetch(configPda);
+        return PublicKey.findProgramAddressSync(
+          [Buffer.from("pending_change"), configPda.toBuffer(), pendingChangeCount.toArrayLike(Buffer, "le", 8)],
+          program.programId
+        )[0];
+      };
+      // The test config has no timelock, so queued changes can execute straight away
+      const applyUpdate = async (maxKeysPerTrade: number) => {
+        const pendingChange = await pendingChangePda();
+        await program.methods
+          .updateConfig(update(maxKeysPerTrade))
+          .accounts({
+            config: configPda,
+            pendingChange,
+            admin: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        await program.methods
+          .executePendingChange()
+          .accounts({ config: configPda, pendingChange, proposer: provider.wallet.publicKey })
+          .rpc();
+      };
+
+      // Only the admin may change the config
+      try {
+        await program.methods
+          .updateConfig(update(1))
+          .accounts({
+            config: configPda,
+            pendingChange: await pendingChangePda(),
+            admin: buyerKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      await applyUpdate(2);
+      const config = await program.account.protocolConfig.fetch(configPda);
+      expect(config.maxKeysPerTrade.toNumber()).to.equal(2);
+      expect(config.rewardPoolBps).to.equal(2_000);
+
+      try {
+        await program.methods
+          .buyKeys(new anchor.BN(3), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+          .accounts({
+            keys: creatorKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("TradeTooLarge");
+      }
+
+      await applyUpdate(0);
+    });
+
+    it("Queues config changes behind the timelock until executed or cancelled", async () => {
+      const update = {
+        maxMediaBytesPerUser: null,
+        rewardPoolBps: null,
+        graceDropBps: null,
+        graceWindowSeconds: null,
+        contentDedupWindowSeconds: null,
+        sellQueueThreshold: null,
+        maxFeeBps: 1_500,
+        maxKeysPerTrade: null,
+        defaultBasePrice: null,
+        milestones: null,
+        timelockSeconds: null,
+        circuitBreakerBps: null,
+        circuitBreakerWindowSeconds: null,
+        graduationMarketCap: null,
+        maxPriceImpactBps: null,
+        earlySupporterBps: null,
+      };
+      const { pendingChangeCount, maxFeeBps } = await program.account.protocolConfig.fetch(configPda);
+      const [pendingChange] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pending_change"), configPda.toBuffer(), pendingChangeCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .updateConfig(update)
+        .accounts({
+          config: configPda,
+          pendingChange,
+          admin: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      // Queueing alone leaves the config untouched
+      const queued = await program.account.pendingChange.fetch(pendingChange);
+      expect(queued.update.maxFeeBps).to.equal(1_500);
+      expect((await program.account.protocolConfig.fetch(configPda)).maxFeeBps).to.equal(maxFeeBps);
+
+      try {
+        await program.methods
+          .cancelPendingChange()
+          .accounts({
+            config: configPda,
+            pendingChange,
+            admin: buyerKeypair.publicKey,
+            proposer: provider.wallet.publicKey,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      await program.methods
+        .cancelPendingChange()
+        .accounts({
+          config: configPda,
+          pendingChange,
+          admin: provider.wallet.publicKey,
+          proposer: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(pendingChange)).to.be.null;
+      expect((await program.account.protocolConfig.fetch(configPda)).maxFeeBps).to.equal(maxFeeBps);
+    });
+
+    it("Halts guarded subsystems through feature flags", async () => {
+      const TRADING_DISABLED = 1 << 0;
+      const EMERGENCY_STOP = 1 << 4;
+      const setFlags = (bits: number) =>
+        program.methods
+          .setFeatureFlags({ bits })
+          .accounts({ config: configPda, admin: provider.wallet.publicKey })
+          .rpc();
+      const buy = () =>
+        program.methods
+          .buyKeys(new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+          .accounts({
+            keys: creatorKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: creatorKeypair.publicKey,
+            config: configPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      // Unknown bits are rejected
+      try {
+        await setFlags(1 << 5);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidConfigValue");
+      }
+
+      for (const [bits, reason] of [
+        [TRADING_DISABLED, "FeatureDisabled"],
+        [EMERGENCY_STOP, "EmergencyStop"],
+      ] as const) {
+        await setFlags(bits);
+        try {
+          await buy();
+          expect.fail("Should have failed");
+        } catch (error) {
+          expect(error.toString()).to.include(reason);
+        }
+      }
+
+      await setFlags(0);
+      await buy();
+    });
+
+    it("Requires M-of-N multisig approval for admin actions", async () => {
+      const [multisigPda] = PublicKey.findProgramAddressSync([Buffer.from("admin_multisig")], program.programId);
+      const signerB = Keypair.generate();
+      const signerC = Keypair.generate();
+      const proposalPda = (index: number) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("admin_proposal"), multisigPda.toBuffer(), new anchor.BN(index).toArrayLike(Buffer, "le", 8)],
+          program.programId
+        )[0];
+
+      await program.methods
+        .createAdminMultisig([provider.wallet.publicKey, signerB.publicKey, signerC.publicKey], 2)
+        .accounts({
+          multisig: multisigPda,
+          config: configPda,
+          admin: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      let config = await program.account.protocolConfig.fetch(configPda);
+      expect(config.admin.toString()).to.equal(multisigPda.toString());
+      expect(config.treasuryAuthority.toString()).to.equal(multisigPda.toString());
+
+      // The old admin key alone can no longer change the config
+      try {
+        await program.methods
+          .setFeatureFlags({ bits: 0 })
+          .accounts({ config: configPda, admin: provider.wallet.publicKey })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      const propose = (index: number, action: any) =>
+        program.methods
+          .proposeAdminAction(action)
+          .accounts({
+            multisig: multisigPda,
+            proposal: proposalPda(index),
+            proposer: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      const approve = (index: number, signer: Keypair) =>
+        program.methods
+          .approveAdminAction()
+          .accounts({ multisig: multisigPda, proposal: proposalPda(index), signer: signer.publicKey })
+          .signers([signer])
+          .rpc();
+      const execute = (index: number, extra: any = {}) =>
+        program.methods
+          .executeAdminAction()
+          .accounts({
+            multisig: multisigPda,
+            proposal: proposalPda(index),
+            config: configPda,
+            executor: provider.wallet.publicKey,
+            treasury: PublicKey.findProgramAddressSync([Buffer.from("treasury")], program.programId)[0],
+            protocolFeeAccount: PublicKey.findProgramAddressSync([Buffer.from("protocol_fee")], program.programId)[0],
+            destination: null,
+            targetUser: null,
+            feeExemption: null,
+            systemProgram: SystemProgram.programId,
+            ...extra,
+          })
+          .rpc();
+
+      await propose(0, { setVerified: { user: creatorKeypair.publicKey, verified: true } });
+
+      // One approval is below the 2-of-3 threshold
+      try {
+        await execute(0, { targetUser: creatorPda });
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientMultisigApprovals");
+      }
+
+      try {
+        await approve(0, buyerKeypair);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("NotMultisigSigner");
+      }
+
+      await approve(0, signerB);
+      await execute(0, { targetUser: creatorPda });
+      expect((await program.account.user.fetch(creatorPda)).isVerified).to.be.true;
+
+      try {
+        await execute(0, { targetUser: creatorPda });
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalAlreadyExecuted");
+      }
+
+      // Fee exemptions, which the old admin key can no longer grant, go through proposals too
+      const exemptWallet = Keypair.generate().publicKey;
+      const [exemptionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("fee_exemption"), exemptWallet.toBuffer()],
+        program.programId
+      );
+      await propose(1, { grantFeeExemption: { account: exemptWallet } });
+      await approve(1, signerB);
+      await execute(1, { feeExemption: exemptionPda });
+      const exemption = await program.account.feeExemption.fetch(exemptionPda);
+      expect(exemption.account.toString()).to.equal(exemptWallet.toString());
+      expect(exemption.grantedBy.toString()).to.equal(multisigPda.toString());
+
+      await propose(2, { revokeFeeExemption: { account: exemptWallet } });
+      await approve(2, signerC);
+      await execute(2, { feeExemption: exemptionPda });
+      expect(await provider.connection.getAccountInfo(exemptionPda)).to.be.null;
+
+      // Hand the admin back so later tests can keep using the provider wallet
+      await propose(3, { setAdmin: [provider.wallet.publicKey] });
+      await approve(3, signerC);
+      await execute(3);
+      config = await program.account.protocolConfig.fetch(configPda);
+      expect(config.admin.toString()).to.equal(provider.wallet.publicKey.toString());
+    });
+
+    it("Sells launch keys through a Dutch auction before opening the curve", async () => {
+      const launchKeypair = Keypair.generate();
+      await provider.connection.requestAirdrop(launchKeypair.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const [launchPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user"), launchKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [launchKeysPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("keys"), launchKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeUser("launcher", "Auction test", "https://example.com/launcher.jpg")
+        .accounts({
+          user: launchPda,
+          authority: launchKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([launchKeypair])
+        .rpc();
+
+      await program.methods
+        .createKeys(new anchor.BN(0), new anchor.BN(0), null, [], {
+          keysForSale: new anchor.BN(2),
+          startPrice: new anchor.BN(50_000_000),
+          floorPrice: new anchor.BN(5_000_000),
+          durationSeconds: new anchor.BN(3_600),
+        }, null, null, null)
+        .accounts({
+          keys: launchKeysPda,
+          user: launchPda,
+          creator: launchKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([launchKeypair])
+        .rpc();
+
+      // The curve stays closed while the auction runs
+      try {
+        await program.methods
+          .buyKeys(new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+          .accounts({
+            keys: launchKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: launchKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("OperationNotAllowed");
+      }
+
+      const bid = (amount: number, maxPrice: number) =>
+        program.methods
+          .bidLaunchAuction(new anchor.BN(amount), new anchor.BN(maxPrice))
+          .accounts({
+            buyer: buyerKeypair.publicKey,
+            subject: launchKeypair.publicKey,
+            keysAccount: launchKeysPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      // Early in the auction the price is still near the start
+      try {
+        await bid(2, 2 * 5_000_000);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("SlippageExceeded");
+      }
+
+      await bid(2, 2 * 50_000_000);
+      const keysAccount = await program.account.userKeys.fetch(launchKeysPda);
+      expect(keysAccount.launchAuction.keysSold.toNumber()).to.equal(2);
+
+      // Sold out: further bids fail and the curve is open
+      try {
+        await bid(1, 50_000_000);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("LaunchAuctionNotLive");
+      }
+    });
+
+    it("Sells presale keys only to allowlisted wallets up to their cap", async () => {
+      const presaleKeypair = Keypair.generate();
+      await provider.connection.requestAirdrop(presaleKeypair.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const [presaleUserPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user"), presaleKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [presaleKeysPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("keys"), presaleKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeUser("presaler", "Presale test", "https://example.com/presaler.jpg")
+        .accounts({
+          user: presaleUserPda,
+          authority: presaleKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([presaleKeypair])
+        .rpc();
+
+      // A one-wallet allowlist: the root is the buyer's leaf and the proof is empty
+      const merkleRoot = Array.from(createHash("sha256").update(buyerKeypair.publicKey.toBuffer()).digest());
+      await program.methods
+        .createKeys(new anchor.BN(0), new anchor.BN(0), null, [], null, {
+          merkleRoot,
+          price: new anchor.BN(20_000_000),
+          maxPerWallet: new anchor.BN(2),
+          keysForSale: new anchor.BN(5),
+          durationSeconds: new anchor.BN(3_600),
+        }, null, null)
+        .accounts({
+          keys: presaleKeysPda,
+          user: presaleUserPda,
+          creator: presaleKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([presaleKeypair])
+        .rpc();
+
+      const buyPresale = (buyer: Keypair, amount: number) =>
+        program.methods
+          .buyPresaleKeys(new anchor.BN(amount), [])
+          .accounts({
+            buyer: buyer.publicKey,
+            subject: presaleKeypair.publicKey,
+            keysAccount: presaleKeysPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+
+      try {
+        await buyPresale(userKeypair, 1);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("NotAllowlisted");
+      }
+
+      const holdersBefore = (await program.account.userKeys.fetch(presaleKeysPda)).holdersCount.toNumber();
+      await buyPresale(buyerKeypair, 2);
+      try {
+        await buyPresale(buyerKeypair, 1);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("PresaleWalletCapReached");
+      }
+
+      const keysAccount = await program.account.userKeys.fetch(presaleKeysPda);
+      expect(keysAccount.presale.keysSold.toNumber()).to.equal(2);
+
+      // Presale keys land on the buyer's holding record, so they count as held and can be sold
+      const [keyHolderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), buyerKeypair.publicKey.toBuffer(), presaleKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const keyHolder = await program.account.keyHolder.fetch(keyHolderPda);
+      expect(keyHolder.amount.toNumber()).to.equal(2);
+      expect(keyHolder.totalInvested.toNumber()).to.equal(40_000_000);
+      expect(keysAccount.holdersCount.toNumber()).to.equal(holdersBefore + 1);
+    });
+
+    it("Caps each wallet's buys during a guarded launch", async () => {
+      const guardedKeypair = Keypair.generate();
+      await provider.connection.requestAirdrop(guardedKeypair.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const [guardedPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user"), guardedKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [guardedKeysPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("keys"), guardedKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeUser("guarded", "Launch guard test", "https://example.com/guarded.jpg")
+        .accounts({
+          user: guardedPda,
+          authority: guardedKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([guardedKeypair])
+        .rpc();
+
+      await program.methods
+        .createKeys(new anchor.BN(0), new anchor.BN(0), null, [], null, null, {
+          guardSlots: new anchor.BN(9_000),
+          maxBuyPerWallet: new anchor.BN(2),
+          cooldownSlots: new anchor.BN(0),
+        }, null)
+        .accounts({
+          keys: guardedKeysPda,
+          user: guardedPda,
+          creator: guardedKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([guardedKeypair])
+        .rpc();
+
+      const buy = (amount: number) =>
+        program.methods
+          .buyKeys(new anchor.BN(amount), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+          .accounts({
+            keys: guardedKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: guardedKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      await buy(2);
+      try {
+        await buy(1);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("LaunchBuyCapReached");
+      }
+    });
+
+    it("Lets the creator halt and resume trading on their market", async () => {
+      const setHalt = (method: "haltTrading" | "resumeTrading", authority: Keypair) =>
+        program.methods[method]()
+          .accounts({
+            keysAccount: creatorKeysPda,
+            subject: creatorKeypair.publicKey,
+            config: configPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+      const buy = () =>
+        program.methods
+          .buyKeys(new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+          .accounts({
+            keys: creatorKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      try {
+        await setHalt("haltTrading", buyerKeypair);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      await setHalt("haltTrading", creatorKeypair);
+      expect((await program.account.userKeys.fetch(creatorKeysPda)).tradingHalted).to.be.true;
+      try {
+        await buy();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("OperationNotAllowed");
+      }
+
+      await setHalt("resumeTrading", creatorKeypair);
+      await buy();
+    });
+
+    it("Escrows an OTC buy offer for its named counterparty and refunds it on cancel", async () => {
+      const offerId = new anchor.BN(1);
+      const [offerPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("otc_offer"),
+          buyerKeypair.publicKey.toBuffer(),
+          creatorKeypair.publicKey.toBuffer(),
+          offerId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("otc_escrow"), offerPda.toBuffer()],
+        program.programId
+      );
+      const price = new anchor.BN(LAMPORTS_PER_SOL / 4);
+      const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+
+      await program.methods
+        .createOtcOffer({ buy: {} }, new anchor.BN(2), price, userKeypair.publicKey, new anchor.BN(expiresAt), offerId)
+        .accounts({
+          maker: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          offer: offerPda,
+          escrow: escrowPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const offer = await program.account.otcOffer.fetch(offerPda);
+      expect(offer.escrowedLamports.toString()).to.equal(price.toString());
+      expect(offer.taker.toString()).to.equal(userKeypair.publicKey.toString());
+
+      // Only the named counterparty may take the offer
+      try {
+        await program.methods
+          .acceptOtcOffer()
+          .accounts({
+            mint: (await program.account.userKeys.fetch(creatorKeysPda)).mint,
+            taker: creatorKeypair.publicKey,
+            maker: buyerKeypair.publicKey,
+            subject: creatorKeypair.publicKey,
+            offer: offerPda,
+            escrow: escrowPda,
+          })
+          .signers([creatorKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("NotOtcCounterparty");
+      }
+
+      const balanceBefore = await provider.connection.getBalance(buyerKeypair.publicKey);
+      await program.methods
+        .cancelOtcOffer()
+        .accounts({
+          mint: (await program.account.userKeys.fetch(creatorKeysPda)).mint,
+          maker: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          offer: offerPda,
+          escrow: escrowPda,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(offerPda)).to.be.null;
+      expect(await provider.connection.getAccountInfo(escrowPda)).to.be.null;
+      const balanceAfter = await provider.connection.getBalance(buyerKeypair.publicKey);
+      expect(balanceAfter - balanceBefore).to.be.at.least(price.toNumber());
+    });
+
+    it("Records each trade in the market's price oracle", async () => {
+      const [priceOraclePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("price_oracle"), creatorKeysPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .buyKeys(new anchor.BN(1))
+        .accounts({
+          keys: creatorKeysPda,
+          user: buyerPda,
+          buyer: buyerKeypair.publicKey,
+          creator: creatorKeypair.publicKey,
+          config: configPda,
+          priceOracle: priceOraclePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const oracle = await program.account.priceOracle.fetch(priceOraclePda);
+      expect(oracle.market.toString()).to.equal(creatorKeysPda.toString());
+      expect(oracle.count).to.be.greaterThan(0);
+      expect(oracle.twap.toNumber()).to.be.greaterThan(0);
+      expect(oracle.lastUpdated.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("Refuses to graduate a market while graduation is disabled", async () => {
+      const config = await program.account.protocolConfig.fetch(configPda);
+      expect(config.graduationMarketCap.toNumber()).to.equal(0);
+
+      const [graduationAuthority] = PublicKey.findProgramAddressSync(
+        [Buffer.from("graduation"), creatorKeysPda.toBuffer()],
+        program.programId
+      );
+      const keys = await program.account.userKeys.fetch(creatorKeysPda);
+      const nativeMint = new PublicKey("So11111111111111111111111111111111111111112");
+
+      try {
+        await program.methods
+          .graduateMarket()
+          .accounts({
+            payer: provider.wallet.publicKey,
+            subject: creatorKeypair.publicKey,
+            mint: keys.mint,
+            nativeMint,
+            graduationAuthority,
+            treasury: PublicKey.findProgramAddressSync([Buffer.from("treasury")], program.programId)[0],
+            authorityKeysAccount: await getAssociatedTokenAddress(keys.mint, graduationAuthority, true),
+            authorityWsolAccount: await getAssociatedTokenAddress(nativeMint, graduationAuthority, true),
+            ammProgram: new PublicKey("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C"),
+            ammConfig: Keypair.generate().publicKey,
+            ammAuthority: Keypair.generate().publicKey,
+            poolState: Keypair.generate().publicKey,
+            lpMint: Keypair.generate().publicKey,
+            authorityLpAccount: Keypair.generate().publicKey,
+            token0Vault: Keypair.generate().publicKey,
+            token1Vault: Keypair.generate().publicKey,
+            createPoolFee: Keypair.generate().publicKey,
+            observationState: Keypair.generate().publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("GraduationDisabled");
+      }
+
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      expect(keysAfter.graduatedAt.toNumber()).to.equal(0);
+    });
+
+    it("Holds a vesting creator allocation back until its cliff", async () => {
+      const vestingKeypair = Keypair.generate();
+      await provider.connection.requestAirdrop(vestingKeypair.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const [vestingUserPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user"), vestingKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [vestingKeysPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("keys"), vestingKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [vestingPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vesting"), vestingKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeUser("vesting", "Vesting test", "https://example.com/vesting.jpg")
+        .accounts({
+          user: vestingUserPda,
+          authority: vestingKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([vestingKeypair])
+        .rpc();
+
+      await program.methods
+        .createKeys(new anchor.BN(0), new anchor.BN(0), null, [], null, null, null, {
+          amount: new anchor.BN(20),
+          cliffSeconds: new anchor.BN(3_600),
+          durationSeconds: new anchor.BN(86_400),
+        })
+        .accounts({
+          keys: vestingKeysPda,
+          user: vestingUserPda,
+          creator: vestingKeypair.publicKey,
+          vesting: vestingPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([vestingKeypair])
+        .rpc();
+
+      const vesting = await program.account.vesting.fetch(vestingPda);
+      expect(vesting.totalAmount.toNumber()).to.equal(20);
+      expect(vesting.cliffAt.sub(vesting.startAt).toNumber()).to.equal(3_600);
+
+      const keysAccount = await program.account.userKeys.fetch(vestingKeysPda);
+      expect(keysAccount.supply.toNumber()).to.equal(1 + 20);
+      expect(keysAccount.creatorLockedAmount.toNumber()).to.equal(1 + 20);
+
+      // The creator paid the allocation's curve price into the reserve up front
+      const keysInfo = await provider.connection.getAccountInfo(vestingKeysPda);
+      const keysRent = await provider.connection.getMinimumBalanceForRentExemption(keysInfo.data.length);
+      expect(keysInfo.lamports).to.be.greaterThan(keysRent + 20 * keysAccount.curveParams.basePrice.toNumber());
+
+      const mint = keysAccount.mint;
+      await createAssociatedTokenAccount(provider.connection, vestingKeypair, mint, vestingKeypair.publicKey);
+      try {
+        await program.methods
+          .claimVestedKeys()
+          .accounts({
+            creator: vestingKeypair.publicKey,
+            vesting: vestingPda,
+            mint,
+          })
+          .signers([vestingKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("NothingToClaim");
+      }
+    });
+
+    it("Backs claimed vesting keys with the creator's deposit so selling them keeps the curve whole", async () => {
+      const vestingKeypair = Keypair.generate();
+      await provider.connection.requestAirdrop(vestingKeypair.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const [vestingUserPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user"), vestingKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [vestingKeysPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("keys"), vestingKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [vestingPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vesting"), vestingKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeUser("vestsell", "Vesting sell test", "https://example.com/vestsell.jpg")
+        .accounts({
+          user: vestingUserPda,
+          authority: vestingKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([vestingKeypair])
+        .rpc();
+
+      // No cliff and a one second schedule, so everything has vested by the claim
+      await program.methods
+        .createKeys(new anchor.BN(0), new anchor.BN(0), null, [], null, null, null, {
+          amount: new anchor.BN(4),
+          cliffSeconds: new anchor.BN(0),
+          durationSeconds: new anchor.BN(1),
+        })
+        .accounts({
+          keys: vestingKeysPda,
+          user: vestingUserPda,
+          creator: vestingKeypair.publicKey,
+          vesting: vestingPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([vestingKeypair])
+        .rpc();
+      await new Promise(resolve => setTimeout(resolve, 2000));
+
+      const mint = (await program.account.userKeys.fetch(vestingKeysPda)).mint;
+      const creatorTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        vestingKeypair,
+        mint,
+        vestingKeypair.publicKey
+      );
+      await program.methods
+        .claimVestedKeys()
+        .accounts({
+          creator: vestingKeypair.publicKey,
+          vesting: vestingPda,
+          mint,
+        })
+        .signers([vestingKeypair])
+        .rpc();
+      expect((await getAccount(provider.connection, creatorTokenAccount)).amount).to.equal(BigInt(4));
+
+      const keysBefore = await program.account.userKeys.fetch(vestingKeysPda);
+      // creator key + vesting allocation, both counted at creation
+      expect(keysBefore.supply.toNumber()).to.equal(1 + 4);
+      expect(keysBefore.creatorLockedAmount.toNumber()).to.equal(1);
+      expect(keysBefore.holdersCount.toNumber()).to.equal(1);
+
+      // Nobody else has bought, yet the reserve pays for every vested key
+      await program.methods
+        .sellKeys(new anchor.BN(4), new anchor.BN(0), null, null)
+        .accounts({
+          sellerWallet: vestingKeypair.publicKey,
+          subject: vestingUserPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([vestingKeypair])
+        .rpc();
+
+      // Every minted key plus the unminted creator key is still backed by supply
+      const keysAfter = await program.account.userKeys.fetch(vestingKeysPda);
+      const mintAfter = await getMint(provider.connection, mint);
+      expect(keysAfter.supply.toNumber()).to.equal(keysBefore.supply.toNumber() - 4);
+      expect(BigInt(keysAfter.supply.toNumber())).to.equal(
+        mintAfter.supply + BigInt(keysAfter.creatorLockedAmount.toNumber())
+      );
+    });
+
+    it("Buys back keys out of the earnings vault and burns them", async () => {
+      const [earningsVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("earnings_vault"), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      // Top the vault up so the buyback doesn't depend on fees from earlier tests
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: provider.wallet.publicKey,
+            toPubkey: earningsVaultPda,
+            lamports: LAMPORTS_PER_SOL / 2,
+          })
+        )
+      );
+
+      const keysBefore = await program.account.userKeys.fetch(creatorKeysPda);
+      const mintBefore = await getMint(provider.connection, keysBefore.mint);
+      const reserveBefore = await provider.connection.getBalance(creatorKeysPda);
+      const events: any[] = [];
+      const listener = program.addEventListener("buybackEvent", (event) => events.push(event));
+
+      await program.methods
+        .buybackKeys(new anchor.BN(1))
+        .accounts({
+          creator: creatorKeypair.publicKey,
+          earningsVault: earningsVaultPda,
+          config: configPda,
+        })
+        .signers([creatorKeypair])
+        .rpc();
+
+      const keysAfter = await program.account.userKeys.fetch(creatorKeysPda);
+      const mintAfter = await getMint(provider.connection, keysBefore.mint);
+      expect(keysAfter.supply.toNumber()).to.equal(keysBefore.supply.toNumber());
+      expect(keysAfter.burnedKeys.toNumber()).to.equal(keysBefore.burnedKeys.toNumber() + 1);
+      expect(mintAfter.supply.toString()).to.equal(mintBefore.supply.toString());
+
+      // The key is bought and burned in one step, so all that's left is the price in the reserve
+      await new Promise(resolve => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+      expect(events.length).to.equal(1);
+      expect(events[0].supplyAfter.toNumber()).to.equal(keysBefore.supply.toNumber());
+      expect(await provider.connection.getBalance(creatorKeysPda)).to.equal(reserveBefore + events[0].price.toNumber());
+      expect(events[0].circulatingSupply.toString()).to.equal(
+        keysAfter.supply.sub(keysAfter.creatorLockedAmount).toString()
+      );
+    });
+
+    it("Refuses to switch a market that has traded to a payment token", async () => {
+      const paymentMint = await createMint(
+        provider.connection,
+        (provider.wallet as anchor.Wallet).payer,
+        provider.wallet.publicKey,
+        null,
+        6
+      );
+      const [paymentReserve] = PublicKey.findProgramAddressSync(
+        [Buffer.from("payment_reserve"), creatorKeysPda.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .setPaymentMint()
+          .accounts({
+            creator: creatorKeypair.publicKey,
+            paymentMint,
+            paymentReserve,
+          })
+          .signers([creatorKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidPaymentMint");
+      }
+
+      const keysAccount = await program.account.userKeys.fetch(creatorKeysPda);
+      expect(keysAccount.paymentMint.equals(PublicKey.default)).to.be.true;
+    });
+
+    it("Enforces the buyer's price impact cap", async () => {
+      const supplyBefore = (await program.account.userKeys.fetch(creatorKeysPda)).supply.toNumber();
+
+      try {
+        await program.methods
+          .buyKeys(new anchor.BN(3), new anchor.BN(LAMPORTS_PER_SOL), null, 1)
+          .accounts({
+            keys: creatorKeysPda,
+            user: buyerPda,
+            buyer: buyerKeypair.publicKey,
+            creator: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("PriceImpactTooHigh");
+      }
+
+      const supplyAfter = (await program.account.userKeys.fetch(creatorKeysPda)).supply.toNumber();
+      expect(supplyAfter).to.equal(supplyBefore);
+    });
+
+    it("Lists created markets in the registry", async () => {
+      const [registryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_market_registry")],
+        program.programId
+      );
+      const [firstPagePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_market_page"), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      const registry = await program.account.keyMarketRegistry.fetch(registryPda);
+      const firstPage = await program.account.keyMarketRegistryPage.fetch(firstPagePda);
+      expect(registry.marketCount.toNumber()).to.be.greaterThan(0);
+      expect(firstPage.pageIndex.toNumber()).to.equal(0);
+
+      const listed = firstPage.markets.slice(0, firstPage.count);
+      expect(listed.some((market: PublicKey) => market.equals(creatorKeysPda))).to.be.true;
+    });
+
+    it("Keeps a portfolio of the buyer's positions", async () => {
+      const [portfolioPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("portfolio"), buyerKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const before = await program.account.portfolio.fetch(portfolioPda);
+
+      await program.methods
+        .buyKeys(new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+        .accounts({
+          keys: creatorKeysPda,
+          user: buyerPda,
+          buyer: buyerKeypair.publicKey,
+          creator: creatorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const after = await program.account.portfolio.fetch(portfolioPda);
+      expect(after.owner.equals(buyerKeypair.publicKey)).to.be.true;
+      expect(after.totalInvested.toNumber()).to.be.greaterThan(before.totalInvested.toNumber());
+      expect(after.tradeCount.toNumber()).to.equal(before.tradeCount.toNumber() + 1);
+      expect(after.marketsHeld).to.be.greaterThan(0);
+    });
+
+    it("Executes trigger orders only once the price crosses them", async () => {
+      const { mint } = await program.account.userKeys.fetch(creatorKeysPda);
+      const triggerOrderPda = (orderId: anchor.BN) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("trigger_order"),
+            buyerKeypair.publicKey.toBuffer(),
+            creatorKeypair.publicKey.toBuffer(),
+            orderId.toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        )[0];
+      const place = (kind: any, orderId: anchor.BN, minProceeds = 0) =>
+        program.methods
+          .createTriggerOrder(kind, new anchor.BN(1), new anchor.BN(1), new anchor.BN(minProceeds), orderId)
+          .accounts({
+            mint,
+            owner: buyerKeypair.publicKey,
+            subject: creatorKeypair.publicKey,
+            order: triggerOrderPda(orderId),
+            config: configPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+      const execute = (orderId: anchor.BN) =>
+        program.methods
+          .executeTriggerOrder()
+          .accounts({
+            keeper: provider.wallet.publicKey,
+            owner: buyerKeypair.publicKey,
+            subject: creatorKeypair.publicKey,
+            order: triggerOrderPda(orderId),
+            config: configPda,
+          })
+          .rpc();
+
+      // The oracle TWAP is far above a 1 lamport stop-loss
+      const stopLossId = new anchor.BN(900);
+      await place({ stopLoss: {} }, stopLossId);
+      try {
+        await execute(stopLossId);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("TriggerNotReached");
+      }
+      await program.methods
+        .cancelTriggerOrder()
+        .accounts({
+          mint,
+          owner: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          order: triggerOrderPda(stopLossId),
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      // ...and already past a 1 lamport take-profit, which still won't sell below its minimum
+      const greedyId = new anchor.BN(902);
+      await place({ takeProfit: {} }, greedyId, 1_000 * LAMPORTS_PER_SOL);
+      try {
+        await execute(greedyId);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("SlippageExceeded");
+      }
+      await program.methods
+        .cancelTriggerOrder()
+        .accounts({
+          mint,
+          owner: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          order: triggerOrderPda(greedyId),
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const takeProfitId = new anchor.BN(901);
+      await place({ takeProfit: {} }, takeProfitId);
+      const supplyBefore = (await program.account.userKeys.fetch(creatorKeysPda)).supply.toNumber();
+      await execute(takeProfitId);
+
+      const supplyAfter = (await program.account.userKeys.fetch(creatorKeysPda)).supply.toNumber();
+      expect(supplyAfter).to.equal(supplyBefore - 1);
+      expect(await provider.connection.getAccountInfo(triggerOrderPda(takeProfitId))).to.be.null;
+    });
+
+    it("Runs a DCA schedule on its interval", async () => {
+      const [schedulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("dca_schedule"),
+          buyerKeypair.publicKey.toBuffer(),
+          creatorKeypair.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const execute = () =>
+        program.methods
+          .executeDca()
+          .accounts({
+            keeper: provider.wallet.publicKey,
+            owner: buyerKeypair.publicKey,
+            subject: creatorKeypair.publicKey,
+            schedule: schedulePda,
+            config: configPda,
+          })
+          .rpc();
+
+      await program.methods
+        .createDcaSchedule(
+          creatorKeypair.publicKey,
+          new anchor.BN(LAMPORTS_PER_SOL / 10),
+          new anchor.BN(3600),
+          new anchor.BN(LAMPORTS_PER_SOL / 2)
+        )
+        .accounts({
+          owner: buyerKeypair.publicKey,
+          schedule: schedulePda,
+          config: configPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      // The first interval is due at creation
+      const supplyBefore = (await program.account.userKeys.fetch(creatorKeysPda)).supply.toNumber();
+      await execute();
+      const schedule = await program.account.dcaSchedule.fetch(schedulePda);
+      expect(schedule.executions.toNumber()).to.equal(1);
+      expect(schedule.keysBought.toNumber()).to.be.greaterThan(0);
+      const supplyAfter = (await program.account.userKeys.fetch(creatorKeysPda)).supply.toNumber();
+      expect(supplyAfter).to.equal(supplyBefore + schedule.keysBought.toNumber());
+
+      // The next one is an hour out
+      try {
+        await execute();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("DcaNotDue");
+      }
+
+      await program.methods
+        .cancelDcaSchedule()
+        .accounts({
+          owner: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          schedule: schedulePda,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+      expect(await provider.connection.getAccountInfo(schedulePda)).to.be.null;
+    });
+
+    it("Pays early supporters a share of later creator fees", async () => {
+      const [poolPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("early_supporter_pool"), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const pool = await program.account.earlySupporterPool.fetch(poolPda);
+      const seated = pool.supporters.slice(0, pool.supporterCount);
+      expect(seated.some((supporter: PublicKey) => supporter.equals(buyerKeypair.publicKey))).to.be.true;
+
+      await program.methods
+        .buyKeys(new anchor.BN(1), new anchor.BN(LAMPORTS_PER_SOL), null, null)
+        .accounts({
+          keys: creatorKeysPda,
+          user: buyerPda,
+          buyer: buyerKeypair.publicKey,
+          creator: creatorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const claim = (supporter: Keypair) =>
+        program.methods
+          .claimEarlySupporterRewards()
+          .accounts({
+            supporter: supporter.publicKey,
+            subject: creatorKeypair.publicKey,
+            earlySupporterPool: poolPda,
+          })
+          .signers([supporter])
+          .rpc();
+
+      await claim(buyerKeypair);
+      const afterClaim = await program.account.earlySupporterPool.fetch(poolPda);
+      expect(afterClaim.totalClaimed.toNumber()).to.be.greaterThan(pool.totalClaimed.toNumber());
+
+      // The creator holds no seat in their own market
+      try {
+        await claim(creatorKeypair);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("NotEarlySupporter");
+      }
+    });
+
+    it("Wraps keys into a transferable token and back", async () => {
+      const keyHolderPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), buyerKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      )[0];
+      const [wrappedMintPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("wrapped_keys_mint"), creatorKeysPda.toBuffer()],
+        program.programId
+      );
+      const accounts = {
+        holder: buyerKeypair.publicKey,
+        subject: creatorKeypair.publicKey,
+        wrappedMint: wrappedMintPda,
+        config: configPda,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
+      const heldBefore = (await program.account.keyHolder.fetch(keyHolderPda)).amount.toNumber();
+
+      await program.methods.wrapKeys(new anchor.BN(1)).accounts(accounts).signers([buyerKeypair]).rpc();
+      expect(Number((await getMint(provider.connection, wrappedMintPda)).supply)).to.equal(1);
+      expect((await program.account.keyHolder.fetch(keyHolderPda)).amount.toNumber()).to.equal(heldBefore - 1);
+
+      await program.methods.unwrapKeys(new anchor.BN(1)).accounts(accounts).signers([buyerKeypair]).rpc();
+      expect(Number((await getMint(provider.connection, wrappedMintPda)).supply)).to.equal(0);
+      expect((await program.account.keyHolder.fetch(keyHolderPda)).amount.toNumber()).to.equal(heldBefore);
+    });
+
+    it("Escrows keys for a loan offer and returns them on cancel", async () => {
+      const loanId = new anchor.BN(1);
+      const [loanPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("key_loan"),
+          buyerKeypair.publicKey.toBuffer(),
+          creatorKeypair.publicKey.toBuffer(),
+          loanId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [escrowPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_loan_escrow"), loanPda.toBuffer()],
+        program.programId
+      );
+      const keyHolderPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), buyerKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      )[0];
+      const heldBefore = (await program.account.keyHolder.fetch(keyHolderPda)).amount.toNumber();
+
+      // The opening ratio must sit above the liquidation line
+      try {
+        await program.methods
+          .createLoanOffer(new anchor.BN(1), 12_000, 12_000, 200, new anchor.BN(86_400), loanId)
+          .accounts({
+            lender: buyerKeypair.publicKey,
+            subject: creatorKeypair.publicKey,
+            loan: loanPda,
+            escrow: escrowPda,
+            config: configPda,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidLoanTerms");
+      }
+
+      await program.methods
+        .createLoanOffer(new anchor.BN(1), 15_000, 12_000, 200, new anchor.BN(86_400), loanId)
+        .accounts({
+          lender: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          loan: loanPda,
+          escrow: escrowPda,
+          config: configPda,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const loan = await program.account.keyLoan.fetch(loanPda);
+      expect(loan.amount.toNumber()).to.equal(1);
+      expect(loan.borrower.toString()).to.equal(PublicKey.default.toString());
+      expect((await program.account.keyHolder.fetch(keyHolderPda)).amount.toNumber()).to.equal(heldBefore - 1);
+
+      await program.methods
+        .cancelLoanOffer()
+        .accounts({
+          mint: (await program.account.userKeys.fetch(creatorKeysPda)).mint,
+          lender: buyerKeypair.publicKey,
+          subject: creatorKeypair.publicKey,
+          loan: loanPda,
+          escrow: escrowPda,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      expect((await program.account.keyHolder.fetch(keyHolderPda)).amount.toNumber()).to.equal(heldBefore);
+      expect(await provider.connection.getAccountInfo(loanPda)).to.be.null;
+    });
+
+    it("Allocates keys to co-founders at creation", async () => {
+      const buyerKeysPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("keys"), buyerKeypair.publicKey.toBuffer()],
+        program.programId
+      )[0];
+      const founders = [userKeypair.publicKey, creatorKeypair.publicKey];
+      const founderHolderPdas = founders.map((founder) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("key_holder"), founder.toBuffer(), buyerKeypair.publicKey.toBuffer()],
+          program.programId
+        )[0]
+      );
+      const [buyerMintPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("keys_mint"), buyerKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const founderTokenAccounts = await Promise.all(
+        founders.map((founder) => getAssociatedTokenAddress(buyerMintPda, founder))
+      );
+
+      await program.methods
+        .createKeys(new anchor.BN(0), new anchor.BN(0), null, [
+          { founder: founders[0], amount: new anchor.BN(5) },
+          { founder: founders[1], amount: new anchor.BN(3) },
+        ], null, null, null, null)
+        .accounts({
+          keys: buyerKeysPda,
+          user: buyerPda,
+          creator: buyerKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          founders.flatMap((founder, i) => [
+            { pubkey: founderHolderPdas[i], isWritable: true, isSigner: false },
+            { pubkey: founder, isWritable: false, isSigner: false },
+            { pubkey: founderTokenAccounts[i], isWritable: true, isSigner: false },
+          ])
+        )
+        .signers([buyerKeypair])
+        .rpc();
+
+      const keysAccount = await program.account.userKeys.fetch(buyerKeysPda);
+      expect(keysAccount.supply.toNumber()).to.equal(1 + 5 + 3);
+      // Only the creator's first key stays unminted; founder keys are real, paid-for tokens
+      expect(keysAccount.creatorLockedAmount.toNumber()).to.equal(1);
+      expect((await getMint(provider.connection, buyerMintPda)).supply).to.equal(BigInt(5 + 3));
+      expect((await getAccount(provider.connection, founderTokenAccounts[0])).amount).to.equal(BigInt(5));
+      expect((await getAccount(provider.connection, founderTokenAccounts[1])).amount).to.equal(BigInt(3));
+
+      const firstFounder = await program.account.keyHolder.fetch(founderHolderPdas[0]);
+      const secondFounder = await program.account.keyHolder.fetch(founderHolderPdas[1]);
+      expect(firstFounder.amount.toNumber()).to.equal(5);
+      expect(secondFounder.amount.toNumber()).to.equal(3);
+
+      // The creator bought the founder keys off the curve, so the reserve backs every one
+      const keysInfo = await provider.connection.getAccountInfo(buyerKeysPda);
+      const keysRent = await provider.connection.getMinimumBalanceForRentExemption(keysInfo.data.length);
+      const founderCost = firstFounder.totalInvested.add(secondFounder.totalInvested);
+      expect(founderCost.toNumber()).to.be.greaterThan(8 * keysAccount.curveParams.basePrice.toNumber());
+      expect(keysInfo.lamports - keysRent).to.equal(founderCost.toNumber());
+    });
+
+    it("Shares a new market's creator fees with collaborators", async () => {
+      const creatorPdaOf = (seed: string, creator: PublicKey) =>
+        PublicKey.findProgramAddressSync([Buffer.from(seed), creator.toBuffer()], program.programId)[0];
+      const revenueSharePda = creatorPdaOf("revenue_share", buyerKeypair.publicKey);
+      const leadVaultPda = creatorPdaOf("earnings_vault", buyerKeypair.publicKey);
+      const collaboratorVaultPda = creatorPdaOf("earnings_vault", userKeypair.publicKey);
+
+      await program.methods
+        .createSharedKeys([
+          { collaborator: buyerKeypair.publicKey, shareBps: 6_000 },
+          { collaborator: userKeypair.publicKey, shareBps: 4_000 },
+        ])
+        .accounts({
+          creator: buyerKeypair.publicKey,
+          revenueShare: revenueSharePda,
+          config: configPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          { pubkey: userKeypair.publicKey, isWritable: false, isSigner: true },
+          { pubkey: collaboratorVaultPda, isWritable: true, isSigner: false },
+        ])
+        .signers([buyerKeypair, userKeypair])
+        .rpc();
+
+      const revenueShare = await program.account.revenueShare.fetch(revenueSharePda);
+      expect(revenueShare.collaboratorCount).to.equal(2);
+      expect(revenueShare.collaborators[1].equals(userKeypair.publicKey)).to.be.true;
+      expect(revenueShare.shareBps.slice(0, 2)).to.deep.equal([6_000, 4_000]);
+      expect((await program.account.earningsVault.fetch(leadVaultPda)).shared).to.be.true;
+      expect(await provider.connection.getAccountInfo(collaboratorVaultPda)).to.not.be.null;
+
+      // Nothing has traded since the market was shared
+      try {
+        await program.methods
+          .distributeSharedEarnings()
+          .accounts({
+            subject: buyerKeypair.publicKey,
+            earningsVault: leadVaultPda,
+            revenueShare: revenueSharePda,
+          })
+          .remainingAccounts([{ pubkey: collaboratorVaultPda, isWritable: true, isSigner: false }])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.toString()).to.include("NothingToClaim");
+      }
+    });
+
+    it("Tallies holder votes weighted by keys held", async () => {
+      const voteId = new anchor.BN(1);
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("holder_vote"), buyerKeypair.publicKey.toBuffer(), voteId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const holderPda = (voter: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("key_holder"), voter.toBuffer(), buyerKeypair.publicKey.toBuffer()],
+          program.programId
+        )[0];
+      const receiptPda = (voter: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("vote_receipt"), votePda.toBuffer(), voter.toBuffer()],
+          program.programId
+        )[0];
+
+      const deadline = Math.floor(Date.now() / 1000) + 3_600;
+      await program.methods
+        .createHolderVote(voteId, "Next feature?", ["Streams", "Merch"], true, new anchor.BN(deadline))
+        .accounts({
+          creatorUser: buyerPda,
+          vote: votePda,
+          creator: buyerKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const castVote = (voter: Keypair, option: number) =>
+        program.methods
+          .castHolderVote(option)
+          .accounts({
+            vote: votePda,
+            keyHolder: holderPda(voter.publicKey),
+            receipt: receiptPda(voter.publicKey),
+            voter: voter.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+
+      // Founder allocations above: 5 keys to the user, 3 to the creator
+      await castVote(userKeypair, 0);
+      await castVote(creatorKeypair, 1);
+
+      const vote = await program.account.holderVote.fetch(votePda);
+      expect(vote.tallies.map((tally) => tally.toNumber())).to.deep.equal([5, 3]);
+      expect(vote.totalWeight.toNumber()).to.equal(8);
+      expect(vote.voterCount.toNumber()).to.equal(2);
+
+      try {
+        await castVote(userKeypair, 1);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("already in use");
+      }
+    });
+
+    it("Counts one vote per holder on unweighted polls", async () => {
+      const voteId = new anchor.BN(2);
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("holder_vote"), buyerKeypair.publicKey.toBuffer(), voteId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const deadline = Math.floor(Date.now() / 1000) + 3_600;
+      await program.methods
+        .createHolderVote(voteId, "Stream day?", ["Friday", "Sunday"], false, new anchor.BN(deadline))
+        .accounts({
+          creatorUser: buyerPda,
+          vote: votePda,
+          creator: buyerKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      for (const [voter, option] of [[userKeypair, 0], [creatorKeypair, 1]] as [Keypair, number][]) {
+        await program.methods
+          .castHolderVote(option)
+          .accounts({
+            vote: votePda,
+            keyHolder: PublicKey.findProgramAddressSync(
+              [Buffer.from("key_holder"), voter.publicKey.toBuffer(), buyerKeypair.publicKey.toBuffer()],
+              program.programId
+            )[0],
+            receipt: PublicKey.findProgramAddressSync(
+              [Buffer.from("vote_receipt"), votePda.toBuffer(), voter.publicKey.toBuffer()],
+              program.programId
+            )[0],
+            voter: voter.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      // 5 and 3 keys held, but each holder counts once
+      const vote = await program.account.holderVote.fetch(votePda);
+      expect(vote.keyWeighted).to.equal(false);
+      expect(vote.tallies.map((tally) => tally.toNumber())).to.deep.equal([1, 1]);
+      expect(vote.totalWeight.toNumber()).to.equal(2);
+    });
+  });
+
+  describe("Social Posts", () => {
+    it("Creates a social post", async () => {
+      const content = "This is my first post on SolSocial!";
+      const imageUrl = "https://example.com/post-image.jpg";
+
+      [postPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("post"),
+          userKeypair.publicKey.toBuffer(),
+          new anchor.BN(0).toArrayLike(Buffer, "le", 8)
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createPost(content, imageUrl)
+        .accounts({
+          post: postPda,
+          user: userPda,
+          author: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const postAccount = await program.account.post.fetch(postPda);
+      expect(postAccount.author.toString()).to.equal(userKeypair.publicKey.toString());
+      expect(postAccount.content).to.equal(content);
+      expect(postAccount.imageUrl).to.equal(imageUrl);
+      expect(postAccount.likesCount.toNumber()).to.equal(0);
+      expect(postAccount.commentsCount.toNumber()).to.equal(0);
+      expect(postAccount.sharesCount.toNumber()).to.equal(0);
+
+      const userAccount = await program.account.user.fetch(userPda);
+      expect(userAccount.postCount.toNumber()).to.equal(1);
+    });
+
+    it("Likes a post", async () => {
+      await program.methods
+        .interactPost({ like: {} })
+        .accounts({
+          post: postPda,
+          user: buyerPda,
+          interactor: buyerKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const postAccount = await program.account.post.fetch(postPda);
+      expect(postAccount.likesCount.toNumber()).to.equal(1);
+    });
+
+    it("Comments on a post", async () => {
+      const comment = "Great post!";
+
+      await program.methods
+        .interactPost({ comment: { content: comment } })
+        .accounts({
+          post: postPda,
+          user: buyerPda,
+          interactor: buyerKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const postAccount = await program.account.post.fetch(postPda);
+      expect(postAccount.commentsCount.toNumber()).to.equal(1);
+    });
+
+    it("Threads replies under standalone comments", async () => {
+      const commentPda = (id: number) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("comment"), postPda.toBuffer(), new anchor.BN(id).toArrayLike(Buffer, "le", 8)],
+          program.programId
+        )[0];
+      const nextId = (await program.account.post.fetch(postPda)).commentCount.toNumber();
+      const rootPda = commentPda(nextId);
+      const replyPda = commentPda(nextId + 1);
+
+      await program.methods
+        .createComment("First!", [])
+        .accounts({
+          post: postPda,
+          comment: rootPda,
+          parentComment: null,
+          gatingKeys: null,
+          keyHolder: null,
+          follow: null,
+          author: buyerKeypair.publicKey,
+          config: configPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      // The same wallet can comment again, this time as a reply
+      await program.methods
+        .createComment("Replying to myself", [])
+        .accounts({
+          post: postPda,
+          comment: replyPda,
+          parentComment: rootPda,
+          gatingKeys: null,
+          keyHolder: null,
+          follow: null,
+          author: buyerKeypair.publicKey,
+          config: configPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const reply = await program.account.comment.fetch(replyPda);
+      expect(reply.parentComment.toString()).to.equal(rootPda.toString());
+      expect((await program.account.comment.fetch(rootPda)).replyCount.toNumber()).to.equal(1);
+
+      await program.methods
+        .deleteComment()
+        .accounts({
+          post: postPda,
+          comment: replyPda,
+          parentComment: rootPda,
+          author: buyerKeypair.publicKey,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      expect((await program.account.comment.fetch(rootPda)).replyCount.toNumber()).to.equal(0);
+      expect(await provider.connection.getAccountInfo(replyPda)).to.be.null;
+      expect((await program.account.post.fetch(postPda)).commentCount.toNumber()).to.equal(nextId + 2);
+    });
+
+    it("Quotes a post with a back-reference and counts it as a share", async () => {
+      const sharesBefore = (await program.account.post.fetch(postPda)).shares.toNumber();
+      const postCount = (await program.account.user.fetch(buyerPda)).postCount;
+      const [quotePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("post"), buyerKeypair.publicKey.toBuffer(), postCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .repost("This, exactly")
+        .accounts({
+          user: buyerPda,
+          post: quotePda,
+          originalPost: postPda,
+          gatingKeys: null,
+          keyHolder: null,
+          activity: null,
+          config: configPda,
+          authority: buyerKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const quote = await program.account.post.fetch(quotePda);
+      expect(quote.originalPost.toString()).to.equal(postPda.toString());
+      expect(quote.content).to.equal("This, exactly");
+      expect((await program.account.post.fetch(postPda)).shares.toNumber()).to.equal(sharesBefore + 1);
+    });
+
+    it("Publishes a post with an off-chain body and verifies it against the hash", async () => {
+      const body = "A long-form thought that would be expensive to keep on-chain. ".repeat(10);
+      const contentHash = createHash("sha256").update(body).digest();
+      const postCount = (await program.account.user.fetch(buyerPda)).postCount;
+      const [hashedPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("post"), buyerKeypair.publicKey.toBuffer(), postCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createPostHashed([...contentHash], "ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U", Buffer.byteLength(body))
+        .accounts({
+          user: buyerPda,
+          post: hashedPda,
+          activity: null,
+          config: configPda,
+          authority: buyerKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const hashed = await program.account.post.fetch(hashedPda);
+      expect(hashed.content).to.equal("");
+      expect(hashed.contentLen).to.equal(Buffer.byteLength(body));
+      expect(Buffer.from(hashed.contentHash).equals(contentHash)).to.be.true;
+
+      await program.methods.verifyPostBody(body).accounts({ post: hashedPda }).view();
+
+      try {
+        await program.methods.verifyPostBody(body.toUpperCase()).accounts({ post: hashedPda }).view();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ContentHashMismatch");
+      }
+    });
+
+    it("Bookmarks a post into a collection and removes it", async () => {
+      const [collectionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("collection"), buyerKeypair.publicKey.toBuffer(), Buffer.from("reading list")],
+        program.programId
+      );
+      const [bookmarkPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bookmark"), buyerKeypair.publicKey.toBuffer(), postPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .createCollection("reading list")
+        .accounts({
+          owner: buyerKeypair.publicKey,
+          collection: collectionPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      await program.methods
+        .bookmarkPost()
+        .accounts({
+          user: buyerKeypair.publicKey,
+          post: postPda,
+          bookmark: bookmarkPda,
+          collection: collectionPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const bookmark = await program.account.bookmark.fetch(bookmarkPda);
+      expect(bookmark.collection.toString()).to.equal(collectionPda.toString());
+      expect((await program.account.collection.fetch(collectionPda)).bookmarkCount.toNumber()).to.equal(1);
+
+      try {
+        await program.methods
+          .deleteCollection()
+          .accounts({ owner: buyerKeypair.publicKey, collection: collectionPda })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("CollectionNotEmpty");
+      }
+
+      await program.methods
+        .removeBookmark()
+        .accounts({
+          user: buyerKeypair.publicKey,
+          bookmark: bookmarkPda,
+          collection: collectionPda,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(bookmarkPda)).to.be.null;
+      expect((await program.account.collection.fetch(collectionPda)).bookmarkCount.toNumber()).to.equal(0);
+
+      await program.methods
+        .deleteCollection()
+        .accounts({ owner: buyerKeypair.publicKey, collection: collectionPda })
+        .signers([buyerKeypair])
+        .rpc();
+      expect(await provider.connection.getAccountInfo(collectionPda)).to.be.null;
+    });
+
+    it("Shares a post", async () => {
+      await program.methods
+        .interactPost({ share: {} })
+        .accounts({
+          post: postPda,
+          user: creatorPda,
+          interactor: creatorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creatorKeypair])
+        .rpc();
+
+      const postAccount = await program.account.post.fetch(postPda);
+      expect(postAccount.sharesCount.toNumber()).to.equal(1);
+    });
+
+    it("Kicks back creator earnings to engagers in proportion to their scores", async () => {
+      const engagementPda = (fan: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("engagement"), userKeypair.publicKey.toBuffer(), fan.toBuffer()],
+          program.programId
+        )[0];
+
+      // Like then comment (1 + 3) versus a single share (5)
+      const buyerEngagement = await program.account.engagementScore.fetch(engagementPda(buyerKeypair.publicKey));
+      const creatorEngagement = await program.account.engagementScore.fetch(engagementPda(creatorKeypair.publicKey));
+      expect(buyerEngagement.score.toNumber()).to.equal(4);
+      expect(creatorEngagement.score.toNumber()).to.equal(5);
+
+      // Give the author some creator earnings to share
+      await program.methods
+        .buyKeys(new anchor.BN(1))
+        .accounts({
+          keys: userKeysPda,
+          user: buyerPda,
+          buyer: buyerKeypair.publicKey,
+          creator: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const rewards: Record<string, number> = {};
+      const listener = program.addEventListener("engagementRewardEvent", (event) => {
+        rewards[event.recipient.toString()] = event.amount.toNumber();
+      });
+
+      await program.methods
+        .distributeEngagementRewards(1_000)
+        .accounts({
+          creator: userKeypair.publicKey,
+          keysAccount: userKeysPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          { pubkey: engagementPda(buyerKeypair.publicKey), isWritable: true, isSigner: false },
+          { pubkey: buyerKeypair.publicKey, isWritable: true, isSigner: false },
+          { pubkey: engagementPda(creatorKeypair.publicKey), isWritable: true, isSigner: false },
+          { pubkey: creatorKeypair.publicKey, isWritable: true, isSigner: false },
+        ])
+        .signers([userKeypair])
+        .rpc();
+
+      await program.removeEventListener(listener);
+
+      const buyerReward = rewards[buyerKeypair.publicKey.toString()];
+      const creatorReward = rewards[creatorKeypair.publicKey.toString()];
+      expect(buyerReward).to.be.greaterThan(0);
+      expect(creatorReward).to.be.greaterThan(buyerReward);
+      expect(Math.abs(buyerReward * 5 - creatorReward * 4)).to.be.lessThan(10);
+
+      const settled = await program.account.engagementScore.fetch(engagementPda(buyerKeypair.publicKey));
+      expect(settled.score.toNumber()).to.equal(0);
+      expect(settled.rewardsEarned.toNumber()).to.equal(buyerReward);
+    });
+
+    it("Applies the author's default visibility when none is given", async () => {
+      await program.methods
+        .updateUserProfile(null, null, null, { keyHoldersOnly: {} }, null, null)
+        .accounts({
+          userAccount: userPda,
+          authority: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const [defaultVisibilityPostPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("post"),
+          userKeypair.publicKey.toBuffer(),
+          new anchor.BN(1).toArrayLike(Buffer, "le", 8)
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createPost("Holders only by default", [], 0, new anchor.BN(0), null, [], null)
+        .accounts({
+          post: defaultVisibilityPostPda,
+          user: userPda,
+          config: configPda,
+          author: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const postAccount = await program.account.post.fetch(defaultVisibilityPostPda);
+      expect(postAccount.visibility).to.deep.equal({ keyHoldersOnly: {} });
+    });
+
+    it("Rejects a post the author can't pay the account rent for", async () => {
+      const postCount = (await program.account.user.fetch(userPda)).postCount;
+      const [postPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("post"), userKeypair.publicKey.toBuffer(), postCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      // Park all but fees and a sliver of the post's rent with the provider for the attempt
+      const postRent = await provider.connection.getMinimumBalanceForRentExemption(1_000);
+      const balance = await provider.connection.getBalance(userKeypair.publicKey);
+      const parked = balance - Math.floor(postRent / 2) - 10_000;
+      const move = (from: Keypair | null, lamports: number) =>
+        provider.sendAndConfirm(
+          new anchor.web3.Transaction().add(
+            SystemProgram.transfer({
+              fromPubkey: from ? from.publicKey : provider.wallet.publicKey,
+              toPubkey: from ? provider.wallet.publicKey : userKeypair.publicKey,
+              lamports,
+            })
+          ),
+          from ? [from] : []
+        );
+      await move(userKeypair, parked);
+
+      try {
+        await program.methods
+          .createPost("Too broke to post", [], 0, new anchor.BN(0), null, [], null)
+          .accounts({
+            post: postPda,
+            user: userPda,
+            config: configPda,
+            author: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientRentExemption");
+      } finally {
+        await move(null, parked);
+      }
+
+      expect(await provider.connection.getAccountInfo(postPda)).to.be.null;
+    });
+
+    it("Closes a story once it expires and refunds the author", async () => {
+      const postCount = (await program.account.user.fetch(userPda)).postCount;
+      const [storyPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("post"), userKeypair.publicKey.toBuffer(), postCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createPost("Gone in a moment", [], 0, new anchor.BN(0), null, [], new anchor.BN(2))
+        .accounts({
+          post: storyPda,
+          user: userPda,
+          config: configPda,
+          author: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const story = await program.account.post.fetch(storyPda);
+      expect(story.expiresAt.sub(story.timestamp).toNumber()).to.equal(2);
+
+      const expire = () =>
+        program.methods
+          .expirePost()
+          .accounts({
+            caller: buyerKeypair.publicKey,
+            post: storyPda,
+            author: userKeypair.publicKey,
+            user: userPda,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      try {
+        await expire();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("PostNotExpired");
+      }
+
+      await new Promise(resolve => setTimeout(resolve, 3000));
+      const authorBalanceBefore = await provider.connection.getBalance(userKeypair.publicKey);
+      const rent = await provider.connection.getBalance(storyPda);
+      await expire();
+
+      expect(await provider.connection.getAccountInfo(storyPda)).to.be.null;
+      const authorBalanceAfter = await provider.connection.getBalance(userKeypair.publicKey);
+      expect(authorBalanceAfter - authorBalanceBefore).to.equal(rent);
+    });
+
+    it("Links thread posts to their root in order", async () => {
+      const appendPart = async (content: string) => {
+        const postCount = (await program.account.user.fetch(userPda)).postCount;
+        const [partPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("post"), userKeypair.publicKey.toBuffer(), postCount.toArrayLike(Buffer, "le", 8)],
+          program.programId
+        );
+        await program.methods
+          .createThreadPost(content)
+          .accounts({
+            user: userPda,
+            post: partPda,
+            threadRoot: postPda,
+            activity: null,
+            config: configPda,
+            authority: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+        return partPda;
+      };
+
+      const first = await appendPart("1/ why bonding curves");
+      const second = await appendPart("2/ and why they need caps");
+
+      for (const [partPda, index] of [[first, 1], [second, 2]] as const) {
+        const part = await program.account.post.fetch(partPda);
+        expect(part.threadRoot.toString()).to.equal(postPda.toString());
+        expect(part.threadIndex).to.equal(index);
+      }
+      expect((await program.account.post.fetch(postPda)).threadLength).to.equal(2);
+
+      // Parts can't be continued as roots of their own
+      try {
+        const postCount = (await program.account.user.fetch(userPda)).postCount;
+        const [strayPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("post"), userKeypair.publicKey.toBuffer(), postCount.toArrayLike(Buffer, "le", 8)],
+          program.programId
+        );
+        await program.methods
+          .createThreadPost("3/ forked")
+          .accounts({
+            user: userPda,
+            post: strayPda,
+            threadRoot: second,
+            activity: null,
+            config: configPda,
+            authority: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidThreadRoot");
+      }
+    });
+
+    it("Only lets followers comment on followers-only posts", async () => {
+      const postCount = (await program.account.user.fetch(userPda)).postCount;
+      const [followersPostPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("post"), userKeypair.publicKey.toBuffer(), postCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [followPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("following"), buyerKeypair.publicKey.toBuffer(), userKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .createPost("Just for followers", [], 0, new anchor.BN(0), { followersOnly: {} }, [], null)
+        .accounts({
+          post: followersPostPda,
+          user: userPda,
+          config: configPda,
+          author: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const comment = (follow: PublicKey | null) => {
+        const [commentPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("comment"), followersPostPda.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+          program.programId
+        );
+        return program.methods
+          .createComment("Following now", [])
+          .accounts({
+            post: followersPostPda,
+            comment: commentPda,
+            parentComment: null,
+            gatingKeys: null,
+            keyHolder: null,
+            follow,
+            author: buyerKeypair.publicKey,
+            config: configPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+      };
+
+      try {
+        await comment(null);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("PostNotVisible");
+      }
+
+      await program.methods
+        .followUser()
+        .accounts({
+          followerAccount: buyerPda,
+          followingAccount: userPda,
+          follow: followPda,
+          follower: buyerKeypair.publicKey,
+          following: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      await comment(followPda);
+      expect((await program.account.post.fetch(followersPostPda)).comments.toNumber()).to.equal(1);
+    });
+
+    it("Indexes the hashtags a post uses and notifies mentioned users", async () => {
+      const postCount = (await program.account.user.fetch(userPda)).postCount;
+      const [taggedPostPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("post"), userKeypair.publicKey.toBuffer(), postCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const hashtagPda = (tag: string) =>
+        PublicKey.findProgramAddressSync([Buffer.from("hashtag"), Buffer.from(tag)], program.programId)[0];
+
+      await program.methods
+        .createPost("Shipping on #Solana today #solana #buildinpublic", [], 0, new anchor.BN(0), null, [buyerKeypair.publicKey], null)
+        .accounts({
+          post: taggedPostPda,
+          user: userPda,
+          config: configPda,
+          author: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...["solana", "buildinpublic"].map((tag) => ({ pubkey: hashtagPda(tag), isWritable: true, isSigner: false })),
+          // Mentioned users' profiles follow the hashtag indexes
+          { pubkey: buyerPda, isWritable: false, isSigner: false },
+        ])
+        .signers([userKeypair])
+        .rpc();
+
+      const index = await program.account.hashtagIndex.fetch(hashtagPda("solana"));
+      expect(index.tag).to.equal("solana");
+      expect(index.usageCount.toNumber()).to.equal(1);
+      expect(index.lastPost.toString()).to.equal(taggedPostPda.toString());
+    });
+
+    it("Tips a post without a share reference", async () => {
+      const [authorVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("earnings_vault"), userKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const tipAmount = new anchor.BN(LAMPORTS_PER_SOL / 100);
+      const vaultBefore = await program.account.earningsVault.fetch(authorVaultPda);
+      const tipsBefore = (await program.account.post.fetch(postPda)).tipsReceived.toNumber();
+      const authorBalanceBefore = await provider.connection.getBalance(authorVaultPda);
+
+      await program.methods
+        .tipPost(tipAmount, "Nice post")
+        .accounts({
+          tipper: buyerKeypair.publicKey,
+          post: postPda,
+          author: userKeypair.publicKey,
+          authorUser: userPda,
+          earningsVault: authorVaultPda,
+          share: null,
+          sharer: null,
+          gatingKeys: null,
+          keyHolder: null,
+          tipMatch: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const authorBalanceAfter = await provider.connection.getBalance(authorVaultPda);
+      expect(authorBalanceAfter - authorBalanceBefore).to.equal(tipAmount.toNumber());
+
+      const vaultAfter = await program.account.earningsVault.fetch(authorVaultPda);
+      expect(vaultAfter.totalAccrued.sub(vaultBefore.totalAccrued).toNumber()).to.equal(tipAmount.toNumber());
+
+      const postAccount = await program.account.post.fetch(postPda);
+      expect(postAccount.tipsReceived.toNumber()).to.equal(tipsBefore + 1);
+    });
+
+    it("Rejects tips sent through interact_post", async () => {
+      try {
+        await program.methods
+          .interactPost({ tip: {} })
+          .accounts({
+            post: postPda,
+            user: buyerPda,
+            interactor: buyerKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("OperationNotAllowed");
+      }
+    });
+
+    it("Boosts a post, splitting the payment between treasury and curators", async () => {
+      const [boostPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("boost"), postPda.toBuffer(), buyerKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [treasuryPda] = PublicKey.findProgramAddressSync([Buffer.from("treasury")], program.programId);
+      const [rewardPoolPda] = PublicKey.findProgramAddressSync([Buffer.from("reward_pool")], program.programId);
+      const payment = new anchor.BN(LAMPORTS_PER_SOL / 50);
+
+      const treasuryBefore = await provider.connection.getBalance(treasuryPda);
+      const poolBefore = await program.account.rewardPool.fetch(rewardPoolPda);
+
+      await program.methods
+        .boostPost(payment, new anchor.BN(60 * 60))
+        .accounts({
+          booster: buyerKeypair.publicKey,
+          post: postPda,
+          boost: boostPda,
+          treasury: treasuryPda,
+          rewardPool: rewardPoolPda,
+          config: configPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const boost = await program.account.boost.fetch(boostPda);
+      expect(boost.post.toString()).to.equal(postPda.toString());
+      expect(boost.author.toString()).to.equal(userKeypair.publicKey.toString());
+      expect(boost.endsAt.sub(boost.startedAt).toNumber()).to.equal(60 * 60);
+      expect(boost.treasuryAmount.add(boost.curatorAmount).toNumber()).to.equal(payment.toNumber());
+
+      const treasuryAfter = await provider.connection.getBalance(treasuryPda);
+      const poolAfter = await program.account.rewardPool.fetch(rewardPoolPda);
+      expect(treasuryAfter - treasuryBefore).to.equal(boost.treasuryAmount.toNumber());
+      expect(poolAfter.totalDeposited.sub(poolBefore.totalDeposited).toNumber()).to.equal(boost.curatorAmount.toNumber());
+
+      // The promotion window is still open
+      try {
+        await program.methods
+          .endBoost()
+          .accounts({
+            caller: creatorKeypair.publicKey,
+            boost: boostPda,
+            booster: buyerKeypair.publicKey,
+          })
+          .signers([creatorKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("BoostStillActive");
+      }
+    });
+
+    it("Splits a tip with the sharer that drove it", async () => {
+      await program.methods
+        .updateUserProfile(null, null, null, null, 1000, null)
+        .accounts({
+          userAccount: userPda,
+          authority: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const [sharePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("share"), postPda.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .sharePost()
+        .accounts({
+          post: postPda,
+          share: sharePda,
+          sharer: creatorKeypair.publicKey,
+          gatingKeys: null,
+          keyHolder: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creatorKeypair])
+        .rpc();
+
+      const [authorVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("earnings_vault"), userKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const tipAmount = new anchor.BN(LAMPORTS_PER_SOL / 100);
+      const authorBalanceBefore = await provider.connection.getBalance(authorVaultPda);
+      const sharerBalanceBefore = await provider.connection.getBalance(creatorKeypair.publicKey);
+
+      await program.methods
+        .tipPost(tipAmount, null)
+        .accounts({
+          tipper: buyerKeypair.publicKey,
+          post: postPda,
+          author: userKeypair.publicKey,
+          authorUser: userPda,
+          earningsVault: authorVaultPda,
+          share: sharePda,
+          sharer: creatorKeypair.publicKey,
+          gatingKeys: null,
+          keyHolder: null,
+          tipMatch: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const authorBalanceAfter = await provider.connection.getBalance(authorVaultPda);
+      const sharerBalanceAfter = await provider.connection.getBalance(creatorKeypair.publicKey);
+      expect(authorBalanceAfter - authorBalanceBefore).to.equal(tipAmount.toNumber() * 0.9);
+      expect(sharerBalanceAfter - sharerBalanceBefore).to.equal(tipAmount.toNumber() * 0.1);
+
+      const shareAccount = await program.account.share.fetch(sharePda);
+      expect(shareAccount.tipsDriven.toNumber()).to.equal(tipAmount.toNumber());
+      expect(shareAccount.rewardsEarned.toNumber()).to.equal(tipAmount.toNumber() * 0.1);
+    });
+
+    it("Matches tips from the creator's escrow until the budget runs out", async () => {
+      const [tipMatchPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("tip_match"), userKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [authorVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("earnings_vault"), userKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const tipAmount = new anchor.BN(LAMPORTS_PER_SOL / 100);
+      const budget = LAMPORTS_PER_SOL / 200 + LAMPORTS_PER_SOL / 500;
+
+      // 50% match with enough budget for one full match and part of a second
+      await program.methods
+        .fundTipMatch(5_000, new anchor.BN(budget))
+        .accounts({
+          tipMatch: tipMatchPda,
+          creator: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const tip = async () => {
+        const before = await provider.connection.getBalance(authorVaultPda);
+        await program.methods
+          .tipPost(tipAmount, null)
+          .accounts({
+            tipper: buyerKeypair.publicKey,
+            post: postPda,
+            author: userKeypair.publicKey,
+            authorUser: userPda,
+          earningsVault: authorVaultPda,
+            share: null,
+            sharer: null,
+            gatingKeys: null,
+            keyHolder: null,
+            tipMatch: tipMatchPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+        const after = await provider.connection.getBalance(authorVaultPda);
+        return after - before - tipAmount.toNumber();
+      };
+
+      expect(await tip()).to.equal(LAMPORTS_PER_SOL / 200);
+      expect(await tip()).to.equal(LAMPORTS_PER_SOL / 500);
+      // Budget exhausted: the tip still lands, unmatched
+      expect(await tip()).to.equal(0);
+
+      const tipMatch = await program.account.tipMatch.fetch(tipMatchPda);
+      expect(tipMatch.remainingBudget.toNumber()).to.equal(0);
+      expect(tipMatch.totalMatched.toNumber()).to.equal(budget);
+    });
+
+    it("Rejects posts once the user's media storage cap is reached", async () => {
+      const creatorPostPda = (index: number) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("post"),
+            creatorKeypair.publicKey.toBuffer(),
+            new anchor.BN(index).toArrayLike(Buffer, "le", 8)
+          ],
+          program.programId
+        )[0];
+      const video = (size: number) => ({
+        url: "https://example.com/clip.mp4",
+        mediaType: "video/mp4",
+        size: new anchor.BN(size),
+        width: 1280,
+        height: 720,
+      });
+
+      await program.methods
+        .createPost("First clip", [video(3_000_000)], 2, new anchor.BN(0), null, [], null)
+        .accounts({
+          post: creatorPostPda(0),
+          user: creatorPda,
+          config: configPda,
+          author: creatorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creatorKeypair])
+        .rpc();
+
+      const creatorAccount = await program.account.user.fetch(creatorPda);
+      expect(creatorAccount.totalMediaBytes.toNumber()).to.equal(3_000_000);
+
+      // An attachment can't declare zero bytes to stay outside the cap
+      try {
+        await program.methods
+          .createPost("Free clip", [video(0)], 2, new anchor.BN(0), null, [], null)
+          .accounts({
+            post: creatorPostPda(1),
+            user: creatorPda,
+            config: configPda,
+            author: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creatorKeypair])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidMetadata");
+      }
+
+      try {
+        await program.methods
+          .createPost("Second clip", [video(3_000_000)], 2, new anchor.BN(0), null, [], null)
+          .accounts({
+            post: creatorPostPda(1),
+            user: creatorPda,
+            config: configPda,
+            author: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creatorKeypair])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("MetadataTooLarge");
+      }
+    });
+
+    it("Rejects identical reposts but accepts distinct content", async () => {
+      const userPostPda = (index: number) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("post"),
+            userKeypair.publicKey.toBuffer(),
+            new anchor.BN(index).toArrayLike(Buffer, "le", 8)
+          ],
+          program.programId
+        )[0];
+      const [recentHashesPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("recent_hashes"), userKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const post = (content: string, index: number) =>
+        program.methods
+          .createPost(content, [], 0, new anchor.BN(0), null, [], null)
+          .accounts({
+            post: userPostPda(index),
+            user: userPda,
+            config: configPda,
+            recentHashes: recentHashesPda,
+            author: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+
+      await post("Buy my keys now!", 2);
+
+      try {
+        await post("Buy my keys now!", 3);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("SpamDetected");
+      }
+
+      await post("Something different entirely", 3);
+
+      const original = await program.account.post.fetch(userPostPda(2));
+      const distinct = await program.account.post.fetch(userPostPda(3));
+      expect(original.contentHash).to.not.be.null;
+      expect(distinct.contentHash).to.not.deep.equal(original.contentHash);
+    });
+
+    it("Rejects a post attributed to another author", async () => {
+      const [spoofedPostPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("post"),
+          creatorKeypair.publicKey.toBuffer(),
+          new anchor.BN(0).toArrayLike(Buffer, "le", 8)
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createPost("Not actually mine", [], 0, new anchor.BN(0), null, [], null)
+          .accounts({
+            post: spoofedPostPda,
+            user: creatorPda,
+            config: configPda,
+            author: creatorKeypair.publicKey,
+            authority: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Rejects gated posts from an author holding too few of their own keys", async () => {
+      const [authorKeyHolderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), creatorKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [gatedPostPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("post"),
+          creatorKeypair.publicKey.toBuffer(),
+          new anchor.BN(1).toArrayLike(Buffer, "le", 8)
+        ],
+        program.programId
+      );
+      const setMinSelfHold = (amount: number) =>
+        program.methods
+          .updateUserProfile(null, null, null, null, null, new anchor.BN(amount))
+          .accounts({
+            userAccount: creatorPda,
+            authority: creatorKeypair.publicKey,
+          })
+          .signers([creatorKeypair])
+          .rpc();
+
+      await setMinSelfHold(1_000);
+
+      try {
+        await program.methods
+          .createPost("Premium alpha", [], 0, new anchor.BN(1), null, [], null)
+          .accounts({
+            post: gatedPostPda,
+            user: creatorPda,
+            config: configPda,
+            authorKeyHolder: authorKeyHolderPda,
+            author: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creatorKeypair])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("SelfHoldTooLow");
+      }
+
+      await setMinSelfHold(0);
+    });
+
+    it("Unlocks a premium post for a reader holding the author's keys", async () => {
+      const postCount = (await program.account.user.fetch(creatorPda)).postCount;
+      const [premiumPostPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("post"), creatorKeypair.publicKey.toBuffer(), postCount.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createPost("Premium alpha", [], 0, new anchor.BN(1), null, [], null)
+        .accounts({
+          post: premiumPostPda,
+          user: creatorPda,
+          config: configPda,
+          author: creatorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creatorKeypair])
+        .rpc();
+
+      const [unlockPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("unlock"), premiumPostPda.toBuffer(), buyerKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const [keyHolderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("key_holder"), buyerKeypair.publicKey.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      // Holding the required key unlocks without paying anything
+      await program.methods
+        .unlockPost(new anchor.BN(0))
+        .accounts({
+          reader: buyerKeypair.publicKey,
+          post: premiumPostPda,
+          keyHolder: keyHolderPda,
+          follow: null,
+          unlock: unlockPda,
+          config: configPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const unlock = await program.account.unlock.fetch(unlockPda);
+      expect(unlock.method).to.deep.equal({ keyHolding: {} });
+      expect(unlock.amountPaid.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("Group Chat", () => {
+    const chatId = "mod-room";
+    let groupChatPda: PublicKey;
+
+    const participantPda = (member: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("chat_participant"), groupChatPda.toBuffer(), member.toBuffer()],
+        program.programId
+      )[0];
+
+    const messageAt = (index: number) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("message"), groupChatPda.toBuffer(), new anchor.BN(index).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+
+    const settingsOf = (user: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("chat_settings"), user.toBuffer()],
+        program.programId
+      )[0];
+
+    it("Keeps auto-muted new members silent until an admin unmutes them", async () => {
+      [groupChatPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("chat"), Buffer.from(chatId)],
+        program.programId
+      );
+
+      await program.methods
+        .createChat(chatId, "Moderated", "New members start muted", false, 10, true, false)
+        .accounts({
+          chat: groupChatPda,
+          creatorUser: creatorPda,
+          creatorParticipant: participantPda(creatorKeypair.publicKey),
+          creator: creatorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creatorKeypair])
+        .rpc();
+
+      await program.methods
+        .joinChat(chatId)
+        .accounts({
+          chat: groupChatPda,
+          memberUser: buyerPda,
+          participant: participantPda(buyerKeypair.publicKey),
+          member: buyerKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      const joined = await program.account.chatParticipant.fetch(participantPda(buyerKeypair.publicKey));
+      expect(joined.isMuted).to.be.true;
+
+      const sendAsBuyer = () =>
+        program.methods
+          .sendMessage("gm", 0, null)
+          .accounts({
+            senderUser: buyerPda,
+            chat: groupChatPda,
+            senderParticipant: participantPda(buyerKeypair.publicKey),
+            message: messageAt(0),
+            recipient: creatorKeypair.publicKey,
+            recipientParticipant: participantPda(creatorKeypair.publicKey),
+            recipientSettings: settingsOf(creatorKeypair.publicKey),
+            senderKeyHolder: null,
+            sender: buyerKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyerKeypair])
+          .rpc();
+
+      try {
+        await sendAsBuyer();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("OperationNotAllowed");
+      }
+
+      await program.methods
+        .setParticipantMute(chatId, false)
+        .accounts({
+          chat: groupChatPda,
+          adminParticipant: participantPda(creatorKeypair.publicKey),
+          targetParticipant: participantPda(buyerKeypair.publicKey),
+          admin: creatorKeypair.publicKey,
+        })
+        .signers([creatorKeypair])
+        .rpc();
+
+      await sendAsBuyer();
+
+      const chatAccount = await program.account.groupChatRoom.fetch(groupChatPda);
+      expect(chatAccount.messageCount.toNumber()).to.equal(1);
+      expect(chatAccount.participantCount).to.equal(2);
+    });
+
+    it("Sizes message accounts to their content", async () => {
+      const send = (content: string, index: number) =>
+        program.methods
+          .sendMessage(content, 0, null)
+          .accounts({
+            senderUser: creatorPda,
+            chat: groupChatPda,
+            senderParticipant: participantPda(creatorKeypair.publicKey),
+            message: messageAt(index),
+            recipient: creatorKeypair.publicKey,
+            recipientParticipant: participantPda(creatorKeypair.publicKey),
+            recipientSettings: settingsOf(creatorKeypair.publicKey),
+            senderKeyHolder: null,
+            sender: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creatorKeypair])
+          .rpc();
+
+      const { messageCount } = await program.account.groupChatRoom.fetch(groupChatPda);
+      const shortIndex = messageCount.toNumber();
+      await send("gm", shortIndex);
+      await send("x".repeat(500), shortIndex + 1);
+
+      const shortInfo = await provider.connection.getAccountInfo(messageAt(shortIndex));
+      const longInfo = await provider.connection.getAccountInfo(messageAt(shortIndex + 1));
+      expect(shortInfo.data.length).to.be.lessThan(longInfo.data.length);
+      expect(longInfo.data.length - shortInfo.data.length).to.equal(500 - "[deleted]".length);
+      expect(shortInfo.lamports).to.be.lessThan(longInfo.lamports);
+    });
+
+    it("Rejects a message the sender can't pay the account rent for", async () => {
+      const member = Keypair.generate();
+      await provider.connection.requestAirdrop(member.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const [memberPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user"), member.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeUser("thinwallet", "Almost out of SOL", "https://example.com/thin.jpg")
+        .accounts({
+          user: memberPda,
+          authority: member.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member])
+        .rpc();
+
+      await program.methods
+        .joinChat(chatId)
+        .accounts({
+          chat: groupChatPda,
+          memberUser: memberPda,
+          participant: participantPda(member.publicKey),
+          member: member.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member])
+        .rpc();
+
+      // Leave enough for fees but only half the message account's rent, so the handler has to
+      // catch it before creating the account
+      const { messageCount } = await program.account.groupChatRoom.fetch(groupChatPda);
+      const messageSpace = (await provider.connection.getAccountInfo(messageAt(0))).data.length;
+      const messageRent = await provider.connection.getMinimumBalanceForRentExemption(messageSpace);
+      const balance = await provider.connection.getBalance(member.publicKey);
+      const keep = Math.floor(messageRent / 2) + 10_000;
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: member.publicKey,
+            toPubkey: provider.wallet.publicKey,
+            lamports: balance - keep - 5_000,
+          })
+        ),
+        [member]
+      );
+
+      try {
+        await program.methods
+          .sendMessage("gm", 0, null)
+          .accounts({
+            senderUser: memberPda,
+            chat: groupChatPda,
+            senderParticipant: participantPda(member.publicKey),
+            message: messageAt(messageCount.toNumber()),
+            recipient: creatorKeypair.publicKey,
+            recipientParticipant: participantPda(creatorKeypair.publicKey),
+            recipientSettings: settingsOf(creatorKeypair.publicKey),
+            senderKeyHolder: null,
+            sender: member.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientRentExemption");
+      }
+    });
+
+    it("Lets only admins pin a message to the room", async () => {
+      const pin = (admin: Keypair) =>
+        program.methods
+          .pinMessage(chatId)
+          .accounts({
+            chat: groupChatPda,
+            adminParticipant: participantPda(admin.publicKey),
+            message: messageAt(0),
+            admin: admin.publicKey,
+          })
+          .signers([admin])
+          .rpc();
+
+      try {
+        await pin(buyerKeypair);
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("NotAuthorizedForChat");
+      }
+
+      await pin(creatorKeypair);
+      let chatAccount = await program.account.groupChatRoom.fetch(groupChatPda);
+      expect(new PublicKey(chatAccount.pinnedMessage).toString()).to.equal(messageAt(0).toString());
+
+      await program.methods
+        .unpinMessage(chatId)
+        .accounts({
+          chat: groupChatPda,
+          adminParticipant: participantPda(creatorKeypair.publicKey),
+          admin: creatorKeypair.publicKey,
+        })
+        .signers([creatorKeypair])
+        .rpc();
+      chatAccount = await program.account.groupChatRoom.fetch(groupChatPda);
+      expect(chatAccount.pinnedMessage).to.be.null;
+    });
+
+    it("Fires the recipient's auto-reply for a non-holder's first message", async () => {
+      const dmId = "dm-auto-reply";
+      const [dmPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("chat"), Buffer.from(dmId)],
+        program.programId
+      );
+      const dmParticipant = (member: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("chat_participant"), dmPda.toBuffer(), member.toBuffer()],
+          program.programId
+        )[0];
+      const dmMessage = (index: number) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("message"), dmPda.toBuffer(), new anchor.BN(index).toArrayLike(Buffer, "le", 8)],
+          program.programId
+        )[0];
+      const settingsPda = settingsOf(userKeypair.publicKey);
+      const reply = "Thanks for reaching out! Holders get replies first.";
+
+      await program.methods
+        .updateChatSettings(true, false, null, null, null, null, null, reply)
+        .accounts({
+          settings: settingsPda,
+          user: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      await program.methods
+        .createChat(dmId, "DM", "", false, 2, false, true)
+        .accounts({
+          chat: dmPda,
+          creatorUser: userPda,
+          creatorParticipant: dmParticipant(userKeypair.publicKey),
+          creator: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      await program.methods
+        .joinChat(dmId)
+        .accounts({
+          chat: dmPda,
+          memberUser: creatorPda,
+          participant: dmParticipant(creatorKeypair.publicKey),
+          member: creatorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creatorKeypair])
+        .rpc();
+
+      const autoReplies: any[] = [];
+      const listener = program.addEventListener("autoReplyEvent", (event) => autoReplies.push(event));
+
+      // The creator holds none of the recipient's keys
+      for (const index of [0, 1]) {
+        await program.methods
+          .sendMessage(`hello ${index}`, 0, null)
+          .accounts({
+            senderUser: creatorPda,
+            chat: dmPda,
+            senderParticipant: dmParticipant(creatorKeypair.publicKey),
+            message: dmMessage(index),
+            recipient: userKeypair.publicKey,
+            recipientParticipant: dmParticipant(userKeypair.publicKey),
+            recipientSettings: settingsPda,
+            senderKeyHolder: null,
+            sender: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creatorKeypair])
+          .rpc();
+      }
+
+      await program.removeEventListener(listener);
+
+      // Only the first message from the non-holder triggers it
+      expect(autoReplies).to.have.length(1);
+      expect(autoReplies[0].message).to.equal(reply);
+      expect(autoReplies[0].recipient.toString()).to.equal(userKeypair.publicKey.toString());
+      expect(autoReplies[0].sender.toString()).to.equal(creatorKeypair.publicKey.toString());
+    });
+
+    it("Applies default DM settings when the recipient never saved any", async () => {
+      const dmId = "dm-default-gate";
+      const [dmPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("chat"), Buffer.from(dmId)],
+        program.programId
+      );
+      const dmParticipant = (member: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("chat_participant"), dmPda.toBuffer(), member.toBuffer()],
+          program.programId
+        )[0];
+      const [firstMessage] = PublicKey.findProgramAddressSync(
+        [Buffer.from("message"), dmPda.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createChat(dmId, "DM", "", false, 2, false, true)
+        .accounts({
+          chat: dmPda,
+          creatorUser: buyerPda,
+          creatorParticipant: dmParticipant(buyerKeypair.publicKey),
+          creator: buyerKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyerKeypair])
+        .rpc();
+
+      await program.methods
+        .joinChat(dmId)
+        .accounts({
+          chat: dmPda,
+          memberUser: creatorPda,
+          participant: dmParticipant(creatorKeypair.publicKey),
+          member: creatorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creatorKeypair])
+        .rpc();
+
+      // The buyer has no settings account, so the open defaults apply and anyone may DM them
+      expect(await provider.connection.getAccountInfo(settingsOf(buyerKeypair.publicKey))).to.be.null;
+      expect((await program.account.groupChatRoom.fetch(dmPda)).isDirect).to.be.true;
+
+      await program.methods
+        .sendMessage(dmId, "hello")
+        .accounts({
+          senderUser: creatorPda,
+          chat: dmPda,
+          senderParticipant: dmParticipant(creatorKeypair.publicKey),
+          message: firstMessage,
+          recipient: buyerKeypair.publicKey,
+          recipientParticipant: dmParticipant(buyerKeypair.publicKey),
+          recipientSettings: settingsOf(buyerKeypair.publicKey),
+          senderKeyHolder: null,
+          sender: creatorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creatorKeypair])
+        .rpc();
+
+      const message = await program.account.chatMessage.fetch(firstMessage);
+      expect(message.content).to.equal("hello");
+    });
+
+    it("Rejects a chat description one byte past the account's capacity", async () => {
+      const createRoom = (roomId: string, description: string) => {
+        const [roomPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("chat"), Buffer.from(roomId)],
+          program.programId
+        );
+        const [creatorParticipantPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("chat_participant"), roomPda.toBuffer(), creatorKeypair.publicKey.toBuffer()],
+          program.programId
+        );
+
+        return program.methods
+          .createChat(roomId, "Boundary", description, false, 10, false, false)
+          .accounts({
+            chat: roomPda,
+            creatorUser: creatorPda,
+            creatorParticipant: creatorParticipantPda,
+            creator: creatorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creatorKeypair])
+          .rpc();
+      };
+
+      try {
+        // Used to pass the old 256-char check and overflow the 200-byte slot
+        await createRoom("too-long", "d".repeat(201));
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("ChatDescriptionTooLong");
+      }
+
+      await createRoom("at-limit", "d".repeat(200));
     });
   });
 